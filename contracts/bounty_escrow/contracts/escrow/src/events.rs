@@ -1,7 +1,10 @@
-use crate::CapabilityAction;
-use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env};
+use crate::{CapabilityAction, DisputeRuling, IdentityTier};
+use soroban_sdk::{contracttype, symbol_short, Address, Bytes, BytesN, Env, String, Symbol, Vec};
 
 pub const EVENT_VERSION_V2: u32 = 2;
+/// Fund-moving events carry the escrow's `remaining_before` and
+/// `remaining_after` from this version on.
+pub const EVENT_VERSION_V3: u32 = 3;
 
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -23,6 +26,8 @@ pub struct FundsLocked {
     pub version: u32,
     pub bounty_id: u64,
     pub amount: i128,
+    pub remaining_before: i128,
+    pub remaining_after: i128,
     pub depositor: Address,
     pub deadline: u64,
 }
@@ -38,6 +43,8 @@ pub struct FundsReleased {
     pub version: u32,
     pub bounty_id: u64,
     pub amount: i128,
+    pub remaining_before: i128,
+    pub remaining_after: i128,
     pub recipient: Address,
     pub timestamp: u64,
 }
@@ -53,6 +60,8 @@ pub struct FundsRefunded {
     pub version: u32,
     pub bounty_id: u64,
     pub amount: i128,
+    pub remaining_before: i128,
+    pub remaining_after: i128,
     pub refund_to: Address,
     pub timestamp: u64,
 }
@@ -167,6 +176,8 @@ pub struct ApprovalAdded {
     pub bounty_id: u64,
     pub contributor: Address,
     pub approver: Address,
+    pub weight: u32,
+    pub total_weight: u32,
     pub timestamp: u64,
 }
 
@@ -175,6 +186,431 @@ pub fn emit_approval_added(env: &Env, event: ApprovalAdded) {
     env.events().publish(topics, event.clone());
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApprovalRevoked {
+    pub bounty_id: u64,
+    pub contributor: Address,
+    pub approver: Address,
+    pub total_weight: u32,
+    pub timestamp: u64,
+}
+
+pub fn emit_approval_revoked(env: &Env, event: ApprovalRevoked) {
+    let topics = (symbol_short!("appr_rev"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+/// Emitted when `release_funds` parks a release for its challenge period.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleasePending {
+    pub bounty_id: u64,
+    pub contributor: Address,
+    pub amount: i128,
+    pub finalize_after: u64,
+}
+
+pub fn emit_release_pending(env: &Env, event: ReleasePending) {
+    let topics = (symbol_short!("rel_pend"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleaseDisputed {
+    pub bounty_id: u64,
+    pub depositor: Address,
+    pub reason_hash: BytesN<32>,
+    pub disputed_at: u64,
+}
+
+pub fn emit_release_disputed(env: &Env, event: ReleaseDisputed) {
+    let topics = (symbol_short!("rel_disp"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+/// Emitted once an arbiter's ruling has been applied, after the
+/// `FundsReleased` / `FundsRefunded` events for the funds it moved.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeResolved {
+    pub version: u32,
+    pub bounty_id: u64,
+    pub arbiter: Address,
+    pub ruling: DisputeRuling,
+    pub contributor_amount: i128,
+    pub depositor_amount: i128,
+    pub remaining_before: i128,
+    pub remaining_after: i128,
+    pub ruled_at: u64,
+}
+
+pub fn emit_dispute_resolved(env: &Env, event: DisputeResolved) {
+    let topics = (symbol_short!("disp_res"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WorkSubmitted {
+    pub bounty_id: u64,
+    pub contributor: Address,
+    pub work_hash: BytesN<32>,
+    pub submitted_at: u64,
+}
+
+pub fn emit_work_submitted(env: &Env, event: WorkSubmitted) {
+    let topics = (symbol_short!("work_sub"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+/// Emitted when a claim bond is staked, returned or slashed; `action` is
+/// `staked`, `returned` or `slashed`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimBondEvent {
+    pub bounty_id: u64,
+    pub contributor: Address,
+    pub amount: i128,
+    pub action: Symbol,
+    pub timestamp: u64,
+}
+
+pub fn emit_claim_bond(env: &Env, event: ClaimBondEvent) {
+    let topics = (symbol_short!("bond"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BountyGroupCreated {
+    pub group_id: u64,
+    pub bounty_ids: Vec<u64>,
+    pub timestamp: u64,
+}
+
+pub fn emit_bounty_group_created(env: &Env, event: BountyGroupCreated) {
+    let topics = (symbol_short!("grp_new"), event.group_id);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BountyGroupPaused {
+    pub group_id: u64,
+    pub paused: bool,
+    pub timestamp: u64,
+}
+
+pub fn emit_bounty_group_paused(env: &Env, event: BountyGroupPaused) {
+    let topics = (symbol_short!("grp_pause"), event.group_id);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AnonymousPayoutReserved {
+    pub bounty_id: u64,
+    pub recipient_commitment: BytesN<32>,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_anonymous_payout_reserved(env: &Env, event: AnonymousPayoutReserved) {
+    let topics = (symbol_short!("anon_rsv"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AnonymousPayoutClaimed {
+    pub version: u32,
+    pub bounty_id: u64,
+    pub recipient: Address,
+    pub amount: i128,
+    pub remaining_before: i128,
+    pub remaining_after: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_anonymous_payout_claimed(env: &Env, event: AnonymousPayoutClaimed) {
+    let topics = (symbol_short!("anon_clm"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DepositorRevealed {
+    pub bounty_id: u64,
+    pub depositor_commitment: BytesN<32>,
+    pub depositor: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_depositor_revealed(env: &Env, event: DepositorRevealed) {
+    let topics = (symbol_short!("dep_rvl"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IdentityTierSet {
+    pub address: Address,
+    pub tier: IdentityTier,
+    pub changed_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_identity_tier_set(env: &Env, event: IdentityTierSet) {
+    let topics = (symbol_short!("id_tier"), event.address.clone());
+    env.events().publish(topics, event);
+}
+
+/// Emitted when a lock, release or refund runs on a jurisdiction-tagged
+/// escrow; `operation` is `lock`, `release` or `refund`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct JurisdictionApplied {
+    pub bounty_id: u64,
+    pub operation: Symbol,
+    pub tag: Option<String>,
+    pub timestamp: u64,
+}
+
+pub fn emit_jurisdiction_applied(env: &Env, event: JurisdictionApplied) {
+    let topics = (symbol_short!("juris"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct JurisdictionTagRegistered {
+    pub tag: String,
+    pub registered_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_jurisdiction_tag_registered(env: &Env, event: JurisdictionTagRegistered) {
+    let topics = (symbol_short!("juris_reg"),);
+    env.events().publish(topics, event);
+}
+
+/// Emitted by `set_escrow_pause_flags`: as `esc_frz` when any operation is
+/// left paused, `esc_unfrz` when all flags are cleared.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowPauseFlagsChanged {
+    pub bounty_id: u64,
+    pub previous_flags: u32,
+    pub flags: u32,
+    pub changed_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_escrow_frozen(env: &Env, event: EscrowPauseFlagsChanged) {
+    let topics = (symbol_short!("esc_frz"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+pub fn emit_escrow_unfrozen(env: &Env, event: EscrowPauseFlagsChanged) {
+    let topics = (symbol_short!("esc_unfrz"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+/// Emitted when the admin timelocks a release until `release_at`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleaseScheduled {
+    pub bounty_id: u64,
+    pub contributor: Address,
+    pub amount: i128,
+    pub release_at: u64,
+}
+
+pub fn emit_release_scheduled(env: &Env, event: ReleaseScheduled) {
+    let topics = (symbol_short!("rel_sched"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+/// Emitted when a scheduled release is cancelled before it executes.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScheduledReleaseCancelled {
+    pub bounty_id: u64,
+    pub contributor: Address,
+    pub cancelled_at: u64,
+}
+
+pub fn emit_scheduled_release_cancelled(env: &Env, event: ScheduledReleaseCancelled) {
+    let topics = (symbol_short!("sched_cxl"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+/// Emitted when an escrow is converted into a vesting stream.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingStarted {
+    pub bounty_id: u64,
+    pub contributor: Address,
+    pub total: i128,
+    pub start: u64,
+    pub duration: u64,
+}
+
+pub fn emit_vesting_started(env: &Env, event: VestingStarted) {
+    let topics = (symbol_short!("vest_new"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+/// Emitted when the depositor cancels a vesting stream and it is settled.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingCancelled {
+    pub version: u32,
+    pub bounty_id: u64,
+    pub contributor: Address,
+    pub contributor_amount: i128,
+    pub depositor_amount: i128,
+    pub remaining_before: i128,
+    pub remaining_after: i128,
+    pub cancelled_at: u64,
+}
+
+pub fn emit_vesting_cancelled(env: &Env, event: VestingCancelled) {
+    let topics = (symbol_short!("vest_cxl"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+/// Emitted when the admin closes an accounting period.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccountingCheckpointed {
+    pub sequence: u64,
+    pub locked: i128,
+    pub released: i128,
+    pub refunded: i128,
+    pub fees_collected: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_accounting_checkpoint(env: &Env, event: AccountingCheckpointed) {
+    let topics = (symbol_short!("acct_chk"), event.sequence);
+    env.events().publish(topics, event);
+}
+
+/// Emitted when the admin swaps the contract wasm.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractUpgraded {
+    pub new_wasm_hash: BytesN<32>,
+    pub version: u32,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_contract_upgraded(env: &Env, event: ContractUpgraded) {
+    let topics = (symbol_short!("upgrade"), symbol_short!("wasm"));
+    env.events().publish(topics, event);
+}
+
+/// Emitted when an escrow record written by older code is rewritten in the
+/// current layout.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowMigrated {
+    pub bounty_id: u64,
+    pub schema_version: u32,
+    pub timestamp: u64,
+}
+
+pub fn emit_escrow_migrated(env: &Env, event: EscrowMigrated) {
+    let topics = (symbol_short!("esc_migr"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+/// Emitted when the compliance block list changes.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AddressBlockedChanged {
+    pub address: Address,
+    pub blocked: bool,
+    pub changed_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_address_blocked(env: &Env, event: AddressBlockedChanged) {
+    let topics = (symbol_short!("blocked"), event.address.clone());
+    env.events().publish(topics, event);
+}
+
+/// Emitted when the compliance role grants or revokes a screening override.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScreeningOverrideChanged {
+    pub address: Address,
+    pub enabled: bool,
+    pub changed_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_screening_override(env: &Env, event: ScreeningOverrideChanged) {
+    let topics = (symbol_short!("scr_ovrd"), event.address.clone());
+    env.events().publish(topics, event);
+}
+
+/// Emitted when a screening override lets an operation through that the
+/// screening contract denied.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScreeningBypassed {
+    pub address: Address,
+    pub amount: i128,
+    pub screener: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_screening_bypassed(env: &Env, event: ScreeningBypassed) {
+    let topics = (symbol_short!("scr_bypas"), event.address.clone());
+    env.events().publish(topics, event);
+}
+
+/// Emitted when a keeper refunds an expired auto-refund bounty.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AutoRefundExecuted {
+    pub version: u32,
+    pub bounty_id: u64,
+    pub keeper: Address,
+    pub reward: i128,
+    pub refunded: i128,
+    pub remaining_before: i128,
+    pub remaining_after: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_auto_refund_executed(env: &Env, event: AutoRefundExecuted) {
+    let topics = (symbol_short!("auto_rfnd"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+/// Emitted when a lock delivers less than the requested amount, e.g. for
+/// tokens that charge a fee on transfer. The escrow holds `received`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransferShortfall {
+    pub bounty_id: u64,
+    pub token: Address,
+    pub expected: i128,
+    pub received: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_transfer_shortfall(env: &Env, event: TransferShortfall) {
+    let topics = (symbol_short!("xfer_shrt"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ClaimCreated {
@@ -187,9 +623,12 @@ pub struct ClaimCreated {
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ClaimExecuted {
+    pub version: u32,
     pub bounty_id: u64,
     pub recipient: Address,
     pub amount: i128,
+    pub remaining_before: i128,
+    pub remaining_after: i128,
     pub claimed_at: u64,
 }
 
@@ -234,6 +673,8 @@ pub struct FundsLockedAnon {
     pub version: u32,
     pub bounty_id: u64,
     pub amount: i128,
+    pub remaining_before: i128,
+    pub remaining_after: i128,
     pub depositor_commitment: BytesN<32>,
     pub deadline: u64,
 }
@@ -336,9 +777,12 @@ pub fn emit_ticket_issued(env: &Env, event: TicketIssued) {
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct TicketClaimed {
+    pub version: u32,
     pub ticket_id: u64,
     pub bounty_id: u64,
     pub claimer: Address,
+    pub remaining_before: i128,
+    pub remaining_after: i128,
     pub claimed_at: u64,
 }
 
@@ -347,17 +791,167 @@ pub fn emit_ticket_claimed(env: &Env, event: TicketClaimed) {
     env.events().publish(topics, event);
 }
 
+/// Emitted for every `claim_with_ticket_partial` withdrawal. `TicketClaimed`
+/// follows once the ticket's balance is exhausted. `remaining_amount` is the
+/// ticket's; `remaining_before` / `remaining_after` are the escrow's.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TicketPartiallyClaimed {
+    pub version: u32,
+    pub ticket_id: u64,
+    pub bounty_id: u64,
+    pub amount: i128,
+    pub remaining_amount: i128,
+    pub remaining_before: i128,
+    pub remaining_after: i128,
+    pub claimer: Address,
+    pub claimed_at: u64,
+}
+
+pub fn emit_ticket_partially_claimed(env: &Env, event: TicketPartiallyClaimed) {
+    let topics = (symbol_short!("ticket_p"), event.ticket_id);
+    env.events().publish(topics, event);
+}
+
+/// Emitted by `claim_with_ticket_for` alongside the usual claim events.
+/// `amount` is the whole claim; the beneficiary received `amount - tip`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RelayedClaim {
+    pub version: u32,
+    pub ticket_id: u64,
+    pub bounty_id: u64,
+    pub beneficiary: Address,
+    pub relayer: Address,
+    pub amount: i128,
+    pub tip: i128,
+    pub remaining_before: i128,
+    pub remaining_after: i128,
+    pub claimed_at: u64,
+}
+
+pub fn emit_relayed_claim(env: &Env, event: RelayedClaim) {
+    let topics = (symbol_short!("tkt_rly"), event.ticket_id);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaymentIntentCreated {
+    pub intent_id: u64,
+    pub bounty_id: u64,
+    pub depositor: Address,
+    pub contributor: Address,
+    pub amount: i128,
+    pub nonce: u64,
+    pub expiry: u64,
+    pub created_at: u64,
+}
+
+pub fn emit_payment_intent_created(env: &Env, event: PaymentIntentCreated) {
+    let topics = (symbol_short!("intent_c"), event.intent_id);
+    env.events().publish(topics, event);
+}
+
+/// Emitted by `execute_payment_intent` alongside `FundsReleased`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaymentIntentExecuted {
+    pub version: u32,
+    pub intent_id: u64,
+    pub bounty_id: u64,
+    pub contributor: Address,
+    pub amount: i128,
+    pub remaining_before: i128,
+    pub remaining_after: i128,
+    pub executed_at: u64,
+}
+
+pub fn emit_payment_intent_executed(env: &Env, event: PaymentIntentExecuted) {
+    let topics = (symbol_short!("intent_e"), event.intent_id);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaymentIntentCancelled {
+    pub intent_id: u64,
+    pub bounty_id: u64,
+    pub cancelled_at: u64,
+}
+
+pub fn emit_payment_intent_cancelled(env: &Env, event: PaymentIntentCancelled) {
+    let topics = (symbol_short!("intent_x"), event.intent_id);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TicketTransferred {
+    pub ticket_id: u64,
+    pub bounty_id: u64,
+    pub from: Address,
+    pub to: Address,
+    pub transferred_at: u64,
+}
+
+pub fn emit_ticket_transferred(env: &Env, event: TicketTransferred) {
+    let topics = (symbol_short!("ticket_t"), event.ticket_id);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TicketRevoked {
+    pub ticket_id: u64,
+    pub bounty_id: u64,
+    /// Reservation returned to the bounty's claimable balance.
+    pub amount: i128,
+    pub revoked_at: u64,
+}
+
+pub fn emit_ticket_revoked(env: &Env, event: TicketRevoked) {
+    let topics = (symbol_short!("ticket_r"), event.ticket_id);
+    env.events().publish(topics, event);
+}
+
+/// Emitted by `sweep_expired_tickets` when at least one ticket was swept.
+/// No tokens move, so `remaining_before` / `remaining_after` are the
+/// escrow's claimable balance (remaining amount less ticket reservations),
+/// which grows by `released_amount`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TicketsSwept {
+    pub version: u32,
+    pub bounty_id: u64,
+    pub count: u32,
+    pub released_amount: i128,
+    pub remaining_before: i128,
+    pub remaining_after: i128,
+    pub swept_at: u64,
+}
+
+pub fn emit_tickets_swept(env: &Env, event: TicketsSwept) {
+    let topics = (symbol_short!("ticket_s"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
 pub fn emit_pause_state_changed(env: &Env, event: crate::PauseStateChanged) {
     let topics = (symbol_short!("pause"), event.operation.clone());
     env.events().publish(topics, event);
 }
 
+/// `remaining_before` / `remaining_after` are the contract's whole token
+/// balance, since the withdrawal drains every escrow at once.
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct EmergencyWithdrawEvent {
+    pub version: u32,
     pub admin: Address,
     pub recipient: Address,
     pub amount: i128,
+    pub remaining_before: i128,
+    pub remaining_after: i128,
     pub timestamp: u64,
 }
 
@@ -415,3 +1009,106 @@ pub fn emit_capability_revoked(env: &Env, event: CapabilityRevoked) {
     let topics = (symbol_short!("cap_rev"), event.capability_id);
     env.events().publish(topics, event);
 }
+
+/// Emitted for each `partial_release` tranche alongside `FundsReleased`,
+/// carrying the balance still locked after the tranche.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TrancheReleased {
+    pub version: u32,
+    pub bounty_id: u64,
+    pub amount: i128,
+    pub remaining_amount: i128,
+    pub remaining_before: i128,
+    pub remaining_after: i128,
+    pub recipient: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_tranche_released(env: &Env, event: TrancheReleased) {
+    let topics = (symbol_short!("tranche"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+/// Emitted when the depositor tops up an already locked bounty.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowIncreased {
+    pub version: u32,
+    pub bounty_id: u64,
+    /// Gross amount transferred in by the depositor.
+    pub added_amount: i128,
+    /// Escrow `amount` after the top-up (net of any lock fee).
+    pub new_amount: i128,
+    pub remaining_amount: i128,
+    pub remaining_before: i128,
+    pub remaining_after: i128,
+    pub depositor: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_escrow_increased(env: &Env, event: EscrowIncreased) {
+    let topics = (symbol_short!("f_incr"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+/// Emitted by `split_escrow`. `remaining_before`/`remaining_after` are the
+/// parent's; the child `new_bounty_id` starts with `amount`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowSplit {
+    pub version: u32,
+    pub bounty_id: u64,
+    pub new_bounty_id: u64,
+    pub amount: i128,
+    pub remaining_before: i128,
+    pub remaining_after: i128,
+    pub depositor: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_escrow_split(env: &Env, event: EscrowSplit) {
+    let topics = (symbol_short!("f_split"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+/// Emitted by `merge_escrows` on the target `bounty_id`, which absorbed
+/// `amount` from `source_bounty_id`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowsMerged {
+    pub version: u32,
+    pub bounty_id: u64,
+    pub source_bounty_id: u64,
+    pub amount: i128,
+    pub remaining_before: i128,
+    pub remaining_after: i128,
+    pub deadline: u64,
+    pub depositor: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_escrows_merged(env: &Env, event: EscrowsMerged) {
+    let topics = (symbol_short!("f_merge"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+/// Emitted when a hashlocked bounty is claimed. Publishes the preimage so
+/// the counterparty of a cross-chain or off-chain swap can settle its leg.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HashlockClaimed {
+    pub version: u32,
+    pub bounty_id: u64,
+    pub amount: i128,
+    pub remaining_before: i128,
+    pub remaining_after: i128,
+    pub claimant: Address,
+    pub preimage: Bytes,
+    pub timestamp: u64,
+}
+
+pub fn emit_hashlock_claimed(env: &Env, event: HashlockClaimed) {
+    let topics = (symbol_short!("htlc_clm"), event.bounty_id);
+    env.events().publish(topics, event);
+}
@@ -16,15 +16,168 @@ mod test_multi_token_fees;
 mod test_rbac;
 #[cfg(test)]
 mod test_risk_flags;
+mod threshold_monitor;
 mod traits;
 pub mod upgrade_safety;
 
 #[cfg(test)]
 mod test_maintenance_mode;
 
+#[cfg(test)]
+mod test_global_halt;
+
+#[cfg(test)]
+mod test_split_release;
+
+#[cfg(test)]
+mod test_release_partial;
+
+#[cfg(test)]
+mod test_increase_escrow;
+
+#[cfg(test)]
+mod test_mutual_cancel;
+
+#[cfg(test)]
+mod test_per_bounty_token;
+
+#[cfg(test)]
+mod test_native_token;
+
+#[cfg(test)]
+mod test_hashlock;
+
+#[cfg(test)]
+mod test_ticket_transfer;
+
+#[cfg(test)]
+mod test_ticket_revocation;
+
+#[cfg(test)]
+mod test_ticket_partial_claims;
+
+#[cfg(test)]
+mod test_ticket_batch;
+
+#[cfg(test)]
+mod test_weighted_approvals;
+
+#[cfg(test)]
+mod test_approval_revocation;
+
+#[cfg(test)]
+mod test_release_dispute_window;
+
+#[cfg(test)]
+mod test_dispute_arbitration;
+
+#[cfg(test)]
+mod test_work_submission;
+
+#[cfg(test)]
+mod test_claim_bonds;
+
+#[cfg(test)]
+mod test_bounty_groups;
+
+#[cfg(test)]
+mod test_anonymous_payouts;
+
+#[cfg(test)]
+mod test_resolver_quorum;
+
+#[cfg(test)]
+mod test_reveal_depositor;
+
+#[cfg(test)]
+mod test_escrow_visibility;
+
+#[cfg(test)]
+mod test_fee_on_transfer;
+
+#[cfg(test)]
+mod test_allowance_lock;
+
+#[cfg(test)]
+mod test_batch_lock_validation;
+
+#[cfg(test)]
+mod test_batch_refund;
+
+#[cfg(test)]
+mod test_auto_refund;
+
+#[cfg(test)]
+mod test_lock_rate_limit;
+
+#[cfg(test)]
+mod test_compliance_blocklist;
+#[cfg(test)]
+mod test_screening;
+
+#[cfg(test)]
+mod test_identity_tiers;
+
+#[cfg(test)]
+mod test_jurisdiction;
+
+#[cfg(test)]
+mod test_escrow_listing;
+
+#[cfg(test)]
+mod test_depositor_summary;
+
+#[cfg(test)]
+mod test_scheduled_release;
+
+#[cfg(test)]
+mod test_vesting_release;
+
+#[cfg(test)]
+mod test_accounting_snapshot;
+
+#[cfg(test)]
+mod test_threshold_monitor;
+
+#[cfg(test)]
+mod test_depositor_reputation;
+
+#[cfg(test)]
+mod test_escrow_migration;
+
+#[cfg(test)]
+mod test_contract_invariants;
+
+#[cfg(test)]
+mod test_event_balances;
+
+#[cfg(test)]
+mod test_relayed_claims;
+
+#[cfg(test)]
+mod test_payment_intents;
+
+#[cfg(test)]
+mod test_signer_nonces;
+
+#[cfg(test)]
+mod test_escrow_pause_flags;
+
+#[cfg(test)]
+mod test_split_escrow;
+
+#[cfg(test)]
+mod test_merge_escrows;
+
+#[cfg(test)]
+mod test_refund_to;
+
 #[cfg(test)]
 mod test_deterministic_error_ordering;
 
+#[cfg(test)]
+mod test_auditor_role;
+
 use events::{
     emit_batch_funds_locked, emit_batch_funds_released, emit_bounty_initialized,
     emit_deprecation_state_changed, emit_deterministic_selection, emit_funds_locked,
@@ -35,12 +188,12 @@ use events::{
     ClaimCancelled, ClaimCreated, ClaimExecuted, CriticalOperationOutcome, DeprecationStateChanged,
     DeterministicSelectionDerived, FundsLocked, FundsLockedAnon, FundsRefunded, FundsReleased,
     MaintenanceModeChanged, NotificationPreferencesUpdated, ParticipantFilterModeChanged,
-    RiskFlagsUpdated, TicketClaimed, TicketIssued, EVENT_VERSION_V2,
+    RiskFlagsUpdated, TicketClaimed, TicketIssued, EVENT_VERSION_V2, EVENT_VERSION_V3,
 };
-use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::xdr::{FromXdr, ToXdr};
 use soroban_sdk::{
     contract, contracterror, contractimpl, contracttype, symbol_short, token, vec, Address, Bytes,
-    BytesN, Env, String, Symbol, Vec,
+    BytesN, Env, IntoVal, Map, String, Symbol, Vec,
 };
 
 // ============================================================================
@@ -463,10 +616,13 @@ mod anti_abuse {
 /// - No privilege escalation: operators cannot call admin-only functions.
 /// - No cross-call escalation: a participant cannot trigger admin actions indirectly.
 /// - `refund` requires both admin AND depositor signatures (dual-auth).
+/// - Auditors (granted with `set_auditor`) may only call the auditor views
+///   (`get_escrow_info_v2`, `get_anonymous_escrow_audit`); the role grants no
+///   fund-moving or configuration permission.
 pub mod rbac {
     use soroban_sdk::{Address, Env};
 
-    use crate::DataKey;
+    use crate::{ComplianceKey, DataKey};
 
     /// Returns the stored admin address, panicking if not initialized.
     pub fn require_admin(env: &Env) -> Address {
@@ -497,12 +653,26 @@ pub mod rbac {
         use crate::anti_abuse;
         anti_abuse::get_admin(env).map(|a| &a == addr).unwrap_or(false)
     }
+
+    /// Returns `true` if `addr` holds the read-only auditor role.
+    pub fn is_auditor(env: &Env, addr: &Address) -> bool {
+        env.storage()
+            .persistent()
+            .get::<ComplianceKey, bool>(&ComplianceKey::Auditor(addr.clone()))
+            .unwrap_or(false)
+    }
 }
 
 #[allow(dead_code)]
 const BASIS_POINTS: i128 = 10_000;
 const MAX_FEE_RATE: i128 = 5_000; // 50% max fee
 const MAX_BATCH_SIZE: u32 = 20;
+const MAX_AUTO_REFUND_REWARD_BPS: u32 = 500; // 5% max keeper reward
+const MAX_RELAYER_TIP_BPS: u32 = 500; // 5% max relayer tip
+/// SEP-53 prefix hashed in front of relayed claim messages before signing.
+const SIGNED_MESSAGE_PREFIX: &[u8] = b"Stellar Signed Message:\n";
+/// Seconds a mutual-cancel proposal stays open for confirmation (7 days).
+const CANCEL_PROPOSAL_WINDOW: u64 = 7 * 24 * 60 * 60;
 
 extern crate grainlify_core;
 use grainlify_core::asset;
@@ -602,11 +772,144 @@ pub enum Error {
     UpgradeSafetyCheckFailed = 43,
 }
 
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ClaimError {
+    /// The bounty was not locked with a hashlock
+    HashlockNotSet = 100,
+    /// sha256 of the supplied preimage does not match the bounty's hashlock
+    InvalidPreimage = 101,
+    /// The hashlocked bounty's deadline has passed; only a refund is possible
+    HashlockExpired = 102,
+    /// The claim ticket was issued as non-transferable
+    TicketNotTransferable = 103,
+    /// The claim ticket was revoked by the admin
+    TicketRevoked = 104,
+    /// The contributor must stake the bounty's claim bond before release
+    BondRequired = 105,
+    /// The contributor already has a claim bond staked on this bounty
+    BondAlreadyStaked = 106,
+    /// The claim bond is still within its timeout
+    BondLocked = 107,
+    /// No anonymous payout is reserved for this bounty
+    AnonymousPayoutNotFound = 108,
+    /// Resolver approvals already target a different refund recipient
+    ResolverRecipientMismatch = 109,
+    /// The signer has not registered an ed25519 claim key
+    ClaimKeyNotSet = 110,
+    /// The signed nonce is not the signer's next nonce (see `get_nonce`)
+    InvalidNonce = 111,
+    /// No payment intent exists with this id
+    PaymentIntentNotFound = 112,
+    /// The payment intent's expiry has passed
+    PaymentIntentExpired = 113,
+    /// The payment intent was already executed or cancelled
+    PaymentIntentClosed = 114,
+    /// The depositor has not registered a recovery key
+    RecoveryKeyNotSet = 115,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ReleaseError {
+    /// A release approval already exists for a different contributor
+    ApprovalContributorMismatch = 200,
+    /// No pending approval from this approver for the bounty
+    ApprovalNotFound = 201,
+    /// No release is pending for this bounty
+    PendingReleaseNotFound = 202,
+    /// The pending release has been disputed by the depositor
+    ReleaseDisputed = 203,
+    /// The challenge period has not elapsed yet
+    ChallengePeriodActive = 204,
+    /// The challenge period is over; the release can no longer be disputed
+    ChallengePeriodElapsed = 205,
+    /// The pending release has not been disputed
+    ReleaseNotDisputed = 206,
+    /// No release is scheduled for this bounty
+    ScheduledReleaseNotFound = 207,
+    /// A release is already scheduled for this bounty
+    ReleaseAlreadyScheduled = 208,
+    /// The scheduled release time has not been reached
+    ScheduledReleaseNotDue = 209,
+    /// The bounty has no vesting stream
+    VestingNotFound = 210,
+    /// Nothing has vested since the last withdrawal
+    NothingVested = 211,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ComplianceError {
+    /// The depositor exceeded its lock count or locked value for the current window
+    RateLimited = 300,
+    /// The address is on the compliance block list
+    AddressBlocked = 301,
+    /// The amount exceeds the depositor's identity tier single-lock limit
+    TierLimitExceeded = 302,
+    /// The lock would push the depositor past its identity tier exposure limit
+    ExposureLimitExceeded = 303,
+    /// The escrow's jurisdiction has paused this operation
+    JurisdictionPaused = 304,
+    /// The escrow's jurisdiction requires a KYC-verified depositor
+    JurisdictionKycRequired = 305,
+    /// The amount exceeds the jurisdiction's max lock amount
+    JurisdictionLimitExceeded = 306,
+    /// Jurisdiction tag is empty or longer than `MAX_JURISDICTION_TAG_LEN`
+    InvalidJurisdictionTag = 307,
+    /// Jurisdiction tag was not registered with `register_jurisdiction_tag`
+    JurisdictionTagNotRegistered = 308,
+    /// The registered screening contract denied the address
+    ScreeningRejected = 309,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum LifecycleError {
+    /// No mutual-cancel proposal exists for the bounty
+    CancelProposalNotFound = 400,
+    /// The mutual-cancel proposal's confirmation window has passed
+    CancelProposalExpired = 401,
+    /// The token is not on the allowlist for per-bounty token selection
+    TokenNotAllowed = 402,
+    /// A bounty group with this id already exists
+    GroupAlreadyExists = 403,
+    /// No bounty group with this id exists
+    GroupNotFound = 404,
+    /// The bounty already belongs to a group
+    BountyAlreadyGrouped = 405,
+    /// Payouts for the bounty's group are paused
+    GroupPaused = 406,
+    /// The bounty is not part of the given group
+    BountyNotInGroup = 407,
+    /// The depositor has not enabled auto-refund for this bounty
+    AutoRefundNotEnabled = 408,
+    /// No accounting checkpoint exists with this sequence
+    AccountingCheckpointNotFound = 409,
+    /// Upgrades require lock, release and refund to be paused
+    UpgradeRequiresPause = 410,
+    /// Escrow pause flags contain bits other than lock, release and refund
+    InvalidPauseFlags = 411,
+    /// Escrows differ in depositor, token or jurisdiction config
+    IncompatibleEscrows = 412,
+}
+
 pub const RISK_FLAG_HIGH_RISK: u32 = 1 << 0;
 pub const RISK_FLAG_UNDER_REVIEW: u32 = 1 << 1;
 pub const RISK_FLAG_RESTRICTED: u32 = 1 << 2;
 pub const RISK_FLAG_DEPRECATED: u32 = 1 << 3;
 
+pub const ESCROW_PAUSE_LOCK: u32 = 1 << 0;
+pub const ESCROW_PAUSE_RELEASE: u32 = 1 << 1;
+pub const ESCROW_PAUSE_REFUND: u32 = 1 << 2;
+const ESCROW_PAUSE_ALL: u32 = ESCROW_PAUSE_LOCK | ESCROW_PAUSE_RELEASE | ESCROW_PAUSE_REFUND;
+
+pub const MAX_JURISDICTION_TAG_LEN: u32 = 32;
+
 /// Notification preference flags (bitfield).
 pub const NOTIFY_ON_LOCK: u32 = 1 << 0;
 pub const NOTIFY_ON_RELEASE: u32 = 1 << 1;
@@ -631,6 +934,8 @@ pub enum EscrowStatus {
     Released,
     Refunded,
     PartiallyRefunded,
+    /// Release requested but held for the bounty's challenge period.
+    PendingRelease,
 }
 
 #[contracttype]
@@ -647,6 +952,22 @@ pub struct Escrow {
     pub refund_history: Vec<RefundRecord>,
 }
 
+/// Escrow layout written before `refund_history` was added. Read only by
+/// the lazy migration, which rewrites it as an [`Escrow`].
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyEscrowV1 {
+    pub depositor: Address,
+    pub amount: i128,
+    pub remaining_amount: i128,
+    pub status: EscrowStatus,
+    pub deadline: u64,
+}
+
+/// Layout version of escrow records written by this code. Records without an
+/// `EscrowSchema` entry predate it and are migrated on first touch.
+pub const ESCROW_SCHEMA_VERSION: u32 = 2;
+
 /// Mutually exclusive participant filtering mode for lock_funds / batch_lock_funds.
 ///
 /// * **Disabled**: No list check; any address may participate (allowlist still used only for anti-abuse bypass).
@@ -697,6 +1018,48 @@ pub enum AnonymousParty {
     Commitment(BytesN<32>),
 }
 
+/// Payout reserved for a recipient known only by a 32-byte commitment,
+/// `sha256(preimage)`. The recipient claims it with `claim_anonymous`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AnonymousPayout {
+    pub recipient: AnonymousParty,
+    pub amount: i128,
+    pub reserved_at: u64,
+}
+
+/// Resolver approvals collected for refunding an anonymous escrow.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResolverApproval {
+    pub recipient: Address,
+    pub approvals: Vec<Address>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum OptionalAnonymousPayout {
+    None,
+    Some(AnonymousPayout),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum OptionalResolverApproval {
+    None,
+    Some(ResolverApproval),
+}
+
+/// Everything stored for an anonymous escrow, as exposed to auditors.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AnonymousEscrowAudit {
+    pub escrow: AnonymousEscrow,
+    pub payout: OptionalAnonymousPayout,
+    pub resolver_approval: OptionalResolverApproval,
+    pub authorized_viewers: Vec<Address>,
+}
+
 /// Unified escrow view: exposes either address or commitment for depositor.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -709,6 +1072,16 @@ pub struct EscrowInfo {
     pub refund_history: Vec<RefundRecord>,
 }
 
+/// Public escrow view without identifying data. `amount_bucket` is the
+/// order of magnitude of the locked amount (`floor(log10(amount))`).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RedactedEscrowInfo {
+    pub amount_bucket: u32,
+    pub status: EscrowStatus,
+    pub deadline: u64,
+}
+
 #[contracttype]
 pub enum DataKey {
     Admin,
@@ -754,6 +1127,94 @@ pub enum DataKey {
     NetworkId,
 
     MaintenanceMode, // bool flag
+
+    /// grainlify-core contract whose global halt this escrow obeys
+    CoreContract,
+
+    ReleaseRecords(u64), // bounty_id -> Vec<ReleaseRecord> (split/partial releases)
+    AssignedContributor(u64), // bounty_id -> Address confirming mutual cancels
+    CancelProposal(u64), // bounty_id -> CancelProposal
+    AllowedToken(Address), // token -> bool, tokens accepted by lock_funds_with_token
+    BountyToken(u64),    // bounty_id -> Address, set only when locked in a non-default token
+    BountyGroup(u64),    // group_id -> BountyGroup
+    BountyGroupOf(u64),  // bounty_id -> group_id
+    AnonymousResolvers,  // Vec<Address> resolver set for anonymous refunds
+    ResolverQuorum,      // u32 distinct resolver approvals required
+    ResolverApprovals(u64), // bounty_id -> ResolverApproval
+    AuthorizedViewers(u64), // bounty_id -> Vec<Address> allowed to read full info
+    AutoRefund(u64),     // bounty_id -> u32 keeper reward in basis points
+    EscrowSchema(u64),   // bounty_id -> layout version of its Escrow record
+}
+
+/// Claim ticket, hashlock, claim bond and anonymous payout storage keys.
+#[contracttype]
+pub enum TicketKey {
+    Hashlock(u64),           // bounty_id -> BytesN<32> sha256 hashlock for HTLC release
+    BountyTickets(u64),      // bounty_id -> Vec<u64> tickets still holding a reservation
+    TicketReserved(u64),     // bounty_id -> i128 total reserved by those tickets
+    TicketClaims(u64),       // ticket_id -> Vec<ReleaseRecord> partial claims
+    ClaimBondConfig(u64),    // bounty_id -> ClaimBondConfig
+    ClaimBond(u64, Address), // (bounty_id, contributor) -> ClaimBond
+    AnonymousPayout(u64),    // bounty_id -> AnonymousPayout
+    TotalBonds,              // i128 outstanding claim bonds held in the default token
+}
+
+/// Storage keys for off-chain signatures, relayed claims and payment intents.
+#[contracttype]
+pub enum SignatureKey {
+    ClaimKey(Address),    // address -> BytesN<32> ed25519 key for off-chain signatures
+    RelayerTipBps,        // u32 share of a relayed claim paid to the relayer
+    PaymentIntentCounter, // monotonic payment intent id
+    PaymentIntent(u64),   // intent_id -> PaymentIntent
+    SignerNonce(Address), // address -> u64 next nonce for signature-authorized calls
+    RecoveryKey(Address), // depositor -> BytesN<32> ed25519 key authorizing refund_to
+}
+
+/// Release approval, dispute, work submission, scheduling and vesting storage keys.
+#[contracttype]
+pub enum ReleaseKey {
+    ApproverWeight(Address), // approver -> u32 weight (default 1, 0 disables)
+    ApprovalWeightThreshold, // u32 weight that auto-executes an approved release
+    ApprovalTimes(u64),      // bounty_id -> Map<Address, u64> when each approval was given
+    ApprovalTtl,             // u64 seconds an approval stays valid (0 = never expires)
+    ChallengePeriod(u64),    // bounty_id -> u64 seconds a release waits before finalizing
+    PendingRelease(u64),     // bounty_id -> PendingRelease
+    Arbiters,                // Vec<Address> allowed to rule on disputed releases
+    DisputeRecord(u64),      // bounty_id -> DisputeRecord
+    ArbiterRulings(Address), // arbiter -> Vec<u64> bounty_ids ruled on
+    WorkSubmissions(u64),    // bounty_id -> Vec<WorkSubmission> in submission order
+    PaidWork(u64),           // bounty_id -> WorkSubmission referenced by the release
+    ScheduledRelease(u64),   // bounty_id -> ScheduledRelease
+    Vesting(u64),            // bounty_id -> VestingStream
+}
+
+/// Rate limit, block list, identity tier, jurisdiction, auditor and screening storage keys.
+#[contracttype]
+pub enum ComplianceKey {
+    LockRateLimit,              // LockRateLimit per-depositor lock caps
+    LockWindow(Address),        // depositor -> LockWindow usage in the current window
+    ComplianceAdmin,            // Address allowed to manage the compliance block list
+    BlockedAddress(Address),    // address -> bool, blocked by compliance
+    IdentityTier(Address),      // address -> IdentityTier assigned by compliance
+    TierLimit(IdentityTier),    // tier -> TierLimit
+    EscrowJurisdiction(u64),    // bounty_id -> EscrowJurisdictionConfig
+    JurisdictionTags,           // Vec<String> tags accepted in EscrowJurisdictionConfig
+    Auditor(Address),           // address -> bool, read-only auditor role
+    ScreeningContract,          // Address cross-called with (address, amount) before lock/payout
+    ScreeningOverride(Address), // address -> bool, screening denial bypassed by compliance
+}
+
+/// Status index, depositor summary, accounting and reputation storage keys.
+#[contracttype]
+pub enum AccountingKey {
+    StatusIndex(EscrowStatus), // Vec<u64> of bounty_ids currently in that status
+    IndexedStatus(u64),        // bounty_id -> status it is filed under in StatusIndex
+    DepositorSummary(Address), // depositor -> DepositorSummary
+    SummaryShare(u64),         // bounty_id -> EscrowSummaryShare last counted in DepositorSummary
+    AccountingTotals,          // AccountingTotals kept current on every escrow write
+    AccountingCheckpointSeq,   // u64 sequence of the latest accounting checkpoint
+    AccountingCheckpoint(u64), // sequence -> AccountingCheckpoint
+    ReputationCounters(Address), // depositor -> ReputationCounters
 }
 
 #[contracttype]
@@ -763,6 +1224,14 @@ pub struct EscrowWithId {
     pub escrow: Escrow,
 }
 
+/// Enumeration entry covering both regular and anonymous escrows.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowInfoWithId {
+    pub bounty_id: u64,
+    pub info: EscrowInfo,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PauseFlags {
@@ -784,6 +1253,120 @@ pub struct AggregateStats {
     pub count_refunded: u32,
 }
 
+/// Contract-level invariant report for auditors and monitors.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowInvariantReport {
+    pub healthy: bool,
+    /// Remaining amount across active escrows in the default token.
+    pub tracked_balance: i128,
+    /// Default token balance held by the contract.
+    pub token_balance: i128,
+    /// `tracked_balance == token_balance`.
+    pub funds_conserved: bool,
+    pub escrows_checked: u32,
+    /// Released or refunded escrows that still report a remaining amount.
+    pub settled_with_remaining: Vec<u64>,
+    pub tickets_checked: u32,
+    /// Claim tickets whose bounty has no escrow record.
+    pub orphaned_tickets: Vec<u64>,
+    pub violation_count: u32,
+}
+
+/// Running totals for one depositor. `total_locked` is the balance currently
+/// held across active escrows; released and refunded amounts are cumulative.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, Default)]
+pub struct DepositorSummary {
+    pub active_escrows: u32,
+    pub total_locked: i128,
+    pub total_released: i128,
+    pub total_refunded: i128,
+}
+
+/// Raw per-depositor history behind `get_depositor_reputation`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, Default)]
+pub struct ReputationCounters {
+    pub escrows: u32,
+    pub payouts: u32,
+    pub on_time_payouts: u32,
+    pub refunds: u32,
+    pub disputes: u32,
+}
+
+/// Depositor track record for contributors choosing a bounty. A payout is
+/// on time when its first release lands at or before the escrow deadline.
+/// Rates are in basis points; a depositor with no history scores 10_000.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DepositorReputation {
+    pub total_escrows: u32,
+    pub total_payouts: u32,
+    pub on_time_payouts: u32,
+    pub refund_count: u32,
+    pub dispute_count: u32,
+    pub on_time_payment_rate_bps: u32,
+    pub dispute_rate_bps: u32,
+    pub refund_rate_bps: u32,
+    /// 50% on-time rate, 25% non-dispute rate, 25% non-refund rate.
+    pub overall_score_bps: u32,
+}
+
+/// Contract-wide running totals. `locked` is the balance currently held in
+/// active escrows; released, refunded and fees are cumulative. `released` is
+/// gross of release fees, which also appear in `fees_collected`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, Default)]
+pub struct AccountingTotals {
+    pub active_escrows: u32,
+    pub locked: i128,
+    pub released: i128,
+    pub refunded: i128,
+    pub fees_collected: i128,
+}
+
+/// Change in each `AccountingTotals` field over a period.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccountingDeltas {
+    pub active_escrows: i64,
+    pub locked: i128,
+    pub released: i128,
+    pub refunded: i128,
+    pub fees_collected: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccountingCheckpoint {
+    pub sequence: u64,
+    pub timestamp: u64,
+    pub ledger: u32,
+    pub totals: AccountingTotals,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccountingSnapshot {
+    pub totals: AccountingTotals,
+    pub deltas: AccountingDeltas,
+    pub since_checkpoint: u64,
+    pub since_timestamp: u64,
+    pub timestamp: u64,
+}
+
+/// What a single escrow last contributed to the accounting totals and its
+/// depositor's summary.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, Default)]
+pub struct EscrowSummaryShare {
+    pub active: bool,
+    pub locked: i128,
+    pub released: i128,
+    pub refunded: i128,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PauseStateChanged {
@@ -794,6 +1377,66 @@ pub struct PauseStateChanged {
     pub timestamp: u64,
 }
 
+/// Per-escrow regulatory controls, matching the soroban `EscrowContract`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowJurisdictionConfig {
+    pub tag: Option<String>,
+    /// Depositor must hold at least the `Basic` identity tier.
+    pub requires_kyc: bool,
+    /// When false, identity tier limits are not applied to the lock.
+    pub enforce_identity_limits: bool,
+    pub lock_paused: bool,
+    pub release_paused: bool,
+    pub refund_paused: bool,
+    pub max_lock_amount: Option<i128>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum OptionalJurisdiction {
+    None,
+    Some(EscrowJurisdictionConfig),
+}
+
+/// Identity verification level assigned to an address by the compliance
+/// role. Addresses without an assignment are `Unverified`.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum IdentityTier {
+    Unverified,
+    Basic,
+    Full,
+}
+
+/// Lock limits for one identity tier. `max_exposure` caps the depositor's
+/// remaining balance across all active escrows. A zero cap is unlimited.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TierLimit {
+    pub max_single_lock: i128,
+    pub max_exposure: i128,
+}
+
+/// Per-depositor caps on lock operations within a window of
+/// `window_size` seconds. A zero cap disables that dimension.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LockRateLimit {
+    pub window_size: u64,
+    pub max_locks: u32,
+    pub max_locked_value: i128,
+}
+
+/// A depositor's lock usage in its current rate-limit window.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LockWindow {
+    pub window_start: u64,
+    pub lock_count: u32,
+    pub locked_value: i128,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 /// Public view of anti-abuse config (rate limit and cooldown).
@@ -872,6 +1515,156 @@ pub struct ClaimTicket {
     pub expires_at: u64,
     pub used: bool,
     pub issued_at: u64,
+    /// Whether the beneficiary may hand the ticket to another address via
+    /// `transfer_claim_ticket`. Fixed at issuance.
+    pub transferable: bool,
+    /// Set by `revoke_claim_ticket`; a revoked ticket can no longer be used.
+    pub revoked: bool,
+    /// Amount still claimable through `claim_with_ticket_partial`; starts at
+    /// `amount`. `used` is set once it reaches zero.
+    pub remaining_amount: i128,
+}
+
+/// A release pre-authorized off-chain by the depositor's signature.
+/// Anyone may execute it with `execute_payment_intent` before `expiry`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaymentIntent {
+    pub intent_id: u64,
+    pub bounty_id: u64,
+    pub depositor: Address,
+    pub contributor: Address,
+    pub amount: i128,
+    pub nonce: u64,
+    pub expiry: u64,
+    pub created_at: u64,
+    pub executed: bool,
+    pub cancelled: bool,
+}
+
+/// A release held for the bounty's challenge period.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingRelease {
+    pub bounty_id: u64,
+    pub contributor: Address,
+    pub requested_at: u64,
+    /// First timestamp at which `finalize_release` succeeds.
+    pub finalize_after: u64,
+    pub disputed: bool,
+    /// Hash of the depositor's off-chain dispute reason; all zeros until
+    /// `disputed` is set.
+    pub reason_hash: BytesN<32>,
+}
+
+/// A release the admin has timelocked until `release_at`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScheduledRelease {
+    pub bounty_id: u64,
+    pub contributor: Address,
+    pub release_at: u64,
+    pub scheduled_at: u64,
+}
+
+/// Linear payout of an escrow to a retained contributor. `total` vests
+/// evenly from `start` to `start + duration`; the escrow is held as
+/// `PendingRelease` until the stream is fully withdrawn or cancelled.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingStream {
+    pub bounty_id: u64,
+    pub contributor: Address,
+    pub total: i128,
+    pub withdrawn: i128,
+    pub start: u64,
+    pub duration: u64,
+}
+
+/// Outcome an arbiter can order for a disputed release.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DisputeRuling {
+    /// Pay the pending release to the contributor as requested.
+    UpholdRelease,
+    /// Return the escrowed funds to the depositor.
+    RefundDepositor,
+    /// Pay the contributor this many basis points; refund the rest.
+    Split(u32),
+}
+
+/// On-chain record of an arbiter's ruling on a disputed release.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeRecord {
+    pub bounty_id: u64,
+    pub arbiter: Address,
+    pub ruling: DisputeRuling,
+    pub contributor: Address,
+    pub contributor_amount: i128,
+    pub depositor_amount: i128,
+    pub reason_hash: BytesN<32>,
+    pub ruled_at: u64,
+}
+
+/// Hash commitment to a contributor's deliverable, recorded by `submit_work`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WorkSubmission {
+    pub contributor: Address,
+    pub work_hash: BytesN<32>,
+    pub submitted_at: u64,
+}
+
+/// Claimant bond a bounty requires before it can be released.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimBondConfig {
+    pub amount: i128,
+    /// Seconds after staking at which the contributor may withdraw the bond.
+    pub timeout: u64,
+}
+
+/// A contributor's staked claim bond.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimBond {
+    pub amount: i128,
+    pub staked_at: u64,
+}
+
+/// A set of bounties managed together, e.g. the milestones of an epic.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BountyGroup {
+    pub group_id: u64,
+    pub bounty_ids: Vec<u64>,
+    pub paused: bool,
+    pub created_at: u64,
+}
+
+/// Aggregate view over the bounties of a group.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BountyGroupSummary {
+    pub group_id: u64,
+    pub bounty_count: u32,
+    pub total_amount: i128,
+    pub total_remaining: i128,
+    pub locked_count: u32,
+    pub released_count: u32,
+    pub refunded_count: u32,
+    pub paused: bool,
+}
+
+/// One ticket to issue through `issue_claim_tickets_batch`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TicketRequest {
+    pub beneficiary: Address,
+    pub amount: i128,
+    pub expires_at: u64,
+    pub transferable: bool,
 }
 
 #[contracttype]
@@ -958,6 +1751,23 @@ pub struct ReleaseFundsItem {
     pub contributor: Address,
 }
 
+/// Outcome of a [`BountyEscrowContract::batch_refund`] call.
+///
+/// Every requested `bounty_id` appears in exactly one of `refunded` or
+/// `skipped`, in request order.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchRefundResult {
+    /// Bounties whose remaining balance was returned to the depositor.
+    pub refunded: Vec<u64>,
+    /// Bounties left untouched: missing, anonymous, not locked, deadline not
+    /// yet passed, with an unclaimed pending claim, whose depositor is on
+    /// the compliance block list, or whose jurisdiction pauses refunds.
+    pub skipped: Vec<u64>,
+    /// Sum of all refunded amounts.
+    pub total_refunded: i128,
+}
+
 /// Result of a dry-run simulation. Indicates whether the operation would succeed
 /// and the resulting state without mutating storage or performing transfers.
 #[contracttype]
@@ -970,6 +1780,27 @@ pub struct SimulationResult {
     pub remaining_amount: i128,
 }
 
+/// One payout to one recipient, recorded by split and partial releases.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleaseRecord {
+    pub recipient: Address,
+    pub amount: i128,
+    pub released_at: u64,
+}
+
+/// Depositor's request to cancel a bounty before its deadline. Refunds
+/// immediately once the assigned contributor (or the admin, if none is
+/// assigned) confirms before `expires_at`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CancelProposal {
+    pub bounty_id: u64,
+    pub proposed_by: Address,
+    pub proposed_at: u64,
+    pub expires_at: u64,
+}
+
 #[contract]
 pub struct BountyEscrowContract;
 
@@ -1031,9 +1862,9 @@ impl BountyEscrowContract {
     }
 
     /// Initialize the contract with the admin address and the token address (XLM).
-    pub fn init(env: Env, admin: Address, token: Address) -> Result<(), Error> {
+    pub fn init(env: Env, admin: Address, token: Address) -> Result<(), soroban_sdk::Error> {
         if env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::AlreadyInitialized);
+            return Err(Error::AlreadyInitialized.into());
         }
         env.storage().instance().set(&DataKey::Admin, &admin);
         env.storage().instance().set(&DataKey::Token, &token);
@@ -1057,7 +1888,7 @@ impl BountyEscrowContract {
         token: Address,
         chain_id: soroban_sdk::String,
         network_id: soroban_sdk::String,
-    ) -> Result<(), Error> {
+    ) -> Result<(), soroban_sdk::Error> {
         Self::init(env.clone(), admin, token)?;
         env.storage().instance().set(&DataKey::ChainId, &chain_id);
         env.storage()
@@ -1086,7 +1917,7 @@ impl BountyEscrowContract {
     }
 
     /// Update the persisted contract version (admin only).
-    pub fn set_version(env: Env, new_version: u32) -> Result<(), Error> {
+    pub fn set_version(env: Env, new_version: u32) -> Result<(), soroban_sdk::Error> {
         let admin: Address = env
             .storage()
             .instance()
@@ -1099,6 +1930,124 @@ impl BountyEscrowContract {
         Ok(())
     }
 
+    /// Replace the contract wasm (admin only).
+    ///
+    /// Lock, release and refund must all be paused and the upgrade safety
+    /// checks must pass. Storage is kept as is: escrow records written by
+    /// older code are migrated lazily when next touched, or in bulk with
+    /// `migrate_escrows`.
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), soroban_sdk::Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        let flags = Self::get_pause_flags(&env);
+        if !(flags.lock_paused && flags.release_paused && flags.refund_paused) {
+            return Err(LifecycleError::UpgradeRequiresPause.into());
+        }
+        upgrade_safety::validate_upgrade(&env)?;
+        upgrade_safety::record_safety_check(&env);
+
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+
+        events::emit_contract_upgraded(
+            &env,
+            events::ContractUpgraded {
+                new_wasm_hash,
+                version: Self::get_version(env.clone()),
+                admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Migrate the given escrow records to the current layout. Anyone may
+    /// call this; records that are already current or missing are skipped.
+    /// Returns the number of records migrated.
+    pub fn migrate_escrows(env: Env, bounty_ids: Vec<u64>) -> Result<u32, soroban_sdk::Error> {
+        if bounty_ids.len() > MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatchSize.into());
+        }
+        let mut migrated: u32 = 0;
+        for bounty_id in bounty_ids.iter() {
+            if Self::migrate_escrow_record(&env, bounty_id) {
+                migrated += 1;
+            }
+        }
+        Ok(migrated)
+    }
+
+    /// Layout version of the escrow record for `bounty_id`: 1 for records
+    /// not yet migrated, `None` if there is no escrow.
+    pub fn get_escrow_schema_version(env: Env, bounty_id: u64) -> Option<u32> {
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return None;
+        }
+        Some(
+            env.storage()
+                .persistent()
+                .get(&DataKey::EscrowSchema(bounty_id))
+                .unwrap_or(1),
+        )
+    }
+
+    /// Internal: upgrade the escrow record for `bounty_id` to the current
+    /// layout if it predates it, and backfill the status index and
+    /// accounting that older code did not maintain. Returns true if the
+    /// record was migrated.
+    fn migrate_escrow_record(env: &Env, bounty_id: u64) -> bool {
+        let schema_key = DataKey::EscrowSchema(bounty_id);
+        if env.storage().persistent().has(&schema_key) {
+            return false;
+        }
+        let key = DataKey::Escrow(bounty_id);
+        let raw: Map<Symbol, soroban_sdk::Val> = match env.storage().persistent().get(&key) {
+            Some(raw) => raw,
+            None => return false,
+        };
+        let escrow: Escrow = if raw.contains_key(Symbol::new(env, "refund_history")) {
+            env.storage().persistent().get(&key).unwrap()
+        } else {
+            let legacy: LegacyEscrowV1 = env.storage().persistent().get(&key).unwrap();
+            Escrow {
+                depositor: legacy.depositor,
+                amount: legacy.amount,
+                remaining_amount: legacy.remaining_amount,
+                status: legacy.status,
+                deadline: legacy.deadline,
+                refund_history: Vec::new(env),
+            }
+        };
+        env.storage().persistent().set(&key, &escrow);
+        env.storage()
+            .persistent()
+            .set(&schema_key, &ESCROW_SCHEMA_VERSION);
+        Self::index_status(env, bounty_id, &escrow.status);
+        Self::sync_escrow_accounting(env, bounty_id, &escrow);
+
+        events::emit_escrow_migrated(
+            env,
+            events::EscrowMigrated {
+                bounty_id,
+                schema_version: ESCROW_SCHEMA_VERSION,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        true
+    }
+
+    /// Internal: mark a freshly written escrow record as current.
+    fn stamp_escrow_schema(env: &Env, bounty_id: u64) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::EscrowSchema(bounty_id), &ESCROW_SCHEMA_VERSION);
+    }
+
     /// Calculate fee amount based on rate (in basis points), using **ceiling division**.
     ///
     /// Ceiling division ensures that a non-zero fee rate always produces at least
@@ -1152,9 +2101,9 @@ impl BountyEscrowContract {
         release_fee_rate: Option<i128>,
         fee_recipient: Option<Address>,
         fee_enabled: Option<bool>,
-    ) -> Result<(), Error> {
+    ) -> Result<(), soroban_sdk::Error> {
         if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
+            return Err(Error::NotInitialized.into());
         }
 
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
@@ -1164,14 +2113,14 @@ impl BountyEscrowContract {
 
         if let Some(rate) = lock_fee_rate {
             if !(0..=MAX_FEE_RATE).contains(&rate) {
-                return Err(Error::InvalidFeeRate);
+                return Err(Error::InvalidFeeRate.into());
             }
             fee_config.lock_fee_rate = rate;
         }
 
         if let Some(rate) = release_fee_rate {
             if !(0..=MAX_FEE_RATE).contains(&rate) {
-                return Err(Error::InvalidFeeRate);
+                return Err(Error::InvalidFeeRate.into());
             }
             fee_config.release_fee_rate = rate;
         }
@@ -1219,9 +2168,9 @@ impl BountyEscrowContract {
         release: Option<bool>,
         refund: Option<bool>,
         reason: Option<soroban_sdk::String>,
-    ) -> Result<(), Error> {
+    ) -> Result<(), soroban_sdk::Error> {
         if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
+            return Err(Error::NotInitialized.into());
         }
 
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
@@ -1302,7 +2251,7 @@ impl BountyEscrowContract {
     /// # Errors
     /// Returns `Error::NotPaused` if `lock_paused` is false.
     /// Returns `Error::Unauthorized` if the caller is not the admin.
-    pub fn emergency_withdraw(env: Env, target: Address) -> Result<(), Error> {
+    pub fn emergency_withdraw(env: Env, target: Address) -> Result<(), soroban_sdk::Error> {
         let admin: Address = env
             .storage()
             .instance()
@@ -1312,7 +2261,7 @@ impl BountyEscrowContract {
 
         let flags = Self::get_pause_flags(&env);
         if !flags.lock_paused {
-            return Err(Error::NotPaused);
+            return Err(Error::NotPaused.into());
         }
 
         let token_address: Address = env.storage().instance().get(&DataKey::Token).unwrap();
@@ -1326,9 +2275,12 @@ impl BountyEscrowContract {
             events::emit_emergency_withdraw(
                 &env,
                 events::EmergencyWithdrawEvent {
+                    version: EVENT_VERSION_V3,
                     admin,
                     recipient: target,
                     amount: balance,
+                    remaining_before: balance,
+                    remaining_after: token_client.balance(&contract_address),
                     timestamp: env.ledger().timestamp(),
                 },
             );
@@ -1342,6 +2294,7 @@ impl BountyEscrowContract {
             .unwrap_or(Vec::new(&env));
         let mut seen_depositors: Vec<Address> = Vec::new(&env);
         for bounty_id in index.iter() {
+            Self::migrate_escrow_record(&env, bounty_id);
             if let Some(escrow) = env
                 .storage()
                 .persistent()
@@ -1371,9 +2324,54 @@ impl BountyEscrowContract {
             env.storage()
                 .persistent()
                 .remove(&DataKey::ReleaseApproval(bounty_id));
+            env.storage()
+                .persistent()
+                .remove(&ReleaseKey::ApprovalTimes(bounty_id));
             env.storage()
                 .persistent()
                 .remove(&DataKey::PendingClaim(bounty_id));
+            env.storage()
+                .persistent()
+                .remove(&ReleaseKey::ChallengePeriod(bounty_id));
+            env.storage()
+                .persistent()
+                .remove(&ReleaseKey::PendingRelease(bounty_id));
+            env.storage()
+                .persistent()
+                .remove(&ReleaseKey::DisputeRecord(bounty_id));
+            env.storage()
+                .persistent()
+                .remove(&ReleaseKey::WorkSubmissions(bounty_id));
+            env.storage()
+                .persistent()
+                .remove(&ReleaseKey::PaidWork(bounty_id));
+            env.storage()
+                .persistent()
+                .remove(&TicketKey::AnonymousPayout(bounty_id));
+            env.storage()
+                .persistent()
+                .remove(&DataKey::AuthorizedViewers(bounty_id));
+            env.storage()
+                .persistent()
+                .remove(&DataKey::AutoRefund(bounty_id));
+            env.storage()
+                .persistent()
+                .remove(&ComplianceKey::EscrowJurisdiction(bounty_id));
+            env.storage()
+                .persistent()
+                .remove(&AccountingKey::IndexedStatus(bounty_id));
+            env.storage()
+                .persistent()
+                .remove(&AccountingKey::SummaryShare(bounty_id));
+            env.storage()
+                .persistent()
+                .remove(&ReleaseKey::ScheduledRelease(bounty_id));
+            env.storage()
+                .persistent()
+                .remove(&ReleaseKey::Vesting(bounty_id));
+            env.storage()
+                .persistent()
+                .remove(&DataKey::EscrowSchema(bounty_id));
             env.storage()
                 .persistent()
                 .remove(&DataKey::NonTransferableRewards(bounty_id));
@@ -1383,6 +2381,29 @@ impl BountyEscrowContract {
             env.storage()
                 .persistent()
                 .remove(&DataKey::DepositorIndex(depositor.clone()));
+            env.storage()
+                .persistent()
+                .remove(&AccountingKey::DepositorSummary(depositor.clone()));
+        }
+
+        // Cumulative accounting survives the reset; nothing is held any more.
+        let mut totals = Self::accounting_totals(&env);
+        totals.active_escrows = 0;
+        totals.locked = 0;
+        env.storage()
+            .persistent()
+            .set(&AccountingKey::AccountingTotals, &totals);
+
+        for status in [
+            EscrowStatus::Locked,
+            EscrowStatus::Released,
+            EscrowStatus::Refunded,
+            EscrowStatus::PartiallyRefunded,
+            EscrowStatus::PendingRelease,
+        ] {
+            env.storage()
+                .persistent()
+                .remove(&AccountingKey::StatusIndex(status));
         }
 
         env.storage()
@@ -1412,19 +2433,19 @@ impl BountyEscrowContract {
 
     /// Enforces participant filtering: returns Err if the address is not allowed to participate
     /// (lock_funds / batch_lock_funds) under the current filter mode.
-    fn check_participant_filter(env: &Env, address: Address) -> Result<(), Error> {
+    fn check_participant_filter(env: &Env, address: Address) -> Result<(), soroban_sdk::Error> {
         let mode = Self::get_participant_filter_mode(env);
         match mode {
             ParticipantFilterMode::Disabled => Ok(()),
             ParticipantFilterMode::BlocklistOnly => {
                 if anti_abuse::is_blocklisted(env, address) {
-                    return Err(Error::ParticipantBlocked);
+                    return Err(Error::ParticipantBlocked.into());
                 }
                 Ok(())
             }
             ParticipantFilterMode::AllowlistOnly => {
                 if !anti_abuse::is_whitelisted(env, address) {
-                    return Err(Error::ParticipantNotAllowed);
+                    return Err(Error::ParticipantNotAllowed.into());
                 }
                 Ok(())
             }
@@ -1438,9 +2459,9 @@ impl BountyEscrowContract {
         env: Env,
         deprecated: bool,
         migration_target: Option<Address>,
-    ) -> Result<(), Error> {
+    ) -> Result<(), soroban_sdk::Error> {
         if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
+            return Err(Error::NotInitialized.into());
         }
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         admin.require_auth();
@@ -1489,6 +2510,9 @@ impl BountyEscrowContract {
 
     /// Check if an operation is paused
     fn check_paused(env: &Env, operation: Symbol) -> bool {
+        if Self::is_globally_halted(env.clone()) {
+            return true;
+        }
         let flags = Self::get_pause_flags(env);
         if operation == symbol_short!("lock") {
             if Self::is_maintenance_mode(env.clone()) {
@@ -1511,10 +2535,47 @@ impl BountyEscrowContract {
             .unwrap_or(false)
     }
 
+    /// Link this escrow to the grainlify-core contract whose global halt it
+    /// obeys, or pass `None` to unlink it (admin only).
+    pub fn set_core_contract(env: Env, core: Option<Address>) -> Result<(), soroban_sdk::Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized.into());
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        match core {
+            Some(core) => env.storage().instance().set(&DataKey::CoreContract, &core),
+            None => env.storage().instance().remove(&DataKey::CoreContract),
+        }
+        Ok(())
+    }
+
+    pub fn get_core_contract(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::CoreContract)
+    }
+
+    /// True when the linked core contract reports a global halt. Fails closed:
+    /// an unreachable core counts as halted until the admin unlinks it.
+    pub fn is_globally_halted(env: Env) -> bool {
+        let core: Address = match env.storage().instance().get(&DataKey::CoreContract) {
+            Some(core) => core,
+            None => return false,
+        };
+        match env.try_invoke_contract::<bool, soroban_sdk::Error>(
+            &core,
+            &Symbol::new(&env, "is_globally_halted"),
+            Vec::new(&env),
+        ) {
+            Ok(Ok(halted)) => halted,
+            _ => true,
+        }
+    }
+
     /// Update maintenance mode (admin only)
-    pub fn set_maintenance_mode(env: Env, enabled: bool) -> Result<(), Error> {
+    pub fn set_maintenance_mode(env: Env, enabled: bool) -> Result<(), soroban_sdk::Error> {
         if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
+            return Err(Error::NotInitialized.into());
         }
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         admin.require_auth();
@@ -1534,9 +2595,13 @@ impl BountyEscrowContract {
         Ok(())
     }
 
-    pub fn set_whitelist(env: Env, address: Address, whitelisted: bool) -> Result<(), Error> {
+    pub fn set_whitelist(
+        env: Env,
+        address: Address,
+        whitelisted: bool,
+    ) -> Result<(), soroban_sdk::Error> {
         if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
+            return Err(Error::NotInitialized.into());
         }
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         admin.require_auth();
@@ -1568,11 +2633,11 @@ impl BountyEscrowContract {
         // Backward-compatible no-op until receipt storage/events are fully wired.
     }
 
-    fn load_capability(env: &Env, capability_id: u64) -> Result<Capability, Error> {
+    fn load_capability(env: &Env, capability_id: u64) -> Result<Capability, soroban_sdk::Error> {
         env.storage()
             .persistent()
             .get(&DataKey::Capability(capability_id))
-            .ok_or(Error::CapabilityNotFound)
+            .ok_or(Error::CapabilityNotFound.into())
     }
 
     fn validate_capability_scope_at_issue(
@@ -1581,9 +2646,9 @@ impl BountyEscrowContract {
         action: &CapabilityAction,
         bounty_id: u64,
         amount_limit: i128,
-    ) -> Result<(), Error> {
+    ) -> Result<(), soroban_sdk::Error> {
         if amount_limit <= 0 {
-            return Err(Error::InvalidAmount);
+            return Err(Error::InvalidAmount.into());
         }
 
         match action {
@@ -1594,16 +2659,16 @@ impl BountyEscrowContract {
                     .get(&DataKey::PendingClaim(bounty_id))
                     .ok_or(Error::BountyNotFound)?;
                 if claim.claimed {
-                    return Err(Error::FundsNotLocked);
+                    return Err(Error::FundsNotLocked.into());
                 }
                 if env.ledger().timestamp() > claim.expires_at {
-                    return Err(Error::DeadlineNotPassed);
+                    return Err(Error::DeadlineNotPassed.into());
                 }
                 if claim.recipient != owner.clone() {
-                    return Err(Error::Unauthorized);
+                    return Err(Error::Unauthorized.into());
                 }
                 if amount_limit > claim.amount {
-                    return Err(Error::CapabilityExceedsAuthority);
+                    return Err(Error::CapabilityExceedsAuthority.into());
                 }
             }
             CapabilityAction::Release => {
@@ -1613,7 +2678,7 @@ impl BountyEscrowContract {
                     .get(&DataKey::Admin)
                     .ok_or(Error::NotInitialized)?;
                 if admin != owner.clone() {
-                    return Err(Error::Unauthorized);
+                    return Err(Error::Unauthorized.into());
                 }
                 let escrow: Escrow = env
                     .storage()
@@ -1621,10 +2686,10 @@ impl BountyEscrowContract {
                     .get(&DataKey::Escrow(bounty_id))
                     .ok_or(Error::BountyNotFound)?;
                 if escrow.status != EscrowStatus::Locked {
-                    return Err(Error::FundsNotLocked);
+                    return Err(Error::FundsNotLocked.into());
                 }
                 if amount_limit > escrow.remaining_amount {
-                    return Err(Error::CapabilityExceedsAuthority);
+                    return Err(Error::CapabilityExceedsAuthority.into());
                 }
             }
             CapabilityAction::Refund => {
@@ -1634,7 +2699,7 @@ impl BountyEscrowContract {
                     .get(&DataKey::Admin)
                     .ok_or(Error::NotInitialized)?;
                 if admin != owner.clone() {
-                    return Err(Error::Unauthorized);
+                    return Err(Error::Unauthorized.into());
                 }
                 let escrow: Escrow = env
                     .storage()
@@ -1644,10 +2709,10 @@ impl BountyEscrowContract {
                 if escrow.status != EscrowStatus::Locked
                     && escrow.status != EscrowStatus::PartiallyRefunded
                 {
-                    return Err(Error::FundsNotLocked);
+                    return Err(Error::FundsNotLocked.into());
                 }
                 if amount_limit > escrow.remaining_amount {
-                    return Err(Error::CapabilityExceedsAuthority);
+                    return Err(Error::CapabilityExceedsAuthority.into());
                 }
             }
         }
@@ -1659,9 +2724,9 @@ impl BountyEscrowContract {
         env: &Env,
         capability: &Capability,
         requested_amount: i128,
-    ) -> Result<(), Error> {
+    ) -> Result<(), soroban_sdk::Error> {
         if requested_amount <= 0 {
-            return Err(Error::InvalidAmount);
+            return Err(Error::InvalidAmount.into());
         }
 
         match capability.action {
@@ -1672,16 +2737,16 @@ impl BountyEscrowContract {
                     .get(&DataKey::PendingClaim(capability.bounty_id))
                     .ok_or(Error::BountyNotFound)?;
                 if claim.claimed {
-                    return Err(Error::FundsNotLocked);
+                    return Err(Error::FundsNotLocked.into());
                 }
                 if env.ledger().timestamp() > claim.expires_at {
-                    return Err(Error::DeadlineNotPassed);
+                    return Err(Error::DeadlineNotPassed.into());
                 }
                 if claim.recipient != capability.owner {
-                    return Err(Error::Unauthorized);
+                    return Err(Error::Unauthorized.into());
                 }
                 if requested_amount > claim.amount {
-                    return Err(Error::CapabilityExceedsAuthority);
+                    return Err(Error::CapabilityExceedsAuthority.into());
                 }
             }
             CapabilityAction::Release => {
@@ -1691,7 +2756,7 @@ impl BountyEscrowContract {
                     .get(&DataKey::Admin)
                     .ok_or(Error::NotInitialized)?;
                 if admin != capability.owner {
-                    return Err(Error::Unauthorized);
+                    return Err(Error::Unauthorized.into());
                 }
                 let escrow: Escrow = env
                     .storage()
@@ -1699,10 +2764,10 @@ impl BountyEscrowContract {
                     .get(&DataKey::Escrow(capability.bounty_id))
                     .ok_or(Error::BountyNotFound)?;
                 if escrow.status != EscrowStatus::Locked {
-                    return Err(Error::FundsNotLocked);
+                    return Err(Error::FundsNotLocked.into());
                 }
                 if requested_amount > escrow.remaining_amount {
-                    return Err(Error::CapabilityExceedsAuthority);
+                    return Err(Error::CapabilityExceedsAuthority.into());
                 }
             }
             CapabilityAction::Refund => {
@@ -1712,7 +2777,7 @@ impl BountyEscrowContract {
                     .get(&DataKey::Admin)
                     .ok_or(Error::NotInitialized)?;
                 if admin != capability.owner {
-                    return Err(Error::Unauthorized);
+                    return Err(Error::Unauthorized.into());
                 }
                 let escrow: Escrow = env
                     .storage()
@@ -1722,10 +2787,10 @@ impl BountyEscrowContract {
                 if escrow.status != EscrowStatus::Locked
                     && escrow.status != EscrowStatus::PartiallyRefunded
                 {
-                    return Err(Error::FundsNotLocked);
+                    return Err(Error::FundsNotLocked.into());
                 }
                 if requested_amount > escrow.remaining_amount {
-                    return Err(Error::CapabilityExceedsAuthority);
+                    return Err(Error::CapabilityExceedsAuthority.into());
                 }
             }
         }
@@ -1739,29 +2804,29 @@ impl BountyEscrowContract {
         expected_action: CapabilityAction,
         bounty_id: u64,
         amount: i128,
-    ) -> Result<Capability, Error> {
+    ) -> Result<Capability, soroban_sdk::Error> {
         let mut capability = Self::load_capability(env, capability_id)?;
 
         if capability.revoked {
-            return Err(Error::CapabilityRevoked);
+            return Err(Error::CapabilityRevoked.into());
         }
         if capability.action != expected_action {
-            return Err(Error::CapabilityActionMismatch);
+            return Err(Error::CapabilityActionMismatch.into());
         }
         if capability.bounty_id != bounty_id {
-            return Err(Error::CapabilityActionMismatch);
+            return Err(Error::CapabilityActionMismatch.into());
         }
         if capability.holder != holder.clone() {
-            return Err(Error::Unauthorized);
+            return Err(Error::Unauthorized.into());
         }
         if env.ledger().timestamp() > capability.expiry {
-            return Err(Error::CapabilityExpired);
+            return Err(Error::CapabilityExpired.into());
         }
         if capability.remaining_uses == 0 {
-            return Err(Error::CapabilityUsesExhausted);
+            return Err(Error::CapabilityUsesExhausted.into());
         }
         if amount > capability.remaining_amount {
-            return Err(Error::CapabilityAmountExceeded);
+            return Err(Error::CapabilityAmountExceeded.into());
         }
 
         holder.require_auth();
@@ -1799,17 +2864,17 @@ impl BountyEscrowContract {
         amount_limit: i128,
         expiry: u64,
         max_uses: u32,
-    ) -> Result<u64, Error> {
+    ) -> Result<u64, soroban_sdk::Error> {
         if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
+            return Err(Error::NotInitialized.into());
         }
         if max_uses == 0 {
-            return Err(Error::InvalidAmount);
+            return Err(Error::InvalidAmount.into());
         }
 
         let now = env.ledger().timestamp();
         if expiry <= now {
-            return Err(Error::InvalidDeadline);
+            return Err(Error::InvalidDeadline.into());
         }
 
         owner.require_auth();
@@ -1850,10 +2915,14 @@ impl BountyEscrowContract {
         Ok(capability_id)
     }
 
-    pub fn revoke_capability(env: Env, owner: Address, capability_id: u64) -> Result<(), Error> {
+    pub fn revoke_capability(
+        env: Env,
+        owner: Address,
+        capability_id: u64,
+    ) -> Result<(), soroban_sdk::Error> {
         let mut capability = Self::load_capability(&env, capability_id)?;
         if capability.owner != owner {
-            return Err(Error::Unauthorized);
+            return Err(Error::Unauthorized.into());
         }
         owner.require_auth();
 
@@ -1878,7 +2947,7 @@ impl BountyEscrowContract {
         Ok(())
     }
 
-    pub fn get_capability(env: Env, capability_id: u64) -> Result<Capability, Error> {
+    pub fn get_capability(env: Env, capability_id: u64) -> Result<Capability, soroban_sdk::Error> {
         Self::load_capability(&env, capability_id)
     }
 
@@ -1910,18 +2979,18 @@ impl BountyEscrowContract {
         release_fee_rate: i128,
         fee_recipient: Address,
         fee_enabled: bool,
-    ) -> Result<(), Error> {
+    ) -> Result<(), soroban_sdk::Error> {
         if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
+            return Err(Error::NotInitialized.into());
         }
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         admin.require_auth();
 
         if !(0..=MAX_FEE_RATE).contains(&lock_fee_rate) {
-            return Err(Error::InvalidFeeRate);
+            return Err(Error::InvalidFeeRate.into());
         }
         if !(0..=MAX_FEE_RATE).contains(&release_fee_rate) {
-            return Err(Error::InvalidFeeRate);
+            return Err(Error::InvalidFeeRate.into());
         }
 
         let config = TokenFeeConfig {
@@ -1948,15 +3017,66 @@ impl BountyEscrowContract {
             .get(&DataKey::TokenFeeConfig(token))
     }
 
+    /// Allow or disallow `token` for `lock_funds_with_token` (admin only).
+    /// The default token passed to `init` is always accepted. Disallowing a
+    /// token does not affect bounties already locked in it.
+    pub fn set_token_allowed(
+        env: Env,
+        token: Address,
+        allowed: bool,
+    ) -> Result<(), soroban_sdk::Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized.into());
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if allowed {
+            env.storage()
+                .instance()
+                .set(&DataKey::AllowedToken(token), &true);
+        } else {
+            env.storage()
+                .instance()
+                .remove(&DataKey::AllowedToken(token));
+        }
+        Ok(())
+    }
+
+    /// Whether `token` is on the per-bounty token allowlist.
+    pub fn is_token_allowed(env: Env, token: Address) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::AllowedToken(token))
+            .unwrap_or(false)
+    }
+
+    /// Internal: token a bounty was locked in, falling back to the default
+    /// token for bounties locked through `lock_funds`.
+    fn bounty_token(env: &Env, bounty_id: u64) -> Address {
+        env.storage()
+            .persistent()
+            .get(&DataKey::BountyToken(bounty_id))
+            .unwrap_or_else(|| env.storage().instance().get(&DataKey::Token).unwrap())
+    }
+
     /// Internal: resolve the effective fee config for the escrow token.
     ///
     /// Precedence: `TokenFeeConfig(token)` > global `FeeConfig`.
     fn resolve_fee_config(env: &Env) -> (i128, i128, Address, bool) {
         let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        Self::resolve_fee_config_for_token(env, &token_addr)
+    }
+
+    /// Same as `resolve_fee_config`, for a bounty locked in `token_addr`.
+    fn resolve_fee_config_for_token(
+        env: &Env,
+        token_addr: &Address,
+    ) -> (i128, i128, Address, bool) {
         if let Some(tok_cfg) = env
             .storage()
             .instance()
-            .get::<DataKey, TokenFeeConfig>(&DataKey::TokenFeeConfig(token_addr))
+            .get::<DataKey, TokenFeeConfig>(&DataKey::TokenFeeConfig(token_addr.clone()))
         {
             (
                 tok_cfg.lock_fee_rate,
@@ -1981,16 +3101,16 @@ impl BountyEscrowContract {
         threshold_amount: i128,
         signers: Vec<Address>,
         required_signatures: u32,
-    ) -> Result<(), Error> {
+    ) -> Result<(), soroban_sdk::Error> {
         if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
+            return Err(Error::NotInitialized.into());
         }
 
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         admin.require_auth();
 
         if required_signatures > signers.len() {
-            return Err(Error::InvalidAmount);
+            return Err(Error::InvalidAmount.into());
         }
 
         let config = MultisigConfig {
@@ -2018,33 +3138,240 @@ impl BountyEscrowContract {
             })
     }
 
-    /// Approve release for large amount (requires multisig)
-    pub fn approve_large_release(
+    /// Set the approval weight of a multisig signer (admin only).
+    ///
+    /// Signers default to a weight of 1; a weight of 0 keeps the signer
+    /// registered but makes their approvals count for nothing.
+    pub fn set_approver_weight(
         env: Env,
-        bounty_id: u64,
-        contributor: Address,
         approver: Address,
-    ) -> Result<(), Error> {
+        weight: u32,
+    ) -> Result<(), soroban_sdk::Error> {
         if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
+            return Err(Error::NotInitialized.into());
         }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
 
-        let multisig_config: MultisigConfig = Self::get_multisig_config(env.clone());
+        env.storage()
+            .instance()
+            .set(&ReleaseKey::ApproverWeight(approver), &weight);
+        Ok(())
+    }
 
-        let mut is_signer = false;
-        for signer in multisig_config.signers.iter() {
-            if signer == approver {
-                is_signer = true;
-                break;
-            }
-        }
+    /// Get the approval weight of a signer (1 when never configured).
+    pub fn get_approver_weight(env: Env, approver: Address) -> u32 {
+        env.storage()
+            .instance()
+            .get(&ReleaseKey::ApproverWeight(approver))
+            .unwrap_or(1)
+    }
 
-        if !is_signer {
-            return Err(Error::Unauthorized);
+    /// Set the accumulated weight at which an approved release executes
+    /// (admin only). Must be non-zero.
+    pub fn set_approval_weight_threshold(
+        env: Env,
+        threshold: u32,
+    ) -> Result<(), soroban_sdk::Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized.into());
         }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if threshold == 0 {
+            return Err(Error::InvalidAmount.into());
+        }
+        env.storage()
+            .instance()
+            .set(&ReleaseKey::ApprovalWeightThreshold, &threshold);
+        Ok(())
+    }
+
+    /// Get the release weight threshold. Falls back to the multisig
+    /// `required_signatures` so unweighted setups behave as before.
+    pub fn get_approval_weight_threshold(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&ReleaseKey::ApprovalWeightThreshold)
+            .unwrap_or_else(|| Self::get_multisig_config(env.clone()).required_signatures)
+    }
+
+    /// Current accumulated weight of the pending approvals for a bounty.
+    ///
+    /// Weights are read at call time, so changing a signer's weight
+    /// re-evaluates every pending quorum.
+    pub fn get_release_approval_weight(env: Env, bounty_id: u64) -> u32 {
+        let approval: Option<ReleaseApproval> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ReleaseApproval(bounty_id));
+        match approval {
+            Some(mut a) => {
+                let mut times = Self::approval_times(&env, bounty_id);
+                Self::prune_expired_approvals(&env, &mut a, &mut times);
+                Self::approval_weight(&env, &a.approvals)
+            }
+            None => 0,
+        }
+    }
+
+    /// Set how long an approval stays valid, in seconds (admin only).
+    /// Expired approvals stop counting towards the release threshold.
+    /// A TTL of 0 disables expiry.
+    pub fn set_approval_ttl(env: Env, ttl_seconds: u64) -> Result<(), soroban_sdk::Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized.into());
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&ReleaseKey::ApprovalTtl, &ttl_seconds);
+        Ok(())
+    }
+
+    /// Get the approval TTL in seconds (0 when approvals never expire).
+    pub fn get_approval_ttl(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&ReleaseKey::ApprovalTtl)
+            .unwrap_or(0)
+    }
+
+    /// Withdraw a pending release approval before quorum is reached.
+    ///
+    /// Once the threshold is crossed the release executes and the approval
+    /// record is gone, so revocation then fails with `ApprovalNotFound`.
+    pub fn revoke_approval(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+        approver: Address,
+    ) -> Result<(), soroban_sdk::Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized.into());
+        }
+        approver.require_auth();
+
+        let approval_key = DataKey::ReleaseApproval(bounty_id);
+        let mut approval: ReleaseApproval = env
+            .storage()
+            .persistent()
+            .get(&approval_key)
+            .ok_or(ReleaseError::ApprovalNotFound)?;
+        if approval.contributor != contributor {
+            return Err(ReleaseError::ApprovalContributorMismatch.into());
+        }
+
+        let index = approval
+            .approvals
+            .first_index_of(&approver)
+            .ok_or(ReleaseError::ApprovalNotFound)?;
+        approval.approvals.remove(index);
+
+        let mut times = Self::approval_times(&env, bounty_id);
+        times.remove(approver.clone());
+        Self::prune_expired_approvals(&env, &mut approval, &mut times);
+        Self::store_approval(&env, &approval, &times);
+
+        events::emit_approval_revoked(
+            &env,
+            events::ApprovalRevoked {
+                bounty_id,
+                contributor,
+                approver,
+                total_weight: Self::approval_weight(&env, &approval.approvals),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    fn approval_times(env: &Env, bounty_id: u64) -> Map<Address, u64> {
+        env.storage()
+            .persistent()
+            .get(&ReleaseKey::ApprovalTimes(bounty_id))
+            .unwrap_or(Map::new(env))
+    }
+
+    /// Drop approvals older than the configured TTL. Approvals recorded
+    /// without a timestamp are treated as given at time 0.
+    fn prune_expired_approvals(
+        env: &Env,
+        approval: &mut ReleaseApproval,
+        times: &mut Map<Address, u64>,
+    ) {
+        let ttl = Self::get_approval_ttl(env.clone());
+        if ttl == 0 {
+            return;
+        }
+        let now = env.ledger().timestamp();
+        let mut live = Vec::new(env);
+        for approver in approval.approvals.iter() {
+            let approved_at = times.get(approver.clone()).unwrap_or(0);
+            if approved_at.saturating_add(ttl) >= now {
+                live.push_back(approver);
+            } else {
+                times.remove(approver);
+            }
+        }
+        approval.approvals = live;
+    }
+
+    /// Persist an approval and its timestamps, clearing both once empty.
+    fn store_approval(env: &Env, approval: &ReleaseApproval, times: &Map<Address, u64>) {
+        let approval_key = DataKey::ReleaseApproval(approval.bounty_id);
+        let times_key = ReleaseKey::ApprovalTimes(approval.bounty_id);
+        if approval.approvals.is_empty() {
+            env.storage().persistent().remove(&approval_key);
+            env.storage().persistent().remove(&times_key);
+        } else {
+            env.storage().persistent().set(&approval_key, approval);
+            env.storage().persistent().set(&times_key, times);
+        }
+    }
+
+    fn approval_weight(env: &Env, approvers: &Vec<Address>) -> u32 {
+        let mut total: u32 = 0;
+        for approver in approvers.iter() {
+            total = total.saturating_add(Self::get_approver_weight(env.clone(), approver));
+        }
+        total
+    }
+
+    /// Approve release for large amount (requires multisig).
+    ///
+    /// Each approval adds the signer's weight. Once the accumulated weight
+    /// reaches the approval threshold the release executes immediately to
+    /// the approved contributor.
+    pub fn approve_large_release(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+        approver: Address,
+    ) -> Result<(), soroban_sdk::Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized.into());
+        }
+
+        let multisig_config: MultisigConfig = Self::get_multisig_config(env.clone());
+
+        let mut is_signer = false;
+        for signer in multisig_config.signers.iter() {
+            if signer == approver {
+                is_signer = true;
+                break;
+            }
+        }
+
+        if !is_signer {
+            return Err(Error::Unauthorized.into());
+        }
+
+        approver.require_auth();
 
-        approver.require_auth();
-
         let approval_key = DataKey::ReleaseApproval(bounty_id);
         let mut approval: ReleaseApproval = env
             .storage()
@@ -2056,14 +3383,29 @@ impl BountyEscrowContract {
                 approvals: vec![&env],
             });
 
-        for existing in approval.approvals.iter() {
-            if existing == approver {
-                return Ok(());
-            }
+        let mut times = Self::approval_times(&env, bounty_id);
+        Self::prune_expired_approvals(&env, &mut approval, &mut times);
+        if approval.approvals.is_empty() {
+            // Every earlier approval expired; start over for this contributor.
+            approval.contributor = contributor.clone();
+        }
+
+        if approval.contributor != contributor {
+            return Err(ReleaseError::ApprovalContributorMismatch.into());
+        }
+
+        let already_approved = approval.approvals.contains(&approver);
+        if !already_approved {
+            approval.approvals.push_back(approver.clone());
+        }
+        times.set(approver.clone(), env.ledger().timestamp());
+        Self::store_approval(&env, &approval, &times);
+        if already_approved {
+            return Ok(());
         }
 
-        approval.approvals.push_back(approver.clone());
-        env.storage().persistent().set(&approval_key, &approval);
+        let weight = Self::get_approver_weight(env.clone(), approver.clone());
+        let total_weight = Self::approval_weight(&env, &approval.approvals);
 
         events::emit_approval_added(
             &env,
@@ -2071,11 +3413,29 @@ impl BountyEscrowContract {
                 bounty_id,
                 contributor: contributor.clone(),
                 approver,
+                weight,
+                total_weight,
                 timestamp: env.ledger().timestamp(),
             },
         );
 
-        Ok(())
+        let threshold = Self::get_approval_weight_threshold(env.clone());
+        if threshold == 0 || total_weight < threshold {
+            return Ok(());
+        }
+
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused.into());
+        }
+
+        reentrancy_guard::acquire(&env);
+        env.storage().persistent().remove(&approval_key);
+        env.storage()
+            .persistent()
+            .remove(&ReleaseKey::ApprovalTimes(bounty_id));
+        let res = Self::execute_release(&env, bounty_id, approval.contributor);
+        reentrancy_guard::release(&env);
+        res
     }
 
     /// Lock funds for a specific bounty.
@@ -2089,120 +3449,543 @@ impl BountyEscrowContract {
         bounty_id: u64,
         amount: i128,
         deadline: u64,
-    ) -> Result<(), Error> {
-        let res =
-            Self::lock_funds_logic(env.clone(), depositor.clone(), bounty_id, amount, deadline);
+    ) -> Result<(), soroban_sdk::Error> {
+        let res = Self::lock_funds_logic(
+            env.clone(),
+            depositor.clone(),
+            bounty_id,
+            None,
+            amount,
+            deadline,
+            false,
+        );
         monitoring::track_operation(&env, symbol_short!("lock"), depositor, res.is_ok());
         res
     }
 
-    fn lock_funds_logic(
+    /// Lock funds for a bounty in `token` instead of the contract's default
+    /// token. `token` must be the default token, native XLM, or on the
+    /// allowlist managed by `set_token_allowed`. Releases and refunds of the bounty pay out in
+    /// the same token; see `get_bounty_token`.
+    pub fn lock_funds_with_token(
         env: Env,
         depositor: Address,
         bounty_id: u64,
+        token: Address,
         amount: i128,
         deadline: u64,
-    ) -> Result<(), Error> {
-        // Validation precedence (deterministic ordering):
-        // 1. Reentrancy guard
-        // 2. Contract initialized
-        // 3. Paused / deprecated (operational state)
-        // 4. Participant filter + rate limiting
-        // 5. Authorization
-        // 6. Input validation (amount policy)
-        // 7. Business logic (bounty uniqueness)
+    ) -> Result<(), soroban_sdk::Error> {
+        let res = Self::lock_funds_logic(
+            env.clone(),
+            depositor.clone(),
+            bounty_id,
+            Some(token),
+            amount,
+            deadline,
+            false,
+        );
+        monitoring::track_operation(&env, symbol_short!("lock"), depositor, res.is_ok());
+        res
+    }
 
-        // 1. GUARD: acquire reentrancy lock
-        reentrancy_guard::acquire(&env);
+    /// Lock funds for a bounty by pulling `amount` from an allowance the
+    /// depositor granted this contract with the token's `approve`. The
+    /// depositor does not sign the call, so integrations such as payroll
+    /// systems or DAO treasuries can lock many bounties against one approval;
+    /// the allowance bounds what can be pulled. Otherwise behaves like
+    /// `lock_funds`.
+    pub fn lock_funds_from_allowance(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+    ) -> Result<(), soroban_sdk::Error> {
+        let res = Self::lock_funds_logic(
+            env.clone(),
+            depositor.clone(),
+            bounty_id,
+            None,
+            amount,
+            deadline,
+            true,
+        );
+        monitoring::track_operation(&env, symbol_short!("lock"), depositor, res.is_ok());
+        res
+    }
 
-        // 2. Contract must be initialized before any other check
-        if !env.storage().instance().has(&DataKey::Admin) {
-            reentrancy_guard::release(&env);
-            return Err(Error::NotInitialized);
+    /// Lock funds for a bounty under a jurisdiction config. The config's
+    /// lock pause, max lock amount and KYC requirement are checked here;
+    /// its release and refund pauses apply for the life of the escrow.
+    /// `OptionalJurisdiction::None` behaves like `lock_funds`.
+    pub fn lock_funds_with_jurisdiction(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+        jurisdiction: OptionalJurisdiction,
+    ) -> Result<(), soroban_sdk::Error> {
+        if let OptionalJurisdiction::Some(config) = &jurisdiction {
+            if config.lock_paused {
+                return Err(ComplianceError::JurisdictionPaused.into());
+            }
+            if let Some(max_lock_amount) = config.max_lock_amount {
+                if amount > max_lock_amount {
+                    return Err(ComplianceError::JurisdictionLimitExceeded.into());
+                }
+            }
+            if let Some(tag) = &config.tag {
+                if !Self::list_jurisdiction_tags(env.clone()).contains(tag) {
+                    return Err(ComplianceError::JurisdictionTagNotRegistered.into());
+                }
+            }
+            if config.requires_kyc
+                && Self::get_identity_tier(env.clone(), depositor.clone())
+                    == IdentityTier::Unverified
+            {
+                return Err(ComplianceError::JurisdictionKycRequired.into());
+            }
+            if env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+                return Err(Error::BountyExists.into());
+            }
+            // Stored first so `lock_funds_logic` sees `enforce_identity_limits`.
+            env.storage()
+                .persistent()
+                .set(&ComplianceKey::EscrowJurisdiction(bounty_id), config);
         }
-        soroban_sdk::log!(&env, "admin ok");
+        Self::lock_funds(env.clone(), depositor, bounty_id, amount, deadline)?;
+        Self::apply_jurisdiction(&env, bounty_id, symbol_short!("lock"))
+    }
 
-        // 3. Operational state: paused / deprecated
-        if Self::check_paused(&env, symbol_short!("lock")) {
-            reentrancy_guard::release(&env);
-            return Err(Error::FundsPaused);
+    /// Register a jurisdiction tag for use in `lock_funds_with_jurisdiction`
+    /// (compliance role). Registering a known tag is a no-op.
+    pub fn register_jurisdiction_tag(env: Env, tag: String) -> Result<(), soroban_sdk::Error> {
+        let authority = Self::compliance_authority(&env)?;
+        authority.require_auth();
+        if tag.is_empty() || tag.len() > MAX_JURISDICTION_TAG_LEN {
+            return Err(ComplianceError::InvalidJurisdictionTag.into());
         }
-        if Self::get_deprecation_state(&env).deprecated {
-            reentrancy_guard::release(&env);
-            return Err(Error::ContractDeprecated);
-        }
-        soroban_sdk::log!(&env, "check paused ok");
-
-        // 4. Participant filtering and rate limiting
-        Self::check_participant_filter(&env, depositor.clone())?;
-        soroban_sdk::log!(&env, "start lock_funds");
-        anti_abuse::check_rate_limit(&env, depositor.clone());
-        soroban_sdk::log!(&env, "rate limit ok");
 
-        let _start = env.ledger().timestamp();
-        let _caller = depositor.clone();
+        let mut tags = Self::list_jurisdiction_tags(env.clone());
+        if tags.contains(&tag) {
+            return Ok(());
+        }
+        tags.push_back(tag.clone());
+        env.storage()
+            .instance()
+            .set(&ComplianceKey::JurisdictionTags, &tags);
+        events::emit_jurisdiction_tag_registered(
+            &env,
+            events::JurisdictionTagRegistered {
+                tag,
+                registered_by: authority,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
 
-        // 5. Authorization
-        depositor.require_auth();
-        soroban_sdk::log!(&env, "auth ok");
+    /// Registered jurisdiction tags, in registration order.
+    pub fn list_jurisdiction_tags(env: Env) -> Vec<String> {
+        env.storage()
+            .instance()
+            .get(&ComplianceKey::JurisdictionTags)
+            .unwrap_or(Vec::new(&env))
+    }
 
-        // 6. Input validation: amount policy
-        // Enforce min/max amount policy if one has been configured (Issue #62).
-        if let Some((min_amount, max_amount)) = env
+    /// Jurisdiction config of a bounty; `None` for untagged escrows.
+    pub fn get_escrow_jurisdiction(env: Env, bounty_id: u64) -> OptionalJurisdiction {
+        match env
             .storage()
-            .instance()
-            .get::<DataKey, (i128, i128)>(&DataKey::AmountPolicy)
+            .persistent()
+            .get(&ComplianceKey::EscrowJurisdiction(bounty_id))
         {
-            if amount < min_amount {
-                reentrancy_guard::release(&env);
-                return Err(Error::AmountBelowMinimum);
-            }
-            if amount > max_amount {
-                reentrancy_guard::release(&env);
-                return Err(Error::AmountAboveMaximum);
-            }
+            Some(config) => OptionalJurisdiction::Some(config),
+            None => OptionalJurisdiction::None,
         }
-        soroban_sdk::log!(&env, "amount policy ok");
+    }
 
-        // 7. Business logic: bounty must not already exist
-        if env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            reentrancy_guard::release(&env);
-            return Err(Error::BountyExists);
+    /// Internal: fail with `JurisdictionPaused` if the bounty's jurisdiction
+    /// pauses `operation` (`lock`, `release` or `refund`), otherwise emit a
+    /// `juris` event for tagged escrows.
+    fn apply_jurisdiction(
+        env: &Env,
+        bounty_id: u64,
+        operation: Symbol,
+    ) -> Result<(), soroban_sdk::Error> {
+        let config: EscrowJurisdictionConfig = match env
+            .storage()
+            .persistent()
+            .get(&ComplianceKey::EscrowJurisdiction(bounty_id))
+        {
+            Some(config) => config,
+            None => return Ok(()),
+        };
+        if Self::jurisdiction_pauses(&config, &operation) {
+            return Err(ComplianceError::JurisdictionPaused.into());
         }
-        soroban_sdk::log!(&env, "bounty exists ok");
-
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-        soroban_sdk::log!(&env, "token client ok");
+        events::emit_jurisdiction_applied(
+            env,
+            events::JurisdictionApplied {
+                bounty_id,
+                operation,
+                tag: config.tag,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
 
-        // Transfer full gross amount from depositor to contract first.
-        client.transfer(&depositor, &env.current_contract_address(), &amount);
-        soroban_sdk::log!(&env, "transfer ok");
+    /// Override the lock/release/refund pause flags of an existing escrow,
+    /// e.g. to freeze ongoing escrows of an affected jurisdiction. `flags`
+    /// is a mask of `ESCROW_PAUSE_LOCK`, `ESCROW_PAUSE_RELEASE` and
+    /// `ESCROW_PAUSE_REFUND`; zero unfreezes. Untagged escrows get an
+    /// untagged config carrying the flags. Requires the compliance officer,
+    /// or the admin when none is set.
+    pub fn set_escrow_pause_flags(
+        env: Env,
+        bounty_id: u64,
+        flags: u32,
+    ) -> Result<(), soroban_sdk::Error> {
+        let authority = Self::compliance_authority(&env)?;
+        authority.require_auth();
+        if flags & !ESCROW_PAUSE_ALL != 0 {
+            return Err(LifecycleError::InvalidPauseFlags.into());
+        }
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id))
+            && !env
+                .storage()
+                .persistent()
+                .has(&DataKey::EscrowAnon(bounty_id))
+        {
+            return Err(Error::BountyNotFound.into());
+        }
+
+        let mut config = env
+            .storage()
+            .persistent()
+            .get::<ComplianceKey, EscrowJurisdictionConfig>(&ComplianceKey::EscrowJurisdiction(
+                bounty_id,
+            ))
+            .unwrap_or(EscrowJurisdictionConfig {
+                tag: None,
+                requires_kyc: false,
+                enforce_identity_limits: true,
+                lock_paused: false,
+                release_paused: false,
+                refund_paused: false,
+                max_lock_amount: None,
+            });
+        let previous_flags = Self::pause_flags_of(&config);
+        config.lock_paused = flags & ESCROW_PAUSE_LOCK != 0;
+        config.release_paused = flags & ESCROW_PAUSE_RELEASE != 0;
+        config.refund_paused = flags & ESCROW_PAUSE_REFUND != 0;
+        env.storage()
+            .persistent()
+            .set(&ComplianceKey::EscrowJurisdiction(bounty_id), &config);
+
+        let event = events::EscrowPauseFlagsChanged {
+            bounty_id,
+            previous_flags,
+            flags,
+            changed_by: authority,
+            timestamp: env.ledger().timestamp(),
+        };
+        if flags == 0 {
+            events::emit_escrow_unfrozen(&env, event);
+        } else {
+            events::emit_escrow_frozen(&env, event);
+        }
+        Ok(())
+    }
+
+    /// Current pause flags of an escrow; zero when nothing is paused.
+    pub fn get_escrow_pause_flags(env: Env, bounty_id: u64) -> u32 {
+        env.storage()
+            .persistent()
+            .get::<ComplianceKey, EscrowJurisdictionConfig>(&ComplianceKey::EscrowJurisdiction(
+                bounty_id,
+            ))
+            .map_or(0, |config| Self::pause_flags_of(&config))
+    }
+
+    fn pause_flags_of(config: &EscrowJurisdictionConfig) -> u32 {
+        let mut flags = 0;
+        if config.lock_paused {
+            flags |= ESCROW_PAUSE_LOCK;
+        }
+        if config.release_paused {
+            flags |= ESCROW_PAUSE_RELEASE;
+        }
+        if config.refund_paused {
+            flags |= ESCROW_PAUSE_REFUND;
+        }
+        flags
+    }
+
+    fn jurisdiction_pauses(config: &EscrowJurisdictionConfig, operation: &Symbol) -> bool {
+        if *operation == symbol_short!("lock") {
+            config.lock_paused
+        } else if *operation == symbol_short!("release") {
+            config.release_paused
+        } else {
+            config.refund_paused
+        }
+    }
+
+    fn jurisdiction_enforces_identity_limits(env: &Env, bounty_id: u64) -> bool {
+        env.storage()
+            .persistent()
+            .get::<ComplianceKey, EscrowJurisdictionConfig>(&ComplianceKey::EscrowJurisdiction(
+                bounty_id,
+            ))
+            .is_none_or(|config| config.enforce_identity_limits)
+    }
+
+    /// Lock funds for a bounty in hash-time-locked (HTLC) mode. Anyone who
+    /// knows a preimage whose sha256 equals `hashlock` can claim the bounty
+    /// with `claim_with_preimage` until `deadline`; after that the depositor
+    /// can only refund. Admin release paths are unaffected.
+    pub fn lock_funds_hashlocked(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+        hashlock: BytesN<32>,
+    ) -> Result<(), soroban_sdk::Error> {
+        Self::lock_funds(env.clone(), depositor, bounty_id, amount, deadline)?;
+        env.storage()
+            .persistent()
+            .set(&TicketKey::Hashlock(bounty_id), &hashlock);
+        Ok(())
+    }
+
+    /// Lock native XLM for a bounty through its Stellar Asset Contract.
+    /// `amount` is in stroops; use `normalize_amount` to convert from whole
+    /// XLM. Native XLM is accepted without an allowlist entry, and releases
+    /// and refunds of the bounty pay out in XLM.
+    pub fn lock_funds_native(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+    ) -> Result<(), soroban_sdk::Error> {
+        let native = asset::native_asset_id(&env);
+        Self::lock_funds_with_token(env, depositor, bounty_id, native, amount, deadline)
+    }
+
+    /// Address of the SAC wrapping native XLM on this network.
+    pub fn get_native_token(env: Env) -> Address {
+        asset::native_asset_id(&env)
+    }
+
+    /// Convert `amount`, expressed with `decimals` decimal places, into the
+    /// smallest unit of `token` (stroops for native XLM). Lets clients build
+    /// lock and release amounts the same way for every token.
+    pub fn normalize_amount(
+        env: Env,
+        token: Address,
+        amount: i128,
+        decimals: u32,
+    ) -> Result<i128, soroban_sdk::Error> {
+        asset::normalize_amount(&env, &token, amount, decimals).ok_or(Error::InvalidAmount.into())
+    }
+
+    /// Pull `amount` from `from` into the contract and return what actually
+    /// arrived. Tokens that charge a fee on transfer deliver less than
+    /// `amount`, so every lock path sizes its escrow from the contract's
+    /// balance delta and reports the gap as a `TransferShortfall`.
+    fn receive_deposit(
+        env: &Env,
+        token_addr: &Address,
+        from: &Address,
+        bounty_id: u64,
+        amount: i128,
+        from_allowance: bool,
+    ) -> Result<i128, soroban_sdk::Error> {
+        let client = token::Client::new(env, token_addr);
+        let contract = env.current_contract_address();
+        let balance_before = client.balance(&contract);
+        if from_allowance {
+            client.transfer_from(&contract, from, &contract, &amount);
+        } else {
+            client.transfer(from, &contract, &amount);
+        }
+        let received = client.balance(&contract) - balance_before;
+        if received <= 0 {
+            return Err(Error::InvalidAmount.into());
+        }
+        if received < amount {
+            events::emit_transfer_shortfall(
+                env,
+                events::TransferShortfall {
+                    bounty_id,
+                    token: token_addr.clone(),
+                    expected: amount,
+                    received,
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
+        }
+        Ok(received)
+    }
+
+    fn lock_funds_logic(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        token: Option<Address>,
+        amount: i128,
+        deadline: u64,
+        from_allowance: bool,
+    ) -> Result<(), soroban_sdk::Error> {
+        // Validation precedence (deterministic ordering):
+        // 1. Reentrancy guard
+        // 2. Contract initialized
+        // 3. Paused / deprecated (operational state)
+        // 4. Participant filter + rate limiting
+        // 5. Authorization
+        // 6. Input validation (token allowlist, amount policy)
+        // 7. Business logic (bounty uniqueness)
+
+        // 1. GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        // 2. Contract must be initialized before any other check
+        if !env.storage().instance().has(&DataKey::Admin) {
+            reentrancy_guard::release(&env);
+            return Err(Error::NotInitialized.into());
+        }
+        soroban_sdk::log!(&env, "admin ok");
+
+        // 3. Operational state: paused / deprecated
+        if Self::check_paused(&env, symbol_short!("lock")) {
+            reentrancy_guard::release(&env);
+            return Err(Error::FundsPaused.into());
+        }
+        if Self::get_deprecation_state(&env).deprecated {
+            reentrancy_guard::release(&env);
+            return Err(Error::ContractDeprecated.into());
+        }
+        soroban_sdk::log!(&env, "check paused ok");
+
+        // 4. Participant filtering and rate limiting
+        Self::check_participant_filter(&env, depositor.clone())?;
+        if let Err(e) = Self::require_not_blocked(&env, &depositor)
+            .and_then(|_| Self::require_screened(&env, &depositor, amount))
+        {
+            reentrancy_guard::release(&env);
+            return Err(e);
+        }
+        soroban_sdk::log!(&env, "start lock_funds");
+        anti_abuse::check_rate_limit(&env, depositor.clone());
+        soroban_sdk::log!(&env, "rate limit ok");
+
+        let _start = env.ledger().timestamp();
+        let _caller = depositor.clone();
+
+        // 5. Authorization: allowance pulls are authorized by the depositor's
+        // earlier `approve` instead of a signature on this call.
+        if !from_allowance {
+            depositor.require_auth();
+        }
+        soroban_sdk::log!(&env, "auth ok");
+
+        // 6. Input validation: token allowlist, then amount policy
+        let default_token: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let token_addr = token.unwrap_or(default_token.clone());
+        if token_addr != default_token
+            && !asset::is_native(&env, &token_addr)
+            && !Self::is_token_allowed(env.clone(), token_addr.clone())
+        {
+            reentrancy_guard::release(&env);
+            return Err(LifecycleError::TokenNotAllowed.into());
+        }
+
+        // Enforce min/max amount policy if one has been configured (Issue #62).
+        if let Some((min_amount, max_amount)) = env
+            .storage()
+            .instance()
+            .get::<DataKey, (i128, i128)>(&DataKey::AmountPolicy)
+        {
+            if amount < min_amount {
+                reentrancy_guard::release(&env);
+                return Err(Error::AmountBelowMinimum.into());
+            }
+            if amount > max_amount {
+                reentrancy_guard::release(&env);
+                return Err(Error::AmountAboveMaximum.into());
+            }
+        }
+        soroban_sdk::log!(&env, "amount policy ok");
+
+        if let Err(e) = Self::check_lock_rate_limit(&env, &depositor, amount) {
+            reentrancy_guard::release(&env);
+            return Err(e);
+        }
+        if Self::jurisdiction_enforces_identity_limits(&env, bounty_id) {
+            if let Err(e) = Self::check_tier_limits(&env, &depositor, amount, amount) {
+                reentrancy_guard::release(&env);
+                return Err(e);
+            }
+        }
+
+        // 7. Business logic: bounty must not already exist
+        if env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            reentrancy_guard::release(&env);
+            return Err(Error::BountyExists.into());
+        }
+        soroban_sdk::log!(&env, "bounty exists ok");
+
+        let client = token::Client::new(&env, &token_addr);
+        soroban_sdk::log!(&env, "token client ok");
+
+        // Transfer full gross amount from depositor to contract first; the
+        // escrow is sized from what actually arrived.
+        let received = match Self::receive_deposit(
+            &env,
+            &token_addr,
+            &depositor,
+            bounty_id,
+            amount,
+            from_allowance,
+        ) {
+            Ok(received) => received,
+            Err(e) => {
+                reentrancy_guard::release(&env);
+                return Err(e);
+            }
+        };
+        soroban_sdk::log!(&env, "transfer ok");
 
         // Resolve effective fee config (per-token takes precedence over global).
         let (lock_fee_rate, _release_fee_rate, fee_recipient, fee_enabled) =
-            Self::resolve_fee_config(&env);
+            Self::resolve_fee_config_for_token(&env, &token_addr);
 
         // Deduct lock fee from the escrowed principal.
         // Ceiling division ensures fee >= 1 stroop whenever rate > 0,
         // preventing principal drain via dust-amount splitting.
         let fee_amount = if fee_enabled && lock_fee_rate > 0 {
-            Self::calculate_fee(amount, lock_fee_rate)
+            Self::calculate_fee(received, lock_fee_rate)
         } else {
             0
         };
 
         // Net amount stored in escrow after fee.
         // Fee must never exceed the deposit; guard against misconfiguration.
-        let net_amount = amount.checked_sub(fee_amount).unwrap_or(amount);
+        let net_amount = received.checked_sub(fee_amount).unwrap_or(received);
         if net_amount <= 0 {
-            return Err(Error::InvalidAmount);
+            return Err(Error::InvalidAmount.into());
         }
 
         // Transfer fee to recipient immediately (separate transfer so it is
         // visible as a distinct on-chain operation).
         if fee_amount > 0 {
             client.transfer(&env.current_contract_address(), &fee_recipient, &fee_amount);
+            Self::record_fee_collected(&env, fee_amount);
             events::emit_fee_collected(
                 &env,
                 events::FeeCollected {
@@ -2230,6 +4013,14 @@ impl BountyEscrowContract {
         env.storage()
             .persistent()
             .set(&DataKey::Escrow(bounty_id), &escrow);
+        Self::stamp_escrow_schema(&env, bounty_id);
+        Self::index_status(&env, bounty_id, &escrow.status);
+        Self::sync_escrow_accounting(&env, bounty_id, &escrow);
+        if token_addr != default_token {
+            env.storage()
+                .persistent()
+                .set(&DataKey::BountyToken(bounty_id), &token_addr);
+        }
 
         // Update indexes
         let mut index: Vec<u64> = env
@@ -2257,9 +4048,11 @@ impl BountyEscrowContract {
         emit_funds_locked(
             &env,
             FundsLocked {
-                version: EVENT_VERSION_V2,
+                version: EVENT_VERSION_V3,
                 bounty_id,
                 amount,
+                remaining_before: 0,
+                remaining_after: escrow.remaining_amount,
                 depositor: depositor.clone(),
                 deadline,
             },
@@ -2273,95 +4066,452 @@ impl BountyEscrowContract {
         Ok(())
     }
 
-    /// Simulate lock operation without state changes or token transfers.
-    ///
-    /// Returns a `SimulationResult` indicating whether the operation would succeed and the
-    /// resulting escrow state. Does not require authorization; safe for off-chain preview.
+    /// Add `additional_amount` to a bounty that is still locked, e.g. when
+    /// its scope grows. Only the original depositor can top up.
     ///
-    /// # Arguments
-    /// * `depositor` - Address that would lock funds
-    /// * `bounty_id` - Bounty identifier
-    /// * `amount` - Amount to lock
-    /// * `deadline` - Deadline timestamp
+    /// The same checks as `lock_funds` apply: pause/deprecation state, the
+    /// participant filter, the jurisdiction's lock pause, and the amount
+    /// policy and jurisdiction `max_lock_amount`, whose maximums are checked
+    /// against the new total rather than the top-up alone. The lock fee is
+    /// charged on the top-up.
     ///
-    /// # Security
-    /// This function performs only read operations. No storage writes, token transfers,
-    /// or events are emitted.
-    pub fn dry_run_lock(
+    /// # Events
+    /// Emits `EscrowIncreased` (topic `f_incr`), not `FundsLocked`.
+    pub fn increase_escrow(
         env: Env,
-        depositor: Address,
         bounty_id: u64,
-        amount: i128,
-        deadline: u64,
-    ) -> SimulationResult {
-        fn err_result(e: Error) -> SimulationResult {
-            SimulationResult {
-                success: false,
-                error_code: e as u32,
-                amount: 0,
-                resulting_status: EscrowStatus::Locked,
-                remaining_amount: 0,
-            }
+        additional_amount: i128,
+    ) -> Result<(), soroban_sdk::Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized.into());
         }
-        match Self::dry_run_lock_impl(&env, depositor, bounty_id, amount, deadline) {
-            Ok((net_amount,)) => SimulationResult {
-                success: true,
-                error_code: 0,
-                amount: net_amount,
-                resulting_status: EscrowStatus::Locked,
-                remaining_amount: net_amount,
-            },
-            Err(e) => err_result(e),
+        if Self::check_paused(&env, symbol_short!("lock")) {
+            return Err(Error::FundsPaused.into());
         }
-    }
-
-    fn dry_run_lock_impl(
-        env: &Env,
-        depositor: Address,
-        bounty_id: u64,
-        amount: i128,
-        _deadline: u64,
-    ) -> Result<(i128,), Error> {
-        // 1. Contract must be initialized
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
+        if Self::get_deprecation_state(&env).deprecated {
+            return Err(Error::ContractDeprecated.into());
         }
-        // 2. Operational state: paused / deprecated
-        if Self::check_paused(env, symbol_short!("lock")) {
-            return Err(Error::FundsPaused);
+
+        Self::migrate_escrow_record(&env, bounty_id);
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked.into());
         }
-        if Self::get_deprecation_state(env).deprecated {
-            return Err(Error::ContractDeprecated);
+
+        let depositor = escrow.depositor.clone();
+        Self::check_participant_filter(&env, depositor.clone())?;
+        depositor.require_auth();
+
+        if additional_amount <= 0 {
+            return Err(Error::InvalidAmount.into());
         }
-        // 3. Participant filtering (read-only)
-        Self::check_participant_filter(env, depositor.clone())?;
-        // 4. Amount policy
-        if let Some((min_amount, max_amount)) = env
+        let new_total = escrow
+            .amount
+            .checked_add(additional_amount)
+            .ok_or(Error::InvalidAmount)?;
+        if let Some((_min_amount, max_amount)) = env
             .storage()
             .instance()
             .get::<DataKey, (i128, i128)>(&DataKey::AmountPolicy)
         {
-            if amount < min_amount {
-                return Err(Error::AmountBelowMinimum);
+            if new_total > max_amount {
+                return Err(Error::AmountAboveMaximum.into());
             }
-            if amount > max_amount {
-                return Err(Error::AmountAboveMaximum);
+        }
+        Self::check_tier_limits(&env, &depositor, new_total, additional_amount)?;
+        if let Some(max_lock_amount) = env
+            .storage()
+            .persistent()
+            .get::<ComplianceKey, EscrowJurisdictionConfig>(&ComplianceKey::EscrowJurisdiction(
+                bounty_id,
+            ))
+            .and_then(|config| config.max_lock_amount)
+        {
+            if new_total > max_lock_amount {
+                return Err(ComplianceError::JurisdictionLimitExceeded.into());
             }
         }
-        // 5. Bounty must not already exist
-        if env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyExists);
+        Self::apply_jurisdiction(&env, bounty_id, symbol_short!("lock"))?;
+
+        reentrancy_guard::acquire(&env);
+
+        let token_addr = Self::bounty_token(&env, bounty_id);
+        let client = token::Client::new(&env, &token_addr);
+        let received = match Self::receive_deposit(
+            &env,
+            &token_addr,
+            &depositor,
+            bounty_id,
+            additional_amount,
+            false,
+        ) {
+            Ok(received) => received,
+            Err(e) => {
+                reentrancy_guard::release(&env);
+                return Err(e);
+            }
+        };
+
+        let (lock_fee_rate, _release_fee_rate, fee_recipient, fee_enabled) =
+            Self::resolve_fee_config_for_token(&env, &token_addr);
+        let fee_amount = if fee_enabled && lock_fee_rate > 0 {
+            Self::calculate_fee(received, lock_fee_rate)
+        } else {
+            0
+        };
+        let net_added = received - fee_amount;
+        if net_added <= 0 {
+            return Err(Error::InvalidAmount.into());
+        }
+        if fee_amount > 0 {
+            client.transfer(&env.current_contract_address(), &fee_recipient, &fee_amount);
+            Self::record_fee_collected(&env, fee_amount);
+            events::emit_fee_collected(
+                &env,
+                events::FeeCollected {
+                    operation_type: events::FeeOperationType::Lock,
+                    amount: fee_amount,
+                    fee_rate: lock_fee_rate,
+                    recipient: fee_recipient,
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
+        }
+
+        escrow.amount += net_added;
+        escrow.remaining_amount += net_added;
+        invariants::assert_escrow(&env, &escrow);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+        Self::sync_escrow_accounting(&env, bounty_id, &escrow);
+
+        events::emit_escrow_increased(
+            &env,
+            events::EscrowIncreased {
+                version: EVENT_VERSION_V3,
+                bounty_id,
+                added_amount: additional_amount,
+                new_amount: escrow.amount,
+                remaining_amount: escrow.remaining_amount,
+                remaining_before: escrow.remaining_amount - net_added,
+                remaining_after: escrow.remaining_amount,
+                depositor: depositor.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        multitoken_invariants::assert_after_lock(&env);
+        reentrancy_guard::release(&env);
+        monitoring::track_operation(&env, symbol_short!("increase"), depositor, true);
+        Ok(())
+    }
+
+    /// Carve `amount` out of a locked bounty into a new, independent escrow
+    /// `new_id`, e.g. when one funded issue is split into two sub-issues.
+    /// Only the depositor can split. The child keeps the parent's deadline,
+    /// token and jurisdiction config; no tokens move and no fee is charged.
+    ///
+    /// `amount` must be positive and below the parent's remaining amount.
+    ///
+    /// # Events
+    /// Emits `EscrowSplit` (topic `f_split`).
+    pub fn split_escrow(
+        env: Env,
+        bounty_id: u64,
+        new_id: u64,
+        amount: i128,
+    ) -> Result<(), soroban_sdk::Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized.into());
+        }
+        Self::migrate_escrow_record(&env, bounty_id);
+        let mut parent: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if parent.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked.into());
+        }
+        let depositor = parent.depositor.clone();
+        depositor.require_auth();
+
+        if amount <= 0 || amount >= parent.remaining_amount {
+            return Err(Error::InvalidAmount.into());
+        }
+        if env.storage().persistent().has(&DataKey::Escrow(new_id))
+            || env.storage().persistent().has(&DataKey::EscrowAnon(new_id))
+        {
+            return Err(Error::BountyExists.into());
+        }
+
+        let remaining_before = parent.remaining_amount;
+        parent.amount -= amount;
+        parent.remaining_amount -= amount;
+        let child = Escrow {
+            depositor: depositor.clone(),
+            amount,
+            remaining_amount: amount,
+            status: EscrowStatus::Locked,
+            deadline: parent.deadline,
+            refund_history: vec![&env],
+        };
+        invariants::assert_escrow(&env, &parent);
+        invariants::assert_escrow(&env, &child);
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &parent);
+        Self::sync_escrow_accounting(&env, bounty_id, &parent);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(new_id), &child);
+        Self::stamp_escrow_schema(&env, new_id);
+        Self::index_status(&env, new_id, &child.status);
+        Self::sync_escrow_accounting(&env, new_id, &child);
+
+        if let Some(token_addr) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, Address>(&DataKey::BountyToken(bounty_id))
+        {
+            env.storage()
+                .persistent()
+                .set(&DataKey::BountyToken(new_id), &token_addr);
+        }
+        if let Some(config) = env
+            .storage()
+            .persistent()
+            .get::<ComplianceKey, EscrowJurisdictionConfig>(&ComplianceKey::EscrowJurisdiction(
+                bounty_id,
+            ))
+        {
+            env.storage()
+                .persistent()
+                .set(&ComplianceKey::EscrowJurisdiction(new_id), &config);
+        }
+
+        let mut index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EscrowIndex)
+            .unwrap_or(Vec::new(&env));
+        index.push_back(new_id);
+        env.storage()
+            .persistent()
+            .set(&DataKey::EscrowIndex, &index);
+        let mut depositor_index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::DepositorIndex(depositor.clone()))
+            .unwrap_or(Vec::new(&env));
+        depositor_index.push_back(new_id);
+        env.storage().persistent().set(
+            &DataKey::DepositorIndex(depositor.clone()),
+            &depositor_index,
+        );
+
+        events::emit_escrow_split(
+            &env,
+            events::EscrowSplit {
+                version: EVENT_VERSION_V3,
+                bounty_id,
+                new_bounty_id: new_id,
+                amount,
+                remaining_before,
+                remaining_after: parent.remaining_amount,
+                depositor,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        multitoken_invariants::assert_after_lock(&env);
+        Ok(())
+    }
+
+    /// Fold the remaining balance of locked bounty `source_id` into locked
+    /// bounty `target_id`, the inverse of `split_escrow`. Both must belong
+    /// to the same depositor, use the same token and carry the same
+    /// jurisdiction config. The target takes the later of the two deadlines;
+    /// the source is left `Released` with nothing remaining.
+    ///
+    /// # Events
+    /// Emits `EscrowsMerged` (topic `f_merge`).
+    pub fn merge_escrows(
+        env: Env,
+        target_id: u64,
+        source_id: u64,
+    ) -> Result<(), soroban_sdk::Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized.into());
+        }
+        if target_id == source_id {
+            return Err(LifecycleError::IncompatibleEscrows.into());
+        }
+        Self::migrate_escrow_record(&env, target_id);
+        Self::migrate_escrow_record(&env, source_id);
+        let mut target: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(target_id))
+            .ok_or(Error::BountyNotFound)?;
+        let mut source: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(source_id))
+            .ok_or(Error::BountyNotFound)?;
+        if target.status != EscrowStatus::Locked || source.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked.into());
+        }
+        target.depositor.require_auth();
+
+        let target_config: Option<EscrowJurisdictionConfig> = env
+            .storage()
+            .persistent()
+            .get(&ComplianceKey::EscrowJurisdiction(target_id));
+        let source_config: Option<EscrowJurisdictionConfig> = env
+            .storage()
+            .persistent()
+            .get(&ComplianceKey::EscrowJurisdiction(source_id));
+        if source.depositor != target.depositor
+            || Self::bounty_token(&env, source_id) != Self::bounty_token(&env, target_id)
+            || source_config != target_config
+        {
+            return Err(LifecycleError::IncompatibleEscrows.into());
+        }
+
+        let moved = source.remaining_amount;
+        let remaining_before = target.remaining_amount;
+        target.amount += moved;
+        target.remaining_amount += moved;
+        target.deadline = target.deadline.max(source.deadline);
+        source.amount -= moved;
+        source.remaining_amount = 0;
+        source.status = EscrowStatus::Released;
+        invariants::assert_escrow(&env, &target);
+        invariants::assert_escrow(&env, &source);
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(source_id), &source);
+        Self::index_status(&env, source_id, &source.status);
+        Self::sync_escrow_accounting(&env, source_id, &source);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(target_id), &target);
+        Self::sync_escrow_accounting(&env, target_id, &target);
+
+        events::emit_escrows_merged(
+            &env,
+            events::EscrowsMerged {
+                version: EVENT_VERSION_V3,
+                bounty_id: target_id,
+                source_bounty_id: source_id,
+                amount: moved,
+                remaining_before,
+                remaining_after: target.remaining_amount,
+                deadline: target.deadline,
+                depositor: target.depositor.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        multitoken_invariants::assert_after_lock(&env);
+        Ok(())
+    }
+
+    /// Simulate lock operation without state changes or token transfers.
+    ///
+    /// Returns a `SimulationResult` indicating whether the operation would succeed and the
+    /// resulting escrow state. Does not require authorization; safe for off-chain preview.
+    ///
+    /// # Arguments
+    /// * `depositor` - Address that would lock funds
+    /// * `bounty_id` - Bounty identifier
+    /// * `amount` - Amount to lock
+    /// * `deadline` - Deadline timestamp
+    ///
+    /// # Security
+    /// This function performs only read operations. No storage writes, token transfers,
+    /// or events are emitted.
+    pub fn dry_run_lock(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+    ) -> SimulationResult {
+        fn err_result(e: soroban_sdk::Error) -> SimulationResult {
+            SimulationResult {
+                success: false,
+                error_code: e.get_code(),
+                amount: 0,
+                resulting_status: EscrowStatus::Locked,
+                remaining_amount: 0,
+            }
+        }
+        match Self::dry_run_lock_impl(&env, depositor, bounty_id, amount, deadline) {
+            Ok((net_amount,)) => SimulationResult {
+                success: true,
+                error_code: 0,
+                amount: net_amount,
+                resulting_status: EscrowStatus::Locked,
+                remaining_amount: net_amount,
+            },
+            Err(e) => err_result(e),
+        }
+    }
+
+    fn dry_run_lock_impl(
+        env: &Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        _deadline: u64,
+    ) -> Result<(i128,), soroban_sdk::Error> {
+        // 1. Contract must be initialized
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized.into());
+        }
+        // 2. Operational state: paused / deprecated
+        if Self::check_paused(env, symbol_short!("lock")) {
+            return Err(Error::FundsPaused.into());
+        }
+        if Self::get_deprecation_state(env).deprecated {
+            return Err(Error::ContractDeprecated.into());
+        }
+        // 3. Participant filtering (read-only)
+        Self::check_participant_filter(env, depositor.clone())?;
+        // 4. Amount policy
+        if let Some((min_amount, max_amount)) = env
+            .storage()
+            .instance()
+            .get::<DataKey, (i128, i128)>(&DataKey::AmountPolicy)
+        {
+            if amount < min_amount {
+                return Err(Error::AmountBelowMinimum.into());
+            }
+            if amount > max_amount {
+                return Err(Error::AmountAboveMaximum.into());
+            }
+        }
+        // 5. Bounty must not already exist
+        if env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyExists.into());
         }
         // 6. Amount validation
         if amount <= 0 {
-            return Err(Error::InvalidAmount);
+            return Err(Error::InvalidAmount.into());
         }
         let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
         let client = token::Client::new(env, &token_addr);
         // 7. Sufficient balance (read-only)
         let balance = client.balance(&depositor);
         if balance < amount {
-            return Err(Error::InsufficientFunds);
+            return Err(Error::InsufficientFunds.into());
         }
         // 8. Fee computation (pure)
         let (lock_fee_rate, _release_fee_rate, _fee_recipient, fee_enabled) =
@@ -2373,16 +4523,19 @@ impl BountyEscrowContract {
         };
         let net_amount = amount.checked_sub(fee_amount).unwrap_or(amount);
         if net_amount <= 0 {
-            return Err(Error::InvalidAmount);
+            return Err(Error::InvalidAmount.into());
         }
         Ok((net_amount,))
     }
 
     /// Returns whether the given bounty escrow is marked as using non-transferable (soulbound)
     /// reward tokens. When true, the token is expected to disallow further transfers after claim.
-    pub fn get_non_transferable_rewards(env: Env, bounty_id: u64) -> Result<bool, Error> {
+    pub fn get_non_transferable_rewards(
+        env: Env,
+        bounty_id: u64,
+    ) -> Result<bool, soroban_sdk::Error> {
         if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
+            return Err(Error::BountyNotFound.into());
         }
         Ok(env
             .storage()
@@ -2391,6 +4544,15 @@ impl BountyEscrowContract {
             .unwrap_or(false))
     }
 
+    /// Token the bounty's funds are held in: the token passed to
+    /// `lock_funds_with_token`, or the default token otherwise.
+    pub fn get_bounty_token(env: Env, bounty_id: u64) -> Result<Address, soroban_sdk::Error> {
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound.into());
+        }
+        Ok(Self::bounty_token(&env, bounty_id))
+    }
+
     /// Lock funds for a bounty in anonymous mode: only a 32-byte depositor commitment is stored.
     /// The depositor must authorize and transfer; their address is used only for the transfer
     /// in this call and is not stored on-chain. Refunds require the configured anonymous
@@ -2402,7 +4564,7 @@ impl BountyEscrowContract {
         bounty_id: u64,
         amount: i128,
         deadline: u64,
-    ) -> Result<(), Error> {
+    ) -> Result<(), soroban_sdk::Error> {
         // Validation precedence (deterministic ordering):
         // 1. Reentrancy guard
         // 2. Contract initialized
@@ -2417,16 +4579,22 @@ impl BountyEscrowContract {
         // 2. Contract must be initialized
         if !env.storage().instance().has(&DataKey::Admin) {
             reentrancy_guard::release(&env);
-            return Err(Error::NotInitialized);
+            return Err(Error::NotInitialized.into());
         }
 
         // 3. Operational state: paused
         if Self::check_paused(&env, symbol_short!("lock")) {
             reentrancy_guard::release(&env);
-            return Err(Error::FundsPaused);
+            return Err(Error::FundsPaused.into());
         }
 
-        // 4. Rate limiting
+        // 4. Compliance block list, screening and rate limiting
+        if let Err(e) = Self::require_not_blocked(&env, &depositor)
+            .and_then(|_| Self::require_screened(&env, &depositor, amount))
+        {
+            reentrancy_guard::release(&env);
+            return Err(e);
+        }
         anti_abuse::check_rate_limit(&env, depositor.clone());
 
         // 5. Authorization
@@ -2439,7 +4607,7 @@ impl BountyEscrowContract {
                 .has(&DataKey::EscrowAnon(bounty_id))
         {
             reentrancy_guard::release(&env);
-            return Err(Error::BountyExists);
+            return Err(Error::BountyExists.into());
         }
 
         if let Some((min_amount, max_amount)) = env
@@ -2449,18 +4617,39 @@ impl BountyEscrowContract {
         {
             if amount < min_amount {
                 reentrancy_guard::release(&env);
-                return Err(Error::AmountBelowMinimum);
+                return Err(Error::AmountBelowMinimum.into());
             }
             if amount > max_amount {
                 reentrancy_guard::release(&env);
-                return Err(Error::AmountAboveMaximum);
+                return Err(Error::AmountAboveMaximum.into());
             }
         }
 
+        if let Err(e) = Self::check_lock_rate_limit(&env, &depositor, amount) {
+            reentrancy_guard::release(&env);
+            return Err(e);
+        }
+        // The depositor is not stored, so this lock never counts towards
+        // their exposure afterwards; it is still held to their tier's limits.
+        if let Err(e) = Self::check_tier_limits(&env, &depositor, amount, amount) {
+            reentrancy_guard::release(&env);
+            return Err(e);
+        }
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let received =
+            match Self::receive_deposit(&env, &token_addr, &depositor, bounty_id, amount, false) {
+                Ok(received) => received,
+                Err(e) => {
+                    reentrancy_guard::release(&env);
+                    return Err(e);
+                }
+            };
+
         let escrow_anon = AnonymousEscrow {
             depositor_commitment: depositor_commitment.clone(),
-            amount,
-            remaining_amount: amount,
+            amount: received,
+            remaining_amount: received,
             status: EscrowStatus::Locked,
             deadline,
             refund_history: vec![&env],
@@ -2469,6 +4658,8 @@ impl BountyEscrowContract {
         env.storage()
             .persistent()
             .set(&DataKey::EscrowAnon(bounty_id), &escrow_anon);
+        Self::index_status(&env, bounty_id, &escrow_anon.status);
+        Self::sync_anon_accounting(&env, bounty_id, &escrow_anon);
 
         let mut index: Vec<u64> = env
             .storage()
@@ -2480,16 +4671,14 @@ impl BountyEscrowContract {
             .persistent()
             .set(&DataKey::EscrowIndex, &index);
 
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-        client.transfer(&depositor, &env.current_contract_address(), &amount);
-
         emit_funds_locked_anon(
             &env,
             FundsLockedAnon {
-                version: EVENT_VERSION_V2,
+                version: EVENT_VERSION_V3,
                 bounty_id,
-                amount,
+                amount: received,
+                remaining_before: 0,
+                remaining_after: escrow_anon.remaining_amount,
                 depositor_commitment,
                 deadline,
             },
@@ -2502,7 +4691,11 @@ impl BountyEscrowContract {
 
     /// Release funds to the contributor.
     /// Only the admin (backend) can authorize this.
-    pub fn release_funds(env: Env, bounty_id: u64, contributor: Address) -> Result<(), Error> {
+    pub fn release_funds(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+    ) -> Result<(), soroban_sdk::Error> {
         let caller = env
             .storage()
             .instance()
@@ -2513,7 +4706,11 @@ impl BountyEscrowContract {
         res
     }
 
-    fn release_funds_logic(env: Env, bounty_id: u64, contributor: Address) -> Result<(), Error> {
+    fn release_funds_logic(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+    ) -> Result<(), soroban_sdk::Error> {
         // Validation precedence (deterministic ordering):
         // 1. Reentrancy guard
         // 2. Contract initialized
@@ -2532,13 +4729,13 @@ impl BountyEscrowContract {
         // 2. Contract must be initialized
         if !env.storage().instance().has(&DataKey::Admin) {
             env.storage().instance().remove(&DataKey::ReentrancyGuard);
-            return Err(Error::NotInitialized);
+            return Err(Error::NotInitialized.into());
         }
 
         // 3. Operational state: paused
         if Self::check_paused(&env, symbol_short!("release")) {
             env.storage().instance().remove(&DataKey::ReentrancyGuard);
-            return Err(Error::FundsPaused);
+            return Err(Error::FundsPaused.into());
         }
 
         let _start = env.ledger().timestamp();
@@ -2547,11 +4744,33 @@ impl BountyEscrowContract {
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         admin.require_auth();
 
-        // 5. Business logic: bounty must exist and be locked
+        // 5. Business logic: bounty must exist and be locked. Bounties with a
+        //    challenge period are held as pending instead of paid out.
+        let res = if Self::get_release_challenge_period(env.clone(), bounty_id) > 0 {
+            Self::start_pending_release(&env, bounty_id, contributor)
+        } else {
+            Self::execute_release(&env, bounty_id, contributor)
+        };
+
+        // Clear reentrancy guard
+        env.storage().instance().remove(&DataKey::ReentrancyGuard);
+
+        res
+    }
+
+    /// Internal: pay whatever is still locked (less any release fee) to
+    /// `contributor`. After partial releases that is `remaining_amount`, not
+    /// the original `amount`. Callers are responsible for authorization and
+    /// the reentrancy guard.
+    fn execute_release(
+        env: &Env,
+        bounty_id: u64,
+        contributor: Address,
+    ) -> Result<(), soroban_sdk::Error> {
         if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            env.storage().instance().remove(&DataKey::ReentrancyGuard);
-            return Err(Error::BountyNotFound);
+            return Err(Error::BountyNotFound.into());
         }
+        Self::migrate_escrow_record(env, bounty_id);
 
         let mut escrow: Escrow = env
             .storage()
@@ -2560,29 +4779,30 @@ impl BountyEscrowContract {
             .unwrap();
 
         if escrow.status != EscrowStatus::Locked {
-            return Err(Error::FundsNotLocked);
+            return Err(Error::FundsNotLocked.into());
         }
+        let payout_amount = escrow.remaining_amount;
+        Self::require_payout_allowed(env, bounty_id, &contributor, payout_amount)?;
 
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
+        let token_addr = Self::bounty_token(env, bounty_id);
+        let client = token::Client::new(env, &token_addr);
 
         // Resolve effective fee config for release.
         let (_lock_fee_rate, release_fee_rate, fee_recipient, fee_enabled) =
-            Self::resolve_fee_config(&env);
+            Self::resolve_fee_config_for_token(env, &token_addr);
 
         let release_fee = if fee_enabled && release_fee_rate > 0 {
-            Self::calculate_fee(escrow.amount, release_fee_rate)
+            Self::calculate_fee(payout_amount, release_fee_rate)
         } else {
             0
         };
 
         // Net payout to contributor after release fee.
-        let net_payout = escrow
-            .amount
+        let net_payout = payout_amount
             .checked_sub(release_fee)
-            .unwrap_or(escrow.amount);
+            .unwrap_or(payout_amount);
         if net_payout <= 0 {
-            return Err(Error::InvalidAmount);
+            return Err(Error::InvalidAmount.into());
         }
 
         if release_fee > 0 {
@@ -2591,8 +4811,9 @@ impl BountyEscrowContract {
                 &fee_recipient,
                 &release_fee,
             );
+            Self::record_fee_collected(env, release_fee);
             events::emit_fee_collected(
-                &env,
+                env,
                 events::FeeCollected {
                     operation_type: events::FeeOperationType::Release,
                     amount: release_fee,
@@ -2605,2326 +4826,7234 @@ impl BountyEscrowContract {
 
         // Transfer net amount to contributor
         client.transfer(&env.current_contract_address(), &contributor, &net_payout);
+        Self::return_claim_bond(env, &client, bounty_id, &contributor);
 
+        let remaining_before = escrow.remaining_amount;
         escrow.status = EscrowStatus::Released;
         escrow.remaining_amount = 0;
-        invariants::assert_escrow(&env, &escrow);
+        invariants::assert_escrow(env, &escrow);
         env.storage()
             .persistent()
             .set(&DataKey::Escrow(bounty_id), &escrow);
+        Self::index_status(env, bounty_id, &escrow.status);
+        Self::sync_escrow_accounting(env, bounty_id, &escrow);
+        threshold_monitor::record_outflow(env, payout_amount);
+        Self::record_paid_work(env, bounty_id, &contributor);
 
         emit_funds_released(
-            &env,
+            env,
             FundsReleased {
-                version: EVENT_VERSION_V2,
+                version: EVENT_VERSION_V3,
                 bounty_id,
-                amount: escrow.amount,
+                amount: payout_amount,
+                remaining_before,
+                remaining_after: escrow.remaining_amount,
                 recipient: contributor.clone(),
                 timestamp: env.ledger().timestamp(),
             },
         );
 
-        // Clear reentrancy guard
-        env.storage().instance().remove(&DataKey::ReentrancyGuard);
-
         Ok(())
     }
 
-    /// Simulate release operation without state changes or token transfers.
-    ///
-    /// Returns a `SimulationResult` indicating whether the operation would succeed and the
-    /// resulting escrow state. Does not require authorization; safe for off-chain preview.
-    ///
-    /// # Arguments
-    /// * `bounty_id` - Bounty identifier
-    /// * `contributor` - Recipient address
+    /// Record a hash of the deliverable a contributor submits for a bounty.
     ///
-    /// # Security
-    /// This function performs only read operations. No storage writes, token transfers,
-    /// or events are emitted.
-    pub fn dry_run_release(env: Env, bounty_id: u64, contributor: Address) -> SimulationResult {
-        fn err_result(e: Error) -> SimulationResult {
-            SimulationResult {
-                success: false,
-                error_code: e as u32,
-                amount: 0,
-                resulting_status: EscrowStatus::Released,
-                remaining_amount: 0,
-            }
-        }
-        match Self::dry_run_release_impl(&env, bounty_id, contributor) {
-            Ok((amount,)) => SimulationResult {
-                success: true,
-                error_code: 0,
-                amount,
-                resulting_status: EscrowStatus::Released,
-                remaining_amount: 0,
-            },
-            Err(e) => err_result(e),
-        }
-    }
-
-    fn dry_run_release_impl(
-        env: &Env,
+    /// Submissions are kept in order so disputes can see who delivered
+    /// first; a contributor may resubmit, and the latest one counts.
+    pub fn submit_work(
+        env: Env,
         bounty_id: u64,
-        _contributor: Address,
-    ) -> Result<(i128,), Error> {
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
-        }
-        if Self::check_paused(env, symbol_short!("release")) {
-            return Err(Error::FundsPaused);
-        }
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
-        }
+        contributor: Address,
+        work_hash: BytesN<32>,
+    ) -> Result<(), soroban_sdk::Error> {
+        contributor.require_auth();
         let escrow: Escrow = env
             .storage()
             .persistent()
             .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
+            .ok_or(Error::BountyNotFound)?;
         if escrow.status != EscrowStatus::Locked {
-            return Err(Error::FundsNotLocked);
+            return Err(Error::FundsNotLocked.into());
         }
-        let (_lock_fee_rate, release_fee_rate, _fee_recipient, fee_enabled) =
-            Self::resolve_fee_config(env);
-        let release_fee = if fee_enabled && release_fee_rate > 0 {
-            Self::calculate_fee(escrow.amount, release_fee_rate)
-        } else {
-            0
+
+        let submission = WorkSubmission {
+            contributor: contributor.clone(),
+            work_hash: work_hash.clone(),
+            submitted_at: env.ledger().timestamp(),
         };
-        let net_payout = escrow
-            .amount
-            .checked_sub(release_fee)
-            .unwrap_or(escrow.amount);
-        if net_payout <= 0 {
-            return Err(Error::InvalidAmount);
+        let key = ReleaseKey::WorkSubmissions(bounty_id);
+        let mut submissions: Vec<WorkSubmission> =
+            env.storage().persistent().get(&key).unwrap_or(vec![&env]);
+        submissions.push_back(submission.clone());
+        env.storage().persistent().set(&key, &submissions);
+
+        events::emit_work_submitted(
+            &env,
+            events::WorkSubmitted {
+                bounty_id,
+                contributor,
+                work_hash,
+                submitted_at: submission.submitted_at,
+            },
+        );
+        Ok(())
+    }
+
+    /// All work submissions for a bounty, in submission order.
+    pub fn get_work_submissions(env: Env, bounty_id: u64) -> Vec<WorkSubmission> {
+        env.storage()
+            .persistent()
+            .get(&ReleaseKey::WorkSubmissions(bounty_id))
+            .unwrap_or(vec![&env])
+    }
+
+    /// The submission the release of a bounty paid for, if the recipient
+    /// had submitted work.
+    pub fn get_paid_work(env: Env, bounty_id: u64) -> Option<WorkSubmission> {
+        env.storage()
+            .persistent()
+            .get(&ReleaseKey::PaidWork(bounty_id))
+    }
+
+    /// Link a payout to the recipient's latest work submission, if any.
+    fn record_paid_work(env: &Env, bounty_id: u64, contributor: &Address) {
+        let submissions = Self::get_work_submissions(env.clone(), bounty_id);
+        for submission in submissions.iter().rev() {
+            if submission.contributor == *contributor {
+                env.storage()
+                    .persistent()
+                    .set(&ReleaseKey::PaidWork(bounty_id), &submission);
+                return;
+            }
         }
-        Ok((escrow.amount,))
     }
 
-    /// Delegated release flow using a capability instead of admin auth.
-    /// The capability amount limit is consumed by `payout_amount`.
-    pub fn release_with_capability(
+    /// Group existing bounties under `group_id` (admin only).
+    ///
+    /// A bounty can belong to at most one group.
+    pub fn create_bounty_group(
         env: Env,
-        bounty_id: u64,
-        contributor: Address,
-        payout_amount: i128,
-        holder: Address,
-        capability_id: u64,
-    ) -> Result<(), Error> {
-        if Self::check_paused(&env, symbol_short!("release")) {
-            return Err(Error::FundsPaused);
-        }
-        if payout_amount <= 0 {
-            return Err(Error::InvalidAmount);
-        }
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
+        group_id: u64,
+        bounty_ids: Vec<u64>,
+    ) -> Result<(), soroban_sdk::Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized.into());
         }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
 
-        let mut escrow: Escrow = env
+        if bounty_ids.is_empty() {
+            return Err(Error::InvalidBatchSize.into());
+        }
+        if env
             .storage()
             .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
-        if escrow.status != EscrowStatus::Locked {
-            return Err(Error::FundsNotLocked);
+            .has(&DataKey::BountyGroup(group_id))
+        {
+            return Err(LifecycleError::GroupAlreadyExists.into());
         }
-        if payout_amount > escrow.remaining_amount {
-            return Err(Error::InsufficientFunds);
+        for (i, bounty_id) in bounty_ids.iter().enumerate() {
+            if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+                return Err(Error::BountyNotFound.into());
+            }
+            if env
+                .storage()
+                .persistent()
+                .has(&DataKey::BountyGroupOf(bounty_id))
+            {
+                return Err(LifecycleError::BountyAlreadyGrouped.into());
+            }
+            for other in bounty_ids.iter().skip(i + 1) {
+                if other == bounty_id {
+                    return Err(Error::DuplicateBountyId.into());
+                }
+            }
         }
 
-        Self::consume_capability(
-            &env,
-            &holder,
-            capability_id,
-            CapabilityAction::Release,
-            bounty_id,
-            payout_amount,
-        )?;
-
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-        client.transfer(
-            &env.current_contract_address(),
-            &contributor,
-            &payout_amount,
-        );
-
-        escrow.remaining_amount -= payout_amount;
-        if escrow.remaining_amount == 0 {
-            escrow.status = EscrowStatus::Released;
+        for bounty_id in bounty_ids.iter() {
+            env.storage()
+                .persistent()
+                .set(&DataKey::BountyGroupOf(bounty_id), &group_id);
         }
+        let group = BountyGroup {
+            group_id,
+            bounty_ids: bounty_ids.clone(),
+            paused: false,
+            created_at: env.ledger().timestamp(),
+        };
         env.storage()
             .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
+            .set(&DataKey::BountyGroup(group_id), &group);
 
-        emit_funds_released(
+        events::emit_bounty_group_created(
             &env,
-            FundsReleased {
-                version: EVENT_VERSION_V2,
-                bounty_id,
-                amount: payout_amount,
-                recipient: contributor,
-                timestamp: env.ledger().timestamp(),
+            events::BountyGroupCreated {
+                group_id,
+                bounty_ids,
+                timestamp: group.created_at,
             },
         );
-
         Ok(())
     }
 
-    /// Set the claim window duration (admin only).
-    /// claim_window: seconds beneficiary has to claim after release is authorized.
-    pub fn set_claim_window(env: Env, claim_window: u64) -> Result<(), Error> {
+    /// Get a bounty group.
+    pub fn get_bounty_group(env: Env, group_id: u64) -> Result<BountyGroup, soroban_sdk::Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::BountyGroup(group_id))
+            .ok_or(LifecycleError::GroupNotFound.into())
+    }
+
+    /// Get the group a bounty belongs to, if any.
+    pub fn get_bounty_group_id(env: Env, bounty_id: u64) -> Option<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::BountyGroupOf(bounty_id))
+    }
+
+    /// Aggregate amounts and statuses over the bounties of a group.
+    pub fn get_group_summary(
+        env: Env,
+        group_id: u64,
+    ) -> Result<BountyGroupSummary, soroban_sdk::Error> {
+        let group = Self::get_bounty_group(env.clone(), group_id)?;
+        let mut summary = BountyGroupSummary {
+            group_id,
+            bounty_count: group.bounty_ids.len(),
+            total_amount: 0,
+            total_remaining: 0,
+            locked_count: 0,
+            released_count: 0,
+            refunded_count: 0,
+            paused: group.paused,
+        };
+        for bounty_id in group.bounty_ids.iter() {
+            let escrow: Option<Escrow> =
+                env.storage().persistent().get(&DataKey::Escrow(bounty_id));
+            if let Some(escrow) = escrow {
+                summary.total_amount += escrow.amount;
+                summary.total_remaining += escrow.remaining_amount;
+                match escrow.status {
+                    EscrowStatus::Locked | EscrowStatus::PendingRelease => {
+                        summary.locked_count += 1
+                    }
+                    EscrowStatus::Released => summary.released_count += 1,
+                    EscrowStatus::Refunded | EscrowStatus::PartiallyRefunded => {
+                        summary.refunded_count += 1
+                    }
+                }
+            }
+        }
+        Ok(summary)
+    }
+
+    /// Pause or resume payouts for every bounty in a group (admin only).
+    pub fn set_group_paused(
+        env: Env,
+        group_id: u64,
+        paused: bool,
+    ) -> Result<(), soroban_sdk::Error> {
         if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
+            return Err(Error::NotInitialized.into());
         }
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         admin.require_auth();
+
+        let mut group = Self::get_bounty_group(env.clone(), group_id)?;
+        group.paused = paused;
         env.storage()
-            .instance()
-            .set(&DataKey::ClaimWindow, &claim_window);
+            .persistent()
+            .set(&DataKey::BountyGroup(group_id), &group);
+
+        events::emit_bounty_group_paused(
+            &env,
+            events::BountyGroupPaused {
+                group_id,
+                paused,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
         Ok(())
     }
 
-    /// Admin can authorize a release as a pending claim instead of immediate transfer.
-    pub fn authorize_claim(
+    /// Release several bounties of a group in one transaction (admin only).
+    ///
+    /// Every item must belong to the group. If any release fails the whole
+    /// call reverts, so either all listed bounties pay out or none do.
+    /// Bounties with a challenge period enter `PendingRelease` as with
+    /// `release_funds`.
+    pub fn release_group(
         env: Env,
-        bounty_id: u64,
-        recipient: Address,
-        reason: DisputeReason,
-    ) -> Result<(), Error> {
-        if Self::check_paused(&env, symbol_short!("release")) {
-            return Err(Error::FundsPaused);
-        }
+        group_id: u64,
+        items: Vec<ReleaseFundsItem>,
+    ) -> Result<u32, soroban_sdk::Error> {
         if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
+            return Err(Error::NotInitialized.into());
+        }
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused.into());
         }
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         admin.require_auth();
 
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
+        let group = Self::get_bounty_group(env.clone(), group_id)?;
+        if group.paused {
+            return Err(LifecycleError::GroupPaused.into());
+        }
+        if items.is_empty() || items.len() > MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatchSize.into());
+        }
+        for (i, item) in items.iter().enumerate() {
+            if !group.bounty_ids.contains(item.bounty_id) {
+                return Err(LifecycleError::BountyNotInGroup.into());
+            }
+            for other in items.iter().skip(i + 1) {
+                if other.bounty_id == item.bounty_id {
+                    return Err(Error::DuplicateBountyId.into());
+                }
+            }
+        }
+
+        reentrancy_guard::acquire(&env);
+        for item in items.iter() {
+            let res = if Self::get_release_challenge_period(env.clone(), item.bounty_id) > 0 {
+                Self::start_pending_release(&env, item.bounty_id, item.contributor)
+            } else {
+                Self::execute_release(&env, item.bounty_id, item.contributor)
+            };
+            if let Err(e) = res {
+                reentrancy_guard::release(&env);
+                return Err(e);
+            }
+        }
+        reentrancy_guard::release(&env);
+        Ok(items.len())
+    }
+
+    fn require_group_active(env: &Env, bounty_id: u64) -> Result<(), soroban_sdk::Error> {
+        if let Some(group_id) = Self::get_bounty_group_id(env.clone(), bounty_id) {
+            if Self::get_bounty_group(env.clone(), group_id)?.paused {
+                return Err(LifecycleError::GroupPaused.into());
+            }
         }
+        Ok(())
+    }
 
+    /// Require contributors to stake a bond before the bounty can be
+    /// released to them (depositor only). An `amount` of 0 removes the
+    /// requirement.
+    pub fn set_claim_bond(
+        env: Env,
+        bounty_id: u64,
+        amount: i128,
+        timeout: u64,
+    ) -> Result<(), soroban_sdk::Error> {
         let escrow: Escrow = env
             .storage()
             .persistent()
             .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
-
+            .ok_or(Error::BountyNotFound)?;
+        escrow.depositor.require_auth();
         if escrow.status != EscrowStatus::Locked {
-            return Err(Error::FundsNotLocked);
+            return Err(Error::FundsNotLocked.into());
+        }
+        if amount < 0 {
+            return Err(Error::InvalidAmount.into());
         }
 
-        let now = env.ledger().timestamp();
-        let claim_window: u64 = env
-            .storage()
-            .instance()
-            .get(&DataKey::ClaimWindow)
-            .unwrap_or(0);
-        let claim = ClaimRecord {
-            bounty_id,
-            recipient: recipient.clone(),
-            amount: escrow.amount,
-            expires_at: now.saturating_add(claim_window),
-            claimed: false,
-            reason: reason.clone(),
-        };
+        let key = TicketKey::ClaimBondConfig(bounty_id);
+        if amount == 0 {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage()
+                .persistent()
+                .set(&key, &ClaimBondConfig { amount, timeout });
+        }
+        Ok(())
+    }
 
+    /// Get the claim bond a bounty requires, if any.
+    pub fn get_claim_bond_config(env: Env, bounty_id: u64) -> Option<ClaimBondConfig> {
         env.storage()
             .persistent()
-            .set(&DataKey::PendingClaim(bounty_id), &claim);
+            .get(&TicketKey::ClaimBondConfig(bounty_id))
+    }
 
-        env.events().publish(
-            (symbol_short!("claim"), symbol_short!("created")),
-            ClaimCreated {
-                bounty_id,
-                recipient,
-                amount: escrow.amount,
-                expires_at: claim.expires_at,
-            },
-        );
-        Ok(())
+    /// Get the bond a contributor has staked on a bounty, if any.
+    pub fn get_claim_bond(env: Env, bounty_id: u64, contributor: Address) -> Option<ClaimBond> {
+        env.storage()
+            .persistent()
+            .get(&TicketKey::ClaimBond(bounty_id, contributor))
     }
 
-    /// Beneficiary calls this to claim their authorized funds within the window.
-    pub fn claim(env: Env, bounty_id: u64) -> Result<(), Error> {
-        if Self::check_paused(&env, symbol_short!("release")) {
-            return Err(Error::FundsPaused);
-        }
-        if !env
+    /// Stake the bounty's claim bond, making `contributor` eligible for
+    /// release. The bond is paid in the bounty's token and must be at least
+    /// the configured amount.
+    pub fn stake_to_claim(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+        amount: i128,
+    ) -> Result<(), soroban_sdk::Error> {
+        contributor.require_auth();
+        let escrow: Escrow = env
             .storage()
             .persistent()
-            .has(&DataKey::PendingClaim(bounty_id))
-        {
-            return Err(Error::BountyNotFound);
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked.into());
         }
-        let mut claim: ClaimRecord = env
-            .storage()
-            .persistent()
-            .get(&DataKey::PendingClaim(bounty_id))
-            .unwrap();
-
-        claim.recipient.require_auth();
-
-        let now = env.ledger().timestamp();
-        if now > claim.expires_at {
-            return Err(Error::DeadlineNotPassed); // reuse or add ClaimExpired error
+        let config = match Self::get_claim_bond_config(env.clone(), bounty_id) {
+            Some(c) => c,
+            None => return Err(Error::InvalidAmount.into()),
+        };
+        if amount < config.amount {
+            return Err(Error::InvalidAmount.into());
         }
-        if claim.claimed {
-            return Err(Error::FundsNotLocked);
+        let key = TicketKey::ClaimBond(bounty_id, contributor.clone());
+        if env.storage().persistent().has(&key) {
+            return Err(ClaimError::BondAlreadyStaked.into());
         }
 
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-        client.transfer(
-            &env.current_contract_address(),
-            &claim.recipient,
-            &claim.amount,
+        let now = env.ledger().timestamp();
+        env.storage().persistent().set(
+            &key,
+            &ClaimBond {
+                amount,
+                staked_at: now,
+            },
         );
+        Self::adjust_total_bonds(&env, bounty_id, amount);
 
-        // Update escrow status
-        let mut escrow: Escrow = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
-        escrow.status = EscrowStatus::Released;
-        env.storage()
-            .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
-
-        claim.claimed = true;
-        env.storage()
-            .persistent()
-            .set(&DataKey::PendingClaim(bounty_id), &claim);
+        let token_addr = Self::bounty_token(&env, bounty_id);
+        let client = token::Client::new(&env, &token_addr);
+        client.transfer(&contributor, &env.current_contract_address(), &amount);
 
-        env.events().publish(
-            (symbol_short!("claim"), symbol_short!("done")),
-            ClaimExecuted {
+        events::emit_claim_bond(
+            &env,
+            events::ClaimBondEvent {
                 bounty_id,
-                recipient: claim.recipient.clone(),
-                amount: claim.amount,
-                claimed_at: now,
+                contributor,
+                amount,
+                action: symbol_short!("staked"),
+                timestamp: now,
             },
         );
         Ok(())
     }
 
-    /// Delegated claim execution using a capability.
-    /// Funds are still transferred to the pending claim recipient.
-    pub fn claim_with_capability(
+    /// Withdraw a claim bond once its timeout has passed.
+    pub fn withdraw_claim_bond(
         env: Env,
         bounty_id: u64,
-        holder: Address,
-        capability_id: u64,
-    ) -> Result<(), Error> {
-        if Self::check_paused(&env, symbol_short!("release")) {
-            return Err(Error::FundsPaused);
-        }
-        if !env
+        contributor: Address,
+    ) -> Result<(), soroban_sdk::Error> {
+        contributor.require_auth();
+        let key = TicketKey::ClaimBond(bounty_id, contributor.clone());
+        let bond: ClaimBond = env
             .storage()
             .persistent()
-            .has(&DataKey::PendingClaim(bounty_id))
-        {
-            return Err(Error::BountyNotFound);
+            .get(&key)
+            .ok_or(ClaimError::BondRequired)?;
+        let timeout = Self::get_claim_bond_config(env.clone(), bounty_id)
+            .map(|c| c.timeout)
+            .unwrap_or(0);
+        if env.ledger().timestamp() < bond.staked_at.saturating_add(timeout) {
+            return Err(ClaimError::BondLocked.into());
         }
 
-        let mut claim: ClaimRecord = env
-            .storage()
-            .persistent()
-            .get(&DataKey::PendingClaim(bounty_id))
-            .unwrap();
+        let token_addr = Self::bounty_token(&env, bounty_id);
+        let client = token::Client::new(&env, &token_addr);
+        Self::return_claim_bond(&env, &client, bounty_id, &contributor);
+        Ok(())
+    }
 
-        let now = env.ledger().timestamp();
-        if now > claim.expires_at {
-            return Err(Error::DeadlineNotPassed);
-        }
-        if claim.claimed {
-            return Err(Error::FundsNotLocked);
+    /// Slash an abandoning contributor's bond to the depositor (admin only).
+    pub fn slash_claim_bond(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+    ) -> Result<(), soroban_sdk::Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized.into());
         }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
 
-        Self::consume_capability(
-            &env,
-            &holder,
-            capability_id,
-            CapabilityAction::Claim,
-            bounty_id,
-            claim.amount,
-        )?;
-
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-        client.transfer(
-            &env.current_contract_address(),
-            &claim.recipient,
-            &claim.amount,
-        );
-
-        let mut escrow: Escrow = env
+        let escrow: Escrow = env
             .storage()
             .persistent()
             .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
-        escrow.status = EscrowStatus::Released;
-        env.storage()
+            .ok_or(Error::BountyNotFound)?;
+        let key = TicketKey::ClaimBond(bounty_id, contributor.clone());
+        let bond: ClaimBond = env
+            .storage()
             .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
+            .get(&key)
+            .ok_or(ClaimError::BondRequired)?;
+        env.storage().persistent().remove(&key);
+        Self::adjust_total_bonds(&env, bounty_id, -bond.amount);
 
-        claim.claimed = true;
-        env.storage()
-            .persistent()
-            .set(&DataKey::PendingClaim(bounty_id), &claim);
+        let token_addr = Self::bounty_token(&env, bounty_id);
+        let client = token::Client::new(&env, &token_addr);
+        client.transfer(
+            &env.current_contract_address(),
+            &escrow.depositor,
+            &bond.amount,
+        );
 
-        env.events().publish(
-            (symbol_short!("claim"), symbol_short!("done")),
-            ClaimExecuted {
+        events::emit_claim_bond(
+            &env,
+            events::ClaimBondEvent {
                 bounty_id,
-                recipient: claim.recipient,
-                amount: claim.amount,
-                claimed_at: now,
+                contributor,
+                amount: bond.amount,
+                action: symbol_short!("slashed"),
+                timestamp: env.ledger().timestamp(),
             },
         );
         Ok(())
     }
 
-    /// Admin can cancel an expired or unwanted pending claim, returning escrow to Locked.
-    pub fn cancel_pending_claim(
-        env: Env,
+    fn require_claim_bond(
+        env: &Env,
         bounty_id: u64,
-        outcome: DisputeOutcome,
-    ) -> Result<(), Error> {
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
+        contributor: &Address,
+    ) -> Result<(), soroban_sdk::Error> {
+        if env
+            .storage()
+            .persistent()
+            .has(&TicketKey::ClaimBondConfig(bounty_id))
+            && !env
+                .storage()
+                .persistent()
+                .has(&TicketKey::ClaimBond(bounty_id, contributor.clone()))
+        {
+            return Err(ClaimError::BondRequired.into());
         }
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
+        Ok(())
+    }
 
-        if !env
+    /// Track bonds staked in the default token, so INV-2 can count them
+    /// against the contract balance alongside active escrows.
+    fn adjust_total_bonds(env: &Env, bounty_id: u64, delta: i128) {
+        if env
             .storage()
             .persistent()
-            .has(&DataKey::PendingClaim(bounty_id))
+            .has(&DataKey::BountyToken(bounty_id))
         {
-            return Err(Error::BountyNotFound);
+            return;
         }
-        let claim: ClaimRecord = env
+        let total: i128 = env
             .storage()
             .persistent()
-            .get(&DataKey::PendingClaim(bounty_id))
-            .unwrap();
+            .get(&TicketKey::TotalBonds)
+            .unwrap_or(0);
+        env.storage()
+            .persistent()
+            .set(&TicketKey::TotalBonds, &(total + delta));
+    }
+
+    /// Pay a staked bond back to its contributor, if one exists.
+    fn return_claim_bond(env: &Env, client: &token::Client, bounty_id: u64, contributor: &Address) {
+        let key = TicketKey::ClaimBond(bounty_id, contributor.clone());
+        let bond: Option<ClaimBond> = env.storage().persistent().get(&key);
+        if let Some(bond) = bond {
+            env.storage().persistent().remove(&key);
+            Self::adjust_total_bonds(env, bounty_id, -bond.amount);
+            client.transfer(&env.current_contract_address(), contributor, &bond.amount);
+            events::emit_claim_bond(
+                env,
+                events::ClaimBondEvent {
+                    bounty_id,
+                    contributor: contributor.clone(),
+                    amount: bond.amount,
+                    action: symbol_short!("returned"),
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
+        }
+    }
 
-        let now = env.ledger().timestamp(); // Added this line
-        let recipient = claim.recipient.clone(); // Added this line
-        let amount = claim.amount; // Added this line
+    /// Set the challenge period for a bounty (depositor only).
+    ///
+    /// While non-zero, `release_funds` only marks the escrow as
+    /// `PendingRelease`; the depositor may dispute it for `seconds` before
+    /// anyone can call `finalize_release`.
+    pub fn set_release_challenge_period(
+        env: Env,
+        bounty_id: u64,
+        seconds: u64,
+    ) -> Result<(), soroban_sdk::Error> {
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        escrow.depositor.require_auth();
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked.into());
+        }
 
         env.storage()
             .persistent()
-            .remove(&DataKey::PendingClaim(bounty_id));
-
-        env.events().publish(
-            (symbol_short!("claim"), symbol_short!("cancel")),
-            ClaimCancelled {
-                bounty_id,
-                recipient,
-                amount,
-                cancelled_at: now,
-                cancelled_by: admin,
-            },
-        );
+            .set(&ReleaseKey::ChallengePeriod(bounty_id), &seconds);
         Ok(())
     }
 
-    /// View: get pending claim for a bounty.
-    pub fn get_pending_claim(env: Env, bounty_id: u64) -> Result<ClaimRecord, Error> {
+    /// Get the challenge period of a bounty in seconds (0 when disabled).
+    pub fn get_release_challenge_period(env: Env, bounty_id: u64) -> u64 {
         env.storage()
             .persistent()
-            .get(&DataKey::PendingClaim(bounty_id))
-            .ok_or(Error::BountyNotFound)
+            .get(&ReleaseKey::ChallengePeriod(bounty_id))
+            .unwrap_or(0)
     }
 
-    /// Approve a refund before deadline (admin only).
-    /// This allows early refunds with admin approval.
-    pub fn approve_refund(
-        env: Env,
-        bounty_id: u64,
-        amount: i128,
-        recipient: Address,
-        mode: RefundMode,
-    ) -> Result<(), Error> {
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
-        }
-
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
-
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
-        }
+    /// Get the pending release of a bounty, if any.
+    pub fn get_pending_release(env: Env, bounty_id: u64) -> Option<PendingRelease> {
+        env.storage()
+            .persistent()
+            .get(&ReleaseKey::PendingRelease(bounty_id))
+    }
 
-        let escrow: Escrow = env
+    fn start_pending_release(
+        env: &Env,
+        bounty_id: u64,
+        contributor: Address,
+    ) -> Result<(), soroban_sdk::Error> {
+        Self::migrate_escrow_record(env, bounty_id);
+        let mut escrow: Escrow = env
             .storage()
             .persistent()
             .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
-
-        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
-        {
-            return Err(Error::FundsNotLocked);
-        }
-
-        if amount <= 0 || amount > escrow.remaining_amount {
-            return Err(Error::InvalidAmount);
+            .ok_or(Error::BountyNotFound)?;
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked.into());
         }
+        Self::require_claim_bond(env, bounty_id, &contributor)?;
+        Self::require_group_active(env, bounty_id)?;
+        Self::require_not_blocked(env, &contributor)?;
+        Self::apply_jurisdiction(env, bounty_id, symbol_short!("release"))?;
 
-        let approval = RefundApproval {
+        let now = env.ledger().timestamp();
+        let period = Self::get_release_challenge_period(env.clone(), bounty_id);
+        let pending = PendingRelease {
             bounty_id,
-            amount,
-            recipient: recipient.clone(),
-            mode: mode.clone(),
-            approved_by: admin.clone(),
-            approved_at: env.ledger().timestamp(),
+            contributor: contributor.clone(),
+            requested_at: now,
+            finalize_after: now.saturating_add(period),
+            disputed: false,
+            reason_hash: BytesN::from_array(env, &[0; 32]),
         };
 
+        escrow.status = EscrowStatus::PendingRelease;
         env.storage()
             .persistent()
-            .set(&DataKey::RefundApproval(bounty_id), &approval);
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+        Self::index_status(env, bounty_id, &escrow.status);
+        Self::sync_escrow_accounting(env, bounty_id, &escrow);
+        env.storage()
+            .persistent()
+            .set(&ReleaseKey::PendingRelease(bounty_id), &pending);
 
+        events::emit_release_pending(
+            env,
+            events::ReleasePending {
+                bounty_id,
+                contributor,
+                amount: escrow.remaining_amount,
+                finalize_after: pending.finalize_after,
+            },
+        );
         Ok(())
     }
 
-    /// Release a partial amount of the locked funds to the contributor.
-    /// Only the admin (backend) can authorize this.
+    /// Dispute a pending release during its challenge period (depositor only).
     ///
-    /// - `payout_amount` must be > 0 and <= `remaining_amount`.
-    /// - `remaining_amount` is decremented by `payout_amount` after each call.
-    /// - When `remaining_amount` reaches 0 the escrow status is set to Released.
-    /// - The bounty stays Locked while any funds remain unreleased.
-    pub fn partial_release(
+    /// The funds stay frozen in `PendingRelease` and `finalize_release`
+    /// is rejected from then on.
+    pub fn dispute_release(
         env: Env,
         bounty_id: u64,
-        contributor: Address,
-        payout_amount: i128,
-    ) -> Result<(), Error> {
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
-        }
-
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
-
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
-        }
-
-        let mut escrow: Escrow = env
+        reason_hash: BytesN<32>,
+    ) -> Result<(), soroban_sdk::Error> {
+        let escrow: Escrow = env
             .storage()
             .persistent()
             .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
+            .ok_or(Error::BountyNotFound)?;
+        escrow.depositor.require_auth();
 
-        if escrow.status != EscrowStatus::Locked {
-            return Err(Error::FundsNotLocked);
-        }
-
-        // Guard: zero or negative payout makes no sense and would corrupt state
-        if payout_amount <= 0 {
-            return Err(Error::InvalidAmount);
-        }
-
-        // Guard: prevent overpayment — payout cannot exceed what is still owed
-        if payout_amount > escrow.remaining_amount {
-            return Err(Error::InsufficientFunds);
-        }
-
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-
-        // Transfer only the requested partial amount to the contributor
-        client.transfer(
-            &env.current_contract_address(),
-            &contributor,
-            &payout_amount,
-        );
-
-        // Decrement remaining; this is always an exact integer subtraction — no rounding
-        escrow.remaining_amount = escrow.remaining_amount.checked_sub(payout_amount).unwrap();
-
-        // Automatically transition to Released once fully paid out
-        if escrow.remaining_amount == 0 {
-            escrow.status = EscrowStatus::Released;
+        let mut pending: PendingRelease = env
+            .storage()
+            .persistent()
+            .get(&ReleaseKey::PendingRelease(bounty_id))
+            .ok_or(ReleaseError::PendingReleaseNotFound)?;
+        if pending.disputed {
+            return Err(ReleaseError::ReleaseDisputed.into());
+        }
+        let now = env.ledger().timestamp();
+        if now >= pending.finalize_after {
+            return Err(ReleaseError::ChallengePeriodElapsed.into());
         }
 
+        pending.disputed = true;
+        pending.reason_hash = reason_hash.clone();
         env.storage()
             .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
+            .set(&ReleaseKey::PendingRelease(bounty_id), &pending);
+        let mut counters = Self::reputation_counters(&env, &escrow.depositor);
+        counters.disputes += 1;
+        env.storage().persistent().set(
+            &AccountingKey::ReputationCounters(escrow.depositor.clone()),
+            &counters,
+        );
 
-        events::emit_funds_released(
+        events::emit_release_disputed(
             &env,
-            FundsReleased {
-                version: EVENT_VERSION_V2,
+            events::ReleaseDisputed {
                 bounty_id,
-                amount: payout_amount,
-                recipient: contributor,
-                timestamp: env.ledger().timestamp(),
+                depositor: escrow.depositor,
+                reason_hash,
+                disputed_at: now,
             },
         );
-
         Ok(())
     }
 
-    /// Refunds remaining funds when refund conditions are met.
-    ///
-    /// # Authorization
-    /// Refund execution requires authenticated authorization from the contract admin
-    /// and the escrow depositor.
-    ///
-    /// # Eligibility
-    /// Refund is allowed when either:
-    /// 1. The deadline has passed (standard full refund to depositor), or
-    /// 2. An admin approval exists (early, partial, or custom-recipient refund).
-    ///
-    /// # Errors
-    /// Returns `Error::NotInitialized` if admin is not set.
-    pub fn refund(env: Env, bounty_id: u64) -> Result<(), Error> {
-        let caller = env
+    /// Pay out an undisputed pending release once its challenge period has
+    /// elapsed. Callable by anyone.
+    pub fn finalize_release(env: Env, bounty_id: u64) -> Result<(), soroban_sdk::Error> {
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused.into());
+        }
+        let pending: PendingRelease = env
             .storage()
             .persistent()
-            .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
-            .map(|escrow| escrow.depositor)
-            .unwrap_or_else(|| env.current_contract_address());
-        let res = Self::refund_logic(env.clone(), bounty_id);
-        monitoring::track_operation(&env, symbol_short!("refund"), caller, res.is_ok());
-        res
-    }
-
-    fn refund_logic(env: Env, bounty_id: u64) -> Result<(), Error> {
-        if Self::check_paused(&env, symbol_short!("refund")) {
-            return Err(Error::FundsPaused);
+            .get(&ReleaseKey::PendingRelease(bounty_id))
+            .ok_or(ReleaseError::PendingReleaseNotFound)?;
+        if pending.disputed {
+            return Err(ReleaseError::ReleaseDisputed.into());
         }
-
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
+        if env.ledger().timestamp() < pending.finalize_after {
+            return Err(ReleaseError::ChallengePeriodActive.into());
         }
 
+        reentrancy_guard::acquire(&env);
         let mut escrow: Escrow = env
             .storage()
             .persistent()
             .get(&DataKey::Escrow(bounty_id))
             .unwrap();
+        escrow.status = EscrowStatus::Locked;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+        Self::index_status(&env, bounty_id, &escrow.status);
+        Self::sync_escrow_accounting(&env, bounty_id, &escrow);
+        env.storage()
+            .persistent()
+            .remove(&ReleaseKey::PendingRelease(bounty_id));
 
-        // Require authenticated approval from both admin and depositor.
+        let res = Self::execute_release(&env, bounty_id, pending.contributor);
+        reentrancy_guard::release(&env);
+        res
+    }
+
+    /// Schedule a full release of `bounty_id` to `contributor` at `release_at`
+    /// (admin only). The escrow stays `Locked` until someone calls
+    /// `execute_scheduled_release`; the schedule is the delay, so the bounty's
+    /// challenge period does not apply on top of it.
+    pub fn schedule_release(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+        release_at: u64,
+    ) -> Result<(), soroban_sdk::Error> {
         let admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
             .ok_or(Error::NotInitialized)?;
         admin.require_auth();
-        escrow.depositor.require_auth();
-
-        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
-        {
-            return Err(Error::FundsNotLocked);
-        }
 
-        // Block refund if there is a pending claim (Issue #391 fix)
-        if env
+        let escrow: Escrow = env
             .storage()
             .persistent()
-            .has(&DataKey::PendingClaim(bounty_id))
-        {
-            let claim: ClaimRecord = env
-                .storage()
-                .persistent()
-                .get(&DataKey::PendingClaim(bounty_id))
-                .unwrap();
-            if !claim.claimed {
-                return Err(Error::ClaimPending);
-            }
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked.into());
+        }
+        let key = ReleaseKey::ScheduledRelease(bounty_id);
+        if env.storage().persistent().has(&key) {
+            return Err(ReleaseError::ReleaseAlreadyScheduled.into());
         }
-
         let now = env.ledger().timestamp();
-        let approval_key = DataKey::RefundApproval(bounty_id);
-        let approval: Option<RefundApproval> = env.storage().persistent().get(&approval_key);
-
-        // Refund is allowed if:
-        // 1. Deadline has passed (returns full amount to depositor)
-        // 2. An administrative approval exists (can be early, partial, and to custom recipient)
-        if now < escrow.deadline && approval.is_none() {
-            return Err(Error::DeadlineNotPassed);
+        if release_at <= now {
+            return Err(Error::InvalidDeadline.into());
         }
+        Self::require_not_blocked(&env, &contributor)?;
 
-        let (refund_amount, refund_to, is_full) = if let Some(app) = approval.clone() {
-            let full = app.mode == RefundMode::Full || app.amount >= escrow.remaining_amount;
-            (app.amount, app.recipient, full)
-        } else {
-            // Standard refund after deadline
-            (escrow.remaining_amount, escrow.depositor.clone(), true)
+        let schedule = ScheduledRelease {
+            bounty_id,
+            contributor: contributor.clone(),
+            release_at,
+            scheduled_at: now,
         };
+        env.storage().persistent().set(&key, &schedule);
 
-        if refund_amount <= 0 || refund_amount > escrow.remaining_amount {
-            return Err(Error::InvalidAmount);
-        }
-
-        // EFFECTS: update state before external call (CEI)
-        invariants::assert_escrow(&env, &escrow);
-        // Update escrow state: subtract the amount exactly refunded
-        escrow.remaining_amount = escrow.remaining_amount.checked_sub(refund_amount).unwrap();
-        if is_full || escrow.remaining_amount == 0 {
-            escrow.status = EscrowStatus::Refunded;
-        } else {
-            escrow.status = EscrowStatus::PartiallyRefunded;
-        }
-
-        // Add to refund history
-        escrow.refund_history.push_back(RefundRecord {
-            amount: refund_amount,
-            recipient: refund_to.clone(),
-            timestamp: now,
-            mode: if is_full {
-                RefundMode::Full
-            } else {
-                RefundMode::Partial
+        events::emit_release_scheduled(
+            &env,
+            events::ReleaseScheduled {
+                bounty_id,
+                contributor,
+                amount: escrow.remaining_amount,
+                release_at,
             },
-        });
-
-        // Save updated escrow
-        env.storage()
-            .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
+        );
+        Ok(())
+    }
 
-        // Remove approval after successful execution
-        if approval.is_some() {
-            env.storage().persistent().remove(&approval_key);
-        }
+    /// Drop a scheduled release before it executes (admin only).
+    pub fn cancel_scheduled_release(env: Env, bounty_id: u64) -> Result<(), soroban_sdk::Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
 
-        // INTERACTION: external token transfer is last
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-        client.transfer(&env.current_contract_address(), &refund_to, &refund_amount);
+        let key = ReleaseKey::ScheduledRelease(bounty_id);
+        let schedule: ScheduledRelease = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(ReleaseError::ScheduledReleaseNotFound)?;
+        env.storage().persistent().remove(&key);
 
-        emit_funds_refunded(
+        events::emit_scheduled_release_cancelled(
             &env,
-            FundsRefunded {
-                version: EVENT_VERSION_V2,
+            events::ScheduledReleaseCancelled {
                 bounty_id,
-                amount: refund_amount,
-                refund_to: refund_to.clone(),
-                timestamp: now,
+                contributor: schedule.contributor,
+                cancelled_at: env.ledger().timestamp(),
             },
         );
-        Self::record_receipt(
-            &env,
-            CriticalOperationOutcome::Refunded,
-            bounty_id,
-            refund_amount,
-            refund_to.clone(),
-        );
-
-        // INV-2: Verify aggregate balance matches token balance after refund
-        multitoken_invariants::assert_after_disbursement(&env);
-
-        // GUARD: release reentrancy lock
-        reentrancy_guard::release(&env);
         Ok(())
     }
 
-    /// Simulate refund operation without state changes or token transfers.
-    ///
-    /// Returns a `SimulationResult` indicating whether the operation would succeed and the
-    /// resulting escrow state. Does not require authorization; safe for off-chain preview.
-    ///
-    /// # Arguments
-    /// * `bounty_id` - Bounty identifier
-    ///
-    /// # Security
-    /// This function performs only read operations. No storage writes, token transfers,
-    /// or events are emitted.
-    pub fn dry_run_refund(env: Env, bounty_id: u64) -> SimulationResult {
-        fn err_result(e: Error, default_status: EscrowStatus) -> SimulationResult {
-            SimulationResult {
-                success: false,
-                error_code: e as u32,
-                amount: 0,
-                resulting_status: default_status,
-                remaining_amount: 0,
-            }
+    /// Pay out a scheduled release once `release_at` has passed. Callable by
+    /// anyone; funds can only go to the contributor the admin scheduled.
+    pub fn execute_scheduled_release(env: Env, bounty_id: u64) -> Result<(), soroban_sdk::Error> {
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused.into());
         }
-        match Self::dry_run_refund_impl(&env, bounty_id) {
-            Ok((refund_amount, resulting_status, remaining_amount)) => SimulationResult {
-                success: true,
-                error_code: 0,
-                amount: refund_amount,
-                resulting_status,
-                remaining_amount,
-            },
-            Err(e) => err_result(e, EscrowStatus::Refunded),
+        let key = ReleaseKey::ScheduledRelease(bounty_id);
+        let schedule: ScheduledRelease = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(ReleaseError::ScheduledReleaseNotFound)?;
+        if env.ledger().timestamp() < schedule.release_at {
+            return Err(ReleaseError::ScheduledReleaseNotDue.into());
         }
+
+        reentrancy_guard::acquire(&env);
+        env.storage().persistent().remove(&key);
+        let res = Self::execute_release(&env, bounty_id, schedule.contributor);
+        reentrancy_guard::release(&env);
+        res
     }
 
-    fn dry_run_refund_impl(env: &Env, bounty_id: u64) -> Result<(i128, EscrowStatus, i128), Error> {
-        if Self::check_paused(env, symbol_short!("refund")) {
-            return Err(Error::FundsPaused);
+    /// The release scheduled for `bounty_id`, if any.
+    pub fn get_scheduled_release(env: Env, bounty_id: u64) -> Option<ScheduledRelease> {
+        env.storage()
+            .persistent()
+            .get(&ReleaseKey::ScheduledRelease(bounty_id))
+    }
+
+    /// Convert a locked escrow into a linear vesting stream to `contributor`
+    /// over `duration` seconds (admin only). The contributor pulls vested
+    /// funds with `withdraw_vested`; the depositor may `cancel_vesting`.
+    pub fn release_vested(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+        duration: u64,
+    ) -> Result<(), soroban_sdk::Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused.into());
         }
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
+        if duration == 0 {
+            return Err(Error::InvalidAmount.into());
         }
-        let escrow: Escrow = env
+
+        let mut escrow: Escrow = env
             .storage()
             .persistent()
             .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
-        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
-        {
-            return Err(Error::FundsNotLocked);
+            .ok_or(Error::BountyNotFound)?;
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked.into());
         }
-        if env
-            .storage()
+        Self::require_not_blocked(&env, &contributor)?;
+        Self::apply_jurisdiction(&env, bounty_id, symbol_short!("release"))?;
+
+        let stream = VestingStream {
+            bounty_id,
+            contributor: contributor.clone(),
+            total: escrow.remaining_amount,
+            withdrawn: 0,
+            start: env.ledger().timestamp(),
+            duration,
+        };
+        escrow.status = EscrowStatus::PendingRelease;
+        env.storage()
             .persistent()
-            .has(&DataKey::PendingClaim(bounty_id))
-        {
-            let claim: ClaimRecord = env
-                .storage()
-                .persistent()
-                .get(&DataKey::PendingClaim(bounty_id))
-                .unwrap();
-            if !claim.claimed {
-                return Err(Error::ClaimPending);
-            }
-        }
-        let now = env.ledger().timestamp();
-        let approval_key = DataKey::RefundApproval(bounty_id);
-        let approval: Option<RefundApproval> = env.storage().persistent().get(&approval_key);
-        if now < escrow.deadline && approval.is_none() {
-            return Err(Error::DeadlineNotPassed);
-        }
-        let (refund_amount, _refund_to, is_full) = if let Some(app) = approval {
-            let full = app.mode == RefundMode::Full || app.amount >= escrow.remaining_amount;
-            (app.amount, app.recipient, full)
-        } else {
-            (escrow.remaining_amount, escrow.depositor.clone(), true)
-        };
-        if refund_amount <= 0 || refund_amount > escrow.remaining_amount {
-            return Err(Error::InvalidAmount);
-        }
-        let remaining_after = escrow
-            .remaining_amount
-            .checked_sub(refund_amount)
-            .unwrap_or(0);
-        let resulting_status = if is_full || remaining_after == 0 {
-            EscrowStatus::Refunded
-        } else {
-            EscrowStatus::PartiallyRefunded
-        };
-        Ok((refund_amount, resulting_status, remaining_after))
-    }
-
-    /// Sets or clears the anonymous resolver address.
-    /// Only the admin can call this. The resolver is the trusted entity that
-    /// resolves anonymous escrow refunds via `refund_resolved`.
-    pub fn set_anonymous_resolver(env: Env, resolver: Option<Address>) -> Result<(), Error> {
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
-        }
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+        Self::index_status(&env, bounty_id, &escrow.status);
+        Self::sync_escrow_accounting(&env, bounty_id, &escrow);
+        env.storage()
+            .persistent()
+            .set(&ReleaseKey::Vesting(bounty_id), &stream);
 
-        match resolver {
-            Some(addr) => env
-                .storage()
-                .instance()
-                .set(&DataKey::AnonymousResolver, &addr),
-            None => env.storage().instance().remove(&DataKey::AnonymousResolver),
-        }
+        events::emit_vesting_started(
+            &env,
+            events::VestingStarted {
+                bounty_id,
+                contributor,
+                total: stream.total,
+                start: stream.start,
+                duration,
+            },
+        );
         Ok(())
     }
 
-    /// Refund an anonymous escrow to a resolved recipient.
-    /// Only the configured anonymous resolver can call this; they resolve the depositor
-    /// commitment off-chain and pass the recipient address (signed instruction pattern).
-    pub fn refund_resolved(env: Env, bounty_id: u64, recipient: Address) -> Result<(), Error> {
-        if Self::check_paused(&env, symbol_short!("refund")) {
-            return Err(Error::FundsPaused);
+    /// Pay the contributor everything vested so far and not yet withdrawn.
+    /// Returns the amount paid. The escrow becomes `Released` once the whole
+    /// stream has been withdrawn.
+    pub fn withdraw_vested(env: Env, bounty_id: u64) -> Result<i128, soroban_sdk::Error> {
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused.into());
         }
-
-        let resolver: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::AnonymousResolver)
-            .ok_or(Error::AnonymousResolverNotSet)?;
-        resolver.require_auth();
-
-        if !env
+        let mut stream: VestingStream = env
             .storage()
             .persistent()
-            .has(&DataKey::EscrowAnon(bounty_id))
-        {
-            return Err(Error::NotAnonymousEscrow);
+            .get(&ReleaseKey::Vesting(bounty_id))
+            .ok_or(ReleaseError::VestingNotFound)?;
+        stream.contributor.require_auth();
+        Self::require_not_blocked(&env, &stream.contributor)?;
+
+        let amount = Self::vested_amount(&env, &stream) - stream.withdrawn;
+        if amount <= 0 {
+            return Err(ReleaseError::NothingVested.into());
         }
+        Self::require_payout_allowed(&env, bounty_id, &stream.contributor, amount)?;
 
         reentrancy_guard::acquire(&env);
-
-        let mut anon: AnonymousEscrow = env
+        let mut escrow: Escrow = env
             .storage()
             .persistent()
-            .get(&DataKey::EscrowAnon(bounty_id))
+            .get(&DataKey::Escrow(bounty_id))
             .unwrap();
 
-        if anon.status != EscrowStatus::Locked && anon.status != EscrowStatus::PartiallyRefunded {
-            return Err(Error::FundsNotLocked);
-        }
-
-        // GUARD 1: Block refund if there is a pending claim (Issue #391 fix)
-        if env
-            .storage()
-            .persistent()
-            .has(&DataKey::PendingClaim(bounty_id))
-        {
-            let claim: ClaimRecord = env
-                .storage()
+        // EFFECTS
+        stream.withdrawn += amount;
+        escrow.remaining_amount -= amount;
+        if stream.withdrawn == stream.total {
+            escrow.status = EscrowStatus::Released;
+            env.storage()
                 .persistent()
-                .get(&DataKey::PendingClaim(bounty_id))
-                .unwrap();
-            if !claim.claimed {
-                return Err(Error::ClaimPending);
-            }
-        }
-
-        let now = env.ledger().timestamp();
-        let approval_key = DataKey::RefundApproval(bounty_id);
-        let approval: Option<RefundApproval> = env.storage().persistent().get(&approval_key);
-
-        // Refund is allowed if:
-        // 1. Deadline has passed (returns full amount to depositor)
-        // 2. An administrative approval exists (can be early, partial, and to custom recipient)
-        if now < anon.deadline && approval.is_none() {
-            return Err(Error::DeadlineNotPassed);
-        }
-
-        let (refund_amount, refund_to, is_full) = if let Some(app) = approval.clone() {
-            let full = app.mode == RefundMode::Full || app.amount >= anon.remaining_amount;
-            (app.amount, app.recipient, full)
-        } else {
-            // Standard refund after deadline
-            (anon.remaining_amount, recipient.clone(), true)
-        };
-
-        if refund_amount <= 0 || refund_amount > anon.remaining_amount {
-            return Err(Error::InvalidAmount);
-        }
-
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-
-        // Transfer the calculated refund amount to the designated recipient
-        client.transfer(&env.current_contract_address(), &refund_to, &refund_amount);
-
-        // Anonymous escrow uses a parallel storage record and invariant model.
-        // Update escrow state: subtract the amount exactly refunded
-        anon.remaining_amount -= refund_amount;
-        if is_full || anon.remaining_amount == 0 {
-            anon.status = EscrowStatus::Refunded;
+                .remove(&ReleaseKey::Vesting(bounty_id));
         } else {
-            anon.status = EscrowStatus::PartiallyRefunded;
+            env.storage()
+                .persistent()
+                .set(&ReleaseKey::Vesting(bounty_id), &stream);
         }
-
-        // Add to refund history
-        anon.refund_history.push_back(RefundRecord {
-            amount: refund_amount,
-            recipient: refund_to.clone(),
-            timestamp: now,
-            mode: if is_full {
-                RefundMode::Full
-            } else {
-                RefundMode::Partial
-            },
-        });
-
-        // Save updated escrow
+        invariants::assert_escrow(&env, &escrow);
         env.storage()
             .persistent()
-            .set(&DataKey::EscrowAnon(bounty_id), &anon);
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+        Self::index_status(&env, bounty_id, &escrow.status);
+        Self::sync_escrow_accounting(&env, bounty_id, &escrow);
 
-        // Remove approval after successful execution
-        if approval.is_some() {
-            env.storage().persistent().remove(&approval_key);
-        }
+        // INTERACTION
+        let token_addr = Self::bounty_token(&env, bounty_id);
+        let client = token::Client::new(&env, &token_addr);
+        client.transfer(
+            &env.current_contract_address(),
+            &stream.contributor,
+            &amount,
+        );
+        threshold_monitor::record_outflow(&env, amount);
 
-        emit_funds_refunded(
+        events::emit_funds_released(
             &env,
-            FundsRefunded {
-                version: EVENT_VERSION_V2,
+            FundsReleased {
+                version: EVENT_VERSION_V3,
                 bounty_id,
-                amount: refund_amount,
-                refund_to: refund_to.clone(),
-                timestamp: now,
+                amount,
+                remaining_before: escrow.remaining_amount + amount,
+                remaining_after: escrow.remaining_amount,
+                recipient: stream.contributor.clone(),
+                timestamp: env.ledger().timestamp(),
             },
         );
-        Ok(())
-    }
-
-    /// Delegated refund path using a capability.
-    /// This can be used for short-lived, bounded delegated refunds without granting admin rights.
-    pub fn refund_with_capability(
-        env: Env,
-        bounty_id: u64,
-        amount: i128,
-        holder: Address,
-        capability_id: u64,
-    ) -> Result<(), Error> {
-        if Self::check_paused(&env, symbol_short!("refund")) {
-            return Err(Error::FundsPaused);
-        }
-        if amount <= 0 {
-            return Err(Error::InvalidAmount);
-        }
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
+        if stream.withdrawn == stream.total {
+            Self::record_paid_work(&env, bounty_id, &stream.contributor);
         }
 
+        reentrancy_guard::release(&env);
+        Ok(amount)
+    }
+
+    /// Stop a vesting stream (depositor only). The contributor is paid what
+    /// has vested but not been withdrawn and the unvested rest is refunded to
+    /// the depositor. Fails with `FundsPaused` while the leg being paid is
+    /// paused.
+    pub fn cancel_vesting(env: Env, bounty_id: u64) -> Result<(), soroban_sdk::Error> {
+        let stream: VestingStream = env
+            .storage()
+            .persistent()
+            .get(&ReleaseKey::Vesting(bounty_id))
+            .ok_or(ReleaseError::VestingNotFound)?;
         let mut escrow: Escrow = env
             .storage()
             .persistent()
             .get(&DataKey::Escrow(bounty_id))
             .unwrap();
+        escrow.depositor.require_auth();
+        Self::require_not_blocked(&env, &escrow.depositor)?;
 
-        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
-        {
-            return Err(Error::FundsNotLocked);
-        }
-        if amount > escrow.remaining_amount {
-            return Err(Error::InvalidAmount);
-        }
-
-        if env
-            .storage()
-            .persistent()
-            .has(&DataKey::PendingClaim(bounty_id))
-        {
-            let claim: ClaimRecord = env
-                .storage()
-                .persistent()
-                .get(&DataKey::PendingClaim(bounty_id))
-                .unwrap();
-            if !claim.claimed {
-                return Err(Error::ClaimPending);
-            }
-        }
-
-        Self::consume_capability(
+        let vested = Self::vested_amount(&env, &stream);
+        let contributor_amount = vested - stream.withdrawn;
+        let depositor_amount = stream.total - vested;
+        Self::require_settlement_allowed(
             &env,
-            &holder,
-            capability_id,
-            CapabilityAction::Refund,
             bounty_id,
-            amount,
+            &stream.contributor,
+            contributor_amount,
+            depositor_amount,
         )?;
-
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
         let now = env.ledger().timestamp();
-        let refund_to = escrow.depositor.clone();
 
-        client.transfer(&env.current_contract_address(), &refund_to, &amount);
+        reentrancy_guard::acquire(&env);
 
-        escrow.remaining_amount -= amount;
-        if escrow.remaining_amount == 0 {
-            escrow.status = EscrowStatus::Refunded;
+        // EFFECTS
+        escrow.remaining_amount = 0;
+        escrow.status = if vested > 0 {
+            EscrowStatus::Released
         } else {
-            escrow.status = EscrowStatus::PartiallyRefunded;
+            EscrowStatus::Refunded
+        };
+        if depositor_amount > 0 {
+            escrow.refund_history.push_back(RefundRecord {
+                amount: depositor_amount,
+                recipient: escrow.depositor.clone(),
+                timestamp: now,
+                mode: if vested > 0 {
+                    RefundMode::Partial
+                } else {
+                    RefundMode::Full
+                },
+            });
         }
-
-        escrow.refund_history.push_back(RefundRecord {
-            amount,
-            recipient: refund_to.clone(),
-            timestamp: now,
-            mode: if escrow.status == EscrowStatus::Refunded {
-                RefundMode::Full
-            } else {
-                RefundMode::Partial
-            },
-        });
-
+        invariants::assert_escrow(&env, &escrow);
         env.storage()
             .persistent()
             .set(&DataKey::Escrow(bounty_id), &escrow);
+        Self::index_status(&env, bounty_id, &escrow.status);
+        Self::sync_escrow_accounting(&env, bounty_id, &escrow);
+        env.storage()
+            .persistent()
+            .remove(&ReleaseKey::Vesting(bounty_id));
 
-        emit_funds_refunded(
+        // INTERACTIONS
+        let token_addr = Self::bounty_token(&env, bounty_id);
+        let client = token::Client::new(&env, &token_addr);
+        if contributor_amount > 0 {
+            client.transfer(
+                &env.current_contract_address(),
+                &stream.contributor,
+                &contributor_amount,
+            );
+        }
+        if depositor_amount > 0 {
+            client.transfer(
+                &env.current_contract_address(),
+                &escrow.depositor,
+                &depositor_amount,
+            );
+        }
+        if vested > 0 {
+            Self::record_paid_work(&env, bounty_id, &stream.contributor);
+        }
+
+        events::emit_vesting_cancelled(
             &env,
-            FundsRefunded {
-                version: EVENT_VERSION_V2,
+            events::VestingCancelled {
+                version: EVENT_VERSION_V3,
                 bounty_id,
-                amount,
-                refund_to,
-                timestamp: now,
+                contributor: stream.contributor,
+                contributor_amount,
+                depositor_amount,
+                remaining_before: contributor_amount + depositor_amount,
+                remaining_after: escrow.remaining_amount,
+                cancelled_at: now,
             },
         );
 
+        reentrancy_guard::release(&env);
         Ok(())
     }
 
-    /// view function to get escrow info
-    pub fn get_escrow_info(env: Env, bounty_id: u64) -> Result<Escrow, Error> {
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
-        }
-        Ok(env
-            .storage()
+    /// The vesting stream for `bounty_id`, if any.
+    pub fn get_vesting(env: Env, bounty_id: u64) -> Option<VestingStream> {
+        env.storage()
             .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap())
+            .get(&ReleaseKey::Vesting(bounty_id))
     }
 
-    /// view function to get contract balance of the token
-    pub fn get_balance(env: Env) -> Result<i128, Error> {
-        if !env.storage().instance().has(&DataKey::Token) {
-            return Err(Error::NotInitialized);
-        }
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-        Ok(client.balance(&env.current_contract_address()))
-    }
-
-    /// Query escrows with filtering and pagination
-    /// Pass 0 for min values and i128::MAX/u64::MAX for max values to disable those filters
-    pub fn query_escrows_by_status(
-        env: Env,
-        status: EscrowStatus,
-        offset: u32,
-        limit: u32,
-    ) -> Vec<EscrowWithId> {
-        let index: Vec<u64> = env
-            .storage()
+    /// Amount the contributor could withdraw right now.
+    pub fn get_withdrawable_vested(env: Env, bounty_id: u64) -> i128 {
+        env.storage()
             .persistent()
-            .get(&DataKey::EscrowIndex)
-            .unwrap_or(Vec::new(&env));
-        let mut results = Vec::new(&env);
-        let mut count = 0u32;
-        let mut skipped = 0u32;
-
-        for i in 0..index.len() {
-            if count >= limit {
-                break;
-            }
+            .get::<_, VestingStream>(&ReleaseKey::Vesting(bounty_id))
+            .map(|stream| Self::vested_amount(&env, &stream) - stream.withdrawn)
+            .unwrap_or(0)
+    }
 
-            let bounty_id = index.get(i).unwrap();
-            if let Some(escrow) = env
-                .storage()
-                .persistent()
-                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
-            {
-                if escrow.status == status {
-                    if skipped < offset {
-                        skipped += 1;
-                        continue;
-                    }
-                    results.push_back(EscrowWithId { bounty_id, escrow });
-                    count += 1;
-                }
-            }
+    /// Internal: portion of the stream vested at the current ledger time.
+    fn vested_amount(env: &Env, stream: &VestingStream) -> i128 {
+        let elapsed = env.ledger().timestamp().saturating_sub(stream.start);
+        if elapsed >= stream.duration {
+            return stream.total;
         }
-        results
+        stream.total * elapsed as i128 / stream.duration as i128
     }
 
-    /// Query escrows with amount range filtering
-    pub fn query_escrows_by_amount(
-        env: Env,
-        min_amount: i128,
-        max_amount: i128,
-        offset: u32,
-        limit: u32,
-    ) -> Vec<EscrowWithId> {
-        let index: Vec<u64> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::EscrowIndex)
-            .unwrap_or(Vec::new(&env));
-        let mut results = Vec::new(&env);
-        let mut count = 0u32;
-        let mut skipped = 0u32;
+    /// Replace the arbiter panel (admin only). Any listed arbiter may rule
+    /// on a disputed release.
+    pub fn set_arbiters(env: Env, arbiters: Vec<Address>) -> Result<(), soroban_sdk::Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized.into());
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
 
-        for i in 0..index.len() {
-            if count >= limit {
-                break;
-            }
+        env.storage()
+            .instance()
+            .set(&ReleaseKey::Arbiters, &arbiters);
+        Ok(())
+    }
 
-            let bounty_id = index.get(i).unwrap();
-            if let Some(escrow) = env
-                .storage()
-                .persistent()
-                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
-            {
-                if escrow.amount >= min_amount && escrow.amount <= max_amount {
-                    if skipped < offset {
-                        skipped += 1;
-                        continue;
-                    }
-                    results.push_back(EscrowWithId { bounty_id, escrow });
-                    count += 1;
-                }
-            }
-        }
-        results
+    /// Get the arbiter panel.
+    pub fn get_arbiters(env: Env) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&ReleaseKey::Arbiters)
+            .unwrap_or(vec![&env])
     }
 
-    /// Query escrows with deadline range filtering
-    pub fn query_escrows_by_deadline(
+    /// Rule on a disputed release (arbiter only).
+    ///
+    /// `UpholdRelease` pays the contributor through the normal release path,
+    /// fees included. `RefundDepositor` and `Split` move the remaining escrow
+    /// directly without a release fee, subject to the same pause flags and
+    /// payout checks as a release or refund. The ruling is stored for later
+    /// lookup.
+    pub fn resolve_dispute(
         env: Env,
-        min_deadline: u64,
-        max_deadline: u64,
-        offset: u32,
-        limit: u32,
-    ) -> Vec<EscrowWithId> {
-        let index: Vec<u64> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::EscrowIndex)
-            .unwrap_or(Vec::new(&env));
-        let mut results = Vec::new(&env);
-        let mut count = 0u32;
-        let mut skipped = 0u32;
-
-        for i in 0..index.len() {
-            if count >= limit {
-                break;
-            }
-
-            let bounty_id = index.get(i).unwrap();
-            if let Some(escrow) = env
-                .storage()
-                .persistent()
-                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
-            {
-                if escrow.deadline >= min_deadline && escrow.deadline <= max_deadline {
-                    if skipped < offset {
-                        skipped += 1;
-                        continue;
-                    }
-                    results.push_back(EscrowWithId { bounty_id, escrow });
-                    count += 1;
-                }
-            }
+        bounty_id: u64,
+        arbiter: Address,
+        ruling: DisputeRuling,
+    ) -> Result<(), soroban_sdk::Error> {
+        if !Self::get_arbiters(env.clone()).contains(&arbiter) {
+            return Err(Error::Unauthorized.into());
         }
-        results
-    }
+        arbiter.require_auth();
 
-    /// Query escrows by depositor
-    pub fn query_escrows_by_depositor(
-        env: Env,
-        depositor: Address,
-        offset: u32,
-        limit: u32,
-    ) -> Vec<EscrowWithId> {
-        let index: Vec<u64> = env
+        let pending: PendingRelease = env
             .storage()
             .persistent()
-            .get(&DataKey::DepositorIndex(depositor))
-            .unwrap_or(Vec::new(&env));
-        let mut results = Vec::new(&env);
-        let start = offset.min(index.len());
-        let end = (offset + limit).min(index.len());
-
-        for i in start..end {
-            let bounty_id = index.get(i).unwrap();
-            if let Some(escrow) = env
-                .storage()
-                .persistent()
-                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
-            {
-                results.push_back(EscrowWithId { bounty_id, escrow });
-            }
+            .get(&ReleaseKey::PendingRelease(bounty_id))
+            .ok_or(ReleaseError::PendingReleaseNotFound)?;
+        if !pending.disputed {
+            return Err(ReleaseError::ReleaseNotDisputed.into());
         }
-        results
-    }
 
-    /// Get aggregate statistics
-    pub fn get_aggregate_stats(env: Env) -> AggregateStats {
-        let index: Vec<u64> = env
+        let remaining_before = env
             .storage()
             .persistent()
-            .get(&DataKey::EscrowIndex)
-            .unwrap_or(Vec::new(&env));
-        let mut stats = AggregateStats {
-            total_locked: 0,
-            total_released: 0,
-            total_refunded: 0,
-            count_locked: 0,
-            count_released: 0,
-            count_refunded: 0,
-        };
-
-        for i in 0..index.len() {
-            let bounty_id = index.get(i).unwrap();
-            if let Some(escrow) = env
-                .storage()
-                .persistent()
-                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
-            {
-                match escrow.status {
-                    EscrowStatus::Locked => {
-                        stats.total_locked += escrow.amount;
-                        stats.count_locked += 1;
-                    }
-                    EscrowStatus::Released => {
-                        stats.total_released += escrow.amount;
-                        stats.count_released += 1;
-                    }
-                    EscrowStatus::Refunded | EscrowStatus::PartiallyRefunded => {
-                        stats.total_refunded += escrow.amount;
-                        stats.count_refunded += 1;
-                    }
-                }
-            }
-        }
-        stats
-    }
-
-    /// Get total count of escrows
-    pub fn get_escrow_count(env: Env) -> u32 {
-        let index: Vec<u64> = env
+            .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+            .map(|escrow| escrow.remaining_amount)
+            .unwrap_or(0);
+        reentrancy_guard::acquire(&env);
+        let res = Self::apply_ruling(&env, &pending, &ruling);
+        reentrancy_guard::release(&env);
+        let (contributor_amount, depositor_amount) = res?;
+        let remaining_after = env
             .storage()
             .persistent()
-            .get(&DataKey::EscrowIndex)
-            .unwrap_or(Vec::new(&env));
-        index.len()
-    }
-
-    /// Set the minimum and maximum allowed lock amount (admin only).
-    ///
-    /// Once set, any call to lock_funds with an amount outside [min_amount, max_amount]
-    /// will be rejected with AmountBelowMinimum or AmountAboveMaximum respectively.
-    /// The policy can be updated at any time by the admin; new limits take effect
-    /// immediately for subsequent lock_funds calls.
-    ///
-    /// Passing min_amount == max_amount restricts locking to a single exact value.
-    /// min_amount must not exceed max_amount — the call panics if this invariant
-    /// is violated.
-    pub fn set_amount_policy(
-        env: Env,
-        caller: Address,
-        min_amount: i128,
-        max_amount: i128,
-    ) -> Result<(), Error> {
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
-        }
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        if caller != admin {
-            return Err(Error::Unauthorized);
-        }
-        admin.require_auth();
-
-        if min_amount > max_amount {
-            panic!("invalid policy: min_amount cannot exceed max_amount");
-        }
+            .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+            .map(|escrow| escrow.remaining_amount)
+            .unwrap_or(0);
 
-        // Persist the policy so lock_funds can enforce it on every subsequent call.
+        let now = env.ledger().timestamp();
+        let record = DisputeRecord {
+            bounty_id,
+            arbiter: arbiter.clone(),
+            ruling: ruling.clone(),
+            contributor: pending.contributor.clone(),
+            contributor_amount,
+            depositor_amount,
+            reason_hash: pending.reason_hash.clone(),
+            ruled_at: now,
+        };
         env.storage()
-            .instance()
-            .set(&DataKey::AmountPolicy, &(min_amount, max_amount));
+            .persistent()
+            .set(&ReleaseKey::DisputeRecord(bounty_id), &record);
+
+        let rulings_key = ReleaseKey::ArbiterRulings(arbiter.clone());
+        let mut rulings: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&rulings_key)
+            .unwrap_or(vec![&env]);
+        rulings.push_back(bounty_id);
+        env.storage().persistent().set(&rulings_key, &rulings);
 
+        events::emit_dispute_resolved(
+            &env,
+            events::DisputeResolved {
+                version: EVENT_VERSION_V3,
+                bounty_id,
+                arbiter,
+                ruling,
+                contributor_amount,
+                depositor_amount,
+                remaining_before,
+                remaining_after,
+                ruled_at: now,
+            },
+        );
         Ok(())
     }
 
-    /// Get escrow IDs by status
-    pub fn get_escrow_ids_by_status(
-        env: Env,
-        status: EscrowStatus,
-        offset: u32,
-        limit: u32,
-    ) -> Vec<u64> {
-        let index: Vec<u64> = env
+    /// Move the funds of a disputed release according to `ruling`.
+    /// Returns `(contributor_amount, depositor_amount)`.
+    fn apply_ruling(
+        env: &Env,
+        pending: &PendingRelease,
+        ruling: &DisputeRuling,
+    ) -> Result<(i128, i128), soroban_sdk::Error> {
+        let bounty_id = pending.bounty_id;
+        let mut escrow: Escrow = env
             .storage()
             .persistent()
-            .get(&DataKey::EscrowIndex)
-            .unwrap_or(Vec::new(&env));
-        let mut results = Vec::new(&env);
-        let mut count = 0u32;
-        let mut skipped = 0u32;
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        let total = escrow.remaining_amount;
 
-        for i in 0..index.len() {
-            if count >= limit {
-                break;
+        let contributor_bps: i128 = match ruling {
+            DisputeRuling::UpholdRelease => {
+                if Self::check_paused(env, symbol_short!("release")) {
+                    return Err(Error::FundsPaused.into());
+                }
+                escrow.status = EscrowStatus::Locked;
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::Escrow(bounty_id), &escrow);
+                Self::index_status(env, bounty_id, &escrow.status);
+                Self::sync_escrow_accounting(env, bounty_id, &escrow);
+                env.storage()
+                    .persistent()
+                    .remove(&ReleaseKey::PendingRelease(bounty_id));
+                Self::execute_release(env, bounty_id, pending.contributor.clone())?;
+                return Ok((total, 0));
             }
-            let bounty_id = index.get(i).unwrap();
-            if let Some(escrow) = env
-                .storage()
-                .persistent()
-                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
-            {
-                if escrow.status == status {
-                    if skipped < offset {
-                        skipped += 1;
-                        continue;
-                    }
-                    results.push_back(bounty_id);
-                    count += 1;
+            DisputeRuling::RefundDepositor => 0,
+            DisputeRuling::Split(bps) => {
+                if *bps > BASIS_POINTS as u32 {
+                    return Err(Error::InvalidAmount.into());
                 }
+                *bps as i128
             }
-        }
-        results
-    }
-
-    pub fn set_anti_abuse_admin(env: Env, admin: Address) -> Result<(), Error> {
-        let current: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .ok_or(Error::NotInitialized)?;
-        current.require_auth();
-        anti_abuse::set_admin(&env, admin);
-        Ok(())
-    }
-
-    pub fn get_anti_abuse_admin(env: Env) -> Option<Address> {
-        anti_abuse::get_admin(&env)
-    }
+        };
 
-    /// Set whitelist status for an address (admin only). Named to avoid SDK client method conflict.
-    /// In AllowlistOnly mode this determines who may participate; in other modes it only affects anti-abuse bypass.
-    pub fn set_whitelist_entry(
-        env: Env,
-        whitelisted_address: Address,
-        whitelisted: bool,
-    ) -> Result<(), Error> {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .ok_or(Error::NotInitialized)?;
-        admin.require_auth();
-        anti_abuse::set_whitelist(&env, whitelisted_address, whitelisted);
-        Ok(())
-    }
+        let contributor_amount = total
+            .checked_mul(contributor_bps)
+            .and_then(|x| x.checked_div(BASIS_POINTS))
+            .ok_or(Error::InvalidAmount)?;
+        let depositor_amount = total - contributor_amount;
+        Self::require_settlement_allowed(
+            env,
+            bounty_id,
+            &pending.contributor,
+            contributor_amount,
+            depositor_amount,
+        )?;
+        let now = env.ledger().timestamp();
 
-    /// Set participant filter mode (admin only). Mutually exclusive: Disabled, BlocklistOnly, or AllowlistOnly.
-    /// Emits ParticipantFilterModeChanged. Transitioning modes does not clear list data; only the active mode is enforced.
-    pub fn set_filter_mode(env: Env, new_mode: ParticipantFilterMode) -> Result<(), Error> {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .ok_or(Error::NotInitialized)?;
-        admin.require_auth();
-        let previous = Self::get_participant_filter_mode(&env);
+        // EFFECTS
+        escrow.remaining_amount = 0;
+        escrow.status = if contributor_amount > 0 {
+            EscrowStatus::Released
+        } else {
+            EscrowStatus::Refunded
+        };
+        if depositor_amount > 0 {
+            escrow.refund_history.push_back(RefundRecord {
+                amount: depositor_amount,
+                recipient: escrow.depositor.clone(),
+                timestamp: now,
+                mode: if contributor_amount > 0 {
+                    RefundMode::Partial
+                } else {
+                    RefundMode::Full
+                },
+            });
+        }
+        invariants::assert_escrow(env, &escrow);
         env.storage()
-            .instance()
-            .set(&DataKey::ParticipantFilterMode, &new_mode);
-        emit_participant_filter_mode_changed(
-            &env,
-            ParticipantFilterModeChanged {
-                previous_mode: previous,
-                new_mode,
-                admin: admin.clone(),
-                timestamp: env.ledger().timestamp(),
-            },
-        );
-        Ok(())
-    }
-
-    /// View: current participant filter mode (default Disabled).
-    pub fn get_filter_mode(env: Env) -> ParticipantFilterMode {
-        Self::get_participant_filter_mode(&env)
-    }
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+        Self::index_status(env, bounty_id, &escrow.status);
+        Self::sync_escrow_accounting(env, bounty_id, &escrow);
+        env.storage()
+            .persistent()
+            .remove(&ReleaseKey::PendingRelease(bounty_id));
+        if contributor_amount > 0 {
+            Self::record_paid_work(env, bounty_id, &pending.contributor);
+        }
 
-    /// Set blocklist status for an address (admin only). Only enforced when mode is BlocklistOnly.
-    pub fn set_blocklist_entry(env: Env, address: Address, blocked: bool) -> Result<(), Error> {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .ok_or(Error::NotInitialized)?;
-        admin.require_auth();
-        anti_abuse::set_blocklist(&env, address, blocked);
-        Ok(())
+        // INTERACTIONS
+        let token_addr = Self::bounty_token(env, bounty_id);
+        let client = token::Client::new(env, &token_addr);
+        if contributor_amount > 0 {
+            client.transfer(
+                &env.current_contract_address(),
+                &pending.contributor,
+                &contributor_amount,
+            );
+        }
+        if depositor_amount > 0 {
+            client.transfer(
+                &env.current_contract_address(),
+                &escrow.depositor,
+                &depositor_amount,
+            );
+        }
+        if contributor_amount > 0 {
+            events::emit_funds_released(
+                env,
+                FundsReleased {
+                    version: EVENT_VERSION_V3,
+                    bounty_id,
+                    amount: contributor_amount,
+                    remaining_before: total,
+                    remaining_after: depositor_amount,
+                    recipient: pending.contributor.clone(),
+                    timestamp: now,
+                },
+            );
+        }
+        if depositor_amount > 0 {
+            events::emit_funds_refunded(
+                env,
+                FundsRefunded {
+                    version: EVENT_VERSION_V3,
+                    bounty_id,
+                    amount: depositor_amount,
+                    remaining_before: depositor_amount,
+                    remaining_after: 0,
+                    refund_to: escrow.depositor.clone(),
+                    timestamp: now,
+                },
+            );
+        }
+        Ok((contributor_amount, depositor_amount))
     }
 
-    /// Update anti-abuse config (rate limit window, max operations per window, cooldown). Admin only.
-    pub fn update_anti_abuse_config(
-        env: Env,
-        window_size: u64,
-        max_operations: u32,
-        cooldown_period: u64,
-    ) -> Result<(), Error> {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .ok_or(Error::NotInitialized)?;
-        admin.require_auth();
-        let config = anti_abuse::AntiAbuseConfig {
-            window_size,
-            max_operations,
-            cooldown_period,
-        };
-        anti_abuse::set_config(&env, config);
-        Ok(())
+    /// Get the arbiter ruling recorded for a bounty, if any.
+    pub fn get_dispute_record(env: Env, bounty_id: u64) -> Option<DisputeRecord> {
+        env.storage()
+            .persistent()
+            .get(&ReleaseKey::DisputeRecord(bounty_id))
     }
 
-    /// Get current anti-abuse config (rate limit and cooldown).
-    pub fn get_anti_abuse_config(env: Env) -> AntiAbuseConfigView {
-        let c = anti_abuse::get_config(&env);
-        AntiAbuseConfigView {
-            window_size: c.window_size,
-            max_operations: c.max_operations,
-            cooldown_period: c.cooldown_period,
-        }
+    /// Bounty ids an arbiter has ruled on, oldest first.
+    pub fn get_arbiter_rulings(env: Env, arbiter: Address) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&ReleaseKey::ArbiterRulings(arbiter))
+            .unwrap_or(vec![&env])
     }
 
-    /// Retrieves the refund history for a specific bounty.
+    /// Simulate release operation without state changes or token transfers.
+    ///
+    /// Returns a `SimulationResult` indicating whether the operation would succeed and the
+    /// resulting escrow state. Does not require authorization; safe for off-chain preview.
     ///
     /// # Arguments
-    /// * `env` - The contract environment
-    /// * `bounty_id` - The bounty to query
+    /// * `bounty_id` - Bounty identifier
+    /// * `contributor` - Recipient address
     ///
-    /// # Returns
-    /// * `Ok(Vec<RefundRecord>)` - The refund history
-    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
-    pub fn get_refund_history(env: Env, bounty_id: u64) -> Result<Vec<RefundRecord>, Error> {
+    /// # Security
+    /// This function performs only read operations. No storage writes, token transfers,
+    /// or events are emitted.
+    pub fn dry_run_release(env: Env, bounty_id: u64, contributor: Address) -> SimulationResult {
+        fn err_result(e: soroban_sdk::Error) -> SimulationResult {
+            SimulationResult {
+                success: false,
+                error_code: e.get_code(),
+                amount: 0,
+                resulting_status: EscrowStatus::Released,
+                remaining_amount: 0,
+            }
+        }
+        match Self::dry_run_release_impl(&env, bounty_id, contributor) {
+            Ok((amount,)) => SimulationResult {
+                success: true,
+                error_code: 0,
+                amount,
+                resulting_status: EscrowStatus::Released,
+                remaining_amount: 0,
+            },
+            Err(e) => err_result(e),
+        }
+    }
+
+    fn dry_run_release_impl(
+        env: &Env,
+        bounty_id: u64,
+        _contributor: Address,
+    ) -> Result<(i128,), soroban_sdk::Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized.into());
+        }
+        if Self::check_paused(env, symbol_short!("release")) {
+            return Err(Error::FundsPaused.into());
+        }
         if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
+            return Err(Error::BountyNotFound.into());
         }
         let escrow: Escrow = env
             .storage()
             .persistent()
             .get(&DataKey::Escrow(bounty_id))
             .unwrap();
-        Ok(escrow.refund_history)
-    }
-
-    /// NEW: Verify escrow invariants for a specific bounty
-    pub fn verify_state(env: Env, bounty_id: u64) -> bool {
-        if let Some(escrow) = env
-            .storage()
-            .persistent()
-            .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
-        {
-            invariants::verify_escrow_invariants(&escrow)
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked.into());
+        }
+        let token_addr = Self::bounty_token(env, bounty_id);
+        let (_lock_fee_rate, release_fee_rate, _fee_recipient, fee_enabled) =
+            Self::resolve_fee_config_for_token(env, &token_addr);
+        let payout_amount = escrow.remaining_amount;
+        let release_fee = if fee_enabled && release_fee_rate > 0 {
+            Self::calculate_fee(payout_amount, release_fee_rate)
         } else {
-            false
+            0
+        };
+        let net_payout = payout_amount
+            .checked_sub(release_fee)
+            .unwrap_or(payout_amount);
+        if net_payout <= 0 {
+            return Err(Error::InvalidAmount.into());
         }
+        Ok((payout_amount,))
     }
-    /// Gets refund eligibility information for a bounty.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `bounty_id` - The bounty to query
-    ///
-    /// # Returns
-    /// * `Ok((bool, bool, i128, Option<RefundApproval>))` - Tuple containing:
-    ///   - can_refund: Whether refund is possible
-    ///   - deadline_passed: Whether the deadline has passed
-    ///   - remaining: Remaining amount in escrow
-    ///   - approval: Optional refund approval if exists
-    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
-    pub fn get_refund_eligibility(
+
+    /// Delegated release flow using a capability instead of admin auth.
+    /// The capability amount limit is consumed by `payout_amount`.
+    pub fn release_with_capability(
         env: Env,
         bounty_id: u64,
-    ) -> Result<(bool, bool, i128, Option<RefundApproval>), Error> {
+        contributor: Address,
+        payout_amount: i128,
+        holder: Address,
+        capability_id: u64,
+    ) -> Result<(), soroban_sdk::Error> {
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused.into());
+        }
+        if payout_amount <= 0 {
+            return Err(Error::InvalidAmount.into());
+        }
         if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
+            return Err(Error::BountyNotFound.into());
         }
-        let escrow: Escrow = env
+
+        let mut escrow: Escrow = env
             .storage()
             .persistent()
             .get(&DataKey::Escrow(bounty_id))
             .unwrap();
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked.into());
+        }
+        if payout_amount > escrow.remaining_amount {
+            return Err(Error::InsufficientFunds.into());
+        }
+        Self::require_payout_allowed(&env, bounty_id, &contributor, payout_amount)?;
 
-        let now = env.ledger().timestamp();
+        Self::consume_capability(
+            &env,
+            &holder,
+            capability_id,
+            CapabilityAction::Release,
+            bounty_id,
+            payout_amount,
+        )?;
+
+        let token_addr = Self::bounty_token(&env, bounty_id);
+        let client = token::Client::new(&env, &token_addr);
+        client.transfer(
+            &env.current_contract_address(),
+            &contributor,
+            &payout_amount,
+        );
+
+        escrow.remaining_amount -= payout_amount;
+        if escrow.remaining_amount == 0 {
+            escrow.status = EscrowStatus::Released;
+            Self::return_claim_bond(&env, &client, bounty_id, &contributor);
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+        Self::index_status(&env, bounty_id, &escrow.status);
+        Self::sync_escrow_accounting(&env, bounty_id, &escrow);
+        threshold_monitor::record_outflow(&env, payout_amount);
+
+        emit_funds_released(
+            &env,
+            FundsReleased {
+                version: EVENT_VERSION_V3,
+                bounty_id,
+                amount: payout_amount,
+                remaining_before: escrow.remaining_amount + payout_amount,
+                remaining_after: escrow.remaining_amount,
+                recipient: contributor,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Set the claim window duration (admin only).
+    /// claim_window: seconds beneficiary has to claim after release is authorized.
+    pub fn set_claim_window(env: Env, claim_window: u64) -> Result<(), soroban_sdk::Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized.into());
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        env.storage()
+            .instance()
+            .set(&DataKey::ClaimWindow, &claim_window);
+        Ok(())
+    }
+
+    /// Admin can authorize a release as a pending claim instead of immediate transfer.
+    pub fn authorize_claim(
+        env: Env,
+        bounty_id: u64,
+        recipient: Address,
+        reason: DisputeReason,
+    ) -> Result<(), soroban_sdk::Error> {
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused.into());
+        }
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized.into());
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound.into());
+        }
+
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked.into());
+        }
+
+        let now = env.ledger().timestamp();
+        let claim_window: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::ClaimWindow)
+            .unwrap_or(0);
+        let claim = ClaimRecord {
+            bounty_id,
+            recipient: recipient.clone(),
+            amount: escrow.remaining_amount,
+            expires_at: now.saturating_add(claim_window),
+            claimed: false,
+            reason: reason.clone(),
+        };
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::PendingClaim(bounty_id), &claim);
+
+        env.events().publish(
+            (symbol_short!("claim"), symbol_short!("created")),
+            ClaimCreated {
+                bounty_id,
+                recipient,
+                amount: claim.amount,
+                expires_at: claim.expires_at,
+            },
+        );
+        Ok(())
+    }
+
+    /// Beneficiary calls this to claim their authorized funds within the window.
+    pub fn claim(env: Env, bounty_id: u64) -> Result<(), soroban_sdk::Error> {
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused.into());
+        }
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::PendingClaim(bounty_id))
+        {
+            return Err(Error::BountyNotFound.into());
+        }
+        let mut claim: ClaimRecord = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PendingClaim(bounty_id))
+            .unwrap();
+
+        claim.recipient.require_auth();
+        Self::require_payout_allowed(&env, bounty_id, &claim.recipient, claim.amount)?;
+
+        let now = env.ledger().timestamp();
+        if now > claim.expires_at {
+            return Err(Error::DeadlineNotPassed.into()); // reuse or add ClaimExpired error
+        }
+        if claim.claimed {
+            return Err(Error::FundsNotLocked.into());
+        }
+
+        let token_addr = Self::bounty_token(&env, bounty_id);
+        let client = token::Client::new(&env, &token_addr);
+        client.transfer(
+            &env.current_contract_address(),
+            &claim.recipient,
+            &claim.amount,
+        );
+        threshold_monitor::record_outflow(&env, claim.amount);
+
+        // Update escrow status
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+        let remaining_before = escrow.remaining_amount;
+        escrow.remaining_amount = (remaining_before - claim.amount).max(0);
+        escrow.status = EscrowStatus::Released;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+        Self::index_status(&env, bounty_id, &escrow.status);
+        Self::sync_escrow_accounting(&env, bounty_id, &escrow);
+
+        claim.claimed = true;
+        env.storage()
+            .persistent()
+            .set(&DataKey::PendingClaim(bounty_id), &claim);
+
+        env.events().publish(
+            (symbol_short!("claim"), symbol_short!("done")),
+            ClaimExecuted {
+                version: EVENT_VERSION_V3,
+                bounty_id,
+                recipient: claim.recipient.clone(),
+                amount: claim.amount,
+                remaining_before,
+                remaining_after: escrow.remaining_amount,
+                claimed_at: now,
+            },
+        );
+        Ok(())
+    }
+
+    /// Delegated claim execution using a capability.
+    /// Funds are still transferred to the pending claim recipient.
+    pub fn claim_with_capability(
+        env: Env,
+        bounty_id: u64,
+        holder: Address,
+        capability_id: u64,
+    ) -> Result<(), soroban_sdk::Error> {
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused.into());
+        }
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::PendingClaim(bounty_id))
+        {
+            return Err(Error::BountyNotFound.into());
+        }
+
+        let mut claim: ClaimRecord = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PendingClaim(bounty_id))
+            .unwrap();
+
+        let now = env.ledger().timestamp();
+        if now > claim.expires_at {
+            return Err(Error::DeadlineNotPassed.into());
+        }
+        if claim.claimed {
+            return Err(Error::FundsNotLocked.into());
+        }
+        Self::require_payout_allowed(&env, bounty_id, &claim.recipient, claim.amount)?;
+
+        Self::consume_capability(
+            &env,
+            &holder,
+            capability_id,
+            CapabilityAction::Claim,
+            bounty_id,
+            claim.amount,
+        )?;
+
+        let token_addr = Self::bounty_token(&env, bounty_id);
+        let client = token::Client::new(&env, &token_addr);
+        client.transfer(
+            &env.current_contract_address(),
+            &claim.recipient,
+            &claim.amount,
+        );
+        threshold_monitor::record_outflow(&env, claim.amount);
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+        let remaining_before = escrow.remaining_amount;
+        escrow.remaining_amount = (remaining_before - claim.amount).max(0);
+        escrow.status = EscrowStatus::Released;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+        Self::index_status(&env, bounty_id, &escrow.status);
+        Self::sync_escrow_accounting(&env, bounty_id, &escrow);
+
+        claim.claimed = true;
+        env.storage()
+            .persistent()
+            .set(&DataKey::PendingClaim(bounty_id), &claim);
+
+        env.events().publish(
+            (symbol_short!("claim"), symbol_short!("done")),
+            ClaimExecuted {
+                version: EVENT_VERSION_V3,
+                bounty_id,
+                recipient: claim.recipient,
+                amount: claim.amount,
+                remaining_before,
+                remaining_after: escrow.remaining_amount,
+                claimed_at: now,
+            },
+        );
+        Ok(())
+    }
+
+    /// Admin can cancel an expired or unwanted pending claim, returning escrow to Locked.
+    pub fn cancel_pending_claim(
+        env: Env,
+        bounty_id: u64,
+        outcome: DisputeOutcome,
+    ) -> Result<(), soroban_sdk::Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized.into());
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::PendingClaim(bounty_id))
+        {
+            return Err(Error::BountyNotFound.into());
+        }
+        let claim: ClaimRecord = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PendingClaim(bounty_id))
+            .unwrap();
+
+        let now = env.ledger().timestamp(); // Added this line
+        let recipient = claim.recipient.clone(); // Added this line
+        let amount = claim.amount; // Added this line
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::PendingClaim(bounty_id));
+
+        env.events().publish(
+            (symbol_short!("claim"), symbol_short!("cancel")),
+            ClaimCancelled {
+                bounty_id,
+                recipient,
+                amount,
+                cancelled_at: now,
+                cancelled_by: admin,
+            },
+        );
+        Ok(())
+    }
+
+    /// View: get pending claim for a bounty.
+    pub fn get_pending_claim(env: Env, bounty_id: u64) -> Result<ClaimRecord, soroban_sdk::Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PendingClaim(bounty_id))
+            .ok_or(Error::BountyNotFound.into())
+    }
+
+    /// Approve a refund before deadline (admin only).
+    /// This allows early refunds with admin approval.
+    pub fn approve_refund(
+        env: Env,
+        bounty_id: u64,
+        amount: i128,
+        recipient: Address,
+        mode: RefundMode,
+    ) -> Result<(), soroban_sdk::Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized.into());
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound.into());
+        }
+
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
+        {
+            return Err(Error::FundsNotLocked.into());
+        }
+
+        if amount <= 0 || amount > escrow.remaining_amount {
+            return Err(Error::InvalidAmount.into());
+        }
+
+        let approval = RefundApproval {
+            bounty_id,
+            amount,
+            recipient: recipient.clone(),
+            mode: mode.clone(),
+            approved_by: admin.clone(),
+            approved_at: env.ledger().timestamp(),
+        };
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::RefundApproval(bounty_id), &approval);
+
+        Ok(())
+    }
+
+    /// Release a partial amount of the locked funds to the contributor.
+    /// Only the admin (backend) can authorize this.
+    ///
+    /// - `payout_amount` must be > 0 and <= `remaining_amount`.
+    /// - `remaining_amount` is decremented by `payout_amount` after each call.
+    /// - When `remaining_amount` reaches 0 the escrow status is set to Released.
+    /// - The bounty stays Locked while any funds remain unreleased.
+    /// - Emits `FundsReleased` and `TrancheReleased` (with the remaining
+    ///   balance), and appends a release record.
+    pub fn partial_release(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+        payout_amount: i128,
+    ) -> Result<(), soroban_sdk::Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized.into());
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound.into());
+        }
+        Self::migrate_escrow_record(&env, bounty_id);
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked.into());
+        }
+
+        // Guard: zero or negative payout makes no sense and would corrupt state
+        if payout_amount <= 0 {
+            return Err(Error::InvalidAmount.into());
+        }
+
+        // Guard: prevent overpayment — payout cannot exceed what is still owed
+        if payout_amount > escrow.remaining_amount {
+            return Err(Error::InsufficientFunds.into());
+        }
+        Self::require_payout_allowed(&env, bounty_id, &contributor, payout_amount)?;
+
+        let token_addr = Self::bounty_token(&env, bounty_id);
+        let client = token::Client::new(&env, &token_addr);
+
+        // Transfer only the requested partial amount to the contributor
+        client.transfer(
+            &env.current_contract_address(),
+            &contributor,
+            &payout_amount,
+        );
+
+        // Decrement remaining; this is always an exact integer subtraction — no rounding
+        escrow.remaining_amount = escrow.remaining_amount.checked_sub(payout_amount).unwrap();
+
+        // Automatically transition to Released once fully paid out
+        if escrow.remaining_amount == 0 {
+            escrow.status = EscrowStatus::Released;
+            Self::return_claim_bond(&env, &client, bounty_id, &contributor);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+        Self::index_status(&env, bounty_id, &escrow.status);
+        Self::sync_escrow_accounting(&env, bounty_id, &escrow);
+        threshold_monitor::record_outflow(&env, payout_amount);
+
+        let now = env.ledger().timestamp();
+        let mut records: Vec<ReleaseRecord> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ReleaseRecords(bounty_id))
+            .unwrap_or(Vec::new(&env));
+        records.push_back(ReleaseRecord {
+            recipient: contributor.clone(),
+            amount: payout_amount,
+            released_at: now,
+        });
+        env.storage()
+            .persistent()
+            .set(&DataKey::ReleaseRecords(bounty_id), &records);
+
+        events::emit_funds_released(
+            &env,
+            FundsReleased {
+                version: EVENT_VERSION_V3,
+                bounty_id,
+                amount: payout_amount,
+                remaining_before: escrow.remaining_amount + payout_amount,
+                remaining_after: escrow.remaining_amount,
+                recipient: contributor.clone(),
+                timestamp: now,
+            },
+        );
+        events::emit_tranche_released(
+            &env,
+            events::TrancheReleased {
+                version: EVENT_VERSION_V3,
+                bounty_id,
+                amount: payout_amount,
+                remaining_amount: escrow.remaining_amount,
+                remaining_before: escrow.remaining_amount + payout_amount,
+                remaining_after: escrow.remaining_amount,
+                recipient: contributor,
+                timestamp: now,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Release funds to several recipients in one call, e.g. a team that
+    /// completed a bounty together. Only the admin (backend) can authorize this.
+    ///
+    /// - `recipients` and `amounts` must be the same non-empty length.
+    /// - Every amount must be > 0 and their sum <= `remaining_amount`; a sum
+    ///   below `remaining_amount` leaves the remainder locked.
+    /// - The release fee is charged on each amount separately, so each
+    ///   recipient receives their amount less its fee.
+    /// - Each payout emits `FundsReleased` and is appended to the bounty's
+    ///   release records (see `get_release_records`).
+    pub fn release_funds_split(
+        env: Env,
+        bounty_id: u64,
+        recipients: Vec<Address>,
+        amounts: Vec<i128>,
+    ) -> Result<(), soroban_sdk::Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized.into());
+        }
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused.into());
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if recipients.is_empty() || recipients.len() > MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatchSize.into());
+        }
+        if recipients.len() != amounts.len() {
+            return Err(Error::BatchSizeMismatch.into());
+        }
+
+        Self::migrate_escrow_record(&env, bounty_id);
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked.into());
+        }
+
+        let mut total: i128 = 0;
+        for amount in amounts.iter() {
+            if amount <= 0 {
+                return Err(Error::InvalidAmount.into());
+            }
+            total = total.checked_add(amount).ok_or(Error::InvalidAmount)?;
+        }
+        if total > escrow.remaining_amount {
+            return Err(Error::InsufficientFunds.into());
+        }
+        for (recipient, amount) in recipients.iter().zip(amounts.iter()) {
+            Self::require_payout_allowed(&env, bounty_id, &recipient, amount)?;
+        }
+
+        reentrancy_guard::acquire(&env);
+
+        let token_addr = Self::bounty_token(&env, bounty_id);
+        let client = token::Client::new(&env, &token_addr);
+        let (_lock_fee_rate, release_fee_rate, fee_recipient, fee_enabled) =
+            Self::resolve_fee_config_for_token(&env, &token_addr);
+        let mut records: Vec<ReleaseRecord> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ReleaseRecords(bounty_id))
+            .unwrap_or(Vec::new(&env));
+        let now = env.ledger().timestamp();
+
+        let mut remaining = escrow.remaining_amount;
+        for (recipient, amount) in recipients.iter().zip(amounts.iter()) {
+            let release_fee = if fee_enabled && release_fee_rate > 0 {
+                Self::calculate_fee(amount, release_fee_rate)
+            } else {
+                0
+            };
+            if release_fee > 0 {
+                client.transfer(
+                    &env.current_contract_address(),
+                    &fee_recipient,
+                    &release_fee,
+                );
+                Self::record_fee_collected(&env, release_fee);
+                events::emit_fee_collected(
+                    &env,
+                    events::FeeCollected {
+                        operation_type: events::FeeOperationType::Release,
+                        amount: release_fee,
+                        fee_rate: release_fee_rate,
+                        recipient: fee_recipient.clone(),
+                        timestamp: now,
+                    },
+                );
+            }
+            client.transfer(
+                &env.current_contract_address(),
+                &recipient,
+                &(amount - release_fee),
+            );
+            Self::return_claim_bond(&env, &client, bounty_id, &recipient);
+            threshold_monitor::record_outflow(&env, amount);
+            records.push_back(ReleaseRecord {
+                recipient: recipient.clone(),
+                amount,
+                released_at: now,
+            });
+            emit_funds_released(
+                &env,
+                FundsReleased {
+                    version: EVENT_VERSION_V3,
+                    bounty_id,
+                    amount,
+                    remaining_before: remaining,
+                    remaining_after: remaining - amount,
+                    recipient,
+                    timestamp: now,
+                },
+            );
+            remaining -= amount;
+        }
+
+        escrow.remaining_amount -= total;
+        if escrow.remaining_amount == 0 {
+            escrow.status = EscrowStatus::Released;
+        }
+        invariants::assert_escrow(&env, &escrow);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+        Self::index_status(&env, bounty_id, &escrow.status);
+        Self::sync_escrow_accounting(&env, bounty_id, &escrow);
+        env.storage()
+            .persistent()
+            .set(&DataKey::ReleaseRecords(bounty_id), &records);
+
+        reentrancy_guard::release(&env);
+        monitoring::track_operation(&env, symbol_short!("rel_split"), admin, true);
+        Ok(())
+    }
+
+    /// Per-recipient payouts made by split and partial releases, oldest first.
+    pub fn get_release_records(env: Env, bounty_id: u64) -> Vec<ReleaseRecord> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ReleaseRecords(bounty_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Opt a bounty into keeper-driven refunds (depositor only).
+    ///
+    /// Once the deadline passes anyone can call `execute_auto_refund`; the
+    /// caller keeps `reward_bps` of the remaining balance (at most 5%) and
+    /// the rest goes back to the depositor. Calling again updates the reward.
+    pub fn enable_auto_refund(
+        env: Env,
+        bounty_id: u64,
+        reward_bps: u32,
+    ) -> Result<(), soroban_sdk::Error> {
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        escrow.depositor.require_auth();
+        if reward_bps > MAX_AUTO_REFUND_REWARD_BPS {
+            return Err(Error::InvalidFeeRate.into());
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::AutoRefund(bounty_id), &reward_bps);
+        Ok(())
+    }
+
+    /// Withdraw a bounty from keeper-driven refunds (depositor only).
+    pub fn disable_auto_refund(env: Env, bounty_id: u64) -> Result<(), soroban_sdk::Error> {
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        escrow.depositor.require_auth();
+        env.storage()
+            .persistent()
+            .remove(&DataKey::AutoRefund(bounty_id));
+        Ok(())
+    }
+
+    /// Keeper reward in basis points if auto-refund is enabled for a bounty.
+    pub fn get_auto_refund(env: Env, bounty_id: u64) -> Option<u32> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::AutoRefund(bounty_id))
+    }
+
+    /// Refund an expired auto-refund bounty on the depositor's behalf.
+    /// `keeper` receives the configured reward out of the escrow; the
+    /// remainder is returned to the depositor. Returns the keeper reward.
+    pub fn execute_auto_refund(
+        env: Env,
+        bounty_id: u64,
+        keeper: Address,
+    ) -> Result<i128, soroban_sdk::Error> {
+        if Self::check_paused(&env, symbol_short!("refund")) {
+            return Err(Error::FundsPaused.into());
+        }
+        keeper.require_auth();
+        let reward_bps: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::AutoRefund(bounty_id))
+            .ok_or(LifecycleError::AutoRefundNotEnabled)?;
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
+        {
+            return Err(Error::FundsNotLocked.into());
+        }
+        let now = env.ledger().timestamp();
+        if now < escrow.deadline {
+            return Err(Error::DeadlineNotPassed.into());
+        }
+        if env
+            .storage()
+            .persistent()
+            .get::<DataKey, ClaimRecord>(&DataKey::PendingClaim(bounty_id))
+            .is_some_and(|claim| !claim.claimed)
+        {
+            return Err(Error::ClaimPending.into());
+        }
+        Self::require_not_blocked(&env, &escrow.depositor)?;
+        Self::apply_jurisdiction(&env, bounty_id, symbol_short!("refund"))?;
+
+        reentrancy_guard::acquire(&env);
+        let total = escrow.remaining_amount;
+        let reward = total * reward_bps as i128 / BASIS_POINTS;
+        let refund_amount = total - reward;
+
+        // EFFECTS
+        escrow.remaining_amount = 0;
+        escrow.status = EscrowStatus::Refunded;
+        escrow.refund_history.push_back(RefundRecord {
+            amount: refund_amount,
+            recipient: escrow.depositor.clone(),
+            timestamp: now,
+            mode: RefundMode::Full,
+        });
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+        Self::index_status(&env, bounty_id, &escrow.status);
+        Self::sync_escrow_accounting(&env, bounty_id, &escrow);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::AutoRefund(bounty_id));
+
+        // INTERACTION
+        let client = token::Client::new(&env, &Self::bounty_token(&env, bounty_id));
+        if reward > 0 {
+            client.transfer(&env.current_contract_address(), &keeper, &reward);
+        }
+        if refund_amount > 0 {
+            client.transfer(
+                &env.current_contract_address(),
+                &escrow.depositor,
+                &refund_amount,
+            );
+        }
+
+        emit_funds_refunded(
+            &env,
+            FundsRefunded {
+                version: EVENT_VERSION_V3,
+                bounty_id,
+                amount: refund_amount,
+                remaining_before: total,
+                remaining_after: escrow.remaining_amount,
+                refund_to: escrow.depositor.clone(),
+                timestamp: now,
+            },
+        );
+        events::emit_auto_refund_executed(
+            &env,
+            events::AutoRefundExecuted {
+                version: EVENT_VERSION_V3,
+                bounty_id,
+                keeper,
+                reward,
+                refunded: refund_amount,
+                remaining_before: total,
+                remaining_after: escrow.remaining_amount,
+                timestamp: now,
+            },
+        );
+        Self::record_receipt(
+            &env,
+            CriticalOperationOutcome::Refunded,
+            bounty_id,
+            refund_amount,
+            escrow.depositor.clone(),
+        );
+
+        multitoken_invariants::assert_after_disbursement(&env);
+        reentrancy_guard::release(&env);
+        Ok(reward)
+    }
+
+    /// Refunds remaining funds when refund conditions are met.
+    ///
+    /// # Authorization
+    /// Refund execution requires authenticated authorization from the contract admin
+    /// and the escrow depositor.
+    ///
+    /// # Eligibility
+    /// Refund is allowed when either:
+    /// 1. The deadline has passed (standard full refund to depositor), or
+    /// 2. An admin approval exists (early, partial, or custom-recipient refund).
+    ///
+    /// # Errors
+    /// Returns `Error::NotInitialized` if admin is not set.
+    pub fn refund(env: Env, bounty_id: u64) -> Result<(), soroban_sdk::Error> {
+        Self::migrate_escrow_record(&env, bounty_id);
+        let caller = env
+            .storage()
+            .persistent()
+            .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+            .map(|escrow| escrow.depositor)
+            .unwrap_or_else(|| env.current_contract_address());
+        let res = Self::refund_logic(env.clone(), bounty_id, None);
+        monitoring::track_operation(&env, symbol_short!("refund"), caller, res.is_ok());
+        res
+    }
+
+    /// Message the depositor's recovery key signs to redirect the refund of
+    /// `bounty_id` to `alternate`. It binds the depositor's current nonce.
+    pub fn get_refund_to_message(
+        env: Env,
+        bounty_id: u64,
+        alternate: Address,
+    ) -> Result<Bytes, soroban_sdk::Error> {
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        let nonce = Self::get_nonce(env.clone(), escrow.depositor);
+        Ok(Self::refund_to_message(&env, bounty_id, &alternate, nonce))
+    }
+
+    /// `refund` for a depositor that lost the key it locked with: the
+    /// depositor's share of the refund goes to `alternate` instead, and its
+    /// auth is replaced by a signature from the key registered with
+    /// `set_recovery_key` over `get_refund_to_message`. Admin approval and
+    /// the deadline rules of `refund` still apply.
+    pub fn refund_to(
+        env: Env,
+        bounty_id: u64,
+        alternate: Address,
+        signature: BytesN<64>,
+    ) -> Result<(), soroban_sdk::Error> {
+        Self::migrate_escrow_record(&env, bounty_id);
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        let depositor = escrow.depositor;
+        let public_key: BytesN<32> = env
+            .storage()
+            .persistent()
+            .get(&SignatureKey::RecoveryKey(depositor.clone()))
+            .ok_or(ClaimError::RecoveryKeyNotSet)?;
+        let nonce = Self::get_nonce(env.clone(), depositor.clone());
+        Self::verify_signed_with_key(
+            &env,
+            &depositor,
+            &public_key,
+            &Self::refund_to_message(&env, bounty_id, &alternate, nonce),
+            nonce,
+            &signature,
+        )?;
+
+        let res = Self::refund_logic(env.clone(), bounty_id, Some(alternate));
+        monitoring::track_operation(&env, symbol_short!("refund"), depositor, res.is_ok());
+        res
+    }
+
+    fn refund_to_message(env: &Env, bounty_id: u64, alternate: &Address, nonce: u64) -> Bytes {
+        let mut message = symbol_short!("rfnd_to").to_xdr(env);
+        message.append(&env.current_contract_address().to_xdr(env));
+        message.append(&Bytes::from_array(env, &bounty_id.to_be_bytes()));
+        message.append(&alternate.clone().to_xdr(env));
+        message.append(&Bytes::from_array(env, &nonce.to_be_bytes()));
+        message
+    }
+
+    /// Refund `bounty_id`. With `alternate`, the depositor's auth has already
+    /// been established by a recovery signature and whatever would be paid
+    /// to the depositor goes to `alternate`.
+    fn refund_logic(
+        env: Env,
+        bounty_id: u64,
+        alternate: Option<Address>,
+    ) -> Result<(), soroban_sdk::Error> {
+        if Self::check_paused(&env, symbol_short!("refund")) {
+            return Err(Error::FundsPaused.into());
+        }
+
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound.into());
+        }
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+
+        // Require authenticated approval from both admin and depositor.
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        if alternate.is_none() {
+            escrow.depositor.require_auth();
+        }
+
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
+        {
+            return Err(Error::FundsNotLocked.into());
+        }
+
+        // Block refund if there is a pending claim (Issue #391 fix)
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::PendingClaim(bounty_id))
+        {
+            let claim: ClaimRecord = env
+                .storage()
+                .persistent()
+                .get(&DataKey::PendingClaim(bounty_id))
+                .unwrap();
+            if !claim.claimed {
+                return Err(Error::ClaimPending.into());
+            }
+        }
+
+        let now = env.ledger().timestamp();
+        let approval_key = DataKey::RefundApproval(bounty_id);
+        let approval: Option<RefundApproval> = env.storage().persistent().get(&approval_key);
+
+        // Refund is allowed if:
+        // 1. Deadline has passed (returns full amount to depositor)
+        // 2. An administrative approval exists (can be early, partial, and to custom recipient)
+        if now < escrow.deadline && approval.is_none() {
+            return Err(Error::DeadlineNotPassed.into());
+        }
+
+        let (refund_amount, refund_to, is_full) = if let Some(app) = approval.clone() {
+            let full = app.mode == RefundMode::Full || app.amount >= escrow.remaining_amount;
+            (app.amount, app.recipient, full)
+        } else {
+            // Standard refund after deadline
+            (escrow.remaining_amount, escrow.depositor.clone(), true)
+        };
+        let refund_to = match alternate {
+            Some(alternate) if refund_to == escrow.depositor => alternate,
+            _ => refund_to,
+        };
+        Self::require_not_blocked(&env, &refund_to)?;
+        Self::apply_jurisdiction(&env, bounty_id, symbol_short!("refund"))?;
+
+        if refund_amount <= 0 || refund_amount > escrow.remaining_amount {
+            return Err(Error::InvalidAmount.into());
+        }
+
+        // EFFECTS: update state before external call (CEI)
+        invariants::assert_escrow(&env, &escrow);
+        // Update escrow state: subtract the amount exactly refunded
+        escrow.remaining_amount = escrow.remaining_amount.checked_sub(refund_amount).unwrap();
+        if is_full || escrow.remaining_amount == 0 {
+            escrow.status = EscrowStatus::Refunded;
+        } else {
+            escrow.status = EscrowStatus::PartiallyRefunded;
+        }
+
+        // Add to refund history
+        escrow.refund_history.push_back(RefundRecord {
+            amount: refund_amount,
+            recipient: refund_to.clone(),
+            timestamp: now,
+            mode: if is_full {
+                RefundMode::Full
+            } else {
+                RefundMode::Partial
+            },
+        });
+
+        // Save updated escrow
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+        Self::index_status(&env, bounty_id, &escrow.status);
+        Self::sync_escrow_accounting(&env, bounty_id, &escrow);
+
+        // Remove approval after successful execution
+        if approval.is_some() {
+            env.storage().persistent().remove(&approval_key);
+        }
+
+        // INTERACTION: external token transfer is last
+        let token_addr = Self::bounty_token(&env, bounty_id);
+        let client = token::Client::new(&env, &token_addr);
+        client.transfer(&env.current_contract_address(), &refund_to, &refund_amount);
+        threshold_monitor::record_outflow(&env, refund_amount);
+
+        emit_funds_refunded(
+            &env,
+            FundsRefunded {
+                version: EVENT_VERSION_V3,
+                bounty_id,
+                amount: refund_amount,
+                remaining_before: escrow.remaining_amount + refund_amount,
+                remaining_after: escrow.remaining_amount,
+                refund_to: refund_to.clone(),
+                timestamp: now,
+            },
+        );
+        Self::record_receipt(
+            &env,
+            CriticalOperationOutcome::Refunded,
+            bounty_id,
+            refund_amount,
+            refund_to.clone(),
+        );
+
+        // INV-2: Verify aggregate balance matches token balance after refund
+        multitoken_invariants::assert_after_disbursement(&env);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(())
+    }
+
+    /// Assign the contributor whose consent is needed to cancel a bounty
+    /// early (admin only). Unassigned bounties are confirmed by the admin.
+    pub fn assign_contributor(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+    ) -> Result<(), soroban_sdk::Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound.into());
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::AssignedContributor(bounty_id), &contributor);
+        Ok(())
+    }
+
+    pub fn get_assigned_contributor(env: Env, bounty_id: u64) -> Option<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::AssignedContributor(bounty_id))
+    }
+
+    /// Depositor proposes cancelling a locked bounty before its deadline.
+    /// The proposal can be confirmed for `CANCEL_PROPOSAL_WINDOW` seconds;
+    /// proposing again restarts the window.
+    pub fn propose_cancel(env: Env, bounty_id: u64) -> Result<CancelProposal, soroban_sdk::Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized.into());
+        }
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        escrow.depositor.require_auth();
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
+        {
+            return Err(Error::FundsNotLocked.into());
+        }
+
+        let now = env.ledger().timestamp();
+        let proposal = CancelProposal {
+            bounty_id,
+            proposed_by: escrow.depositor,
+            proposed_at: now,
+            expires_at: now.saturating_add(CANCEL_PROPOSAL_WINDOW),
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::CancelProposal(bounty_id), &proposal);
+        env.events()
+            .publish((symbol_short!("cncl_prop"), bounty_id), proposal.clone());
+        Ok(proposal)
+    }
+
+    /// Depositor withdraws a pending cancel proposal.
+    pub fn withdraw_cancel(env: Env, bounty_id: u64) -> Result<(), soroban_sdk::Error> {
+        let proposal: CancelProposal = env
+            .storage()
+            .persistent()
+            .get(&DataKey::CancelProposal(bounty_id))
+            .ok_or(LifecycleError::CancelProposalNotFound)?;
+        proposal.proposed_by.require_auth();
+        env.storage()
+            .persistent()
+            .remove(&DataKey::CancelProposal(bounty_id));
+        Ok(())
+    }
+
+    pub fn get_cancel_proposal(env: Env, bounty_id: u64) -> Option<CancelProposal> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::CancelProposal(bounty_id))
+    }
+
+    /// Confirm a pending cancel proposal and refund the remaining balance to
+    /// the depositor immediately, regardless of the deadline. `confirmer`
+    /// must be the assigned contributor, or the admin if none is assigned.
+    pub fn confirm_cancel(
+        env: Env,
+        bounty_id: u64,
+        confirmer: Address,
+    ) -> Result<(), soroban_sdk::Error> {
+        if Self::check_paused(&env, symbol_short!("refund")) {
+            return Err(Error::FundsPaused.into());
+        }
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+
+        let proposal: CancelProposal = env
+            .storage()
+            .persistent()
+            .get(&DataKey::CancelProposal(bounty_id))
+            .ok_or(LifecycleError::CancelProposalNotFound)?;
+
+        let expected = env
+            .storage()
+            .persistent()
+            .get(&DataKey::AssignedContributor(bounty_id))
+            .unwrap_or(admin);
+        if confirmer != expected {
+            return Err(Error::Unauthorized.into());
+        }
+        confirmer.require_auth();
+
+        let now = env.ledger().timestamp();
+        if now > proposal.expires_at {
+            return Err(LifecycleError::CancelProposalExpired.into());
+        }
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
+        {
+            return Err(Error::FundsNotLocked.into());
+        }
+        if let Some(claim) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, ClaimRecord>(&DataKey::PendingClaim(bounty_id))
+        {
+            if !claim.claimed {
+                return Err(Error::ClaimPending.into());
+            }
+        }
+
+        let refund_amount = escrow.remaining_amount;
+        let refund_to = escrow.depositor.clone();
+
+        // EFFECTS before the token transfer (CEI)
+        escrow.remaining_amount = 0;
+        escrow.status = EscrowStatus::Refunded;
+        escrow.refund_history.push_back(RefundRecord {
+            amount: refund_amount,
+            recipient: refund_to.clone(),
+            timestamp: now,
+            mode: RefundMode::Full,
+        });
+        invariants::assert_escrow(&env, &escrow);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+        Self::index_status(&env, bounty_id, &escrow.status);
+        Self::sync_escrow_accounting(&env, bounty_id, &escrow);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::CancelProposal(bounty_id));
+
+        if refund_amount > 0 {
+            let token_addr = Self::bounty_token(&env, bounty_id);
+            let client = token::Client::new(&env, &token_addr);
+            client.transfer(&env.current_contract_address(), &refund_to, &refund_amount);
+        }
+
+        env.events()
+            .publish((symbol_short!("cncl_ok"), bounty_id), confirmer.clone());
+        emit_funds_refunded(
+            &env,
+            FundsRefunded {
+                version: EVENT_VERSION_V3,
+                bounty_id,
+                amount: refund_amount,
+                remaining_before: escrow.remaining_amount + refund_amount,
+                remaining_after: escrow.remaining_amount,
+                refund_to: refund_to.clone(),
+                timestamp: now,
+            },
+        );
+        Self::record_receipt(
+            &env,
+            CriticalOperationOutcome::Refunded,
+            bounty_id,
+            refund_amount,
+            refund_to,
+        );
+
+        multitoken_invariants::assert_after_disbursement(&env);
+        monitoring::track_operation(&env, symbol_short!("cancel"), confirmer, true);
+        Ok(())
+    }
+
+    pub fn get_hashlock(env: Env, bounty_id: u64) -> Option<BytesN<32>> {
+        env.storage()
+            .persistent()
+            .get(&TicketKey::Hashlock(bounty_id))
+    }
+
+    /// Release the full remaining balance of a hashlocked bounty to
+    /// `claimant` if `sha256(preimage)` matches its hashlock and the deadline
+    /// has not passed. The preimage is published in the `HashlockClaimed`
+    /// event.
+    pub fn claim_with_preimage(
+        env: Env,
+        bounty_id: u64,
+        claimant: Address,
+        preimage: Bytes,
+    ) -> Result<(), soroban_sdk::Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized.into());
+        }
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused.into());
+        }
+        claimant.require_auth();
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        let hashlock: BytesN<32> = env
+            .storage()
+            .persistent()
+            .get(&TicketKey::Hashlock(bounty_id))
+            .ok_or(ClaimError::HashlockNotSet)?;
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked.into());
+        }
+        let now = env.ledger().timestamp();
+        if now > escrow.deadline {
+            return Err(ClaimError::HashlockExpired.into());
+        }
+        if BytesN::from(env.crypto().sha256(&preimage)) != hashlock {
+            return Err(ClaimError::InvalidPreimage.into());
+        }
+        let amount = escrow.remaining_amount;
+        Self::require_payout_allowed(&env, bounty_id, &claimant, amount)?;
+
+        reentrancy_guard::acquire(&env);
+
+        // EFFECTS before the token transfer (CEI)
+        escrow.remaining_amount = 0;
+        escrow.status = EscrowStatus::Released;
+        invariants::assert_escrow(&env, &escrow);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+        Self::index_status(&env, bounty_id, &escrow.status);
+        Self::sync_escrow_accounting(&env, bounty_id, &escrow);
+
+        let token_addr = Self::bounty_token(&env, bounty_id);
+        let client = token::Client::new(&env, &token_addr);
+        client.transfer(&env.current_contract_address(), &claimant, &amount);
+        Self::return_claim_bond(&env, &client, bounty_id, &claimant);
+        threshold_monitor::record_outflow(&env, amount);
+
+        emit_funds_released(
+            &env,
+            FundsReleased {
+                version: EVENT_VERSION_V3,
+                bounty_id,
+                amount,
+                remaining_before: amount,
+                remaining_after: escrow.remaining_amount,
+                recipient: claimant.clone(),
+                timestamp: now,
+            },
+        );
+        events::emit_hashlock_claimed(
+            &env,
+            events::HashlockClaimed {
+                version: EVENT_VERSION_V3,
+                bounty_id,
+                amount,
+                remaining_before: amount,
+                remaining_after: escrow.remaining_amount,
+                claimant: claimant.clone(),
+                preimage,
+                timestamp: now,
+            },
+        );
+        Self::record_receipt(
+            &env,
+            CriticalOperationOutcome::Released,
+            bounty_id,
+            amount,
+            claimant.clone(),
+        );
+
+        multitoken_invariants::assert_after_disbursement(&env);
+        reentrancy_guard::release(&env);
+        monitoring::track_operation(&env, symbol_short!("htlc"), claimant, true);
+        Ok(())
+    }
+
+    /// Reserve a bounty's payout for a recipient identified only by a
+    /// commitment (admin only).
+    ///
+    /// The escrow moves to `PendingRelease` until the recipient calls
+    /// `claim_anonymous` with the preimage of `recipient_commitment`.
+    pub fn release_funds_anonymous(
+        env: Env,
+        bounty_id: u64,
+        recipient_commitment: BytesN<32>,
+    ) -> Result<(), soroban_sdk::Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized.into());
+        }
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused.into());
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked.into());
+        }
+        Self::require_group_active(&env, bounty_id)?;
+
+        let now = env.ledger().timestamp();
+        let payout = AnonymousPayout {
+            recipient: AnonymousParty::Commitment(recipient_commitment.clone()),
+            amount: escrow.remaining_amount,
+            reserved_at: now,
+        };
+        escrow.status = EscrowStatus::PendingRelease;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+        Self::index_status(&env, bounty_id, &escrow.status);
+        Self::sync_escrow_accounting(&env, bounty_id, &escrow);
+        env.storage()
+            .persistent()
+            .set(&TicketKey::AnonymousPayout(bounty_id), &payout);
+
+        events::emit_anonymous_payout_reserved(
+            &env,
+            events::AnonymousPayoutReserved {
+                bounty_id,
+                recipient_commitment,
+                amount: payout.amount,
+                timestamp: now,
+            },
+        );
+        Ok(())
+    }
+
+    /// Get the anonymous payout reserved for a bounty, if any.
+    pub fn get_anonymous_payout(env: Env, bounty_id: u64) -> Option<AnonymousPayout> {
+        env.storage()
+            .persistent()
+            .get(&TicketKey::AnonymousPayout(bounty_id))
+    }
+
+    /// Claim an anonymous payout by revealing the commitment preimage.
+    ///
+    /// The preimage is public once submitted, so it should be a fresh secret
+    /// used for this bounty only.
+    pub fn claim_anonymous(
+        env: Env,
+        bounty_id: u64,
+        preimage: Bytes,
+        payout_address: Address,
+    ) -> Result<(), soroban_sdk::Error> {
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused.into());
+        }
+        payout_address.require_auth();
+        Self::require_not_blocked(&env, &payout_address)?;
+
+        let payout: AnonymousPayout = env
+            .storage()
+            .persistent()
+            .get(&TicketKey::AnonymousPayout(bounty_id))
+            .ok_or(ClaimError::AnonymousPayoutNotFound)?;
+        let commitment = match payout.recipient {
+            AnonymousParty::Commitment(c) => c,
+            AnonymousParty::Address(_) => return Err(ClaimError::AnonymousPayoutNotFound.into()),
+        };
+        if BytesN::from(env.crypto().sha256(&preimage)) != commitment {
+            return Err(ClaimError::InvalidPreimage.into());
+        }
+
+        reentrancy_guard::acquire(&env);
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+        escrow.status = EscrowStatus::Locked;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+        Self::index_status(&env, bounty_id, &escrow.status);
+        Self::sync_escrow_accounting(&env, bounty_id, &escrow);
+        env.storage()
+            .persistent()
+            .remove(&TicketKey::AnonymousPayout(bounty_id));
+
+        let res = Self::execute_release(&env, bounty_id, payout_address.clone());
+        reentrancy_guard::release(&env);
+        res?;
+
+        events::emit_anonymous_payout_claimed(
+            &env,
+            events::AnonymousPayoutClaimed {
+                version: EVENT_VERSION_V3,
+                bounty_id,
+                recipient: payout_address,
+                amount: payout.amount,
+                remaining_before: escrow.remaining_amount,
+                remaining_after: 0,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Simulate refund operation without state changes or token transfers.
+    ///
+    /// Returns a `SimulationResult` indicating whether the operation would succeed and the
+    /// resulting escrow state. Does not require authorization; safe for off-chain preview.
+    ///
+    /// # Arguments
+    /// * `bounty_id` - Bounty identifier
+    ///
+    /// # Security
+    /// This function performs only read operations. No storage writes, token transfers,
+    /// or events are emitted.
+    pub fn dry_run_refund(env: Env, bounty_id: u64) -> SimulationResult {
+        fn err_result(e: soroban_sdk::Error, default_status: EscrowStatus) -> SimulationResult {
+            SimulationResult {
+                success: false,
+                error_code: e.get_code(),
+                amount: 0,
+                resulting_status: default_status,
+                remaining_amount: 0,
+            }
+        }
+        match Self::dry_run_refund_impl(&env, bounty_id) {
+            Ok((refund_amount, resulting_status, remaining_amount)) => SimulationResult {
+                success: true,
+                error_code: 0,
+                amount: refund_amount,
+                resulting_status,
+                remaining_amount,
+            },
+            Err(e) => err_result(e, EscrowStatus::Refunded),
+        }
+    }
+
+    fn dry_run_refund_impl(
+        env: &Env,
+        bounty_id: u64,
+    ) -> Result<(i128, EscrowStatus, i128), soroban_sdk::Error> {
+        if Self::check_paused(env, symbol_short!("refund")) {
+            return Err(Error::FundsPaused.into());
+        }
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound.into());
+        }
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
+        {
+            return Err(Error::FundsNotLocked.into());
+        }
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::PendingClaim(bounty_id))
+        {
+            let claim: ClaimRecord = env
+                .storage()
+                .persistent()
+                .get(&DataKey::PendingClaim(bounty_id))
+                .unwrap();
+            if !claim.claimed {
+                return Err(Error::ClaimPending.into());
+            }
+        }
+        let now = env.ledger().timestamp();
+        let approval_key = DataKey::RefundApproval(bounty_id);
+        let approval: Option<RefundApproval> = env.storage().persistent().get(&approval_key);
+        if now < escrow.deadline && approval.is_none() {
+            return Err(Error::DeadlineNotPassed.into());
+        }
+        let (refund_amount, _refund_to, is_full) = if let Some(app) = approval {
+            let full = app.mode == RefundMode::Full || app.amount >= escrow.remaining_amount;
+            (app.amount, app.recipient, full)
+        } else {
+            (escrow.remaining_amount, escrow.depositor.clone(), true)
+        };
+        if refund_amount <= 0 || refund_amount > escrow.remaining_amount {
+            return Err(Error::InvalidAmount.into());
+        }
+        let remaining_after = escrow
+            .remaining_amount
+            .checked_sub(refund_amount)
+            .unwrap_or(0);
+        let resulting_status = if is_full || remaining_after == 0 {
+            EscrowStatus::Refunded
+        } else {
+            EscrowStatus::PartiallyRefunded
+        };
+        Ok((refund_amount, resulting_status, remaining_after))
+    }
+
+    /// Sets or clears the anonymous resolver address.
+    ///
+    /// Kept for compatibility: `Some(addr)` replaces the resolver set with
+    /// `[addr]` and a quorum of 1, `None` clears the set. Use `add_resolver`,
+    /// `remove_resolver` and `set_resolver_quorum` for an M-of-N set.
+    pub fn set_anonymous_resolver(
+        env: Env,
+        resolver: Option<Address>,
+    ) -> Result<(), soroban_sdk::Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized.into());
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        env.storage().instance().remove(&DataKey::AnonymousResolver);
+        match resolver {
+            Some(addr) => {
+                env.storage()
+                    .instance()
+                    .set(&DataKey::AnonymousResolvers, &vec![&env, addr]);
+                env.storage()
+                    .instance()
+                    .set(&DataKey::ResolverQuorum, &1u32);
+            }
+            None => {
+                env.storage()
+                    .instance()
+                    .remove(&DataKey::AnonymousResolvers);
+                env.storage().instance().remove(&DataKey::ResolverQuorum);
+            }
+        }
+        Ok(())
+    }
+
+    /// Add a resolver to the anonymous refund resolver set (admin only).
+    pub fn add_resolver(env: Env, resolver: Address) -> Result<(), soroban_sdk::Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized.into());
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let mut resolvers = Self::get_resolvers(env.clone());
+        if !resolvers.contains(&resolver) {
+            resolvers.push_back(resolver);
+        }
+        env.storage().instance().remove(&DataKey::AnonymousResolver);
+        env.storage()
+            .instance()
+            .set(&DataKey::AnonymousResolvers, &resolvers);
+        Ok(())
+    }
+
+    /// Remove a resolver from the set (admin only). Fails if the remaining
+    /// set would be smaller than the quorum.
+    pub fn remove_resolver(env: Env, resolver: Address) -> Result<(), soroban_sdk::Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized.into());
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let mut resolvers = Self::get_resolvers(env.clone());
+        let index = resolvers
+            .first_index_of(&resolver)
+            .ok_or(Error::Unauthorized)?;
+        resolvers.remove(index);
+        if !resolvers.is_empty() && resolvers.len() < Self::get_resolver_quorum(env.clone()) {
+            return Err(Error::InvalidAmount.into());
+        }
+        env.storage().instance().remove(&DataKey::AnonymousResolver);
+        env.storage()
+            .instance()
+            .set(&DataKey::AnonymousResolvers, &resolvers);
+        Ok(())
+    }
+
+    /// Set how many distinct resolvers must approve an anonymous refund
+    /// (admin only). Must be between 1 and the size of the resolver set.
+    pub fn set_resolver_quorum(env: Env, quorum: u32) -> Result<(), soroban_sdk::Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized.into());
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if quorum == 0 || quorum > Self::get_resolvers(env.clone()).len() {
+            return Err(Error::InvalidAmount.into());
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::ResolverQuorum, &quorum);
+        Ok(())
+    }
+
+    /// Get the anonymous refund resolver set. A resolver configured before
+    /// resolver sets existed is reported as a one-member set.
+    pub fn get_resolvers(env: Env) -> Vec<Address> {
+        if let Some(resolvers) = env.storage().instance().get(&DataKey::AnonymousResolvers) {
+            return resolvers;
+        }
+        match env
+            .storage()
+            .instance()
+            .get::<DataKey, Address>(&DataKey::AnonymousResolver)
+        {
+            Some(legacy) => vec![&env, legacy],
+            None => vec![&env],
+        }
+    }
+
+    /// Get the resolver quorum (1 when unset).
+    pub fn get_resolver_quorum(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::ResolverQuorum)
+            .unwrap_or(1)
+    }
+
+    /// Get the pending resolver approvals for an anonymous refund, if any.
+    pub fn get_resolver_approvals(env: Env, bounty_id: u64) -> Option<ResolverApproval> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ResolverApprovals(bounty_id))
+    }
+
+    /// Approve refunding an anonymous escrow to a resolved recipient.
+    ///
+    /// Each resolver resolves the depositor commitment off-chain and approves
+    /// the same recipient (signed instruction pattern). The refund executes
+    /// once approvals from the quorum of distinct resolvers are recorded.
+    pub fn refund_resolved(
+        env: Env,
+        bounty_id: u64,
+        recipient: Address,
+        resolver: Address,
+    ) -> Result<(), soroban_sdk::Error> {
+        if Self::check_paused(&env, symbol_short!("refund")) {
+            return Err(Error::FundsPaused.into());
+        }
+
+        let resolvers = Self::get_resolvers(env.clone());
+        if resolvers.is_empty() {
+            return Err(Error::AnonymousResolverNotSet.into());
+        }
+        if !resolvers.contains(&resolver) {
+            return Err(Error::Unauthorized.into());
+        }
+        resolver.require_auth();
+        Self::record_resolver_approval(&env, bounty_id, recipient, resolver, resolvers)
+    }
+
+    /// Message a resolver signs to approve refunding anonymous escrow
+    /// `bounty_id` to `recipient` via `refund_resolved_signed`.
+    pub fn get_resolver_approval_message(
+        env: Env,
+        bounty_id: u64,
+        recipient: Address,
+        nonce: u64,
+    ) -> Bytes {
+        Self::resolver_approval_message(&env, bounty_id, &recipient, nonce)
+    }
+
+    /// `refund_resolved` for a resolver that approved off-chain: anyone may
+    /// submit the resolver's ed25519 signature over
+    /// `get_resolver_approval_message`, consuming the resolver's `nonce`.
+    pub fn refund_resolved_signed(
+        env: Env,
+        bounty_id: u64,
+        recipient: Address,
+        resolver: Address,
+        nonce: u64,
+        signature: BytesN<64>,
+    ) -> Result<(), soroban_sdk::Error> {
+        if Self::check_paused(&env, symbol_short!("refund")) {
+            return Err(Error::FundsPaused.into());
+        }
+
+        let resolvers = Self::get_resolvers(env.clone());
+        if resolvers.is_empty() {
+            return Err(Error::AnonymousResolverNotSet.into());
+        }
+        if !resolvers.contains(&resolver) {
+            return Err(Error::Unauthorized.into());
+        }
+        Self::verify_signed_message(
+            &env,
+            &resolver,
+            &Self::resolver_approval_message(&env, bounty_id, &recipient, nonce),
+            nonce,
+            &signature,
+        )?;
+        Self::record_resolver_approval(&env, bounty_id, recipient, resolver, resolvers)
+    }
+
+    fn resolver_approval_message(
+        env: &Env,
+        bounty_id: u64,
+        recipient: &Address,
+        nonce: u64,
+    ) -> Bytes {
+        let mut message = symbol_short!("rslv_rfnd").to_xdr(env);
+        message.append(&env.current_contract_address().to_xdr(env));
+        message.append(&Bytes::from_array(env, &bounty_id.to_be_bytes()));
+        message.append(&recipient.clone().to_xdr(env));
+        message.append(&Bytes::from_array(env, &nonce.to_be_bytes()));
+        message
+    }
+
+    /// Add `resolver`'s approval and run the refund once the quorum of
+    /// current resolvers agrees on `recipient`.
+    fn record_resolver_approval(
+        env: &Env,
+        bounty_id: u64,
+        recipient: Address,
+        resolver: Address,
+        resolvers: Vec<Address>,
+    ) -> Result<(), soroban_sdk::Error> {
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::EscrowAnon(bounty_id))
+        {
+            return Err(Error::NotAnonymousEscrow.into());
+        }
+
+        let approvals_key = DataKey::ResolverApprovals(bounty_id);
+        let mut approval: ResolverApproval = env
+            .storage()
+            .persistent()
+            .get(&approvals_key)
+            .unwrap_or(ResolverApproval {
+                recipient: recipient.clone(),
+                approvals: vec![env],
+            });
+        if approval.recipient != recipient {
+            return Err(ClaimError::ResolverRecipientMismatch.into());
+        }
+        if !approval.approvals.contains(&resolver) {
+            approval.approvals.push_back(resolver);
+        }
+
+        // Only approvals from current resolvers count towards the quorum.
+        let mut count: u32 = 0;
+        for approver in approval.approvals.iter() {
+            if resolvers.contains(&approver) {
+                count += 1;
+            }
+        }
+        if count < Self::get_resolver_quorum(env.clone()) {
+            env.storage().persistent().set(&approvals_key, &approval);
+            return Ok(());
+        }
+
+        env.storage().persistent().remove(&approvals_key);
+        reentrancy_guard::acquire(env);
+        let res = Self::execute_resolved_refund(env, bounty_id, recipient);
+        reentrancy_guard::release(env);
+        res
+    }
+
+    fn execute_resolved_refund(
+        env: &Env,
+        bounty_id: u64,
+        recipient: Address,
+    ) -> Result<(), soroban_sdk::Error> {
+        Self::require_not_blocked(env, &recipient)?;
+        let mut anon: AnonymousEscrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EscrowAnon(bounty_id))
+            .unwrap();
+
+        if anon.status != EscrowStatus::Locked && anon.status != EscrowStatus::PartiallyRefunded {
+            return Err(Error::FundsNotLocked.into());
+        }
+
+        // GUARD 1: Block refund if there is a pending claim (Issue #391 fix)
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::PendingClaim(bounty_id))
+        {
+            let claim: ClaimRecord = env
+                .storage()
+                .persistent()
+                .get(&DataKey::PendingClaim(bounty_id))
+                .unwrap();
+            if !claim.claimed {
+                return Err(Error::ClaimPending.into());
+            }
+        }
+
+        let now = env.ledger().timestamp();
+        let approval_key = DataKey::RefundApproval(bounty_id);
+        let approval: Option<RefundApproval> = env.storage().persistent().get(&approval_key);
+
+        // Refund is allowed if:
+        // 1. Deadline has passed (returns full amount to depositor)
+        // 2. An administrative approval exists (can be early, partial, and to custom recipient)
+        if now < anon.deadline && approval.is_none() {
+            return Err(Error::DeadlineNotPassed.into());
+        }
+
+        let (refund_amount, refund_to, is_full) = if let Some(app) = approval.clone() {
+            let full = app.mode == RefundMode::Full || app.amount >= anon.remaining_amount;
+            (app.amount, app.recipient, full)
+        } else {
+            // Standard refund after deadline
+            (anon.remaining_amount, recipient.clone(), true)
+        };
+
+        if refund_amount <= 0 || refund_amount > anon.remaining_amount {
+            return Err(Error::InvalidAmount.into());
+        }
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(env, &token_addr);
+
+        // Transfer the calculated refund amount to the designated recipient
+        client.transfer(&env.current_contract_address(), &refund_to, &refund_amount);
+
+        // Anonymous escrow uses a parallel storage record and invariant model.
+        // Update escrow state: subtract the amount exactly refunded
+        anon.remaining_amount -= refund_amount;
+        if is_full || anon.remaining_amount == 0 {
+            anon.status = EscrowStatus::Refunded;
+        } else {
+            anon.status = EscrowStatus::PartiallyRefunded;
+        }
+
+        // Add to refund history
+        anon.refund_history.push_back(RefundRecord {
+            amount: refund_amount,
+            recipient: refund_to.clone(),
+            timestamp: now,
+            mode: if is_full {
+                RefundMode::Full
+            } else {
+                RefundMode::Partial
+            },
+        });
+
+        // Save updated escrow
+        env.storage()
+            .persistent()
+            .set(&DataKey::EscrowAnon(bounty_id), &anon);
+        Self::index_status(env, bounty_id, &anon.status);
+        Self::sync_anon_accounting(env, bounty_id, &anon);
+
+        // Remove approval after successful execution
+        if approval.is_some() {
+            env.storage().persistent().remove(&approval_key);
+        }
+
+        emit_funds_refunded(
+            env,
+            FundsRefunded {
+                version: EVENT_VERSION_V3,
+                bounty_id,
+                amount: refund_amount,
+                remaining_before: anon.remaining_amount + refund_amount,
+                remaining_after: anon.remaining_amount,
+                refund_to: refund_to.clone(),
+                timestamp: now,
+            },
+        );
+        Ok(())
+    }
+
+    /// De-anonymize an anonymous escrow by revealing its depositor commitment.
+    ///
+    /// `preimage` is the XDR encoding of the depositor address, optionally
+    /// followed by a salt, such that `sha256(preimage)` equals the stored
+    /// commitment. The depositor must authorize the reveal. The escrow is
+    /// converted into a regular escrow so `refund` works without a resolver.
+    pub fn reveal_depositor(
+        env: Env,
+        bounty_id: u64,
+        preimage: Bytes,
+    ) -> Result<Address, soroban_sdk::Error> {
+        let anon: AnonymousEscrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EscrowAnon(bounty_id))
+            .ok_or(Error::NotAnonymousEscrow)?;
+        if BytesN::from(env.crypto().sha256(&preimage)) != anon.depositor_commitment {
+            return Err(ClaimError::InvalidPreimage.into());
+        }
+        let depositor =
+            Self::depositor_from_preimage(&env, &preimage).ok_or(ClaimError::InvalidPreimage)?;
+        depositor.require_auth();
+
+        let escrow = Escrow {
+            depositor: depositor.clone(),
+            amount: anon.amount,
+            remaining_amount: anon.remaining_amount,
+            status: anon.status,
+            deadline: anon.deadline,
+            refund_history: anon.refund_history,
+        };
+        env.storage()
+            .persistent()
+            .remove(&DataKey::EscrowAnon(bounty_id));
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+        Self::stamp_escrow_schema(&env, bounty_id);
+        let share: EscrowSummaryShare = env
+            .storage()
+            .persistent()
+            .get(&AccountingKey::SummaryShare(bounty_id))
+            .unwrap_or_default();
+        Self::apply_depositor_share(&env, &depositor, &EscrowSummaryShare::default(), &share);
+        Self::update_reputation(
+            &env,
+            &depositor,
+            escrow.deadline,
+            &EscrowSummaryShare::default(),
+            &share,
+        );
+        env.storage()
+            .persistent()
+            .remove(&DataKey::ResolverApprovals(bounty_id));
+
+        let mut depositor_index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::DepositorIndex(depositor.clone()))
+            .unwrap_or(Vec::new(&env));
+        depositor_index.push_back(bounty_id);
+        env.storage().persistent().set(
+            &DataKey::DepositorIndex(depositor.clone()),
+            &depositor_index,
+        );
+
+        events::emit_depositor_revealed(
+            &env,
+            events::DepositorRevealed {
+                bounty_id,
+                depositor_commitment: anon.depositor_commitment,
+                depositor: depositor.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(depositor)
+    }
+
+    /// Decode the address at the start of a reveal preimage. An address
+    /// `ScVal` is 44 bytes for accounts and 40 bytes for contracts; the
+    /// ScAddress discriminant sits in byte 7.
+    fn depositor_from_preimage(env: &Env, preimage: &Bytes) -> Option<Address> {
+        let len = match preimage.get(7)? {
+            0 => 44,
+            1 => 40,
+            _ => return None,
+        };
+        if preimage.len() < len {
+            return None;
+        }
+        Address::from_xdr(env, &preimage.slice(0..len)).ok()
+    }
+
+    /// Delegated refund path using a capability.
+    /// This can be used for short-lived, bounded delegated refunds without granting admin rights.
+    pub fn refund_with_capability(
+        env: Env,
+        bounty_id: u64,
+        amount: i128,
+        holder: Address,
+        capability_id: u64,
+    ) -> Result<(), soroban_sdk::Error> {
+        if Self::check_paused(&env, symbol_short!("refund")) {
+            return Err(Error::FundsPaused.into());
+        }
+        if amount <= 0 {
+            return Err(Error::InvalidAmount.into());
+        }
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound.into());
+        }
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
+        {
+            return Err(Error::FundsNotLocked.into());
+        }
+        if amount > escrow.remaining_amount {
+            return Err(Error::InvalidAmount.into());
+        }
+
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::PendingClaim(bounty_id))
+        {
+            let claim: ClaimRecord = env
+                .storage()
+                .persistent()
+                .get(&DataKey::PendingClaim(bounty_id))
+                .unwrap();
+            if !claim.claimed {
+                return Err(Error::ClaimPending.into());
+            }
+        }
+
+        Self::consume_capability(
+            &env,
+            &holder,
+            capability_id,
+            CapabilityAction::Refund,
+            bounty_id,
+            amount,
+        )?;
+
+        let token_addr = Self::bounty_token(&env, bounty_id);
+        let client = token::Client::new(&env, &token_addr);
+        let now = env.ledger().timestamp();
+        let refund_to = escrow.depositor.clone();
+        Self::require_not_blocked(&env, &refund_to)?;
+        Self::apply_jurisdiction(&env, bounty_id, symbol_short!("refund"))?;
+
+        client.transfer(&env.current_contract_address(), &refund_to, &amount);
+
+        escrow.remaining_amount -= amount;
+        if escrow.remaining_amount == 0 {
+            escrow.status = EscrowStatus::Refunded;
+        } else {
+            escrow.status = EscrowStatus::PartiallyRefunded;
+        }
+
+        escrow.refund_history.push_back(RefundRecord {
+            amount,
+            recipient: refund_to.clone(),
+            timestamp: now,
+            mode: if escrow.status == EscrowStatus::Refunded {
+                RefundMode::Full
+            } else {
+                RefundMode::Partial
+            },
+        });
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+        Self::index_status(&env, bounty_id, &escrow.status);
+        Self::sync_escrow_accounting(&env, bounty_id, &escrow);
+
+        emit_funds_refunded(
+            &env,
+            FundsRefunded {
+                version: EVENT_VERSION_V3,
+                bounty_id,
+                amount,
+                remaining_before: escrow.remaining_amount + amount,
+                remaining_after: escrow.remaining_amount,
+                refund_to,
+                timestamp: now,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// view function to get escrow info
+    pub fn get_escrow_info(env: Env, bounty_id: u64) -> Result<Escrow, soroban_sdk::Error> {
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound.into());
+        }
+        Self::migrate_escrow_record(&env, bounty_id);
+        Ok(env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap())
+    }
+
+    /// Unified escrow view covering both regular and anonymous escrows.
+    fn escrow_info(env: &Env, bounty_id: u64) -> Result<EscrowInfo, soroban_sdk::Error> {
+        Self::migrate_escrow_record(env, bounty_id);
+        if let Some(escrow) = env
+            .storage()
+            .persistent()
+            .get::<_, Escrow>(&DataKey::Escrow(bounty_id))
+        {
+            return Ok(EscrowInfo {
+                depositor: AnonymousParty::Address(escrow.depositor),
+                amount: escrow.amount,
+                remaining_amount: escrow.remaining_amount,
+                status: escrow.status,
+                deadline: escrow.deadline,
+                refund_history: escrow.refund_history,
+            });
+        }
+        let anon: AnonymousEscrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EscrowAnon(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        Ok(EscrowInfo {
+            depositor: AnonymousParty::Commitment(anon.depositor_commitment),
+            amount: anon.amount,
+            remaining_amount: anon.remaining_amount,
+            status: anon.status,
+            deadline: anon.deadline,
+            refund_history: anon.refund_history,
+        })
+    }
+
+    /// Non-identifying escrow view for public consumers: amount order of
+    /// magnitude, status and deadline only.
+    pub fn get_escrow_info_redacted(
+        env: Env,
+        bounty_id: u64,
+    ) -> Result<RedactedEscrowInfo, soroban_sdk::Error> {
+        let info = Self::escrow_info(&env, bounty_id)?;
+        let amount_bucket = if info.amount > 0 {
+            info.amount.ilog10()
+        } else {
+            0
+        };
+        Ok(RedactedEscrowInfo {
+            amount_bucket,
+            status: info.status,
+            deadline: info.deadline,
+        })
+    }
+
+    /// Full escrow view for a viewer the depositor authorized with
+    /// `authorize_viewer`. The depositor, admin and auditors can always read
+    /// it.
+    pub fn get_escrow_info_v2(
+        env: Env,
+        bounty_id: u64,
+        viewer: Address,
+    ) -> Result<EscrowInfo, soroban_sdk::Error> {
+        viewer.require_auth();
+        let info = Self::escrow_info(&env, bounty_id)?;
+        let is_depositor = info.depositor == AnonymousParty::Address(viewer.clone());
+        let is_admin =
+            env.storage().instance().get::<_, Address>(&DataKey::Admin) == Some(viewer.clone());
+        if !is_depositor
+            && !is_admin
+            && !rbac::is_auditor(&env, &viewer)
+            && !Self::get_authorized_viewers(env.clone(), bounty_id).contains(&viewer)
+        {
+            return Err(Error::Unauthorized.into());
+        }
+        Ok(info)
+    }
+
+    /// Auditor-only view of an anonymous escrow: the sealed record together
+    /// with its reserved payout, pending resolver approval and viewer grants.
+    pub fn get_anonymous_escrow_audit(
+        env: Env,
+        bounty_id: u64,
+        auditor: Address,
+    ) -> Result<AnonymousEscrowAudit, soroban_sdk::Error> {
+        auditor.require_auth();
+        if !rbac::is_auditor(&env, &auditor) {
+            return Err(Error::Unauthorized.into());
+        }
+        let escrow: AnonymousEscrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EscrowAnon(bounty_id))
+            .ok_or(Error::NotAnonymousEscrow)?;
+        let payout = match env
+            .storage()
+            .persistent()
+            .get(&TicketKey::AnonymousPayout(bounty_id))
+        {
+            Some(payout) => OptionalAnonymousPayout::Some(payout),
+            None => OptionalAnonymousPayout::None,
+        };
+        let resolver_approval = match env
+            .storage()
+            .persistent()
+            .get(&DataKey::ResolverApprovals(bounty_id))
+        {
+            Some(approval) => OptionalResolverApproval::Some(approval),
+            None => OptionalResolverApproval::None,
+        };
+        Ok(AnonymousEscrowAudit {
+            escrow,
+            payout,
+            resolver_approval,
+            authorized_viewers: Self::get_authorized_viewers(env.clone(), bounty_id),
+        })
+    }
+
+    /// Grant `viewer` access to `get_escrow_info_v2` for a bounty. Requires
+    /// the depositor; anonymous escrows have no known depositor, so the
+    /// admin grants access for those.
+    pub fn authorize_viewer(
+        env: Env,
+        bounty_id: u64,
+        viewer: Address,
+    ) -> Result<(), soroban_sdk::Error> {
+        Self::viewer_grantor(&env, bounty_id)?.require_auth();
+        let mut viewers = Self::get_authorized_viewers(env.clone(), bounty_id);
+        if !viewers.contains(&viewer) {
+            viewers.push_back(viewer);
+            env.storage()
+                .persistent()
+                .set(&DataKey::AuthorizedViewers(bounty_id), &viewers);
+        }
+        Ok(())
+    }
+
+    /// Withdraw a viewer grant made with `authorize_viewer`.
+    pub fn revoke_viewer(
+        env: Env,
+        bounty_id: u64,
+        viewer: Address,
+    ) -> Result<(), soroban_sdk::Error> {
+        Self::viewer_grantor(&env, bounty_id)?.require_auth();
+        let mut viewers = Self::get_authorized_viewers(env.clone(), bounty_id);
+        if let Some(index) = viewers.first_index_of(&viewer) {
+            viewers.remove(index);
+            env.storage()
+                .persistent()
+                .set(&DataKey::AuthorizedViewers(bounty_id), &viewers);
+        }
+        Ok(())
+    }
+
+    /// Addresses granted full-info access for a bounty.
+    pub fn get_authorized_viewers(env: Env, bounty_id: u64) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::AuthorizedViewers(bounty_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Internal: the address allowed to manage viewer grants for a bounty.
+    fn viewer_grantor(env: &Env, bounty_id: u64) -> Result<Address, soroban_sdk::Error> {
+        match Self::escrow_info(env, bounty_id)?.depositor {
+            AnonymousParty::Address(depositor) => Ok(depositor),
+            AnonymousParty::Commitment(_) => env
+                .storage()
+                .instance()
+                .get(&DataKey::Admin)
+                .ok_or(Error::NotInitialized.into()),
+        }
+    }
+
+    /// view function to get contract balance of the token
+    pub fn get_balance(env: Env) -> Result<i128, soroban_sdk::Error> {
+        if !env.storage().instance().has(&DataKey::Token) {
+            return Err(Error::NotInitialized.into());
+        }
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        Ok(client.balance(&env.current_contract_address()))
+    }
+
+    /// Funds-conservation view used by grainlify-core's invariant checks.
+    ///
+    /// Returns `(tracked, held)`: the remaining amount across active escrows
+    /// and the token balance the contract holds. Both are 0 before `init`.
+    pub fn reconcile_balances(env: Env) -> (i128, i128) {
+        if !env.storage().instance().has(&DataKey::Token) {
+            return (0, 0);
+        }
+        (
+            multitoken_invariants::sum_active_escrow_balances(&env),
+            multitoken_invariants::get_contract_token_balance(&env),
+        )
+    }
+
+    /// Return a detailed invariant report for auditors and monitoring tools.
+    ///
+    /// Checks that the remaining amount across active escrows equals the
+    /// contract's token balance, that no released or refunded escrow still
+    /// reports a remaining amount, and that every claim ticket references an
+    /// escrow that still exists.
+    pub fn check_invariants(env: Env) -> Result<EscrowInvariantReport, soroban_sdk::Error> {
+        if !env.storage().instance().has(&DataKey::Token) {
+            return Err(Error::NotInitialized.into());
+        }
+        let (tracked_balance, token_balance) = Self::reconcile_balances(env.clone());
+        let funds_conserved = tracked_balance == token_balance;
+
+        let index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EscrowIndex)
+            .unwrap_or(Vec::new(&env));
+        let mut settled_with_remaining: Vec<u64> = Vec::new(&env);
+        for bounty_id in index.iter() {
+            let (status, remaining) = if let Some(escrow) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+            {
+                (escrow.status, escrow.remaining_amount)
+            } else if let Some(anon) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, AnonymousEscrow>(&DataKey::EscrowAnon(bounty_id))
+            {
+                (anon.status, anon.remaining_amount)
+            } else {
+                continue;
+            };
+            if matches!(status, EscrowStatus::Released | EscrowStatus::Refunded) && remaining != 0 {
+                settled_with_remaining.push_back(bounty_id);
+            }
+        }
+
+        let tickets: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ClaimTicketIndex)
+            .unwrap_or(Vec::new(&env));
+        let mut orphaned_tickets: Vec<u64> = Vec::new(&env);
+        for ticket_id in tickets.iter() {
+            let live = match env
+                .storage()
+                .persistent()
+                .get::<DataKey, ClaimTicket>(&DataKey::ClaimTicket(ticket_id))
+            {
+                Some(ticket) => {
+                    env.storage()
+                        .persistent()
+                        .has(&DataKey::Escrow(ticket.bounty_id))
+                        || env
+                            .storage()
+                            .persistent()
+                            .has(&DataKey::EscrowAnon(ticket.bounty_id))
+                }
+                None => false,
+            };
+            if !live {
+                orphaned_tickets.push_back(ticket_id);
+            }
+        }
+
+        let mut violation_count = settled_with_remaining.len() + orphaned_tickets.len();
+        if !funds_conserved {
+            violation_count += 1;
+        }
+        Ok(EscrowInvariantReport {
+            healthy: violation_count == 0,
+            tracked_balance,
+            token_balance,
+            funds_conserved,
+            escrows_checked: index.len(),
+            settled_with_remaining,
+            tickets_checked: tickets.len(),
+            orphaned_tickets,
+            violation_count,
+        })
+    }
+
+    /// Lightweight invariant verdict for frequent monitoring calls.
+    pub fn verify_invariants(env: Env) -> bool {
+        Self::check_invariants(env)
+            .map(|report| report.healthy)
+            .unwrap_or(false)
+    }
+
+    /// Query escrows with filtering and pagination
+    /// Pass 0 for min values and i128::MAX/u64::MAX for max values to disable those filters
+    pub fn query_escrows_by_status(
+        env: Env,
+        status: EscrowStatus,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<EscrowWithId> {
+        let index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EscrowIndex)
+            .unwrap_or(Vec::new(&env));
+        let mut results = Vec::new(&env);
+        let mut count = 0u32;
+        let mut skipped = 0u32;
+
+        for i in 0..index.len() {
+            if count >= limit {
+                break;
+            }
+
+            let bounty_id = index.get(i).unwrap();
+            if let Some(escrow) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+            {
+                if escrow.status == status {
+                    if skipped < offset {
+                        skipped += 1;
+                        continue;
+                    }
+                    results.push_back(EscrowWithId { bounty_id, escrow });
+                    count += 1;
+                }
+            }
+        }
+        results
+    }
+
+    /// Query escrows with amount range filtering
+    pub fn query_escrows_by_amount(
+        env: Env,
+        min_amount: i128,
+        max_amount: i128,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<EscrowWithId> {
+        let index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EscrowIndex)
+            .unwrap_or(Vec::new(&env));
+        let mut results = Vec::new(&env);
+        let mut count = 0u32;
+        let mut skipped = 0u32;
+
+        for i in 0..index.len() {
+            if count >= limit {
+                break;
+            }
+
+            let bounty_id = index.get(i).unwrap();
+            if let Some(escrow) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+            {
+                if escrow.amount >= min_amount && escrow.amount <= max_amount {
+                    if skipped < offset {
+                        skipped += 1;
+                        continue;
+                    }
+                    results.push_back(EscrowWithId { bounty_id, escrow });
+                    count += 1;
+                }
+            }
+        }
+        results
+    }
+
+    /// Query escrows with deadline range filtering
+    pub fn query_escrows_by_deadline(
+        env: Env,
+        min_deadline: u64,
+        max_deadline: u64,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<EscrowWithId> {
+        let index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EscrowIndex)
+            .unwrap_or(Vec::new(&env));
+        let mut results = Vec::new(&env);
+        let mut count = 0u32;
+        let mut skipped = 0u32;
+
+        for i in 0..index.len() {
+            if count >= limit {
+                break;
+            }
+
+            let bounty_id = index.get(i).unwrap();
+            if let Some(escrow) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+            {
+                if escrow.deadline >= min_deadline && escrow.deadline <= max_deadline {
+                    if skipped < offset {
+                        skipped += 1;
+                        continue;
+                    }
+                    results.push_back(EscrowWithId { bounty_id, escrow });
+                    count += 1;
+                }
+            }
+        }
+        results
+    }
+
+    /// Query escrows by depositor
+    pub fn query_escrows_by_depositor(
+        env: Env,
+        depositor: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<EscrowWithId> {
+        let index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::DepositorIndex(depositor))
+            .unwrap_or(Vec::new(&env));
+        let mut results = Vec::new(&env);
+        let start = offset.min(index.len());
+        let end = (offset + limit).min(index.len());
+
+        for i in start..end {
+            let bounty_id = index.get(i).unwrap();
+            if let Some(escrow) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+            {
+                results.push_back(EscrowWithId { bounty_id, escrow });
+            }
+        }
+        results
+    }
+
+    /// Get aggregate statistics
+    pub fn get_aggregate_stats(env: Env) -> AggregateStats {
+        let index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EscrowIndex)
+            .unwrap_or(Vec::new(&env));
+        let mut stats = AggregateStats {
+            total_locked: 0,
+            total_released: 0,
+            total_refunded: 0,
+            count_locked: 0,
+            count_released: 0,
+            count_refunded: 0,
+        };
+
+        for i in 0..index.len() {
+            let bounty_id = index.get(i).unwrap();
+            if let Some(escrow) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+            {
+                match escrow.status {
+                    EscrowStatus::Locked | EscrowStatus::PendingRelease => {
+                        stats.total_locked += escrow.amount;
+                        stats.count_locked += 1;
+                    }
+                    EscrowStatus::Released => {
+                        stats.total_released += escrow.amount;
+                        stats.count_released += 1;
+                    }
+                    EscrowStatus::Refunded | EscrowStatus::PartiallyRefunded => {
+                        stats.total_refunded += escrow.amount;
+                        stats.count_refunded += 1;
+                    }
+                }
+            }
+        }
+        stats
+    }
+
+    /// List escrows in creation order, regular and anonymous alike.
+    pub fn list_escrows(env: Env, offset: u32, limit: u32) -> Vec<EscrowInfoWithId> {
+        let index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EscrowIndex)
+            .unwrap_or(Vec::new(&env));
+        Self::escrow_page(&env, &index, offset, limit)
+    }
+
+    /// List escrows currently in `status`, read from the maintained status
+    /// index rather than a scan of every escrow. Within a status, entries are
+    /// ordered by when they entered it.
+    pub fn list_escrows_by_status(
+        env: Env,
+        status: EscrowStatus,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<EscrowInfoWithId> {
+        let index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&AccountingKey::StatusIndex(status))
+            .unwrap_or(Vec::new(&env));
+        Self::escrow_page(&env, &index, offset, limit)
+    }
+
+    fn escrow_page(env: &Env, index: &Vec<u64>, offset: u32, limit: u32) -> Vec<EscrowInfoWithId> {
+        let mut results = Vec::new(env);
+        let start = offset.min(index.len());
+        let end = offset.saturating_add(limit).min(index.len());
+        for i in start..end {
+            let bounty_id = index.get(i).unwrap();
+            if let Ok(info) = Self::escrow_info(env, bounty_id) {
+                results.push_back(EscrowInfoWithId { bounty_id, info });
+            }
+        }
+        results
+    }
+
+    /// Files `bounty_id` under `status` in the status index, moving it out of
+    /// the list it was previously filed under. No-op when unchanged.
+    fn index_status(env: &Env, bounty_id: u64, status: &EscrowStatus) {
+        let previous: Option<EscrowStatus> = env
+            .storage()
+            .persistent()
+            .get(&AccountingKey::IndexedStatus(bounty_id));
+        if previous.as_ref() == Some(status) {
+            return;
+        }
+        if let Some(previous) = previous {
+            let key = AccountingKey::StatusIndex(previous);
+            let mut ids: Vec<u64> = env
+                .storage()
+                .persistent()
+                .get(&key)
+                .unwrap_or(Vec::new(env));
+            if let Some(pos) = ids.first_index_of(bounty_id) {
+                ids.remove(pos);
+            }
+            env.storage().persistent().set(&key, &ids);
+        }
+        let key = AccountingKey::StatusIndex(status.clone());
+        let mut ids: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env));
+        ids.push_back(bounty_id);
+        env.storage().persistent().set(&key, &ids);
+        env.storage()
+            .persistent()
+            .set(&AccountingKey::IndexedStatus(bounty_id), status);
+    }
+
+    /// Get total count of escrows
+    pub fn get_escrow_count(env: Env) -> u32 {
+        let index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EscrowIndex)
+            .unwrap_or(Vec::new(&env));
+        index.len()
+    }
+
+    /// Set the minimum and maximum allowed lock amount (admin only).
+    ///
+    /// Once set, any call to lock_funds with an amount outside [min_amount, max_amount]
+    /// will be rejected with AmountBelowMinimum or AmountAboveMaximum respectively.
+    /// The policy can be updated at any time by the admin; new limits take effect
+    /// immediately for subsequent lock_funds calls.
+    ///
+    /// Passing min_amount == max_amount restricts locking to a single exact value.
+    /// min_amount must not exceed max_amount — the call panics if this invariant
+    /// is violated.
+    pub fn set_amount_policy(
+        env: Env,
+        caller: Address,
+        min_amount: i128,
+        max_amount: i128,
+    ) -> Result<(), soroban_sdk::Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized.into());
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if caller != admin {
+            return Err(Error::Unauthorized.into());
+        }
+        admin.require_auth();
+
+        if min_amount > max_amount {
+            panic!("invalid policy: min_amount cannot exceed max_amount");
+        }
+
+        // Persist the policy so lock_funds can enforce it on every subsequent call.
+        env.storage()
+            .instance()
+            .set(&DataKey::AmountPolicy, &(min_amount, max_amount));
+
+        Ok(())
+    }
+
+    /// Get escrow IDs by status
+    pub fn get_escrow_ids_by_status(
+        env: Env,
+        status: EscrowStatus,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<u64> {
+        let index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EscrowIndex)
+            .unwrap_or(Vec::new(&env));
+        let mut results = Vec::new(&env);
+        let mut count = 0u32;
+        let mut skipped = 0u32;
+
+        for i in 0..index.len() {
+            if count >= limit {
+                break;
+            }
+            let bounty_id = index.get(i).unwrap();
+            if let Some(escrow) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+            {
+                if escrow.status == status {
+                    if skipped < offset {
+                        skipped += 1;
+                        continue;
+                    }
+                    results.push_back(bounty_id);
+                    count += 1;
+                }
+            }
+        }
+        results
+    }
+
+    pub fn set_anti_abuse_admin(env: Env, admin: Address) -> Result<(), soroban_sdk::Error> {
+        let current: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        current.require_auth();
+        anti_abuse::set_admin(&env, admin);
+        Ok(())
+    }
+
+    pub fn get_anti_abuse_admin(env: Env) -> Option<Address> {
+        anti_abuse::get_admin(&env)
+    }
+
+    /// Grant or revoke the read-only auditor role (admin only).
+    pub fn set_auditor(
+        env: Env,
+        auditor: Address,
+        enabled: bool,
+    ) -> Result<(), soroban_sdk::Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        let key = ComplianceKey::Auditor(auditor);
+        if enabled {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        Ok(())
+    }
+
+    pub fn is_auditor(env: Env, addr: Address) -> bool {
+        rbac::is_auditor(&env, &addr)
+    }
+
+    /// Set whitelist status for an address (admin only). Named to avoid SDK client method conflict.
+    /// In AllowlistOnly mode this determines who may participate; in other modes it only affects anti-abuse bypass.
+    pub fn set_whitelist_entry(
+        env: Env,
+        whitelisted_address: Address,
+        whitelisted: bool,
+    ) -> Result<(), soroban_sdk::Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        anti_abuse::set_whitelist(&env, whitelisted_address, whitelisted);
+        Ok(())
+    }
+
+    /// Set participant filter mode (admin only). Mutually exclusive: Disabled, BlocklistOnly, or AllowlistOnly.
+    /// Emits ParticipantFilterModeChanged. Transitioning modes does not clear list data; only the active mode is enforced.
+    pub fn set_filter_mode(
+        env: Env,
+        new_mode: ParticipantFilterMode,
+    ) -> Result<(), soroban_sdk::Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        let previous = Self::get_participant_filter_mode(&env);
+        env.storage()
+            .instance()
+            .set(&DataKey::ParticipantFilterMode, &new_mode);
+        emit_participant_filter_mode_changed(
+            &env,
+            ParticipantFilterModeChanged {
+                previous_mode: previous,
+                new_mode,
+                admin: admin.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// View: current participant filter mode (default Disabled).
+    pub fn get_filter_mode(env: Env) -> ParticipantFilterMode {
+        Self::get_participant_filter_mode(&env)
+    }
+
+    /// Set blocklist status for an address (admin only). Only enforced when mode is BlocklistOnly.
+    pub fn set_blocklist_entry(
+        env: Env,
+        address: Address,
+        blocked: bool,
+    ) -> Result<(), soroban_sdk::Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        anti_abuse::set_blocklist(&env, address, blocked);
+        Ok(())
+    }
+
+    /// Update anti-abuse config (rate limit window, max operations per window, cooldown). Admin only.
+    pub fn update_anti_abuse_config(
+        env: Env,
+        window_size: u64,
+        max_operations: u32,
+        cooldown_period: u64,
+    ) -> Result<(), soroban_sdk::Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        let config = anti_abuse::AntiAbuseConfig {
+            window_size,
+            max_operations,
+            cooldown_period,
+        };
+        anti_abuse::set_config(&env, config);
+        Ok(())
+    }
+
+    /// Get current anti-abuse config (rate limit and cooldown).
+    pub fn get_anti_abuse_config(env: Env) -> AntiAbuseConfigView {
+        let c = anti_abuse::get_config(&env);
+        AntiAbuseConfigView {
+            window_size: c.window_size,
+            max_operations: c.max_operations,
+            cooldown_period: c.cooldown_period,
+        }
+    }
+
+    /// Cap how many locks, and how much value, each depositor may lock per
+    /// `window_size` seconds (admin only). A zero `max_locks` or
+    /// `max_locked_value` leaves that dimension unlimited; a zero
+    /// `window_size` removes the limit. Whitelisted addresses are exempt.
+    pub fn set_lock_rate_limit(
+        env: Env,
+        window_size: u64,
+        max_locks: u32,
+        max_locked_value: i128,
+    ) -> Result<(), soroban_sdk::Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        if max_locked_value < 0 {
+            return Err(Error::InvalidAmount.into());
+        }
+        if window_size == 0 {
+            env.storage()
+                .instance()
+                .remove(&ComplianceKey::LockRateLimit);
+        } else {
+            env.storage().instance().set(
+                &ComplianceKey::LockRateLimit,
+                &LockRateLimit {
+                    window_size,
+                    max_locks,
+                    max_locked_value,
+                },
+            );
+        }
+        Ok(())
+    }
+
+    /// Current per-depositor lock limit, if one is configured.
+    pub fn get_lock_rate_limit(env: Env) -> Option<LockRateLimit> {
+        env.storage().instance().get(&ComplianceKey::LockRateLimit)
+    }
+
+    /// A depositor's usage in its current lock rate-limit window.
+    pub fn get_lock_window(env: Env, depositor: Address) -> Option<LockWindow> {
+        env.storage()
+            .persistent()
+            .get(&ComplianceKey::LockWindow(depositor))
+    }
+
+    /// Appoint the compliance officer who manages the address block list
+    /// (admin only). Until one is set the admin manages the list.
+    pub fn set_compliance_admin(env: Env, compliance: Address) -> Result<(), soroban_sdk::Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        env.storage()
+            .instance()
+            .set(&ComplianceKey::ComplianceAdmin, &compliance);
+        Ok(())
+    }
+
+    /// Current compliance officer, if one has been appointed.
+    pub fn get_compliance_admin(env: Env) -> Option<Address> {
+        env.storage()
+            .instance()
+            .get(&ComplianceKey::ComplianceAdmin)
+    }
+
+    /// Block or unblock `address` for compliance reasons, e.g. sanctions.
+    ///
+    /// Unlike the participant filter this list is always enforced: a blocked
+    /// address cannot lock funds, receive a release, receive a refund, or
+    /// claim a pending claim or ticket. Requires the compliance officer, or
+    /// the admin when none is set.
+    pub fn set_address_blocked(
+        env: Env,
+        address: Address,
+        blocked: bool,
+    ) -> Result<(), soroban_sdk::Error> {
+        let authority = Self::compliance_authority(&env)?;
+        authority.require_auth();
+
+        let key = ComplianceKey::BlockedAddress(address.clone());
+        if blocked {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        events::emit_address_blocked(
+            &env,
+            events::AddressBlockedChanged {
+                address,
+                blocked,
+                changed_by: authority,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Whether `address` is on the compliance block list.
+    pub fn is_blocked(env: Env, address: Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&ComplianceKey::BlockedAddress(address))
+    }
+
+    /// Register the screening contract consulted before every lock and payout,
+    /// or pass `None` to stop screening (compliance role).
+    ///
+    /// The contract must expose `screen(address: Address, amount: i128) ->
+    /// bool`, returning `false` to deny. A denied or failed call blocks the
+    /// operation with `ScreeningRejected` unless the address has an override.
+    pub fn set_screening_contract(
+        env: Env,
+        screener: Option<Address>,
+    ) -> Result<(), soroban_sdk::Error> {
+        Self::compliance_authority(&env)?.require_auth();
+        match screener {
+            Some(screener) => env
+                .storage()
+                .instance()
+                .set(&ComplianceKey::ScreeningContract, &screener),
+            None => env
+                .storage()
+                .instance()
+                .remove(&ComplianceKey::ScreeningContract),
+        }
+        Ok(())
+    }
+
+    pub fn get_screening_contract(env: Env) -> Option<Address> {
+        env.storage()
+            .instance()
+            .get(&ComplianceKey::ScreeningContract)
+    }
+
+    /// Let `address` through despite a screening denial, e.g. after a false
+    /// positive was cleared manually (compliance role). Granting, revoking
+    /// and every use of an override emit an event.
+    pub fn set_screening_override(
+        env: Env,
+        address: Address,
+        enabled: bool,
+    ) -> Result<(), soroban_sdk::Error> {
+        let authority = Self::compliance_authority(&env)?;
+        authority.require_auth();
+
+        let key = ComplianceKey::ScreeningOverride(address.clone());
+        if enabled {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        events::emit_screening_override(
+            &env,
+            events::ScreeningOverrideChanged {
+                address,
+                enabled,
+                changed_by: authority,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Whether `address` has a screening override.
+    pub fn has_screening_override(env: Env, address: Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&ComplianceKey::ScreeningOverride(address))
+    }
+
+    /// Assign an identity verification tier to `address` (compliance role).
+    pub fn set_identity_tier(
+        env: Env,
+        address: Address,
+        tier: IdentityTier,
+    ) -> Result<(), soroban_sdk::Error> {
+        let authority = Self::compliance_authority(&env)?;
+        authority.require_auth();
+        env.storage()
+            .persistent()
+            .set(&ComplianceKey::IdentityTier(address.clone()), &tier);
+        events::emit_identity_tier_set(
+            &env,
+            events::IdentityTierSet {
+                address,
+                tier,
+                changed_by: authority,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Identity tier of `address`; `Unverified` when none was assigned.
+    pub fn get_identity_tier(env: Env, address: Address) -> IdentityTier {
+        env.storage()
+            .persistent()
+            .get(&ComplianceKey::IdentityTier(address))
+            .unwrap_or(IdentityTier::Unverified)
+    }
+
+    /// Set the lock limits for a tier (compliance role). Tiers without
+    /// limits are unrestricted; zero caps clear them.
+    pub fn set_tier_limit(
+        env: Env,
+        tier: IdentityTier,
+        max_single_lock: i128,
+        max_exposure: i128,
+    ) -> Result<(), soroban_sdk::Error> {
+        Self::compliance_authority(&env)?.require_auth();
+        if max_single_lock < 0 || max_exposure < 0 {
+            return Err(Error::InvalidAmount.into());
+        }
+        if max_single_lock == 0 && max_exposure == 0 {
+            env.storage()
+                .instance()
+                .remove(&ComplianceKey::TierLimit(tier));
+        } else {
+            env.storage().instance().set(
+                &ComplianceKey::TierLimit(tier),
+                &TierLimit {
+                    max_single_lock,
+                    max_exposure,
+                },
+            );
+        }
+        Ok(())
+    }
+
+    /// Lock limits configured for a tier, if any.
+    pub fn get_tier_limit(env: Env, tier: IdentityTier) -> Option<TierLimit> {
+        env.storage()
+            .instance()
+            .get(&ComplianceKey::TierLimit(tier))
+    }
+
+    /// Remaining balance across the depositor's active escrows.
+    pub fn get_depositor_exposure(env: Env, depositor: Address) -> i128 {
+        let bounty_ids: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::DepositorIndex(depositor))
+            .unwrap_or(Vec::new(&env));
+        let mut exposure: i128 = 0;
+        for bounty_id in bounty_ids.iter() {
+            if let Some(escrow) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+            {
+                match escrow.status {
+                    EscrowStatus::Locked
+                    | EscrowStatus::PartiallyRefunded
+                    | EscrowStatus::PendingRelease => {
+                        exposure = exposure.saturating_add(escrow.remaining_amount);
+                    }
+                    _ => {}
+                }
+            }
+        }
+        exposure
+    }
+
+    /// Active escrow count, currently locked balance and lifetime released and
+    /// refunded totals for `depositor`, kept up to date on every escrow write.
+    pub fn get_depositor_summary(env: Env, depositor: Address) -> DepositorSummary {
+        env.storage()
+            .persistent()
+            .get(&AccountingKey::DepositorSummary(depositor))
+            .unwrap_or_default()
+    }
+
+    /// On-time payment, dispute and refund record for `depositor`, with an
+    /// overall basis-point score.
+    pub fn get_depositor_reputation(env: Env, depositor: Address) -> DepositorReputation {
+        let c = Self::reputation_counters(&env, &depositor);
+        let rate = |part: u32, whole: u32, empty: u32| -> u32 {
+            if whole == 0 {
+                empty
+            } else {
+                ((part.min(whole) as u64 * BASIS_POINTS as u64) / whole as u64) as u32
+            }
+        };
+        let on_time = rate(c.on_time_payouts, c.payouts, BASIS_POINTS as u32);
+        let dispute = rate(c.disputes, c.escrows, 0);
+        let refund = rate(c.refunds, c.escrows, 0);
+        let max = BASIS_POINTS as u32;
+        let overall = (on_time * 2 + (max - dispute) + (max - refund)) / 4;
+        DepositorReputation {
+            total_escrows: c.escrows,
+            total_payouts: c.payouts,
+            on_time_payouts: c.on_time_payouts,
+            refund_count: c.refunds,
+            dispute_count: c.disputes,
+            on_time_payment_rate_bps: on_time,
+            dispute_rate_bps: dispute,
+            refund_rate_bps: refund,
+            overall_score_bps: overall,
+        }
+    }
+
+    fn reputation_counters(env: &Env, depositor: &Address) -> ReputationCounters {
+        env.storage()
+            .persistent()
+            .get(&AccountingKey::ReputationCounters(depositor.clone()))
+            .unwrap_or_default()
+    }
+
+    /// Internal: count a new escrow, its first payout (on time if at or
+    /// before `deadline`) and its first refund as they happen.
+    fn update_reputation(
+        env: &Env,
+        depositor: &Address,
+        deadline: u64,
+        previous: &EscrowSummaryShare,
+        share: &EscrowSummaryShare,
+    ) {
+        let mut counters = Self::reputation_counters(env, depositor);
+        if *previous == EscrowSummaryShare::default() {
+            counters.escrows += 1;
+        }
+        if previous.released == 0 && share.released > 0 {
+            counters.payouts += 1;
+            if env.ledger().timestamp() <= deadline {
+                counters.on_time_payouts += 1;
+            }
+        }
+        if previous.refunded == 0 && share.refunded > 0 {
+            counters.refunds += 1;
+        }
+        env.storage().persistent().set(
+            &AccountingKey::ReputationCounters(depositor.clone()),
+            &counters,
+        );
+    }
+
+    /// Internal: fold the change in `escrow` since it was last counted into
+    /// the contract-wide accounting totals and its depositor's summary.
+    fn sync_escrow_accounting(env: &Env, bounty_id: u64, escrow: &Escrow) {
+        let share = Self::summary_share(
+            &escrow.status,
+            escrow.amount,
+            escrow.remaining_amount,
+            &escrow.refund_history,
+        );
+        if let Some(previous) = Self::record_share(env, bounty_id, &share) {
+            Self::apply_depositor_share(env, &escrow.depositor, &previous, &share);
+            Self::update_reputation(env, &escrow.depositor, escrow.deadline, &previous, &share);
+        }
+    }
+
+    /// Internal: as `sync_escrow_accounting` for an anonymous escrow, which
+    /// has no depositor summary until it is revealed.
+    fn sync_anon_accounting(env: &Env, bounty_id: u64, anon: &AnonymousEscrow) {
+        let share = Self::summary_share(
+            &anon.status,
+            anon.amount,
+            anon.remaining_amount,
+            &anon.refund_history,
+        );
+        Self::record_share(env, bounty_id, &share);
+    }
+
+    /// Internal: what an escrow contributes to the totals. Released is derived
+    /// as whatever left the escrow that is not in its refund history.
+    fn summary_share(
+        status: &EscrowStatus,
+        amount: i128,
+        remaining_amount: i128,
+        refund_history: &Vec<RefundRecord>,
+    ) -> EscrowSummaryShare {
+        let active = matches!(
+            status,
+            EscrowStatus::Locked | EscrowStatus::PartiallyRefunded | EscrowStatus::PendingRelease
+        );
+        let mut refunded: i128 = 0;
+        for record in refund_history.iter() {
+            refunded = refunded.saturating_add(record.amount);
+        }
+        EscrowSummaryShare {
+            active,
+            locked: if active { remaining_amount } else { 0 },
+            released: (amount - remaining_amount - refunded).max(0),
+            refunded,
+        }
+    }
+
+    /// Internal: store `share` for `bounty_id` and apply the change to the
+    /// accounting totals. Returns the previous share, or `None` if unchanged.
+    fn record_share(
+        env: &Env,
+        bounty_id: u64,
+        share: &EscrowSummaryShare,
+    ) -> Option<EscrowSummaryShare> {
+        let share_key = AccountingKey::SummaryShare(bounty_id);
+        let previous: EscrowSummaryShare = env
+            .storage()
+            .persistent()
+            .get(&share_key)
+            .unwrap_or_default();
+        if previous == *share {
+            return None;
+        }
+
+        let mut totals = Self::accounting_totals(env);
+        if share.active && !previous.active {
+            totals.active_escrows += 1;
+        } else if !share.active && previous.active {
+            totals.active_escrows = totals.active_escrows.saturating_sub(1);
+        }
+        totals.locked += share.locked - previous.locked;
+        totals.released += share.released - previous.released;
+        totals.refunded += share.refunded - previous.refunded;
+        env.storage()
+            .persistent()
+            .set(&AccountingKey::AccountingTotals, &totals);
+        env.storage().persistent().set(&share_key, share);
+        Some(previous)
+    }
+
+    /// Internal: move `depositor`'s summary from `previous` to `share`.
+    fn apply_depositor_share(
+        env: &Env,
+        depositor: &Address,
+        previous: &EscrowSummaryShare,
+        share: &EscrowSummaryShare,
+    ) {
+        let summary_key = AccountingKey::DepositorSummary(depositor.clone());
+        let mut summary: DepositorSummary = env
+            .storage()
+            .persistent()
+            .get(&summary_key)
+            .unwrap_or_default();
+        if share.active && !previous.active {
+            summary.active_escrows += 1;
+        } else if !share.active && previous.active {
+            summary.active_escrows = summary.active_escrows.saturating_sub(1);
+        }
+        summary.total_locked += share.locked - previous.locked;
+        summary.total_released += share.released - previous.released;
+        summary.total_refunded += share.refunded - previous.refunded;
+        env.storage().persistent().set(&summary_key, &summary);
+    }
+
+    /// Internal: add a collected protocol fee to the accounting totals.
+    fn record_fee_collected(env: &Env, amount: i128) {
+        let mut totals = Self::accounting_totals(env);
+        totals.fees_collected += amount;
+        env.storage()
+            .persistent()
+            .set(&AccountingKey::AccountingTotals, &totals);
+    }
+
+    fn accounting_totals(env: &Env) -> AccountingTotals {
+        env.storage()
+            .persistent()
+            .get(&AccountingKey::AccountingTotals)
+            .unwrap_or_default()
+    }
+
+    /// Record the current accounting totals as a numbered checkpoint (admin
+    /// only) and return its sequence. Finance teams close a period with this
+    /// and later ask `get_accounting_snapshot` for movements since it.
+    pub fn checkpoint_accounting(env: Env) -> Result<u64, soroban_sdk::Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        let sequence: u64 = env
+            .storage()
+            .instance()
+            .get(&AccountingKey::AccountingCheckpointSeq)
+            .unwrap_or(0)
+            + 1;
+        let checkpoint = AccountingCheckpoint {
+            sequence,
+            timestamp: env.ledger().timestamp(),
+            ledger: env.ledger().sequence(),
+            totals: Self::accounting_totals(&env),
+        };
+        env.storage()
+            .persistent()
+            .set(&AccountingKey::AccountingCheckpoint(sequence), &checkpoint);
+        env.storage()
+            .instance()
+            .set(&AccountingKey::AccountingCheckpointSeq, &sequence);
+
+        events::emit_accounting_checkpoint(
+            &env,
+            events::AccountingCheckpointed {
+                sequence,
+                locked: checkpoint.totals.locked,
+                released: checkpoint.totals.released,
+                refunded: checkpoint.totals.refunded,
+                fees_collected: checkpoint.totals.fees_collected,
+                timestamp: checkpoint.timestamp,
+            },
+        );
+        Ok(sequence)
+    }
+
+    /// Enable or retune threshold monitoring (admin only). Releases, ticket
+    /// claims and refunds feed the monitor; when a window's outflow or
+    /// failure count goes over its limit the breaker opens and halts
+    /// releases and ticket claims until `reset_threshold_breaker`.
+    pub fn set_threshold_config(
+        env: Env,
+        config: threshold_monitor::ThresholdConfig,
+    ) -> Result<(), soroban_sdk::Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        threshold_monitor::set_threshold_config(&env, config)?;
+        Ok(())
+    }
+
+    /// Disable threshold monitoring (admin only).
+    pub fn clear_threshold_config(env: Env) -> Result<(), soroban_sdk::Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        threshold_monitor::clear_threshold_config(&env);
+        Ok(())
+    }
+
+    pub fn get_threshold_config(env: Env) -> Option<threshold_monitor::ThresholdConfig> {
+        threshold_monitor::get_threshold_config(&env)
+    }
+
+    pub fn get_threshold_metrics(env: Env) -> threshold_monitor::WindowMetrics {
+        threshold_monitor::get_current_metrics(&env)
+    }
+
+    pub fn get_threshold_breaker(env: Env) -> threshold_monitor::BreakerState {
+        threshold_monitor::get_breaker(&env)
+    }
+
+    /// Close the circuit breaker once its cooldown has elapsed (admin only).
+    pub fn reset_threshold_breaker(env: Env) -> Result<(), soroban_sdk::Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        threshold_monitor::reset_breaker(&env, &admin)?;
+        Ok(())
+    }
+
+    /// Current accounting totals plus the movement since checkpoint
+    /// `since_checkpoint`. Pass 0 to measure from contract deployment.
+    pub fn get_accounting_snapshot(
+        env: Env,
+        since_checkpoint: u64,
+    ) -> Result<AccountingSnapshot, soroban_sdk::Error> {
+        let (base, since_timestamp) = if since_checkpoint == 0 {
+            (AccountingTotals::default(), 0)
+        } else {
+            let checkpoint: AccountingCheckpoint = env
+                .storage()
+                .persistent()
+                .get(&AccountingKey::AccountingCheckpoint(since_checkpoint))
+                .ok_or(LifecycleError::AccountingCheckpointNotFound)?;
+            (checkpoint.totals, checkpoint.timestamp)
+        };
+        let totals = Self::accounting_totals(&env);
+        let deltas = AccountingDeltas {
+            active_escrows: totals.active_escrows as i64 - base.active_escrows as i64,
+            locked: totals.locked - base.locked,
+            released: totals.released - base.released,
+            refunded: totals.refunded - base.refunded,
+            fees_collected: totals.fees_collected - base.fees_collected,
+        };
+        Ok(AccountingSnapshot {
+            totals,
+            deltas,
+            since_checkpoint,
+            since_timestamp,
+            timestamp: env.ledger().timestamp(),
+        })
+    }
+
+    /// Internal: enforce the depositor's tier limits on a lock of
+    /// `single_amount` that adds `added_exposure` to its active balance.
+    fn check_tier_limits(
+        env: &Env,
+        depositor: &Address,
+        single_amount: i128,
+        added_exposure: i128,
+    ) -> Result<(), soroban_sdk::Error> {
+        let tier = Self::get_identity_tier(env.clone(), depositor.clone());
+        let limit = match Self::get_tier_limit(env.clone(), tier) {
+            Some(limit) => limit,
+            None => return Ok(()),
+        };
+        if limit.max_single_lock > 0 && single_amount > limit.max_single_lock {
+            return Err(ComplianceError::TierLimitExceeded.into());
+        }
+        if limit.max_exposure > 0 {
+            let exposure = Self::get_depositor_exposure(env.clone(), depositor.clone());
+            if exposure.saturating_add(added_exposure) > limit.max_exposure {
+                return Err(ComplianceError::ExposureLimitExceeded.into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Internal: the compliance officer, falling back to the admin.
+    fn compliance_authority(env: &Env) -> Result<Address, soroban_sdk::Error> {
+        match env
+            .storage()
+            .instance()
+            .get(&ComplianceKey::ComplianceAdmin)
+        {
+            Some(compliance) => Ok(compliance),
+            None => env
+                .storage()
+                .instance()
+                .get(&DataKey::Admin)
+                .ok_or(Error::NotInitialized.into()),
+        }
+    }
+
+    /// Internal: fail with `AddressBlocked` if `address` is on the block list.
+    fn require_not_blocked(env: &Env, address: &Address) -> Result<(), soroban_sdk::Error> {
+        if Self::is_blocked(env.clone(), address.clone()) {
+            return Err(ComplianceError::AddressBlocked.into());
+        }
+        Ok(())
+    }
+
+    /// Internal: ask the screening contract about `address` moving `amount`.
+    /// Fails closed: a denial or failed call is `ScreeningRejected` unless the
+    /// address has an override, whose use is logged.
+    fn require_screened(
+        env: &Env,
+        address: &Address,
+        amount: i128,
+    ) -> Result<(), soroban_sdk::Error> {
+        let screener: Address = match env
+            .storage()
+            .instance()
+            .get(&ComplianceKey::ScreeningContract)
+        {
+            Some(screener) => screener,
+            None => return Ok(()),
+        };
+        let allowed = matches!(
+            env.try_invoke_contract::<bool, soroban_sdk::Error>(
+                &screener,
+                &Symbol::new(env, "screen"),
+                vec![env, address.into_val(env), amount.into_val(env)],
+            ),
+            Ok(Ok(true))
+        );
+        if allowed {
+            return Ok(());
+        }
+        if !Self::has_screening_override(env.clone(), address.clone()) {
+            return Err(ComplianceError::ScreeningRejected.into());
+        }
+        events::emit_screening_bypassed(
+            env,
+            events::ScreeningBypassed {
+                address: address.clone(),
+                amount,
+                screener,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Internal: the checks every payout of `amount` from `bounty_id` to
+    /// `recipient` must pass, whichever entrypoint makes it: the claim bond,
+    /// group pause, block list, screening, jurisdiction release pause and the
+    /// circuit breaker.
+    fn require_payout_allowed(
+        env: &Env,
+        bounty_id: u64,
+        recipient: &Address,
+        amount: i128,
+    ) -> Result<(), soroban_sdk::Error> {
+        Self::require_claim_bond(env, bounty_id, recipient)?;
+        Self::require_group_active(env, bounty_id)?;
+        Self::require_not_blocked(env, recipient)?;
+        Self::require_screened(env, recipient, amount)?;
+        Self::apply_jurisdiction(env, bounty_id, symbol_short!("release"))?;
+        threshold_monitor::ensure_closed(env)?;
+        threshold_monitor::check_single_payout(env, amount)?;
+        Ok(())
+    }
+
+    /// Internal: checks for settling a bounty by paying `contributor_amount`
+    /// to the contributor and refunding `depositor_amount` to the depositor,
+    /// as arbiter rulings and cancelled vesting streams do. Each leg obeys
+    /// its own pause flag; the contributor leg also passes
+    /// `require_payout_allowed`.
+    fn require_settlement_allowed(
+        env: &Env,
+        bounty_id: u64,
+        contributor: &Address,
+        contributor_amount: i128,
+        depositor_amount: i128,
+    ) -> Result<(), soroban_sdk::Error> {
+        if contributor_amount > 0 {
+            if Self::check_paused(env, symbol_short!("release")) {
+                return Err(Error::FundsPaused.into());
+            }
+            Self::require_payout_allowed(env, bounty_id, contributor, contributor_amount)?;
+        }
+        if depositor_amount > 0 && Self::check_paused(env, symbol_short!("refund")) {
+            return Err(Error::FundsPaused.into());
+        }
+        Ok(())
+    }
+
+    /// Internal: count a lock of `amount` against the depositor's window,
+    /// failing with `RateLimited` when it would exceed either cap. The window
+    /// restarts once `window_size` seconds have passed since it opened.
+    fn check_lock_rate_limit(
+        env: &Env,
+        depositor: &Address,
+        amount: i128,
+    ) -> Result<(), soroban_sdk::Error> {
+        let limit: LockRateLimit = match env.storage().instance().get(&ComplianceKey::LockRateLimit)
+        {
+            Some(limit) => limit,
+            None => return Ok(()),
+        };
+        if anti_abuse::is_whitelisted(env, depositor.clone()) {
+            return Ok(());
+        }
+
+        let now = env.ledger().timestamp();
+        let key = ComplianceKey::LockWindow(depositor.clone());
+        let mut window = env
+            .storage()
+            .persistent()
+            .get::<ComplianceKey, LockWindow>(&key)
+            .filter(|w| now < w.window_start.saturating_add(limit.window_size))
+            .unwrap_or(LockWindow {
+                window_start: now,
+                lock_count: 0,
+                locked_value: 0,
+            });
+
+        window.lock_count += 1;
+        window.locked_value = window.locked_value.saturating_add(amount);
+        if (limit.max_locks > 0 && window.lock_count > limit.max_locks)
+            || (limit.max_locked_value > 0 && window.locked_value > limit.max_locked_value)
+        {
+            return Err(ComplianceError::RateLimited.into());
+        }
+        env.storage().persistent().set(&key, &window);
+        Ok(())
+    }
+
+    /// Retrieves the refund history for a specific bounty.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `bounty_id` - The bounty to query
+    ///
+    /// # Returns
+    /// * `Ok(Vec<RefundRecord>)` - The refund history
+    /// * `Err(Error::BountyNotFound.into())` - Bounty doesn't exist
+    pub fn get_refund_history(
+        env: Env,
+        bounty_id: u64,
+    ) -> Result<Vec<RefundRecord>, soroban_sdk::Error> {
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound.into());
+        }
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+        Ok(escrow.refund_history)
+    }
+
+    /// NEW: Verify escrow invariants for a specific bounty
+    pub fn verify_state(env: Env, bounty_id: u64) -> bool {
+        if let Some(escrow) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+        {
+            invariants::verify_escrow_invariants(&escrow)
+        } else {
+            false
+        }
+    }
+    /// Gets refund eligibility information for a bounty.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `bounty_id` - The bounty to query
+    ///
+    /// # Returns
+    /// * `Ok((bool, bool, i128, Option<RefundApproval>))` - Tuple containing:
+    ///   - can_refund: Whether refund is possible
+    ///   - deadline_passed: Whether the deadline has passed
+    ///   - remaining: Remaining amount in escrow
+    ///   - approval: Optional refund approval if exists
+    /// * `Err(Error::BountyNotFound.into())` - Bounty doesn't exist
+    pub fn get_refund_eligibility(
+        env: Env,
+        bounty_id: u64,
+    ) -> Result<(bool, bool, i128, Option<RefundApproval>), soroban_sdk::Error> {
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound.into());
+        }
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+
+        let now = env.ledger().timestamp();
         let deadline_passed = now >= escrow.deadline;
 
-        let approval = if env
+        let approval = if env
+            .storage()
+            .persistent()
+            .has(&DataKey::RefundApproval(bounty_id))
+        {
+            Some(
+                env.storage()
+                    .persistent()
+                    .get(&DataKey::RefundApproval(bounty_id))
+                    .unwrap(),
+            )
+        } else {
+            None
+        };
+
+        // can_refund is true if:
+        // 1. Status is Locked or PartiallyRefunded AND
+        // 2. (deadline has passed OR there's an approval)
+        let can_refund = (escrow.status == EscrowStatus::Locked
+            || escrow.status == EscrowStatus::PartiallyRefunded)
+            && (deadline_passed || approval.is_some());
+
+        Ok((
+            can_refund,
+            deadline_passed,
+            escrow.remaining_amount,
+            approval,
+        ))
+    }
+
+    /// Batch lock funds for multiple bounties in a single atomic transaction.
+    ///
+    /// Locks between 1 and [`MAX_BATCH_SIZE`] bounties in one call, reducing
+    /// per-transaction overhead compared to repeated single-item `lock_funds`
+    /// calls.
+    ///
+    /// ## Batch failure semantics
+    ///
+    /// This operation is **strictly atomic** (all-or-nothing):
+    ///
+    /// 1. All items are validated in a single pass **before** any state is
+    ///    mutated or any token transfer is initiated.
+    /// 2. If *any* item fails validation the entire call reverts immediately.
+    ///    No escrow record is written, no token is transferred, and every
+    ///    "sibling" row in the same batch is left completely unaffected.
+    /// 3. After a failed batch the contract is in exactly the same state as
+    ///    before the call; subsequent operations behave as if this call never
+    ///    happened.
+    ///
+    /// ## Ordering guarantee
+    ///
+    /// Items are processed in ascending `bounty_id` order regardless of the
+    /// caller-supplied ordering. This ensures deterministic execution and
+    /// eliminates ordering-based front-running attacks.
+    ///
+    /// ## Checks-Effects-Interactions (CEI)
+    ///
+    /// All escrow records and index updates are written in a first pass
+    /// (Effects); external token transfers and event emissions happen in a
+    /// second pass (Interactions). This ordering prevents reentrancy attacks.
+    ///
+    /// # Arguments
+    /// * `items` - 1–[`MAX_BATCH_SIZE`] [`LockFundsItem`] entries (bounty_id,
+    ///   depositor, amount, deadline).
+    ///
+    /// # Returns
+    /// Number of bounties successfully locked (equals `items.len()` on success).
+    ///
+    /// # Errors
+    /// * [`Error::InvalidBatchSize`] — batch is empty or exceeds `MAX_BATCH_SIZE`
+    /// * [`Error::ContractDeprecated`] — contract has been killed via `set_deprecated`
+    /// * [`Error::FundsPaused`] — lock operations are currently paused
+    /// * [`Error::NotInitialized`] — `init` has not been called
+    /// * [`Error::BountyExists`] — a `bounty_id` already exists in storage
+    /// * [`Error::DuplicateBountyId`] — the same `bounty_id` appears more than once
+    /// * [`Error::InvalidAmount`] — any item has `amount ≤ 0`
+    /// * [`Error::AmountBelowMinimum`] / [`Error::AmountAboveMaximum`] — outside the amount policy
+    /// * [`Error::ParticipantBlocked`] / [`Error::ParticipantNotAllowed`] — participant filter
+    ///
+    /// # Reentrancy
+    /// Protected by the shared reentrancy guard (acquired before validation,
+    /// released after all effects and interactions complete).
+    pub fn batch_lock_funds(
+        env: Env,
+        items: Vec<LockFundsItem>,
+    ) -> Result<u32, soroban_sdk::Error> {
+        if Self::check_paused(&env, symbol_short!("lock")) {
+            return Err(Error::FundsPaused.into());
+        }
+
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+        let result: Result<u32, soroban_sdk::Error> = (|| {
+            if Self::get_deprecation_state(&env).deprecated {
+                return Err(Error::ContractDeprecated.into());
+            }
+            // Validate batch size
+            let batch_size = items.len();
+            if batch_size == 0 {
+                return Err(Error::InvalidBatchSize.into());
+            }
+            if batch_size > MAX_BATCH_SIZE {
+                return Err(Error::InvalidBatchSize.into());
+            }
+
+            if !env.storage().instance().has(&DataKey::Admin) {
+                return Err(Error::NotInitialized.into());
+            }
+
+            let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+            let timestamp = env.ledger().timestamp();
+
+            let amount_policy = env
+                .storage()
+                .instance()
+                .get::<DataKey, (i128, i128)>(&DataKey::AmountPolicy);
+
+            // Locks earlier in the batch are not stored yet, so each
+            // depositor's exposure is accumulated here for the tier check.
+            let mut batch_exposure: Map<Address, i128> = Map::new(&env);
+
+            // Validate all items before processing (all-or-nothing approach)
+            for item in items.iter() {
+                // Participant filtering (blocklist-only / allowlist-only / disabled)
+                Self::check_participant_filter(&env, item.depositor.clone())?;
+                Self::require_not_blocked(&env, &item.depositor)?;
+                Self::require_screened(&env, &item.depositor, item.amount)?;
+
+                // Check if bounty already exists, in either escrow form
+                if env
+                    .storage()
+                    .persistent()
+                    .has(&DataKey::Escrow(item.bounty_id))
+                    || env
+                        .storage()
+                        .persistent()
+                        .has(&DataKey::EscrowAnon(item.bounty_id))
+                {
+                    return Err(Error::BountyExists.into());
+                }
+
+                // Validate amount, including the min/max policy `lock_funds` enforces
+                if item.amount <= 0 {
+                    return Err(Error::InvalidAmount.into());
+                }
+                if let Some((min_amount, max_amount)) = amount_policy {
+                    if item.amount < min_amount {
+                        return Err(Error::AmountBelowMinimum.into());
+                    }
+                    if item.amount > max_amount {
+                        return Err(Error::AmountAboveMaximum.into());
+                    }
+                }
+                Self::check_lock_rate_limit(&env, &item.depositor, item.amount)?;
+                let added_exposure = batch_exposure
+                    .get(item.depositor.clone())
+                    .unwrap_or(0)
+                    .saturating_add(item.amount);
+                batch_exposure.set(item.depositor.clone(), added_exposure);
+                if Self::jurisdiction_enforces_identity_limits(&env, item.bounty_id) {
+                    Self::check_tier_limits(&env, &item.depositor, item.amount, added_exposure)?;
+                }
+
+                // Check for duplicate bounty_ids in the batch
+                let mut count = 0u32;
+                for other_item in items.iter() {
+                    if other_item.bounty_id == item.bounty_id {
+                        count += 1;
+                    }
+                }
+                if count > 1 {
+                    return Err(Error::DuplicateBountyId.into());
+                }
+            }
+
+            let ordered_items = Self::order_batch_lock_items(&env, &items);
+
+            // Collect unique depositors and require auth once for each
+            // This prevents "frame is already authorized" errors when same depositor appears multiple times
+            let mut seen_depositors: Vec<Address> = Vec::new(&env);
+            for item in ordered_items.iter() {
+                let mut found = false;
+                for seen in seen_depositors.iter() {
+                    if seen.clone() == item.depositor {
+                        found = true;
+                        break;
+                    }
+                }
+                if !found {
+                    seen_depositors.push_back(item.depositor.clone());
+                    item.depositor.require_auth();
+                }
+            }
+
+            // Process all items (atomic - all succeed or all fail). Each
+            // escrow is sized from what its transfer actually delivered, so
+            // the transfer comes first; the reentrancy guard is held
+            // throughout.
+            let mut locked_count = 0u32;
+            let mut total_received = 0i128;
+            for item in ordered_items.iter() {
+                let received = Self::receive_deposit(
+                    &env,
+                    &token_addr,
+                    &item.depositor,
+                    item.bounty_id,
+                    item.amount,
+                    false,
+                )?;
+                total_received = total_received
+                    .checked_add(received)
+                    .ok_or(Error::InvalidAmount)?;
+
+                let escrow = Escrow {
+                    depositor: item.depositor.clone(),
+                    amount: received,
+                    status: EscrowStatus::Locked,
+                    deadline: item.deadline,
+                    refund_history: vec![&env],
+                    remaining_amount: received,
+                };
+
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::Escrow(item.bounty_id), &escrow);
+                Self::stamp_escrow_schema(&env, item.bounty_id);
+                Self::index_status(&env, item.bounty_id, &escrow.status);
+                Self::sync_escrow_accounting(&env, item.bounty_id, &escrow);
+
+                let mut index: Vec<u64> = env
+                    .storage()
+                    .persistent()
+                    .get(&DataKey::EscrowIndex)
+                    .unwrap_or(Vec::new(&env));
+                index.push_back(item.bounty_id);
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::EscrowIndex, &index);
+
+                let mut depositor_index: Vec<u64> = env
+                    .storage()
+                    .persistent()
+                    .get(&DataKey::DepositorIndex(item.depositor.clone()))
+                    .unwrap_or(Vec::new(&env));
+                depositor_index.push_back(item.bounty_id);
+                env.storage().persistent().set(
+                    &DataKey::DepositorIndex(item.depositor.clone()),
+                    &depositor_index,
+                );
+
+                emit_funds_locked(
+                    &env,
+                    FundsLocked {
+                        version: EVENT_VERSION_V3,
+                        bounty_id: item.bounty_id,
+                        amount: received,
+                        remaining_before: 0,
+                        remaining_after: received,
+                        depositor: item.depositor.clone(),
+                        deadline: item.deadline,
+                    },
+                );
+
+                locked_count += 1;
+            }
+
+            emit_batch_funds_locked(
+                &env,
+                BatchFundsLocked {
+                    count: locked_count,
+                    total_amount: total_received,
+                    timestamp,
+                },
+            );
+
+            Ok(locked_count)
+        })();
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        result
+    }
+
+    /// Batch release funds to multiple contributors in a single atomic transaction.
+    ///
+    /// Releases between 1 and [`MAX_BATCH_SIZE`] bounties in one admin-authorised
+    /// call, reducing per-transaction overhead compared to repeated single-item
+    /// `release_funds` calls.
+    ///
+    /// ## Batch failure semantics
+    ///
+    /// This operation is **strictly atomic** (all-or-nothing):
+    ///
+    /// 1. All items are validated in a single pass **before** any escrow status
+    ///    is updated or any token transfer is initiated.
+    /// 2. If *any* item fails validation the entire call reverts immediately.
+    ///    No status is changed, no token leaves the contract, and every
+    ///    "sibling" row in the same batch is left completely unaffected.
+    /// 3. After a failed batch the contract is in exactly the same state as
+    ///    before the call; subsequent operations behave as if this call never
+    ///    happened.
+    ///
+    /// ## Ordering guarantee
+    ///
+    /// Items are processed in ascending `bounty_id` order regardless of the
+    /// caller-supplied ordering, ensuring deterministic execution.
+    ///
+    /// ## Checks-Effects-Interactions (CEI)
+    ///
+    /// All escrow statuses are updated to `Released` in a first pass (Effects);
+    /// external token transfers and event emissions happen in a second pass
+    /// (Interactions).
+    ///
+    /// # Arguments
+    /// * `items` - 1–[`MAX_BATCH_SIZE`] [`ReleaseFundsItem`] entries (bounty_id,
+    ///   contributor address).
+    ///
+    /// # Returns
+    /// Number of bounties successfully released (equals `items.len()` on success).
+    ///
+    /// # Errors
+    /// * [`Error::InvalidBatchSize`] — batch is empty or exceeds `MAX_BATCH_SIZE`
+    /// * [`Error::FundsPaused`] — release operations are currently paused
+    /// * [`Error::NotInitialized`] — `init` has not been called
+    /// * [`Error::Unauthorized`] — caller is not the admin
+    /// * [`Error::BountyNotFound`] — a `bounty_id` does not exist in storage
+    /// * [`Error::FundsNotLocked`] — a bounty's status is not `Locked`
+    /// * [`Error::DuplicateBountyId`] — the same `bounty_id` appears more than once
+    ///
+    /// # Reentrancy
+    /// Protected by the shared reentrancy guard (acquired before validation,
+    /// released after all effects and interactions complete).
+    pub fn batch_release_funds(
+        env: Env,
+        items: Vec<ReleaseFundsItem>,
+    ) -> Result<u32, soroban_sdk::Error> {
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused.into());
+        }
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+        let result: Result<u32, soroban_sdk::Error> = (|| {
+            // Validate batch size
+            let batch_size = items.len();
+            if batch_size == 0 {
+                return Err(Error::InvalidBatchSize.into());
+            }
+            if batch_size > MAX_BATCH_SIZE {
+                return Err(Error::InvalidBatchSize.into());
+            }
+
+            if !env.storage().instance().has(&DataKey::Admin) {
+                return Err(Error::NotInitialized.into());
+            }
+
+            let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+            admin.require_auth();
+
+            let contract_address = env.current_contract_address();
+            let timestamp = env.ledger().timestamp();
+
+            // Validate all items before processing (all-or-nothing approach)
+            let mut total_amount: i128 = 0;
+            for item in items.iter() {
+                // Check if bounty exists
+                if !env
+                    .storage()
+                    .persistent()
+                    .has(&DataKey::Escrow(item.bounty_id))
+                {
+                    return Err(Error::BountyNotFound.into());
+                }
+
+                let escrow: Escrow = env
+                    .storage()
+                    .persistent()
+                    .get(&DataKey::Escrow(item.bounty_id))
+                    .unwrap();
+
+                // Check if funds are locked
+                if escrow.status != EscrowStatus::Locked {
+                    return Err(Error::FundsNotLocked.into());
+                }
+                Self::require_payout_allowed(
+                    &env,
+                    item.bounty_id,
+                    &item.contributor,
+                    escrow.remaining_amount,
+                )?;
+
+                // Check for duplicate bounty_ids in the batch
+                let mut count = 0u32;
+                for other_item in items.iter() {
+                    if other_item.bounty_id == item.bounty_id {
+                        count += 1;
+                    }
+                }
+                if count > 1 {
+                    return Err(Error::DuplicateBountyId.into());
+                }
+
+                total_amount = total_amount
+                    .checked_add(escrow.remaining_amount)
+                    .ok_or(Error::InvalidAmount)?;
+            }
+
+            let ordered_items = Self::order_batch_release_items(&env, &items);
+
+            // EFFECTS: update all escrow records before any external calls (CEI)
+            // We collect (contributor, amount, remaining_before) for the transfer pass.
+            let mut release_pairs: Vec<(Address, i128, i128)> = Vec::new(&env);
+            let mut released_count = 0u32;
+            for item in ordered_items.iter() {
+                let mut escrow: Escrow = env
+                    .storage()
+                    .persistent()
+                    .get(&DataKey::Escrow(item.bounty_id))
+                    .unwrap();
+
+                let amount = escrow.remaining_amount;
+                let remaining_before = escrow.remaining_amount;
+                escrow.status = EscrowStatus::Released;
+                escrow.remaining_amount = 0;
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::Escrow(item.bounty_id), &escrow);
+                Self::index_status(&env, item.bounty_id, &escrow.status);
+                Self::sync_escrow_accounting(&env, item.bounty_id, &escrow);
+
+                release_pairs.push_back((item.contributor.clone(), amount, remaining_before));
+                released_count += 1;
+            }
+
+            // INTERACTION: all external token transfers happen after state is finalized
+            for (idx, item) in ordered_items.iter().enumerate() {
+                let (ref contributor, amount, remaining_before) =
+                    release_pairs.get(idx as u32).unwrap();
+                let token_addr = Self::bounty_token(&env, item.bounty_id);
+                let client = token::Client::new(&env, &token_addr);
+                client.transfer(&contract_address, contributor, &amount);
+                Self::return_claim_bond(&env, &client, item.bounty_id, contributor);
+                threshold_monitor::record_outflow(&env, amount);
+
+                emit_funds_released(
+                    &env,
+                    FundsReleased {
+                        version: EVENT_VERSION_V3,
+                        bounty_id: item.bounty_id,
+                        amount,
+                        remaining_before,
+                        remaining_after: 0,
+                        recipient: contributor.clone(),
+                        timestamp,
+                    },
+                );
+            }
+
+            // Emit batch event
+            emit_batch_funds_released(
+                &env,
+                BatchFundsReleased {
+                    count: released_count,
+                    total_amount,
+                    timestamp,
+                },
+            );
+
+            Ok(released_count)
+        })();
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        result
+    }
+
+    /// Refund up to [`MAX_BATCH_SIZE`] expired bounties in one admin-authorised
+    /// call, e.g. to clear unclaimed bounties after a hackathon.
+    ///
+    /// Each eligible escrow has its full remaining balance returned to its
+    /// depositor, exactly like a post-deadline `refund`. Ineligible ids do not
+    /// fail the batch; they are reported in [`BatchRefundResult::skipped`]
+    /// and counted as failures by the threshold monitor.
+    /// Refund approvals are not consumed here; use `refund` for approved
+    /// early, partial or custom-recipient refunds.
+    ///
+    /// # Errors
+    /// * [`Error::InvalidBatchSize`] — batch is empty or exceeds `MAX_BATCH_SIZE`
+    /// * [`Error::FundsPaused`] — refunds are currently paused
+    /// * [`Error::NotInitialized`] — `init` has not been called
+    /// * [`Error::DuplicateBountyId`] — the same `bounty_id` appears more than once
+    pub fn batch_refund(
+        env: Env,
+        bounty_ids: Vec<u64>,
+    ) -> Result<BatchRefundResult, soroban_sdk::Error> {
+        if bounty_ids.is_empty() || bounty_ids.len() > MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatchSize.into());
+        }
+        if Self::check_paused(&env, symbol_short!("refund")) {
+            return Err(Error::FundsPaused.into());
+        }
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        reentrancy_guard::acquire(&env);
+        let mut seen: Vec<u64> = Vec::new(&env);
+        for bounty_id in bounty_ids.iter() {
+            if seen.contains(bounty_id) {
+                reentrancy_guard::release(&env);
+                return Err(Error::DuplicateBountyId.into());
+            }
+            seen.push_back(bounty_id);
+        }
+
+        let now = env.ledger().timestamp();
+        let mut result = BatchRefundResult {
+            refunded: Vec::new(&env),
+            skipped: Vec::new(&env),
+            total_refunded: 0,
+        };
+        for bounty_id in bounty_ids.iter() {
+            Self::migrate_escrow_record(&env, bounty_id);
+            let escrow: Option<Escrow> =
+                env.storage().persistent().get(&DataKey::Escrow(bounty_id));
+            let mut escrow = match escrow {
+                Some(escrow) if Self::batch_refund_eligible(&env, bounty_id, &escrow, now) => {
+                    escrow
+                }
+                _ => {
+                    result.skipped.push_back(bounty_id);
+                    threshold_monitor::record_operation_failure(&env);
+                    continue;
+                }
+            };
+
+            // EFFECTS
+            let refund_amount = escrow.remaining_amount;
+            escrow.remaining_amount = 0;
+            escrow.status = EscrowStatus::Refunded;
+            escrow.refund_history.push_back(RefundRecord {
+                amount: refund_amount,
+                recipient: escrow.depositor.clone(),
+                timestamp: now,
+                mode: RefundMode::Full,
+            });
+            env.storage()
+                .persistent()
+                .set(&DataKey::Escrow(bounty_id), &escrow);
+            Self::index_status(&env, bounty_id, &escrow.status);
+            Self::sync_escrow_accounting(&env, bounty_id, &escrow);
+
+            // INTERACTION
+            let client = token::Client::new(&env, &Self::bounty_token(&env, bounty_id));
+            client.transfer(
+                &env.current_contract_address(),
+                &escrow.depositor,
+                &refund_amount,
+            );
+
+            emit_funds_refunded(
+                &env,
+                FundsRefunded {
+                    version: EVENT_VERSION_V3,
+                    bounty_id,
+                    amount: refund_amount,
+                    remaining_before: refund_amount,
+                    remaining_after: escrow.remaining_amount,
+                    refund_to: escrow.depositor.clone(),
+                    timestamp: now,
+                },
+            );
+            Self::record_receipt(
+                &env,
+                CriticalOperationOutcome::Refunded,
+                bounty_id,
+                refund_amount,
+                escrow.depositor.clone(),
+            );
+            result.refunded.push_back(bounty_id);
+            result.total_refunded += refund_amount;
+        }
+
+        multitoken_invariants::assert_after_disbursement(&env);
+        reentrancy_guard::release(&env);
+        Ok(result)
+    }
+
+    /// Internal: whether `batch_refund` may return `escrow` to its depositor.
+    fn batch_refund_eligible(env: &Env, bounty_id: u64, escrow: &Escrow, now: u64) -> bool {
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
+        {
+            return false;
+        }
+        if now < escrow.deadline || escrow.remaining_amount <= 0 {
+            return false;
+        }
+        if Self::is_blocked(env.clone(), escrow.depositor.clone()) {
+            return false;
+        }
+        if let OptionalJurisdiction::Some(config) =
+            Self::get_escrow_jurisdiction(env.clone(), bounty_id)
+        {
+            if config.refund_paused {
+                return false;
+            }
+        }
+        let pending_claim = env
             .storage()
             .persistent()
-            .has(&DataKey::RefundApproval(bounty_id))
-        {
-            Some(
-                env.storage()
-                    .persistent()
-                    .get(&DataKey::RefundApproval(bounty_id))
-                    .unwrap(),
-            )
-        } else {
-            None
+            .get::<DataKey, ClaimRecord>(&DataKey::PendingClaim(bounty_id))
+            .is_some_and(|claim| !claim.claimed);
+        !pending_claim
+    }
+    /// Update stored metadata for a bounty.
+    ///
+    /// # Arguments
+    /// * `env` - Contract environment
+    /// * `_admin` - Admin address (auth enforced against stored admin)
+    /// * `bounty_id` - Bounty identifier
+    /// * `repo_id` - Repository identifier
+    /// * `issue_id` - Issue identifier
+    /// * `bounty_type` - Human-readable bounty type tag (1..=50 chars)
+    /// * `reference_hash` - Optional reference hash for off-chain metadata
+    ///
+    /// # Panics
+    /// Panics if `bounty_type` is empty or exceeds the maximum length.
+    pub fn update_metadata(
+        env: Env,
+        _admin: Address,
+        bounty_id: u64,
+        repo_id: u64,
+        issue_id: u64,
+        bounty_type: soroban_sdk::String,
+        reference_hash: Option<soroban_sdk::Bytes>,
+    ) -> Result<(), soroban_sdk::Error> {
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        stored_admin.require_auth();
+
+        validation::validate_tag(&env, &bounty_type, "bounty_type");
+
+        let (existing_flags, existing_prefs) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, EscrowMetadata>(&DataKey::Metadata(bounty_id))
+            .map(|metadata| (metadata.risk_flags, metadata.notification_prefs))
+            .unwrap_or((0, 0));
+
+        let metadata = EscrowMetadata {
+            repo_id,
+            issue_id,
+            bounty_type,
+            risk_flags: existing_flags,
+            notification_prefs: existing_prefs,
+            reference_hash,
         };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Metadata(bounty_id), &metadata);
+        Ok(())
+    }
 
-        // can_refund is true if:
-        // 1. Status is Locked or PartiallyRefunded AND
-        // 2. (deadline has passed OR there's an approval)
-        let can_refund = (escrow.status == EscrowStatus::Locked
-            || escrow.status == EscrowStatus::PartiallyRefunded)
-            && (deadline_passed || approval.is_some());
+    pub fn get_metadata(env: Env, bounty_id: u64) -> Result<EscrowMetadata, soroban_sdk::Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Metadata(bounty_id))
+            .ok_or(Error::BountyNotFound.into())
+    }
 
-        Ok((
-            can_refund,
-            deadline_passed,
-            escrow.remaining_amount,
-            approval,
-        ))
+    /// Build the context bytes that feed into the deterministic PRNG.
+    ///
+    /// The context binds selection to the current contract address, bounty
+    /// parameters, **ledger timestamp**, and the monotonic ticket counter.
+    /// Changing any of these inputs produces a completely different SHA-256
+    /// digest and therefore a different winner.
+    ///
+    /// # Ledger inputs included
+    /// - `env.ledger().timestamp()` — ties the result to the block that
+    ///   executes the transaction.
+    /// - `TicketCounter` — monotonically increasing; prevents two calls
+    ///   within the same ledger close from producing identical context.
+    ///
+    /// # Predictability limits
+    /// Because the ledger timestamp is known to validators before block
+    /// close, a validator-level adversary can predict the outcome for a
+    /// given external seed.  See `DETERMINISTIC_RANDOMNESS.md` for the
+    /// full threat model.
+    fn build_claim_selection_context(
+        env: &Env,
+        bounty_id: u64,
+        amount: i128,
+        expires_at: u64,
+    ) -> Bytes {
+        let mut context = Bytes::new(env);
+        context.append(&env.current_contract_address().to_xdr(env));
+        context.append(&Bytes::from_array(env, &bounty_id.to_be_bytes()));
+        context.append(&Bytes::from_array(env, &amount.to_be_bytes()));
+        context.append(&Bytes::from_array(env, &expires_at.to_be_bytes()));
+        context.append(&Bytes::from_array(
+            env,
+            &env.ledger().timestamp().to_be_bytes(),
+        ));
+        let ticket_counter: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::TicketCounter)
+            .unwrap_or(0);
+        context.append(&Bytes::from_array(env, &ticket_counter.to_be_bytes()));
+        context
     }
 
-    /// Batch lock funds for multiple bounties in a single atomic transaction.
+    /// Deterministically derive the winner index for claim ticket issuance.
     ///
-    /// Locks between 1 and [`MAX_BATCH_SIZE`] bounties in one call, reducing
-    /// per-transaction overhead compared to repeated single-item `lock_funds`
-    /// calls.
+    /// This is a pure/view helper that lets clients verify expected results
+    /// before issuing a ticket.  The index is computed via per-candidate
+    /// SHA-256 scoring (see `grainlify_core::pseudo_randomness`), making
+    /// the result **order-independent** — shuffling `candidates` does not
+    /// change which address is selected.
     ///
-    /// ## Batch failure semantics
+    /// # Arguments
+    /// * `bounty_id` — Bounty whose context seeds the PRNG.
+    /// * `candidates` — Non-empty list of eligible addresses.
+    /// * `amount` — Claim amount mixed into the context hash.
+    /// * `expires_at` — Ticket expiry mixed into the context hash.
+    /// * `external_seed` — Caller-provided 32-byte seed.
     ///
-    /// This operation is **strictly atomic** (all-or-nothing):
+    /// # Errors
+    /// Returns `Error::InvalidSelectionInput` when `candidates` is empty.
+    pub fn derive_claim_ticket_winner_index(
+        env: Env,
+        bounty_id: u64,
+        candidates: Vec<Address>,
+        amount: i128,
+        expires_at: u64,
+        external_seed: BytesN<32>,
+    ) -> Result<u32, soroban_sdk::Error> {
+        if candidates.is_empty() {
+            return Err(Error::InvalidSelectionInput.into());
+        }
+        let context = Self::build_claim_selection_context(&env, bounty_id, amount, expires_at);
+        let domain = Symbol::new(&env, "claim_prng_v1");
+        let selection = pseudo_randomness::derive_selection(
+            &env,
+            &domain,
+            &context,
+            &external_seed,
+            &candidates,
+        )
+        .ok_or(Error::InvalidSelectionInput)?;
+        Ok(selection.index)
+    }
+
+    /// Deterministically derive the winner **address** for claim ticket issuance.
     ///
-    /// 1. All items are validated in a single pass **before** any state is
-    ///    mutated or any token transfer is initiated.
-    /// 2. If *any* item fails validation the entire call reverts immediately.
-    ///    No escrow record is written, no token is transferred, and every
-    ///    "sibling" row in the same batch is left completely unaffected.
-    /// 3. After a failed batch the contract is in exactly the same state as
-    ///    before the call; subsequent operations behave as if this call never
-    ///    happened.
+    /// Convenience wrapper around [`Self::derive_claim_ticket_winner_index`]
+    /// that resolves the winning index back to an `Address`.
     ///
-    /// ## Ordering guarantee
+    /// # Errors
+    /// Returns `Error::InvalidSelectionInput` when `candidates` is empty or
+    /// the resolved index is out of bounds.
+    pub fn derive_claim_ticket_winner(
+        env: Env,
+        bounty_id: u64,
+        candidates: Vec<Address>,
+        amount: i128,
+        expires_at: u64,
+        external_seed: BytesN<32>,
+    ) -> Result<Address, soroban_sdk::Error> {
+        let index = Self::derive_claim_ticket_winner_index(
+            env.clone(),
+            bounty_id,
+            candidates.clone(),
+            amount,
+            expires_at,
+            external_seed,
+        )?;
+        candidates
+            .get(index)
+            .ok_or(Error::InvalidSelectionInput.into())
+    }
+
+    /// Deterministically select a winner from `candidates` and issue a claim ticket.
     ///
-    /// Items are processed in ascending `bounty_id` order regardless of the
-    /// caller-supplied ordering. This ensures deterministic execution and
-    /// eliminates ordering-based front-running attacks.
+    /// Combines [`Self::derive_claim_ticket_winner`] with
+    /// [`Self::issue_claim_ticket`] in a single atomic call.  Emits a
+    /// `DeterministicSelectionDerived` event containing the seed hash,
+    /// winner score, and selected index for off-chain auditability.
     ///
-    /// ## Checks-Effects-Interactions (CEI)
+    /// # Security notes
+    /// - **Deterministic and verifiable** — any observer can replay the
+    ///   selection from the published event fields.
+    /// - **Not unbiased randomness** — callers who control both the
+    ///   external seed and submission timing can influence outcomes.
+    ///   See `DETERMINISTIC_RANDOMNESS.md` for mitigation guidance.
+    /// - The selection is **order-independent**: candidate list ordering
+    ///   does not affect which address wins.
+    ///
+    /// # Errors
+    /// Returns `Error::InvalidSelectionInput` when `candidates` is empty.
+    pub fn issue_claim_ticket_deterministic(
+        env: Env,
+        bounty_id: u64,
+        candidates: Vec<Address>,
+        amount: i128,
+        expires_at: u64,
+        external_seed: BytesN<32>,
+    ) -> Result<u64, soroban_sdk::Error> {
+        if candidates.is_empty() {
+            return Err(Error::InvalidSelectionInput.into());
+        }
+
+        let context = Self::build_claim_selection_context(&env, bounty_id, amount, expires_at);
+        let domain = Symbol::new(&env, "claim_prng_v1");
+        let selection = pseudo_randomness::derive_selection(
+            &env,
+            &domain,
+            &context,
+            &external_seed,
+            &candidates,
+        )
+        .ok_or(Error::InvalidSelectionInput)?;
+
+        let selected = candidates
+            .get(selection.index)
+            .ok_or(Error::InvalidSelectionInput)?;
+
+        emit_deterministic_selection(
+            &env,
+            DeterministicSelectionDerived {
+                bounty_id,
+                selected_index: selection.index,
+                candidate_count: candidates.len(),
+                selected_beneficiary: selected.clone(),
+                seed_hash: selection.seed_hash,
+                winner_score: selection.winner_score,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Self::issue_claim_ticket(env, bounty_id, selected, amount, expires_at)
+    }
+
+    /// Issue a single-use claim ticket to a bounty winner (admin only)
     ///
-    /// All escrow records and index updates are written in a first pass
-    /// (Effects); external token transfers and event emissions happen in a
-    /// second pass (Interactions). This ordering prevents reentrancy attacks.
+    /// This creates a ticket that the beneficiary can use to claim their reward exactly once.
+    /// Tickets are bound to a specific address, amount, and expiry time.
     ///
     /// # Arguments
-    /// * `items` - 1–[`MAX_BATCH_SIZE`] [`LockFundsItem`] entries (bounty_id,
-    ///   depositor, amount, deadline).
+    /// * `env` - Contract environment
+    /// * `bounty_id` - ID of the bounty being claimed
+    /// * `beneficiary` - Address of the winner who will claim the reward
+    /// * `amount` - Amount to be claimed (in token units)
+    /// * `expires_at` - Unix timestamp when the ticket expires
     ///
     /// # Returns
-    /// Number of bounties successfully locked (equals `items.len()` on success).
-    ///
-    /// # Errors
-    /// * [`Error::InvalidBatchSize`] — batch is empty or exceeds `MAX_BATCH_SIZE`
-    /// * [`Error::ContractDeprecated`] — contract has been killed via `set_deprecated`
-    /// * [`Error::FundsPaused`] — lock operations are currently paused
-    /// * [`Error::NotInitialized`] — `init` has not been called
-    /// * [`Error::BountyExists`] — a `bounty_id` already exists in storage
-    /// * [`Error::DuplicateBountyId`] — the same `bounty_id` appears more than once
-    /// * [`Error::InvalidAmount`] — any item has `amount ≤ 0`
-    /// * [`Error::ParticipantBlocked`] / [`Error::ParticipantNotAllowed`] — participant filter
-    ///
-    /// # Reentrancy
-    /// Protected by the shared reentrancy guard (acquired before validation,
-    /// released after all effects and interactions complete).
-    pub fn batch_lock_funds(env: Env, items: Vec<LockFundsItem>) -> Result<u32, Error> {
-        if Self::check_paused(&env, symbol_short!("lock")) {
-            return Err(Error::FundsPaused);
+    /// * `Ok(ticket_id)` - The unique ticket ID for this claim
+    /// * `Err(Error::NotInitialized.into())` - Contract not initialized
+    /// * `Err(Error::Unauthorized.into())` - Caller is not admin
+    /// * `Err(Error::BountyNotFound.into())` - Bounty doesn't exist
+    /// * `Err(Error::InvalidDeadline.into())` - Expiry time is in the past
+    /// * `Err(Error::InvalidAmount.into())` - Amount is invalid or exceeds escrow amount
+    pub fn issue_claim_ticket(
+        env: Env,
+        bounty_id: u64,
+        beneficiary: Address,
+        amount: i128,
+        expires_at: u64,
+    ) -> Result<u64, soroban_sdk::Error> {
+        Self::issue_claim_ticket_internal(env, bounty_id, beneficiary, amount, expires_at, false)
+    }
+
+    /// Issue a claim ticket that the beneficiary can later redirect to
+    /// another address (e.g. a team wallet) with `transfer_claim_ticket`.
+    /// Otherwise identical to `issue_claim_ticket`.
+    pub fn issue_transferable_claim_ticket(
+        env: Env,
+        bounty_id: u64,
+        beneficiary: Address,
+        amount: i128,
+        expires_at: u64,
+    ) -> Result<u64, soroban_sdk::Error> {
+        Self::issue_claim_ticket_internal(env, bounty_id, beneficiary, amount, expires_at, true)
+    }
+
+    fn issue_claim_ticket_internal(
+        env: Env,
+        bounty_id: u64,
+        beneficiary: Address,
+        amount: i128,
+        expires_at: u64,
+        transferable: bool,
+    ) -> Result<u64, soroban_sdk::Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized.into());
         }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
 
-        // GUARD: acquire reentrancy lock
-        reentrancy_guard::acquire(&env);
-        let result: Result<u32, Error> = (|| {
-            if Self::get_deprecation_state(&env).deprecated {
-                return Err(Error::ContractDeprecated);
-            }
-            // Validate batch size
-            let batch_size = items.len();
-            if batch_size == 0 {
-                return Err(Error::InvalidBatchSize);
+        // Outstanding tickets reserve part of the balance; a new ticket can
+        // only draw on what is left.
+        let available = Self::ticket_available_amount(&env, bounty_id)?;
+        if amount <= 0 || amount > available {
+            return Err(Error::InvalidAmount.into());
+        }
+
+        let now = env.ledger().timestamp();
+        if expires_at <= now {
+            return Err(Error::InvalidDeadline.into());
+        }
+
+        Ok(Self::store_claim_ticket(
+            &env,
+            bounty_id,
+            beneficiary,
+            amount,
+            expires_at,
+            transferable,
+        ))
+    }
+
+    /// Issue several claim tickets on one bounty with a single admin auth,
+    /// e.g. for hackathon prize lists. All items are validated before any
+    /// ticket is stored: each needs a positive amount and a future expiry,
+    /// and together they must fit in the bounty's unreserved balance.
+    /// Returns the new ticket ids in item order.
+    pub fn issue_claim_tickets_batch(
+        env: Env,
+        bounty_id: u64,
+        items: Vec<TicketRequest>,
+    ) -> Result<Vec<u64>, soroban_sdk::Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized.into());
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if items.is_empty() || items.len() > MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatchSize.into());
+        }
+
+        let available = Self::ticket_available_amount(&env, bounty_id)?;
+        let now = env.ledger().timestamp();
+        let mut total: i128 = 0;
+        for item in items.iter() {
+            if item.amount <= 0 {
+                return Err(Error::InvalidAmount.into());
             }
-            if batch_size > MAX_BATCH_SIZE {
-                return Err(Error::InvalidBatchSize);
+            if item.expires_at <= now {
+                return Err(Error::InvalidDeadline.into());
             }
-
-            if !env.storage().instance().has(&DataKey::Admin) {
-                return Err(Error::NotInitialized);
+            total = total.checked_add(item.amount).ok_or(Error::InvalidAmount)?;
+            if total > available {
+                return Err(Error::InvalidAmount.into());
             }
+        }
 
-            let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-            let client = token::Client::new(&env, &token_addr);
-            let contract_address = env.current_contract_address();
-            let timestamp = env.ledger().timestamp();
-
-            // Validate all items before processing (all-or-nothing approach)
-            for item in items.iter() {
-                // Participant filtering (blocklist-only / allowlist-only / disabled)
-                Self::check_participant_filter(&env, item.depositor.clone())?;
+        let mut ticket_ids: Vec<u64> = Vec::new(&env);
+        for item in items.iter() {
+            ticket_ids.push_back(Self::store_claim_ticket(
+                &env,
+                bounty_id,
+                item.beneficiary,
+                item.amount,
+                item.expires_at,
+                item.transferable,
+            ));
+        }
+        Ok(ticket_ids)
+    }
 
-                // Check if bounty already exists
-                if env
-                    .storage()
-                    .persistent()
-                    .has(&DataKey::Escrow(item.bounty_id))
-                {
-                    return Err(Error::BountyExists);
-                }
+    /// Internal: balance of a locked bounty not yet reserved by tickets.
+    fn ticket_available_amount(env: &Env, bounty_id: u64) -> Result<i128, soroban_sdk::Error> {
+        let escrow_remaining: i128;
+        let escrow_status: EscrowStatus;
+        if env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            let escrow: Escrow = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Escrow(bounty_id))
+                .unwrap();
+            escrow_remaining = escrow.remaining_amount;
+            escrow_status = escrow.status;
+        } else if env
+            .storage()
+            .persistent()
+            .has(&DataKey::EscrowAnon(bounty_id))
+        {
+            let anon: AnonymousEscrow = env
+                .storage()
+                .persistent()
+                .get(&DataKey::EscrowAnon(bounty_id))
+                .unwrap();
+            escrow_remaining = anon.remaining_amount;
+            escrow_status = anon.status;
+        } else {
+            return Err(Error::BountyNotFound.into());
+        }
 
-                // Validate amount
-                if item.amount <= 0 {
-                    return Err(Error::InvalidAmount);
-                }
+        if escrow_status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked.into());
+        }
+        let reserved = Self::get_ticket_reserved(env.clone(), bounty_id);
+        Ok(escrow_remaining - reserved)
+    }
 
-                // Check for duplicate bounty_ids in the batch
-                let mut count = 0u32;
-                for other_item in items.iter() {
-                    if other_item.bounty_id == item.bounty_id {
-                        count += 1;
-                    }
-                }
-                if count > 1 {
-                    return Err(Error::DuplicateBountyId);
-                }
-            }
+    /// Internal: store a validated ticket, reserve its amount and index it.
+    fn store_claim_ticket(
+        env: &Env,
+        bounty_id: u64,
+        beneficiary: Address,
+        amount: i128,
+        expires_at: u64,
+        transferable: bool,
+    ) -> u64 {
+        let now = env.ledger().timestamp();
+        let ticket_counter_key = DataKey::TicketCounter;
+        let mut ticket_id: u64 = env
+            .storage()
+            .persistent()
+            .get(&ticket_counter_key)
+            .unwrap_or(0);
+        ticket_id += 1;
+        env.storage()
+            .persistent()
+            .set(&ticket_counter_key, &ticket_id);
 
-            let ordered_items = Self::order_batch_lock_items(&env, &items);
+        let ticket = ClaimTicket {
+            ticket_id,
+            bounty_id,
+            beneficiary: beneficiary.clone(),
+            amount,
+            expires_at,
+            used: false,
+            issued_at: now,
+            transferable,
+            revoked: false,
+            remaining_amount: amount,
+        };
 
-            // Collect unique depositors and require auth once for each
-            // This prevents "frame is already authorized" errors when same depositor appears multiple times
-            let mut seen_depositors: Vec<Address> = Vec::new(&env);
-            for item in ordered_items.iter() {
-                let mut found = false;
-                for seen in seen_depositors.iter() {
-                    if seen.clone() == item.depositor {
-                        found = true;
-                        break;
-                    }
-                }
-                if !found {
-                    seen_depositors.push_back(item.depositor.clone());
-                    item.depositor.require_auth();
-                }
-            }
+        env.storage()
+            .persistent()
+            .set(&DataKey::ClaimTicket(ticket_id), &ticket);
+        Self::reserve_ticket(env, bounty_id, ticket_id, amount);
 
-            // Process all items (atomic - all succeed or all fail)
-            // First loop: write all state (escrow, indices). Second loop: transfers + events.
-            let mut locked_count = 0u32;
-            for item in ordered_items.iter() {
-                let escrow = Escrow {
-                    depositor: item.depositor.clone(),
-                    amount: item.amount,
-                    status: EscrowStatus::Locked,
-                    deadline: item.deadline,
-                    refund_history: vec![&env],
-                    remaining_amount: item.amount,
-                };
+        let mut ticket_index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ClaimTicketIndex)
+            .unwrap_or(Vec::new(env));
+        ticket_index.push_back(ticket_id);
+        env.storage()
+            .persistent()
+            .set(&DataKey::ClaimTicketIndex, &ticket_index);
 
-                env.storage()
-                    .persistent()
-                    .set(&DataKey::Escrow(item.bounty_id), &escrow);
+        let mut beneficiary_tickets: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::BeneficiaryTickets(beneficiary.clone()))
+            .unwrap_or(Vec::new(env));
+        beneficiary_tickets.push_back(ticket_id);
+        env.storage().persistent().set(
+            &DataKey::BeneficiaryTickets(beneficiary.clone()),
+            &beneficiary_tickets,
+        );
 
-                let mut index: Vec<u64> = env
-                    .storage()
-                    .persistent()
-                    .get(&DataKey::EscrowIndex)
-                    .unwrap_or(Vec::new(&env));
-                index.push_back(item.bounty_id);
-                env.storage()
-                    .persistent()
-                    .set(&DataKey::EscrowIndex, &index);
+        emit_ticket_issued(
+            &env,
+            TicketIssued {
+                ticket_id,
+                bounty_id,
+                beneficiary,
+                amount,
+                expires_at,
+                issued_at: now,
+            },
+        );
 
-                let mut depositor_index: Vec<u64> = env
-                    .storage()
-                    .persistent()
-                    .get(&DataKey::DepositorIndex(item.depositor.clone()))
-                    .unwrap_or(Vec::new(&env));
-                depositor_index.push_back(item.bounty_id);
-                env.storage().persistent().set(
-                    &DataKey::DepositorIndex(item.depositor.clone()),
-                    &depositor_index,
-                );
-            }
+        ticket_id
+    }
 
-            // INTERACTION: all external token transfers happen after state is finalized
-            for item in ordered_items.iter() {
-                client.transfer(&item.depositor, &contract_address, &item.amount);
+    pub fn get_claim_ticket(env: Env, ticket_id: u64) -> Result<ClaimTicket, soroban_sdk::Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ClaimTicket(ticket_id))
+            .ok_or(Error::TicketNotFound.into())
+    }
 
-                emit_funds_locked(
-                    &env,
-                    FundsLocked {
-                        version: EVENT_VERSION_V2,
-                        bounty_id: item.bounty_id,
-                        amount: item.amount,
-                        depositor: item.depositor.clone(),
-                        deadline: item.deadline,
-                    },
-                );
+    /// Move an unused, unexpired transferable ticket to `new_beneficiary`.
+    /// Requires the current beneficiary's auth; the ticket keeps its id,
+    /// amount and expiry.
+    pub fn transfer_claim_ticket(
+        env: Env,
+        ticket_id: u64,
+        new_beneficiary: Address,
+    ) -> Result<(), soroban_sdk::Error> {
+        let mut ticket: ClaimTicket = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ClaimTicket(ticket_id))
+            .ok_or(Error::TicketNotFound)?;
+        if !ticket.transferable {
+            return Err(ClaimError::TicketNotTransferable.into());
+        }
+        if ticket.revoked {
+            return Err(ClaimError::TicketRevoked.into());
+        }
+        if ticket.used {
+            return Err(Error::TicketAlreadyUsed.into());
+        }
+        let now = env.ledger().timestamp();
+        if now >= ticket.expires_at {
+            return Err(Error::TicketExpired.into());
+        }
+        let previous = ticket.beneficiary.clone();
+        previous.require_auth();
 
-                locked_count += 1;
-            }
+        ticket.beneficiary = new_beneficiary.clone();
+        env.storage()
+            .persistent()
+            .set(&DataKey::ClaimTicket(ticket_id), &ticket);
 
-            emit_batch_funds_locked(
-                &env,
-                BatchFundsLocked {
-                    count: locked_count,
-                    total_amount: ordered_items
-                        .iter()
-                        .try_fold(0i128, |acc, i| acc.checked_add(i.amount))
-                        .unwrap(),
-                    timestamp,
-                },
-            );
+        let mut from_tickets: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::BeneficiaryTickets(previous.clone()))
+            .unwrap_or(Vec::new(&env));
+        if let Some(idx) = from_tickets.first_index_of(ticket_id) {
+            from_tickets.remove(idx);
+        }
+        env.storage().persistent().set(
+            &DataKey::BeneficiaryTickets(previous.clone()),
+            &from_tickets,
+        );
 
-            Ok(locked_count)
-        })();
+        let mut to_tickets: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::BeneficiaryTickets(new_beneficiary.clone()))
+            .unwrap_or(Vec::new(&env));
+        to_tickets.push_back(ticket_id);
+        env.storage().persistent().set(
+            &DataKey::BeneficiaryTickets(new_beneficiary.clone()),
+            &to_tickets,
+        );
 
-        emit_batch_funds_locked(
+        events::emit_ticket_transferred(
             &env,
-            BatchFundsLocked {
-                count: locked_count,
-                total_amount: ordered_items
-                    .iter()
-                    .try_fold(0i128, |acc, i| acc.checked_add(i.amount))
-                    .unwrap(),
-                timestamp,
+            events::TicketTransferred {
+                ticket_id,
+                bounty_id: ticket.bounty_id,
+                from: previous,
+                to: new_beneficiary,
+                transferred_at: now,
             },
         );
-
-        // GUARD: release reentrancy lock
-        reentrancy_guard::release(&env);
-        Ok(locked_count)
-        result
+        Ok(())
     }
 
-    /// Batch release funds to multiple contributors in a single atomic transaction.
-    ///
-    /// Releases between 1 and [`MAX_BATCH_SIZE`] bounties in one admin-authorised
-    /// call, reducing per-transaction overhead compared to repeated single-item
-    /// `release_funds` calls.
-    ///
-    /// ## Batch failure semantics
-    ///
-    /// This operation is **strictly atomic** (all-or-nothing):
-    ///
-    /// 1. All items are validated in a single pass **before** any escrow status
-    ///    is updated or any token transfer is initiated.
-    /// 2. If *any* item fails validation the entire call reverts immediately.
-    ///    No status is changed, no token leaves the contract, and every
-    ///    "sibling" row in the same batch is left completely unaffected.
-    /// 3. After a failed batch the contract is in exactly the same state as
-    ///    before the call; subsequent operations behave as if this call never
-    ///    happened.
-    ///
-    /// ## Ordering guarantee
-    ///
-    /// Items are processed in ascending `bounty_id` order regardless of the
-    /// caller-supplied ordering, ensuring deterministic execution.
-    ///
-    /// ## Checks-Effects-Interactions (CEI)
-    ///
-    /// All escrow statuses are updated to `Released` in a first pass (Effects);
-    /// external token transfers and event emissions happen in a second pass
-    /// (Interactions).
-    ///
-    /// # Arguments
-    /// * `items` - 1–[`MAX_BATCH_SIZE`] [`ReleaseFundsItem`] entries (bounty_id,
-    ///   contributor address).
-    ///
-    /// # Returns
-    /// Number of bounties successfully released (equals `items.len()` on success).
-    ///
-    /// # Errors
-    /// * [`Error::InvalidBatchSize`] — batch is empty or exceeds `MAX_BATCH_SIZE`
-    /// * [`Error::FundsPaused`] — release operations are currently paused
-    /// * [`Error::NotInitialized`] — `init` has not been called
-    /// * [`Error::Unauthorized`] — caller is not the admin
-    /// * [`Error::BountyNotFound`] — a `bounty_id` does not exist in storage
-    /// * [`Error::FundsNotLocked`] — a bounty's status is not `Locked`
-    /// * [`Error::DuplicateBountyId`] — the same `bounty_id` appears more than once
-    ///
-    /// # Reentrancy
-    /// Protected by the shared reentrancy guard (acquired before validation,
-    /// released after all effects and interactions complete).
-    pub fn batch_release_funds(env: Env, items: Vec<ReleaseFundsItem>) -> Result<u32, Error> {
-        if Self::check_paused(&env, symbol_short!("release")) {
-            return Err(Error::FundsPaused);
-        }
-        // GUARD: acquire reentrancy lock
-        reentrancy_guard::acquire(&env);
-        let result: Result<u32, Error> = (|| {
-            // Validate batch size
-            let batch_size = items.len();
-            if batch_size == 0 {
-                return Err(Error::InvalidBatchSize);
-            }
-            if batch_size > MAX_BATCH_SIZE {
-                return Err(Error::InvalidBatchSize);
-            }
-
-            if !env.storage().instance().has(&DataKey::Admin) {
-                return Err(Error::NotInitialized);
-            }
+    /// Revoke an unused claim ticket (admin only) and return its reserved
+    /// amount to the bounty's claimable balance.
+    pub fn revoke_claim_ticket(env: Env, ticket_id: u64) -> Result<(), soroban_sdk::Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
 
-            let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-            admin.require_auth();
+        let mut ticket: ClaimTicket = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ClaimTicket(ticket_id))
+            .ok_or(Error::TicketNotFound)?;
+        if ticket.used {
+            return Err(Error::TicketAlreadyUsed.into());
+        }
+        if ticket.revoked {
+            return Err(ClaimError::TicketRevoked.into());
+        }
 
-            let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-            let client = token::Client::new(&env, &token_addr);
-            let contract_address = env.current_contract_address();
-            let timestamp = env.ledger().timestamp();
+        ticket.revoked = true;
+        env.storage()
+            .persistent()
+            .set(&DataKey::ClaimTicket(ticket_id), &ticket);
+        Self::unreserve_ticket(&env, ticket.bounty_id, ticket_id, ticket.remaining_amount);
 
-            // Validate all items before processing (all-or-nothing approach)
-            let mut total_amount: i128 = 0;
-            for item in items.iter() {
-                // Check if bounty exists
-                if !env
-                    .storage()
-                    .persistent()
-                    .has(&DataKey::Escrow(item.bounty_id))
-                {
-                    return Err(Error::BountyNotFound);
-                }
+        events::emit_ticket_revoked(
+            &env,
+            events::TicketRevoked {
+                ticket_id,
+                bounty_id: ticket.bounty_id,
+                amount: ticket.remaining_amount,
+                revoked_at: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
 
-                let escrow: Escrow = env
-                    .storage()
-                    .persistent()
-                    .get(&DataKey::Escrow(item.bounty_id))
-                    .unwrap();
+    /// Return the reservations of all expired, unused tickets on `bounty_id`
+    /// to its claimable balance. Permissionless, since it only frees funds
+    /// that no ticket can claim any more. Returns the number of tickets swept.
+    pub fn sweep_expired_tickets(env: Env, bounty_id: u64) -> u32 {
+        let outstanding: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&TicketKey::BountyTickets(bounty_id))
+            .unwrap_or(Vec::new(&env));
+        let now = env.ledger().timestamp();
 
-                // Check if funds are locked
-                if escrow.status != EscrowStatus::Locked {
-                    return Err(Error::FundsNotLocked);
+        let mut kept: Vec<u64> = Vec::new(&env);
+        let mut swept: u32 = 0;
+        let mut released: i128 = 0;
+        for ticket_id in outstanding.iter() {
+            let ticket: Option<ClaimTicket> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::ClaimTicket(ticket_id));
+            match ticket {
+                Some(t) if now >= t.expires_at => {
+                    swept += 1;
+                    released += t.remaining_amount;
                 }
+                Some(_) => kept.push_back(ticket_id),
+                None => {}
+            }
+        }
+        if swept == 0 {
+            return 0;
+        }
 
-                // Check for duplicate bounty_ids in the batch
-                let mut count = 0u32;
-                for other_item in items.iter() {
-                    if other_item.bounty_id == item.bounty_id {
-                        count += 1;
-                    }
-                }
-                if count > 1 {
-                    return Err(Error::DuplicateBountyId);
-                }
+        env.storage()
+            .persistent()
+            .set(&TicketKey::BountyTickets(bounty_id), &kept);
+        let reserved = Self::get_ticket_reserved(env.clone(), bounty_id);
+        let reserved_after = (reserved - released).max(0);
+        env.storage()
+            .persistent()
+            .set(&TicketKey::TicketReserved(bounty_id), &reserved_after);
+        let escrow_remaining = env
+            .storage()
+            .persistent()
+            .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+            .map(|escrow| escrow.remaining_amount)
+            .unwrap_or(0);
 
-                total_amount = total_amount
-                    .checked_add(escrow.amount)
-                    .ok_or(Error::InvalidAmount)?;
-            }
+        events::emit_tickets_swept(
+            &env,
+            events::TicketsSwept {
+                version: EVENT_VERSION_V3,
+                bounty_id,
+                count: swept,
+                released_amount: released,
+                remaining_before: escrow_remaining - reserved,
+                remaining_after: escrow_remaining - reserved_after,
+                swept_at: now,
+            },
+        );
+        swept
+    }
 
-            let ordered_items = Self::order_batch_release_items(&env, &items);
+    /// Claim `amount` of a ticket's remaining balance. The ticket stays
+    /// usable until its balance is exhausted, it expires, or it is revoked,
+    /// so beneficiaries can stagger withdrawals. Requires the current
+    /// beneficiary's auth; each withdrawal is recorded in
+    /// `get_ticket_claims`.
+    pub fn claim_with_ticket_partial(
+        env: Env,
+        ticket_id: u64,
+        amount: i128,
+    ) -> Result<(), soroban_sdk::Error> {
+        let ticket = Self::load_claimable_ticket(&env, ticket_id)?;
+        ticket.beneficiary.require_auth();
+        Self::require_not_blocked(&env, &ticket.beneficiary)?;
+        if amount <= 0 || amount > ticket.remaining_amount {
+            return Err(Error::InvalidAmount.into());
+        }
 
-            // EFFECTS: update all escrow records before any external calls (CEI)
-            // We collect (contributor, amount) pairs for the transfer pass.
-            let mut release_pairs: Vec<(Address, i128)> = Vec::new(&env);
-            let mut released_count = 0u32;
-            for item in ordered_items.iter() {
-                let mut escrow: Escrow = env
-                    .storage()
-                    .persistent()
-                    .get(&DataKey::Escrow(item.bounty_id))
-                    .unwrap();
+        let beneficiary = ticket.beneficiary.clone();
+        Self::pay_ticket_claim(&env, ticket_id, ticket, amount, None)?;
+        monitoring::track_operation(&env, symbol_short!("tkt_claim"), beneficiary, true);
+        Ok(())
+    }
 
-                let amount = escrow.amount;
-                escrow.status = EscrowStatus::Released;
-                escrow.remaining_amount = 0;
-                env.storage()
-                    .persistent()
-                    .set(&DataKey::Escrow(item.bounty_id), &escrow);
+    /// Register the ed25519 key that signs `beneficiary`'s off-chain
+    /// authorizations: relayed claims and, for depositors, payment intents.
+    /// Requires the beneficiary's auth, which can itself be relayed as a
+    /// signed authorization entry.
+    pub fn set_claim_key(env: Env, beneficiary: Address, public_key: BytesN<32>) {
+        beneficiary.require_auth();
+        env.storage()
+            .persistent()
+            .set(&SignatureKey::ClaimKey(beneficiary), &public_key);
+    }
 
-                release_pairs.push_back((item.contributor.clone(), amount));
-                released_count += 1;
-            }
+    pub fn get_claim_key(env: Env, beneficiary: Address) -> Option<BytesN<32>> {
+        env.storage()
+            .persistent()
+            .get(&SignatureKey::ClaimKey(beneficiary))
+    }
 
-            // INTERACTION: all external token transfers happen after state is finalized
-            for (idx, item) in ordered_items.iter().enumerate() {
-                let (ref contributor, amount) = release_pairs.get(idx as u32).unwrap();
-                client.transfer(&contract_address, contributor, &amount);
+    /// Register the ed25519 key that can redirect `depositor`'s refunds via
+    /// `refund_to` should the depositor lose its own key. Kept apart from the
+    /// claim key so it can stay offline.
+    pub fn set_recovery_key(env: Env, depositor: Address, public_key: BytesN<32>) {
+        depositor.require_auth();
+        env.storage()
+            .persistent()
+            .set(&SignatureKey::RecoveryKey(depositor), &public_key);
+    }
 
-                emit_funds_released(
-                    &env,
-                    FundsReleased {
-                        version: EVENT_VERSION_V2,
-                        bounty_id: item.bounty_id,
-                        amount,
-                        recipient: contributor.clone(),
-                        timestamp,
-                    },
-                );
-            }
+    pub fn get_recovery_key(env: Env, depositor: Address) -> Option<BytesN<32>> {
+        env.storage()
+            .persistent()
+            .get(&SignatureKey::RecoveryKey(depositor))
+    }
 
-            // Emit batch event
-            emit_batch_funds_released(
-                &env,
-                BatchFundsReleased {
-                    count: released_count,
-                    total_amount,
-                    timestamp,
-                },
-            );
+    /// Set the share of a relayed claim paid to the relayer, in basis
+    /// points (admin only, at most 5%).
+    pub fn set_relayer_tip_bps(env: Env, tip_bps: u32) -> Result<(), soroban_sdk::Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        if tip_bps > MAX_RELAYER_TIP_BPS {
+            return Err(Error::InvalidFeeRate.into());
+        }
+        env.storage()
+            .instance()
+            .set(&SignatureKey::RelayerTipBps, &tip_bps);
+        Ok(())
+    }
 
-            Ok(released_count)
-        })();
+    pub fn get_relayer_tip_bps(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&SignatureKey::RelayerTipBps)
+            .unwrap_or(0)
+    }
 
-        // GUARD: release reentrancy lock
-        reentrancy_guard::release(&env);
-        result
+    /// Message the beneficiary signs to let `relayer` submit the claim of
+    /// `ticket_id`. It binds this contract, the ticket, the relayer, the
+    /// current tip and the beneficiary's `nonce`, so a signature cannot be
+    /// replayed elsewhere or reused after the tip changes. Signers follow
+    /// SEP-53: the signature is over
+    /// `sha256("Stellar Signed Message:\n" || message)`.
+    pub fn get_relayed_claim_message(
+        env: Env,
+        ticket_id: u64,
+        relayer: Address,
+        nonce: u64,
+    ) -> Bytes {
+        Self::relayed_claim_message(&env, ticket_id, &relayer, nonce)
     }
-    /// Update stored metadata for a bounty.
-    ///
-    /// # Arguments
-    /// * `env` - Contract environment
-    /// * `_admin` - Admin address (auth enforced against stored admin)
-    /// * `bounty_id` - Bounty identifier
-    /// * `repo_id` - Repository identifier
-    /// * `issue_id` - Issue identifier
-    /// * `bounty_type` - Human-readable bounty type tag (1..=50 chars)
-    /// * `reference_hash` - Optional reference hash for off-chain metadata
+
+    /// Claim a ticket's whole remaining balance on the beneficiary's behalf.
     ///
-    /// # Panics
-    /// Panics if `bounty_type` is empty or exceeds the maximum length.
-    pub fn update_metadata(
+    /// `relayer` submits (and pays for) the transaction; the beneficiary
+    /// only signs the `get_relayed_claim_message` payload off-chain with the
+    /// key registered through `set_claim_key`, consuming their `nonce`.
+    /// The relayer receives `get_relayer_tip_bps` of the claim and the
+    /// beneficiary the rest. An invalid signature aborts the call. Returns
+    /// the tip paid.
+    pub fn claim_with_ticket_for(
         env: Env,
-        _admin: Address,
-        bounty_id: u64,
-        repo_id: u64,
-        issue_id: u64,
-        bounty_type: soroban_sdk::String,
-        reference_hash: Option<soroban_sdk::Bytes>,
-    ) -> Result<(), Error> {
-        let stored_admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .ok_or(Error::NotInitialized)?;
-        stored_admin.require_auth();
+        ticket_id: u64,
+        relayer: Address,
+        nonce: u64,
+        signature: BytesN<64>,
+    ) -> Result<i128, soroban_sdk::Error> {
+        let ticket = Self::load_claimable_ticket(&env, ticket_id)?;
+        Self::verify_signed_message(
+            &env,
+            &ticket.beneficiary,
+            &Self::relayed_claim_message(&env, ticket_id, &relayer, nonce),
+            nonce,
+            &signature,
+        )?;
+        Self::require_not_blocked(&env, &ticket.beneficiary)?;
+        Self::require_not_blocked(&env, &relayer)?;
 
-        validation::validate_tag(&env, &bounty_type, "bounty_type");
+        let amount = ticket.remaining_amount;
+        let tip = amount * Self::get_relayer_tip_bps(env.clone()) as i128 / BASIS_POINTS;
+        Self::pay_ticket_claim(
+            &env,
+            ticket_id,
+            ticket,
+            amount,
+            Some((relayer.clone(), tip)),
+        )?;
+        monitoring::track_operation(&env, symbol_short!("tkt_relay"), relayer, true);
+        Ok(tip)
+    }
 
-        let (existing_flags, existing_prefs) = env
+    /// Next nonce `signer` must put in a signed message. Every
+    /// signature-authorized entrypoint (relayed claims, payment intents,
+    /// signed resolver approvals, recovery refunds) draws from this one
+    /// counter, so each signature is usable exactly once.
+    pub fn get_nonce(env: Env, signer: Address) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&SignatureKey::SignerNonce(signer))
+            .unwrap_or(0)
+    }
+
+    /// Check `signature` over `message` against `signer`'s registered claim
+    /// key, using the SEP-53 digest, and consume `nonce`, which must be the
+    /// signer's next nonce. A bad signature aborts the call.
+    fn verify_signed_message(
+        env: &Env,
+        signer: &Address,
+        message: &Bytes,
+        nonce: u64,
+        signature: &BytesN<64>,
+    ) -> Result<(), soroban_sdk::Error> {
+        let public_key: BytesN<32> = env
             .storage()
             .persistent()
-            .get::<DataKey, EscrowMetadata>(&DataKey::Metadata(bounty_id))
-            .map(|metadata| (metadata.risk_flags, metadata.notification_prefs))
-            .unwrap_or((0, 0));
+            .get(&SignatureKey::ClaimKey(signer.clone()))
+            .ok_or(ClaimError::ClaimKeyNotSet)?;
+        Self::verify_signed_with_key(env, signer, &public_key, message, nonce, signature)
+    }
 
-        let metadata = EscrowMetadata {
-            repo_id,
-            issue_id,
-            bounty_type,
-            risk_flags: existing_flags,
-            notification_prefs: existing_prefs,
-            reference_hash,
-        };
+    /// As `verify_signed_message`, against an explicit `public_key`.
+    fn verify_signed_with_key(
+        env: &Env,
+        signer: &Address,
+        public_key: &BytesN<32>,
+        message: &Bytes,
+        nonce: u64,
+        signature: &BytesN<64>,
+    ) -> Result<(), soroban_sdk::Error> {
+        let expected = Self::get_nonce(env.clone(), signer.clone());
+        if nonce != expected {
+            return Err(ClaimError::InvalidNonce.into());
+        }
+        let mut signed = Bytes::from_slice(env, SIGNED_MESSAGE_PREFIX);
+        signed.append(message);
+        let digest: Bytes = env.crypto().sha256(&signed).into();
+        env.crypto().ed25519_verify(public_key, &digest, signature);
         env.storage()
             .persistent()
-            .set(&DataKey::Metadata(bounty_id), &metadata);
+            .set(&SignatureKey::SignerNonce(signer.clone()), &(expected + 1));
         Ok(())
     }
 
-    pub fn get_metadata(env: Env, bounty_id: u64) -> Result<EscrowMetadata, Error> {
-        env.storage()
-            .persistent()
-            .get(&DataKey::Metadata(bounty_id))
-            .ok_or(Error::BountyNotFound)
+    fn relayed_claim_message(env: &Env, ticket_id: u64, relayer: &Address, nonce: u64) -> Bytes {
+        let tip_bps = Self::get_relayer_tip_bps(env.clone());
+        let mut message = symbol_short!("rly_claim").to_xdr(env);
+        message.append(&env.current_contract_address().to_xdr(env));
+        message.append(&Bytes::from_array(env, &ticket_id.to_be_bytes()));
+        message.append(&relayer.clone().to_xdr(env));
+        message.append(&Bytes::from_array(env, &tip_bps.to_be_bytes()));
+        message.append(&Bytes::from_array(env, &nonce.to_be_bytes()));
+        message
     }
 
-    /// Build the context bytes that feed into the deterministic PRNG.
-    ///
-    /// The context binds selection to the current contract address, bounty
-    /// parameters, **ledger timestamp**, and the monotonic ticket counter.
-    /// Changing any of these inputs produces a completely different SHA-256
-    /// digest and therefore a different winner.
-    ///
-    /// # Ledger inputs included
-    /// - `env.ledger().timestamp()` — ties the result to the block that
-    ///   executes the transaction.
-    /// - `TicketCounter` — monotonically increasing; prevents two calls
-    ///   within the same ledger close from producing identical context.
-    ///
-    /// # Predictability limits
-    /// Because the ledger timestamp is known to validators before block
-    /// close, a validator-level adversary can predict the outcome for a
-    /// given external seed.  See `DETERMINISTIC_RANDOMNESS.md` for the
-    /// full threat model.
-    fn build_claim_selection_context(
-        env: &Env,
+    /// Message the depositor signs to pre-authorize releasing `amount` of
+    /// `bounty_id` to `contributor`. Signed the same way as relayed claims
+    /// (SEP-53) with the depositor's key from `set_claim_key`.
+    pub fn get_payment_intent_message(
+        env: Env,
         bounty_id: u64,
+        contributor: Address,
         amount: i128,
-        expires_at: u64,
+        nonce: u64,
+        expiry: u64,
     ) -> Bytes {
-        let mut context = Bytes::new(env);
-        context.append(&env.current_contract_address().to_xdr(env));
-        context.append(&Bytes::from_array(env, &bounty_id.to_be_bytes()));
-        context.append(&Bytes::from_array(env, &amount.to_be_bytes()));
-        context.append(&Bytes::from_array(env, &expires_at.to_be_bytes()));
-        context.append(&Bytes::from_array(
-            env,
-            &env.ledger().timestamp().to_be_bytes(),
-        ));
-        let ticket_counter: u64 = env
-            .storage()
-            .persistent()
-            .get(&DataKey::TicketCounter)
-            .unwrap_or(0);
-        context.append(&Bytes::from_array(env, &ticket_counter.to_be_bytes()));
-        context
+        Self::payment_intent_message(&env, bounty_id, &contributor, amount, nonce, expiry)
     }
 
-    /// Deterministically derive the winner index for claim ticket issuance.
-    ///
-    /// This is a pure/view helper that lets clients verify expected results
-    /// before issuing a ticket.  The index is computed via per-candidate
-    /// SHA-256 scoring (see `grainlify_core::pseudo_randomness`), making
-    /// the result **order-independent** — shuffling `candidates` does not
-    /// change which address is selected.
-    ///
-    /// # Arguments
-    /// * `bounty_id` — Bounty whose context seeds the PRNG.
-    /// * `candidates` — Non-empty list of eligible addresses.
-    /// * `amount` — Claim amount mixed into the context hash.
-    /// * `expires_at` — Ticket expiry mixed into the context hash.
-    /// * `external_seed` — Caller-provided 32-byte seed.
-    ///
-    /// # Errors
-    /// Returns `Error::InvalidSelectionInput` when `candidates` is empty.
-    pub fn derive_claim_ticket_winner_index(
+    /// Record a release the depositor approved off-chain, e.g. by a bot
+    /// holding the depositor's claim key. Anyone may submit it; the
+    /// signature is checked and the depositor's `nonce` consumed here.
+    /// Funds move only on `execute_payment_intent`. Returns the intent id.
+    pub fn create_payment_intent(
         env: Env,
         bounty_id: u64,
-        candidates: Vec<Address>,
+        contributor: Address,
         amount: i128,
-        expires_at: u64,
-        external_seed: BytesN<32>,
-    ) -> Result<u32, Error> {
-        if candidates.is_empty() {
-            return Err(Error::InvalidSelectionInput);
+        nonce: u64,
+        expiry: u64,
+        signature: BytesN<64>,
+    ) -> Result<u64, soroban_sdk::Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized.into());
         }
-        let context = Self::build_claim_selection_context(&env, bounty_id, amount, expires_at);
-        let domain = Symbol::new(&env, "claim_prng_v1");
-        let selection = pseudo_randomness::derive_selection(
+        Self::migrate_escrow_record(&env, bounty_id);
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked.into());
+        }
+        if amount <= 0 {
+            return Err(Error::InvalidAmount.into());
+        }
+        if amount > escrow.remaining_amount {
+            return Err(Error::InsufficientFunds.into());
+        }
+        let now = env.ledger().timestamp();
+        if expiry <= now {
+            return Err(Error::InvalidDeadline.into());
+        }
+        Self::verify_signed_message(
             &env,
-            &domain,
-            &context,
-            &external_seed,
-            &candidates,
-        )
-        .ok_or(Error::InvalidSelectionInput)?;
-        Ok(selection.index)
-    }
+            &escrow.depositor,
+            &Self::payment_intent_message(&env, bounty_id, &contributor, amount, nonce, expiry),
+            nonce,
+            &signature,
+        )?;
 
-    /// Deterministically derive the winner **address** for claim ticket issuance.
-    ///
-    /// Convenience wrapper around [`Self::derive_claim_ticket_winner_index`]
-    /// that resolves the winning index back to an `Address`.
-    ///
-    /// # Errors
-    /// Returns `Error::InvalidSelectionInput` when `candidates` is empty or
-    /// the resolved index is out of bounds.
-    pub fn derive_claim_ticket_winner(
-        env: Env,
-        bounty_id: u64,
-        candidates: Vec<Address>,
-        amount: i128,
-        expires_at: u64,
-        external_seed: BytesN<32>,
-    ) -> Result<Address, Error> {
-        let index = Self::derive_claim_ticket_winner_index(
-            env.clone(),
+        let intent_id: u64 = env
+            .storage()
+            .persistent()
+            .get(&SignatureKey::PaymentIntentCounter)
+            .unwrap_or(0)
+            + 1;
+        env.storage()
+            .persistent()
+            .set(&SignatureKey::PaymentIntentCounter, &intent_id);
+        let intent = PaymentIntent {
+            intent_id,
             bounty_id,
-            candidates.clone(),
+            depositor: escrow.depositor,
+            contributor,
             amount,
-            expires_at,
-            external_seed,
-        )?;
-        candidates.get(index).ok_or(Error::InvalidSelectionInput)
+            nonce,
+            expiry,
+            created_at: now,
+            executed: false,
+            cancelled: false,
+        };
+        env.storage()
+            .persistent()
+            .set(&SignatureKey::PaymentIntent(intent_id), &intent);
+
+        events::emit_payment_intent_created(
+            &env,
+            events::PaymentIntentCreated {
+                intent_id,
+                bounty_id,
+                depositor: intent.depositor,
+                contributor: intent.contributor,
+                amount,
+                nonce,
+                expiry,
+                created_at: now,
+            },
+        );
+        Ok(intent_id)
     }
 
-    /// Deterministically select a winner from `candidates` and issue a claim ticket.
-    ///
-    /// Combines [`Self::derive_claim_ticket_winner`] with
-    /// [`Self::issue_claim_ticket`] in a single atomic call.  Emits a
-    /// `DeterministicSelectionDerived` event containing the seed hash,
-    /// winner score, and selected index for off-chain auditability.
-    ///
-    /// # Security notes
-    /// - **Deterministic and verifiable** — any observer can replay the
-    ///   selection from the published event fields.
-    /// - **Not unbiased randomness** — callers who control both the
-    ///   external seed and submission timing can influence outcomes.
-    ///   See `DETERMINISTIC_RANDOMNESS.md` for mitigation guidance.
-    /// - The selection is **order-independent**: candidate list ordering
-    ///   does not affect which address wins.
-    ///
-    /// # Errors
-    /// Returns `Error::InvalidSelectionInput` when `candidates` is empty.
-    pub fn issue_claim_ticket_deterministic(
-        env: Env,
-        bounty_id: u64,
-        candidates: Vec<Address>,
-        amount: i128,
-        expires_at: u64,
-        external_seed: BytesN<32>,
-    ) -> Result<u64, Error> {
-        if candidates.is_empty() {
-            return Err(Error::InvalidSelectionInput);
+    /// Release a payment intent's amount to its contributor. Callable by
+    /// anyone until the intent expires; the escrow must still be locked
+    /// with enough remaining balance.
+    pub fn execute_payment_intent(env: Env, intent_id: u64) -> Result<(), soroban_sdk::Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized.into());
         }
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused.into());
+        }
+
+        let mut intent: PaymentIntent = env
+            .storage()
+            .persistent()
+            .get(&SignatureKey::PaymentIntent(intent_id))
+            .ok_or(ClaimError::PaymentIntentNotFound)?;
+        if intent.executed || intent.cancelled {
+            return Err(ClaimError::PaymentIntentClosed.into());
+        }
+        let now = env.ledger().timestamp();
+        if now >= intent.expiry {
+            return Err(ClaimError::PaymentIntentExpired.into());
+        }
+        let bounty_id = intent.bounty_id;
+        let amount = intent.amount;
+
+        Self::migrate_escrow_record(&env, bounty_id);
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked.into());
+        }
+        if amount > escrow.remaining_amount {
+            return Err(Error::InsufficientFunds.into());
+        }
+        Self::require_payout_allowed(&env, bounty_id, &intent.contributor, amount)?;
+
+        reentrancy_guard::acquire(&env);
+
+        // EFFECTS before the token transfer (CEI)
+        intent.executed = true;
+        env.storage()
+            .persistent()
+            .set(&SignatureKey::PaymentIntent(intent_id), &intent);
+
+        escrow.remaining_amount -= amount;
+        if escrow.remaining_amount == 0 {
+            escrow.status = EscrowStatus::Released;
+        }
+        invariants::assert_escrow(&env, &escrow);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+        Self::index_status(&env, bounty_id, &escrow.status);
+        Self::sync_escrow_accounting(&env, bounty_id, &escrow);
+
+        let mut records: Vec<ReleaseRecord> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ReleaseRecords(bounty_id))
+            .unwrap_or(Vec::new(&env));
+        records.push_back(ReleaseRecord {
+            recipient: intent.contributor.clone(),
+            amount,
+            released_at: now,
+        });
+        env.storage()
+            .persistent()
+            .set(&DataKey::ReleaseRecords(bounty_id), &records);
 
-        let context = Self::build_claim_selection_context(&env, bounty_id, amount, expires_at);
-        let domain = Symbol::new(&env, "claim_prng_v1");
-        let selection = pseudo_randomness::derive_selection(
+        // INTERACTION
+        let client = token::Client::new(&env, &Self::bounty_token(&env, bounty_id));
+        client.transfer(
+            &env.current_contract_address(),
+            &intent.contributor,
+            &amount,
+        );
+        threshold_monitor::record_outflow(&env, amount);
+
+        emit_funds_released(
             &env,
-            &domain,
-            &context,
-            &external_seed,
-            &candidates,
-        )
-        .ok_or(Error::InvalidSelectionInput)?;
+            FundsReleased {
+                version: EVENT_VERSION_V3,
+                bounty_id,
+                amount,
+                remaining_before: escrow.remaining_amount + amount,
+                remaining_after: escrow.remaining_amount,
+                recipient: intent.contributor.clone(),
+                timestamp: now,
+            },
+        );
+        events::emit_payment_intent_executed(
+            &env,
+            events::PaymentIntentExecuted {
+                version: EVENT_VERSION_V3,
+                intent_id,
+                bounty_id,
+                contributor: intent.contributor.clone(),
+                amount,
+                remaining_before: escrow.remaining_amount + amount,
+                remaining_after: escrow.remaining_amount,
+                executed_at: now,
+            },
+        );
 
-        let selected = candidates
-            .get(selection.index)
-            .ok_or(Error::InvalidSelectionInput)?;
+        multitoken_invariants::assert_after_disbursement(&env);
+        reentrancy_guard::release(&env);
+        monitoring::track_operation(&env, symbol_short!("intent"), intent.contributor, true);
+        Ok(())
+    }
 
-        emit_deterministic_selection(
+    /// Withdraw a payment intent before it is executed (depositor only).
+    pub fn cancel_payment_intent(env: Env, intent_id: u64) -> Result<(), soroban_sdk::Error> {
+        let mut intent: PaymentIntent = env
+            .storage()
+            .persistent()
+            .get(&SignatureKey::PaymentIntent(intent_id))
+            .ok_or(ClaimError::PaymentIntentNotFound)?;
+        intent.depositor.require_auth();
+        if intent.executed || intent.cancelled {
+            return Err(ClaimError::PaymentIntentClosed.into());
+        }
+        intent.cancelled = true;
+        env.storage()
+            .persistent()
+            .set(&SignatureKey::PaymentIntent(intent_id), &intent);
+        events::emit_payment_intent_cancelled(
             &env,
-            DeterministicSelectionDerived {
-                bounty_id,
-                selected_index: selection.index,
-                candidate_count: candidates.len(),
-                selected_beneficiary: selected.clone(),
-                seed_hash: selection.seed_hash,
-                winner_score: selection.winner_score,
-                timestamp: env.ledger().timestamp(),
+            events::PaymentIntentCancelled {
+                intent_id,
+                bounty_id: intent.bounty_id,
+                cancelled_at: env.ledger().timestamp(),
             },
         );
+        Ok(())
+    }
 
-        Self::issue_claim_ticket(env, bounty_id, selected, amount, expires_at)
+    pub fn get_payment_intent(env: Env, intent_id: u64) -> Option<PaymentIntent> {
+        env.storage()
+            .persistent()
+            .get(&SignatureKey::PaymentIntent(intent_id))
     }
 
-    /// Issue a single-use claim ticket to a bounty winner (admin only)
-    ///
-    /// This creates a ticket that the beneficiary can use to claim their reward exactly once.
-    /// Tickets are bound to a specific address, amount, and expiry time.
-    ///
-    /// # Arguments
-    /// * `env` - Contract environment
-    /// * `bounty_id` - ID of the bounty being claimed
-    /// * `beneficiary` - Address of the winner who will claim the reward
-    /// * `amount` - Amount to be claimed (in token units)
-    /// * `expires_at` - Unix timestamp when the ticket expires
-    ///
-    /// # Returns
-    /// * `Ok(ticket_id)` - The unique ticket ID for this claim
-    /// * `Err(Error::NotInitialized)` - Contract not initialized
-    /// * `Err(Error::Unauthorized)` - Caller is not admin
-    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
-    /// * `Err(Error::InvalidDeadline)` - Expiry time is in the past
-    /// * `Err(Error::InvalidAmount)` - Amount is invalid or exceeds escrow amount
-    pub fn issue_claim_ticket(
-        env: Env,
+    fn payment_intent_message(
+        env: &Env,
         bounty_id: u64,
-        beneficiary: Address,
+        contributor: &Address,
         amount: i128,
-        expires_at: u64,
-    ) -> Result<u64, Error> {
+        nonce: u64,
+        expiry: u64,
+    ) -> Bytes {
+        // Leading tag keeps intent and relayed claim payloads disjoint.
+        let mut message = symbol_short!("pay_int").to_xdr(env);
+        message.append(&env.current_contract_address().to_xdr(env));
+        message.append(&Bytes::from_array(env, &bounty_id.to_be_bytes()));
+        message.append(&contributor.clone().to_xdr(env));
+        message.append(&Bytes::from_array(env, &amount.to_be_bytes()));
+        message.append(&Bytes::from_array(env, &nonce.to_be_bytes()));
+        message.append(&Bytes::from_array(env, &expiry.to_be_bytes()));
+        message
+    }
+
+    /// Load a ticket that can still be claimed: initialized contract,
+    /// releases not paused, and the ticket neither revoked, used nor
+    /// expired.
+    fn load_claimable_ticket(env: &Env, ticket_id: u64) -> Result<ClaimTicket, soroban_sdk::Error> {
         if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
+            return Err(Error::NotInitialized.into());
+        }
+        if Self::check_paused(env, symbol_short!("release")) {
+            return Err(Error::FundsPaused.into());
         }
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
 
-        let escrow_amount: i128;
-        let escrow_status: EscrowStatus;
-        if env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            let escrow: Escrow = env
-                .storage()
-                .persistent()
-                .get(&DataKey::Escrow(bounty_id))
-                .unwrap();
-            escrow_amount = escrow.amount;
-            escrow_status = escrow.status;
-        } else if env
+        let ticket: ClaimTicket = env
             .storage()
             .persistent()
-            .has(&DataKey::EscrowAnon(bounty_id))
-        {
-            let anon: AnonymousEscrow = env
-                .storage()
-                .persistent()
-                .get(&DataKey::EscrowAnon(bounty_id))
-                .unwrap();
-            escrow_amount = anon.amount;
-            escrow_status = anon.status;
-        } else {
-            return Err(Error::BountyNotFound);
+            .get(&DataKey::ClaimTicket(ticket_id))
+            .ok_or(Error::TicketNotFound)?;
+        if ticket.revoked {
+            return Err(ClaimError::TicketRevoked.into());
         }
-
-        if escrow_status != EscrowStatus::Locked {
-            return Err(Error::FundsNotLocked);
+        if ticket.used {
+            return Err(Error::TicketAlreadyUsed.into());
         }
-        if amount <= 0 || amount > escrow_amount {
-            return Err(Error::InvalidAmount);
+        if env.ledger().timestamp() >= ticket.expires_at {
+            return Err(Error::TicketExpired.into());
         }
+        Ok(ticket)
+    }
 
+    /// Pay `amount` of a ticket out of its escrow. With `relayer_tip` set,
+    /// the tip goes to the relayer and the rest to the beneficiary.
+    fn pay_ticket_claim(
+        env: &Env,
+        ticket_id: u64,
+        mut ticket: ClaimTicket,
+        amount: i128,
+        relayer_tip: Option<(Address, i128)>,
+    ) -> Result<(), soroban_sdk::Error> {
         let now = env.ledger().timestamp();
-        if expires_at <= now {
-            return Err(Error::InvalidDeadline);
-        }
-
-        let ticket_counter_key = DataKey::TicketCounter;
-        let mut ticket_id: u64 = env
+        let bounty_id = ticket.bounty_id;
+        let mut escrow: Escrow = env
             .storage()
             .persistent()
-            .get(&ticket_counter_key)
-            .unwrap_or(0);
-        ticket_id += 1;
-        env.storage()
-            .persistent()
-            .set(&ticket_counter_key, &ticket_id);
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked.into());
+        }
+        if amount > escrow.remaining_amount {
+            return Err(Error::InsufficientFunds.into());
+        }
+        Self::require_payout_allowed(env, bounty_id, &ticket.beneficiary, amount)?;
 
-        let ticket = ClaimTicket {
-            ticket_id,
-            bounty_id,
-            beneficiary: beneficiary.clone(),
-            amount,
-            expires_at,
-            used: false,
-            issued_at: now,
-        };
+        reentrancy_guard::acquire(env);
 
+        // EFFECTS before the token transfer (CEI)
+        ticket.remaining_amount -= amount;
+        if ticket.remaining_amount == 0 {
+            ticket.used = true;
+        }
         env.storage()
             .persistent()
             .set(&DataKey::ClaimTicket(ticket_id), &ticket);
+        Self::release_ticket_reservation(env, bounty_id, ticket_id, amount, ticket.used);
 
-        let mut ticket_index: Vec<u64> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::ClaimTicketIndex)
-            .unwrap_or(Vec::new(&env));
-        ticket_index.push_back(ticket_id);
+        escrow.remaining_amount -= amount;
+        if escrow.remaining_amount == 0 {
+            escrow.status = EscrowStatus::Released;
+        }
+        invariants::assert_escrow(env, &escrow);
         env.storage()
             .persistent()
-            .set(&DataKey::ClaimTicketIndex, &ticket_index);
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+        Self::index_status(env, bounty_id, &escrow.status);
+        Self::sync_escrow_accounting(env, bounty_id, &escrow);
 
-        let mut beneficiary_tickets: Vec<u64> = env
+        let tip = relayer_tip.as_ref().map_or(0, |(_, tip)| *tip);
+        let mut claims: Vec<ReleaseRecord> = env
             .storage()
             .persistent()
-            .get(&DataKey::BeneficiaryTickets(beneficiary.clone()))
-            .unwrap_or(Vec::new(&env));
-        beneficiary_tickets.push_back(ticket_id);
-        env.storage().persistent().set(
-            &DataKey::BeneficiaryTickets(beneficiary.clone()),
-            &beneficiary_tickets,
+            .get(&TicketKey::TicketClaims(ticket_id))
+            .unwrap_or(Vec::new(env));
+        claims.push_back(ReleaseRecord {
+            recipient: ticket.beneficiary.clone(),
+            amount: amount - tip,
+            released_at: now,
+        });
+        if let Some((relayer, tip)) = relayer_tip.clone().filter(|(_, tip)| *tip > 0) {
+            claims.push_back(ReleaseRecord {
+                recipient: relayer,
+                amount: tip,
+                released_at: now,
+            });
+        }
+        env.storage()
+            .persistent()
+            .set(&TicketKey::TicketClaims(ticket_id), &claims);
+
+        let token_addr = Self::bounty_token(env, bounty_id);
+        let client = token::Client::new(env, &token_addr);
+        client.transfer(
+            &env.current_contract_address(),
+            &ticket.beneficiary,
+            &(amount - tip),
         );
+        if let Some((relayer, tip)) = relayer_tip.clone().filter(|(_, tip)| *tip > 0) {
+            client.transfer(&env.current_contract_address(), &relayer, &tip);
+        }
+        threshold_monitor::record_outflow(env, amount);
 
-        emit_ticket_issued(
-            &env,
-            TicketIssued {
+        emit_funds_released(
+            env,
+            FundsReleased {
+                version: EVENT_VERSION_V3,
+                bounty_id,
+                amount,
+                remaining_before: escrow.remaining_amount + amount,
+                remaining_after: escrow.remaining_amount,
+                recipient: ticket.beneficiary.clone(),
+                timestamp: now,
+            },
+        );
+        events::emit_ticket_partially_claimed(
+            env,
+            events::TicketPartiallyClaimed {
+                version: EVENT_VERSION_V3,
                 ticket_id,
                 bounty_id,
-                beneficiary,
                 amount,
-                expires_at,
-                issued_at: now,
+                remaining_amount: ticket.remaining_amount,
+                remaining_before: escrow.remaining_amount + amount,
+                remaining_after: escrow.remaining_amount,
+                claimer: ticket.beneficiary.clone(),
+                claimed_at: now,
             },
         );
+        if ticket.used {
+            emit_ticket_claimed(
+                env,
+                TicketClaimed {
+                    version: EVENT_VERSION_V3,
+                    ticket_id,
+                    bounty_id,
+                    claimer: ticket.beneficiary.clone(),
+                    remaining_before: escrow.remaining_amount + amount,
+                    remaining_after: escrow.remaining_amount,
+                    claimed_at: now,
+                },
+            );
+        }
+        if let Some((relayer, tip)) = relayer_tip {
+            events::emit_relayed_claim(
+                env,
+                events::RelayedClaim {
+                    version: EVENT_VERSION_V3,
+                    ticket_id,
+                    bounty_id,
+                    beneficiary: ticket.beneficiary.clone(),
+                    relayer,
+                    amount,
+                    tip,
+                    remaining_before: escrow.remaining_amount + amount,
+                    remaining_after: escrow.remaining_amount,
+                    claimed_at: now,
+                },
+            );
+        }
+
+        multitoken_invariants::assert_after_disbursement(env);
+        reentrancy_guard::release(env);
+        Ok(())
+    }
+
+    /// Withdrawals made against a ticket, oldest first.
+    pub fn get_ticket_claims(env: Env, ticket_id: u64) -> Vec<ReleaseRecord> {
+        env.storage()
+            .persistent()
+            .get(&TicketKey::TicketClaims(ticket_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Amount of the bounty's balance reserved by outstanding claim tickets.
+    pub fn get_ticket_reserved(env: Env, bounty_id: u64) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&TicketKey::TicketReserved(bounty_id))
+            .unwrap_or(0)
+    }
+
+    fn reserve_ticket(env: &Env, bounty_id: u64, ticket_id: u64, amount: i128) {
+        let mut outstanding: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&TicketKey::BountyTickets(bounty_id))
+            .unwrap_or(Vec::new(env));
+        outstanding.push_back(ticket_id);
+        env.storage()
+            .persistent()
+            .set(&TicketKey::BountyTickets(bounty_id), &outstanding);
+        let reserved = Self::get_ticket_reserved(env.clone(), bounty_id);
+        env.storage()
+            .persistent()
+            .set(&TicketKey::TicketReserved(bounty_id), &(reserved + amount));
+    }
+
+    fn unreserve_ticket(env: &Env, bounty_id: u64, ticket_id: u64, amount: i128) {
+        Self::release_ticket_reservation(env, bounty_id, ticket_id, amount, true);
+    }
+
+    /// Release `amount` of a ticket's reservation, dropping the ticket from
+    /// the bounty's outstanding list when `close` is set.
+    fn release_ticket_reservation(
+        env: &Env,
+        bounty_id: u64,
+        ticket_id: u64,
+        amount: i128,
+        close: bool,
+    ) {
+        let mut outstanding: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&TicketKey::BountyTickets(bounty_id))
+            .unwrap_or(Vec::new(env));
+        let idx = match outstanding.first_index_of(ticket_id) {
+            Some(idx) => idx,
+            None => return,
+        };
+        if close {
+            outstanding.remove(idx);
+            env.storage()
+                .persistent()
+                .set(&TicketKey::BountyTickets(bounty_id), &outstanding);
+        }
+        let reserved = Self::get_ticket_reserved(env.clone(), bounty_id);
+        env.storage().persistent().set(
+            &TicketKey::TicketReserved(bounty_id),
+            &(reserved - amount).max(0),
+        );
+    }
 
-        Ok(ticket_id)
+    /// Ticket ids currently held by `beneficiary`, in the order received.
+    pub fn get_beneficiary_tickets(env: Env, beneficiary: Address) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::BeneficiaryTickets(beneficiary))
+            .unwrap_or(Vec::new(&env))
     }
 
     pub fn set_escrow_risk_flags(
         env: Env,
         bounty_id: u64,
         flags: u32,
-    ) -> Result<EscrowMetadata, Error> {
+    ) -> Result<EscrowMetadata, soroban_sdk::Error> {
         let stored_admin: Address = env
             .storage()
             .instance()
@@ -4967,11 +12096,66 @@ impl BountyEscrowContract {
         Ok(metadata)
     }
 
+    /// Set the notification preference bits (`NOTIFY_ON_*`) for a bounty.
+    /// Only the escrow depositor may change them.
+    pub fn set_notification_preferences(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        prefs: u32,
+    ) -> Result<EscrowMetadata, soroban_sdk::Error> {
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if escrow.depositor != depositor {
+            return Err(Error::Unauthorized.into());
+        }
+        depositor.require_auth();
+
+        let existing = env
+            .storage()
+            .persistent()
+            .get::<DataKey, EscrowMetadata>(&DataKey::Metadata(bounty_id));
+        let created = existing.is_none();
+        let mut metadata = existing.unwrap_or(EscrowMetadata {
+            repo_id: 0,
+            issue_id: 0,
+            bounty_type: soroban_sdk::String::from_str(&env, ""),
+            risk_flags: 0,
+            notification_prefs: 0,
+            reference_hash: None,
+        });
+
+        let previous_prefs = metadata.notification_prefs;
+        metadata.notification_prefs = prefs;
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Metadata(bounty_id), &metadata);
+
+        emit_notification_preferences_updated(
+            &env,
+            NotificationPreferencesUpdated {
+                version: EVENT_VERSION_V2,
+                bounty_id,
+                previous_prefs,
+                new_prefs: prefs,
+                actor: depositor,
+                created,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(metadata)
+    }
+
     pub fn clear_escrow_risk_flags(
         env: Env,
         bounty_id: u64,
         flags: u32,
-    ) -> Result<EscrowMetadata, Error> {
+    ) -> Result<EscrowMetadata, soroban_sdk::Error> {
         let stored_admin: Address = env
             .storage()
             .instance()
@@ -5023,12 +12207,16 @@ impl traits::EscrowInterface for BountyEscrowContract {
         bounty_id: u64,
         amount: i128,
         deadline: u64,
-    ) -> Result<(), crate::Error> {
+    ) -> Result<(), soroban_sdk::Error> {
         BountyEscrowContract::lock_funds(env.clone(), depositor, bounty_id, amount, deadline)
     }
 
     /// Release funds to contributor through the trait interface
-    fn release_funds(env: &Env, bounty_id: u64, contributor: Address) -> Result<(), crate::Error> {
+    fn release_funds(
+        env: &Env,
+        bounty_id: u64,
+        contributor: Address,
+    ) -> Result<(), soroban_sdk::Error> {
         BountyEscrowContract::release_funds(env.clone(), bounty_id, contributor)
     }
 
@@ -5038,32 +12226,35 @@ impl traits::EscrowInterface for BountyEscrowContract {
         bounty_id: u64,
         contributor: Address,
         payout_amount: i128,
-    ) -> Result<(), crate::Error> {
+    ) -> Result<(), soroban_sdk::Error> {
         BountyEscrowContract::partial_release(env.clone(), bounty_id, contributor, payout_amount)
     }
 
     /// Batch lock funds through the trait interface
-    fn batch_lock_funds(env: &Env, items: Vec<LockFundsItem>) -> Result<u32, crate::Error> {
+    fn batch_lock_funds(env: &Env, items: Vec<LockFundsItem>) -> Result<u32, soroban_sdk::Error> {
         BountyEscrowContract::batch_lock_funds(env.clone(), items)
     }
 
     /// Batch release funds through the trait interface
-    fn batch_release_funds(env: &Env, items: Vec<ReleaseFundsItem>) -> Result<u32, crate::Error> {
+    fn batch_release_funds(
+        env: &Env,
+        items: Vec<ReleaseFundsItem>,
+    ) -> Result<u32, soroban_sdk::Error> {
         BountyEscrowContract::batch_release_funds(env.clone(), items)
     }
 
     /// Refund funds to depositor through the trait interface
-    fn refund(env: &Env, bounty_id: u64) -> Result<(), crate::Error> {
+    fn refund(env: &Env, bounty_id: u64) -> Result<(), soroban_sdk::Error> {
         BountyEscrowContract::refund(env.clone(), bounty_id)
     }
 
     /// Get escrow information through the trait interface
-    fn get_escrow_info(env: &Env, bounty_id: u64) -> Result<crate::Escrow, crate::Error> {
+    fn get_escrow_info(env: &Env, bounty_id: u64) -> Result<crate::Escrow, soroban_sdk::Error> {
         BountyEscrowContract::get_escrow_info(env.clone(), bounty_id)
     }
 
     /// Get contract balance through the trait interface
-    fn get_balance(env: &Env) -> Result<i128, crate::Error> {
+    fn get_balance(env: &Env) -> Result<i128, soroban_sdk::Error> {
         BountyEscrowContract::get_balance(env.clone())
     }
 }
@@ -5075,7 +12266,7 @@ impl traits::UpgradeInterface for BountyEscrowContract {
     }
 
     /// Set contract version (admin only)
-    fn set_version(env: &Env, new_version: u32) -> Result<(), crate::Error> {
+    fn set_version(env: &Env, new_version: u32) -> Result<(), soroban_sdk::Error> {
         BountyEscrowContract::set_version(env.clone(), new_version)
     }
 }
@@ -5087,7 +12278,7 @@ impl traits::PauseInterface for BountyEscrowContract {
         release: Option<bool>,
         refund: Option<bool>,
         reason: Option<soroban_sdk::String>,
-    ) -> Result<(), crate::Error> {
+    ) -> Result<(), soroban_sdk::Error> {
         BountyEscrowContract::set_paused(env.clone(), lock, release, refund, reason)
     }
 
@@ -5107,7 +12298,7 @@ impl traits::FeeInterface for BountyEscrowContract {
         release_fee_rate: Option<i128>,
         fee_recipient: Option<Address>,
         fee_enabled: Option<bool>,
-    ) -> Result<(), crate::Error> {
+    ) -> Result<(), soroban_sdk::Error> {
         BountyEscrowContract::update_fee_config(
             env.clone(),
             lock_fee_rate,
@@ -5272,7 +12463,7 @@ mod escrow_status_transition_tests {
         label: &'static str,
         from: EscrowStatus,
         action: TransitionAction,
-        expected_result: Result<(), Error>,
+        expected_result: Result<(), soroban_sdk::Error>,
     }
 
     /// Table-driven test function executing all exhaustive transitions from the matrix
@@ -5283,7 +12474,7 @@ mod escrow_status_transition_tests {
                 label: "Locked to Locked (Lock)",
                 from: EscrowStatus::Locked,
                 action: TransitionAction::Lock,
-                expected_result: Err(Error::BountyExists),
+                expected_result: Err(Error::BountyExists.into()),
             },
             TransitionTestCase {
                 label: "Locked to Released (Release)",
@@ -5301,37 +12492,37 @@ mod escrow_status_transition_tests {
                 label: "Released to Locked (Lock)",
                 from: EscrowStatus::Released,
                 action: TransitionAction::Lock,
-                expected_result: Err(Error::BountyExists),
+                expected_result: Err(Error::BountyExists.into()),
             },
             TransitionTestCase {
                 label: "Released to Released (Release)",
                 from: EscrowStatus::Released,
                 action: TransitionAction::Release,
-                expected_result: Err(Error::FundsNotLocked),
+                expected_result: Err(Error::FundsNotLocked.into()),
             },
             TransitionTestCase {
                 label: "Released to Refunded (Refund)",
                 from: EscrowStatus::Released,
                 action: TransitionAction::Refund,
-                expected_result: Err(Error::FundsNotLocked),
+                expected_result: Err(Error::FundsNotLocked.into()),
             },
             TransitionTestCase {
                 label: "Refunded to Locked (Lock)",
                 from: EscrowStatus::Refunded,
                 action: TransitionAction::Lock,
-                expected_result: Err(Error::BountyExists),
+                expected_result: Err(Error::BountyExists.into()),
             },
             TransitionTestCase {
                 label: "Refunded to Released (Release)",
                 from: EscrowStatus::Refunded,
                 action: TransitionAction::Release,
-                expected_result: Err(Error::FundsNotLocked),
+                expected_result: Err(Error::FundsNotLocked.into()),
             },
             TransitionTestCase {
                 label: "Refunded to Refunded (Refund)",
                 from: EscrowStatus::Refunded,
                 action: TransitionAction::Refund,
-                expected_result: Err(Error::FundsNotLocked),
+                expected_result: Err(Error::FundsNotLocked.into()),
             },
         ];
 
@@ -5474,7 +12665,7 @@ mod escrow_status_transition_tests {
         );
         assert_eq!(
             result.unwrap_err().unwrap(),
-            Error::BountyExists,
+            Error::BountyExists.into(),
             "Expected BountyExists when attempting to Lock Released escrow."
         );
         let stored = setup.client.get_escrow_info(&bounty_id);
@@ -5501,7 +12692,7 @@ mod escrow_status_transition_tests {
         );
         assert_eq!(
             result.unwrap_err().unwrap(),
-            Error::FundsNotLocked,
+            Error::FundsNotLocked.into(),
             "Expected FundsNotLocked error variant"
         );
         let stored = setup.client.get_escrow_info(&bounty_id);
@@ -5526,7 +12717,7 @@ mod escrow_status_transition_tests {
         );
         assert_eq!(
             result.unwrap_err().unwrap(),
-            Error::BountyNotFound,
+            Error::BountyNotFound.into(),
             "Expected BountyNotFound error variant"
         );
     }
@@ -5548,7 +12739,7 @@ mod escrow_status_transition_tests {
         );
         assert_eq!(
             result.unwrap_err().unwrap(),
-            Error::FundsNotLocked,
+            Error::FundsNotLocked.into(),
             "Expected FundsNotLocked on idempotent attempt"
         );
     }
@@ -34,7 +34,7 @@
 //
 // ============================================================================
 
-use crate::{AnonymousEscrow, DataKey, Escrow, EscrowStatus};
+use crate::{AnonymousEscrow, DataKey, Escrow, EscrowStatus, TicketKey};
 use soroban_sdk::{token, Address, Env, Vec};
 
 /// Full result of a multi-token balance invariant check.
@@ -140,8 +140,13 @@ pub(crate) fn check_anon_refund_consistency(anon: &AnonymousEscrow) -> bool {
 // INV-2  Aggregate-to-Ledger
 // ---------------------------------------------------------------------------
 
-/// Sum the remaining_amount of all active (Locked or PartiallyRefunded) escrows,
-/// including both normal Escrow and AnonymousEscrow.
+/// Sum the remaining_amount of all active (Locked, PartiallyRefunded or
+/// PendingRelease) escrows,
+/// including both normal Escrow and AnonymousEscrow. Escrows locked in a
+/// non-default token (`DataKey::BountyToken`) are excluded, since they are not
+/// backed by the default token balance this sum is compared against.
+/// Outstanding claim bonds in the default token (`TicketKey::TotalBonds`) are
+/// held by the contract too and are added to the sum.
 pub(crate) fn sum_active_escrow_balances(env: &Env) -> i128 {
     let index: Vec<u64> = env
         .storage()
@@ -149,8 +154,19 @@ pub(crate) fn sum_active_escrow_balances(env: &Env) -> i128 {
         .get(&DataKey::EscrowIndex)
         .unwrap_or(Vec::new(env));
 
-    let mut total: i128 = 0;
+    let mut total: i128 = env
+        .storage()
+        .persistent()
+        .get(&TicketKey::TotalBonds)
+        .unwrap_or(0);
     for bounty_id in index.iter() {
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::BountyToken(bounty_id))
+        {
+            continue;
+        }
         if let Some(escrow) = env
             .storage()
             .persistent()
@@ -158,6 +174,7 @@ pub(crate) fn sum_active_escrow_balances(env: &Env) -> i128 {
         {
             if escrow.status == EscrowStatus::Locked
                 || escrow.status == EscrowStatus::PartiallyRefunded
+                || escrow.status == EscrowStatus::PendingRelease
             {
                 total += escrow.remaining_amount;
             }
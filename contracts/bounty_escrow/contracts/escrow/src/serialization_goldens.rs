@@ -20,19 +20,19 @@ pub const EXPECTED: &[(&str, &str)] = &[
   ("LockFundsItem", "0000001100000001000000040000000f00000006616d6f756e7400000000000a0000000000000000000000000000007b0000000f00000009626f756e74795f696400000000000005000000000000002a0000000f00000008646561646c696e650000000500000000000001c80000000f000000096465706f7369746f7200000000000012000000010303030303030303030303030303030303030303030303030303030303030303"),
   ("ReleaseFundsItem", "0000001100000001000000020000000f00000009626f756e74795f696400000000000005000000000000002a0000000f0000000b636f6e7472696275746f720000000012000000010404040404040404040404040404040404040404040404040404040404040404"),
   ("BountyEscrowInitialized", "0000001100000001000000040000000f0000000561646d696e000000000000120000000101010101010101010101010101010101010101010101010101010101010101010000000f0000000974696d657374616d700000000000000500000000000000010000000f00000005746f6b656e000000000000120000000102020202020202020202020202020202020202020202020202020202020202020000000f0000000776657273696f6e000000000300000002"),
-  ("FundsLocked", "0000001100000001000000050000000f00000006616d6f756e7400000000000a0000000000000000000000000012d6870000000f00000009626f756e74795f696400000000000005000000000000002a0000000f00000008646561646c696e6500000005000000006553f1000000000f000000096465706f7369746f72000000000000120000000103030303030303030303030303030303030303030303030303030303030303030000000f0000000776657273696f6e000000000300000002"),
-  ("FundsReleased", "0000001100000001000000050000000f00000006616d6f756e7400000000000a0000000000000000000000000000007b0000000f00000009626f756e74795f696400000000000005000000000000002a0000000f00000009726563697069656e74000000000000120000000104040404040404040404040404040404040404040404040404040404040404040000000f0000000974696d657374616d700000000000000500000000000001c80000000f0000000776657273696f6e000000000300000002"),
-  ("FundsRefunded", "0000001100000001000000050000000f00000006616d6f756e7400000000000a000000000000000000000000000000640000000f00000009626f756e74795f696400000000000005000000000000002a0000000f00000009726566756e645f746f000000000000120000000103030303030303030303030303030303030303030303030303030303030303030000000f0000000974696d657374616d700000000000000500000000000000c80000000f0000000776657273696f6e000000000300000002"),
+  ("FundsLocked", "0000001100000001000000070000000f00000006616d6f756e7400000000000a0000000000000000000000000012d6870000000f00000009626f756e74795f696400000000000005000000000000002a0000000f00000008646561646c696e6500000005000000006553f1000000000f000000096465706f7369746f72000000000000120000000103030303030303030303030303030303030303030303030303030303030303030000000f0000000f72656d61696e696e675f6166746572000000000a0000000000000000000000000012d6870000000f0000001072656d61696e696e675f6265666f72650000000a000000000000000000000000000000000000000f0000000776657273696f6e000000000300000003"),
+  ("FundsReleased", "0000001100000001000000070000000f00000006616d6f756e7400000000000a0000000000000000000000000000007b0000000f00000009626f756e74795f696400000000000005000000000000002a0000000f00000009726563697069656e74000000000000120000000104040404040404040404040404040404040404040404040404040404040404040000000f0000000f72656d61696e696e675f6166746572000000000a000000000000000000000000000000000000000f0000001072656d61696e696e675f6265666f72650000000a0000000000000000000000000000007b0000000f0000000974696d657374616d700000000000000500000000000001c80000000f0000000776657273696f6e000000000300000003"),
+  ("FundsRefunded", "0000001100000001000000070000000f00000006616d6f756e7400000000000a000000000000000000000000000000640000000f00000009626f756e74795f696400000000000005000000000000002a0000000f00000009726566756e645f746f000000000000120000000103030303030303030303030303030303030303030303030303030303030303030000000f0000000f72656d61696e696e675f6166746572000000000a000000000000000000000000000000320000000f0000001072656d61696e696e675f6265666f72650000000a000000000000000000000000000000960000000f0000000974696d657374616d700000000000000500000000000000c80000000f0000000776657273696f6e000000000300000003"),
   ("FeeOperationType::Lock", "0000001000000001000000010000000f000000044c6f636b"),
   ("FeeCollected", "0000001100000001000000050000000f00000006616d6f756e7400000000000a000000000000000000000000000001c80000000f000000086665655f726174650000000a0000000000000000000000000000007b0000000f0000000e6f7065726174696f6e5f7479706500000000001000000001000000010000000f0000000752656c65617365000000000f00000009726563697069656e74000000000000120000000105050505050505050505050505050505050505050505050505050505050505050000000f0000000974696d657374616d700000000000000500000000000003e7"),
   ("BatchFundsLocked", "0000001100000001000000030000000f00000005636f756e7400000000000003000000020000000f0000000974696d657374616d700000000000000500000000000000010000000f0000000c746f74616c5f616d6f756e740000000a000000000000000000000000000003e7"),
   ("FeeConfigUpdated", "0000001100000001000000050000000f0000000b6665655f656e61626c65640000000000000000010000000f0000000d6665655f726563697069656e74000000000000120000000105050505050505050505050505050505050505050505050505050505050505050000000f0000000d6c6f636b5f6665655f726174650000000000000a0000000000000000000000000000000a0000000f0000001072656c656173655f6665655f726174650000000a000000000000000000000000000000140000000f0000000974696d657374616d70000000000000050000000000000002"),
   ("BatchFundsReleased", "0000001100000001000000030000000f00000005636f756e7400000000000003000000010000000f0000000974696d657374616d700000000000000500000000000000030000000f0000000c746f74616c5f616d6f756e740000000a0000000000000000000000000000014d"),
-  ("ApprovalAdded", "0000001100000001000000040000000f00000008617070726f766572000000120000000101010101010101010101010101010101010101010101010101010101010101010000000f00000009626f756e74795f696400000000000005000000000000002a0000000f0000000b636f6e7472696275746f7200000000120000000104040404040404040404040404040404040404040404040404040404040404040000000f0000000974696d657374616d70000000000000050000000000000004"),
+  ("ApprovalAdded", "0000001100000001000000060000000f00000008617070726f766572000000120000000101010101010101010101010101010101010101010101010101010101010101010000000f00000009626f756e74795f696400000000000005000000000000002a0000000f0000000b636f6e7472696275746f7200000000120000000104040404040404040404040404040404040404040404040404040404040404040000000f0000000974696d657374616d700000000000000500000000000000040000000f0000000c746f74616c5f77656967687400000003000000030000000f0000000677656967687400000000000300000002"),
   ("ClaimCreated", "0000001100000001000000040000000f00000006616d6f756e7400000000000a000000000000000000000000000000640000000f00000009626f756e74795f696400000000000005000000000000002a0000000f0000000a657870697265735f617400000000000500000000000000c80000000f00000009726563697069656e7400000000000012000000010606060606060606060606060606060606060606060606060606060606060606"),
-  ("ClaimExecuted", "0000001100000001000000040000000f00000006616d6f756e7400000000000a000000000000000000000000000000640000000f00000009626f756e74795f696400000000000005000000000000002a0000000f0000000a636c61696d65645f6174000000000005000000000000012c0000000f00000009726563697069656e7400000000000012000000010606060606060606060606060606060606060606060606060606060606060606"),
+  ("ClaimExecuted", "0000001100000001000000070000000f00000006616d6f756e7400000000000a000000000000000000000000000000640000000f00000009626f756e74795f696400000000000005000000000000002a0000000f0000000a636c61696d65645f6174000000000005000000000000012c0000000f00000009726563697069656e74000000000000120000000106060606060606060606060606060606060606060606060606060606060606060000000f0000000f72656d61696e696e675f6166746572000000000a000000000000000000000000000000000000000f0000001072656d61696e696e675f6265666f72650000000a000000000000000000000000000000640000000f0000000776657273696f6e000000000300000003"),
   ("ClaimCancelled", "0000001100000001000000050000000f00000006616d6f756e7400000000000a000000000000000000000000000000640000000f00000009626f756e74795f696400000000000005000000000000002a0000000f0000000c63616e63656c6c65645f61740000000500000000000001900000000f0000000c63616e63656c6c65645f6279000000120000000101010101010101010101010101010101010101010101010101010101010101010000000f00000009726563697069656e7400000000000012000000010606060606060606060606060606060606060606060606060606060606060606"),
-  ("EmergencyWithdrawEvent", "0000001100000001000000040000000f0000000561646d696e000000000000120000000101010101010101010101010101010101010101010101010101010101010101010000000f00000006616d6f756e7400000000000a000000000000000000000000000003e80000000f00000009726563697069656e74000000000000120000000103030303030303030303030303030303030303030303030303030303030303030000000f0000000974696d657374616d700000000000000500000000000001f4"),
+  ("EmergencyWithdrawEvent", "0000001100000001000000070000000f0000000561646d696e000000000000120000000101010101010101010101010101010101010101010101010101010101010101010000000f00000006616d6f756e7400000000000a000000000000000000000000000003e80000000f00000009726563697069656e74000000000000120000000103030303030303030303030303030303030303030303030303030303030303030000000f0000000f72656d61696e696e675f6166746572000000000a000000000000000000000000000000000000000f0000001072656d61696e696e675f6265666f72650000000a000000000000000000000000000003e80000000f0000000974696d657374616d700000000000000500000000000001f40000000f0000000776657273696f6e000000000300000003"),
   ("CapabilityIssued", "0000001100000001000000090000000f00000006616374696f6e00000000001000000001000000010000000f00000006526566756e6400000000000f0000000c616d6f756e745f6c696d69740000000a0000000000000000000000000000007b0000000f00000009626f756e74795f696400000000000005000000000000002a0000000f0000000d6361706162696c6974795f69640000000000000500000000000000070000000f0000000a657870697265735f617400000000000500000000000001c80000000f00000006686f6c6465720000000000120000000107070707070707070707070707070707070707070707070707070707070707070000000f000000086d61785f7573657300000003000000020000000f000000056f776e6572000000000000120000000101010101010101010101010101010101010101010101010101010101010101010000000f0000000974696d657374616d70000000000000050000000000000315"),
   ("CapabilityUsed", "0000001100000001000000080000000f00000006616374696f6e00000000001000000001000000010000000f00000006526566756e6400000000000f0000000b616d6f756e745f75736564000000000a0000000000000000000000000000000b0000000f00000009626f756e74795f696400000000000005000000000000002a0000000f0000000d6361706162696c6974795f69640000000000000500000000000000070000000f00000006686f6c6465720000000000120000000107070707070707070707070707070707070707070707070707070707070707070000000f0000001072656d61696e696e675f616d6f756e740000000a000000000000000000000000000000160000000f0000000e72656d61696e696e675f75736573000000000003000000010000000f00000007757365645f6174000000000500000000000003e7"),
   ("CapabilityRevoked", "0000001100000001000000030000000f0000000d6361706162696c6974795f69640000000000000500000000000000070000000f000000056f776e6572000000000000120000000101010101010101010101010101010101010101010101010101010101010101010000000f0000000a7265766f6b65645f6174000000000005000000000000006f"),
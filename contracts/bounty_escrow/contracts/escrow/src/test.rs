@@ -339,6 +339,25 @@ fn test_get_balance() {
     assert_eq!(setup.escrow.get_balance(), amount);
 }
 
+#[test]
+fn test_reconcile_balances_tracks_active_escrows() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    assert_eq!(setup.escrow.reconcile_balances(), (0, 0));
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &500, &deadline);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &300, &deadline);
+    assert_eq!(setup.escrow.reconcile_balances(), (800, 800));
+
+    setup.escrow.release_funds(&1, &setup.contributor);
+    assert_eq!(setup.escrow.reconcile_balances(), (300, 300));
+}
+
 // =============================================================================
 // Partial Payout Rounding and Small Amount Tests (Issue #354)
 // =============================================================================
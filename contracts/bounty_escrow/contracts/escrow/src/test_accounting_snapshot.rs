@@ -0,0 +1,118 @@
+#![cfg(test)]
+
+use crate::{
+    AccountingDeltas, AccountingTotals, BountyEscrowContract, BountyEscrowContractClient,
+    LifecycleError,
+};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, BytesN, Env,
+};
+
+struct Setup<'a> {
+    client: BountyEscrowContractClient<'a>,
+    depositor: Address,
+    deadline: u64,
+}
+
+/// Contract charging a 1% lock fee, with bounties 1 and 2 locked for 1_000.
+fn setup<'a>(env: &Env) -> Setup<'a> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+    client.update_fee_config(
+        &Some(100),
+        &Some(0),
+        &Some(Address::generate(env)),
+        &Some(true),
+    );
+
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_address).mint(&depositor, &10_000);
+    let deadline = env.ledger().timestamp() + 100;
+    client.lock_funds(&depositor, &1, &1_000, &deadline);
+    client.lock_funds(&depositor, &2, &1_000, &deadline);
+
+    Setup {
+        client,
+        depositor,
+        deadline,
+    }
+}
+
+#[test]
+fn test_totals_from_deployment() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let snapshot = s.client.get_accounting_snapshot(&0);
+    let expected = AccountingTotals {
+        active_escrows: 2,
+        locked: 1_980,
+        released: 0,
+        refunded: 0,
+        fees_collected: 20,
+    };
+    assert_eq!(snapshot.totals, expected);
+    assert_eq!(snapshot.deltas.locked, 1_980);
+    assert_eq!(snapshot.deltas.active_escrows, 2);
+}
+
+#[test]
+fn test_deltas_since_checkpoint() {
+    let env = Env::default();
+    let s = setup(&env);
+    let checkpoint = s.client.checkpoint_accounting();
+    assert_eq!(checkpoint, 1);
+
+    s.client.release_funds(&1, &Address::generate(&env));
+    env.ledger().with_mut(|li| li.timestamp = s.deadline + 1);
+    s.client.refund(&2);
+    s.client
+        .lock_funds(&s.depositor, &3, &500, &(s.deadline + 100));
+
+    let snapshot = s.client.get_accounting_snapshot(&checkpoint);
+    assert_eq!(
+        snapshot.deltas,
+        AccountingDeltas {
+            active_escrows: -1,
+            locked: -1_485,
+            released: 990,
+            refunded: 990,
+            fees_collected: 5,
+        }
+    );
+    assert_eq!(snapshot.totals.locked, 495);
+    assert_eq!(snapshot.since_checkpoint, 1);
+    assert_eq!(s.client.checkpoint_accounting(), 2);
+}
+
+#[test]
+fn test_anonymous_escrows_are_counted() {
+    let env = Env::default();
+    let s = setup(&env);
+    let commitment = BytesN::from_array(&env, &[3u8; 32]);
+    s.client
+        .lock_funds_anonymous(&s.depositor, &commitment, &3, &1_000, &s.deadline);
+
+    let totals = s.client.get_accounting_snapshot(&0).totals;
+    assert_eq!(totals.active_escrows, 3);
+    assert_eq!(totals.locked, 2_980);
+}
+
+#[test]
+fn test_unknown_checkpoint() {
+    let env = Env::default();
+    let s = setup(&env);
+    assert_eq!(
+        s.client.try_get_accounting_snapshot(&7),
+        Err(Ok(LifecycleError::AccountingCheckpointNotFound.into()))
+    );
+}
@@ -0,0 +1,83 @@
+#![cfg(test)]
+
+use crate::{BountyEscrowContract, BountyEscrowContractClient, EscrowStatus};
+use soroban_sdk::{testutils::Address as _, token, Address, Env};
+
+struct Setup<'a> {
+    client: BountyEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    depositor: Address,
+}
+
+/// Depositor holds 1_000 and has approved the escrow to pull 600.
+fn setup<'a>(env: &Env) -> Setup<'a> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_address).mint(&depositor, &1_000);
+    let token = token::Client::new(env, &token_address);
+    let expiration = env.ledger().sequence() + 1_000;
+    token.approve(&depositor, &contract_id, &600, &expiration);
+
+    Setup {
+        client,
+        token,
+        depositor,
+    }
+}
+
+#[test]
+fn test_lock_from_allowance_pulls_funds() {
+    let env = Env::default();
+    let s = setup(&env);
+    let deadline = env.ledger().timestamp() + 100;
+
+    s.client
+        .lock_funds_from_allowance(&s.depositor, &1, &200, &deadline);
+    s.client
+        .lock_funds_from_allowance(&s.depositor, &2, &300, &deadline);
+
+    assert_eq!(s.token.balance(&s.depositor), 500);
+    assert_eq!(s.token.allowance(&s.depositor, &s.client.address), 100);
+    let escrow = s.client.get_escrow_info(&2);
+    assert_eq!(escrow.depositor, s.depositor);
+    assert_eq!(escrow.remaining_amount, 300);
+    assert_eq!(escrow.status, EscrowStatus::Locked);
+}
+
+#[test]
+fn test_lock_from_allowance_does_not_require_depositor_auth() {
+    let env = Env::default();
+    let s = setup(&env);
+    let deadline = env.ledger().timestamp() + 100;
+
+    s.client
+        .lock_funds_from_allowance(&s.depositor, &1, &200, &deadline);
+    assert!(env
+        .auths()
+        .iter()
+        .all(|(address, _)| *address != s.depositor));
+}
+
+#[test]
+fn test_lock_from_allowance_fails_beyond_allowance() {
+    let env = Env::default();
+    let s = setup(&env);
+    let deadline = env.ledger().timestamp() + 100;
+
+    assert!(s
+        .client
+        .try_lock_funds_from_allowance(&s.depositor, &1, &700, &deadline)
+        .is_err());
+    assert_eq!(s.token.balance(&s.depositor), 1_000);
+    assert!(s.client.try_get_escrow_info(&1).is_err());
+}
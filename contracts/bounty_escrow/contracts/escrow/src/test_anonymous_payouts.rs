@@ -0,0 +1,93 @@
+#![cfg(test)]
+
+use crate::{
+    AnonymousParty, BountyEscrowContract, BountyEscrowContractClient, ClaimError, Error,
+    EscrowStatus,
+};
+use soroban_sdk::{testutils::Address as _, token, Address, Bytes, BytesN, Env};
+
+fn setup<'a>(env: &Env) -> (BountyEscrowContractClient<'a>, token::Client<'a>) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_address).mint(&depositor, &10_000);
+    let deadline = env.ledger().timestamp() + 10_000;
+    client.lock_funds(&depositor, &1, &1_000, &deadline);
+    (client, token::Client::new(env, &token_address))
+}
+
+fn secret(env: &Env) -> (Bytes, BytesN<32>) {
+    let preimage = Bytes::from_slice(env, b"grainlify-recipient-secret");
+    let commitment: BytesN<32> = env.crypto().sha256(&preimage).into();
+    (preimage, commitment)
+}
+
+#[test]
+fn test_anonymous_payout_reserved_then_claimed() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let (preimage, commitment) = secret(&env);
+    let payout_address = Address::generate(&env);
+
+    client.release_funds_anonymous(&1, &commitment);
+    assert_eq!(
+        client.get_escrow_info(&1).status,
+        EscrowStatus::PendingRelease
+    );
+    let payout = client.get_anonymous_payout(&1).unwrap();
+    assert_eq!(payout.recipient, AnonymousParty::Commitment(commitment));
+    assert_eq!(payout.amount, 1_000);
+    assert_eq!(token.balance(&client.address), 1_000);
+
+    client.claim_anonymous(&1, &preimage, &payout_address);
+    assert_eq!(token.balance(&payout_address), 1_000);
+    assert_eq!(client.get_escrow_info(&1).status, EscrowStatus::Released);
+    assert_eq!(client.get_anonymous_payout(&1), None);
+}
+
+#[test]
+fn test_claim_anonymous_rejects_wrong_preimage() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let (_preimage, commitment) = secret(&env);
+    let payout_address = Address::generate(&env);
+    client.release_funds_anonymous(&1, &commitment);
+
+    assert_eq!(
+        client.try_claim_anonymous(&1, &Bytes::from_slice(&env, b"guess"), &payout_address),
+        Err(Ok(ClaimError::InvalidPreimage.into()))
+    );
+    assert_eq!(token.balance(&payout_address), 0);
+}
+
+#[test]
+fn test_reserved_payout_blocks_other_releases() {
+    let env = Env::default();
+    let (client, _token) = setup(&env);
+    let (preimage, commitment) = secret(&env);
+    client.release_funds_anonymous(&1, &commitment);
+
+    assert_eq!(
+        client.try_release_funds(&1, &Address::generate(&env)),
+        Err(Ok(Error::FundsNotLocked.into()))
+    );
+    assert_eq!(
+        client.try_release_funds_anonymous(&1, &commitment),
+        Err(Ok(Error::FundsNotLocked.into()))
+    );
+
+    client.claim_anonymous(&1, &preimage, &Address::generate(&env));
+    assert_eq!(
+        client.try_claim_anonymous(&1, &preimage, &Address::generate(&env)),
+        Err(Ok(ClaimError::AnonymousPayoutNotFound.into()))
+    );
+}
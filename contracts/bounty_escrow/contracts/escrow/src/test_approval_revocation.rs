@@ -0,0 +1,220 @@
+#![cfg(test)]
+
+use crate::{BountyEscrowContract, BountyEscrowContractClient, EscrowStatus, ReleaseError};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env,
+};
+
+struct Setup<'a> {
+    client: BountyEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    signers: [Address; 3],
+}
+
+fn setup<'a>(env: &Env) -> Setup<'a> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_address).mint(&depositor, &10_000);
+    let deadline = env.ledger().timestamp() + 1_000_000;
+    client.lock_funds(&depositor, &1, &1_000, &deadline);
+
+    let signers = [
+        Address::generate(env),
+        Address::generate(env),
+        Address::generate(env),
+    ];
+    client.update_multisig_config(
+        &500,
+        &vec![
+            env,
+            signers[0].clone(),
+            signers[1].clone(),
+            signers[2].clone(),
+        ],
+        &2,
+    );
+
+    Setup {
+        client,
+        token: token::Client::new(env, &token_address),
+        signers,
+    }
+}
+
+fn advance(env: &Env, seconds: u64) {
+    env.ledger().with_mut(|li| li.timestamp += seconds);
+}
+
+#[test]
+fn test_revoke_lets_signers_switch_contributor() {
+    let env = Env::default();
+    let s = setup(&env);
+    let wrong = Address::generate(&env);
+    let right = Address::generate(&env);
+
+    s.client.approve_large_release(&1, &wrong, &s.signers[0]);
+    s.client.revoke_approval(&1, &wrong, &s.signers[0]);
+    assert_eq!(s.client.get_release_approval_weight(&1), 0);
+
+    s.client.approve_large_release(&1, &right, &s.signers[0]);
+    s.client.approve_large_release(&1, &right, &s.signers[1]);
+    assert_eq!(s.client.get_escrow_info(&1).status, EscrowStatus::Released);
+    assert_eq!(s.token.balance(&right), 1_000);
+    assert_eq!(s.token.balance(&wrong), 0);
+}
+
+#[test]
+fn test_revoked_approval_does_not_count_towards_quorum() {
+    let env = Env::default();
+    let s = setup(&env);
+    let contributor = Address::generate(&env);
+    s.client.set_approval_weight_threshold(&3);
+
+    s.client
+        .approve_large_release(&1, &contributor, &s.signers[0]);
+    s.client
+        .approve_large_release(&1, &contributor, &s.signers[1]);
+    s.client.revoke_approval(&1, &contributor, &s.signers[1]);
+    assert_eq!(s.client.get_release_approval_weight(&1), 1);
+
+    // The third approval would have completed quorum had the revoke not landed first.
+    s.client
+        .approve_large_release(&1, &contributor, &s.signers[2]);
+    assert_eq!(s.client.get_escrow_info(&1).status, EscrowStatus::Locked);
+    assert_eq!(s.client.get_release_approval_weight(&1), 2);
+}
+
+#[test]
+fn test_revoke_after_quorum_fails() {
+    let env = Env::default();
+    let s = setup(&env);
+    let contributor = Address::generate(&env);
+
+    s.client
+        .approve_large_release(&1, &contributor, &s.signers[0]);
+    s.client
+        .approve_large_release(&1, &contributor, &s.signers[1]);
+    assert_eq!(s.client.get_escrow_info(&1).status, EscrowStatus::Released);
+
+    assert_eq!(
+        s.client
+            .try_revoke_approval(&1, &contributor, &s.signers[0]),
+        Err(Ok(ReleaseError::ApprovalNotFound.into()))
+    );
+}
+
+#[test]
+fn test_revoke_rejects_unknown_approval() {
+    let env = Env::default();
+    let s = setup(&env);
+    let contributor = Address::generate(&env);
+
+    assert_eq!(
+        s.client
+            .try_revoke_approval(&1, &contributor, &s.signers[0]),
+        Err(Ok(ReleaseError::ApprovalNotFound.into()))
+    );
+
+    s.client
+        .approve_large_release(&1, &contributor, &s.signers[0]);
+    assert_eq!(
+        s.client
+            .try_revoke_approval(&1, &contributor, &s.signers[1]),
+        Err(Ok(ReleaseError::ApprovalNotFound.into()))
+    );
+    assert_eq!(
+        s.client
+            .try_revoke_approval(&1, &Address::generate(&env), &s.signers[0]),
+        Err(Ok(ReleaseError::ApprovalContributorMismatch.into()))
+    );
+}
+
+#[test]
+fn test_stale_approval_expires_before_quorum() {
+    let env = Env::default();
+    let s = setup(&env);
+    let contributor = Address::generate(&env);
+    s.client.set_approval_ttl(&100);
+    assert_eq!(s.client.get_approval_ttl(), 100);
+
+    s.client
+        .approve_large_release(&1, &contributor, &s.signers[0]);
+    advance(&env, 101);
+    assert_eq!(s.client.get_release_approval_weight(&1), 0);
+
+    s.client
+        .approve_large_release(&1, &contributor, &s.signers[1]);
+    assert_eq!(s.client.get_escrow_info(&1).status, EscrowStatus::Locked);
+    assert_eq!(s.client.get_release_approval_weight(&1), 1);
+
+    s.client
+        .approve_large_release(&1, &contributor, &s.signers[2]);
+    assert_eq!(s.client.get_escrow_info(&1).status, EscrowStatus::Released);
+}
+
+#[test]
+fn test_approval_within_ttl_still_counts() {
+    let env = Env::default();
+    let s = setup(&env);
+    let contributor = Address::generate(&env);
+    s.client.set_approval_ttl(&100);
+
+    s.client
+        .approve_large_release(&1, &contributor, &s.signers[0]);
+    advance(&env, 100);
+    s.client
+        .approve_large_release(&1, &contributor, &s.signers[1]);
+    assert_eq!(s.client.get_escrow_info(&1).status, EscrowStatus::Released);
+}
+
+#[test]
+fn test_reapproval_refreshes_expiry() {
+    let env = Env::default();
+    let s = setup(&env);
+    let contributor = Address::generate(&env);
+    s.client.set_approval_ttl(&100);
+
+    s.client
+        .approve_large_release(&1, &contributor, &s.signers[0]);
+    advance(&env, 80);
+    s.client
+        .approve_large_release(&1, &contributor, &s.signers[0]);
+    advance(&env, 80);
+    assert_eq!(s.client.get_release_approval_weight(&1), 1);
+
+    s.client
+        .approve_large_release(&1, &contributor, &s.signers[1]);
+    assert_eq!(s.client.get_escrow_info(&1).status, EscrowStatus::Released);
+}
+
+#[test]
+fn test_expired_approvals_free_the_contributor_slot() {
+    let env = Env::default();
+    let s = setup(&env);
+    let wrong = Address::generate(&env);
+    let right = Address::generate(&env);
+    s.client.set_approval_ttl(&100);
+
+    s.client.approve_large_release(&1, &wrong, &s.signers[0]);
+    assert_eq!(
+        s.client
+            .try_approve_large_release(&1, &right, &s.signers[1]),
+        Err(Ok(ReleaseError::ApprovalContributorMismatch.into()))
+    );
+
+    advance(&env, 101);
+    s.client.approve_large_release(&1, &right, &s.signers[1]);
+    s.client.approve_large_release(&1, &right, &s.signers[2]);
+    assert_eq!(s.token.balance(&right), 1_000);
+}
@@ -0,0 +1,97 @@
+#![cfg(test)]
+
+use crate::{
+    AnonymousParty, BountyEscrowContract, BountyEscrowContractClient, Error,
+    OptionalAnonymousPayout, OptionalResolverApproval,
+};
+use soroban_sdk::{testutils::Address as _, token, Address, BytesN, Env};
+
+struct Setup<'a> {
+    client: BountyEscrowContractClient<'a>,
+    auditor: Address,
+    commitment: BytesN<32>,
+}
+
+/// Regular escrow on bounty 1, anonymous escrow on bounty 2 and one auditor.
+fn setup<'a>(env: &Env) -> Setup<'a> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_address).mint(&depositor, &2_000);
+    let deadline = env.ledger().timestamp() + 1_000;
+    client.lock_funds(&depositor, &1, &1_000, &deadline);
+    let commitment = BytesN::from_array(env, &[7u8; 32]);
+    client.lock_funds_anonymous(&depositor, &commitment, &2, &500, &deadline);
+
+    let auditor = Address::generate(env);
+    client.set_auditor(&auditor, &true);
+
+    Setup {
+        client,
+        auditor,
+        commitment,
+    }
+}
+
+#[test]
+fn test_auditor_reads_restricted_escrow_info() {
+    let env = Env::default();
+    let s = setup(&env);
+    assert!(s.client.is_auditor(&s.auditor));
+
+    let info = s.client.get_escrow_info_v2(&1, &s.auditor);
+    assert_eq!(info.amount, 1_000);
+    let info = s.client.get_escrow_info_v2(&2, &s.auditor);
+    assert_eq!(info.depositor, AnonymousParty::Commitment(s.commitment));
+}
+
+#[test]
+fn test_auditor_reads_anonymous_escrow_record() {
+    let env = Env::default();
+    let s = setup(&env);
+    let viewer = Address::generate(&env);
+    s.client.authorize_viewer(&2, &viewer);
+
+    let audit = s.client.get_anonymous_escrow_audit(&2, &s.auditor);
+    assert_eq!(audit.escrow.depositor_commitment, s.commitment);
+    assert_eq!(audit.escrow.remaining_amount, 500);
+    assert_eq!(audit.payout, OptionalAnonymousPayout::None);
+    assert_eq!(audit.resolver_approval, OptionalResolverApproval::None);
+    assert_eq!(audit.authorized_viewers.len(), 1);
+
+    assert_eq!(
+        s.client.try_get_anonymous_escrow_audit(&1, &s.auditor),
+        Err(Ok(Error::NotAnonymousEscrow.into()))
+    );
+}
+
+#[test]
+fn test_non_auditor_and_revoked_auditor_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+    let outsider = Address::generate(&env);
+    assert_eq!(
+        s.client.try_get_anonymous_escrow_audit(&2, &outsider),
+        Err(Ok(Error::Unauthorized.into()))
+    );
+
+    s.client.set_auditor(&s.auditor, &false);
+    assert!(!s.client.is_auditor(&s.auditor));
+    assert_eq!(
+        s.client.try_get_escrow_info_v2(&1, &s.auditor),
+        Err(Ok(Error::Unauthorized.into()))
+    );
+    assert_eq!(
+        s.client.try_get_anonymous_escrow_audit(&2, &s.auditor),
+        Err(Ok(Error::Unauthorized.into()))
+    );
+}
@@ -0,0 +1,106 @@
+#![cfg(test)]
+
+use crate::{
+    BountyEscrowContract, BountyEscrowContractClient, Error, EscrowStatus, LifecycleError,
+};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env,
+};
+
+struct Setup<'a> {
+    client: BountyEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    depositor: Address,
+    deadline: u64,
+}
+
+/// Lock 10_000 on bounty 1 with a 1% (100 bps) keeper reward.
+fn setup<'a>(env: &Env) -> Setup<'a> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_address).mint(&depositor, &10_000);
+    let deadline = env.ledger().timestamp() + 100;
+    client.lock_funds(&depositor, &1, &10_000, &deadline);
+    client.enable_auto_refund(&1, &100);
+
+    Setup {
+        client,
+        token: token::Client::new(env, &token_address),
+        depositor,
+        deadline,
+    }
+}
+
+#[test]
+fn test_keeper_refunds_after_deadline_and_earns_reward() {
+    let env = Env::default();
+    let s = setup(&env);
+    let keeper = Address::generate(&env);
+    env.ledger().with_mut(|li| li.timestamp = s.deadline);
+
+    assert_eq!(s.client.execute_auto_refund(&1, &keeper), 100);
+    assert_eq!(s.token.balance(&keeper), 100);
+    assert_eq!(s.token.balance(&s.depositor), 9_900);
+    assert_eq!(s.token.balance(&s.client.address), 0);
+
+    let escrow = s.client.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Refunded);
+    assert_eq!(escrow.remaining_amount, 0);
+    assert_eq!(s.client.get_auto_refund(&1), None);
+}
+
+#[test]
+fn test_auto_refund_waits_for_deadline() {
+    let env = Env::default();
+    let s = setup(&env);
+    let keeper = Address::generate(&env);
+
+    assert_eq!(
+        s.client.try_execute_auto_refund(&1, &keeper),
+        Err(Ok(Error::DeadlineNotPassed.into()))
+    );
+}
+
+#[test]
+fn test_auto_refund_requires_opt_in() {
+    let env = Env::default();
+    let s = setup(&env);
+    let keeper = Address::generate(&env);
+    s.client.disable_auto_refund(&1);
+    env.ledger().with_mut(|li| li.timestamp = s.deadline);
+
+    assert_eq!(
+        s.client.try_execute_auto_refund(&1, &keeper),
+        Err(Ok(LifecycleError::AutoRefundNotEnabled.into()))
+    );
+}
+
+#[test]
+fn test_reward_capped_and_settled_bounties_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+    let keeper = Address::generate(&env);
+
+    assert_eq!(
+        s.client.try_enable_auto_refund(&1, &501),
+        Err(Ok(Error::InvalidFeeRate.into()))
+    );
+
+    s.client.release_funds(&1, &Address::generate(&env));
+    env.ledger().with_mut(|li| li.timestamp = s.deadline);
+    assert_eq!(
+        s.client.try_execute_auto_refund(&1, &keeper),
+        Err(Ok(Error::FundsNotLocked.into()))
+    );
+}
@@ -208,7 +208,7 @@ fn batch_lock_empty_batch_is_rejected() {
     let ctx = TestCtx::new();
     let empty: soroban_sdk::Vec<LockFundsItem> = Vec::new(&ctx.env);
     let result = ctx.client.try_batch_lock_funds(&empty);
-    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidBatchSize);
+    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidBatchSize.into());
 }
 
 #[test]
@@ -238,7 +238,7 @@ fn batch_lock_exceeds_max_batch_size_is_rejected() {
         .mint(&ctx.depositor, &(AMOUNT * (MAX_BATCH as i128 + 1)));
     let items = ctx.build_lock_batch(MAX_BATCH + 1);
     let result = ctx.client.try_batch_lock_funds(&items);
-    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidBatchSize);
+    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidBatchSize.into());
 }
 
 // ===========================================================================
@@ -253,7 +253,7 @@ fn batch_lock_duplicate_bounty_id_in_batch_is_rejected() {
     items.push_back(ctx.lock_item(2));
     items.push_back(ctx.lock_item(1)); // duplicate
     let result = ctx.client.try_batch_lock_funds(&items);
-    assert_eq!(result.unwrap_err().unwrap(), Error::DuplicateBountyId);
+    assert_eq!(result.unwrap_err().unwrap(), Error::DuplicateBountyId.into());
 }
 
 #[test]
@@ -280,7 +280,7 @@ fn batch_lock_existing_bounty_id_is_rejected() {
     let mut items = Vec::new(&ctx.env);
     items.push_back(ctx.lock_item(1)); // already locked
     let result = ctx.client.try_batch_lock_funds(&items);
-    assert_eq!(result.unwrap_err().unwrap(), Error::BountyExists);
+    assert_eq!(result.unwrap_err().unwrap(), Error::BountyExists.into());
 }
 
 // ===========================================================================
@@ -299,7 +299,7 @@ fn batch_lock_zero_amount_is_rejected() {
     let mut items = Vec::new(&ctx.env);
     items.push_back(bad_item);
     let result = ctx.client.try_batch_lock_funds(&items);
-    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidAmount);
+    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidAmount.into());
 }
 
 #[test]
@@ -314,7 +314,7 @@ fn batch_lock_negative_amount_is_rejected() {
     let mut items = Vec::new(&ctx.env);
     items.push_back(bad_item);
     let result = ctx.client.try_batch_lock_funds(&items);
-    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidAmount);
+    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidAmount.into());
 }
 
 // ===========================================================================
@@ -329,7 +329,7 @@ fn batch_lock_while_paused_is_rejected() {
 
     let items = ctx.build_lock_batch(2);
     let result = ctx.client.try_batch_lock_funds(&items);
-    assert_eq!(result.unwrap_err().unwrap(), Error::FundsPaused);
+    assert_eq!(result.unwrap_err().unwrap(), Error::FundsPaused.into());
 }
 
 // ===========================================================================
@@ -353,7 +353,7 @@ fn batch_lock_middle_invalid_item_causes_full_rollback() {
     items.push_back(ctx.lock_item(4));
 
     let result = ctx.client.try_batch_lock_funds(&items);
-    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidAmount);
+    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidAmount.into());
 
     // None of the IDs should be stored
     for id in [1u64, 2, 3, 4] {
@@ -377,7 +377,7 @@ fn batch_lock_first_item_invalid_causes_full_rollback() {
     items.push_back(ctx.lock_item(3));
 
     let result = ctx.client.try_batch_lock_funds(&items);
-    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidAmount);
+    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidAmount.into());
 
     for id in [1u64, 2, 3] {
         ctx.assert_no_escrow(id);
@@ -394,7 +394,7 @@ fn batch_lock_last_item_duplicate_causes_full_rollback() {
     items.push_back(ctx.lock_item(1)); // duplicate of first, placed last
 
     let result = ctx.client.try_batch_lock_funds(&items);
-    assert_eq!(result.unwrap_err().unwrap(), Error::DuplicateBountyId);
+    assert_eq!(result.unwrap_err().unwrap(), Error::DuplicateBountyId.into());
 
     for id in [1u64, 2] {
         ctx.assert_no_escrow(id);
@@ -414,7 +414,7 @@ fn batch_lock_one_existing_in_otherwise_valid_batch_causes_full_rollback() {
     items.push_back(ctx.lock_item(20));
 
     let result = ctx.client.try_batch_lock_funds(&items);
-    assert_eq!(result.unwrap_err().unwrap(), Error::BountyExists);
+    assert_eq!(result.unwrap_err().unwrap(), Error::BountyExists.into());
 
     // New IDs should not have been stored
     ctx.assert_no_escrow(10);
@@ -432,7 +432,7 @@ fn batch_release_empty_batch_is_rejected() {
     let ctx = TestCtx::new();
     let empty: soroban_sdk::Vec<ReleaseFundsItem> = Vec::new(&ctx.env);
     let result = ctx.client.try_batch_release_funds(&empty);
-    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidBatchSize);
+    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidBatchSize.into());
 }
 
 #[test]
@@ -464,7 +464,7 @@ fn batch_release_exceeds_max_batch_size_is_rejected() {
     ctx.lock_n(MAX_BATCH as u64 + 1);
     let items = ctx.build_release_batch(MAX_BATCH + 1);
     let result = ctx.client.try_batch_release_funds(&items);
-    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidBatchSize);
+    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidBatchSize.into());
 }
 
 // ===========================================================================
@@ -483,7 +483,7 @@ fn batch_release_duplicate_bounty_id_in_batch_is_rejected() {
     items.push_back(ctx.release_item(1)); // duplicate
 
     let result = ctx.client.try_batch_release_funds(&items);
-    assert_eq!(result.unwrap_err().unwrap(), Error::DuplicateBountyId);
+    assert_eq!(result.unwrap_err().unwrap(), Error::DuplicateBountyId.into());
 }
 
 #[test]
@@ -511,7 +511,7 @@ fn batch_release_nonexistent_bounty_is_rejected() {
     let mut items = Vec::new(&ctx.env);
     items.push_back(ctx.release_item(999)); // never locked
     let result = ctx.client.try_batch_release_funds(&items);
-    assert_eq!(result.unwrap_err().unwrap(), Error::BountyNotFound);
+    assert_eq!(result.unwrap_err().unwrap(), Error::BountyNotFound.into());
 }
 
 // ===========================================================================
@@ -528,7 +528,7 @@ fn batch_release_already_released_bounty_is_rejected() {
     let mut items = Vec::new(&ctx.env);
     items.push_back(ctx.release_item(1));
     let result = ctx.client.try_batch_release_funds(&items);
-    assert_eq!(result.unwrap_err().unwrap(), Error::FundsNotLocked);
+    assert_eq!(result.unwrap_err().unwrap(), Error::FundsNotLocked.into());
 }
 
 #[test]
@@ -544,7 +544,7 @@ fn batch_release_refunded_bounty_is_rejected() {
     let mut items = Vec::new(&ctx.env);
     items.push_back(ctx.release_item(1));
     let result = ctx.client.try_batch_release_funds(&items);
-    assert_eq!(result.unwrap_err().unwrap(), Error::FundsNotLocked);
+    assert_eq!(result.unwrap_err().unwrap(), Error::FundsNotLocked.into());
 }
 
 // ===========================================================================
@@ -561,7 +561,7 @@ fn batch_release_while_paused_is_rejected() {
     let mut items = Vec::new(&ctx.env);
     items.push_back(ctx.release_item(1));
     let result = ctx.client.try_batch_release_funds(&items);
-    assert_eq!(result.unwrap_err().unwrap(), Error::FundsPaused);
+    assert_eq!(result.unwrap_err().unwrap(), Error::FundsPaused.into());
 }
 
 // ===========================================================================
@@ -583,7 +583,7 @@ fn batch_release_middle_nonexistent_causes_full_rollback() {
     items.push_back(ctx.release_item(2));
 
     let result = ctx.client.try_batch_release_funds(&items);
-    assert_eq!(result.unwrap_err().unwrap(), Error::BountyNotFound);
+    assert_eq!(result.unwrap_err().unwrap(), Error::BountyNotFound.into());
 
     // All locked bounties must remain Locked
     ctx.assert_escrow_status(1, EscrowStatus::Locked);
@@ -606,7 +606,7 @@ fn batch_release_one_already_released_causes_full_rollback() {
     items.push_back(ctx.release_item(3));
 
     let result = ctx.client.try_batch_release_funds(&items);
-    assert_eq!(result.unwrap_err().unwrap(), Error::FundsNotLocked);
+    assert_eq!(result.unwrap_err().unwrap(), Error::FundsNotLocked.into());
 
     // Bounties 1 and 3 must still be Locked
     ctx.assert_escrow_status(1, EscrowStatus::Locked);
@@ -626,7 +626,7 @@ fn batch_release_first_item_nonexistent_causes_full_rollback() {
     items.push_back(ctx.release_item(3));
 
     let result = ctx.client.try_batch_release_funds(&items);
-    assert_eq!(result.unwrap_err().unwrap(), Error::BountyNotFound);
+    assert_eq!(result.unwrap_err().unwrap(), Error::BountyNotFound.into());
 
     ctx.assert_escrow_status(2, EscrowStatus::Locked);
     ctx.assert_escrow_status(3, EscrowStatus::Locked);
@@ -645,7 +645,7 @@ fn batch_release_last_item_nonexistent_causes_full_rollback() {
     items.push_back(ctx.release_item(999)); // not found
 
     let result = ctx.client.try_batch_release_funds(&items);
-    assert_eq!(result.unwrap_err().unwrap(), Error::BountyNotFound);
+    assert_eq!(result.unwrap_err().unwrap(), Error::BountyNotFound.into());
 
     ctx.assert_escrow_status(1, EscrowStatus::Locked);
     ctx.assert_escrow_status(2, EscrowStatus::Locked);
@@ -731,7 +731,7 @@ fn batch_lock_deprecated_contract_is_rejected() {
 
     let items = ctx.build_lock_batch(2);
     let result = ctx.client.try_batch_lock_funds(&items);
-    assert_eq!(result.unwrap_err().unwrap(), Error::ContractDeprecated);
+    assert_eq!(result.unwrap_err().unwrap(), Error::ContractDeprecated.into());
 
     ctx.assert_no_escrow(1);
     ctx.assert_no_escrow(2);
@@ -980,7 +980,7 @@ fn batch_lock_not_initialized_is_rejected() {
     });
 
     let result = client.try_batch_lock_funds(&items);
-    assert_eq!(result.unwrap_err().unwrap(), Error::NotInitialized);
+    assert_eq!(result.unwrap_err().unwrap(), Error::NotInitialized.into());
 }
 
 // ===========================================================================
@@ -1004,7 +1004,7 @@ fn batch_release_not_initialized_is_rejected() {
     });
 
     let result = client.try_batch_release_funds(&items);
-    assert_eq!(result.unwrap_err().unwrap(), Error::NotInitialized);
+    assert_eq!(result.unwrap_err().unwrap(), Error::NotInitialized.into());
 }
 
 // ===========================================================================
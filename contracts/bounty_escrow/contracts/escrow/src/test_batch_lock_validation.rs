@@ -0,0 +1,111 @@
+#![cfg(test)]
+
+use crate::{BountyEscrowContract, BountyEscrowContractClient, Error, LockFundsItem};
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    token, vec, Address, BytesN, Env, Symbol, TryFromVal,
+};
+
+struct Setup<'a> {
+    client: BountyEscrowContractClient<'a>,
+    admin: Address,
+    depositor: Address,
+    deadline: u64,
+}
+
+fn setup<'a>(env: &Env) -> Setup<'a> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_address).mint(&depositor, &10_000);
+
+    Setup {
+        client,
+        admin,
+        depositor,
+        deadline: env.ledger().timestamp() + 1_000,
+    }
+}
+
+fn item(s: &Setup, bounty_id: u64, amount: i128) -> LockFundsItem {
+    LockFundsItem {
+        bounty_id,
+        depositor: s.depositor.clone(),
+        amount,
+        deadline: s.deadline,
+    }
+}
+
+#[test]
+fn test_batch_lock_emits_funds_locked_per_item() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let locked = s.client.batch_lock_funds(&vec![
+        &env,
+        item(&s, 1, 100),
+        item(&s, 2, 200),
+        item(&s, 3, 300),
+    ]);
+    assert_eq!(locked, 3);
+
+    let f_lock = Symbol::new(&env, "f_lock");
+    let emitted = env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| {
+            topics
+                .get(0)
+                .and_then(|t| Symbol::try_from_val(&env, &t).ok())
+                == Some(f_lock.clone())
+        })
+        .count();
+    assert_eq!(emitted, 3);
+}
+
+#[test]
+fn test_batch_lock_enforces_amount_policy() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.set_amount_policy(&s.admin, &100, &1_000);
+
+    assert_eq!(
+        s.client
+            .try_batch_lock_funds(&vec![&env, item(&s, 1, 500), item(&s, 2, 50)]),
+        Err(Ok(Error::AmountBelowMinimum.into()))
+    );
+    assert_eq!(
+        s.client
+            .try_batch_lock_funds(&vec![&env, item(&s, 1, 500), item(&s, 2, 5_000)]),
+        Err(Ok(Error::AmountAboveMaximum.into()))
+    );
+    assert_eq!(
+        s.client.try_get_escrow_info(&1),
+        Err(Ok(Error::BountyNotFound.into()))
+    );
+}
+
+#[test]
+fn test_batch_lock_rejects_existing_anonymous_bounty() {
+    let env = Env::default();
+    let s = setup(&env);
+    let commitment = BytesN::from_array(&env, &[1u8; 32]);
+    s.client
+        .lock_funds_anonymous(&s.depositor, &commitment, &2, &100, &s.deadline);
+
+    assert_eq!(
+        s.client
+            .try_batch_lock_funds(&vec![&env, item(&s, 1, 100), item(&s, 2, 100)]),
+        Err(Ok(Error::BountyExists.into()))
+    );
+}
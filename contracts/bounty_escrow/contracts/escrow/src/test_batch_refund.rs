@@ -0,0 +1,93 @@
+#![cfg(test)]
+
+use crate::{BountyEscrowContract, BountyEscrowContractClient, Error, EscrowStatus};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env,
+};
+
+struct Setup<'a> {
+    client: BountyEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    depositor: Address,
+}
+
+/// Bounties 1 and 2 expire at +100, bounty 3 at +10_000; the ledger is
+/// moved past the first deadline.
+fn setup<'a>(env: &Env) -> Setup<'a> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_address).mint(&depositor, &1_000);
+    let now = env.ledger().timestamp();
+    client.lock_funds(&depositor, &1, &100, &(now + 100));
+    client.lock_funds(&depositor, &2, &200, &(now + 100));
+    client.lock_funds(&depositor, &3, &300, &(now + 10_000));
+    env.ledger().with_mut(|li| li.timestamp = now + 101);
+
+    Setup {
+        client,
+        token: token::Client::new(env, &token_address),
+        depositor,
+    }
+}
+
+#[test]
+fn test_batch_refund_returns_expired_and_skips_others() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.release_funds(&2, &Address::generate(&env));
+
+    let result = s.client.batch_refund(&vec![&env, 1, 2, 3, 99]);
+
+    assert_eq!(result.refunded, vec![&env, 1]);
+    assert_eq!(result.skipped, vec![&env, 2, 3, 99]);
+    assert_eq!(result.total_refunded, 100);
+    assert_eq!(s.token.balance(&s.depositor), 500);
+    assert_eq!(s.client.get_escrow_info(&1).status, EscrowStatus::Refunded);
+    assert_eq!(s.client.get_escrow_info(&3).status, EscrowStatus::Locked);
+}
+
+#[test]
+fn test_batch_refund_is_idempotent() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    assert_eq!(s.client.batch_refund(&vec![&env, 1, 2]).total_refunded, 300);
+    let again = s.client.batch_refund(&vec![&env, 1, 2]);
+    assert_eq!(again.refunded.len(), 0);
+    assert_eq!(again.skipped, vec![&env, 1, 2]);
+    assert_eq!(s.token.balance(&s.depositor), 700);
+}
+
+#[test]
+fn test_batch_refund_validates_batch() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    assert_eq!(
+        s.client.try_batch_refund(&vec![&env]),
+        Err(Ok(Error::InvalidBatchSize.into()))
+    );
+    let mut too_many = vec![&env];
+    for id in 0..21u64 {
+        too_many.push_back(id);
+    }
+    assert_eq!(
+        s.client.try_batch_refund(&too_many),
+        Err(Ok(Error::InvalidBatchSize.into()))
+    );
+    assert_eq!(
+        s.client.try_batch_refund(&vec![&env, 1, 1]),
+        Err(Ok(Error::DuplicateBountyId.into()))
+    );
+}
@@ -45,7 +45,12 @@ fn assert_current_call_has_versioned_contract_event(env: &Env, contract_id: &Add
             None => continue,
         };
         let version = u32::try_from_val(env, &version_val).expect("version should decode as u32");
-        assert_eq!(version, 2);
+        // Fund-moving events moved to v3; everything else stays on v2.
+        assert!(
+            version == 2 || version == 3,
+            "unexpected event version {}",
+            version
+        );
         found = true;
     }
     assert!(found, "expected at least one versioned contract event");
@@ -628,7 +633,7 @@ fn test_update_fee_config_rejects_negative_lock_fee() {
 
     let result =
         client.try_update_fee_config(&Some(-1), &None, &Some(fee_recipient.clone()), &None);
-    assert_eq!(result, Err(Ok(ContractError::InvalidFeeRate)));
+    assert_eq!(result, Err(Ok(ContractError::InvalidFeeRate.into())));
 
     let current_config = client.get_fee_config();
     assert_eq!(current_config.lock_fee_rate, original_config.lock_fee_rate);
@@ -653,7 +658,7 @@ fn test_update_fee_config_rejects_negative_release_fee() {
 
     let result =
         client.try_update_fee_config(&None, &Some(-1), &Some(fee_recipient.clone()), &None);
-    assert_eq!(result, Err(Ok(ContractError::InvalidFeeRate)));
+    assert_eq!(result, Err(Ok(ContractError::InvalidFeeRate.into())));
 
     let current_config = client.get_fee_config();
     assert_eq!(current_config.lock_fee_rate, original_config.lock_fee_rate);
@@ -678,7 +683,7 @@ fn test_update_fee_config_rejects_over_max_lock_fee() {
 
     let result =
         client.try_update_fee_config(&Some(5001), &None, &Some(fee_recipient.clone()), &None);
-    assert_eq!(result, Err(Ok(ContractError::InvalidFeeRate)));
+    assert_eq!(result, Err(Ok(ContractError::InvalidFeeRate.into())));
 
     let current_config = client.get_fee_config();
     assert_eq!(current_config.lock_fee_rate, original_config.lock_fee_rate);
@@ -703,7 +708,7 @@ fn test_update_fee_config_rejects_over_max_release_fee() {
 
     let result =
         client.try_update_fee_config(&None, &Some(5001), &Some(fee_recipient.clone()), &None);
-    assert_eq!(result, Err(Ok(ContractError::InvalidFeeRate)));
+    assert_eq!(result, Err(Ok(ContractError::InvalidFeeRate.into())));
 
     let current_config = client.get_fee_config();
     assert_eq!(current_config.lock_fee_rate, original_config.lock_fee_rate);
@@ -728,7 +733,7 @@ fn test_update_fee_config_rejects_overflow_lock_fee() {
 
     let result =
         client.try_update_fee_config(&Some(i128::MAX), &None, &Some(fee_recipient.clone()), &None);
-    assert_eq!(result, Err(Ok(ContractError::InvalidFeeRate)));
+    assert_eq!(result, Err(Ok(ContractError::InvalidFeeRate.into())));
 
     let current_config = client.get_fee_config();
     assert_eq!(current_config.lock_fee_rate, original_config.lock_fee_rate);
@@ -753,7 +758,7 @@ fn test_update_fee_config_rejects_overflow_release_fee() {
 
     let result =
         client.try_update_fee_config(&None, &Some(i128::MAX), &Some(fee_recipient.clone()), &None);
-    assert_eq!(result, Err(Ok(ContractError::InvalidFeeRate)));
+    assert_eq!(result, Err(Ok(ContractError::InvalidFeeRate.into())));
 
     let current_config = client.get_fee_config();
     assert_eq!(current_config.lock_fee_rate, original_config.lock_fee_rate);
@@ -893,7 +898,7 @@ fn test_update_fee_config_fails_with_one_invalid_rate_preserves_state() {
     let original_config = client.get_fee_config();
 
     let result = client.try_update_fee_config(&Some(300), &Some(5001), &None, &None);
-    assert_eq!(result, Err(Ok(ContractError::InvalidFeeRate)));
+    assert_eq!(result, Err(Ok(ContractError::InvalidFeeRate.into())));
 
     let config = client.get_fee_config();
     assert_eq!(config.lock_fee_rate, original_config.lock_fee_rate);
@@ -915,7 +920,7 @@ fn test_update_fee_config_rejects_100_percent_lock_fee() {
 
     let result =
         client.try_update_fee_config(&Some(10_000), &None, &Some(fee_recipient.clone()), &None);
-    assert_eq!(result, Err(Ok(ContractError::InvalidFeeRate)));
+    assert_eq!(result, Err(Ok(ContractError::InvalidFeeRate.into())));
 
     let current_config = client.get_fee_config();
     assert_eq!(current_config.lock_fee_rate, original_config.lock_fee_rate);
@@ -940,7 +945,7 @@ fn test_update_fee_config_rejects_100_percent_release_fee() {
 
     let result =
         client.try_update_fee_config(&None, &Some(10_000), &Some(fee_recipient.clone()), &None);
-    assert_eq!(result, Err(Ok(ContractError::InvalidFeeRate)));
+    assert_eq!(result, Err(Ok(ContractError::InvalidFeeRate.into())));
 
     let current_config = client.get_fee_config();
     assert_eq!(current_config.lock_fee_rate, original_config.lock_fee_rate);
@@ -965,7 +970,7 @@ fn test_update_fee_config_rejects_over_100_percent_lock_fee() {
 
     let result =
         client.try_update_fee_config(&Some(10_001), &None, &Some(fee_recipient.clone()), &None);
-    assert_eq!(result, Err(Ok(ContractError::InvalidFeeRate)));
+    assert_eq!(result, Err(Ok(ContractError::InvalidFeeRate.into())));
 
     let current_config = client.get_fee_config();
     assert_eq!(current_config.lock_fee_rate, original_config.lock_fee_rate);
@@ -990,7 +995,7 @@ fn test_update_fee_config_rejects_over_100_percent_release_fee() {
 
     let result =
         client.try_update_fee_config(&None, &Some(10_001), &Some(fee_recipient.clone()), &None);
-    assert_eq!(result, Err(Ok(ContractError::InvalidFeeRate)));
+    assert_eq!(result, Err(Ok(ContractError::InvalidFeeRate.into())));
 
     let current_config = client.get_fee_config();
     assert_eq!(current_config.lock_fee_rate, original_config.lock_fee_rate);
@@ -0,0 +1,142 @@
+#![cfg(test)]
+
+use crate::{
+    BountyEscrowContract, BountyEscrowContractClient, Error, EscrowStatus, LifecycleError,
+    ReleaseFundsItem,
+};
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env};
+
+/// Lock bounties 1..=3 for 100, 200 and 300 from one depositor.
+fn setup<'a>(env: &Env) -> (BountyEscrowContractClient<'a>, token::Client<'a>) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_address).mint(&depositor, &10_000);
+    let deadline = env.ledger().timestamp() + 10_000;
+    for id in 1..=3u64 {
+        client.lock_funds(&depositor, &id, &(id as i128 * 100), &deadline);
+    }
+    (client, token::Client::new(env, &token_address))
+}
+
+fn item(bounty_id: u64, contributor: &Address) -> ReleaseFundsItem {
+    ReleaseFundsItem {
+        bounty_id,
+        contributor: contributor.clone(),
+    }
+}
+
+#[test]
+fn test_group_summary_tracks_members() {
+    let env = Env::default();
+    let (client, _token) = setup(&env);
+    client.create_bounty_group(&7, &vec![&env, 1, 2, 3]);
+
+    assert_eq!(client.get_bounty_group_id(&2), Some(7));
+    client.release_funds(&1, &Address::generate(&env));
+
+    let summary = client.get_group_summary(&7);
+    assert_eq!(summary.bounty_count, 3);
+    assert_eq!(summary.total_amount, 600);
+    assert_eq!(summary.total_remaining, 500);
+    assert_eq!(summary.locked_count, 2);
+    assert_eq!(summary.released_count, 1);
+    assert!(!summary.paused);
+}
+
+#[test]
+fn test_release_group_pays_listed_bounties() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    client.create_bounty_group(&7, &vec![&env, 1, 2, 3]);
+
+    let released = client.release_group(&7, &vec![&env, item(1, &alice), item(3, &bob)]);
+
+    assert_eq!(released, 2);
+    assert_eq!(token.balance(&alice), 100);
+    assert_eq!(token.balance(&bob), 300);
+    assert_eq!(client.get_escrow_info(&2).status, EscrowStatus::Locked);
+}
+
+#[test]
+fn test_release_group_is_atomic() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let alice = Address::generate(&env);
+    client.create_bounty_group(&7, &vec![&env, 1, 2, 3]);
+    client.release_funds(&3, &alice);
+
+    assert_eq!(
+        client.try_release_group(&7, &vec![&env, item(1, &alice), item(3, &alice)]),
+        Err(Ok(Error::FundsNotLocked.into()))
+    );
+    assert_eq!(client.get_escrow_info(&1).status, EscrowStatus::Locked);
+    assert_eq!(token.balance(&alice), 300);
+}
+
+#[test]
+fn test_group_pause_blocks_member_releases() {
+    let env = Env::default();
+    let (client, _token) = setup(&env);
+    let alice = Address::generate(&env);
+    client.create_bounty_group(&7, &vec![&env, 1, 2]);
+    client.set_group_paused(&7, &true);
+
+    assert_eq!(
+        client.try_release_funds(&1, &alice),
+        Err(Ok(LifecycleError::GroupPaused.into()))
+    );
+    assert_eq!(
+        client.try_release_group(&7, &vec![&env, item(2, &alice)]),
+        Err(Ok(LifecycleError::GroupPaused.into()))
+    );
+    // Bounties outside the group are unaffected.
+    client.release_funds(&3, &alice);
+
+    client.set_group_paused(&7, &false);
+    client.release_funds(&1, &alice);
+}
+
+#[test]
+fn test_group_creation_and_release_validation() {
+    let env = Env::default();
+    let (client, _token) = setup(&env);
+    let alice = Address::generate(&env);
+    client.create_bounty_group(&7, &vec![&env, 1, 2]);
+
+    assert_eq!(
+        client.try_create_bounty_group(&7, &vec![&env, 3]),
+        Err(Ok(LifecycleError::GroupAlreadyExists.into()))
+    );
+    assert_eq!(
+        client.try_create_bounty_group(&8, &vec![&env, 2, 3]),
+        Err(Ok(LifecycleError::BountyAlreadyGrouped.into()))
+    );
+    assert_eq!(
+        client.try_create_bounty_group(&8, &vec![&env, 3, 3]),
+        Err(Ok(Error::DuplicateBountyId.into()))
+    );
+    assert_eq!(
+        client.try_create_bounty_group(&8, &vec![&env, 9]),
+        Err(Ok(Error::BountyNotFound.into()))
+    );
+    assert_eq!(
+        client.try_release_group(&7, &vec![&env, item(3, &alice)]),
+        Err(Ok(LifecycleError::BountyNotInGroup.into()))
+    );
+    assert_eq!(
+        client.try_get_group_summary(&9),
+        Err(Ok(LifecycleError::GroupNotFound.into()))
+    );
+}
@@ -130,7 +130,7 @@ fn test_issue_and_use_release_capability() {
     );
     assert_eq!(
         too_large.unwrap_err().unwrap(),
-        Error::CapabilityAmountExceeded
+        Error::CapabilityAmountExceeded.into()
     );
 }
 
@@ -188,7 +188,7 @@ fn test_capability_expiry_and_revocation() {
     let expired = setup
         .client
         .try_refund_with_capability(&3, &100, &setup.delegate, &expired_id);
-    assert_eq!(expired.unwrap_err().unwrap(), Error::CapabilityExpired);
+    assert_eq!(expired.unwrap_err().unwrap(), Error::CapabilityExpired.into());
 
     let active_expiry = setup.env.ledger().timestamp() + 200;
     let active_id = setup.client.issue_capability(
@@ -206,7 +206,7 @@ fn test_capability_expiry_and_revocation() {
     let revoked = setup
         .client
         .try_refund_with_capability(&3, &100, &setup.delegate, &active_id);
-    assert_eq!(revoked.unwrap_err().unwrap(), Error::CapabilityRevoked);
+    assert_eq!(revoked.unwrap_err().unwrap(), Error::CapabilityRevoked.into());
 }
 
 #[test]
@@ -224,7 +224,7 @@ fn test_capability_cannot_exceed_owner_authority() {
         &expiry,
         &1,
     );
-    assert_eq!(non_admin_issue.unwrap_err().unwrap(), Error::Unauthorized);
+    assert_eq!(non_admin_issue.unwrap_err().unwrap(), Error::Unauthorized.into());
 
     let over_limit_issue = setup.client.try_issue_capability(
         &setup.admin,
@@ -237,7 +237,7 @@ fn test_capability_cannot_exceed_owner_authority() {
     );
     assert_eq!(
         over_limit_issue.unwrap_err().unwrap(),
-        Error::CapabilityExceedsAuthority
+        Error::CapabilityExceedsAuthority.into()
     );
 
     setup.client.set_claim_window(&300);
@@ -253,5 +253,5 @@ fn test_capability_cannot_exceed_owner_authority() {
         &expiry,
         &1,
     );
-    assert_eq!(wrong_claim_owner.unwrap_err().unwrap(), Error::Unauthorized);
+    assert_eq!(wrong_claim_owner.unwrap_err().unwrap(), Error::Unauthorized.into());
 }
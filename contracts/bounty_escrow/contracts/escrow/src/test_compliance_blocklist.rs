@@ -0,0 +1,153 @@
+#![cfg(test)]
+
+use crate::{BountyEscrowContract, BountyEscrowContractClient, ComplianceError};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Bytes, BytesN, Env,
+};
+
+struct Setup<'a> {
+    client: BountyEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    depositor: Address,
+    deadline: u64,
+}
+
+/// Bounty 1 locked for 1_000 by `depositor`.
+fn setup<'a>(env: &Env) -> Setup<'a> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_address).mint(&depositor, &5_000);
+    let deadline = env.ledger().timestamp() + 100;
+    client.lock_funds(&depositor, &1, &1_000, &deadline);
+
+    Setup {
+        client,
+        token: token::Client::new(env, &token_address),
+        depositor,
+        deadline,
+    }
+}
+
+#[test]
+fn test_blocked_depositor_cannot_lock_or_be_refunded() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.set_address_blocked(&s.depositor, &true);
+    assert!(s.client.is_blocked(&s.depositor));
+
+    assert_eq!(
+        s.client
+            .try_lock_funds(&s.depositor, &2, &1_000, &s.deadline),
+        Err(Ok(ComplianceError::AddressBlocked.into()))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = s.deadline + 1);
+    assert_eq!(
+        s.client.try_refund(&1),
+        Err(Ok(ComplianceError::AddressBlocked.into()))
+    );
+    let result = s.client.batch_refund(&vec![&env, 1]);
+    assert_eq!(result.skipped.len(), 1);
+
+    s.client.set_address_blocked(&s.depositor, &false);
+    s.client.refund(&1);
+    assert_eq!(s.token.balance(&s.depositor), 5_000);
+}
+
+#[test]
+fn test_blocked_contributor_cannot_receive_release() {
+    let env = Env::default();
+    let s = setup(&env);
+    let contributor = Address::generate(&env);
+    s.client.set_address_blocked(&contributor, &true);
+
+    assert_eq!(
+        s.client.try_release_funds(&1, &contributor),
+        Err(Ok(ComplianceError::AddressBlocked.into()))
+    );
+    assert_eq!(
+        s.client.try_partial_release(&1, &contributor, &100),
+        Err(Ok(ComplianceError::AddressBlocked.into()))
+    );
+    assert_eq!(s.token.balance(&contributor), 0);
+}
+
+#[test]
+fn test_blocked_recipient_fails_the_whole_split() {
+    let env = Env::default();
+    let s = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    s.client.set_address_blocked(&bob, &true);
+
+    assert_eq!(
+        s.client.try_release_funds_split(
+            &1,
+            &vec![&env, alice.clone(), bob.clone()],
+            &vec![&env, 400_i128, 600_i128]
+        ),
+        Err(Ok(ComplianceError::AddressBlocked.into()))
+    );
+    assert_eq!(s.token.balance(&alice), 0);
+    assert_eq!(s.client.get_escrow_info(&1).remaining_amount, 1_000);
+}
+
+#[test]
+fn test_blocked_claimant_cannot_claim_with_preimage() {
+    let env = Env::default();
+    let s = setup(&env);
+    let preimage = Bytes::from_slice(&env, b"blocked-claimant-secret");
+    let hashlock: BytesN<32> = env.crypto().sha256(&preimage).into();
+    s.client
+        .lock_funds_hashlocked(&s.depositor, &2, &1_000, &s.deadline, &hashlock);
+    let claimant = Address::generate(&env);
+    s.client.set_address_blocked(&claimant, &true);
+
+    assert_eq!(
+        s.client.try_claim_with_preimage(&2, &claimant, &preimage),
+        Err(Ok(ComplianceError::AddressBlocked.into()))
+    );
+    assert_eq!(s.token.balance(&claimant), 0);
+}
+
+#[test]
+fn test_blocked_beneficiary_cannot_claim_ticket() {
+    let env = Env::default();
+    let s = setup(&env);
+    let beneficiary = Address::generate(&env);
+    let ticket_id = s
+        .client
+        .issue_claim_ticket(&1, &beneficiary, &500, &(s.deadline - 1));
+    s.client.set_address_blocked(&beneficiary, &true);
+
+    assert_eq!(
+        s.client.try_claim_with_ticket_partial(&ticket_id, &500),
+        Err(Ok(ComplianceError::AddressBlocked.into()))
+    );
+}
+
+#[test]
+fn test_compliance_officer_manages_list() {
+    let env = Env::default();
+    let s = setup(&env);
+    let compliance = Address::generate(&env);
+    let target = Address::generate(&env);
+    s.client.set_compliance_admin(&compliance);
+    assert_eq!(s.client.get_compliance_admin(), Some(compliance.clone()));
+
+    s.client.set_address_blocked(&target, &true);
+    let auths = env.auths();
+    assert_eq!(auths[0].0, compliance);
+    assert!(s.client.is_blocked(&target));
+}
@@ -0,0 +1,132 @@
+#![cfg(test)]
+
+use crate::{
+    BountyEscrowContract, BountyEscrowContractClient, DataKey, Error, Escrow, EscrowStatus,
+};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env,
+};
+
+struct Setup<'a> {
+    client: BountyEscrowContractClient<'a>,
+    sac: token::StellarAssetClient<'a>,
+    contract_id: Address,
+    depositor: Address,
+    deadline: u64,
+}
+
+/// Bounties 1 and 2 locked for 1_000 each.
+fn setup<'a>(env: &Env) -> Setup<'a> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+
+    let depositor = Address::generate(env);
+    let sac = token::StellarAssetClient::new(env, &token_address);
+    sac.mint(&depositor, &2_000);
+    let deadline = env.ledger().timestamp() + 100;
+    client.lock_funds(&depositor, &1, &1_000, &deadline);
+    client.lock_funds(&depositor, &2, &1_000, &deadline);
+
+    Setup {
+        client,
+        sac,
+        contract_id,
+        depositor,
+        deadline,
+    }
+}
+
+#[test]
+fn test_healthy_after_lock_release_and_refund() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.release_funds(&1, &Address::generate(&env));
+    env.ledger().with_mut(|li| li.timestamp = s.deadline + 1);
+    s.client.refund(&2);
+
+    let report = s.client.check_invariants();
+    assert!(report.healthy);
+    assert!(report.funds_conserved);
+    assert_eq!(report.tracked_balance, 0);
+    assert_eq!(report.token_balance, 0);
+    assert_eq!(report.escrows_checked, 2);
+    assert_eq!(report.violation_count, 0);
+    assert!(s.client.verify_invariants());
+}
+
+#[test]
+fn test_untracked_balance_breaks_conservation() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.sac.mint(&s.contract_id, &5);
+
+    let report = s.client.check_invariants();
+    assert!(!report.healthy);
+    assert!(!report.funds_conserved);
+    assert_eq!(report.tracked_balance, 2_000);
+    assert_eq!(report.token_balance, 2_005);
+    assert_eq!(report.violation_count, 1);
+    assert!(!s.client.verify_invariants());
+}
+
+#[test]
+fn test_settled_escrow_with_remaining_is_reported() {
+    let env = Env::default();
+    let s = setup(&env);
+    env.as_contract(&s.contract_id, || {
+        let mut escrow: Escrow = env.storage().persistent().get(&DataKey::Escrow(2)).unwrap();
+        escrow.status = EscrowStatus::Released;
+        env.storage().persistent().set(&DataKey::Escrow(2), &escrow);
+    });
+
+    let report = s.client.check_invariants();
+    assert!(!report.healthy);
+    assert_eq!(report.settled_with_remaining, vec![&env, 2]);
+    // The stuck 1_000 is no longer tracked either.
+    assert!(!report.funds_conserved);
+    assert_eq!(report.violation_count, 2);
+}
+
+#[test]
+fn test_ticket_without_escrow_is_orphaned() {
+    let env = Env::default();
+    let s = setup(&env);
+    let expires_at = s.deadline + 1_000;
+    s.client
+        .issue_claim_ticket(&1, &s.depositor, &500, &expires_at);
+    let orphan = s
+        .client
+        .issue_claim_ticket(&2, &s.depositor, &500, &expires_at);
+    assert!(s.client.check_invariants().healthy);
+
+    env.as_contract(&s.contract_id, || {
+        env.storage().persistent().remove(&DataKey::Escrow(2));
+    });
+
+    let report = s.client.check_invariants();
+    assert_eq!(report.tickets_checked, 2);
+    assert_eq!(report.orphaned_tickets, vec![&env, orphan]);
+    assert!(!report.healthy);
+}
+
+#[test]
+fn test_check_invariants_requires_init() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(&env, &contract_id);
+
+    assert_eq!(
+        client.try_check_invariants(),
+        Err(Ok(Error::NotInitialized.into()))
+    );
+    assert!(!client.verify_invariants());
+}
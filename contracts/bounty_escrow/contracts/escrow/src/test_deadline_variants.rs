@@ -191,7 +191,7 @@ fn test_future_deadline_refund_blocked_before_expiry() {
     s.escrow.lock_funds(&s.depositor, &11, &1_000, &deadline);
 
     let result = s.escrow.try_refund(&11);
-    assert_eq!(result.unwrap_err().unwrap(), Error::DeadlineNotPassed);
+    assert_eq!(result.unwrap_err().unwrap(), Error::DeadlineNotPassed.into());
 
     let info = s.escrow.get_escrow_info(&11);
     assert_eq!(info.status, EscrowStatus::Locked);
@@ -277,7 +277,7 @@ fn test_no_deadline_refund_blocked_without_approval() {
     s.escrow.lock_funds(&s.depositor, &21, &1_000, &NO_DEADLINE);
 
     let result = s.escrow.try_refund(&21);
-    assert_eq!(result.unwrap_err().unwrap(), Error::DeadlineNotPassed);
+    assert_eq!(result.unwrap_err().unwrap(), Error::DeadlineNotPassed.into());
 
     let info = s.escrow.get_escrow_info(&21);
     assert_eq!(info.status, EscrowStatus::Locked);
@@ -293,7 +293,7 @@ fn test_no_deadline_refund_blocked_even_after_large_time_advance() {
     s.env.ledger().set_timestamp(100 * 365 * 24 * 3600);
 
     let result = s.escrow.try_refund(&22);
-    assert_eq!(result.unwrap_err().unwrap(), Error::DeadlineNotPassed);
+    assert_eq!(result.unwrap_err().unwrap(), Error::DeadlineNotPassed.into());
 }
 
 #[test]
@@ -363,7 +363,7 @@ fn test_deadline_zero_vs_future_refund_eligibility() {
     assert!(s.escrow.try_refund(&30).is_ok());
     assert_eq!(
         s.escrow.try_refund(&31).unwrap_err().unwrap(),
-        Error::DeadlineNotPassed
+        Error::DeadlineNotPassed.into()
     );
 }
 
@@ -385,7 +385,7 @@ fn test_deadline_future_vs_no_deadline_after_expiry() {
     assert!(s.escrow.try_refund(&32).is_ok());
     assert_eq!(
         s.escrow.try_refund(&33).unwrap_err().unwrap(),
-        Error::DeadlineNotPassed
+        Error::DeadlineNotPassed.into()
     );
 }
 
@@ -406,7 +406,7 @@ fn test_refund_at_exact_deadline_boundary() {
     // One second before deadline: refund blocked
     s.env.ledger().set_timestamp(deadline - 1);
     let result = s.escrow.try_refund(&40);
-    assert_eq!(result.unwrap_err().unwrap(), Error::DeadlineNotPassed);
+    assert_eq!(result.unwrap_err().unwrap(), Error::DeadlineNotPassed.into());
 
     // Exactly at deadline: refund succeeds
     s.env.ledger().set_timestamp(deadline);
@@ -497,13 +497,13 @@ fn test_mixed_deadline_types_coexist_independently() {
     // Future-deadline bounty still blocked
     assert_eq!(
         s.escrow.try_refund(&61).unwrap_err().unwrap(),
-        Error::DeadlineNotPassed
+        Error::DeadlineNotPassed.into()
     );
 
     // No-deadline bounty permanently blocked
     assert_eq!(
         s.escrow.try_refund(&62).unwrap_err().unwrap(),
-        Error::DeadlineNotPassed
+        Error::DeadlineNotPassed.into()
     );
 
     // Advance past future deadline
@@ -515,7 +515,7 @@ fn test_mixed_deadline_types_coexist_independently() {
     // No-deadline bounty still blocked
     assert_eq!(
         s.escrow.try_refund(&62).unwrap_err().unwrap(),
-        Error::DeadlineNotPassed
+        Error::DeadlineNotPassed.into()
     );
 }
 
@@ -594,7 +594,7 @@ fn test_no_deadline_full_refund_workflow() {
     // Confirm refund is blocked initially
     assert_eq!(
         s.escrow.try_refund(&90).unwrap_err().unwrap(),
-        Error::DeadlineNotPassed
+        Error::DeadlineNotPassed.into()
     );
 
     // Admin approves full refund
@@ -620,7 +620,7 @@ fn test_no_deadline_escrow_released_after_refund_blocked() {
     // Refund blocked (no deadline, no approval)
     assert_eq!(
         s.escrow.try_refund(&91).unwrap_err().unwrap(),
-        Error::DeadlineNotPassed
+        Error::DeadlineNotPassed.into()
     );
 
     // But release always works
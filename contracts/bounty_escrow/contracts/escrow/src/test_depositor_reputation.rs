@@ -0,0 +1,90 @@
+#![cfg(test)]
+
+use crate::{BountyEscrowContract, BountyEscrowContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, BytesN, Env,
+};
+
+struct Setup<'a> {
+    client: BountyEscrowContractClient<'a>,
+    depositor: Address,
+    deadline: u64,
+}
+
+/// Bounties 1..=4 locked for 1_000 each, due in 100s.
+fn setup<'a>(env: &Env) -> Setup<'a> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_address).mint(&depositor, &10_000);
+    let deadline = env.ledger().timestamp() + 100;
+    for id in 1..=4u64 {
+        client.lock_funds(&depositor, &id, &1_000, &deadline);
+    }
+
+    Setup {
+        client,
+        depositor,
+        deadline,
+    }
+}
+
+#[test]
+fn test_new_depositor_has_perfect_score() {
+    let env = Env::default();
+    let s = setup(&env);
+    let rep = s.client.get_depositor_reputation(&Address::generate(&env));
+    assert_eq!(rep.total_escrows, 0);
+    assert_eq!(rep.on_time_payment_rate_bps, 10_000);
+    assert_eq!(rep.overall_score_bps, 10_000);
+
+    let rep = s.client.get_depositor_reputation(&s.depositor);
+    assert_eq!(rep.total_escrows, 4);
+    assert_eq!(rep.overall_score_bps, 10_000);
+}
+
+#[test]
+fn test_late_payouts_and_refunds_lower_score() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.release_funds(&1, &Address::generate(&env));
+    env.ledger().with_mut(|li| li.timestamp = s.deadline + 1);
+    s.client.release_funds(&2, &Address::generate(&env));
+    s.client.refund(&3);
+
+    let rep = s.client.get_depositor_reputation(&s.depositor);
+    assert_eq!(rep.total_payouts, 2);
+    assert_eq!(rep.on_time_payouts, 1);
+    assert_eq!(rep.refund_count, 1);
+    assert_eq!(rep.on_time_payment_rate_bps, 5_000);
+    assert_eq!(rep.refund_rate_bps, 2_500);
+    assert_eq!(rep.dispute_rate_bps, 0);
+    // (5_000 * 2 + 10_000 + 7_500) / 4
+    assert_eq!(rep.overall_score_bps, 6_875);
+}
+
+#[test]
+fn test_disputes_are_counted() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.set_release_challenge_period(&1, &50);
+    s.client.release_funds(&1, &Address::generate(&env));
+    s.client
+        .dispute_release(&1, &BytesN::from_array(&env, &[1u8; 32]));
+
+    let rep = s.client.get_depositor_reputation(&s.depositor);
+    assert_eq!(rep.dispute_count, 1);
+    assert_eq!(rep.dispute_rate_bps, 2_500);
+    assert_eq!(rep.total_payouts, 0);
+}
@@ -0,0 +1,113 @@
+#![cfg(test)]
+
+use crate::{BountyEscrowContract, BountyEscrowContractClient, DepositorSummary, RefundMode};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env,
+};
+
+struct Setup<'a> {
+    client: BountyEscrowContractClient<'a>,
+    depositor: Address,
+    deadline: u64,
+}
+
+fn setup<'a>(env: &Env) -> Setup<'a> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_address).mint(&depositor, &10_000);
+
+    Setup {
+        client,
+        depositor,
+        deadline: env.ledger().timestamp() + 100,
+    }
+}
+
+fn summary(active: u32, locked: i128, released: i128, refunded: i128) -> DepositorSummary {
+    DepositorSummary {
+        active_escrows: active,
+        total_locked: locked,
+        total_released: released,
+        total_refunded: refunded,
+    }
+}
+
+#[test]
+fn test_unknown_depositor_has_empty_summary() {
+    let env = Env::default();
+    let s = setup(&env);
+    assert_eq!(
+        s.client.get_depositor_summary(&s.depositor),
+        DepositorSummary::default()
+    );
+}
+
+#[test]
+fn test_summary_tracks_lock_release_and_refund() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.lock_funds(&s.depositor, &1, &1_000, &s.deadline);
+    s.client.lock_funds(&s.depositor, &2, &2_000, &s.deadline);
+    s.client.lock_funds(&s.depositor, &3, &3_000, &s.deadline);
+    assert_eq!(
+        s.client.get_depositor_summary(&s.depositor),
+        summary(3, 6_000, 0, 0)
+    );
+
+    s.client.release_funds(&1, &Address::generate(&env));
+    assert_eq!(
+        s.client.get_depositor_summary(&s.depositor),
+        summary(2, 5_000, 1_000, 0)
+    );
+
+    s.client
+        .approve_refund(&2, &500, &s.depositor, &RefundMode::Partial);
+    s.client.refund(&2);
+    assert_eq!(
+        s.client.get_depositor_summary(&s.depositor),
+        summary(2, 4_500, 1_000, 500)
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = s.deadline + 1);
+    s.client.refund(&2);
+    assert_eq!(
+        s.client.get_depositor_summary(&s.depositor),
+        summary(1, 3_000, 1_000, 2_000)
+    );
+}
+
+#[test]
+fn test_partial_release_and_top_up() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.lock_funds(&s.depositor, &1, &1_000, &s.deadline);
+    s.client.increase_escrow(&1, &500);
+    assert_eq!(
+        s.client.get_depositor_summary(&s.depositor),
+        summary(1, 1_500, 0, 0)
+    );
+
+    let contributor = Address::generate(&env);
+    s.client.partial_release(&1, &contributor, &600);
+    assert_eq!(
+        s.client.get_depositor_summary(&s.depositor),
+        summary(1, 900, 600, 0)
+    );
+
+    s.client.partial_release(&1, &contributor, &900);
+    assert_eq!(
+        s.client.get_depositor_summary(&s.depositor),
+        summary(0, 0, 1_500, 0)
+    );
+}
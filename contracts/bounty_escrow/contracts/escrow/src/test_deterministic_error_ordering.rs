@@ -52,7 +52,7 @@ fn test_lock_not_initialized_beats_paused() {
     let depositor = Address::generate(&env);
     let future = 99_999_999u64;
     let result = client.try_lock_funds(&depositor, &1, &1_000, &future);
-    assert_eq!(result, Err(Ok(Error::NotInitialized)));
+    assert_eq!(result, Err(Ok(Error::NotInitialized.into())));
 }
 
 /// Priority 2 beats priority 7: NotInitialized returned even when bounty would also be invalid.
@@ -61,7 +61,7 @@ fn test_lock_not_initialized_beats_business_logic() {
     let (env, client) = setup_uninitialized();
     let depositor = Address::generate(&env);
     let result = client.try_lock_funds(&depositor, &1, &1_000, &0u64);
-    assert_eq!(result, Err(Ok(Error::NotInitialized)));
+    assert_eq!(result, Err(Ok(Error::NotInitialized.into())));
 }
 
 /// Priority 3 beats priority 7: FundsPaused returned even when bounty already exists.
@@ -76,7 +76,7 @@ fn test_lock_paused_beats_bounty_exists() {
     client.set_paused(&Some(true), &None, &None, &None);
     // Both paused AND bounty #1 already exists — must get FundsPaused first
     let result = client.try_lock_funds(&depositor, &1, &1_000, &future);
-    assert_eq!(result, Err(Ok(Error::FundsPaused)));
+    assert_eq!(result, Err(Ok(Error::FundsPaused.into())));
 }
 
 /// Priority 3 beats priority 6: FundsPaused returned even when amount is below minimum.
@@ -89,7 +89,7 @@ fn test_lock_paused_beats_amount_below_minimum() {
     client.set_paused(&Some(true), &None, &None, &None);
     // Both paused AND amount below minimum — must get FundsPaused first
     let result = client.try_lock_funds(&depositor, &1, &1, &future);
-    assert_eq!(result, Err(Ok(Error::FundsPaused)));
+    assert_eq!(result, Err(Ok(Error::FundsPaused.into())));
 }
 
 /// Priority 7 (BountyExists) fires after all higher-priority checks pass.
@@ -102,7 +102,7 @@ fn test_lock_bounty_exists_after_higher_checks_pass() {
     client.lock_funds(&depositor, &1, &1_000, &future);
     // Contract initialized, not paused — only bounty already exists
     let result = client.try_lock_funds(&depositor, &1, &1_000, &future);
-    assert_eq!(result, Err(Ok(Error::BountyExists)));
+    assert_eq!(result, Err(Ok(Error::BountyExists.into())));
 }
 
 // ── release_funds ─────────────────────────────────────────────────────────────
@@ -113,7 +113,7 @@ fn test_release_not_initialized_beats_paused() {
     let (env, client) = setup_uninitialized();
     let contributor = Address::generate(&env);
     let result = client.try_release_funds(&1, &contributor);
-    assert_eq!(result, Err(Ok(Error::NotInitialized)));
+    assert_eq!(result, Err(Ok(Error::NotInitialized.into())));
 }
 
 /// Priority 2 beats priority 7: NotInitialized returned even when bounty doesn't exist.
@@ -122,7 +122,7 @@ fn test_release_not_initialized_beats_bounty_not_found() {
     let (env, client) = setup_uninitialized();
     let contributor = Address::generate(&env);
     let result = client.try_release_funds(&999, &contributor);
-    assert_eq!(result, Err(Ok(Error::NotInitialized)));
+    assert_eq!(result, Err(Ok(Error::NotInitialized.into())));
 }
 
 /// Priority 3 beats priority 7: FundsPaused returned even when bounty doesn't exist.
@@ -133,7 +133,7 @@ fn test_release_paused_beats_bounty_not_found() {
     client.set_paused(&None, &Some(true), &None, &None);
     // Both paused AND bounty #999 doesn't exist — must get FundsPaused first
     let result = client.try_release_funds(&999, &contributor);
-    assert_eq!(result, Err(Ok(Error::FundsPaused)));
+    assert_eq!(result, Err(Ok(Error::FundsPaused.into())));
 }
 
 /// Priority 7 (BountyNotFound) fires after all higher-priority checks pass.
@@ -143,7 +143,7 @@ fn test_release_bounty_not_found_after_higher_checks_pass() {
     let contributor = Address::generate(&env);
     // Contract initialized, not paused — only bounty doesn't exist
     let result = client.try_release_funds(&999, &contributor);
-    assert_eq!(result, Err(Ok(Error::BountyNotFound)));
+    assert_eq!(result, Err(Ok(Error::BountyNotFound.into())));
 }
 
 /// Priority 7 (FundsNotLocked) fires after BountyNotFound check passes.
@@ -159,5 +159,5 @@ fn test_release_funds_not_locked_after_bounty_found() {
     client.release_funds(&1, &contributor);
     // Now try again — FundsNotLocked
     let result = client.try_release_funds(&1, &contributor);
-    assert_eq!(result, Err(Ok(Error::FundsNotLocked)));
+    assert_eq!(result, Err(Ok(Error::FundsNotLocked.into())));
 }
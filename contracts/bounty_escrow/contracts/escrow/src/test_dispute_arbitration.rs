@@ -0,0 +1,231 @@
+#![cfg(test)]
+
+use crate::{
+    events::{DisputeResolved, FundsRefunded, FundsReleased, EVENT_VERSION_V3},
+    BountyEscrowContract, BountyEscrowContractClient, DisputeRuling, Error, EscrowStatus,
+    ReleaseError,
+};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    token, vec, Address, BytesN, Env, Symbol, TryFromVal, TryIntoVal, Val,
+};
+
+/// Data of the last event published under `topic` in the latest invocation.
+fn event_data(env: &Env, topic: Symbol) -> Val {
+    env.events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| {
+            topics
+                .get(0)
+                .and_then(|t| Symbol::try_from_val(env, &t).ok())
+                == Some(topic.clone())
+        })
+        .last()
+        .map(|(_, _, data)| data)
+        .expect("event not emitted")
+}
+
+struct Setup<'a> {
+    client: BountyEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    depositor: Address,
+    contributor: Address,
+    arbiter: Address,
+}
+
+/// Lock 1_000, release it under a challenge period and dispute it.
+fn setup<'a>(env: &Env) -> Setup<'a> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_address).mint(&depositor, &1_000);
+    let deadline = env.ledger().timestamp() + 10_000;
+    client.lock_funds(&depositor, &1, &1_000, &deadline);
+
+    let arbiter = Address::generate(env);
+    client.set_arbiters(&vec![env, arbiter.clone()]);
+
+    let contributor = Address::generate(env);
+    client.set_release_challenge_period(&1, &500);
+    client.release_funds(&1, &contributor);
+    client.dispute_release(&1, &BytesN::from_array(env, &[9u8; 32]));
+
+    Setup {
+        client,
+        token: token::Client::new(env, &token_address),
+        depositor,
+        contributor,
+        arbiter,
+    }
+}
+
+#[test]
+fn test_uphold_release_pays_contributor() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client
+        .resolve_dispute(&1, &s.arbiter, &DisputeRuling::UpholdRelease);
+
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+    assert_eq!(s.client.get_escrow_info(&1).status, EscrowStatus::Released);
+    assert_eq!(s.client.get_pending_release(&1), None);
+}
+
+#[test]
+fn test_refund_ruling_returns_funds_to_depositor() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client
+        .resolve_dispute(&1, &s.arbiter, &DisputeRuling::RefundDepositor);
+
+    assert_eq!(s.token.balance(&s.depositor), 1_000);
+    assert_eq!(s.token.balance(&s.contributor), 0);
+    let escrow = s.client.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Refunded);
+    assert_eq!(escrow.remaining_amount, 0);
+    assert_eq!(escrow.refund_history.len(), 1);
+}
+
+#[test]
+fn test_split_ruling_divides_funds_and_records_ruling() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client
+        .resolve_dispute(&1, &s.arbiter, &DisputeRuling::Split(2_500));
+
+    assert_eq!(s.token.balance(&s.contributor), 250);
+    assert_eq!(s.token.balance(&s.depositor), 750);
+    assert_eq!(s.client.get_escrow_info(&1).status, EscrowStatus::Released);
+
+    let record = s.client.get_dispute_record(&1).unwrap();
+    assert_eq!(record.arbiter, s.arbiter);
+    assert_eq!(record.ruling, DisputeRuling::Split(2_500));
+    assert_eq!(record.contributor_amount, 250);
+    assert_eq!(record.depositor_amount, 750);
+    assert_eq!(record.reason_hash, BytesN::from_array(&env, &[9u8; 32]));
+    assert_eq!(s.client.get_arbiter_rulings(&s.arbiter), vec![&env, 1u64]);
+}
+
+#[test]
+fn test_split_ruling_emits_versioned_fund_events() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client
+        .resolve_dispute(&1, &s.arbiter, &DisputeRuling::Split(2_500));
+
+    let released: FundsReleased = event_data(&env, symbol_short!("f_rel"))
+        .try_into_val(&env)
+        .unwrap();
+    assert_eq!(released.version, EVENT_VERSION_V3);
+    assert_eq!(released.amount, 250);
+    assert_eq!(
+        (released.remaining_before, released.remaining_after),
+        (1_000, 750)
+    );
+    assert_eq!(released.recipient, s.contributor);
+
+    let refunded: FundsRefunded = event_data(&env, symbol_short!("f_ref"))
+        .try_into_val(&env)
+        .unwrap();
+    assert_eq!(refunded.amount, 750);
+    assert_eq!(
+        (refunded.remaining_before, refunded.remaining_after),
+        (750, 0)
+    );
+    assert_eq!(refunded.refund_to, s.depositor);
+
+    let resolved: DisputeResolved = event_data(&env, symbol_short!("disp_res"))
+        .try_into_val(&env)
+        .unwrap();
+    assert_eq!(resolved.version, EVENT_VERSION_V3);
+    assert_eq!(
+        (resolved.remaining_before, resolved.remaining_after),
+        (1_000, 0)
+    );
+}
+
+#[test]
+fn test_resolve_dispute_rejects_invalid_calls() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    assert_eq!(
+        s.client
+            .try_resolve_dispute(&1, &Address::generate(&env), &DisputeRuling::UpholdRelease),
+        Err(Ok(Error::Unauthorized.into()))
+    );
+    assert_eq!(
+        s.client
+            .try_resolve_dispute(&1, &s.arbiter, &DisputeRuling::Split(10_001)),
+        Err(Ok(Error::InvalidAmount.into()))
+    );
+
+    s.client
+        .resolve_dispute(&1, &s.arbiter, &DisputeRuling::RefundDepositor);
+    assert_eq!(
+        s.client
+            .try_resolve_dispute(&1, &s.arbiter, &DisputeRuling::UpholdRelease),
+        Err(Ok(ReleaseError::PendingReleaseNotFound.into()))
+    );
+}
+
+#[test]
+fn test_undisputed_release_cannot_be_ruled_on() {
+    let env = Env::default();
+    let s = setup(&env);
+    let depositor = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &s.token.address).mint(&depositor, &500);
+    let deadline = env.ledger().timestamp() + 10_000;
+    s.client.lock_funds(&depositor, &2, &500, &deadline);
+    s.client.set_release_challenge_period(&2, &500);
+    s.client.release_funds(&2, &s.contributor);
+
+    assert_eq!(
+        s.client
+            .try_resolve_dispute(&2, &s.arbiter, &DisputeRuling::RefundDepositor),
+        Err(Ok(ReleaseError::ReleaseNotDisputed.into()))
+    );
+}
+
+#[test]
+fn test_rulings_respect_pause_flags() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.set_paused(&None, &Some(true), &None, &None);
+    assert_eq!(
+        s.client
+            .try_resolve_dispute(&1, &s.arbiter, &DisputeRuling::UpholdRelease),
+        Err(Ok(Error::FundsPaused.into()))
+    );
+    assert_eq!(
+        s.client
+            .try_resolve_dispute(&1, &s.arbiter, &DisputeRuling::Split(2_500)),
+        Err(Ok(Error::FundsPaused.into()))
+    );
+
+    s.client.set_paused(&None, &Some(false), &Some(true), &None);
+    assert_eq!(
+        s.client
+            .try_resolve_dispute(&1, &s.arbiter, &DisputeRuling::RefundDepositor),
+        Err(Ok(Error::FundsPaused.into()))
+    );
+    s.client
+        .resolve_dispute(&1, &s.arbiter, &DisputeRuling::Split(10_000));
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+}
@@ -159,15 +159,15 @@ fn test_e2e_upgrade_with_pause() {
     assert_eq!(
         s.escrow_client
             .try_lock_funds(&s.depositor, &3, &5_000, &deadline),
-        Err(Ok(Error::FundsPaused))
+        Err(Ok(Error::FundsPaused.into()))
     );
 
     assert_eq!(
         s.escrow_client.try_release_funds(&1, &s.contributor),
-        Err(Ok(Error::FundsPaused))
+        Err(Ok(Error::FundsPaused.into()))
     );
 
-    assert_eq!(s.escrow_client.try_refund(&2), Err(Ok(Error::FundsPaused)));
+    assert_eq!(s.escrow_client.try_refund(&2), Err(Ok(Error::FundsPaused.into())));
 
     // Ensure balance hasn't moved
     let balance_during_pause = s.token_client.balance(&s.escrow_id);
@@ -505,7 +505,7 @@ fn test_emergency_withdraw_preserves_pause_state() {
     assert_eq!(
         s.escrow_client
             .try_lock_funds(&s.depositor, &99, &100, &deadline),
-        Err(Ok(Error::FundsPaused))
+        Err(Ok(Error::FundsPaused.into()))
     );
 
     // Admin can unpause and resume after draining
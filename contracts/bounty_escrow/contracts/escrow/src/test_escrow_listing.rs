@@ -0,0 +1,122 @@
+#![cfg(test)]
+
+use crate::{AnonymousParty, BountyEscrowContract, BountyEscrowContractClient, EscrowStatus};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, BytesN, Env, Vec,
+};
+
+struct Setup<'a> {
+    client: BountyEscrowContractClient<'a>,
+    depositor: Address,
+    deadline: u64,
+}
+
+/// Bounties 1..=3 locked for 1_000 each.
+fn setup<'a>(env: &Env) -> Setup<'a> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_address).mint(&depositor, &10_000);
+
+    let deadline = env.ledger().timestamp() + 100;
+    for id in 1..=3u64 {
+        client.lock_funds(&depositor, &id, &1_000, &deadline);
+    }
+
+    Setup {
+        client,
+        depositor,
+        deadline,
+    }
+}
+
+fn ids(env: &Env, client: &BountyEscrowContractClient, status: EscrowStatus) -> Vec<u64> {
+    let mut out = Vec::new(env);
+    for entry in client.list_escrows_by_status(&status, &0, &100).iter() {
+        out.push_back(entry.bounty_id);
+    }
+    out
+}
+
+#[test]
+fn test_list_escrows_paginates_in_creation_order() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let page: Vec<_> = s.client.list_escrows(&1, &5);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().bounty_id, 2);
+    assert_eq!(page.get(1).unwrap().bounty_id, 3);
+    assert_eq!(
+        page.get(0).unwrap().info.depositor,
+        AnonymousParty::Address(s.depositor.clone())
+    );
+    assert_eq!(s.client.list_escrows(&3, &5).len(), 0);
+}
+
+#[test]
+fn test_status_index_follows_transitions() {
+    let env = Env::default();
+    let s = setup(&env);
+    assert_eq!(
+        ids(&env, &s.client, EscrowStatus::Locked),
+        vec![&env, 1, 2, 3]
+    );
+
+    s.client.release_funds(&2, &Address::generate(&env));
+    assert_eq!(ids(&env, &s.client, EscrowStatus::Locked), vec![&env, 1, 3]);
+    assert_eq!(ids(&env, &s.client, EscrowStatus::Released), vec![&env, 2]);
+
+    env.ledger().with_mut(|li| li.timestamp = s.deadline + 1);
+    s.client.refund(&1);
+    assert_eq!(ids(&env, &s.client, EscrowStatus::Locked), vec![&env, 3]);
+    assert_eq!(ids(&env, &s.client, EscrowStatus::Refunded), vec![&env, 1]);
+}
+
+#[test]
+fn test_locked_escrows_past_deadline_are_found() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.release_funds(&1, &Address::generate(&env));
+    env.ledger().with_mut(|li| li.timestamp = s.deadline + 1);
+
+    let now = env.ledger().timestamp();
+    let mut expired = Vec::new(&env);
+    for entry in s
+        .client
+        .list_escrows_by_status(&EscrowStatus::Locked, &0, &100)
+        .iter()
+    {
+        if entry.info.deadline < now {
+            expired.push_back(entry.bounty_id);
+        }
+    }
+    assert_eq!(expired, vec![&env, 2, 3]);
+}
+
+#[test]
+fn test_anonymous_escrows_are_listed() {
+    let env = Env::default();
+    let s = setup(&env);
+    let commitment = BytesN::from_array(&env, &[7u8; 32]);
+    s.client
+        .lock_funds_anonymous(&s.depositor, &commitment, &4, &1_000, &s.deadline);
+
+    assert_eq!(
+        ids(&env, &s.client, EscrowStatus::Locked),
+        vec![&env, 1, 2, 3, 4]
+    );
+    let entry = s.client.list_escrows(&3, &1).get(0).unwrap();
+    assert_eq!(entry.bounty_id, 4);
+    assert_eq!(entry.info.depositor, AnonymousParty::Commitment(commitment));
+}
@@ -0,0 +1,168 @@
+#![cfg(test)]
+
+use crate::{
+    BountyEscrowContract, BountyEscrowContractClient, DataKey, Error, EscrowStatus, LegacyEscrowV1,
+    LifecycleError, ESCROW_SCHEMA_VERSION,
+};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, BytesN, Env, String, Vec,
+};
+
+struct Setup<'a> {
+    client: BountyEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    contract_id: Address,
+    depositor: Address,
+    deadline: u64,
+}
+
+/// Bounty 1 locked by current code and bounty 7 written in the pre-upgrade
+/// layout, both for 1_000.
+fn setup<'a>(env: &Env) -> Setup<'a> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+
+    let depositor = Address::generate(env);
+    let sac = token::StellarAssetClient::new(env, &token_address);
+    sac.mint(&depositor, &1_000);
+    let deadline = env.ledger().timestamp() + 100;
+    client.lock_funds(&depositor, &1, &1_000, &deadline);
+
+    // Funds for the legacy escrow are already held by the contract.
+    sac.mint(&contract_id, &1_000);
+    let legacy = LegacyEscrowV1 {
+        depositor: depositor.clone(),
+        amount: 1_000,
+        remaining_amount: 1_000,
+        status: EscrowStatus::Locked,
+        deadline,
+    };
+    env.as_contract(&contract_id, || {
+        env.storage().persistent().set(&DataKey::Escrow(7), &legacy);
+        let mut index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EscrowIndex)
+            .unwrap();
+        index.push_back(7);
+        env.storage()
+            .persistent()
+            .set(&DataKey::EscrowIndex, &index);
+    });
+
+    Setup {
+        client,
+        token: token::Client::new(env, &token_address),
+        contract_id,
+        depositor,
+        deadline,
+    }
+}
+
+#[test]
+fn test_new_escrows_use_current_schema() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    assert_eq!(
+        s.client.get_escrow_schema_version(&1),
+        Some(ESCROW_SCHEMA_VERSION)
+    );
+    assert_eq!(s.client.get_escrow_schema_version(&7), Some(1));
+    assert_eq!(s.client.get_escrow_schema_version(&99), None);
+}
+
+#[test]
+fn test_legacy_escrow_migrated_on_first_read() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let escrow = s.client.get_escrow_info(&7);
+    assert_eq!(escrow.depositor, s.depositor);
+    assert_eq!(escrow.remaining_amount, 1_000);
+    assert_eq!(escrow.refund_history.len(), 0);
+    assert_eq!(
+        s.client.get_escrow_schema_version(&7),
+        Some(ESCROW_SCHEMA_VERSION)
+    );
+
+    // Side storage the old code never wrote is backfilled.
+    let locked = s
+        .client
+        .list_escrows_by_status(&EscrowStatus::Locked, &0, &10);
+    assert_eq!(locked.len(), 2);
+    assert_eq!(
+        s.client.get_depositor_summary(&s.depositor).total_locked,
+        2_000
+    );
+}
+
+#[test]
+fn test_legacy_escrow_refunds_after_migration() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    env.ledger().with_mut(|li| li.timestamp = s.deadline + 1);
+    s.client.refund(&7);
+
+    assert_eq!(s.token.balance(&s.depositor), 1_000);
+    let escrow = s.client.get_escrow_info(&7);
+    assert_eq!(escrow.status, EscrowStatus::Refunded);
+    assert_eq!(escrow.refund_history.len(), 1);
+}
+
+#[test]
+fn test_migrate_escrows_skips_current_and_missing() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    assert_eq!(s.client.migrate_escrows(&vec![&env, 1, 7, 99]), 1);
+    assert_eq!(s.client.migrate_escrows(&vec![&env, 1, 7, 99]), 0);
+    assert_eq!(s.client.get_escrow_info(&7).amount, 1_000);
+}
+
+#[test]
+fn test_upgrade_requires_full_pause() {
+    let env = Env::default();
+    let s = setup(&env);
+    let hash = BytesN::from_array(&env, &[1; 32]);
+
+    assert_eq!(
+        s.client.try_upgrade(&hash),
+        Err(Ok(LifecycleError::UpgradeRequiresPause.into()))
+    );
+
+    s.client.set_paused(
+        &Some(true),
+        &Some(true),
+        &None,
+        &Some(String::from_str(&env, "upgrade")),
+    );
+    assert_eq!(
+        s.client.try_upgrade(&hash),
+        Err(Ok(LifecycleError::UpgradeRequiresPause.into()))
+    );
+    assert_eq!(s.token.balance(&s.contract_id), 2_000);
+}
+
+#[test]
+fn test_upgrade_requires_init() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(&env, &contract_id);
+
+    assert_eq!(
+        client.try_upgrade(&BytesN::from_array(&env, &[1; 32])),
+        Err(Ok(Error::NotInitialized.into()))
+    );
+}
@@ -0,0 +1,145 @@
+#![cfg(test)]
+
+use crate::events::EscrowPauseFlagsChanged;
+use crate::{
+    BountyEscrowContract, BountyEscrowContractClient, ComplianceError, Error, LifecycleError,
+    ESCROW_PAUSE_LOCK, ESCROW_PAUSE_REFUND, ESCROW_PAUSE_RELEASE,
+};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    token, Address, Env, IntoVal, Symbol, TryFromVal, Val,
+};
+
+struct Setup<'a> {
+    client: BountyEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    depositor: Address,
+    deadline: u64,
+}
+
+/// Bounty 1 locked for 1_000 with no jurisdiction config.
+fn setup<'a>(env: &Env) -> Setup<'a> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_address).mint(&depositor, &1_000);
+    let deadline = env.ledger().timestamp() + 1_000;
+    client.lock_funds(&depositor, &1, &1_000, &deadline);
+
+    Setup {
+        client,
+        token: token::Client::new(env, &token_address),
+        depositor,
+        deadline,
+    }
+}
+
+/// The pause-change event emitted by the last call under `topic`, if any.
+fn pause_event(env: &Env, topic: Symbol) -> Option<EscrowPauseFlagsChanged> {
+    let topic: Val = topic.into_val(env);
+    env.events()
+        .all()
+        .iter()
+        .last()
+        .filter(|(_, topics, _)| {
+            topics
+                .get(0)
+                .map(|t| t.get_payload() == topic.get_payload())
+                .unwrap_or(false)
+        })
+        .map(|(_, _, data)| EscrowPauseFlagsChanged::try_from_val(env, &data).unwrap())
+}
+
+#[test]
+fn test_freeze_release_keeps_refund_open() {
+    let env = Env::default();
+    let s = setup(&env);
+    assert_eq!(s.client.get_escrow_pause_flags(&1), 0);
+
+    s.client.set_escrow_pause_flags(&1, &ESCROW_PAUSE_RELEASE);
+    let event = pause_event(&env, symbol_short!("esc_frz")).unwrap();
+    assert_eq!(event.previous_flags, 0);
+    assert_eq!(event.flags, ESCROW_PAUSE_RELEASE);
+    assert_eq!(s.client.get_escrow_pause_flags(&1), ESCROW_PAUSE_RELEASE);
+
+    assert_eq!(
+        s.client.try_release_funds(&1, &Address::generate(&env)),
+        Err(Ok(ComplianceError::JurisdictionPaused.into()))
+    );
+    env.ledger().with_mut(|li| li.timestamp = s.deadline + 1);
+    s.client.refund(&1);
+    assert_eq!(s.token.balance(&s.depositor), 1_000);
+}
+
+#[test]
+fn test_unfreeze_restores_release() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client
+        .set_escrow_pause_flags(&1, &(ESCROW_PAUSE_RELEASE | ESCROW_PAUSE_REFUND));
+
+    s.client.set_escrow_pause_flags(&1, &0);
+    let event = pause_event(&env, symbol_short!("esc_unfrz")).unwrap();
+    assert_eq!(
+        event.previous_flags,
+        ESCROW_PAUSE_RELEASE | ESCROW_PAUSE_REFUND
+    );
+    assert_eq!(event.flags, 0);
+    assert!(pause_event(&env, symbol_short!("esc_frz")).is_none());
+
+    let contributor = Address::generate(&env);
+    s.client.release_funds(&1, &contributor);
+    assert_eq!(s.token.balance(&contributor), 1_000);
+}
+
+#[test]
+fn test_freeze_refund() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.set_escrow_pause_flags(&1, &ESCROW_PAUSE_REFUND);
+
+    env.ledger().with_mut(|li| li.timestamp = s.deadline + 1);
+    assert_eq!(
+        s.client.try_refund(&1),
+        Err(Ok(ComplianceError::JurisdictionPaused.into()))
+    );
+}
+
+#[test]
+fn test_compliance_officer_can_freeze() {
+    let env = Env::default();
+    let s = setup(&env);
+    let compliance = Address::generate(&env);
+    s.client.set_compliance_admin(&compliance);
+
+    s.client.set_escrow_pause_flags(&1, &ESCROW_PAUSE_LOCK);
+    let event = pause_event(&env, symbol_short!("esc_frz")).unwrap();
+    assert_eq!(event.changed_by, compliance);
+}
+
+#[test]
+fn test_invalid_flags_and_missing_bounty() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    assert_eq!(
+        s.client.try_set_escrow_pause_flags(&1, &8),
+        Err(Ok(LifecycleError::InvalidPauseFlags.into()))
+    );
+    assert_eq!(
+        s.client
+            .try_set_escrow_pause_flags(&2, &ESCROW_PAUSE_RELEASE),
+        Err(Ok(Error::BountyNotFound.into()))
+    );
+    assert_eq!(s.client.get_escrow_pause_flags(&1), 0);
+}
@@ -0,0 +1,112 @@
+#![cfg(test)]
+
+use crate::{
+    AnonymousParty, BountyEscrowContract, BountyEscrowContractClient, Error, EscrowStatus,
+};
+use soroban_sdk::{testutils::Address as _, token, Address, BytesN, Env};
+
+struct Setup<'a> {
+    client: BountyEscrowContractClient<'a>,
+    admin: Address,
+    depositor: Address,
+    deadline: u64,
+}
+
+/// Regular escrow of 12_345 on bounty 1 and an anonymous escrow of 500 on
+/// bounty 2.
+fn setup<'a>(env: &Env) -> Setup<'a> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_address).mint(&depositor, &20_000);
+    let deadline = env.ledger().timestamp() + 1_000;
+    client.lock_funds(&depositor, &1, &12_345, &deadline);
+    let commitment = BytesN::from_array(env, &[9u8; 32]);
+    client.lock_funds_anonymous(&depositor, &commitment, &2, &500, &deadline);
+
+    Setup {
+        client,
+        admin,
+        depositor,
+        deadline,
+    }
+}
+
+#[test]
+fn test_redacted_info_hides_amount_and_depositor() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let redacted = s.client.get_escrow_info_redacted(&1);
+    assert_eq!(redacted.amount_bucket, 4);
+    assert_eq!(redacted.status, EscrowStatus::Locked);
+    assert_eq!(redacted.deadline, s.deadline);
+
+    assert_eq!(s.client.get_escrow_info_redacted(&2).amount_bucket, 2);
+    assert_eq!(
+        s.client.try_get_escrow_info_redacted(&3),
+        Err(Ok(Error::BountyNotFound.into()))
+    );
+}
+
+#[test]
+fn test_full_info_requires_authorized_viewer() {
+    let env = Env::default();
+    let s = setup(&env);
+    let reviewer = Address::generate(&env);
+
+    assert_eq!(
+        s.client.try_get_escrow_info_v2(&1, &reviewer),
+        Err(Ok(Error::Unauthorized.into()))
+    );
+
+    s.client.authorize_viewer(&1, &reviewer);
+    let info = s.client.get_escrow_info_v2(&1, &reviewer);
+    assert_eq!(info.depositor, AnonymousParty::Address(s.depositor.clone()));
+    assert_eq!(info.amount, 12_345);
+
+    s.client.revoke_viewer(&1, &reviewer);
+    assert_eq!(s.client.get_authorized_viewers(&1).len(), 0);
+    assert_eq!(
+        s.client.try_get_escrow_info_v2(&1, &reviewer),
+        Err(Ok(Error::Unauthorized.into()))
+    );
+}
+
+#[test]
+fn test_depositor_and_admin_always_see_full_info() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    assert_eq!(s.client.get_escrow_info_v2(&1, &s.depositor).amount, 12_345);
+    assert_eq!(s.client.get_escrow_info_v2(&1, &s.admin).amount, 12_345);
+}
+
+#[test]
+fn test_viewer_grant_on_anonymous_escrow() {
+    let env = Env::default();
+    let s = setup(&env);
+    let reviewer = Address::generate(&env);
+
+    // The anonymous depositor cannot be matched to an address.
+    assert_eq!(
+        s.client.try_get_escrow_info_v2(&2, &s.depositor),
+        Err(Ok(Error::Unauthorized.into()))
+    );
+
+    s.client.authorize_viewer(&2, &reviewer);
+    let info = s.client.get_escrow_info_v2(&2, &reviewer);
+    assert_eq!(
+        info.depositor,
+        AnonymousParty::Commitment(BytesN::from_array(&env, &[9u8; 32]))
+    );
+}
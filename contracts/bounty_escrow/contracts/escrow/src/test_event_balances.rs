@@ -0,0 +1,127 @@
+#![cfg(test)]
+
+use crate::events::{FundsLocked, FundsRefunded, FundsReleased, TicketClaimed, EVENT_VERSION_V3};
+use crate::{BountyEscrowContract, BountyEscrowContractClient};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    token, vec, Address, Env, IntoVal, Symbol, TryFromVal, Val, Vec,
+};
+
+struct Setup<'a> {
+    client: BountyEscrowContractClient<'a>,
+    depositor: Address,
+    deadline: u64,
+}
+
+/// Bounty 1 locked for 1_000.
+fn setup<'a>(env: &Env) -> Setup<'a> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_address).mint(&depositor, &10_000);
+    let deadline = env.ledger().timestamp() + 1_000;
+    client.lock_funds(&depositor, &1, &1_000, &deadline);
+
+    Setup {
+        client,
+        depositor,
+        deadline,
+    }
+}
+
+/// Payloads of the last call's events whose first topic is `topic`.
+fn payloads(env: &Env, topic: Symbol) -> Vec<Val> {
+    let topic: Val = topic.into_val(env);
+    let mut out = Vec::new(env);
+    for (_, topics, data) in env.events().all().iter() {
+        let matches = topics
+            .get(0)
+            .map(|t| t.get_payload() == topic.get_payload())
+            .unwrap_or(false);
+        if matches {
+            out.push_back(data);
+        }
+    }
+    out
+}
+
+fn released(env: &Env) -> Vec<(i128, i128)> {
+    let mut out = Vec::new(env);
+    for data in payloads(env, symbol_short!("f_rel")).iter() {
+        let event = FundsReleased::try_from_val(env, &data).unwrap();
+        assert_eq!(event.version, EVENT_VERSION_V3);
+        out.push_back((event.remaining_before, event.remaining_after));
+    }
+    out
+}
+
+#[test]
+fn test_lock_reports_zero_before() {
+    let env = Env::default();
+    let _s = setup(&env);
+
+    let data = payloads(&env, symbol_short!("f_lock")).last().unwrap();
+    let event = FundsLocked::try_from_val(&env, &data).unwrap();
+    assert_eq!(event.version, EVENT_VERSION_V3);
+    assert_eq!(event.remaining_before, 0);
+    assert_eq!(event.remaining_after, 1_000);
+}
+
+#[test]
+fn test_partial_then_refund_chain_balances() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.partial_release(&1, &Address::generate(&env), &300);
+    assert_eq!(released(&env), vec![&env, (1_000, 700)]);
+
+    env.ledger().with_mut(|li| li.timestamp = s.deadline + 1);
+    s.client.refund(&1);
+    let data = payloads(&env, symbol_short!("f_ref")).last().unwrap();
+    let event = FundsRefunded::try_from_val(&env, &data).unwrap();
+    assert_eq!(event.version, EVENT_VERSION_V3);
+    assert_eq!(event.remaining_before, 700);
+    assert_eq!(event.remaining_after, 0);
+    assert_eq!(event.refund_to, s.depositor);
+}
+
+#[test]
+fn test_split_release_emits_running_balance() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.release_funds_split(
+        &1,
+        &vec![&env, Address::generate(&env), Address::generate(&env)],
+        &vec![&env, 200, 800],
+    );
+    assert_eq!(released(&env), vec![&env, (1_000, 800), (800, 0)]);
+}
+
+#[test]
+fn test_ticket_claim_reports_escrow_balance() {
+    let env = Env::default();
+    let s = setup(&env);
+    let beneficiary = Address::generate(&env);
+    let ticket_id = s
+        .client
+        .issue_claim_ticket(&1, &beneficiary, &400, &(s.deadline + 1_000));
+
+    s.client.claim_with_ticket_partial(&ticket_id, &400);
+    assert_eq!(released(&env), vec![&env, (1_000, 600)]);
+    let data = payloads(&env, symbol_short!("ticket_c")).last().unwrap();
+    let event = TicketClaimed::try_from_val(&env, &data).unwrap();
+    assert_eq!(event.version, EVENT_VERSION_V3);
+    assert_eq!(event.remaining_before, 1_000);
+    assert_eq!(event.remaining_after, 600);
+}
@@ -99,7 +99,7 @@ fn test_pending_claim_blocks_refund() {
     let res = setup.escrow.try_refund(&bounty_id);
     assert!(res.is_err());
     // Error::ClaimPending is variant #22
-    assert_eq!(res.unwrap_err().unwrap(), Error::ClaimPending);
+    assert_eq!(res.unwrap_err().unwrap(), Error::ClaimPending.into());
 
     // Verify funds were NOT refunded
     let escrow = setup.escrow.get_escrow_info(&bounty_id);
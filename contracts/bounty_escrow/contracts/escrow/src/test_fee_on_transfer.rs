@@ -0,0 +1,174 @@
+#![cfg(test)]
+
+use crate::{
+    events::TransferShortfall, BountyEscrowContract, BountyEscrowContractClient, LockFundsItem,
+};
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Events},
+    vec, Address, BytesN, Env, Symbol, TryFromVal, TryIntoVal,
+};
+
+/// Token that burns 1% of every transfer, rounded down.
+#[contract]
+pub struct FeeToken;
+
+#[contractimpl]
+impl FeeToken {
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        let balance = Self::balance(env.clone(), to.clone());
+        env.storage().persistent().set(&to, &(balance + amount));
+    }
+
+    pub fn balance(env: Env, id: Address) -> i128 {
+        env.storage().persistent().get(&id).unwrap_or(0)
+    }
+
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        from.require_auth();
+        let fee = amount / 100;
+        let from_balance = Self::balance(env.clone(), from.clone());
+        env.storage()
+            .persistent()
+            .set(&from, &(from_balance - amount));
+        let to_balance = Self::balance(env.clone(), to.clone());
+        env.storage()
+            .persistent()
+            .set(&to, &(to_balance + amount - fee));
+    }
+}
+
+fn setup<'a>(env: &Env) -> (BountyEscrowContractClient<'a>, FeeTokenClient<'a>, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_id = env.register_contract(None, FeeToken);
+    client.init(&admin, &token_id);
+    (client, FeeTokenClient::new(env, &token_id), admin)
+}
+
+#[test]
+fn test_lock_stores_amount_actually_received() {
+    let env = Env::default();
+    let (client, token, _admin) = setup(&env);
+    let depositor = Address::generate(&env);
+    token.mint(&depositor, &1_000);
+
+    let deadline = env.ledger().timestamp() + 100;
+    client.lock_funds(&depositor, &1, &1_000, &deadline);
+
+    let escrow = client.get_escrow_info(&1);
+    assert_eq!(escrow.amount, 990);
+    assert_eq!(escrow.remaining_amount, 990);
+    assert_eq!(token.balance(&client.address), 990);
+}
+
+#[test]
+fn test_increase_escrow_credits_amount_actually_received() {
+    let env = Env::default();
+    let (client, token, _admin) = setup(&env);
+    let depositor = Address::generate(&env);
+    token.mint(&depositor, &1_500);
+
+    let deadline = env.ledger().timestamp() + 100;
+    client.lock_funds(&depositor, &1, &1_000, &deadline);
+    client.increase_escrow(&1, &500);
+
+    let escrow = client.get_escrow_info(&1);
+    assert_eq!(escrow.amount, 1_485);
+    assert_eq!(escrow.remaining_amount, 1_485);
+    assert_eq!(token.balance(&client.address), 1_485);
+}
+
+#[test]
+fn test_batch_lock_stores_amounts_actually_received() {
+    let env = Env::default();
+    let (client, token, _admin) = setup(&env);
+    let depositor = Address::generate(&env);
+    token.mint(&depositor, &3_000);
+
+    let deadline = env.ledger().timestamp() + 100;
+    client.batch_lock_funds(&vec![
+        &env,
+        LockFundsItem {
+            bounty_id: 1,
+            depositor: depositor.clone(),
+            amount: 1_000,
+            deadline,
+        },
+        LockFundsItem {
+            bounty_id: 2,
+            depositor: depositor.clone(),
+            amount: 2_000,
+            deadline,
+        },
+    ]);
+
+    assert_eq!(client.get_escrow_info(&1).remaining_amount, 990);
+    assert_eq!(client.get_escrow_info(&2).remaining_amount, 1_980);
+    assert_eq!(token.balance(&client.address), 2_970);
+}
+
+#[test]
+fn test_anonymous_lock_stores_amount_actually_received() {
+    let env = Env::default();
+    let (client, token, admin) = setup(&env);
+    let depositor = Address::generate(&env);
+    token.mint(&depositor, &1_000);
+
+    let deadline = env.ledger().timestamp() + 100;
+    let commitment = BytesN::from_array(&env, &[7; 32]);
+    client.lock_funds_anonymous(&depositor, &commitment, &1, &1_000, &deadline);
+
+    let escrow = client.get_escrow_info_v2(&1, &admin);
+    assert_eq!(escrow.amount, 990);
+    assert_eq!(escrow.remaining_amount, 990);
+    assert_eq!(token.balance(&client.address), 990);
+}
+
+#[test]
+fn test_lock_emits_shortfall_event() {
+    let env = Env::default();
+    let (client, token, _admin) = setup(&env);
+    let depositor = Address::generate(&env);
+    token.mint(&depositor, &1_000);
+
+    let deadline = env.ledger().timestamp() + 100;
+    client.lock_funds(&depositor, &1, &1_000, &deadline);
+
+    let expected = Symbol::new(&env, "xfer_shrt");
+    let (_, _, data) = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| {
+            topics
+                .get(0)
+                .and_then(|t| Symbol::try_from_val(&env, &t).ok())
+                == Some(expected.clone())
+        })
+        .expect("shortfall event");
+    let event: TransferShortfall = data.try_into_val(&env).unwrap();
+    assert_eq!(event.bounty_id, 1);
+    assert_eq!(event.expected, 1_000);
+    assert_eq!(event.received, 990);
+}
+
+#[test]
+fn test_release_pays_out_received_amount() {
+    let env = Env::default();
+    let (client, token, _admin) = setup(&env);
+    let depositor = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    token.mint(&depositor, &1_000);
+
+    let deadline = env.ledger().timestamp() + 100;
+    client.lock_funds(&depositor, &1, &1_000, &deadline);
+    client.release_funds(&1, &contributor);
+
+    // 990 leaves the escrow; the token keeps 1% of that transfer too.
+    assert_eq!(token.balance(&client.address), 0);
+    assert_eq!(token.balance(&contributor), 981);
+}
@@ -0,0 +1,99 @@
+#![cfg(test)]
+
+use crate::{BountyEscrowContract, BountyEscrowContractClient, Error};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, testutils::Address as _, token, Address, Env,
+};
+
+#[contract]
+pub struct MockCore;
+
+#[contractimpl]
+impl MockCore {
+    pub fn set_halted(env: Env, halted: bool) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("halted"), &halted);
+    }
+
+    pub fn is_globally_halted(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("halted"))
+            .unwrap_or(false)
+    }
+}
+
+fn setup<'a>(
+    env: &Env,
+) -> (
+    BountyEscrowContractClient<'a>,
+    MockCoreClient<'a>,
+    token::Client<'a>,
+    Address,
+) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_address).mint(&depositor, &10_000);
+
+    let core_id = env.register_contract(None, MockCore);
+    (
+        client,
+        MockCoreClient::new(env, &core_id),
+        token::Client::new(env, &token_address),
+        depositor,
+    )
+}
+
+#[test]
+fn test_global_halt_blocks_lock_and_release() {
+    let env = Env::default();
+    let (client, core, token, depositor) = setup(&env);
+    client.set_core_contract(&Some(core.address.clone()));
+    let deadline = env.ledger().timestamp() + 1_000;
+
+    client.lock_funds(&depositor, &1, &1_000, &deadline);
+
+    core.set_halted(&true);
+    assert!(client.is_globally_halted());
+    assert_eq!(
+        client.try_lock_funds(&depositor, &2, &1_000, &deadline),
+        Err(Ok(Error::FundsPaused.into()))
+    );
+    let contributor = Address::generate(&env);
+    assert!(client.try_release_funds(&1, &contributor).is_err());
+
+    core.set_halted(&false);
+    client.release_funds(&1, &contributor);
+    assert_eq!(token.balance(&contributor), 1_000);
+}
+
+#[test]
+fn test_unreachable_core_fails_closed_until_unlinked() {
+    let env = Env::default();
+    let (client, _core, _token, depositor) = setup(&env);
+    let deadline = env.ledger().timestamp() + 1_000;
+
+    assert_eq!(client.get_core_contract(), None);
+    assert!(!client.is_globally_halted());
+
+    client.set_core_contract(&Some(Address::generate(&env)));
+    assert!(client.is_globally_halted());
+    assert!(client
+        .try_lock_funds(&depositor, &1, &1_000, &deadline)
+        .is_err());
+
+    client.set_core_contract(&None);
+    assert!(!client.is_globally_halted());
+    client.lock_funds(&depositor, &1, &1_000, &deadline);
+}
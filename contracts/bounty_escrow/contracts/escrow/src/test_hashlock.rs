@@ -0,0 +1,107 @@
+#![cfg(test)]
+
+use crate::{BountyEscrowContract, BountyEscrowContractClient, ClaimError, Error, EscrowStatus};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Bytes, BytesN, Env,
+};
+
+fn setup<'a>(
+    env: &Env,
+) -> (
+    BountyEscrowContractClient<'a>,
+    token::Client<'a>,
+    Address,
+    Bytes,
+    u64,
+) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_address).mint(&depositor, &10_000);
+
+    let preimage = Bytes::from_slice(env, b"grainlify-htlc-secret");
+    let hashlock: BytesN<32> = env.crypto().sha256(&preimage).into();
+    let deadline = env.ledger().timestamp() + 1_000;
+    client.lock_funds_hashlocked(&depositor, &1, &1_000, &deadline, &hashlock);
+
+    (
+        client,
+        token::Client::new(env, &token_address),
+        depositor,
+        preimage,
+        deadline,
+    )
+}
+
+#[test]
+fn test_claim_with_valid_preimage_releases_to_claimant() {
+    let env = Env::default();
+    let (client, token, _depositor, preimage, _deadline) = setup(&env);
+    let claimant = Address::generate(&env);
+
+    client.claim_with_preimage(&1, &claimant, &preimage);
+
+    assert_eq!(token.balance(&claimant), 1_000);
+    let escrow = client.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Released);
+    assert_eq!(escrow.remaining_amount, 0);
+}
+
+#[test]
+fn test_claim_with_wrong_preimage_fails() {
+    let env = Env::default();
+    let (client, token, _depositor, _preimage, _deadline) = setup(&env);
+    let claimant = Address::generate(&env);
+
+    assert_eq!(
+        client.try_claim_with_preimage(&1, &claimant, &Bytes::from_slice(&env, b"guess")),
+        Err(Ok(ClaimError::InvalidPreimage.into()))
+    );
+    assert_eq!(token.balance(&client.address), 1_000);
+}
+
+#[test]
+fn test_claim_after_deadline_fails_and_refund_succeeds() {
+    let env = Env::default();
+    let (client, token, depositor, preimage, deadline) = setup(&env);
+    let claimant = Address::generate(&env);
+
+    env.ledger().set_timestamp(deadline + 1);
+    assert_eq!(
+        client.try_claim_with_preimage(&1, &claimant, &preimage),
+        Err(Ok(ClaimError::HashlockExpired.into()))
+    );
+
+    client.refund(&1);
+    assert_eq!(token.balance(&depositor), 10_000);
+}
+
+#[test]
+fn test_claim_requires_hashlocked_bounty() {
+    let env = Env::default();
+    let (client, _token, depositor, preimage, deadline) = setup(&env);
+    let claimant = Address::generate(&env);
+
+    client.lock_funds(&depositor, &2, &500, &deadline);
+    assert_eq!(client.get_hashlock(&2), None);
+    assert_eq!(
+        client.try_claim_with_preimage(&2, &claimant, &preimage),
+        Err(Ok(ClaimError::HashlockNotSet.into()))
+    );
+
+    client.claim_with_preimage(&1, &claimant, &preimage);
+    assert_eq!(
+        client.try_claim_with_preimage(&1, &claimant, &preimage),
+        Err(Ok(Error::FundsNotLocked.into()))
+    );
+}
@@ -0,0 +1,140 @@
+#![cfg(test)]
+
+use crate::{
+    BountyEscrowContract, BountyEscrowContractClient, ComplianceError, IdentityTier, LockFundsItem,
+};
+use soroban_sdk::{testutils::Address as _, token, vec, Address, BytesN, Env};
+
+struct Setup<'a> {
+    client: BountyEscrowContractClient<'a>,
+    depositor: Address,
+    deadline: u64,
+}
+
+/// Unverified depositors may lock at most 500 per bounty and 800 in total;
+/// Full depositors are unrestricted.
+fn setup<'a>(env: &Env) -> Setup<'a> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+    client.set_tier_limit(&IdentityTier::Unverified, &500, &800);
+
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_address).mint(&depositor, &10_000);
+
+    Setup {
+        client,
+        depositor,
+        deadline: env.ledger().timestamp() + 1_000,
+    }
+}
+
+#[test]
+fn test_unverified_single_lock_limit() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    assert_eq!(
+        s.client.get_identity_tier(&s.depositor),
+        IdentityTier::Unverified
+    );
+    assert_eq!(
+        s.client.try_lock_funds(&s.depositor, &1, &501, &s.deadline),
+        Err(Ok(ComplianceError::TierLimitExceeded.into()))
+    );
+    s.client.lock_funds(&s.depositor, &1, &500, &s.deadline);
+}
+
+#[test]
+fn test_exposure_limit_spans_lock_and_increase() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.lock_funds(&s.depositor, &1, &400, &s.deadline);
+    s.client.lock_funds(&s.depositor, &2, &300, &s.deadline);
+    assert_eq!(s.client.get_depositor_exposure(&s.depositor), 700);
+
+    assert_eq!(
+        s.client.try_lock_funds(&s.depositor, &3, &200, &s.deadline),
+        Err(Ok(ComplianceError::ExposureLimitExceeded.into()))
+    );
+    assert_eq!(
+        s.client.try_increase_escrow(&2, &101),
+        Err(Ok(ComplianceError::ExposureLimitExceeded.into()))
+    );
+    assert_eq!(
+        s.client.try_increase_escrow(&1, &150),
+        Err(Ok(ComplianceError::TierLimitExceeded.into()))
+    );
+    s.client.increase_escrow(&2, &100);
+
+    // Released escrows no longer count towards exposure.
+    s.client.release_funds(&1, &Address::generate(&env));
+    s.client.lock_funds(&s.depositor, &3, &400, &s.deadline);
+}
+
+#[test]
+fn test_higher_tier_lifts_limits() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client
+        .set_identity_tier(&s.depositor, &IdentityTier::Full);
+
+    assert_eq!(s.client.get_tier_limit(&IdentityTier::Full), None);
+    s.client.lock_funds(&s.depositor, &1, &5_000, &s.deadline);
+}
+
+#[test]
+fn test_batch_lock_counts_exposure_across_items() {
+    let env = Env::default();
+    let s = setup(&env);
+    let item = |bounty_id: u64, amount: i128| LockFundsItem {
+        bounty_id,
+        depositor: s.depositor.clone(),
+        amount,
+        deadline: s.deadline,
+    };
+
+    assert_eq!(
+        s.client.try_batch_lock_funds(&vec![&env, item(1, 501)]),
+        Err(Ok(ComplianceError::TierLimitExceeded.into()))
+    );
+    // Each lock is within the single-lock limit, but together they exceed
+    // the exposure limit.
+    assert_eq!(
+        s.client
+            .try_batch_lock_funds(&vec![&env, item(1, 400), item(2, 450)]),
+        Err(Ok(ComplianceError::ExposureLimitExceeded.into()))
+    );
+    s.client
+        .batch_lock_funds(&vec![&env, item(1, 400), item(2, 400)]);
+    assert_eq!(s.client.get_depositor_exposure(&s.depositor), 800);
+}
+
+#[test]
+fn test_anonymous_lock_respects_tier_limits() {
+    let env = Env::default();
+    let s = setup(&env);
+    let commitment = BytesN::from_array(&env, &[5; 32]);
+
+    assert_eq!(
+        s.client
+            .try_lock_funds_anonymous(&s.depositor, &commitment, &1, &501, &s.deadline),
+        Err(Ok(ComplianceError::TierLimitExceeded.into()))
+    );
+    s.client.lock_funds(&s.depositor, &2, &500, &s.deadline);
+    assert_eq!(
+        s.client
+            .try_lock_funds_anonymous(&s.depositor, &commitment, &1, &400, &s.deadline),
+        Err(Ok(ComplianceError::ExposureLimitExceeded.into()))
+    );
+    s.client
+        .lock_funds_anonymous(&s.depositor, &commitment, &1, &300, &s.deadline);
+}
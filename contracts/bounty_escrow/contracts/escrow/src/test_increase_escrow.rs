@@ -0,0 +1,139 @@
+#![cfg(test)]
+
+use crate::{
+    BountyEscrowContract, BountyEscrowContractClient, ComplianceError, Error,
+    EscrowJurisdictionConfig, EscrowStatus, OptionalJurisdiction, ParticipantFilterMode,
+    ESCROW_PAUSE_LOCK,
+};
+use soroban_sdk::{testutils::Address as _, token, Address, Env};
+
+fn setup<'a>(
+    env: &Env,
+) -> (
+    BountyEscrowContractClient<'a>,
+    token::Client<'a>,
+    Address,
+    Address,
+) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_address).mint(&depositor, &10_000);
+    let deadline = env.ledger().timestamp() + 1_000;
+    client.lock_funds(&depositor, &1, &1_000, &deadline);
+
+    (
+        client,
+        token::Client::new(env, &token_address),
+        admin,
+        depositor,
+    )
+}
+
+#[test]
+fn test_increase_escrow_raises_amounts() {
+    let env = Env::default();
+    let (client, token, _admin, depositor) = setup(&env);
+    let contributor = Address::generate(&env);
+
+    client.partial_release(&1, &contributor, &400);
+    client.increase_escrow(&1, &500);
+
+    let escrow = client.get_escrow_info(&1);
+    assert_eq!(escrow.amount, 1_500);
+    assert_eq!(escrow.remaining_amount, 1_100);
+    assert_eq!(escrow.status, EscrowStatus::Locked);
+    assert_eq!(token.balance(&depositor), 8_500);
+    assert_eq!(token.balance(&client.address), 1_100);
+}
+
+#[test]
+fn test_increase_escrow_checks_max_against_new_total() {
+    let env = Env::default();
+    let (client, _token, admin, _depositor) = setup(&env);
+    client.set_amount_policy(&admin, &1, &1_200);
+
+    assert_eq!(
+        client.try_increase_escrow(&1, &201),
+        Err(Ok(Error::AmountAboveMaximum.into()))
+    );
+    client.increase_escrow(&1, &200);
+    assert_eq!(client.get_escrow_info(&1).amount, 1_200);
+}
+
+#[test]
+fn test_increase_escrow_applies_jurisdiction_limit_and_pause() {
+    let env = Env::default();
+    let (client, _token, _admin, depositor) = setup(&env);
+    let deadline = env.ledger().timestamp() + 1_000;
+    client.lock_funds_with_jurisdiction(
+        &depositor,
+        &2,
+        &1_000,
+        &deadline,
+        &OptionalJurisdiction::Some(EscrowJurisdictionConfig {
+            tag: None,
+            requires_kyc: false,
+            enforce_identity_limits: false,
+            lock_paused: false,
+            release_paused: false,
+            refund_paused: false,
+            max_lock_amount: Some(1_500),
+        }),
+    );
+
+    assert_eq!(
+        client.try_increase_escrow(&2, &501),
+        Err(Ok(ComplianceError::JurisdictionLimitExceeded.into()))
+    );
+    client.increase_escrow(&2, &500);
+
+    client.set_escrow_pause_flags(&1, &ESCROW_PAUSE_LOCK);
+    assert_eq!(
+        client.try_increase_escrow(&1, &100),
+        Err(Ok(ComplianceError::JurisdictionPaused.into()))
+    );
+}
+
+#[test]
+fn test_increase_escrow_rejects_blocked_depositor() {
+    let env = Env::default();
+    let (client, _token, _admin, depositor) = setup(&env);
+    client.set_filter_mode(&ParticipantFilterMode::BlocklistOnly);
+    client.set_blocklist_entry(&depositor, &true);
+
+    assert_eq!(
+        client.try_increase_escrow(&1, &100),
+        Err(Ok(Error::ParticipantBlocked.into()))
+    );
+}
+
+#[test]
+fn test_increase_escrow_requires_locked_bounty() {
+    let env = Env::default();
+    let (client, _token, _admin, _depositor) = setup(&env);
+
+    assert_eq!(
+        client.try_increase_escrow(&1, &0),
+        Err(Ok(Error::InvalidAmount.into()))
+    );
+    assert_eq!(
+        client.try_increase_escrow(&2, &100),
+        Err(Ok(Error::BountyNotFound.into()))
+    );
+
+    client.release_funds(&1, &Address::generate(&env));
+    assert_eq!(
+        client.try_increase_escrow(&1, &100),
+        Err(Ok(Error::FundsNotLocked.into()))
+    );
+}
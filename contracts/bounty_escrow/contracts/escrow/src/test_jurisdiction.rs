@@ -0,0 +1,240 @@
+#![cfg(test)]
+
+use crate::{
+    BountyEscrowContract, BountyEscrowContractClient, ComplianceError, EscrowJurisdictionConfig,
+    IdentityTier, OptionalJurisdiction,
+};
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    token, vec, Address, Env, String, Symbol, TryFromVal,
+};
+
+struct Setup<'a> {
+    client: BountyEscrowContractClient<'a>,
+    depositor: Address,
+    deadline: u64,
+}
+
+fn setup<'a>(env: &Env) -> Setup<'a> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_address).mint(&depositor, &100_000);
+    client.register_jurisdiction_tag(&String::from_str(env, "EU-only"));
+
+    Setup {
+        client,
+        depositor,
+        deadline: env.ledger().timestamp() + 1_000,
+    }
+}
+
+fn config(env: &Env) -> EscrowJurisdictionConfig {
+    EscrowJurisdictionConfig {
+        tag: Some(String::from_str(env, "EU-only")),
+        requires_kyc: false,
+        enforce_identity_limits: true,
+        lock_paused: false,
+        release_paused: false,
+        refund_paused: false,
+        max_lock_amount: Some(20_000),
+    }
+}
+
+fn has_juris_event(env: &Env) -> bool {
+    let expected = Symbol::new(env, "juris");
+    env.events().all().iter().any(|(_, topics, _)| {
+        topics
+            .get(0)
+            .and_then(|t| Symbol::try_from_val(env, &t).ok())
+            == Some(expected.clone())
+    })
+}
+
+#[test]
+fn test_generic_escrow_has_no_jurisdiction() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.lock_funds(&s.depositor, &1, &1_000, &s.deadline);
+
+    assert_eq!(
+        s.client.get_escrow_jurisdiction(&1),
+        OptionalJurisdiction::None
+    );
+}
+
+#[test]
+fn test_tagged_lock_stores_config_and_emits_event() {
+    let env = Env::default();
+    let s = setup(&env);
+    let cfg = config(&env);
+
+    s.client.lock_funds_with_jurisdiction(
+        &s.depositor,
+        &1,
+        &10_000,
+        &s.deadline,
+        &OptionalJurisdiction::Some(cfg.clone()),
+    );
+    assert!(has_juris_event(&env));
+    assert_eq!(
+        s.client.get_escrow_jurisdiction(&1),
+        OptionalJurisdiction::Some(cfg)
+    );
+
+    s.client.release_funds(&1, &Address::generate(&env));
+    assert!(has_juris_event(&env));
+}
+
+#[test]
+fn test_lock_rules() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let mut paused = config(&env);
+    paused.lock_paused = true;
+    assert_eq!(
+        s.client.try_lock_funds_with_jurisdiction(
+            &s.depositor,
+            &1,
+            &10_000,
+            &s.deadline,
+            &OptionalJurisdiction::Some(paused),
+        ),
+        Err(Ok(ComplianceError::JurisdictionPaused.into()))
+    );
+
+    assert_eq!(
+        s.client.try_lock_funds_with_jurisdiction(
+            &s.depositor,
+            &1,
+            &20_001,
+            &s.deadline,
+            &OptionalJurisdiction::Some(config(&env)),
+        ),
+        Err(Ok(ComplianceError::JurisdictionLimitExceeded.into()))
+    );
+
+    let mut kyc = config(&env);
+    kyc.requires_kyc = true;
+    let kyc = OptionalJurisdiction::Some(kyc);
+    assert_eq!(
+        s.client
+            .try_lock_funds_with_jurisdiction(&s.depositor, &1, &10_000, &s.deadline, &kyc),
+        Err(Ok(ComplianceError::JurisdictionKycRequired.into()))
+    );
+    s.client
+        .set_identity_tier(&s.depositor, &IdentityTier::Basic);
+    s.client
+        .lock_funds_with_jurisdiction(&s.depositor, &1, &10_000, &s.deadline, &kyc);
+}
+
+#[test]
+fn test_jurisdiction_can_skip_identity_limits() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client
+        .set_tier_limit(&IdentityTier::Unverified, &5_000, &0);
+
+    assert_eq!(
+        s.client.try_lock_funds_with_jurisdiction(
+            &s.depositor,
+            &1,
+            &10_000,
+            &s.deadline,
+            &OptionalJurisdiction::Some(config(&env)),
+        ),
+        Err(Ok(ComplianceError::TierLimitExceeded.into()))
+    );
+
+    let mut relaxed = config(&env);
+    relaxed.enforce_identity_limits = false;
+    s.client.lock_funds_with_jurisdiction(
+        &s.depositor,
+        &1,
+        &10_000,
+        &s.deadline,
+        &OptionalJurisdiction::Some(relaxed),
+    );
+}
+
+#[test]
+fn test_release_and_refund_pauses() {
+    let env = Env::default();
+    let s = setup(&env);
+    let mut cfg = config(&env);
+    cfg.release_paused = true;
+    cfg.refund_paused = true;
+    s.client.lock_funds_with_jurisdiction(
+        &s.depositor,
+        &1,
+        &10_000,
+        &s.deadline,
+        &OptionalJurisdiction::Some(cfg),
+    );
+
+    assert_eq!(
+        s.client.try_release_funds(&1, &Address::generate(&env)),
+        Err(Ok(ComplianceError::JurisdictionPaused.into()))
+    );
+    env.ledger().with_mut(|li| li.timestamp = s.deadline + 1);
+    assert_eq!(
+        s.client.try_refund(&1),
+        Err(Ok(ComplianceError::JurisdictionPaused.into()))
+    );
+}
+
+#[test]
+fn test_unregistered_tag_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+    let mut cfg = config(&env);
+    cfg.tag = Some(String::from_str(&env, "eu_only"));
+    let cfg = OptionalJurisdiction::Some(cfg);
+
+    assert_eq!(
+        s.client
+            .try_lock_funds_with_jurisdiction(&s.depositor, &1, &10_000, &s.deadline, &cfg),
+        Err(Ok(ComplianceError::JurisdictionTagNotRegistered.into()))
+    );
+    s.client
+        .register_jurisdiction_tag(&String::from_str(&env, "eu_only"));
+    s.client
+        .lock_funds_with_jurisdiction(&s.depositor, &1, &10_000, &s.deadline, &cfg);
+}
+
+#[test]
+fn test_tag_registry() {
+    let env = Env::default();
+    let s = setup(&env);
+    let us = String::from_str(&env, "US-only");
+
+    s.client.register_jurisdiction_tag(&us);
+    s.client.register_jurisdiction_tag(&us);
+    assert_eq!(
+        s.client.list_jurisdiction_tags(),
+        vec![&env, String::from_str(&env, "EU-only"), us]
+    );
+
+    assert_eq!(
+        s.client
+            .try_register_jurisdiction_tag(&String::from_str(&env, "")),
+        Err(Ok(ComplianceError::InvalidJurisdictionTag.into()))
+    );
+    assert_eq!(
+        s.client.try_register_jurisdiction_tag(&String::from_str(
+            &env,
+            "a-tag-that-is-longer-than-32-bytes"
+        )),
+        Err(Ok(ComplianceError::InvalidJurisdictionTag.into()))
+    );
+}
@@ -0,0 +1,94 @@
+#![cfg(test)]
+
+use crate::{BountyEscrowContract, BountyEscrowContractClient, ComplianceError};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env,
+};
+
+struct Setup<'a> {
+    client: BountyEscrowContractClient<'a>,
+    sac: token::StellarAssetClient<'a>,
+    depositor: Address,
+    deadline: u64,
+}
+
+/// At most 2 locks and 1_000 locked value per depositor per hour.
+fn setup<'a>(env: &Env) -> Setup<'a> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+    client.set_lock_rate_limit(&3_600, &2, &1_000);
+
+    let sac = token::StellarAssetClient::new(env, &token_address);
+    let depositor = Address::generate(env);
+    sac.mint(&depositor, &10_000);
+
+    Setup {
+        client,
+        sac,
+        depositor,
+        deadline: 100_000,
+    }
+}
+
+#[test]
+fn test_lock_count_limited_per_window() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.lock_funds(&s.depositor, &1, &100, &s.deadline);
+    s.client.lock_funds(&s.depositor, &2, &100, &s.deadline);
+    assert_eq!(
+        s.client.try_lock_funds(&s.depositor, &3, &100, &s.deadline),
+        Err(Ok(ComplianceError::RateLimited.into()))
+    );
+
+    let window = s.client.get_lock_window(&s.depositor).unwrap();
+    assert_eq!(window.lock_count, 2);
+    assert_eq!(window.locked_value, 200);
+
+    // A new window opens once the old one has elapsed.
+    env.ledger().with_mut(|li| li.timestamp = 3_600);
+    s.client.lock_funds(&s.depositor, &3, &100, &s.deadline);
+    assert_eq!(
+        s.client.get_lock_window(&s.depositor).unwrap().lock_count,
+        1
+    );
+}
+
+#[test]
+fn test_locked_value_limited_per_window() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.lock_funds(&s.depositor, &1, &800, &s.deadline);
+    assert_eq!(
+        s.client.try_lock_funds(&s.depositor, &2, &201, &s.deadline),
+        Err(Ok(ComplianceError::RateLimited.into()))
+    );
+    s.client.lock_funds(&s.depositor, &2, &200, &s.deadline);
+}
+
+#[test]
+fn test_limits_are_per_depositor_and_clearable() {
+    let env = Env::default();
+    let s = setup(&env);
+    let other = Address::generate(&env);
+    s.sac.mint(&other, &1_000);
+    s.client.lock_funds(&s.depositor, &1, &1_000, &s.deadline);
+
+    // Another depositor has its own window.
+    s.client.lock_funds(&other, &2, &1_000, &s.deadline);
+
+    s.client.set_lock_rate_limit(&0, &0, &0);
+    assert_eq!(s.client.get_lock_rate_limit(), None);
+    s.client.lock_funds(&s.depositor, &3, &1_000, &s.deadline);
+}
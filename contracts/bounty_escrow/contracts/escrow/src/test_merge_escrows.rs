@@ -0,0 +1,149 @@
+#![cfg(test)]
+
+use crate::{
+    BountyEscrowContract, BountyEscrowContractClient, Error, EscrowJurisdictionConfig,
+    EscrowStatus, LifecycleError, OptionalJurisdiction,
+};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env,
+};
+
+struct Setup<'a> {
+    client: BountyEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    sac: token::StellarAssetClient<'a>,
+    depositor: Address,
+    deadline: u64,
+}
+
+/// Bounties 1 (600, deadline) and 2 (400, deadline + 500) by one depositor.
+fn setup<'a>(env: &Env) -> Setup<'a> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+
+    let depositor = Address::generate(env);
+    let sac = token::StellarAssetClient::new(env, &token_address);
+    sac.mint(&depositor, &1_000);
+    let deadline = env.ledger().timestamp() + 1_000;
+    client.lock_funds(&depositor, &1, &600, &deadline);
+    client.lock_funds(&depositor, &2, &400, &(deadline + 500));
+
+    Setup {
+        client,
+        token: token::Client::new(env, &token_address),
+        sac,
+        depositor,
+        deadline,
+    }
+}
+
+#[test]
+fn test_merge_sums_and_takes_later_deadline() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.merge_escrows(&1, &2);
+    let target = s.client.get_escrow_info(&1);
+    assert_eq!((target.amount, target.remaining_amount), (1_000, 1_000));
+    assert_eq!(target.deadline, s.deadline + 500);
+    let source = s.client.get_escrow_info(&2);
+    assert_eq!((source.amount, source.remaining_amount), (0, 0));
+    assert_eq!(source.status, EscrowStatus::Released);
+
+    let summary = s.client.get_depositor_summary(&s.depositor);
+    assert_eq!(summary.active_escrows, 1);
+    assert_eq!(summary.total_locked, 1_000);
+    assert_eq!(summary.total_released, 0);
+
+    let contributor = Address::generate(&env);
+    s.client.release_funds(&1, &contributor);
+    assert_eq!(s.token.balance(&contributor), 1_000);
+}
+
+#[test]
+fn test_merge_moves_only_remaining() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.partial_release(&2, &Address::generate(&env), &100);
+
+    s.client.merge_escrows(&1, &2);
+    let target = s.client.get_escrow_info(&1);
+    assert_eq!((target.amount, target.remaining_amount), (900, 900));
+    let source = s.client.get_escrow_info(&2);
+    assert_eq!((source.amount, source.remaining_amount), (100, 0));
+    assert_eq!(
+        s.client.get_depositor_summary(&s.depositor).total_released,
+        100
+    );
+}
+
+#[test]
+fn test_merge_requires_same_depositor() {
+    let env = Env::default();
+    let s = setup(&env);
+    let other = Address::generate(&env);
+    s.sac.mint(&other, &100);
+    s.client.lock_funds(&other, &3, &100, &s.deadline);
+
+    assert_eq!(
+        s.client.try_merge_escrows(&1, &3),
+        Err(Ok(LifecycleError::IncompatibleEscrows.into()))
+    );
+    assert_eq!(
+        s.client.try_merge_escrows(&1, &1),
+        Err(Ok(LifecycleError::IncompatibleEscrows.into()))
+    );
+}
+
+#[test]
+fn test_merge_requires_same_jurisdiction() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.sac.mint(&s.depositor, &100);
+    s.client.lock_funds_with_jurisdiction(
+        &s.depositor,
+        &3,
+        &100,
+        &s.deadline,
+        &OptionalJurisdiction::Some(EscrowJurisdictionConfig {
+            tag: None,
+            requires_kyc: false,
+            enforce_identity_limits: true,
+            lock_paused: false,
+            release_paused: true,
+            refund_paused: false,
+            max_lock_amount: None,
+        }),
+    );
+
+    assert_eq!(
+        s.client.try_merge_escrows(&1, &3),
+        Err(Ok(LifecycleError::IncompatibleEscrows.into()))
+    );
+}
+
+#[test]
+fn test_merge_requires_locked_escrows() {
+    let env = Env::default();
+    let s = setup(&env);
+    env.ledger().with_mut(|li| li.timestamp = s.deadline + 1);
+    s.client.refund(&1);
+
+    assert_eq!(
+        s.client.try_merge_escrows(&2, &1),
+        Err(Ok(Error::FundsNotLocked.into()))
+    );
+    assert_eq!(
+        s.client.try_merge_escrows(&2, &9),
+        Err(Ok(Error::BountyNotFound.into()))
+    );
+}
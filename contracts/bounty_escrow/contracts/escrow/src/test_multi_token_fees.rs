@@ -128,7 +128,7 @@ fn test_set_token_fee_config_invalid_lock_rate_rejected() {
         &s.fee_recipient,
         &true,
     );
-    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidFeeRate);
+    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidFeeRate.into());
 }
 
 #[test]
@@ -137,7 +137,7 @@ fn test_set_token_fee_config_invalid_release_rate_rejected() {
     let result =
         s.client
             .try_set_token_fee_config(&s.token_id, &0, &10_001, &s.fee_recipient, &true);
-    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidFeeRate);
+    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidFeeRate.into());
 }
 
 #[test]
@@ -263,7 +263,7 @@ fn test_fee_cannot_consume_entire_principal() {
     let result = s.client.try_lock_funds(&s.depositor, &1, &1, &s.deadline());
     assert_eq!(
         result.unwrap_err().unwrap(),
-        Error::InvalidAmount,
+        Error::InvalidAmount.into(),
         "must reject when net_amount <= 0"
     );
 }
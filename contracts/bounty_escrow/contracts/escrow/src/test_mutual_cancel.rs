@@ -0,0 +1,117 @@
+#![cfg(test)]
+
+use crate::{
+    BountyEscrowContract, BountyEscrowContractClient, Error, EscrowStatus, LifecycleError,
+};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env,
+};
+
+fn setup<'a>(
+    env: &Env,
+) -> (
+    BountyEscrowContractClient<'a>,
+    token::Client<'a>,
+    Address,
+    Address,
+) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_address).mint(&depositor, &10_000);
+    let deadline = env.ledger().timestamp() + 30 * 24 * 60 * 60;
+    client.lock_funds(&depositor, &1, &1_000, &deadline);
+
+    (
+        client,
+        token::Client::new(env, &token_address),
+        admin,
+        depositor,
+    )
+}
+
+#[test]
+fn test_assigned_contributor_confirms_early_refund() {
+    let env = Env::default();
+    let (client, token, admin, depositor) = setup(&env);
+    let contributor = Address::generate(&env);
+    client.assign_contributor(&1, &contributor);
+
+    client.propose_cancel(&1);
+    // The admin cannot stand in for an assigned contributor.
+    assert_eq!(
+        client.try_confirm_cancel(&1, &admin),
+        Err(Ok(Error::Unauthorized.into()))
+    );
+
+    client.confirm_cancel(&1, &contributor);
+    assert_eq!(token.balance(&depositor), 10_000);
+    let escrow = client.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Refunded);
+    assert_eq!(escrow.remaining_amount, 0);
+    assert_eq!(escrow.refund_history.len(), 1);
+    assert_eq!(client.get_cancel_proposal(&1), None);
+}
+
+#[test]
+fn test_admin_confirms_when_no_contributor_assigned() {
+    let env = Env::default();
+    let (client, token, admin, depositor) = setup(&env);
+
+    client.propose_cancel(&1);
+    client.confirm_cancel(&1, &admin);
+    assert_eq!(token.balance(&depositor), 10_000);
+}
+
+#[test]
+fn test_expired_proposal_cannot_be_confirmed() {
+    let env = Env::default();
+    let (client, _token, admin, _depositor) = setup(&env);
+
+    let proposal = client.propose_cancel(&1);
+    env.ledger()
+        .with_mut(|li| li.timestamp = proposal.expires_at + 1);
+    assert_eq!(
+        client.try_confirm_cancel(&1, &admin),
+        Err(Ok(LifecycleError::CancelProposalExpired.into()))
+    );
+    assert_eq!(client.get_escrow_info(&1).status, EscrowStatus::Locked);
+}
+
+#[test]
+fn test_withdrawn_proposal_cannot_be_confirmed() {
+    let env = Env::default();
+    let (client, _token, admin, _depositor) = setup(&env);
+
+    assert_eq!(
+        client.try_confirm_cancel(&1, &admin),
+        Err(Ok(LifecycleError::CancelProposalNotFound.into()))
+    );
+    client.propose_cancel(&1);
+    client.withdraw_cancel(&1);
+    assert_eq!(
+        client.try_confirm_cancel(&1, &admin),
+        Err(Ok(LifecycleError::CancelProposalNotFound.into()))
+    );
+}
+
+#[test]
+fn test_cancel_after_partial_release_refunds_remainder() {
+    let env = Env::default();
+    let (client, token, admin, depositor) = setup(&env);
+    client.partial_release(&1, &Address::generate(&env), &400);
+
+    client.propose_cancel(&1);
+    client.confirm_cancel(&1, &admin);
+    assert_eq!(token.balance(&depositor), 9_600);
+}
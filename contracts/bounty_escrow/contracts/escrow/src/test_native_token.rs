@@ -0,0 +1,70 @@
+#![cfg(test)]
+
+use crate::{BountyEscrowContract, BountyEscrowContractClient, Error, LifecycleError};
+use soroban_sdk::{testutils::Address as _, token, Address, Bytes, Env};
+
+fn setup<'a>(env: &Env) -> (BountyEscrowContractClient<'a>, token::Client<'a>) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+
+    (client, token::Client::new(env, &token_address))
+}
+
+#[test]
+fn test_get_native_token_is_native_sac() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let native_sac = env
+        .deployer()
+        .with_stellar_asset(Bytes::from_array(&env, &[0, 0, 0, 0]))
+        .deploy();
+
+    assert_eq!(client.get_native_token(), native_sac);
+    assert_ne!(client.get_native_token(), token.address);
+}
+
+#[test]
+fn test_native_token_bypasses_allowlist() {
+    let env = Env::default();
+    let (client, _token) = setup(&env);
+    let depositor = Address::generate(&env);
+    let other = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 1_000;
+
+    // An unlisted token is rejected before any transfer is attempted...
+    assert_eq!(
+        client.try_lock_funds_with_token(&depositor, &1, &other, &100, &deadline),
+        Err(Ok(LifecycleError::TokenNotAllowed.into()))
+    );
+    // ...while native XLM passes validation and only fails on the transfer
+    // itself, since the depositor holds no XLM.
+    assert!(client
+        .try_lock_funds_native(&depositor, &1, &100, &deadline)
+        .is_err_and(|e| e != Ok(LifecycleError::TokenNotAllowed.into())));
+}
+
+#[test]
+fn test_normalize_amount_for_native_and_sac() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let native = client.get_native_token();
+
+    assert_eq!(client.normalize_amount(&native, &25, &0), 250_000_000);
+    assert_eq!(
+        client.normalize_amount(&token.address, &25, &0),
+        250_000_000
+    );
+    assert_eq!(client.normalize_amount(&native, &1_500, &10), 1);
+    assert_eq!(
+        client.try_normalize_amount(&native, &i128::MAX, &0),
+        Err(Ok(Error::InvalidAmount.into()))
+    );
+}
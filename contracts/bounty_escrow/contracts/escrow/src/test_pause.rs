@@ -503,9 +503,12 @@ fn test_rbac_emergency_withdraw_emits_event() {
                 escrow_client.address.clone(),
                 (symbol_short!("em_wtd"),).into_val(&env),
                 events::EmergencyWithdrawEvent {
+                    version: events::EVENT_VERSION_V3,
                     admin: admin.clone(),
                     recipient: target.clone(),
                     amount: 500i128,
+                    remaining_before: 500i128,
+                    remaining_after: 0,
                     timestamp: env.ledger().timestamp(),
                 }
                 .into_val(&env)
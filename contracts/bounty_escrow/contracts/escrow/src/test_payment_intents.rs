@@ -0,0 +1,181 @@
+#![cfg(test)]
+
+use crate::{BountyEscrowContract, BountyEscrowContractClient, ClaimError, Error, EscrowStatus};
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Bytes, BytesN, Env,
+};
+
+struct Setup<'a> {
+    client: BountyEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    depositor: Address,
+    contributor: Address,
+    key: SigningKey,
+    expiry: u64,
+}
+
+/// Bounty 1 locked for 1_000 by a depositor with a registered claim key.
+fn setup<'a>(env: &Env) -> Setup<'a> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_address).mint(&depositor, &1_000);
+    let deadline = env.ledger().timestamp() + 1_000;
+    client.lock_funds(&depositor, &1, &1_000, &deadline);
+
+    let key = SigningKey::from_bytes(&[3; 32]);
+    client.set_claim_key(
+        &depositor,
+        &BytesN::from_array(env, &key.verifying_key().to_bytes()),
+    );
+
+    Setup {
+        client,
+        token: token::Client::new(env, &token_address),
+        depositor,
+        contributor: Address::generate(env),
+        key,
+        expiry: env.ledger().timestamp() + 500,
+    }
+}
+
+fn sign(env: &Env, s: &Setup, key: &SigningKey, amount: i128, nonce: u64) -> BytesN<64> {
+    let mut signed = Bytes::from_slice(env, b"Stellar Signed Message:\n");
+    signed.append(&s.client.get_payment_intent_message(
+        &1,
+        &s.contributor,
+        &amount,
+        &nonce,
+        &s.expiry,
+    ));
+    let digest = env.crypto().sha256(&signed).to_array();
+    BytesN::from_array(env, &key.sign(&digest).to_bytes())
+}
+
+fn create(env: &Env, s: &Setup, amount: i128, nonce: u64) -> u64 {
+    let signature = sign(env, s, &s.key, amount, nonce);
+    s.client
+        .create_payment_intent(&1, &s.contributor, &amount, &nonce, &s.expiry, &signature)
+}
+
+#[test]
+fn test_intent_executes_release() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let intent_id = create(&env, &s, 400, 0);
+    assert_eq!(s.token.balance(&s.contributor), 0);
+    assert_eq!(s.client.get_nonce(&s.depositor), 1);
+
+    s.client.execute_payment_intent(&intent_id);
+    assert_eq!(s.token.balance(&s.contributor), 400);
+    let escrow = s.client.get_escrow_info(&1);
+    assert_eq!(escrow.remaining_amount, 600);
+    assert_eq!(escrow.status, EscrowStatus::Locked);
+    assert!(s.client.get_payment_intent(&intent_id).unwrap().executed);
+
+    assert_eq!(
+        s.client.try_execute_payment_intent(&intent_id),
+        Err(Ok(ClaimError::PaymentIntentClosed.into()))
+    );
+}
+
+#[test]
+fn test_nonce_cannot_be_reused() {
+    let env = Env::default();
+    let s = setup(&env);
+    create(&env, &s, 100, 0);
+
+    let signature = sign(&env, &s, &s.key, 100, 0);
+    assert_eq!(
+        s.client
+            .try_create_payment_intent(&1, &s.contributor, &100, &0, &s.expiry, &signature),
+        Err(Ok(ClaimError::InvalidNonce.into()))
+    );
+    create(&env, &s, 100, 1);
+}
+
+#[test]
+fn test_tampered_amount_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+    let signature = sign(&env, &s, &s.key, 100, 0);
+
+    assert!(s
+        .client
+        .try_create_payment_intent(&1, &s.contributor, &900, &0, &s.expiry, &signature)
+        .is_err());
+    assert_eq!(s.client.get_nonce(&s.depositor), 0);
+}
+
+#[test]
+fn test_foreign_key_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+    let signature = sign(&env, &s, &SigningKey::from_bytes(&[4; 32]), 100, 0);
+
+    assert!(s
+        .client
+        .try_create_payment_intent(&1, &s.contributor, &100, &0, &s.expiry, &signature)
+        .is_err());
+}
+
+#[test]
+fn test_expired_intent_not_executed() {
+    let env = Env::default();
+    let s = setup(&env);
+    let intent_id = create(&env, &s, 400, 0);
+
+    env.ledger().with_mut(|li| li.timestamp = s.expiry);
+    assert_eq!(
+        s.client.try_execute_payment_intent(&intent_id),
+        Err(Ok(ClaimError::PaymentIntentExpired.into()))
+    );
+    assert_eq!(s.client.get_escrow_info(&1).remaining_amount, 1_000);
+}
+
+#[test]
+fn test_cancelled_intent_not_executed() {
+    let env = Env::default();
+    let s = setup(&env);
+    let intent_id = create(&env, &s, 400, 0);
+
+    s.client.cancel_payment_intent(&intent_id);
+    assert_eq!(
+        s.client.try_execute_payment_intent(&intent_id),
+        Err(Ok(ClaimError::PaymentIntentClosed.into()))
+    );
+    assert_eq!(
+        s.client.try_cancel_payment_intent(&intent_id),
+        Err(Ok(ClaimError::PaymentIntentClosed.into()))
+    );
+}
+
+#[test]
+fn test_intents_limited_by_remaining_balance() {
+    let env = Env::default();
+    let s = setup(&env);
+    let first = create(&env, &s, 700, 0);
+    let second = create(&env, &s, 700, 1);
+
+    s.client.execute_payment_intent(&first);
+    assert_eq!(
+        s.client.try_execute_payment_intent(&second),
+        Err(Ok(Error::InsufficientFunds.into()))
+    );
+    assert_eq!(
+        s.client.try_execute_payment_intent(&99),
+        Err(Ok(ClaimError::PaymentIntentNotFound.into()))
+    );
+}
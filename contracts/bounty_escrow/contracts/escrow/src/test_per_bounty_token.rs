@@ -0,0 +1,112 @@
+#![cfg(test)]
+
+use crate::{BountyEscrowContract, BountyEscrowContractClient, LifecycleError};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env,
+};
+
+fn create_token<'a>(env: &Env, holder: &Address) -> token::Client<'a> {
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    token::StellarAssetClient::new(env, &token_address).mint(holder, &10_000);
+    token::Client::new(env, &token_address)
+}
+
+fn setup<'a>(
+    env: &Env,
+) -> (
+    BountyEscrowContractClient<'a>,
+    token::Client<'a>,
+    token::Client<'a>,
+    Address,
+) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let depositor = Address::generate(env);
+
+    let default_token = create_token(env, &depositor);
+    let other_token = create_token(env, &depositor);
+    client.init(&admin, &default_token.address);
+
+    (client, default_token, other_token, depositor)
+}
+
+#[test]
+fn test_lock_with_token_requires_allowlist() {
+    let env = Env::default();
+    let (client, _default_token, other_token, depositor) = setup(&env);
+    let deadline = env.ledger().timestamp() + 1_000;
+
+    assert_eq!(
+        client.try_lock_funds_with_token(&depositor, &1, &other_token.address, &500, &deadline),
+        Err(Ok(LifecycleError::TokenNotAllowed.into()))
+    );
+
+    client.set_token_allowed(&other_token.address, &true);
+    assert!(client.is_token_allowed(&other_token.address));
+    client.lock_funds_with_token(&depositor, &1, &other_token.address, &500, &deadline);
+
+    assert_eq!(client.get_bounty_token(&1), other_token.address);
+    assert_eq!(other_token.balance(&client.address), 500);
+}
+
+#[test]
+fn test_default_token_needs_no_allowlist_entry() {
+    let env = Env::default();
+    let (client, default_token, _other_token, depositor) = setup(&env);
+    let deadline = env.ledger().timestamp() + 1_000;
+
+    client.lock_funds_with_token(&depositor, &1, &default_token.address, &500, &deadline);
+    client.lock_funds(&depositor, &2, &300, &deadline);
+
+    assert_eq!(client.get_bounty_token(&1), default_token.address);
+    assert_eq!(client.get_bounty_token(&2), default_token.address);
+    assert_eq!(default_token.balance(&client.address), 800);
+}
+
+#[test]
+fn test_release_and_refund_use_bounty_token() {
+    let env = Env::default();
+    let (client, default_token, other_token, depositor) = setup(&env);
+    let contributor = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 1_000;
+
+    client.set_token_allowed(&other_token.address, &true);
+    client.lock_funds(&depositor, &1, &1_000, &deadline);
+    client.lock_funds_with_token(&depositor, &2, &other_token.address, &600, &deadline);
+    client.lock_funds_with_token(&depositor, &3, &other_token.address, &400, &deadline);
+
+    client.release_funds(&2, &contributor);
+    assert_eq!(other_token.balance(&contributor), 600);
+    assert_eq!(default_token.balance(&contributor), 0);
+
+    env.ledger().set_timestamp(deadline + 1);
+    client.refund(&3);
+    assert_eq!(other_token.balance(&depositor), 9_400);
+    assert_eq!(other_token.balance(&client.address), 0);
+    assert_eq!(default_token.balance(&client.address), 1_000);
+}
+
+#[test]
+fn test_disallowing_token_keeps_existing_bounties_payable() {
+    let env = Env::default();
+    let (client, _default_token, other_token, depositor) = setup(&env);
+    let contributor = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 1_000;
+
+    client.set_token_allowed(&other_token.address, &true);
+    client.lock_funds_with_token(&depositor, &1, &other_token.address, &500, &deadline);
+    client.set_token_allowed(&other_token.address, &false);
+
+    assert_eq!(
+        client.try_lock_funds_with_token(&depositor, &2, &other_token.address, &500, &deadline),
+        Err(Ok(LifecycleError::TokenNotAllowed.into()))
+    );
+    client.release_funds(&1, &contributor);
+    assert_eq!(other_token.balance(&contributor), 500);
+}
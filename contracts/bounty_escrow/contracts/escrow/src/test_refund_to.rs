@@ -0,0 +1,161 @@
+#![cfg(test)]
+
+use crate::{
+    BountyEscrowContract, BountyEscrowContractClient, ClaimError, Error, EscrowStatus, RefundMode,
+};
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Bytes, BytesN, Env,
+};
+
+struct Setup<'a> {
+    client: BountyEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    depositor: Address,
+    recovery: SigningKey,
+    deadline: u64,
+}
+
+/// Bounty 1 locked for 1_000 by a depositor with a registered recovery key.
+fn setup<'a>(env: &Env) -> Setup<'a> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_address).mint(&depositor, &1_000);
+    let deadline = env.ledger().timestamp() + 1_000;
+    client.lock_funds(&depositor, &1, &1_000, &deadline);
+
+    let recovery = SigningKey::from_bytes(&[21; 32]);
+    client.set_recovery_key(
+        &depositor,
+        &BytesN::from_array(env, &recovery.verifying_key().to_bytes()),
+    );
+
+    Setup {
+        client,
+        token: token::Client::new(env, &token_address),
+        depositor,
+        recovery,
+        deadline,
+    }
+}
+
+fn sign(env: &Env, key: &SigningKey, message: &Bytes) -> BytesN<64> {
+    let mut signed = Bytes::from_slice(env, b"Stellar Signed Message:\n");
+    signed.append(message);
+    let digest = env.crypto().sha256(&signed).to_array();
+    BytesN::from_array(env, &key.sign(&digest).to_bytes())
+}
+
+#[test]
+fn test_refund_lands_in_alternate() {
+    let env = Env::default();
+    let s = setup(&env);
+    let alternate = Address::generate(&env);
+    env.ledger().with_mut(|li| li.timestamp = s.deadline + 1);
+
+    let message = s.client.get_refund_to_message(&1, &alternate);
+    let signature = sign(&env, &s.recovery, &message);
+    s.client.refund_to(&1, &alternate, &signature);
+
+    assert_eq!(s.token.balance(&alternate), 1_000);
+    assert_eq!(s.token.balance(&s.depositor), 0);
+    let escrow = s.client.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Refunded);
+    assert_eq!(escrow.refund_history.get(0).unwrap().recipient, alternate);
+    assert_eq!(s.client.get_nonce(&s.depositor), 1);
+}
+
+#[test]
+fn test_signature_bound_to_alternate() {
+    let env = Env::default();
+    let s = setup(&env);
+    let alternate = Address::generate(&env);
+    env.ledger().with_mut(|li| li.timestamp = s.deadline + 1);
+
+    let message = s.client.get_refund_to_message(&1, &alternate);
+    let signature = sign(&env, &s.recovery, &message);
+    assert!(s
+        .client
+        .try_refund_to(&1, &Address::generate(&env), &signature)
+        .is_err());
+    assert_eq!(s.client.get_escrow_info(&1).remaining_amount, 1_000);
+}
+
+#[test]
+fn test_claim_key_cannot_redirect() {
+    let env = Env::default();
+    let s = setup(&env);
+    let claim_key = SigningKey::from_bytes(&[22; 32]);
+    s.client.set_claim_key(
+        &s.depositor,
+        &BytesN::from_array(&env, &claim_key.verifying_key().to_bytes()),
+    );
+    let alternate = Address::generate(&env);
+    env.ledger().with_mut(|li| li.timestamp = s.deadline + 1);
+
+    let message = s.client.get_refund_to_message(&1, &alternate);
+    assert!(s
+        .client
+        .try_refund_to(&1, &alternate, &sign(&env, &claim_key, &message))
+        .is_err());
+}
+
+#[test]
+fn test_deadline_still_applies() {
+    let env = Env::default();
+    let s = setup(&env);
+    let alternate = Address::generate(&env);
+
+    let message = s.client.get_refund_to_message(&1, &alternate);
+    assert_eq!(
+        s.client
+            .try_refund_to(&1, &alternate, &sign(&env, &s.recovery, &message)),
+        Err(Ok(Error::DeadlineNotPassed.into()))
+    );
+    assert_eq!(s.client.get_nonce(&s.depositor), 0);
+}
+
+#[test]
+fn test_approved_third_party_refund_not_redirected() {
+    let env = Env::default();
+    let s = setup(&env);
+    let recipient = Address::generate(&env);
+    let alternate = Address::generate(&env);
+    s.client
+        .approve_refund(&1, &400, &recipient, &RefundMode::Partial);
+
+    let message = s.client.get_refund_to_message(&1, &alternate);
+    s.client
+        .refund_to(&1, &alternate, &sign(&env, &s.recovery, &message));
+    assert_eq!(s.token.balance(&recipient), 400);
+    assert_eq!(s.token.balance(&alternate), 0);
+}
+
+#[test]
+fn test_recovery_key_required() {
+    let env = Env::default();
+    let s = setup(&env);
+    let other = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &s.token.address).mint(&other, &100);
+    s.client.lock_funds(&other, &2, &100, &s.deadline);
+
+    assert_eq!(
+        s.client.try_refund_to(
+            &2,
+            &Address::generate(&env),
+            &BytesN::from_array(&env, &[0; 64])
+        ),
+        Err(Ok(ClaimError::RecoveryKeyNotSet.into()))
+    );
+}
@@ -0,0 +1,188 @@
+#![cfg(test)]
+
+use crate::{BountyEscrowContract, BountyEscrowContractClient, ClaimError, Error};
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Bytes, BytesN, Env,
+};
+
+struct Setup<'a> {
+    client: BountyEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    beneficiary: Address,
+    key: SigningKey,
+    ticket_id: u64,
+    expires_at: u64,
+}
+
+/// Bounty 1 locked for 1_000 with a 400 ticket for a beneficiary whose
+/// claim key is registered.
+fn setup<'a>(env: &Env) -> Setup<'a> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_address).mint(&depositor, &1_000);
+    let deadline = env.ledger().timestamp() + 1_000;
+    client.lock_funds(&depositor, &1, &1_000, &deadline);
+
+    let beneficiary = Address::generate(env);
+    let key = SigningKey::from_bytes(&[7; 32]);
+    client.set_claim_key(
+        &beneficiary,
+        &BytesN::from_array(env, &key.verifying_key().to_bytes()),
+    );
+    let expires_at = deadline + 1_000;
+    let ticket_id = client.issue_claim_ticket(&1, &beneficiary, &400, &expires_at);
+
+    Setup {
+        client,
+        token: token::Client::new(env, &token_address),
+        beneficiary,
+        key,
+        ticket_id,
+        expires_at,
+    }
+}
+
+/// SEP-53 signature over the contract's relayed claim message.
+fn sign(env: &Env, s: &Setup, key: &SigningKey, relayer: &Address) -> BytesN<64> {
+    let mut signed = Bytes::from_slice(env, b"Stellar Signed Message:\n");
+    signed.append(
+        &s.client
+            .get_relayed_claim_message(&s.ticket_id, relayer, &0),
+    );
+    let digest = env.crypto().sha256(&signed).to_array();
+    BytesN::from_array(env, &key.sign(&digest).to_bytes())
+}
+
+#[test]
+fn test_relayer_claims_and_receives_tip() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.set_relayer_tip_bps(&250);
+    let relayer = Address::generate(&env);
+
+    let signature = sign(&env, &s, &s.key, &relayer);
+    let tip = s
+        .client
+        .claim_with_ticket_for(&s.ticket_id, &relayer, &0, &signature);
+
+    assert_eq!(tip, 10);
+    assert_eq!(s.token.balance(&relayer), 10);
+    assert_eq!(s.token.balance(&s.beneficiary), 390);
+    assert_eq!(s.client.get_escrow_info(&1).remaining_amount, 600);
+    assert_eq!(s.client.get_ticket_claims(&s.ticket_id).len(), 2);
+    assert_eq!(
+        s.client
+            .try_claim_with_ticket_for(&s.ticket_id, &relayer, &0, &signature),
+        Err(Ok(Error::TicketAlreadyUsed.into()))
+    );
+}
+
+#[test]
+fn test_no_tip_pays_beneficiary_in_full() {
+    let env = Env::default();
+    let s = setup(&env);
+    let relayer = Address::generate(&env);
+
+    let signature = sign(&env, &s, &s.key, &relayer);
+    assert_eq!(
+        s.client
+            .claim_with_ticket_for(&s.ticket_id, &relayer, &0, &signature),
+        0
+    );
+    assert_eq!(s.token.balance(&s.beneficiary), 400);
+    assert_eq!(s.client.get_ticket_claims(&s.ticket_id).len(), 1);
+}
+
+#[test]
+fn test_signature_bound_to_relayer_and_tip() {
+    let env = Env::default();
+    let s = setup(&env);
+    let relayer = Address::generate(&env);
+    let signature = sign(&env, &s, &s.key, &relayer);
+
+    // Another relayer cannot reuse the signature.
+    assert!(s
+        .client
+        .try_claim_with_ticket_for(&s.ticket_id, &Address::generate(&env), &0, &signature)
+        .is_err());
+
+    // Nor can it be used once the tip has changed.
+    s.client.set_relayer_tip_bps(&100);
+    assert!(s
+        .client
+        .try_claim_with_ticket_for(&s.ticket_id, &relayer, &0, &signature)
+        .is_err());
+    assert_eq!(s.token.balance(&s.beneficiary), 0);
+}
+
+#[test]
+fn test_wrong_key_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+    let relayer = Address::generate(&env);
+    let signature = sign(&env, &s, &SigningKey::from_bytes(&[9; 32]), &relayer);
+
+    assert!(s
+        .client
+        .try_claim_with_ticket_for(&s.ticket_id, &relayer, &0, &signature)
+        .is_err());
+    assert_eq!(s.client.get_escrow_info(&1).remaining_amount, 1_000);
+}
+
+#[test]
+fn test_claim_key_required() {
+    let env = Env::default();
+    let s = setup(&env);
+    let other = Address::generate(&env);
+    let ticket_id = s.client.issue_claim_ticket(&1, &other, &100, &s.expires_at);
+
+    assert_eq!(
+        s.client.try_claim_with_ticket_for(
+            &ticket_id,
+            &Address::generate(&env),
+            &0,
+            &BytesN::from_array(&env, &[0; 64]),
+        ),
+        Err(Ok(ClaimError::ClaimKeyNotSet.into()))
+    );
+}
+
+#[test]
+fn test_expired_ticket_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+    let relayer = Address::generate(&env);
+    let signature = sign(&env, &s, &s.key, &relayer);
+    env.ledger().with_mut(|li| li.timestamp = s.expires_at);
+
+    assert_eq!(
+        s.client
+            .try_claim_with_ticket_for(&s.ticket_id, &relayer, &0, &signature),
+        Err(Ok(Error::TicketExpired.into()))
+    );
+}
+
+#[test]
+fn test_tip_capped() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    assert_eq!(
+        s.client.try_set_relayer_tip_bps(&501),
+        Err(Ok(Error::InvalidFeeRate.into()))
+    );
+    s.client.set_relayer_tip_bps(&500);
+    assert_eq!(s.client.get_relayer_tip_bps(), 500);
+}
@@ -0,0 +1,137 @@
+#![cfg(test)]
+
+use crate::{BountyEscrowContract, BountyEscrowContractClient, Error, EscrowStatus, ReleaseError};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, BytesN, Env,
+};
+
+fn setup<'a>(env: &Env) -> (BountyEscrowContractClient<'a>, token::Client<'a>, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_address).mint(&depositor, &10_000);
+    let deadline = env.ledger().timestamp() + 10_000;
+    client.lock_funds(&depositor, &1, &1_000, &deadline);
+
+    (client, token::Client::new(env, &token_address), depositor)
+}
+
+fn advance(env: &Env, seconds: u64) {
+    env.ledger().with_mut(|li| li.timestamp += seconds);
+}
+
+#[test]
+fn test_release_without_challenge_period_pays_immediately() {
+    let env = Env::default();
+    let (client, token, _depositor) = setup(&env);
+    let contributor = Address::generate(&env);
+
+    client.release_funds(&1, &contributor);
+    assert_eq!(client.get_escrow_info(&1).status, EscrowStatus::Released);
+    assert_eq!(token.balance(&contributor), 1_000);
+    assert_eq!(client.get_pending_release(&1), None);
+}
+
+#[test]
+fn test_release_is_held_then_finalized_by_anyone() {
+    let env = Env::default();
+    let (client, token, _depositor) = setup(&env);
+    let contributor = Address::generate(&env);
+    client.set_release_challenge_period(&1, &500);
+
+    client.release_funds(&1, &contributor);
+    assert_eq!(
+        client.get_escrow_info(&1).status,
+        EscrowStatus::PendingRelease
+    );
+    assert_eq!(token.balance(&contributor), 0);
+    let pending = client.get_pending_release(&1).unwrap();
+    assert_eq!(pending.contributor, contributor);
+    assert_eq!(pending.finalize_after, env.ledger().timestamp() + 500);
+
+    assert_eq!(
+        client.try_finalize_release(&1),
+        Err(Ok(ReleaseError::ChallengePeriodActive.into()))
+    );
+
+    advance(&env, 500);
+    client.finalize_release(&1);
+    assert_eq!(client.get_escrow_info(&1).status, EscrowStatus::Released);
+    assert_eq!(token.balance(&contributor), 1_000);
+    assert_eq!(client.get_pending_release(&1), None);
+}
+
+#[test]
+fn test_disputed_release_cannot_finalize() {
+    let env = Env::default();
+    let (client, token, _depositor) = setup(&env);
+    let contributor = Address::generate(&env);
+    let reason = BytesN::from_array(&env, &[7u8; 32]);
+    client.set_release_challenge_period(&1, &500);
+    client.release_funds(&1, &contributor);
+
+    advance(&env, 100);
+    client.dispute_release(&1, &reason);
+    let pending = client.get_pending_release(&1).unwrap();
+    assert!(pending.disputed);
+    assert_eq!(pending.reason_hash, reason);
+
+    advance(&env, 1_000);
+    assert_eq!(
+        client.try_finalize_release(&1),
+        Err(Ok(ReleaseError::ReleaseDisputed.into()))
+    );
+    assert_eq!(
+        client.try_dispute_release(&1, &reason),
+        Err(Ok(ReleaseError::ReleaseDisputed.into()))
+    );
+    assert_eq!(token.balance(&contributor), 0);
+    assert_eq!(token.balance(&client.address), 1_000);
+}
+
+#[test]
+fn test_dispute_rejected_after_challenge_period() {
+    let env = Env::default();
+    let (client, _token, _depositor) = setup(&env);
+    let reason = BytesN::from_array(&env, &[1u8; 32]);
+    client.set_release_challenge_period(&1, &500);
+
+    assert_eq!(
+        client.try_dispute_release(&1, &reason),
+        Err(Ok(ReleaseError::PendingReleaseNotFound.into()))
+    );
+
+    client.release_funds(&1, &Address::generate(&env));
+    advance(&env, 500);
+    assert_eq!(
+        client.try_dispute_release(&1, &reason),
+        Err(Ok(ReleaseError::ChallengePeriodElapsed.into()))
+    );
+}
+
+#[test]
+fn test_pending_release_blocks_other_payouts() {
+    let env = Env::default();
+    let (client, _token, _depositor) = setup(&env);
+    client.set_release_challenge_period(&1, &500);
+    client.release_funds(&1, &Address::generate(&env));
+
+    assert_eq!(
+        client.try_release_funds(&1, &Address::generate(&env)),
+        Err(Ok(Error::FundsNotLocked.into()))
+    );
+    assert_eq!(
+        client.try_set_release_challenge_period(&1, &0),
+        Err(Ok(Error::FundsNotLocked.into()))
+    );
+}
@@ -0,0 +1,106 @@
+#![cfg(test)]
+
+use crate::events::TrancheReleased;
+use crate::{BountyEscrowContract, BountyEscrowContractClient, Error, EscrowStatus};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    token, Address, Env, IntoVal, TryFromVal, Val,
+};
+
+fn setup<'a>(env: &Env) -> (BountyEscrowContractClient<'a>, token::Client<'a>) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_address).mint(&depositor, &10_000);
+    let deadline = env.ledger().timestamp() + 1_000;
+    client.lock_funds(&depositor, &1, &1_000, &deadline);
+
+    (client, token::Client::new(env, &token_address))
+}
+
+fn last_tranche(env: &Env) -> TrancheReleased {
+    let tranche: Val = symbol_short!("tranche").into_val(env);
+    let (_, _, data) = env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| {
+            topics
+                .get(0)
+                .map(|t| t.get_payload() == tranche.get_payload())
+                .unwrap_or(false)
+        })
+        .last()
+        .unwrap();
+    TrancheReleased::try_from_val(env, &data).unwrap()
+}
+
+#[test]
+fn test_tranches_until_fully_released() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let contributor = Address::generate(&env);
+
+    client.partial_release(&1, &contributor, &300);
+    assert_eq!(last_tranche(&env).remaining_amount, 700);
+    let escrow = client.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Locked);
+    assert_eq!(escrow.remaining_amount, 700);
+
+    client.partial_release(&1, &contributor, &700);
+    let event = last_tranche(&env);
+    assert_eq!(event.amount, 700);
+    assert_eq!(event.remaining_amount, 0);
+    assert_eq!(client.get_escrow_info(&1).status, EscrowStatus::Released);
+
+    assert_eq!(token.balance(&contributor), 1_000);
+    assert_eq!(client.get_release_records(&1).len(), 2);
+}
+
+#[test]
+fn test_release_partial_rejects_bad_amounts() {
+    let env = Env::default();
+    let (client, _token) = setup(&env);
+    let contributor = Address::generate(&env);
+
+    assert_eq!(
+        client.try_partial_release(&1, &contributor, &0),
+        Err(Ok(Error::InvalidAmount.into()))
+    );
+    assert_eq!(
+        client.try_partial_release(&1, &contributor, &1_001),
+        Err(Ok(Error::InsufficientFunds.into()))
+    );
+
+    client.partial_release(&1, &contributor, &1_000);
+    assert_eq!(
+        client.try_partial_release(&1, &contributor, &1),
+        Err(Ok(Error::FundsNotLocked.into()))
+    );
+}
+
+#[test]
+fn test_full_release_after_tranche_pays_only_the_remainder() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let contributor = Address::generate(&env);
+
+    client.partial_release(&1, &contributor, &300);
+    client.release_funds(&1, &contributor);
+
+    assert_eq!(token.balance(&contributor), 1_000);
+    assert_eq!(token.balance(&client.address), 0);
+    let escrow = client.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Released);
+    assert_eq!(escrow.remaining_amount, 0);
+}
@@ -0,0 +1,149 @@
+#![cfg(test)]
+
+use crate::{BountyEscrowContract, BountyEscrowContractClient, ClaimError, Error};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, BytesN, Env,
+};
+
+struct Setup<'a> {
+    client: BountyEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    resolvers: [Address; 3],
+}
+
+/// Anonymous escrow of 1_000 on bounty 1, past its deadline, with a 2-of-3
+/// resolver set.
+fn setup<'a>(env: &Env) -> Setup<'a> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_address).mint(&depositor, &1_000);
+    let deadline = env.ledger().timestamp() + 100;
+    let commitment = BytesN::from_array(env, &[5u8; 32]);
+    client.lock_funds_anonymous(&depositor, &commitment, &1, &1_000, &deadline);
+    env.ledger().with_mut(|li| li.timestamp = deadline + 1);
+
+    let resolvers = [
+        Address::generate(env),
+        Address::generate(env),
+        Address::generate(env),
+    ];
+    for r in resolvers.iter() {
+        client.add_resolver(r);
+    }
+    client.set_resolver_quorum(&2);
+
+    Setup {
+        client,
+        token: token::Client::new(env, &token_address),
+        resolvers,
+    }
+}
+
+#[test]
+fn test_refund_waits_for_quorum() {
+    let env = Env::default();
+    let s = setup(&env);
+    let recipient = Address::generate(&env);
+
+    s.client.refund_resolved(&1, &recipient, &s.resolvers[0]);
+    assert_eq!(s.token.balance(&recipient), 0);
+    let pending = s.client.get_resolver_approvals(&1).unwrap();
+    assert_eq!(pending.recipient, recipient);
+    assert_eq!(pending.approvals.len(), 1);
+
+    // A repeated approval from the same resolver does not count twice.
+    s.client.refund_resolved(&1, &recipient, &s.resolvers[0]);
+    assert_eq!(s.token.balance(&recipient), 0);
+
+    s.client.refund_resolved(&1, &recipient, &s.resolvers[2]);
+    assert_eq!(s.token.balance(&recipient), 1_000);
+    assert_eq!(s.client.get_resolver_approvals(&1), None);
+}
+
+#[test]
+fn test_resolvers_must_agree_on_recipient() {
+    let env = Env::default();
+    let s = setup(&env);
+    let recipient = Address::generate(&env);
+
+    s.client.refund_resolved(&1, &recipient, &s.resolvers[0]);
+    assert_eq!(
+        s.client
+            .try_refund_resolved(&1, &Address::generate(&env), &s.resolvers[1]),
+        Err(Ok(ClaimError::ResolverRecipientMismatch.into()))
+    );
+    assert_eq!(
+        s.client
+            .try_refund_resolved(&1, &recipient, &Address::generate(&env)),
+        Err(Ok(Error::Unauthorized.into()))
+    );
+}
+
+#[test]
+fn test_removed_resolver_approval_no_longer_counts() {
+    let env = Env::default();
+    let s = setup(&env);
+    let recipient = Address::generate(&env);
+
+    s.client.refund_resolved(&1, &recipient, &s.resolvers[0]);
+    s.client.remove_resolver(&s.resolvers[0]);
+    assert_eq!(s.client.get_resolvers().len(), 2);
+
+    s.client.refund_resolved(&1, &recipient, &s.resolvers[1]);
+    assert_eq!(s.token.balance(&recipient), 0);
+    s.client.refund_resolved(&1, &recipient, &s.resolvers[2]);
+    assert_eq!(s.token.balance(&recipient), 1_000);
+}
+
+#[test]
+fn test_quorum_bounds() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    assert_eq!(
+        s.client.try_set_resolver_quorum(&0),
+        Err(Ok(Error::InvalidAmount.into()))
+    );
+    assert_eq!(
+        s.client.try_set_resolver_quorum(&4),
+        Err(Ok(Error::InvalidAmount.into()))
+    );
+
+    s.client.set_resolver_quorum(&3);
+    assert_eq!(
+        s.client.try_remove_resolver(&s.resolvers[0]),
+        Err(Ok(Error::InvalidAmount.into()))
+    );
+}
+
+#[test]
+fn test_legacy_single_resolver_still_refunds() {
+    let env = Env::default();
+    let s = setup(&env);
+    let resolver = Address::generate(&env);
+    let recipient = Address::generate(&env);
+
+    s.client.set_anonymous_resolver(&Some(resolver.clone()));
+    assert_eq!(s.client.get_resolvers(), vec![&env, resolver.clone()]);
+    assert_eq!(s.client.get_resolver_quorum(), 1);
+
+    s.client.refund_resolved(&1, &recipient, &resolver);
+    assert_eq!(s.token.balance(&recipient), 1_000);
+
+    s.client.set_anonymous_resolver(&None);
+    assert_eq!(
+        s.client.try_refund_resolved(&1, &recipient, &resolver),
+        Err(Ok(Error::AnonymousResolverNotSet.into()))
+    );
+}
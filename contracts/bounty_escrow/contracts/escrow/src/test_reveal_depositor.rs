@@ -0,0 +1,100 @@
+#![cfg(test)]
+
+use crate::{BountyEscrowContract, BountyEscrowContractClient, ClaimError, Error, EscrowStatus};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token,
+    xdr::ToXdr,
+    Address, Bytes, BytesN, Env,
+};
+
+struct Setup<'a> {
+    client: BountyEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    depositor: Address,
+    preimage: Bytes,
+    deadline: u64,
+}
+
+/// Anonymous escrow of 1_000 on bounty 1 committed to `sha256(depositor_xdr || salt)`.
+fn setup<'a>(env: &Env) -> Setup<'a> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_address).mint(&depositor, &1_000);
+
+    let mut preimage = depositor.clone().to_xdr(env);
+    preimage.append(&Bytes::from_slice(env, b"salt-1"));
+    let commitment: BytesN<32> = env.crypto().sha256(&preimage).into();
+    let deadline = env.ledger().timestamp() + 100;
+    client.lock_funds_anonymous(&depositor, &commitment, &1, &1_000, &deadline);
+
+    Setup {
+        client,
+        token: token::Client::new(env, &token_address),
+        depositor,
+        preimage,
+        deadline,
+    }
+}
+
+#[test]
+fn test_reveal_converts_to_regular_escrow() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    assert_eq!(
+        s.client.try_get_escrow_info(&1),
+        Err(Ok(Error::BountyNotFound.into()))
+    );
+
+    let revealed = s.client.reveal_depositor(&1, &s.preimage);
+    assert_eq!(revealed, s.depositor);
+
+    let escrow = s.client.get_escrow_info(&1);
+    assert_eq!(escrow.depositor, s.depositor);
+    assert_eq!(escrow.remaining_amount, 1_000);
+    assert_eq!(escrow.status, EscrowStatus::Locked);
+}
+
+#[test]
+fn test_revealed_escrow_refunds_without_resolver() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.reveal_depositor(&1, &s.preimage);
+
+    env.ledger().with_mut(|li| li.timestamp = s.deadline + 1);
+    s.client.refund(&1);
+
+    assert_eq!(s.token.balance(&s.depositor), 1_000);
+    assert_eq!(s.client.get_escrow_info(&1).status, EscrowStatus::Refunded);
+}
+
+#[test]
+fn test_reveal_rejects_bad_preimage() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    // Right address but wrong salt.
+    let mut wrong = s.depositor.clone().to_xdr(&env);
+    wrong.append(&Bytes::from_slice(&env, b"salt-2"));
+    assert_eq!(
+        s.client.try_reveal_depositor(&1, &wrong),
+        Err(Ok(ClaimError::InvalidPreimage.into()))
+    );
+
+    s.client.reveal_depositor(&1, &s.preimage);
+    assert_eq!(
+        s.client.try_reveal_depositor(&1, &s.preimage),
+        Err(Ok(Error::NotAnonymousEscrow.into()))
+    );
+}
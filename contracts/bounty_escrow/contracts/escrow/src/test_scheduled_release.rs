@@ -0,0 +1,122 @@
+#![cfg(test)]
+
+use crate::{BountyEscrowContract, BountyEscrowContractClient, Error, EscrowStatus, ReleaseError};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env,
+};
+
+struct Setup<'a> {
+    client: BountyEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    contributor: Address,
+    release_at: u64,
+}
+
+/// Bounty 1 locked for 1_000 with a release to `contributor` scheduled 50s out.
+fn setup<'a>(env: &Env) -> Setup<'a> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_address).mint(&depositor, &1_000);
+    let now = env.ledger().timestamp();
+    client.lock_funds(&depositor, &1, &1_000, &(now + 1_000));
+
+    let contributor = Address::generate(env);
+    let release_at = now + 50;
+    client.schedule_release(&1, &contributor, &release_at);
+
+    Setup {
+        client,
+        token: token::Client::new(env, &token_address),
+        contributor,
+        release_at,
+    }
+}
+
+#[test]
+fn test_scheduled_release_pays_after_release_at() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let schedule = s.client.get_scheduled_release(&1).unwrap();
+    assert_eq!(schedule.contributor, s.contributor);
+    assert_eq!(schedule.release_at, s.release_at);
+
+    assert_eq!(
+        s.client.try_execute_scheduled_release(&1),
+        Err(Ok(ReleaseError::ScheduledReleaseNotDue.into()))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = s.release_at);
+    s.client.execute_scheduled_release(&1);
+
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+    assert_eq!(s.client.get_escrow_info(&1).status, EscrowStatus::Released);
+    assert_eq!(s.client.get_scheduled_release(&1), None);
+    assert_eq!(
+        s.client.try_execute_scheduled_release(&1),
+        Err(Ok(ReleaseError::ScheduledReleaseNotFound.into()))
+    );
+}
+
+#[test]
+fn test_schedule_validation() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    assert_eq!(
+        s.client
+            .try_schedule_release(&1, &s.contributor, &(s.release_at + 10)),
+        Err(Ok(ReleaseError::ReleaseAlreadyScheduled.into()))
+    );
+    assert_eq!(
+        s.client
+            .try_schedule_release(&2, &s.contributor, &s.release_at),
+        Err(Ok(Error::BountyNotFound.into()))
+    );
+
+    s.client.cancel_scheduled_release(&1);
+    assert_eq!(
+        s.client
+            .try_schedule_release(&1, &s.contributor, &env.ledger().timestamp()),
+        Err(Ok(Error::InvalidDeadline.into()))
+    );
+}
+
+#[test]
+fn test_cancelled_schedule_cannot_execute() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.cancel_scheduled_release(&1);
+
+    env.ledger().with_mut(|li| li.timestamp = s.release_at);
+    assert_eq!(
+        s.client.try_execute_scheduled_release(&1),
+        Err(Ok(ReleaseError::ScheduledReleaseNotFound.into()))
+    );
+    assert_eq!(s.client.get_escrow_info(&1).status, EscrowStatus::Locked);
+}
+
+#[test]
+fn test_schedule_fails_if_escrow_released_meanwhile() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.release_funds(&1, &Address::generate(&env));
+
+    env.ledger().with_mut(|li| li.timestamp = s.release_at);
+    assert_eq!(
+        s.client.try_execute_scheduled_release(&1),
+        Err(Ok(Error::FundsNotLocked.into()))
+    );
+    assert_eq!(s.token.balance(&s.contributor), 0);
+}
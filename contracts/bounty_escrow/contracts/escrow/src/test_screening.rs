@@ -0,0 +1,142 @@
+#![cfg(test)]
+
+use crate::{BountyEscrowContract, BountyEscrowContractClient, ComplianceError};
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, token, Address, Env};
+
+/// Screening oracle that denies any address it was told about.
+#[contract]
+pub struct MockScreening;
+
+#[contractimpl]
+impl MockScreening {
+    pub fn deny(env: Env, address: Address) {
+        env.storage().persistent().set(&address, &true);
+    }
+
+    pub fn screen(env: Env, address: Address, _amount: i128) -> bool {
+        !env.storage().persistent().has(&address)
+    }
+}
+
+struct Setup<'a> {
+    client: BountyEscrowContractClient<'a>,
+    screening: MockScreeningClient<'a>,
+    token: token::Client<'a>,
+    depositor: Address,
+    deadline: u64,
+}
+
+/// Bounty 1 locked for 1_000 by `depositor`, then a screening contract
+/// registered.
+fn setup<'a>(env: &Env) -> Setup<'a> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_address).mint(&depositor, &5_000);
+    let deadline = env.ledger().timestamp() + 100;
+    client.lock_funds(&depositor, &1, &1_000, &deadline);
+
+    let screening_id = env.register_contract(None, MockScreening);
+    client.set_screening_contract(&Some(screening_id.clone()));
+
+    Setup {
+        client,
+        screening: MockScreeningClient::new(env, &screening_id),
+        token: token::Client::new(env, &token_address),
+        depositor,
+        deadline,
+    }
+}
+
+#[test]
+fn test_screening_allows_clean_addresses() {
+    let env = Env::default();
+    let s = setup(&env);
+    let contributor = Address::generate(&env);
+
+    s.client.lock_funds(&s.depositor, &2, &1_000, &s.deadline);
+    s.client.release_funds(&1, &contributor);
+    assert_eq!(s.token.balance(&contributor), 1_000);
+    assert_eq!(
+        s.client.get_screening_contract(),
+        Some(s.screening.address.clone())
+    );
+}
+
+#[test]
+fn test_denied_depositor_cannot_lock() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.screening.deny(&s.depositor);
+
+    assert_eq!(
+        s.client
+            .try_lock_funds(&s.depositor, &2, &1_000, &s.deadline),
+        Err(Ok(ComplianceError::ScreeningRejected.into()))
+    );
+}
+
+#[test]
+fn test_denied_contributor_cannot_be_paid() {
+    let env = Env::default();
+    let s = setup(&env);
+    let contributor = Address::generate(&env);
+    s.screening.deny(&contributor);
+
+    assert_eq!(
+        s.client.try_release_funds(&1, &contributor),
+        Err(Ok(ComplianceError::ScreeningRejected.into()))
+    );
+    assert_eq!(
+        s.client.try_partial_release(&1, &contributor, &100),
+        Err(Ok(ComplianceError::ScreeningRejected.into()))
+    );
+    assert_eq!(s.token.balance(&contributor), 0);
+}
+
+#[test]
+fn test_compliance_override_bypasses_denial() {
+    let env = Env::default();
+    let s = setup(&env);
+    let compliance = Address::generate(&env);
+    s.client.set_compliance_admin(&compliance);
+    let contributor = Address::generate(&env);
+    s.screening.deny(&contributor);
+
+    s.client.set_screening_override(&contributor, &true);
+    assert!(s.client.has_screening_override(&contributor));
+    s.client.release_funds(&1, &contributor);
+    assert_eq!(s.token.balance(&contributor), 1_000);
+
+    s.client.set_screening_override(&contributor, &false);
+    s.client.lock_funds(&s.depositor, &2, &1_000, &s.deadline);
+    assert_eq!(
+        s.client.try_release_funds(&2, &contributor),
+        Err(Ok(ComplianceError::ScreeningRejected.into()))
+    );
+}
+
+#[test]
+fn test_unreachable_screening_contract_fails_closed() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client
+        .set_screening_contract(&Some(Address::generate(&env)));
+    assert_eq!(
+        s.client
+            .try_lock_funds(&s.depositor, &2, &1_000, &s.deadline),
+        Err(Ok(ComplianceError::ScreeningRejected.into()))
+    );
+
+    s.client.set_screening_contract(&None);
+    s.client.lock_funds(&s.depositor, &2, &1_000, &s.deadline);
+}
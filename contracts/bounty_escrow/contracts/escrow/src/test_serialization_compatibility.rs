@@ -260,9 +260,11 @@ fn serialization_compatibility_public_types_and_events() {
         (
             "FundsLocked",
             FundsLocked {
-                version: EVENT_VERSION_V2,
+                version: EVENT_VERSION_V3,
                 bounty_id,
                 amount,
+                remaining_before: 0,
+                remaining_after: amount,
                 depositor: depositor.clone(),
                 deadline,
             }
@@ -271,9 +273,11 @@ fn serialization_compatibility_public_types_and_events() {
         (
             "FundsReleased",
             FundsReleased {
-                version: EVENT_VERSION_V2,
+                version: EVENT_VERSION_V3,
                 bounty_id,
                 amount: 123,
+                remaining_before: 123,
+                remaining_after: 0,
                 recipient: contributor.clone(),
                 timestamp: 456,
             }
@@ -282,9 +286,11 @@ fn serialization_compatibility_public_types_and_events() {
         (
             "FundsRefunded",
             FundsRefunded {
-                version: EVENT_VERSION_V2,
+                version: EVENT_VERSION_V3,
                 bounty_id,
                 amount: 100,
+                remaining_before: 150,
+                remaining_after: 50,
                 refund_to: depositor.clone(),
                 timestamp: 200,
             }
@@ -340,6 +346,8 @@ fn serialization_compatibility_public_types_and_events() {
                 bounty_id,
                 contributor: contributor.clone(),
                 approver: admin.clone(),
+                weight: 2,
+                total_weight: 3,
                 timestamp: 4,
             }
             .into_val(&env),
@@ -357,9 +365,12 @@ fn serialization_compatibility_public_types_and_events() {
         (
             "ClaimExecuted",
             ClaimExecuted {
+                version: EVENT_VERSION_V3,
                 bounty_id,
                 recipient: recipient.clone(),
                 amount: 100,
+                remaining_before: 100,
+                remaining_after: 0,
                 claimed_at: 300,
             }
             .into_val(&env),
@@ -378,9 +389,12 @@ fn serialization_compatibility_public_types_and_events() {
         (
             "EmergencyWithdrawEvent",
             EmergencyWithdrawEvent {
+                version: EVENT_VERSION_V3,
                 admin: admin.clone(),
                 recipient: depositor.clone(),
                 amount: 1000,
+                remaining_before: 1000,
+                remaining_after: 0,
                 timestamp: 500,
             }
             .into_val(&env),
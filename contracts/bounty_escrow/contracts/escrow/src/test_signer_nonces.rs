@@ -0,0 +1,167 @@
+#![cfg(test)]
+
+use crate::{BountyEscrowContract, BountyEscrowContractClient, ClaimError, Error};
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Bytes, BytesN, Env,
+};
+
+struct Setup<'a> {
+    client: BountyEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    sac: token::StellarAssetClient<'a>,
+    signer: Address,
+    key: SigningKey,
+}
+
+/// Contract with an address holding a registered claim key.
+fn setup<'a>(env: &Env) -> Setup<'a> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+
+    let signer = Address::generate(env);
+    let key = SigningKey::from_bytes(&[11; 32]);
+    client.set_claim_key(
+        &signer,
+        &BytesN::from_array(env, &key.verifying_key().to_bytes()),
+    );
+
+    Setup {
+        client,
+        token: token::Client::new(env, &token_address),
+        sac: token::StellarAssetClient::new(env, &token_address),
+        signer,
+        key,
+    }
+}
+
+fn sign(env: &Env, key: &SigningKey, message: &Bytes) -> BytesN<64> {
+    let mut signed = Bytes::from_slice(env, b"Stellar Signed Message:\n");
+    signed.append(message);
+    let digest = env.crypto().sha256(&signed).to_array();
+    BytesN::from_array(env, &key.sign(&digest).to_bytes())
+}
+
+#[test]
+fn test_nonce_shared_across_intents_and_claims() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.sac.mint(&s.signer, &1_000);
+    let deadline = env.ledger().timestamp() + 1_000;
+    s.client.lock_funds(&s.signer, &1, &1_000, &deadline);
+    let ticket_id = s.client.issue_claim_ticket(&1, &s.signer, &200, &deadline);
+    let contributor = Address::generate(&env);
+    let relayer = Address::generate(&env);
+    assert_eq!(s.client.get_nonce(&s.signer), 0);
+
+    let message = s
+        .client
+        .get_payment_intent_message(&1, &contributor, &300, &0, &deadline);
+    s.client.create_payment_intent(
+        &1,
+        &contributor,
+        &300,
+        &0,
+        &deadline,
+        &sign(&env, &s.key, &message),
+    );
+    assert_eq!(s.client.get_nonce(&s.signer), 1);
+
+    // Nonce 0 is spent, even for a different kind of message.
+    let stale = s.client.get_relayed_claim_message(&ticket_id, &relayer, &0);
+    assert_eq!(
+        s.client
+            .try_claim_with_ticket_for(&ticket_id, &relayer, &0, &sign(&env, &s.key, &stale)),
+        Err(Ok(ClaimError::InvalidNonce.into()))
+    );
+
+    let fresh = s.client.get_relayed_claim_message(&ticket_id, &relayer, &1);
+    s.client
+        .claim_with_ticket_for(&ticket_id, &relayer, &1, &sign(&env, &s.key, &fresh));
+    assert_eq!(s.token.balance(&s.signer), 200);
+    assert_eq!(s.client.get_nonce(&s.signer), 2);
+}
+
+#[test]
+fn test_nonce_cannot_skip_ahead() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.sac.mint(&s.signer, &1_000);
+    let deadline = env.ledger().timestamp() + 1_000;
+    s.client.lock_funds(&s.signer, &1, &1_000, &deadline);
+    let contributor = Address::generate(&env);
+
+    let message = s
+        .client
+        .get_payment_intent_message(&1, &contributor, &300, &5, &deadline);
+    assert_eq!(
+        s.client.try_create_payment_intent(
+            &1,
+            &contributor,
+            &300,
+            &5,
+            &deadline,
+            &sign(&env, &s.key, &message),
+        ),
+        Err(Ok(ClaimError::InvalidNonce.into()))
+    );
+    assert_eq!(s.client.get_nonce(&s.signer), 0);
+}
+
+#[test]
+fn test_signed_resolver_approval() {
+    let env = Env::default();
+    let s = setup(&env);
+    let depositor = Address::generate(&env);
+    s.sac.mint(&depositor, &1_000);
+    let deadline = env.ledger().timestamp() + 100;
+    let commitment = BytesN::from_array(&env, &[5u8; 32]);
+    s.client
+        .lock_funds_anonymous(&depositor, &commitment, &1, &1_000, &deadline);
+    env.ledger().with_mut(|li| li.timestamp = deadline + 1);
+    s.client.add_resolver(&s.signer);
+    let recipient = Address::generate(&env);
+
+    let message = s.client.get_resolver_approval_message(&1, &recipient, &0);
+    let signature = sign(&env, &s.key, &message);
+    s.client
+        .refund_resolved_signed(&1, &recipient, &s.signer, &0, &signature);
+    assert_eq!(s.token.balance(&recipient), 1_000);
+    assert_eq!(s.client.get_nonce(&s.signer), 1);
+
+    // The same signature cannot be replayed.
+    assert_eq!(
+        s.client
+            .try_refund_resolved_signed(&1, &recipient, &s.signer, &0, &signature),
+        Err(Ok(ClaimError::InvalidNonce.into()))
+    );
+}
+
+#[test]
+fn test_signed_resolver_approval_requires_resolver() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.add_resolver(&Address::generate(&env));
+    let recipient = Address::generate(&env);
+
+    let message = s.client.get_resolver_approval_message(&1, &recipient, &0);
+    assert_eq!(
+        s.client.try_refund_resolved_signed(
+            &1,
+            &recipient,
+            &s.signer,
+            &0,
+            &sign(&env, &s.key, &message),
+        ),
+        Err(Ok(Error::Unauthorized.into()))
+    );
+}
@@ -0,0 +1,151 @@
+#![cfg(test)]
+
+use crate::{
+    BountyEscrowContract, BountyEscrowContractClient, ComplianceError, Error,
+    EscrowJurisdictionConfig, EscrowStatus, OptionalJurisdiction,
+};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+struct Setup<'a> {
+    client: BountyEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    depositor: Address,
+    deadline: u64,
+}
+
+/// Bounty 1 locked for 1_000.
+fn setup<'a>(env: &Env) -> Setup<'a> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_address).mint(&depositor, &1_000);
+    let deadline = env.ledger().timestamp() + 1_000;
+    client.lock_funds(&depositor, &1, &1_000, &deadline);
+
+    Setup {
+        client,
+        token: token::Client::new(env, &token_address),
+        depositor,
+        deadline,
+    }
+}
+
+#[test]
+fn test_split_creates_independent_child() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.split_escrow(&1, &2, &400);
+    let parent = s.client.get_escrow_info(&1);
+    let child = s.client.get_escrow_info(&2);
+    assert_eq!((parent.amount, parent.remaining_amount), (600, 600));
+    assert_eq!((child.amount, child.remaining_amount), (400, 400));
+    assert_eq!(child.depositor, s.depositor);
+    assert_eq!(child.deadline, s.deadline);
+    assert_eq!(child.status, EscrowStatus::Locked);
+
+    let summary = s.client.get_depositor_summary(&s.depositor);
+    assert_eq!(summary.active_escrows, 2);
+    assert_eq!(summary.total_locked, 1_000);
+
+    // The two escrows now settle independently.
+    let contributor = Address::generate(&env);
+    s.client.release_funds(&2, &contributor);
+    assert_eq!(s.token.balance(&contributor), 400);
+    env.ledger().with_mut(|li| li.timestamp = s.deadline + 1);
+    s.client.refund(&1);
+    assert_eq!(s.token.balance(&s.depositor), 600);
+}
+
+#[test]
+fn test_split_after_partial_release() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.partial_release(&1, &Address::generate(&env), &300);
+
+    assert_eq!(
+        s.client.try_split_escrow(&1, &2, &700),
+        Err(Ok(Error::InvalidAmount.into()))
+    );
+    s.client.split_escrow(&1, &2, &500);
+    let parent = s.client.get_escrow_info(&1);
+    assert_eq!((parent.amount, parent.remaining_amount), (500, 200));
+}
+
+#[test]
+fn test_split_preserves_jurisdiction() {
+    let env = Env::default();
+    let s = setup(&env);
+    let tag = String::from_str(&env, "EU-only");
+    s.client.register_jurisdiction_tag(&tag);
+    let config = EscrowJurisdictionConfig {
+        tag: Some(tag),
+        requires_kyc: false,
+        enforce_identity_limits: true,
+        lock_paused: false,
+        release_paused: true,
+        refund_paused: false,
+        max_lock_amount: None,
+    };
+    let sac = token::StellarAssetClient::new(&env, &s.token.address);
+    sac.mint(&s.depositor, &1_000);
+    s.client.lock_funds_with_jurisdiction(
+        &s.depositor,
+        &3,
+        &1_000,
+        &s.deadline,
+        &OptionalJurisdiction::Some(config.clone()),
+    );
+
+    s.client.split_escrow(&3, &4, &250);
+    assert_eq!(
+        s.client.get_escrow_jurisdiction(&4),
+        OptionalJurisdiction::Some(config)
+    );
+    assert_eq!(
+        s.client.try_release_funds(&4, &Address::generate(&env)),
+        Err(Ok(ComplianceError::JurisdictionPaused.into()))
+    );
+}
+
+#[test]
+fn test_split_rejections() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    assert_eq!(
+        s.client.try_split_escrow(&1, &2, &0),
+        Err(Ok(Error::InvalidAmount.into()))
+    );
+    assert_eq!(
+        s.client.try_split_escrow(&1, &2, &1_000),
+        Err(Ok(Error::InvalidAmount.into()))
+    );
+    assert_eq!(
+        s.client.try_split_escrow(&9, &2, &100),
+        Err(Ok(Error::BountyNotFound.into()))
+    );
+    s.client.split_escrow(&1, &2, &100);
+    assert_eq!(
+        s.client.try_split_escrow(&1, &2, &100),
+        Err(Ok(Error::BountyExists.into()))
+    );
+
+    s.client.release_funds(&2, &Address::generate(&env));
+    assert_eq!(
+        s.client.try_split_escrow(&2, &3, &10),
+        Err(Ok(Error::FundsNotLocked.into()))
+    );
+}
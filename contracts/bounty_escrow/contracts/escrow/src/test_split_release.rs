@@ -0,0 +1,131 @@
+#![cfg(test)]
+
+use crate::{BountyEscrowContract, BountyEscrowContractClient, Error, EscrowStatus};
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env};
+
+fn setup<'a>(env: &Env) -> (BountyEscrowContractClient<'a>, token::Client<'a>, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_address).mint(&depositor, &10_000);
+    let deadline = env.ledger().timestamp() + 1_000;
+    client.lock_funds(&depositor, &1, &1_000, &deadline);
+
+    (client, token::Client::new(env, &token_address), depositor)
+}
+
+#[test]
+fn test_split_release_pays_every_recipient() {
+    let env = Env::default();
+    let (client, token, _depositor) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    client.release_funds_split(
+        &1,
+        &vec![&env, alice.clone(), bob.clone()],
+        &vec![&env, 600, 400],
+    );
+
+    assert_eq!(token.balance(&alice), 600);
+    assert_eq!(token.balance(&bob), 400);
+    let escrow = client.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Released);
+    assert_eq!(escrow.remaining_amount, 0);
+
+    let records = client.get_release_records(&1);
+    assert_eq!(records.len(), 2);
+    assert_eq!(records.get(0).unwrap().recipient, alice);
+    assert_eq!(records.get(1).unwrap().amount, 400);
+}
+
+#[test]
+fn test_split_release_charges_the_release_fee_per_payout() {
+    let env = Env::default();
+    let (client, token, _depositor) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    // 5% release fee
+    client.update_fee_config(&None, &Some(500), &Some(treasury.clone()), &Some(true));
+
+    client.release_funds_split(
+        &1,
+        &vec![&env, alice.clone(), bob.clone()],
+        &vec![&env, 600, 400],
+    );
+
+    assert_eq!(token.balance(&alice), 570);
+    assert_eq!(token.balance(&bob), 380);
+    assert_eq!(token.balance(&treasury), 50);
+    assert_eq!(client.get_escrow_info(&1).remaining_amount, 0);
+    assert_eq!(client.get_release_records(&1).get(0).unwrap().amount, 600);
+}
+
+#[test]
+fn test_partial_split_keeps_remainder_locked() {
+    let env = Env::default();
+    let (client, token, _depositor) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    client.release_funds_split(&1, &vec![&env, alice.clone()], &vec![&env, 300]);
+    let escrow = client.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Locked);
+    assert_eq!(escrow.remaining_amount, 700);
+
+    client.release_funds_split(&1, &vec![&env, bob.clone()], &vec![&env, 700]);
+    assert_eq!(token.balance(&bob), 700);
+    assert_eq!(client.get_escrow_info(&1).status, EscrowStatus::Released);
+    assert_eq!(client.get_release_records(&1).len(), 2);
+}
+
+#[test]
+fn test_split_release_rejects_invalid_splits() {
+    let env = Env::default();
+    let (client, token, _depositor) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    assert_eq!(
+        client.try_release_funds_split(
+            &1,
+            &vec![&env, alice.clone(), bob.clone()],
+            &vec![&env, 600, 401]
+        ),
+        Err(Ok(Error::InsufficientFunds.into()))
+    );
+    assert_eq!(
+        client.try_release_funds_split(
+            &1,
+            &vec![&env, alice.clone(), bob.clone()],
+            &vec![&env, 600]
+        ),
+        Err(Ok(Error::BatchSizeMismatch.into()))
+    );
+    assert_eq!(
+        client.try_release_funds_split(&1, &vec![&env, alice.clone(), bob], &vec![&env, 600, 0]),
+        Err(Ok(Error::InvalidAmount.into()))
+    );
+    assert_eq!(
+        client.try_release_funds_split(&1, &vec![&env], &vec![&env]),
+        Err(Ok(Error::InvalidBatchSize.into()))
+    );
+    assert_eq!(
+        client.try_release_funds_split(&2, &vec![&env, alice.clone()], &vec![&env, 1]),
+        Err(Ok(Error::BountyNotFound.into()))
+    );
+
+    assert_eq!(token.balance(&alice), 0);
+    assert_eq!(client.get_escrow_info(&1).remaining_amount, 1_000);
+    assert_eq!(client.get_release_records(&1).len(), 0);
+}
@@ -0,0 +1,171 @@
+#![cfg(test)]
+
+use crate::threshold_monitor::{ThresholdConfig, ThresholdError};
+use crate::{BountyEscrowContract, BountyEscrowContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env,
+};
+
+struct Setup<'a> {
+    client: BountyEscrowContractClient<'a>,
+    depositor: Address,
+    deadline: u64,
+}
+
+/// Monitor allowing 1_500 of outflow per 600s window and 1_000 per payout,
+/// with bounties 1..=4 locked for 1_000 each.
+fn setup<'a>(env: &Env) -> Setup<'a> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_address).mint(&depositor, &10_000);
+    let deadline = env.ledger().timestamp() + 100;
+    for id in 1..=4u64 {
+        client.lock_funds(&depositor, &id, &1_000, &deadline);
+    }
+
+    client.set_threshold_config(&config());
+
+    Setup {
+        client,
+        depositor,
+        deadline,
+    }
+}
+
+fn config() -> ThresholdConfig {
+    ThresholdConfig {
+        failure_rate_threshold: 1_000,
+        outflow_volume_threshold: 1_500,
+        max_single_payout: 1_000,
+        time_window_secs: 600,
+        cooldown_period_secs: 60,
+        cooldown_multiplier: 2,
+    }
+}
+
+#[test]
+fn test_outflow_volume_opens_breaker() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.release_funds(&1, &Address::generate(&env));
+    assert!(!s.client.get_threshold_breaker().open);
+
+    // The payout that crosses the window limit completes, then trips.
+    s.client.release_funds(&2, &Address::generate(&env));
+    let breaker = s.client.get_threshold_breaker();
+    assert!(breaker.open);
+    assert_eq!(breaker.cooldown_end, env.ledger().timestamp() + 60);
+    assert_eq!(s.client.get_threshold_metrics().total_outflow, 2_000);
+
+    assert_eq!(
+        s.client.try_release_funds(&3, &Address::generate(&env)),
+        Err(Ok(ThresholdError::CircuitOpen.into()))
+    );
+    let expires_at = env.ledger().timestamp() + 50;
+    let ticket = s
+        .client
+        .issue_claim_ticket(&3, &Address::generate(&env), &100, &expires_at);
+    assert_eq!(
+        s.client.try_claim_with_ticket_partial(&ticket, &100),
+        Err(Ok(ThresholdError::CircuitOpen.into()))
+    );
+}
+
+#[test]
+fn test_reset_waits_for_cooldown_with_backoff() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.release_funds(&1, &Address::generate(&env));
+    s.client.release_funds(&2, &Address::generate(&env));
+
+    assert_eq!(
+        s.client.try_reset_threshold_breaker(),
+        Err(Ok(ThresholdError::ThresholdCooldownActive.into()))
+    );
+    env.ledger().with_mut(|li| li.timestamp += 60);
+    s.client.reset_threshold_breaker();
+    assert!(!s.client.get_threshold_breaker().open);
+    assert_eq!(s.client.get_threshold_metrics().total_outflow, 0);
+
+    // A second breach doubles the cooldown.
+    s.client.release_funds(&3, &Address::generate(&env));
+    s.client.release_funds(&4, &Address::generate(&env));
+    let breaker = s.client.get_threshold_breaker();
+    assert!(breaker.open);
+    assert_eq!(breaker.cooldown_end, env.ledger().timestamp() + 120);
+}
+
+#[test]
+fn test_oversized_payout_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.lock_funds(&s.depositor, &5, &1_200, &s.deadline);
+
+    assert_eq!(
+        s.client.try_release_funds(&5, &Address::generate(&env)),
+        Err(Ok(ThresholdError::ThresholdBreached.into()))
+    );
+    assert!(!s.client.get_threshold_breaker().open);
+}
+
+#[test]
+fn test_refunds_count_but_are_not_halted() {
+    let env = Env::default();
+    let s = setup(&env);
+    env.ledger().with_mut(|li| li.timestamp = s.deadline + 1);
+
+    s.client.refund(&1);
+    s.client.refund(&2);
+    assert!(s.client.get_threshold_breaker().open);
+    s.client.refund(&3);
+    assert_eq!(s.client.get_threshold_metrics().total_outflow, 3_000);
+}
+
+#[test]
+fn test_skipped_batch_refunds_count_as_failures() {
+    let env = Env::default();
+    let s = setup(&env);
+    let mut strict = config();
+    strict.failure_rate_threshold = 1;
+    s.client.set_threshold_config(&strict);
+
+    // A failing call rolls back, so it leaves no trace in the metrics.
+    assert!(s.client.try_release_funds(&99, &s.depositor).is_err());
+    assert_eq!(s.client.get_threshold_metrics().failure_count, 0);
+
+    // Neither bounty is past its deadline; both are skipped.
+    let result = s.client.batch_refund(&vec![&env, 1, 2]);
+    assert_eq!(result.skipped.len(), 2);
+    assert_eq!(s.client.get_threshold_metrics().failure_count, 2);
+    assert!(s.client.get_threshold_breaker().open);
+}
+
+#[test]
+fn test_invalid_config_and_disable() {
+    let env = Env::default();
+    let s = setup(&env);
+    let mut bad = config();
+    bad.time_window_secs = 5;
+    assert_eq!(
+        s.client.try_set_threshold_config(&bad),
+        Err(Ok(ThresholdError::InvalidThresholdConfig.into()))
+    );
+
+    s.client.clear_threshold_config();
+    assert_eq!(s.client.get_threshold_config(), None);
+    s.client.release_funds(&1, &Address::generate(&env));
+    s.client.release_funds(&2, &Address::generate(&env));
+    assert!(!s.client.get_threshold_breaker().open);
+}
@@ -0,0 +1,99 @@
+#![cfg(test)]
+
+use crate::{BountyEscrowContract, BountyEscrowContractClient, Error, TicketRequest};
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, Vec};
+
+fn setup<'a>(env: &Env) -> BountyEscrowContractClient<'a> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_address).mint(&depositor, &10_000);
+    let deadline = env.ledger().timestamp() + 10_000;
+    client.lock_funds(&depositor, &1, &1_000, &deadline);
+    client
+}
+
+fn request(env: &Env, amount: i128, expires_at: u64) -> TicketRequest {
+    TicketRequest {
+        beneficiary: Address::generate(env),
+        amount,
+        expires_at,
+        transferable: false,
+    }
+}
+
+#[test]
+fn test_batch_issues_tickets_in_order() {
+    let env = Env::default();
+    let client = setup(&env);
+    let expires_at = env.ledger().timestamp() + 1_000;
+    let items = vec![
+        &env,
+        request(&env, 500, expires_at),
+        request(&env, 300, expires_at),
+        request(&env, 200, expires_at),
+    ];
+
+    let ids = client.issue_claim_tickets_batch(&1, &items);
+
+    assert_eq!(ids, vec![&env, 1, 2, 3]);
+    for (i, id) in ids.iter().enumerate() {
+        let ticket = client.get_claim_ticket(&id);
+        let item = items.get(i as u32).unwrap();
+        assert_eq!(ticket.beneficiary, item.beneficiary);
+        assert_eq!(ticket.amount, item.amount);
+    }
+    assert_eq!(client.get_ticket_reserved(&1), 1_000);
+}
+
+#[test]
+fn test_batch_rejects_total_over_unreserved_balance() {
+    let env = Env::default();
+    let client = setup(&env);
+    let expires_at = env.ledger().timestamp() + 1_000;
+    client.issue_claim_ticket(&1, &Address::generate(&env), &400, &expires_at);
+
+    let items = vec![
+        &env,
+        request(&env, 300, expires_at),
+        request(&env, 301, expires_at),
+    ];
+    assert_eq!(
+        client.try_issue_claim_tickets_batch(&1, &items),
+        Err(Ok(Error::InvalidAmount.into()))
+    );
+    // Nothing from the failed batch was reserved
+    assert_eq!(client.get_ticket_reserved(&1), 400);
+}
+
+#[test]
+fn test_batch_validates_every_item() {
+    let env = Env::default();
+    let client = setup(&env);
+    let now = env.ledger().timestamp();
+
+    assert_eq!(
+        client.try_issue_claim_tickets_batch(&1, &Vec::new(&env)),
+        Err(Ok(Error::InvalidBatchSize.into()))
+    );
+    assert_eq!(
+        client.try_issue_claim_tickets_batch(
+            &1,
+            &vec![&env, request(&env, 100, now + 10), request(&env, 100, now)]
+        ),
+        Err(Ok(Error::InvalidDeadline.into()))
+    );
+    assert_eq!(
+        client.try_issue_claim_tickets_batch(&2, &vec![&env, request(&env, 100, now + 10)]),
+        Err(Ok(Error::BountyNotFound.into()))
+    );
+}
@@ -0,0 +1,103 @@
+#![cfg(test)]
+
+use crate::{BountyEscrowContract, BountyEscrowContractClient, Error, EscrowStatus};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env,
+};
+
+fn setup<'a>(env: &Env) -> (BountyEscrowContractClient<'a>, token::Client<'a>, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_address).mint(&depositor, &10_000);
+    let deadline = env.ledger().timestamp() + 10_000;
+    client.lock_funds(&depositor, &1, &1_000, &deadline);
+
+    (
+        client,
+        token::Client::new(env, &token_address),
+        Address::generate(env),
+    )
+}
+
+#[test]
+fn test_partial_claims_until_exhausted() {
+    let env = Env::default();
+    let (client, token, contributor) = setup(&env);
+    let expires_at = env.ledger().timestamp() + 1_000;
+    let ticket_id = client.issue_claim_ticket(&1, &contributor, &600, &expires_at);
+
+    client.claim_with_ticket_partial(&ticket_id, &200);
+    let ticket = client.get_claim_ticket(&ticket_id);
+    assert_eq!(ticket.remaining_amount, 400);
+    assert!(!ticket.used);
+    assert_eq!(client.get_ticket_reserved(&1), 400);
+
+    client.claim_with_ticket_partial(&ticket_id, &400);
+    let ticket = client.get_claim_ticket(&ticket_id);
+    assert_eq!(ticket.remaining_amount, 0);
+    assert!(ticket.used);
+    assert_eq!(client.get_ticket_reserved(&1), 0);
+
+    assert_eq!(token.balance(&contributor), 600);
+    let escrow = client.get_escrow_info(&1);
+    assert_eq!(escrow.remaining_amount, 400);
+    assert_eq!(escrow.status, EscrowStatus::Locked);
+
+    let claims = client.get_ticket_claims(&ticket_id);
+    assert_eq!(claims.len(), 2);
+    assert_eq!(claims.get(0).unwrap().amount, 200);
+    assert_eq!(claims.get(1).unwrap().amount, 400);
+
+    assert_eq!(
+        client.try_claim_with_ticket_partial(&ticket_id, &1),
+        Err(Ok(Error::TicketAlreadyUsed.into()))
+    );
+}
+
+#[test]
+fn test_partial_claim_cannot_exceed_ticket_balance() {
+    let env = Env::default();
+    let (client, _token, contributor) = setup(&env);
+    let expires_at = env.ledger().timestamp() + 1_000;
+    let ticket_id = client.issue_claim_ticket(&1, &contributor, &300, &expires_at);
+
+    client.claim_with_ticket_partial(&ticket_id, &100);
+    assert_eq!(
+        client.try_claim_with_ticket_partial(&ticket_id, &201),
+        Err(Ok(Error::InvalidAmount.into()))
+    );
+    assert_eq!(
+        client.try_claim_with_ticket_partial(&ticket_id, &0),
+        Err(Ok(Error::InvalidAmount.into()))
+    );
+}
+
+#[test]
+fn test_expired_ticket_sweeps_only_unclaimed_balance() {
+    let env = Env::default();
+    let (client, _token, contributor) = setup(&env);
+    let expires_at = env.ledger().timestamp() + 1_000;
+    let ticket_id = client.issue_claim_ticket(&1, &contributor, &500, &expires_at);
+
+    client.claim_with_ticket_partial(&ticket_id, &150);
+    env.ledger().set_timestamp(expires_at);
+    assert_eq!(
+        client.try_claim_with_ticket_partial(&ticket_id, &50),
+        Err(Ok(Error::TicketExpired.into()))
+    );
+
+    assert_eq!(client.sweep_expired_tickets(&1), 1);
+    assert_eq!(client.get_ticket_reserved(&1), 0);
+    assert_eq!(client.get_escrow_info(&1).remaining_amount, 850);
+}
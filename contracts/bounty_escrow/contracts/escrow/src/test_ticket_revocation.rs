@@ -0,0 +1,92 @@
+#![cfg(test)]
+
+use crate::{BountyEscrowContract, BountyEscrowContractClient, ClaimError, Error};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env,
+};
+
+fn setup<'a>(env: &Env) -> (BountyEscrowContractClient<'a>, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_address).mint(&depositor, &10_000);
+    let deadline = env.ledger().timestamp() + 10_000;
+    client.lock_funds(&depositor, &1, &1_000, &deadline);
+
+    (client, Address::generate(env))
+}
+
+#[test]
+fn test_tickets_cannot_over_reserve_bounty() {
+    let env = Env::default();
+    let (client, contributor) = setup(&env);
+    let expires_at = env.ledger().timestamp() + 1_000;
+
+    client.issue_claim_ticket(&1, &contributor, &700, &expires_at);
+    assert_eq!(client.get_ticket_reserved(&1), 700);
+    assert_eq!(
+        client.try_issue_claim_ticket(&1, &contributor, &301, &expires_at),
+        Err(Ok(Error::InvalidAmount.into()))
+    );
+    client.issue_claim_ticket(&1, &contributor, &300, &expires_at);
+    assert_eq!(client.get_ticket_reserved(&1), 1_000);
+}
+
+#[test]
+fn test_revoke_frees_reservation() {
+    let env = Env::default();
+    let (client, contributor) = setup(&env);
+    let expires_at = env.ledger().timestamp() + 1_000;
+    let ticket_id = client.issue_claim_ticket(&1, &contributor, &1_000, &expires_at);
+
+    client.revoke_claim_ticket(&ticket_id);
+
+    assert!(client.get_claim_ticket(&ticket_id).revoked);
+    assert_eq!(client.get_ticket_reserved(&1), 0);
+    assert_eq!(
+        client.try_revoke_claim_ticket(&ticket_id),
+        Err(Ok(ClaimError::TicketRevoked.into()))
+    );
+    client.issue_claim_ticket(&1, &contributor, &1_000, &expires_at);
+}
+
+#[test]
+fn test_revoked_transferable_ticket_cannot_move() {
+    let env = Env::default();
+    let (client, contributor) = setup(&env);
+    let expires_at = env.ledger().timestamp() + 1_000;
+    let ticket_id = client.issue_transferable_claim_ticket(&1, &contributor, &500, &expires_at);
+
+    client.revoke_claim_ticket(&ticket_id);
+    assert_eq!(
+        client.try_transfer_claim_ticket(&ticket_id, &Address::generate(&env)),
+        Err(Ok(ClaimError::TicketRevoked.into()))
+    );
+}
+
+#[test]
+fn test_sweep_releases_only_expired_tickets() {
+    let env = Env::default();
+    let (client, contributor) = setup(&env);
+    let now = env.ledger().timestamp();
+    client.issue_claim_ticket(&1, &contributor, &400, &(now + 100));
+    client.issue_claim_ticket(&1, &contributor, &250, &(now + 5_000));
+
+    assert_eq!(client.sweep_expired_tickets(&1), 0);
+    assert_eq!(client.get_ticket_reserved(&1), 650);
+
+    env.ledger().set_timestamp(now + 100);
+    assert_eq!(client.sweep_expired_tickets(&1), 1);
+    assert_eq!(client.get_ticket_reserved(&1), 250);
+    assert_eq!(client.sweep_expired_tickets(&1), 0);
+}
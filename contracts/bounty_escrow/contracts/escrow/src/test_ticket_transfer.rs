@@ -0,0 +1,82 @@
+#![cfg(test)]
+
+use crate::{BountyEscrowContract, BountyEscrowContractClient, ClaimError, Error};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env,
+};
+
+fn setup<'a>(env: &Env) -> (BountyEscrowContractClient<'a>, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_address).mint(&depositor, &10_000);
+    let deadline = env.ledger().timestamp() + 10_000;
+    client.lock_funds(&depositor, &1, &1_000, &deadline);
+
+    (client, Address::generate(env))
+}
+
+#[test]
+fn test_transfer_moves_ticket_to_new_beneficiary() {
+    let env = Env::default();
+    let (client, contributor) = setup(&env);
+    let team_wallet = Address::generate(&env);
+    let expires_at = env.ledger().timestamp() + 1_000;
+
+    let ticket_id = client.issue_transferable_claim_ticket(&1, &contributor, &400, &expires_at);
+    assert!(client.get_claim_ticket(&ticket_id).transferable);
+
+    client.transfer_claim_ticket(&ticket_id, &team_wallet);
+
+    let ticket = client.get_claim_ticket(&ticket_id);
+    assert_eq!(ticket.beneficiary, team_wallet);
+    assert_eq!(ticket.amount, 400);
+    assert_eq!(client.get_beneficiary_tickets(&contributor), vec![&env]);
+    assert_eq!(
+        client.get_beneficiary_tickets(&team_wallet),
+        vec![&env, ticket_id]
+    );
+}
+
+#[test]
+fn test_default_tickets_are_not_transferable() {
+    let env = Env::default();
+    let (client, contributor) = setup(&env);
+    let expires_at = env.ledger().timestamp() + 1_000;
+
+    let ticket_id = client.issue_claim_ticket(&1, &contributor, &400, &expires_at);
+    assert!(!client.get_claim_ticket(&ticket_id).transferable);
+    assert_eq!(
+        client.try_transfer_claim_ticket(&ticket_id, &Address::generate(&env)),
+        Err(Ok(ClaimError::TicketNotTransferable.into()))
+    );
+}
+
+#[test]
+fn test_transfer_rejects_expired_or_missing_ticket() {
+    let env = Env::default();
+    let (client, contributor) = setup(&env);
+    let expires_at = env.ledger().timestamp() + 1_000;
+    let ticket_id = client.issue_transferable_claim_ticket(&1, &contributor, &400, &expires_at);
+
+    assert_eq!(
+        client.try_transfer_claim_ticket(&99, &contributor),
+        Err(Ok(Error::TicketNotFound.into()))
+    );
+
+    env.ledger().set_timestamp(expires_at);
+    assert_eq!(
+        client.try_transfer_claim_ticket(&ticket_id, &Address::generate(&env)),
+        Err(Ok(Error::TicketExpired.into()))
+    );
+}
@@ -0,0 +1,145 @@
+#![cfg(test)]
+
+use crate::{BountyEscrowContract, BountyEscrowContractClient, Error, EscrowStatus, ReleaseError};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env,
+};
+
+struct Setup<'a> {
+    client: BountyEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    depositor: Address,
+    contributor: Address,
+    start: u64,
+}
+
+/// Bounty 1 locked for 1_000 and vesting to `contributor` over 100s.
+fn setup<'a>(env: &Env) -> Setup<'a> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_address).mint(&depositor, &1_000);
+    let start = env.ledger().timestamp();
+    client.lock_funds(&depositor, &1, &1_000, &(start + 1_000));
+
+    let contributor = Address::generate(env);
+    client.release_vested(&1, &contributor, &100);
+
+    Setup {
+        client,
+        token: token::Client::new(env, &token_address),
+        depositor,
+        contributor,
+        start,
+    }
+}
+
+#[test]
+fn test_contributor_withdraws_linearly() {
+    let env = Env::default();
+    let s = setup(&env);
+    assert_eq!(
+        s.client.get_escrow_info(&1).status,
+        EscrowStatus::PendingRelease
+    );
+    assert_eq!(
+        s.client.try_withdraw_vested(&1),
+        Err(Ok(ReleaseError::NothingVested.into()))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = s.start + 25);
+    assert_eq!(s.client.get_withdrawable_vested(&1), 250);
+    assert_eq!(s.client.withdraw_vested(&1), 250);
+    assert_eq!(s.token.balance(&s.contributor), 250);
+    assert_eq!(s.client.get_escrow_info(&1).remaining_amount, 750);
+
+    env.ledger().with_mut(|li| li.timestamp = s.start + 500);
+    assert_eq!(s.client.withdraw_vested(&1), 750);
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+    assert_eq!(s.client.get_escrow_info(&1).status, EscrowStatus::Released);
+    assert_eq!(s.client.get_vesting(&1), None);
+}
+
+#[test]
+fn test_cancel_settles_vested_and_refunds_rest() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    env.ledger().with_mut(|li| li.timestamp = s.start + 20);
+    s.client.withdraw_vested(&1);
+    env.ledger().with_mut(|li| li.timestamp = s.start + 40);
+    s.client.cancel_vesting(&1);
+
+    assert_eq!(s.token.balance(&s.contributor), 400);
+    assert_eq!(s.token.balance(&s.depositor), 600);
+    let escrow = s.client.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Released);
+    assert_eq!(escrow.remaining_amount, 0);
+    assert_eq!(escrow.refund_history.get(0).unwrap().amount, 600);
+    assert_eq!(
+        s.client.try_withdraw_vested(&1),
+        Err(Ok(ReleaseError::VestingNotFound.into()))
+    );
+}
+
+#[test]
+fn test_cancel_before_anything_vests_is_full_refund() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.cancel_vesting(&1);
+
+    assert_eq!(s.token.balance(&s.depositor), 1_000);
+    assert_eq!(s.client.get_escrow_info(&1).status, EscrowStatus::Refunded);
+}
+
+#[test]
+fn test_vesting_escrow_rejects_other_payouts() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    assert_eq!(
+        s.client.try_release_funds(&1, &Address::generate(&env)),
+        Err(Ok(Error::FundsNotLocked.into()))
+    );
+    assert_eq!(
+        s.client.try_release_vested(&1, &s.contributor, &100),
+        Err(Ok(Error::FundsNotLocked.into()))
+    );
+    assert_eq!(
+        s.client.try_release_vested(&2, &s.contributor, &0),
+        Err(Ok(Error::InvalidAmount.into()))
+    );
+}
+
+#[test]
+fn test_cancel_respects_pause_flags() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.set_paused(&None, &None, &Some(true), &None);
+    assert_eq!(
+        s.client.try_cancel_vesting(&1),
+        Err(Ok(Error::FundsPaused.into()))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = s.start + 100);
+    s.client.set_paused(&None, &Some(true), &Some(false), &None);
+    assert_eq!(
+        s.client.try_cancel_vesting(&1),
+        Err(Ok(Error::FundsPaused.into()))
+    );
+    s.client.set_paused(&None, &Some(false), &None, &None);
+    s.client.cancel_vesting(&1);
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+    assert_eq!(s.token.balance(&s.depositor), 0);
+}
@@ -0,0 +1,147 @@
+#![cfg(test)]
+
+use crate::{BountyEscrowContract, BountyEscrowContractClient, Error, EscrowStatus, ReleaseError};
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env};
+
+struct Setup<'a> {
+    client: BountyEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    signers: [Address; 3],
+}
+
+fn setup<'a>(env: &Env) -> Setup<'a> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_address).mint(&depositor, &10_000);
+    let deadline = env.ledger().timestamp() + 1_000;
+    client.lock_funds(&depositor, &1, &1_000, &deadline);
+
+    let signers = [
+        Address::generate(env),
+        Address::generate(env),
+        Address::generate(env),
+    ];
+    client.update_multisig_config(
+        &500,
+        &vec![
+            env,
+            signers[0].clone(),
+            signers[1].clone(),
+            signers[2].clone(),
+        ],
+        &2,
+    );
+
+    Setup {
+        client,
+        token: token::Client::new(env, &token_address),
+        signers,
+    }
+}
+
+#[test]
+fn test_threshold_defaults_to_required_signatures() {
+    let env = Env::default();
+    let s = setup(&env);
+    let contributor = Address::generate(&env);
+
+    assert_eq!(s.client.get_approval_weight_threshold(), 2);
+    assert_eq!(s.client.get_approver_weight(&s.signers[0]), 1);
+
+    s.client
+        .approve_large_release(&1, &contributor, &s.signers[0]);
+    assert_eq!(s.client.get_release_approval_weight(&1), 1);
+    assert_eq!(s.client.get_escrow_info(&1).status, EscrowStatus::Locked);
+
+    s.client
+        .approve_large_release(&1, &contributor, &s.signers[1]);
+    assert_eq!(s.client.get_escrow_info(&1).status, EscrowStatus::Released);
+    assert_eq!(s.token.balance(&contributor), 1_000);
+    assert_eq!(s.client.get_release_approval_weight(&1), 0);
+}
+
+#[test]
+fn test_heavy_signer_reaches_threshold_alone() {
+    let env = Env::default();
+    let s = setup(&env);
+    let contributor = Address::generate(&env);
+    s.client.set_approval_weight_threshold(&3);
+    s.client.set_approver_weight(&s.signers[0], &3);
+
+    s.client
+        .approve_large_release(&1, &contributor, &s.signers[0]);
+    assert_eq!(s.client.get_escrow_info(&1).status, EscrowStatus::Released);
+    assert_eq!(s.token.balance(&contributor), 1_000);
+}
+
+#[test]
+fn test_zero_weight_signer_does_not_count() {
+    let env = Env::default();
+    let s = setup(&env);
+    let contributor = Address::generate(&env);
+    s.client.set_approver_weight(&s.signers[1], &0);
+
+    s.client
+        .approve_large_release(&1, &contributor, &s.signers[0]);
+    s.client
+        .approve_large_release(&1, &contributor, &s.signers[1]);
+    assert_eq!(s.client.get_release_approval_weight(&1), 1);
+    assert_eq!(s.client.get_escrow_info(&1).status, EscrowStatus::Locked);
+
+    s.client
+        .approve_large_release(&1, &contributor, &s.signers[2]);
+    assert_eq!(s.client.get_escrow_info(&1).status, EscrowStatus::Released);
+}
+
+#[test]
+fn test_weight_change_recalculates_pending_quorum() {
+    let env = Env::default();
+    let s = setup(&env);
+    let contributor = Address::generate(&env);
+    s.client.set_approval_weight_threshold(&4);
+
+    s.client
+        .approve_large_release(&1, &contributor, &s.signers[0]);
+    assert_eq!(s.client.get_release_approval_weight(&1), 1);
+
+    s.client.set_approver_weight(&s.signers[0], &3);
+    assert_eq!(s.client.get_release_approval_weight(&1), 3);
+
+    s.client
+        .approve_large_release(&1, &contributor, &s.signers[1]);
+    assert_eq!(s.client.get_escrow_info(&1).status, EscrowStatus::Released);
+}
+
+#[test]
+fn test_approval_rejects_mismatched_contributor_and_bad_threshold() {
+    let env = Env::default();
+    let s = setup(&env);
+    let contributor = Address::generate(&env);
+
+    s.client
+        .approve_large_release(&1, &contributor, &s.signers[0]);
+    assert_eq!(
+        s.client
+            .try_approve_large_release(&1, &Address::generate(&env), &s.signers[1]),
+        Err(Ok(ReleaseError::ApprovalContributorMismatch.into()))
+    );
+    assert_eq!(
+        s.client.try_set_approval_weight_threshold(&0),
+        Err(Ok(Error::InvalidAmount.into()))
+    );
+    assert_eq!(
+        s.client
+            .try_approve_large_release(&1, &contributor, &Address::generate(&env)),
+        Err(Ok(Error::Unauthorized.into()))
+    );
+}
@@ -0,0 +1,93 @@
+#![cfg(test)]
+
+use crate::{BountyEscrowContract, BountyEscrowContractClient, Error};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, BytesN, Env,
+};
+
+fn setup<'a>(env: &Env) -> BountyEscrowContractClient<'a> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.init(&admin, &token_address);
+
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_address).mint(&depositor, &10_000);
+    let deadline = env.ledger().timestamp() + 10_000;
+    client.lock_funds(&depositor, &1, &1_000, &deadline);
+    client
+}
+
+fn hash(env: &Env, byte: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[byte; 32])
+}
+
+#[test]
+fn test_submissions_are_kept_in_order() {
+    let env = Env::default();
+    let client = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    client.submit_work(&1, &alice, &hash(&env, 1));
+    env.ledger().with_mut(|li| li.timestamp += 10);
+    client.submit_work(&1, &bob, &hash(&env, 2));
+
+    let submissions = client.get_work_submissions(&1);
+    assert_eq!(submissions.len(), 2);
+    let first = submissions.get(0).unwrap();
+    let second = submissions.get(1).unwrap();
+    assert_eq!(first.contributor, alice);
+    assert_eq!(second.contributor, bob);
+    assert!(first.submitted_at < second.submitted_at);
+}
+
+#[test]
+fn test_release_references_latest_submission_of_recipient() {
+    let env = Env::default();
+    let client = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    client.submit_work(&1, &alice, &hash(&env, 1));
+    client.submit_work(&1, &alice, &hash(&env, 3));
+    client.submit_work(&1, &bob, &hash(&env, 2));
+    client.release_funds(&1, &alice);
+
+    let paid = client.get_paid_work(&1).unwrap();
+    assert_eq!(paid.contributor, alice);
+    assert_eq!(paid.work_hash, hash(&env, 3));
+}
+
+#[test]
+fn test_release_without_submission_records_nothing() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    client.release_funds(&1, &Address::generate(&env));
+    assert_eq!(client.get_paid_work(&1), None);
+}
+
+#[test]
+fn test_submit_work_requires_locked_bounty() {
+    let env = Env::default();
+    let client = setup(&env);
+    let alice = Address::generate(&env);
+
+    assert_eq!(
+        client.try_submit_work(&2, &alice, &hash(&env, 1)),
+        Err(Ok(Error::BountyNotFound.into()))
+    );
+    client.release_funds(&1, &alice);
+    assert_eq!(
+        client.try_submit_work(&1, &alice, &hash(&env, 1)),
+        Err(Ok(Error::FundsNotLocked.into()))
+    );
+}
@@ -0,0 +1,381 @@
+// contracts/bounty_escrow/contracts/escrow/src/threshold_monitor.rs
+//
+// Threshold Monitor Module
+//
+// Port of the program-escrow threshold monitor. Tracks payout outflows and
+// failed operations in fixed time windows and opens a circuit breaker that
+// halts releases when a window looks like an abnormal drain. Monitoring is
+// off until the admin stores a `ThresholdConfig`.
+
+use soroban_sdk::{contracterror, contracttype, symbol_short, Address, Env, Symbol};
+
+// ─────────────────────────────────────────────────────────
+// Types
+// ─────────────────────────────────────────────────────────
+
+/// Errors raised by the threshold monitor (codes 500+)
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ThresholdError {
+    /// The payout exceeds the threshold monitor's max single payout
+    ThresholdBreached = 500,
+    /// The threshold circuit breaker is open; releases are halted
+    CircuitOpen = 501,
+    /// The circuit breaker cooldown has not elapsed
+    ThresholdCooldownActive = 502,
+    /// The threshold monitor configuration is out of range
+    InvalidThresholdConfig = 503,
+}
+
+/// Configuration for threshold-based circuit breaking
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ThresholdConfig {
+    /// Maximum failures allowed per time window. Only failures recorded by a
+    /// committed call count (ids skipped by `batch_refund`); an entrypoint
+    /// that returns an error rolls back everything it wrote.
+    pub failure_rate_threshold: u32,
+    /// Maximum outflow amount per time window
+    pub outflow_volume_threshold: i128,
+    /// Maximum amount for a single payout transaction
+    pub max_single_payout: i128,
+    /// Time window duration in seconds
+    pub time_window_secs: u64,
+    /// Minimum cooldown period before the breaker can be reset (seconds)
+    pub cooldown_period_secs: u64,
+    /// Backoff multiplier for repeated breaches
+    pub cooldown_multiplier: u32,
+}
+
+impl ThresholdConfig {
+    /// Validate configuration values
+    pub fn validate(&self) -> Result<(), ThresholdError> {
+        if self.failure_rate_threshold == 0 || self.failure_rate_threshold > 1000 {
+            return Err(ThresholdError::InvalidThresholdConfig);
+        }
+        if self.outflow_volume_threshold <= 0 || self.max_single_payout <= 0 {
+            return Err(ThresholdError::InvalidThresholdConfig);
+        }
+        if self.time_window_secs < 10 || self.time_window_secs > 86400 {
+            return Err(ThresholdError::InvalidThresholdConfig);
+        }
+        if self.cooldown_period_secs < 60 || self.cooldown_period_secs > 3600 {
+            return Err(ThresholdError::InvalidThresholdConfig);
+        }
+        if self.cooldown_multiplier == 0 {
+            return Err(ThresholdError::InvalidThresholdConfig);
+        }
+        Ok(())
+    }
+}
+
+/// Current metrics for a time window
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WindowMetrics {
+    /// Window start timestamp
+    pub window_start: u64,
+    /// Failures in current window
+    pub failure_count: u32,
+    /// Successful outflows in current window
+    pub success_count: u32,
+    /// Total outflow in current window
+    pub total_outflow: i128,
+    /// Largest single outflow in window
+    pub max_single_outflow: i128,
+    /// Number of times thresholds breached
+    pub breach_count: u32,
+}
+
+impl WindowMetrics {
+    pub fn new(window_start: u64) -> Self {
+        WindowMetrics {
+            window_start,
+            failure_count: 0,
+            success_count: 0,
+            total_outflow: 0,
+            max_single_outflow: 0,
+            breach_count: 0,
+        }
+    }
+}
+
+/// Circuit breaker state driven by the monitor
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BreakerState {
+    /// Releases are halted while open
+    pub open: bool,
+    /// Earliest time the admin may close the breaker
+    pub cooldown_end: u64,
+    /// Applied to `cooldown_period_secs` on the next breach
+    pub cooldown_multiplier: u32,
+}
+
+/// Threshold breach information
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ThresholdBreach {
+    /// Type of metric that breached ("failure" or "outflow")
+    pub metric_type: Symbol,
+    /// Configured threshold value
+    pub threshold_value: i128,
+    /// Actual value that breached
+    pub actual_value: i128,
+    /// When breach occurred
+    pub timestamp: u64,
+    /// Total breaches in this window
+    pub breach_count: u32,
+}
+
+/// Storage keys for threshold monitoring
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ThresholdKey {
+    Config,
+    CurrentMetrics,
+    PreviousMetrics,
+    Breaker,
+}
+
+// ─────────────────────────────────────────────────────────
+// Configuration Management
+// ─────────────────────────────────────────────────────────
+
+/// Store a new configuration (admin only - caller must enforce auth)
+pub fn set_threshold_config(env: &Env, config: ThresholdConfig) -> Result<(), ThresholdError> {
+    config.validate()?;
+
+    if get_threshold_config(env).is_none() {
+        env.storage().persistent().set(
+            &ThresholdKey::CurrentMetrics,
+            &WindowMetrics::new(env.ledger().timestamp()),
+        );
+    }
+    env.storage()
+        .persistent()
+        .set(&ThresholdKey::Config, &config);
+
+    env.events().publish(
+        (symbol_short!("th_cfg"), symbol_short!("update")),
+        (
+            config.failure_rate_threshold,
+            config.outflow_volume_threshold,
+            config.max_single_payout,
+            config.time_window_secs,
+        ),
+    );
+    Ok(())
+}
+
+/// Turn monitoring off. An open breaker stays open until reset.
+pub fn clear_threshold_config(env: &Env) {
+    env.storage().persistent().remove(&ThresholdKey::Config);
+}
+
+/// Get current threshold configuration, if monitoring is enabled
+pub fn get_threshold_config(env: &Env) -> Option<ThresholdConfig> {
+    env.storage().persistent().get(&ThresholdKey::Config)
+}
+
+// ─────────────────────────────────────────────────────────
+// Metrics Tracking and Window Management
+// ─────────────────────────────────────────────────────────
+
+/// Record a failed protected operation. Must be called from a path that
+/// still commits, or the count is rolled back with the failure.
+pub fn record_operation_failure(env: &Env) {
+    let config = match get_threshold_config(env) {
+        Some(config) => config,
+        None => return,
+    };
+    rotate_window_if_needed(env, &config);
+
+    let mut metrics = get_current_metrics(env);
+    metrics.failure_count += 1;
+    store_metrics(env, &metrics);
+    evaluate(env, &config, metrics);
+}
+
+/// Record a completed outflow and trip the breaker if the window is now over
+/// its limits. The outflow itself has already happened.
+pub fn record_outflow(env: &Env, amount: i128) {
+    let config = match get_threshold_config(env) {
+        Some(config) => config,
+        None => return,
+    };
+    rotate_window_if_needed(env, &config);
+
+    let mut metrics = get_current_metrics(env);
+    metrics.success_count += 1;
+    metrics.total_outflow = metrics.total_outflow.saturating_add(amount);
+    if amount > metrics.max_single_outflow {
+        metrics.max_single_outflow = amount;
+    }
+    store_metrics(env, &metrics);
+    evaluate(env, &config, metrics);
+}
+
+/// Get current window metrics
+pub fn get_current_metrics(env: &Env) -> WindowMetrics {
+    env.storage()
+        .persistent()
+        .get(&ThresholdKey::CurrentMetrics)
+        .unwrap_or_else(|| WindowMetrics::new(env.ledger().timestamp()))
+}
+
+fn store_metrics(env: &Env, metrics: &WindowMetrics) {
+    env.storage()
+        .persistent()
+        .set(&ThresholdKey::CurrentMetrics, metrics);
+}
+
+/// Check if window has expired and rotate if needed. A window that closes
+/// without a breach resets the cooldown backoff.
+fn rotate_window_if_needed(env: &Env, config: &ThresholdConfig) {
+    let metrics = get_current_metrics(env);
+    let now = env.ledger().timestamp();
+
+    if now >= metrics.window_start + config.time_window_secs {
+        env.storage()
+            .persistent()
+            .set(&ThresholdKey::PreviousMetrics, &metrics);
+        env.events().publish(
+            (symbol_short!("th_win"), symbol_short!("rotate")),
+            (
+                metrics.window_start,
+                metrics.failure_count,
+                metrics.success_count,
+                metrics.total_outflow,
+            ),
+        );
+
+        let mut breaker = get_breaker(env);
+        if metrics.breach_count == 0 && !breaker.open && breaker.cooldown_multiplier > 1 {
+            breaker.cooldown_multiplier = 1;
+            env.storage()
+                .persistent()
+                .set(&ThresholdKey::Breaker, &breaker);
+        }
+        store_metrics(env, &WindowMetrics::new(now));
+    }
+}
+
+// ─────────────────────────────────────────────────────────
+// Threshold Checking
+// ─────────────────────────────────────────────────────────
+
+/// Reject protected operations while the breaker is open
+pub fn ensure_closed(env: &Env) -> Result<(), ThresholdError> {
+    if get_breaker(env).open {
+        return Err(ThresholdError::CircuitOpen);
+    }
+    Ok(())
+}
+
+/// Reject a single payout above `max_single_payout` before it executes
+pub fn check_single_payout(env: &Env, amount: i128) -> Result<(), ThresholdError> {
+    match get_threshold_config(env) {
+        Some(config) if amount > config.max_single_payout => Err(ThresholdError::ThresholdBreached),
+        _ => Ok(()),
+    }
+}
+
+fn evaluate(env: &Env, config: &ThresholdConfig, metrics: WindowMetrics) {
+    if get_breaker(env).open {
+        return;
+    }
+    let (metric_type, threshold_value, actual_value) =
+        if metrics.failure_count > config.failure_rate_threshold {
+            (
+                symbol_short!("failure"),
+                config.failure_rate_threshold as i128,
+                metrics.failure_count as i128,
+            )
+        } else if metrics.total_outflow > config.outflow_volume_threshold {
+            (
+                symbol_short!("outflow"),
+                config.outflow_volume_threshold,
+                metrics.total_outflow,
+            )
+        } else {
+            return;
+        };
+
+    let breach = ThresholdBreach {
+        metric_type,
+        threshold_value,
+        actual_value,
+        timestamp: env.ledger().timestamp(),
+        breach_count: metrics.breach_count + 1,
+    };
+    open_breaker(env, config, metrics, &breach);
+}
+
+// ─────────────────────────────────────────────────────────
+// Breaker, Cooldown and Anti-Flapping Logic
+// ─────────────────────────────────────────────────────────
+
+/// Get breaker state
+pub fn get_breaker(env: &Env) -> BreakerState {
+    env.storage()
+        .persistent()
+        .get(&ThresholdKey::Breaker)
+        .unwrap_or(BreakerState {
+            open: false,
+            cooldown_end: 0,
+            cooldown_multiplier: 1,
+        })
+}
+
+/// Open the breaker and start a cooldown that grows with repeated breaches
+fn open_breaker(
+    env: &Env,
+    config: &ThresholdConfig,
+    mut metrics: WindowMetrics,
+    breach: &ThresholdBreach,
+) {
+    let mut breaker = get_breaker(env);
+    let now = env.ledger().timestamp();
+    breaker.open = true;
+    breaker.cooldown_end = now + config.cooldown_period_secs * breaker.cooldown_multiplier as u64;
+    breaker.cooldown_multiplier = breaker
+        .cooldown_multiplier
+        .saturating_mul(config.cooldown_multiplier);
+    env.storage()
+        .persistent()
+        .set(&ThresholdKey::Breaker, &breaker);
+
+    metrics.breach_count += 1;
+    store_metrics(env, &metrics);
+
+    env.events().publish(
+        (symbol_short!("th_breach"), breach.metric_type.clone()),
+        (
+            breach.threshold_value,
+            breach.actual_value,
+            breach.timestamp,
+            breach.breach_count,
+        ),
+    );
+}
+
+/// Close the breaker once its cooldown has passed and start a fresh window
+/// (admin only - caller must enforce auth)
+pub fn reset_breaker(env: &Env, admin: &Address) -> Result<(), ThresholdError> {
+    let mut breaker = get_breaker(env);
+    let now = env.ledger().timestamp();
+    if breaker.open && now < breaker.cooldown_end {
+        return Err(ThresholdError::ThresholdCooldownActive);
+    }
+    breaker.open = false;
+    env.storage()
+        .persistent()
+        .set(&ThresholdKey::Breaker, &breaker);
+    store_metrics(env, &WindowMetrics::new(now));
+
+    env.events()
+        .publish((symbol_short!("th_reset"),), (admin.clone(), now));
+    Ok(())
+}
@@ -66,10 +66,10 @@ pub trait EscrowInterface {
         bounty_id: u64,
         amount: i128,
         deadline: u64,
-    ) -> Result<(), crate::Error>;
+    ) -> Result<(), soroban_sdk::Error>;
 
     /// Release the full locked amount to `contributor`. Admin-only.
-    fn release_funds(env: &Env, bounty_id: u64, contributor: Address) -> Result<(), crate::Error>;
+    fn release_funds(env: &Env, bounty_id: u64, contributor: Address) -> Result<(), soroban_sdk::Error>;
 
     /// Release a partial `payout_amount` to `contributor`. Admin-only.
     fn partial_release(
@@ -77,26 +77,26 @@ pub trait EscrowInterface {
         bounty_id: u64,
         contributor: Address,
         payout_amount: i128,
-    ) -> Result<(), crate::Error>;
+    ) -> Result<(), soroban_sdk::Error>;
 
     /// Lock multiple escrows in a batch. Returns count of successfully locked items.
-    fn batch_lock_funds(env: &Env, items: Vec<crate::LockFundsItem>) -> Result<u32, crate::Error>;
+    fn batch_lock_funds(env: &Env, items: Vec<crate::LockFundsItem>) -> Result<u32, soroban_sdk::Error>;
 
     /// Release multiple escrows in a batch. Returns count of successfully released items.
     fn batch_release_funds(
         env: &Env,
         items: Vec<crate::ReleaseFundsItem>,
-    ) -> Result<u32, crate::Error>;
+    ) -> Result<u32, soroban_sdk::Error>;
 
     /// Refund the remaining amount to the original depositor.
     /// Only callable after the escrow deadline has passed (or with admin approval).
-    fn refund(env: &Env, bounty_id: u64) -> Result<(), crate::Error>;
+    fn refund(env: &Env, bounty_id: u64) -> Result<(), soroban_sdk::Error>;
 
     /// Return the current [`crate::Escrow`] record for `bounty_id`.
-    fn get_escrow_info(env: &Env, bounty_id: u64) -> Result<crate::Escrow, crate::Error>;
+    fn get_escrow_info(env: &Env, bounty_id: u64) -> Result<crate::Escrow, soroban_sdk::Error>;
 
     /// Return the contract's current token balance.
-    fn get_balance(env: &Env) -> Result<i128, crate::Error>;
+    fn get_balance(env: &Env) -> Result<i128, soroban_sdk::Error>;
 }
 
 // ============================================================================
@@ -116,7 +116,7 @@ pub trait UpgradeInterface {
     fn get_version(env: &Env) -> u32;
 
     /// Overwrite the stored version number. Admin-only in all implementations.
-    fn set_version(env: &Env, new_version: u32) -> Result<(), crate::Error>;
+    fn set_version(env: &Env, new_version: u32) -> Result<(), soroban_sdk::Error>;
 }
 
 // ============================================================================
@@ -150,7 +150,7 @@ pub trait PauseInterface {
         release: Option<bool>,
         refund: Option<bool>,
         reason: Option<soroban_sdk::String>,
-    ) -> Result<(), crate::Error>;
+    ) -> Result<(), soroban_sdk::Error>;
 
     /// Return the current [`crate::PauseFlags`] without mutating state.
     fn get_pause_flags(env: &Env) -> crate::PauseFlags;
@@ -187,7 +187,7 @@ pub trait FeeInterface {
         release_fee_rate: Option<i128>,
         fee_recipient: Option<Address>,
         fee_enabled: Option<bool>,
-    ) -> Result<(), crate::Error>;
+    ) -> Result<(), soroban_sdk::Error>;
 
     /// Return the current [`crate::FeeConfig`] without mutating state.
     fn get_fee_config(env: &Env) -> crate::FeeConfig;
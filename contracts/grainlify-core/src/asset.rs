@@ -4,7 +4,8 @@
 //! interface by:
 //!
 //! - validating asset identifiers as contract strkeys,
-//! - constructing the current `token::TokenClient`, and
+//! - constructing the current `token::TokenClient`,
+//! - resolving the Stellar Asset Contract (SAC) that wraps native XLM, and
 //! - enforcing exact-balance transfer semantics for escrow-style accounting.
 //!
 //! `validate_asset_id` intentionally only proves that an identifier is a
@@ -14,11 +15,17 @@
 //! guarantees should use the exact-transfer helpers, which verify observed
 //! balance deltas during the actual token operation.
 
-use soroban_sdk::{contracterror, token, Address, Env};
+use soroban_sdk::{contracterror, token, Address, Bytes, Env};
 
 pub type AssetId = Address;
 pub type AssetClient<'a> = token::TokenClient<'a>;
 
+/// XDR encoding of `Asset::Native` (asset type discriminant 0).
+const NATIVE_ASSET_XDR: [u8; 4] = [0, 0, 0, 0];
+
+/// Decimal places of native XLM (1 XLM = 10^7 stroops).
+pub const NATIVE_DECIMALS: u32 = 7;
+
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
@@ -55,6 +62,46 @@ pub fn validate_asset_id(env: &Env, asset_id: &AssetId) -> Result<(), AssetIdErr
     }
 }
 
+/// Returns the address of the SAC wrapping native XLM on the current network.
+///
+/// SAC addresses are derived from the asset, so this does not require the
+/// contract to be deployed yet.
+pub fn native_asset_id(env: &Env) -> AssetId {
+    env.deployer()
+        .with_stellar_asset(Bytes::from_array(env, &NATIVE_ASSET_XDR))
+        .deployed_address()
+}
+
+/// Returns true when `asset_id` is the native XLM SAC.
+pub fn is_native(env: &Env, asset_id: &AssetId) -> bool {
+    *asset_id == native_asset_id(env)
+}
+
+/// Scales `amount`, expressed with `from_decimals` decimal places, to the
+/// smallest unit of `asset_id`.
+///
+/// Native XLM uses `NATIVE_DECIMALS` without querying the SAC; other assets
+/// report their own decimals. Scaling down floors. Returns `None` on overflow.
+pub fn normalize_amount(
+    env: &Env,
+    asset_id: &AssetId,
+    amount: i128,
+    from_decimals: u32,
+) -> Option<i128> {
+    let to_decimals = if is_native(env, asset_id) {
+        NATIVE_DECIMALS
+    } else {
+        token::TokenClient::new(env, asset_id).decimals()
+    };
+    if to_decimals >= from_decimals {
+        let factor = 10_i128.checked_pow(to_decimals - from_decimals)?;
+        amount.checked_mul(factor)
+    } else {
+        let factor = 10_i128.checked_pow(from_decimals - to_decimals)?;
+        Some(amount / factor)
+    }
+}
+
 /// Returns a Soroban token client for the validated asset identifier.
 pub fn token_client<'a>(env: &'a Env, asset_id: &AssetId) -> Result<AssetClient<'a>, AssetIdError> {
     validate_asset_id(env, asset_id)?;
@@ -0,0 +1,76 @@
+//! Escrow version compatibility matrix.
+//!
+//! For each core version the admin declares, per escrow kind, the range of
+//! escrow versions that core version works with. Escrows deployed through the
+//! factory have their `get_version` recorded at registration (and can be
+//! re-read with `refresh_escrow_version` after they upgrade), so clients can
+//! ask whether a given escrow is safe to use with the running core. Entries
+//! for the next core version can be written before it is installed, which
+//! lets the fleet be upgraded in stages.
+
+use crate::factory::EscrowKind;
+use soroban_sdk::{contracttype, Address, Env, Symbol, Vec};
+
+#[contracttype]
+enum CompatKey {
+    /// (core version, escrow kind) -> VersionRange
+    Range(u32, EscrowKind),
+    /// escrow address -> last reported escrow version
+    Reported(Address),
+}
+
+/// Inclusive range of escrow versions supported by a core version.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VersionRange {
+    pub min_version: u32,
+    pub max_version: u32,
+}
+
+pub fn set_range(env: &Env, core_version: u32, kind: EscrowKind, range: &VersionRange) {
+    if range.min_version > range.max_version {
+        panic!("Invalid version range");
+    }
+    env.storage()
+        .persistent()
+        .set(&CompatKey::Range(core_version, kind), range);
+}
+
+pub fn get_range(env: &Env, core_version: u32, kind: EscrowKind) -> Option<VersionRange> {
+    env.storage()
+        .persistent()
+        .get(&CompatKey::Range(core_version, kind))
+}
+
+/// Whether `version` of `kind` is supported by `core_version`. Kinds with no
+/// declared range are treated as incompatible.
+pub fn is_compatible(env: &Env, core_version: u32, kind: EscrowKind, version: u32) -> bool {
+    match get_range(env, core_version, kind) {
+        Some(range) => version >= range.min_version && version <= range.max_version,
+        None => false,
+    }
+}
+
+/// Read the escrow's own `get_version`. Returns `None` if the call fails.
+pub fn query_version(env: &Env, escrow: &Address) -> Option<u32> {
+    match env.try_invoke_contract::<u32, soroban_sdk::Error>(
+        escrow,
+        &Symbol::new(env, "get_version"),
+        Vec::new(env),
+    ) {
+        Ok(Ok(version)) => Some(version),
+        _ => None,
+    }
+}
+
+pub fn set_reported(env: &Env, escrow: &Address, version: u32) {
+    env.storage()
+        .persistent()
+        .set(&CompatKey::Reported(escrow.clone()), &version);
+}
+
+pub fn get_reported(env: &Env, escrow: &Address) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get(&CompatKey::Reported(escrow.clone()))
+}
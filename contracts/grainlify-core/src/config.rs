@@ -0,0 +1,115 @@
+//! Protocol configuration store.
+//!
+//! Parameters shared by several escrow contracts (default fee bps, default
+//! thresholds, oracle addresses, ...) are kept here under symbolic keys so
+//! they are governed in one place. Every value is typed; reading a key with
+//! the wrong getter panics rather than silently reinterpreting it. Each write
+//! is appended to a bounded change log, so the previous value of a key is
+//! the one in its preceding entry.
+
+use soroban_sdk::{contracttype, Address, Env, Symbol, Vec};
+
+/// Number of changes retained in the history log; oldest are dropped first.
+pub const CONFIG_HISTORY_LIMIT: u32 = 100;
+
+#[contracttype]
+enum ConfigKey {
+    /// key -> ConfigValue
+    Value(Symbol),
+    /// Vec<ConfigChange>, oldest first
+    History,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ConfigValue {
+    U64(u64),
+    I128(i128),
+    Address(Address),
+    Bool(bool),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfigChange {
+    pub key: Symbol,
+    pub value: ConfigValue,
+    pub changed_by: Address,
+    pub changed_at: u64,
+}
+
+pub fn get(env: &Env, key: &Symbol) -> Option<ConfigValue> {
+    env.storage()
+        .persistent()
+        .get(&ConfigKey::Value(key.clone()))
+}
+
+/// Store `value` under `key` and log the change.
+///
+/// # Panics
+/// * If `key` already holds a value of a different type
+pub fn set(env: &Env, key: &Symbol, value: ConfigValue, changed_by: Address) -> ConfigChange {
+    if let Some(prev) = get(env, key) {
+        if core::mem::discriminant(&prev) != core::mem::discriminant(&value) {
+            panic!("Config type mismatch");
+        }
+    }
+    env.storage()
+        .persistent()
+        .set(&ConfigKey::Value(key.clone()), &value);
+
+    let change = ConfigChange {
+        key: key.clone(),
+        value,
+        changed_by,
+        changed_at: env.ledger().timestamp(),
+    };
+    let mut history = get_history(env);
+    history.push_back(change.clone());
+    while history.len() > CONFIG_HISTORY_LIMIT {
+        history.pop_front();
+    }
+    env.storage()
+        .persistent()
+        .set(&ConfigKey::History, &history);
+    change
+}
+
+pub fn get_history(env: &Env) -> Vec<ConfigChange> {
+    env.storage()
+        .persistent()
+        .get(&ConfigKey::History)
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn get_u64(env: &Env, key: &Symbol) -> Option<u64> {
+    match get(env, key) {
+        Some(ConfigValue::U64(v)) => Some(v),
+        Some(_) => panic!("Config type mismatch"),
+        None => None,
+    }
+}
+
+pub fn get_i128(env: &Env, key: &Symbol) -> Option<i128> {
+    match get(env, key) {
+        Some(ConfigValue::I128(v)) => Some(v),
+        Some(_) => panic!("Config type mismatch"),
+        None => None,
+    }
+}
+
+pub fn get_address(env: &Env, key: &Symbol) -> Option<Address> {
+    match get(env, key) {
+        Some(ConfigValue::Address(v)) => Some(v),
+        Some(_) => panic!("Config type mismatch"),
+        None => None,
+    }
+}
+
+pub fn get_bool(env: &Env, key: &Symbol) -> Option<bool> {
+    match get(env, key) {
+        Some(ConfigValue::Bool(v)) => Some(v),
+        Some(_) => panic!("Config type mismatch"),
+        None => None,
+    }
+}
@@ -0,0 +1,237 @@
+//! Global error-code registry.
+//!
+//! Each contract numbers its errors independently, so the same numeric code
+//! means different things depending on which contract raised it (for example
+//! `InvalidAmount` is 13 in the bounty escrow while 13 is
+//! `ExecutionDelayNotMet` in governance). This module assigns every known
+//! error a stable global code of `domain * DOMAIN_WIDTH + local_code` and a
+//! short name, so indexers and support tooling can decode a failure with a
+//! single `describe_error` lookup.
+//!
+//! Codes are append-only: never renumber or reuse an entry once published.
+
+use soroban_sdk::{contracttype, Env, String, Vec};
+
+/// Size of the code space reserved for each domain.
+pub const DOMAIN_WIDTH: u32 = 1_000;
+
+/// The contract (or module) an error originates from.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum ErrorDomain {
+    /// `ContractError`, `NonceError` and `AssetIdError` in grainlify-core.
+    Core = 1,
+    /// Governance module errors in grainlify-core.
+    Governance = 2,
+    /// Bounty escrow contract `Error` (1-99) and its per-area enums
+    /// `ClaimError` (100s), `ReleaseError` (200s), `ComplianceError` (300s),
+    /// `LifecycleError` (400s) and `ThresholdError` (500s).
+    BountyEscrow = 3,
+    /// Program escrow contract `BatchError`.
+    ProgramEscrow = 4,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ErrorInfo {
+    /// Stable global code.
+    pub code: u32,
+    pub domain: ErrorDomain,
+    /// Code as raised by the originating contract.
+    pub local_code: u32,
+    /// Variant name of the originating error enum.
+    pub name: String,
+}
+
+const CORE_ERRORS: &[(u32, &str)] = &[
+    (1, "AlreadyInitialized"),
+    (2, "NotAdmin"),
+    (3, "NotInitialized"),
+    (4, "ThresholdNotMet"),
+    (5, "ProposalNotFound"),
+    (6, "UnauthorizedReporter"),
+    (100, "InvalidNonce"),
+    (200, "MustBeContractAddress"),
+    (201, "TransferAmountMismatch"),
+];
+
+const GOVERNANCE_ERRORS: &[(u32, &str)] = &[
+    (1, "NotInitialized"),
+    (2, "InvalidThreshold"),
+    (3, "ThresholdTooLow"),
+    (4, "InsufficientStake"),
+    (5, "ProposalsNotFound"),
+    (6, "ProposalNotFound"),
+    (7, "ProposalNotActive"),
+    (8, "VotingNotStarted"),
+    (9, "VotingEnded"),
+    (10, "VotingStillActive"),
+    (11, "AlreadyVoted"),
+    (12, "ProposalNotApproved"),
+    (13, "ExecutionDelayNotMet"),
+    (14, "ProposalExpired"),
+];
+
+const BOUNTY_ESCROW_ERRORS: &[(u32, &str)] = &[
+    (1, "AlreadyInitialized"),
+    (2, "NotInitialized"),
+    (3, "BountyExists"),
+    (4, "BountyNotFound"),
+    (5, "FundsNotLocked"),
+    (6, "DeadlineNotPassed"),
+    (7, "Unauthorized"),
+    (8, "InvalidFeeRate"),
+    (9, "FeeRecipientNotSet"),
+    (10, "InvalidBatchSize"),
+    (11, "BatchSizeMismatch"),
+    (12, "DuplicateBountyId"),
+    (13, "InvalidAmount"),
+    (14, "InvalidDeadline"),
+    (16, "InsufficientFunds"),
+    (17, "RefundNotApproved"),
+    (18, "FundsPaused"),
+    (19, "AmountBelowMinimum"),
+    (20, "AmountAboveMaximum"),
+    (21, "NotPaused"),
+    (22, "ClaimPending"),
+    (23, "TicketNotFound"),
+    (24, "TicketAlreadyUsed"),
+    (25, "TicketExpired"),
+    (26, "CapabilityNotFound"),
+    (27, "CapabilityExpired"),
+    (28, "CapabilityRevoked"),
+    (29, "CapabilityActionMismatch"),
+    (30, "CapabilityAmountExceeded"),
+    (31, "CapabilityUsesExhausted"),
+    (32, "CapabilityExceedsAuthority"),
+    (33, "InvalidAssetId"),
+    (34, "ContractDeprecated"),
+    (35, "ParticipantBlocked"),
+    (36, "ParticipantNotAllowed"),
+    (37, "UseGetEscrowInfoV2ForAnonymous"),
+    (39, "AnonymousRefundRequiresResolution"),
+    (40, "AnonymousResolverNotSet"),
+    (41, "NotAnonymousEscrow"),
+    (42, "InvalidSelectionInput"),
+    (43, "UpgradeSafetyCheckFailed"),
+    // ClaimError
+    (100, "HashlockNotSet"),
+    (101, "InvalidPreimage"),
+    (102, "HashlockExpired"),
+    (103, "TicketNotTransferable"),
+    (104, "TicketRevoked"),
+    (105, "BondRequired"),
+    (106, "BondAlreadyStaked"),
+    (107, "BondLocked"),
+    (108, "AnonymousPayoutNotFound"),
+    (109, "ResolverRecipientMismatch"),
+    (110, "ClaimKeyNotSet"),
+    (111, "InvalidNonce"),
+    (112, "PaymentIntentNotFound"),
+    (113, "PaymentIntentExpired"),
+    (114, "PaymentIntentClosed"),
+    (115, "RecoveryKeyNotSet"),
+    // ReleaseError
+    (200, "ApprovalContributorMismatch"),
+    (201, "ApprovalNotFound"),
+    (202, "PendingReleaseNotFound"),
+    (203, "ReleaseDisputed"),
+    (204, "ChallengePeriodActive"),
+    (205, "ChallengePeriodElapsed"),
+    (206, "ReleaseNotDisputed"),
+    (207, "ScheduledReleaseNotFound"),
+    (208, "ReleaseAlreadyScheduled"),
+    (209, "ScheduledReleaseNotDue"),
+    (210, "VestingNotFound"),
+    (211, "NothingVested"),
+    // ComplianceError
+    (300, "RateLimited"),
+    (301, "AddressBlocked"),
+    (302, "TierLimitExceeded"),
+    (303, "ExposureLimitExceeded"),
+    (304, "JurisdictionPaused"),
+    (305, "JurisdictionKycRequired"),
+    (306, "JurisdictionLimitExceeded"),
+    (307, "InvalidJurisdictionTag"),
+    (308, "JurisdictionTagNotRegistered"),
+    (309, "ScreeningRejected"),
+    // LifecycleError
+    (400, "CancelProposalNotFound"),
+    (401, "CancelProposalExpired"),
+    (402, "TokenNotAllowed"),
+    (403, "GroupAlreadyExists"),
+    (404, "GroupNotFound"),
+    (405, "BountyAlreadyGrouped"),
+    (406, "GroupPaused"),
+    (407, "BountyNotInGroup"),
+    (408, "AutoRefundNotEnabled"),
+    (409, "AccountingCheckpointNotFound"),
+    (410, "UpgradeRequiresPause"),
+    (411, "InvalidPauseFlags"),
+    (412, "IncompatibleEscrows"),
+    // ThresholdError
+    (500, "ThresholdBreached"),
+    (501, "CircuitOpen"),
+    (502, "ThresholdCooldownActive"),
+    (503, "InvalidThresholdConfig"),
+];
+
+const PROGRAM_ESCROW_ERRORS: &[(u32, &str)] = &[
+    (1, "InvalidBatchSize"),
+    (2, "ProgramAlreadyExists"),
+    (3, "DuplicateProgramId"),
+];
+
+const DOMAINS: [ErrorDomain; 4] = [
+    ErrorDomain::Core,
+    ErrorDomain::Governance,
+    ErrorDomain::BountyEscrow,
+    ErrorDomain::ProgramEscrow,
+];
+
+fn table(domain: ErrorDomain) -> &'static [(u32, &'static str)] {
+    match domain {
+        ErrorDomain::Core => CORE_ERRORS,
+        ErrorDomain::Governance => GOVERNANCE_ERRORS,
+        ErrorDomain::BountyEscrow => BOUNTY_ESCROW_ERRORS,
+        ErrorDomain::ProgramEscrow => PROGRAM_ESCROW_ERRORS,
+    }
+}
+
+fn domain_for(id: u32) -> Option<ErrorDomain> {
+    DOMAINS.iter().copied().find(|d| *d as u32 == id)
+}
+
+/// Global code for `local_code` raised by `domain`.
+pub fn global_code(domain: ErrorDomain, local_code: u32) -> u32 {
+    domain as u32 * DOMAIN_WIDTH + local_code
+}
+
+fn info(env: &Env, domain: ErrorDomain, local_code: u32, name: &str) -> ErrorInfo {
+    ErrorInfo {
+        code: global_code(domain, local_code),
+        domain,
+        local_code,
+        name: String::from_str(env, name),
+    }
+}
+
+/// Look up a global code. Returns `None` for unregistered codes.
+pub fn describe(env: &Env, code: u32) -> Option<ErrorInfo> {
+    let domain = domain_for(code / DOMAIN_WIDTH)?;
+    let local_code = code % DOMAIN_WIDTH;
+    table(domain)
+        .iter()
+        .find(|(local, _)| *local == local_code)
+        .map(|(local, name)| info(env, domain, *local, name))
+}
+
+/// Every registered error of `domain`, ordered by local code.
+pub fn list(env: &Env, domain: ErrorDomain) -> Vec<ErrorInfo> {
+    let mut errors = Vec::new(env);
+    for (local, name) in table(domain) {
+        errors.push_back(info(env, domain, *local, name));
+    }
+    errors
+}
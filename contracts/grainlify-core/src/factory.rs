@@ -0,0 +1,140 @@
+//! Escrow factory.
+//!
+//! Deploys isolated program-escrow or bounty-escrow instances from wasm
+//! hashes registered by the admin, optionally initializes them in the same
+//! call, and keeps a registry of every instance deployed through this
+//! contract. Instance addresses are derived from this contract's address and
+//! the caller-supplied salt, so they can be computed before deployment.
+
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, Symbol, Val, Vec};
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EscrowKind {
+    Program,
+    Bounty,
+}
+
+#[contracttype]
+enum FactoryKey {
+    /// Installed wasm hash per escrow kind
+    Wasm(EscrowKind),
+    /// Addresses of deployed instances, in deployment order
+    Registry,
+    /// Deployment record per instance address
+    Record(Address),
+}
+
+/// Registry entry for an escrow deployed through the factory.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowDeployment {
+    pub address: Address,
+    pub kind: EscrowKind,
+    pub wasm_hash: BytesN<32>,
+    pub salt: BytesN<32>,
+    pub initialized: bool,
+    pub deployed_at: u64,
+}
+
+/// Name of the initializer invoked on a freshly deployed instance.
+fn init_fn(env: &Env, kind: EscrowKind) -> Symbol {
+    match kind {
+        EscrowKind::Program => Symbol::new(env, "init_program"),
+        EscrowKind::Bounty => symbol_short!("init"),
+    }
+}
+
+pub fn set_wasm_hash(env: &Env, kind: EscrowKind, wasm_hash: &BytesN<32>) {
+    env.storage()
+        .instance()
+        .set(&FactoryKey::Wasm(kind), wasm_hash);
+    env.events().publish(
+        (symbol_short!("factory"), symbol_short!("wasm")),
+        (kind, wasm_hash.clone()),
+    );
+}
+
+pub fn get_wasm_hash(env: &Env, kind: EscrowKind) -> Option<BytesN<32>> {
+    env.storage().instance().get(&FactoryKey::Wasm(kind))
+}
+
+/// Address an instance deployed with `salt` will have.
+pub fn deployed_address(env: &Env, salt: &BytesN<32>) -> Address {
+    env.deployer()
+        .with_current_contract(salt.clone())
+        .deployed_address()
+}
+
+/// Deploy an instance of `kind` and record it in the registry.
+///
+/// When `init_args` is non-empty it is passed to the kind's initializer
+/// (`init_program` for program escrows, `init` for bounty escrows) before
+/// returning, so the instance cannot be front-run to initialization.
+///
+/// # Panics
+/// * If no wasm hash is registered for `kind`
+/// * If an instance was already deployed with `salt`
+/// * If the initializer fails
+pub fn deploy(
+    env: &Env,
+    kind: EscrowKind,
+    salt: BytesN<32>,
+    init_args: Vec<Val>,
+) -> EscrowDeployment {
+    let wasm_hash =
+        get_wasm_hash(env, kind).unwrap_or_else(|| panic!("Escrow wasm not registered"));
+
+    let address = env
+        .deployer()
+        .with_current_contract(salt.clone())
+        .deploy(wasm_hash.clone());
+
+    let initialized = !init_args.is_empty();
+    if initialized {
+        env.invoke_contract::<Val>(&address, &init_fn(env, kind), init_args);
+    }
+
+    let deployment = EscrowDeployment {
+        address,
+        kind,
+        wasm_hash,
+        salt,
+        initialized,
+        deployed_at: env.ledger().timestamp(),
+    };
+
+    record(env, &deployment);
+
+    env.events().publish(
+        (symbol_short!("factory"), symbol_short!("deploy")),
+        deployment.clone(),
+    );
+
+    deployment
+}
+
+/// Append `deployment` to the registry.
+pub(crate) fn record(env: &Env, deployment: &EscrowDeployment) {
+    let mut registry = get_registry(env);
+    registry.push_back(deployment.address.clone());
+    env.storage()
+        .persistent()
+        .set(&FactoryKey::Registry, &registry);
+    env.storage()
+        .persistent()
+        .set(&FactoryKey::Record(deployment.address.clone()), deployment);
+}
+
+pub fn get_registry(env: &Env) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&FactoryKey::Registry)
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn get_deployment(env: &Env, address: &Address) -> Option<EscrowDeployment> {
+    env.storage()
+        .persistent()
+        .get(&FactoryKey::Record(address.clone()))
+}
@@ -0,0 +1,180 @@
+//! Invariant run history.
+//!
+//! Persists the outcome of each invariant check in a fixed-size ring buffer
+//! so violations stay visible on-chain after the fact, not only to whoever
+//! happened to call `check_invariants` at the time. Runs are recorded by the
+//! admin on demand or by any keeper through `run_scheduled_check`, which is
+//! rate-limited to one run per `min_interval` and optionally pays the keeper
+//! a reward from this contract's token balance.
+//!
+//! Each run checks one page of the escrow registry and the next run resumes
+//! where it stopped, so successive runs cover every escrow.
+
+use crate::monitoring::{self, InvariantReport};
+use soroban_sdk::{contracttype, symbol_short, token, Address, Env, Vec};
+
+/// Number of runs retained; older runs are overwritten.
+pub const INVARIANT_HISTORY_CAPACITY: u64 = 50;
+
+#[contracttype]
+enum HistoryKey {
+    /// Ring buffer slot (run_id % capacity) -> InvariantRun
+    Slot(u64),
+    /// Id of the next run to be recorded
+    NextRunId,
+    /// Keeper incentive configuration
+    KeeperConfig,
+    /// Timestamp of the last keeper-triggered run
+    LastScheduledRun,
+    /// Registry index the next recorded run starts checking escrows from
+    EscrowCursor,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvariantRun {
+    pub run_id: u64,
+    pub timestamp: u64,
+    pub triggered_by: Address,
+    pub healthy: bool,
+    pub violation_count: u32,
+    pub unbalanced_escrows: Vec<Address>,
+}
+
+/// Reward paid to keepers for scheduled checks.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeeperConfig {
+    pub reward_token: Address,
+    pub reward_amount: i128,
+    /// Minimum seconds between scheduled runs.
+    pub min_interval: u64,
+}
+
+/// Check invariants for the next page of escrows and advance the cursor,
+/// wrapping back to the start after the last page.
+pub fn check_next_page(env: &Env) -> InvariantReport {
+    let start: u32 = env
+        .storage()
+        .instance()
+        .get(&HistoryKey::EscrowCursor)
+        .unwrap_or(0);
+    let report = monitoring::check_invariants_page(env, start, monitoring::MAX_ESCROW_CHECKS);
+    env.storage().instance().set(
+        &HistoryKey::EscrowCursor,
+        &report.next_escrow_cursor.unwrap_or(0),
+    );
+    report
+}
+
+/// Append a run to the ring buffer and emit it.
+pub fn record(
+    env: &Env,
+    triggered_by: Address,
+    healthy: bool,
+    violation_count: u32,
+    unbalanced_escrows: Vec<Address>,
+) -> InvariantRun {
+    let run_id: u64 = env
+        .storage()
+        .persistent()
+        .get(&HistoryKey::NextRunId)
+        .unwrap_or(0);
+
+    let run = InvariantRun {
+        run_id,
+        timestamp: env.ledger().timestamp(),
+        triggered_by,
+        healthy,
+        violation_count,
+        unbalanced_escrows,
+    };
+    env.storage()
+        .persistent()
+        .set(&HistoryKey::Slot(run_id % INVARIANT_HISTORY_CAPACITY), &run);
+    env.storage()
+        .persistent()
+        .set(&HistoryKey::NextRunId, &(run_id + 1));
+
+    let topic = if healthy {
+        symbol_short!("ok")
+    } else {
+        symbol_short!("violated")
+    };
+    env.events()
+        .publish((symbol_short!("inv_run"), topic), run.clone());
+    run
+}
+
+/// Up to `limit` most recent runs, newest first.
+pub fn get_history(env: &Env, limit: u32) -> Vec<InvariantRun> {
+    let next: u64 = env
+        .storage()
+        .persistent()
+        .get(&HistoryKey::NextRunId)
+        .unwrap_or(0);
+    let retained = next.min(INVARIANT_HISTORY_CAPACITY);
+    let count = retained.min(limit as u64);
+
+    let mut runs = Vec::new(env);
+    for i in 0..count {
+        let run_id = next - 1 - i;
+        if let Some(run) = env
+            .storage()
+            .persistent()
+            .get(&HistoryKey::Slot(run_id % INVARIANT_HISTORY_CAPACITY))
+        {
+            runs.push_back(run);
+        }
+    }
+    runs
+}
+
+pub fn set_keeper_config(env: &Env, config: &KeeperConfig) {
+    if config.reward_amount < 0 {
+        panic!("Invalid keeper reward");
+    }
+    env.storage()
+        .instance()
+        .set(&HistoryKey::KeeperConfig, config);
+}
+
+pub fn get_keeper_config(env: &Env) -> Option<KeeperConfig> {
+    env.storage().instance().get(&HistoryKey::KeeperConfig)
+}
+
+/// Enforce the scheduling interval and mark a scheduled run as started.
+pub fn begin_scheduled_run(env: &Env) {
+    let config = get_keeper_config(env).unwrap_or_else(|| panic!("Keeper not configured"));
+    let now = env.ledger().timestamp();
+    if let Some(last) = env
+        .storage()
+        .instance()
+        .get::<HistoryKey, u64>(&HistoryKey::LastScheduledRun)
+    {
+        if now < last.saturating_add(config.min_interval) {
+            panic!("Check not due");
+        }
+    }
+    env.storage()
+        .instance()
+        .set(&HistoryKey::LastScheduledRun, &now);
+}
+
+/// Pay the keeper reward if the contract can cover it. Returns the amount paid.
+pub fn pay_keeper(env: &Env, keeper: &Address) -> i128 {
+    let config = match get_keeper_config(env) {
+        Some(config) if config.reward_amount > 0 => config,
+        _ => return 0,
+    };
+    let client = token::Client::new(env, &config.reward_token);
+    if client.balance(&env.current_contract_address()) < config.reward_amount {
+        return 0;
+    }
+    client.transfer(
+        &env.current_contract_address(),
+        keeper,
+        &config.reward_amount,
+    );
+    config.reward_amount
+}
@@ -8,17 +8,31 @@
 mod multisig;
 use multisig::{MultiSig, MultiSigConfig};
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, Address, BytesN, Env,
-    String, Symbol, Vec,
+    contract, contracterror, contractimpl, contracttype, symbol_short, Address, BytesN, Env, Map,
+    String, Symbol, Val, Vec,
 };
 pub mod asset;
+pub mod compatibility;
+pub mod config;
+pub mod error_registry;
+pub mod factory;
 mod governance;
+pub mod invariant_history;
+pub mod metrics;
 pub mod nonce;
 pub mod pseudo_randomness;
+pub mod recovery;
 
+pub use compatibility::VersionRange;
+pub use config::{ConfigChange, ConfigValue};
+pub use error_registry::{ErrorDomain, ErrorInfo};
+pub use factory::{EscrowDeployment, EscrowKind};
 pub use governance::{
     Error as GovError, GovernanceConfig, Proposal, ProposalStatus, Vote, VoteType, VotingScheme,
 };
+pub use invariant_history::{InvariantRun, KeeperConfig};
+pub use metrics::{Metric, MetricKind};
+pub use recovery::{GuardianConfig, RecoveryRequest};
 
 // ============================================================================
 // Contract Errors
@@ -43,6 +57,8 @@ pub enum ContractError {
     ThresholdNotMet = 4,
     /// The referenced upgrade proposal does not exist.
     ProposalNotFound = 5,
+    /// Metrics reporter is neither the admin nor a factory-deployed escrow.
+    UnauthorizedReporter = 6,
 }
 
 // ============================================================================
@@ -74,6 +90,10 @@ mod monitoring {
     const USER_COUNT: &str = "usr_count";
     const ERROR_COUNT: &str = "err_count";
 
+    /// Escrows queried per invariant check, keeping each call within budget
+    /// however large the factory registry grows.
+    pub const MAX_ESCROW_CHECKS: u32 = 20;
+
     // Event: Operation metric
     #[contracttype]
     #[derive(Clone, Debug)]
@@ -152,6 +172,17 @@ mod monitoring {
         pub unique_users: u64,
         pub error_count: u64,
         pub violation_count: u32,
+        /// Every escrow checked in this page holds at least what it tracks.
+        pub funds_conserved: bool,
+        pub escrows_checked: u32,
+        /// Escrows holding less than their tracked balance, or that could not
+        /// be queried.
+        pub unbalanced_escrows: Vec<Address>,
+        /// Size of the factory registry.
+        pub escrow_count: u32,
+        /// Registry index to pass as `start` for the next page; `None` once
+        /// the last escrow has been checked.
+        pub next_escrow_cursor: Option<u32>,
     }
 
     // Track operation
@@ -340,9 +371,17 @@ mod monitoring {
         }
     }
 
-    /// Verify core monitoring/config invariants.
-    /// This is view-only and safe for frequent calls by off-chain monitors.
+    /// Verify core monitoring/config invariants against the first page of
+    /// escrows. This is view-only and safe for frequent calls by off-chain
+    /// monitors.
     pub fn check_invariants(env: &Env) -> InvariantReport {
+        check_invariants_page(env, 0, MAX_ESCROW_CHECKS)
+    }
+
+    /// Like [`check_invariants`], but checks funds conservation for at most
+    /// `limit` escrows (capped at [`MAX_ESCROW_CHECKS`]) starting at registry
+    /// index `start`.
+    pub fn check_invariants_page(env: &Env, start: u32, limit: u32) -> InvariantReport {
         let op_key = Symbol::new(env, OPERATION_COUNT);
         let usr_key = Symbol::new(env, USER_COUNT);
         let err_key = Symbol::new(env, ERROR_COUNT);
@@ -403,8 +442,13 @@ mod monitoring {
             violation_count += 1;
         }
 
+        let (escrows_checked, unbalanced_escrows, escrow_count, next_escrow_cursor) =
+            check_escrow_balances(env, start, limit);
+        let funds_conserved = unbalanced_escrows.is_empty();
+        violation_count += unbalanced_escrows.len();
+
         InvariantReport {
-            healthy: config_sane && metrics_sane,
+            healthy: config_sane && metrics_sane && funds_conserved,
             config_sane,
             metrics_sane,
             admin_set,
@@ -414,19 +458,84 @@ mod monitoring {
             unique_users,
             error_count,
             violation_count,
+            funds_conserved,
+            escrows_checked,
+            unbalanced_escrows,
+            escrow_count,
+            next_escrow_cursor,
         }
     }
 
+    /// Funds conservation across escrows deployed by the factory.
+    ///
+    /// Each escrow's `reconcile_balances()` returns `(tracked, held)`: the
+    /// amount its bookkeeping says it owes and the token balance it actually
+    /// holds. Anyone can send tokens to an escrow, so a surplus is fine; an
+    /// escrow is flagged when it holds less than it tracks or the call fails.
+    ///
+    /// Returns the number checked, the flagged escrows, the registry size and
+    /// the cursor of the next page.
+    fn check_escrow_balances(
+        env: &Env,
+        start: u32,
+        limit: u32,
+    ) -> (u32, Vec<Address>, u32, Option<u32>) {
+        let registry = crate::factory::get_registry(env);
+        let total = registry.len();
+        let end = start
+            .saturating_add(limit.min(MAX_ESCROW_CHECKS))
+            .min(total);
+        let mut unbalanced = Vec::new(env);
+        let mut checked = 0;
+        for i in start..end {
+            let escrow = registry.get(i).unwrap();
+            let balanced = match env.try_invoke_contract::<(i128, i128), soroban_sdk::Error>(
+                &escrow,
+                &Symbol::new(env, "reconcile_balances"),
+                Vec::new(env),
+            ) {
+                Ok(Ok((tracked, held))) => held >= tracked,
+                _ => false,
+            };
+            if !balanced {
+                unbalanced.push_back(escrow);
+            }
+            checked += 1;
+        }
+        let next = if end < total { Some(end) } else { None };
+        (checked, unbalanced, total, next)
+    }
+
     pub fn verify_invariants(env: &Env) -> bool {
         check_invariants(env).healthy
     }
 }
 
+#[cfg(test)]
+mod test_admin_rotation;
+#[cfg(test)]
+mod test_compatibility;
+#[cfg(test)]
+mod test_config;
 #[cfg(test)]
 mod test_core_monitoring;
 #[cfg(test)]
+mod test_error_registry;
+#[cfg(test)]
+mod test_factory;
+#[cfg(test)]
+mod test_feature_flags;
+#[cfg(test)]
+mod test_global_halt;
+#[cfg(test)]
+mod test_invariant_history;
+#[cfg(test)]
+mod test_metrics;
+#[cfg(test)]
 mod test_performance_stats;
 #[cfg(test)]
+mod test_recovery;
+#[cfg(test)]
 mod test_serialization_compatibility;
 #[cfg(test)]
 mod test_version_helpers;
@@ -474,14 +583,16 @@ pub struct GrainlifyContract;
 ///
 /// # Security Notes
 /// - Instance storage persists across WASM upgrades automatically
-/// - Admin address (Admin key) is immutable after initialization
+/// - Admin address (Admin key) changes only through two-step rotation or
+///   guardian recovery
 /// - Migration state prevents replayed or duplicated migrations
 /// - All storage operations are admin-only or derived from admin authorization
 #[contracttype]
 #[derive(Clone)]
 enum DataKey {
     /// Administrator address with upgrade authority
-    /// - Immutable after initialization via init_admin()
+    /// - Set by init_admin(); rotated via propose_admin()/accept_admin() or
+    ///   guardian recovery
     /// - Required for all admin operations (upgrade, migrate, set_version)
     /// - Persists across all WASM upgrades
     Admin,
@@ -537,6 +648,27 @@ enum DataKey {
     /// - May be used for feature flags or behavior divergence
     /// - Persists across upgrades
     NetworkId,
+
+    /// Feature flag state by flag name
+    /// - Set via set_feature_flag(), read by escrow contracts cross-contract
+    /// - Missing flags are treated as disabled
+    FeatureFlag(Symbol),
+
+    /// Names of every flag ever set, in first-set order
+    /// - Used by get_feature_flags() for enumeration
+    FeatureFlagNames,
+
+    /// Active global halt, if any
+    /// - Set by global_halt(), removed by global_resume()
+    /// - Consulted by escrow contracts before moving funds
+    GlobalHalt,
+
+    /// Admin proposed by the current admin, awaiting acceptance
+    /// - Set by propose_admin(), cleared by accept_admin()/cancel_admin_proposal()
+    PendingAdmin,
+
+    /// Vec<AdminRotation> of every completed admin change, oldest first
+    AdminHistory,
 }
 
 // ============================================================================
@@ -572,6 +704,121 @@ pub struct CoreConfigSnapshot {
     pub multisig_signers: Vec<Address>,
 }
 
+/// Record of an active global halt.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GlobalHaltState {
+    pub halted_by: Address,
+    pub reason: String,
+    pub halted_at: u64,
+}
+
+/// Admin change awaiting acceptance by the proposed address.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingAdmin {
+    pub new_admin: Address,
+    pub proposed_by: Address,
+    pub proposed_at: u64,
+}
+
+/// Audit record of a completed admin change.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminRotation {
+    pub previous_admin: Address,
+    pub new_admin: Address,
+    /// How the change was authorized: `accept` or `recovery`.
+    pub method: Symbol,
+    pub rotated_at: u64,
+}
+
+fn require_admin(env: &Env) -> Address {
+    let admin: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .unwrap_or_else(|| panic!("{}", ContractError::NotInitialized as u32));
+    admin.require_auth();
+    admin
+}
+
+/// Replace the admin, append the change to the admin history and emit
+/// `("admin", "rotated")`.
+fn rotate_admin(env: &Env, previous_admin: Address, new_admin: Address, method: Symbol) {
+    env.storage().instance().set(&DataKey::Admin, &new_admin);
+    env.storage().instance().remove(&DataKey::PendingAdmin);
+
+    let record = AdminRotation {
+        previous_admin,
+        new_admin: new_admin.clone(),
+        method: method.clone(),
+        rotated_at: env.ledger().timestamp(),
+    };
+    let mut history: Vec<AdminRotation> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::AdminHistory)
+        .unwrap_or(Vec::new(env));
+    history.push_back(record.clone());
+    env.storage()
+        .persistent()
+        .set(&DataKey::AdminHistory, &history);
+
+    env.events()
+        .publish((symbol_short!("admin"), symbol_short!("rotated")), record);
+    monitoring::track_operation(env, method, new_admin, true);
+}
+
+fn set_protocol_config(env: &Env, key: Symbol, value: ConfigValue) {
+    let admin = require_admin(env);
+    let change = config::set(env, &key, value, admin.clone());
+    env.events()
+        .publish((symbol_short!("config"), symbol_short!("set")), change);
+    monitoring::track_operation(env, symbol_short!("cfg_set"), admin, true);
+}
+
+/// Rotate the admin to the recovery target if the pending request is ready.
+fn try_execute_recovery(env: &Env, request: RecoveryRequest) -> bool {
+    if !recovery::is_executable(env, &request) {
+        return false;
+    }
+    let previous: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .unwrap_or_else(|| panic!("{}", ContractError::NotInitialized as u32));
+    recovery::clear_request(env);
+    rotate_admin(env, previous, request.new_admin, symbol_short!("recovery"));
+    true
+}
+
+/// The security council is the admin plus every multisig signer; any one of
+/// them can pull or release the global halt.
+fn require_council_member(env: &Env, caller: &Address) {
+    caller.require_auth();
+
+    let admin: Option<Address> = env.storage().instance().get(&DataKey::Admin);
+    let is_admin = admin.as_ref() == Some(caller);
+    let is_signer = MultiSig::get_config_opt(env)
+        .map(|cfg| cfg.signers.contains(caller))
+        .unwrap_or(false);
+    if !is_admin && !is_signer {
+        panic!("{}", ContractError::NotAdmin as u32);
+    }
+}
+
+/// Metrics may be reported by the admin or by any escrow deployed through
+/// the factory (which authorizes as the calling contract).
+fn require_metrics_reporter(env: &Env, reporter: &Address) {
+    reporter.require_auth();
+
+    let admin: Option<Address> = env.storage().instance().get(&DataKey::Admin);
+    if admin.as_ref() != Some(reporter) && factory::get_deployment(env, reporter).is_none() {
+        panic!("{}", ContractError::UnauthorizedReporter as u32);
+    }
+}
+
 fn contract_is_initialized(env: &Env) -> bool {
     env.storage().instance().has(&DataKey::Version)
         || env.storage().instance().has(&DataKey::Admin)
@@ -749,7 +996,7 @@ impl GrainlifyContract {
         env.storage().instance().set(&DataKey::Version, &VERSION);
 
         // Track successful operation
-        let caller = Address::generate(&env);
+        let caller = env.current_contract_address();
         monitoring::track_operation(&env, symbol_short!("init"), caller.clone(), true);
 
         // Track performance
@@ -1631,15 +1878,100 @@ impl GrainlifyContract {
     }
 
     /// Return a detailed invariant report for auditors and monitoring tools.
+    /// Funds conservation covers the first
+    /// [`monitoring::MAX_ESCROW_CHECKS`] escrows; use
+    /// [`check_invariants_page`](Self::check_invariants_page) for the rest.
     pub fn check_invariants(env: Env) -> monitoring::InvariantReport {
         monitoring::check_invariants(&env)
     }
 
+    /// Invariant report checking up to `limit` escrows from registry index
+    /// `start`. Follow `next_escrow_cursor` to walk the whole registry.
+    pub fn check_invariants_page(env: Env, start: u32, limit: u32) -> monitoring::InvariantReport {
+        monitoring::check_invariants_page(&env, start, limit)
+    }
+
     /// Lightweight invariant verdict for frequent monitoring calls.
     pub fn verify_invariants(env: Env) -> bool {
         monitoring::verify_invariants(&env)
     }
 
+    /// Runs the invariant checks and stores the result in the on-chain
+    /// history (admin-only).
+    pub fn record_invariant_run(env: Env) -> InvariantRun {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("{}", ContractError::NotInitialized as u32));
+        admin.require_auth();
+
+        let report = invariant_history::check_next_page(&env);
+        invariant_history::record(
+            &env,
+            admin,
+            report.healthy,
+            report.violation_count,
+            report.unbalanced_escrows,
+        )
+    }
+
+    /// Returns up to `limit` recorded invariant runs, newest first. Only the
+    /// last [`invariant_history::INVARIANT_HISTORY_CAPACITY`] runs are kept.
+    pub fn get_invariant_history(env: Env, limit: u32) -> Vec<InvariantRun> {
+        invariant_history::get_history(&env, limit)
+    }
+
+    /// Configures the keeper reward and minimum interval for
+    /// [`run_scheduled_check`](Self::run_scheduled_check) (admin-only).
+    ///
+    /// Rewards are paid from this contract's balance of `reward_token`; fund
+    /// it with a plain token transfer.
+    pub fn set_keeper_config(env: Env, config: KeeperConfig) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("{}", ContractError::NotInitialized as u32));
+        admin.require_auth();
+
+        invariant_history::set_keeper_config(&env, &config);
+    }
+
+    pub fn get_keeper_config(env: Env) -> Option<KeeperConfig> {
+        invariant_history::get_keeper_config(&env)
+    }
+
+    /// Permissionless invariant check for keepers.
+    ///
+    /// Records the run in the history and pays `keeper` the configured reward
+    /// when the contract holds enough of the reward token; an unfunded
+    /// contract still records the run but pays nothing.
+    ///
+    /// # Panics
+    /// * If no keeper config is set
+    /// * If called again before `min_interval` has elapsed
+    pub fn run_scheduled_check(env: Env, keeper: Address) -> InvariantRun {
+        keeper.require_auth();
+        invariant_history::begin_scheduled_run(&env);
+
+        let report = invariant_history::check_next_page(&env);
+        let run = invariant_history::record(
+            &env,
+            keeper.clone(),
+            report.healthy,
+            report.violation_count,
+            report.unbalanced_escrows,
+        );
+
+        let reward = invariant_history::pay_keeper(&env, &keeper);
+        env.events().publish(
+            (symbol_short!("keeper"), symbol_short!("paid")),
+            (keeper, reward),
+        );
+        run
+    }
+
     // ========================================================================
     // State Migration System
     // ========================================================================
@@ -1867,6 +2199,561 @@ impl GrainlifyContract {
             None
         }
     }
+
+    // ========================================================================
+    // Escrow Factory
+    // ========================================================================
+
+    /// Registers the wasm hash used to deploy escrows of `kind` (admin-only).
+    ///
+    /// The wasm must already be uploaded to the network. Re-registering a
+    /// kind only affects future deployments.
+    pub fn set_escrow_wasm(env: Env, kind: EscrowKind, wasm_hash: BytesN<32>) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("{}", ContractError::NotInitialized as u32));
+        admin.require_auth();
+
+        factory::set_wasm_hash(&env, kind, &wasm_hash);
+        monitoring::track_operation(&env, symbol_short!("set_wasm"), admin, true);
+    }
+
+    /// Returns the wasm hash registered for `kind`, if any.
+    pub fn get_escrow_wasm(env: Env, kind: EscrowKind) -> Option<BytesN<32>> {
+        factory::get_wasm_hash(&env, kind)
+    }
+
+    /// Deploys a new escrow instance of `kind` (admin-only).
+    ///
+    /// # Arguments
+    /// * `kind` - Program or bounty escrow
+    /// * `salt` - Unique per deployment; determines the instance address
+    /// * `init_args` - Arguments for the instance initializer (`init_program`
+    ///   or `init`); pass an empty vector to deploy uninitialized
+    ///
+    /// # Returns
+    /// * `Address` - Address of the deployed instance
+    ///
+    /// # Events
+    /// Emits `("factory", "deploy")` with the [`EscrowDeployment`] record.
+    pub fn deploy_escrow(
+        env: Env,
+        kind: EscrowKind,
+        salt: BytesN<32>,
+        init_args: Vec<Val>,
+    ) -> Address {
+        let start = env.ledger().timestamp();
+
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("{}", ContractError::NotInitialized as u32));
+        admin.require_auth();
+
+        let deployment = factory::deploy(&env, kind, salt, init_args);
+        if deployment.initialized {
+            if let Some(version) = compatibility::query_version(&env, &deployment.address) {
+                compatibility::set_reported(&env, &deployment.address, version);
+            }
+        }
+
+        monitoring::track_operation(&env, symbol_short!("deploy"), admin, true);
+        let duration = env.ledger().timestamp().saturating_sub(start);
+        monitoring::emit_performance(&env, symbol_short!("deploy"), duration);
+
+        deployment.address
+    }
+
+    /// Computes the address `deploy_escrow` will use for `salt`.
+    pub fn get_escrow_address(env: Env, salt: BytesN<32>) -> Address {
+        factory::deployed_address(&env, &salt)
+    }
+
+    /// Lists escrows deployed through the factory, oldest first.
+    pub fn get_deployed_escrows(env: Env) -> Vec<EscrowDeployment> {
+        let mut deployments = Vec::new(&env);
+        for address in factory::get_registry(&env).iter() {
+            if let Some(deployment) = factory::get_deployment(&env, &address) {
+                deployments.push_back(deployment);
+            }
+        }
+        deployments
+    }
+
+    /// Returns the registry record for an escrow deployed by the factory.
+    pub fn get_escrow_deployment(env: Env, address: Address) -> Option<EscrowDeployment> {
+        factory::get_deployment(&env, &address)
+    }
+
+    // ========================================================================
+    // Feature Flags
+    // ========================================================================
+
+    /// Enables or disables a named feature for this network (admin-only).
+    ///
+    /// Escrow contracts gate risky capabilities on these flags by calling
+    /// [`is_feature_enabled`](Self::is_feature_enabled) on this contract, so
+    /// a capability can be rolled out or killed without redeploying them.
+    ///
+    /// # Events
+    /// Emits `("feature", "set")` with `(name, enabled)`.
+    pub fn set_feature_flag(env: Env, name: Symbol, enabled: bool) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("{}", ContractError::NotInitialized as u32));
+        admin.require_auth();
+
+        let key = DataKey::FeatureFlag(name.clone());
+        if !env.storage().instance().has(&key) {
+            let mut names: Vec<Symbol> = env
+                .storage()
+                .instance()
+                .get(&DataKey::FeatureFlagNames)
+                .unwrap_or(Vec::new(&env));
+            names.push_back(name.clone());
+            env.storage()
+                .instance()
+                .set(&DataKey::FeatureFlagNames, &names);
+        }
+        env.storage().instance().set(&key, &enabled);
+
+        env.events().publish(
+            (symbol_short!("feature"), symbol_short!("set")),
+            (name, enabled),
+        );
+        monitoring::track_operation(&env, symbol_short!("set_flag"), admin, true);
+    }
+
+    /// Returns whether `name` is enabled. Unknown flags are disabled.
+    ///
+    /// This is the read hook escrow contracts call cross-contract before
+    /// exercising a gated capability.
+    pub fn is_feature_enabled(env: Env, name: Symbol) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::FeatureFlag(name))
+            .unwrap_or(false)
+    }
+
+    /// Returns every flag that has been set, with its current state.
+    pub fn get_feature_flags(env: Env) -> Map<Symbol, bool> {
+        let names: Vec<Symbol> = env
+            .storage()
+            .instance()
+            .get(&DataKey::FeatureFlagNames)
+            .unwrap_or(Vec::new(&env));
+
+        let mut flags = Map::new(&env);
+        for name in names.iter() {
+            let enabled = Self::is_feature_enabled(env.clone(), name.clone());
+            flags.set(name, enabled);
+        }
+        flags
+    }
+
+    // ========================================================================
+    // Global Halt
+    // ========================================================================
+
+    /// Halts every fund-moving operation in the escrow contracts that point
+    /// at this contract, until [`global_resume`](Self::global_resume).
+    ///
+    /// Callable by the admin or any multisig signer. Escrows consult
+    /// [`is_globally_halted`](Self::is_globally_halted) from their pause
+    /// checks, so the halt takes effect on their next operation.
+    ///
+    /// # Events
+    /// Emits `("halt", "on")` with the [`GlobalHaltState`].
+    pub fn global_halt(env: Env, caller: Address, reason: String) {
+        require_council_member(&env, &caller);
+        if env.storage().instance().has(&DataKey::GlobalHalt) {
+            panic!("Already halted");
+        }
+
+        let state = GlobalHaltState {
+            halted_by: caller.clone(),
+            reason,
+            halted_at: env.ledger().timestamp(),
+        };
+        env.storage().instance().set(&DataKey::GlobalHalt, &state);
+
+        env.events()
+            .publish((symbol_short!("halt"), symbol_short!("on")), state);
+        monitoring::track_operation(&env, symbol_short!("halt"), caller, true);
+    }
+
+    /// Lifts the global halt. Callable by the admin or any multisig signer.
+    ///
+    /// # Events
+    /// Emits `("halt", "off")` with `(caller, timestamp)`.
+    pub fn global_resume(env: Env, caller: Address) {
+        require_council_member(&env, &caller);
+        if !env.storage().instance().has(&DataKey::GlobalHalt) {
+            panic!("Not halted");
+        }
+        env.storage().instance().remove(&DataKey::GlobalHalt);
+
+        env.events().publish(
+            (symbol_short!("halt"), symbol_short!("off")),
+            (caller.clone(), env.ledger().timestamp()),
+        );
+        monitoring::track_operation(&env, symbol_short!("resume"), caller, true);
+    }
+
+    /// Returns whether a global halt is active.
+    pub fn is_globally_halted(env: Env) -> bool {
+        env.storage().instance().has(&DataKey::GlobalHalt)
+    }
+
+    /// Returns the active halt record, if any.
+    pub fn get_global_halt(env: Env) -> Option<GlobalHaltState> {
+        env.storage().instance().get(&DataKey::GlobalHalt)
+    }
+
+    // ========================================================================
+    // Metrics
+    // ========================================================================
+
+    /// Increments the counter `name` by one. Callable by the admin or a
+    /// factory-deployed escrow.
+    pub fn increment_counter(env: Env, reporter: Address, name: Symbol) -> Metric {
+        require_metrics_reporter(&env, &reporter);
+        metrics::increment_counter(&env, &name, 1)
+    }
+
+    /// Sets the gauge `name` to `value`. Callable by the admin or a
+    /// factory-deployed escrow.
+    pub fn set_gauge(env: Env, reporter: Address, name: Symbol, value: i128) -> Metric {
+        require_metrics_reporter(&env, &reporter);
+        metrics::set_gauge(&env, &name, value)
+    }
+
+    pub fn get_metric(env: Env, name: Symbol) -> Option<Metric> {
+        metrics::get_metric(&env, &name)
+    }
+
+    /// Lists all live metrics in registration order.
+    pub fn list_metrics(env: Env) -> Vec<Metric> {
+        metrics::list_metrics(&env)
+    }
+
+    /// Sets how many ledgers `name` lives after each write (admin-only).
+    /// Defaults to [`metrics::DEFAULT_METRIC_TTL`].
+    pub fn set_metric_ttl(env: Env, name: Symbol, ttl_ledgers: u32) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("{}", ContractError::NotInitialized as u32));
+        admin.require_auth();
+
+        metrics::set_ttl(&env, &name, ttl_ledgers);
+    }
+
+    pub fn get_metric_ttl(env: Env, name: Symbol) -> u32 {
+        metrics::get_ttl(&env, &name)
+    }
+
+    // ========================================================================
+    // Error Registry
+    // ========================================================================
+
+    /// Decodes a global error code into its domain, local code and name.
+    /// Returns `None` for codes that are not registered.
+    pub fn describe_error(env: Env, code: u32) -> Option<ErrorInfo> {
+        error_registry::describe(&env, code)
+    }
+
+    /// Returns the global code for `local_code` raised by `domain`.
+    pub fn get_global_error_code(_env: Env, domain: ErrorDomain, local_code: u32) -> u32 {
+        error_registry::global_code(domain, local_code)
+    }
+
+    /// Lists every registered error of `domain`, ordered by local code.
+    pub fn list_error_codes(env: Env, domain: ErrorDomain) -> Vec<ErrorInfo> {
+        error_registry::list(&env, domain)
+    }
+
+    // ========================================================================
+    // Admin Rotation
+    // ========================================================================
+
+    /// Proposes `new_admin` as the next admin (admin-only). The change takes
+    /// effect only once `new_admin` calls [`accept_admin`]; a new proposal
+    /// replaces any pending one.
+    ///
+    /// # Events
+    /// Emits `("admin", "proposed")` → [`PendingAdmin`].
+    pub fn propose_admin(env: Env, new_admin: Address) {
+        let admin = require_admin(&env);
+        if new_admin == admin {
+            panic!("Already admin");
+        }
+
+        let pending = PendingAdmin {
+            new_admin,
+            proposed_by: admin.clone(),
+            proposed_at: env.ledger().timestamp(),
+        };
+        env.storage()
+            .instance()
+            .set(&DataKey::PendingAdmin, &pending);
+
+        env.events()
+            .publish((symbol_short!("admin"), symbol_short!("proposed")), pending);
+        monitoring::track_operation(&env, symbol_short!("adm_prop"), admin, true);
+    }
+
+    /// Completes a pending rotation. Must be authorized by the proposed admin.
+    ///
+    /// # Events
+    /// Emits `("admin", "rotated")` → [`AdminRotation`].
+    pub fn accept_admin(env: Env) {
+        let pending: PendingAdmin = env
+            .storage()
+            .instance()
+            .get(&DataKey::PendingAdmin)
+            .unwrap_or_else(|| panic!("No pending admin"));
+        pending.new_admin.require_auth();
+
+        let previous: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("{}", ContractError::NotInitialized as u32));
+        rotate_admin(&env, previous, pending.new_admin, symbol_short!("accept"));
+    }
+
+    /// Withdraws the pending proposal (admin-only).
+    pub fn cancel_admin_proposal(env: Env) {
+        let admin = require_admin(&env);
+        if !env.storage().instance().has(&DataKey::PendingAdmin) {
+            panic!("No pending admin");
+        }
+        env.storage().instance().remove(&DataKey::PendingAdmin);
+
+        env.events().publish(
+            (symbol_short!("admin"), symbol_short!("cancel")),
+            admin.clone(),
+        );
+        monitoring::track_operation(&env, symbol_short!("adm_cncl"), admin, true);
+    }
+
+    pub fn get_pending_admin(env: Env) -> Option<PendingAdmin> {
+        env.storage().instance().get(&DataKey::PendingAdmin)
+    }
+
+    /// Returns every completed admin change, oldest first.
+    pub fn get_admin_history(env: Env) -> Vec<AdminRotation> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::AdminHistory)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    // ========================================================================
+    // Guardian Recovery
+    // ========================================================================
+
+    /// Registers the guardian set allowed to recover the admin (admin-only).
+    /// Fails while a recovery request is pending.
+    pub fn set_guardians(env: Env, config: GuardianConfig) {
+        let admin = require_admin(&env);
+        recovery::set_config(&env, &config);
+        monitoring::track_operation(&env, symbol_short!("guardians"), admin, true);
+    }
+
+    pub fn get_guardian_config(env: Env) -> Option<GuardianConfig> {
+        recovery::get_config(&env)
+    }
+
+    /// Opens a request to replace the admin with `new_admin`. The initiating
+    /// guardian's approval is counted immediately. Fails while another
+    /// request is pending.
+    ///
+    /// # Events
+    /// Emits `("recovery", "init")` → [`RecoveryRequest`].
+    pub fn initiate_recovery(env: Env, guardian: Address, new_admin: Address) -> RecoveryRequest {
+        let request = recovery::initiate(&env, &guardian, &new_admin);
+        env.events().publish(
+            (symbol_short!("recovery"), symbol_short!("init")),
+            request.clone(),
+        );
+        request
+    }
+
+    /// Adds `guardian`'s approval to the pending request and executes it if
+    /// quorum is reached and the timelock has elapsed. Returns whether the
+    /// admin was rotated.
+    ///
+    /// # Events
+    /// Emits `("recovery", "approve")` with `(guardian, approvals)`.
+    pub fn approve_recovery(env: Env, guardian: Address) -> bool {
+        let request = recovery::approve(&env, &guardian);
+        env.events().publish(
+            (symbol_short!("recovery"), symbol_short!("approve")),
+            (guardian, request.approvals.len()),
+        );
+        try_execute_recovery(&env, request)
+    }
+
+    /// Executes the pending request once quorum and timelock are both met.
+    /// Callable by anyone.
+    pub fn execute_recovery(env: Env) {
+        let request = recovery::get_request(&env).unwrap_or_else(|| panic!("No recovery pending"));
+        if !try_execute_recovery(&env, request) {
+            panic!("Recovery not ready");
+        }
+    }
+
+    /// Adds `guardian`'s vote to cancel the pending request, e.g. when a
+    /// guardian key has been compromised. The request is discarded once the
+    /// same quorum that executes a recovery has voted. Returns whether it was
+    /// discarded.
+    ///
+    /// # Events
+    /// Emits `("recovery", "cancel")` with `(guardian, cancellations)`.
+    pub fn cancel_recovery(env: Env, guardian: Address) -> bool {
+        let (request, cancelled) = recovery::cancel(&env, &guardian);
+        env.events().publish(
+            (symbol_short!("recovery"), symbol_short!("cancel")),
+            (guardian.clone(), request.cancellations.len()),
+        );
+        if cancelled {
+            monitoring::track_operation(&env, symbol_short!("rec_cncl"), guardian, true);
+        }
+        cancelled
+    }
+
+    pub fn get_recovery_request(env: Env) -> Option<RecoveryRequest> {
+        recovery::get_request(&env)
+    }
+
+    // ========================================================================
+    // Protocol Configuration
+    // ========================================================================
+
+    /// Sets a `u64` protocol parameter (admin-only).
+    ///
+    /// # Events
+    /// Emits `("config", "set")` → [`ConfigChange`] for every setter.
+    pub fn set_config_u64(env: Env, key: Symbol, value: u64) {
+        set_protocol_config(&env, key, ConfigValue::U64(value));
+    }
+
+    /// Sets an `i128` protocol parameter (admin-only).
+    pub fn set_config_i128(env: Env, key: Symbol, value: i128) {
+        set_protocol_config(&env, key, ConfigValue::I128(value));
+    }
+
+    /// Sets an address protocol parameter (admin-only).
+    pub fn set_config_address(env: Env, key: Symbol, value: Address) {
+        set_protocol_config(&env, key, ConfigValue::Address(value));
+    }
+
+    /// Sets a boolean protocol parameter (admin-only).
+    pub fn set_config_bool(env: Env, key: Symbol, value: bool) {
+        set_protocol_config(&env, key, ConfigValue::Bool(value));
+    }
+
+    /// Getters return `None` for unset keys and panic if `key` holds a value
+    /// of another type.
+    pub fn get_config_u64(env: Env, key: Symbol) -> Option<u64> {
+        config::get_u64(&env, &key)
+    }
+
+    pub fn get_config_i128(env: Env, key: Symbol) -> Option<i128> {
+        config::get_i128(&env, &key)
+    }
+
+    pub fn get_config_address(env: Env, key: Symbol) -> Option<Address> {
+        config::get_address(&env, &key)
+    }
+
+    pub fn get_config_bool(env: Env, key: Symbol) -> Option<bool> {
+        config::get_bool(&env, &key)
+    }
+
+    /// Returns retained configuration changes, oldest first.
+    pub fn get_config_history(env: Env) -> Vec<ConfigChange> {
+        config::get_history(&env)
+    }
+
+    // ========================================================================
+    // Version Compatibility
+    // ========================================================================
+
+    /// Declares which `kind` escrow versions work with `core_version`
+    /// (admin-only). May be set ahead of upgrading the core itself.
+    pub fn set_compatibility(
+        env: Env,
+        kind: EscrowKind,
+        core_version: u32,
+        min_version: u32,
+        max_version: u32,
+    ) {
+        let admin = require_admin(&env);
+        compatibility::set_range(
+            &env,
+            core_version,
+            kind,
+            &VersionRange {
+                min_version,
+                max_version,
+            },
+        );
+        monitoring::track_operation(&env, symbol_short!("compat"), admin, true);
+    }
+
+    pub fn get_compatibility(
+        env: Env,
+        kind: EscrowKind,
+        core_version: u32,
+    ) -> Option<VersionRange> {
+        compatibility::get_range(&env, core_version, kind)
+    }
+
+    /// Returns whether escrow `version` of `kind` is compatible with the
+    /// current core version.
+    pub fn check_compatibility(env: Env, kind: EscrowKind, version: u32) -> bool {
+        let core_version = Self::get_version(env.clone());
+        compatibility::is_compatible(&env, core_version, kind, version)
+    }
+
+    /// Re-reads a registered escrow's `get_version`, e.g. after it upgraded.
+    /// Callable by anyone since the version comes from the escrow itself.
+    pub fn refresh_escrow_version(env: Env, escrow: Address) -> u32 {
+        if factory::get_deployment(&env, &escrow).is_none() {
+            panic!("Escrow not registered");
+        }
+        let version = compatibility::query_version(&env, &escrow)
+            .unwrap_or_else(|| panic!("Escrow version unavailable"));
+        compatibility::set_reported(&env, &escrow, version);
+        version
+    }
+
+    pub fn get_escrow_version(env: Env, escrow: Address) -> Option<u32> {
+        compatibility::get_reported(&env, &escrow)
+    }
+
+    /// Returns whether a registered escrow's reported version is compatible
+    /// with the current core. Unregistered escrows, and escrows that have
+    /// not reported a version, are incompatible.
+    pub fn check_escrow_compatibility(env: Env, escrow: Address) -> bool {
+        let deployment = match factory::get_deployment(&env, &escrow) {
+            Some(deployment) => deployment,
+            None => return false,
+        };
+        match compatibility::get_reported(&env, &escrow) {
+            Some(version) => Self::check_compatibility(env, deployment.kind, version),
+            None => false,
+        }
+    }
 }
 
 // ── UpgradeInterface conformance (Issue #574) ───────────────────────────────
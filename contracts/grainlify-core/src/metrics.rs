@@ -0,0 +1,142 @@
+//! Named operational metrics.
+//!
+//! Structured counters and gauges that the core contract and the escrows it
+//! deployed report into, replacing ad-hoc symbol counters with a single
+//! queryable registry. Each metric lives in its own persistent entry whose
+//! TTL is extended on every write; the admin can give individual metrics a
+//! longer or shorter lifetime with `set_metric_ttl`.
+
+use soroban_sdk::{contracttype, Env, Symbol, Vec};
+
+/// Default number of ledgers a metric entry lives after its last write
+/// (~30 days at 5s ledgers).
+pub const DEFAULT_METRIC_TTL: u32 = 518_400;
+
+#[contracttype]
+enum MetricKey {
+    /// name -> Metric
+    Metric(Symbol),
+    /// name -> u32 TTL override in ledgers
+    Ttl(Symbol),
+    /// Vec<Symbol> of every metric name, in registration order
+    Names,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MetricKind {
+    Counter,
+    Gauge,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Metric {
+    pub name: Symbol,
+    pub kind: MetricKind,
+    pub value: i128,
+    pub updated_at: u64,
+}
+
+pub fn get_metric(env: &Env, name: &Symbol) -> Option<Metric> {
+    env.storage()
+        .persistent()
+        .get(&MetricKey::Metric(name.clone()))
+}
+
+pub fn get_ttl(env: &Env, name: &Symbol) -> u32 {
+    env.storage()
+        .instance()
+        .get(&MetricKey::Ttl(name.clone()))
+        .unwrap_or(DEFAULT_METRIC_TTL)
+}
+
+pub fn set_ttl(env: &Env, name: &Symbol, ttl: u32) {
+    if ttl == 0 {
+        panic!("Invalid metric TTL");
+    }
+    env.storage()
+        .instance()
+        .set(&MetricKey::Ttl(name.clone()), &ttl);
+    if get_metric(env, name).is_some() {
+        bump(env, name);
+    }
+}
+
+fn bump(env: &Env, name: &Symbol) {
+    let ttl = get_ttl(env, name);
+    env.storage()
+        .persistent()
+        .extend_ttl(&MetricKey::Metric(name.clone()), ttl / 2, ttl);
+}
+
+fn store(env: &Env, name: &Symbol, kind: MetricKind, value: i128) -> Metric {
+    let key = MetricKey::Metric(name.clone());
+    if !env.storage().persistent().has(&key) {
+        let mut names = list_names(env);
+        names.push_back(name.clone());
+        env.storage().instance().set(&MetricKey::Names, &names);
+    }
+
+    let metric = Metric {
+        name: name.clone(),
+        kind,
+        value,
+        updated_at: env.ledger().timestamp(),
+    };
+    env.storage().persistent().set(&key, &metric);
+    bump(env, name);
+    metric
+}
+
+/// Add `delta` to the counter `name`, creating it at zero if needed.
+///
+/// # Panics
+/// * If `name` is already registered as a gauge
+/// * If `delta` is negative
+pub fn increment_counter(env: &Env, name: &Symbol, delta: i128) -> Metric {
+    if delta < 0 {
+        panic!("Counters only increase");
+    }
+    let current = match get_metric(env, name) {
+        Some(metric) if metric.kind == MetricKind::Counter => metric.value,
+        Some(_) => panic!("Metric kind mismatch"),
+        None => 0,
+    };
+    let value = current
+        .checked_add(delta)
+        .unwrap_or_else(|| panic!("Metric overflow"));
+    store(env, name, MetricKind::Counter, value)
+}
+
+/// Set the gauge `name` to `value`.
+///
+/// # Panics
+/// * If `name` is already registered as a counter
+pub fn set_gauge(env: &Env, name: &Symbol, value: i128) -> Metric {
+    if let Some(metric) = get_metric(env, name) {
+        if metric.kind != MetricKind::Gauge {
+            panic!("Metric kind mismatch");
+        }
+    }
+    store(env, name, MetricKind::Gauge, value)
+}
+
+fn list_names(env: &Env) -> Vec<Symbol> {
+    env.storage()
+        .instance()
+        .get(&MetricKey::Names)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Every live metric, in registration order. Metrics whose entries have
+/// expired are skipped.
+pub fn list_metrics(env: &Env) -> Vec<Metric> {
+    let mut metrics = Vec::new(env);
+    for name in list_names(env).iter() {
+        if let Some(metric) = get_metric(env, &name) {
+            metrics.push_back(metric);
+        }
+    }
+    metrics
+}
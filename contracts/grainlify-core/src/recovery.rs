@@ -0,0 +1,158 @@
+//! Guardian-based admin recovery.
+//!
+//! The admin registers a set of guardians and a quorum. If the admin key is
+//! lost or compromised, any guardian can open a recovery request naming a
+//! replacement admin; once `threshold` guardians have approved it and the
+//! timelock has elapsed, the request can be executed and the admin rotated.
+//! The timelock gives the remaining guardians the chance to cancel a
+//! request they did not expect; cancelling takes the same quorum as
+//! executing, so neither the admin being replaced nor a single guardian can
+//! block a recovery on their own. The guardian set is frozen while a request
+//! is pending.
+
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+#[contracttype]
+enum RecoveryKey {
+    Config,
+    Request,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GuardianConfig {
+    pub guardians: Vec<Address>,
+    /// Approvals required to execute a recovery.
+    pub threshold: u32,
+    /// Seconds between initiation and earliest execution.
+    pub timelock: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecoveryRequest {
+    pub new_admin: Address,
+    pub initiated_by: Address,
+    pub initiated_at: u64,
+    pub approvals: Vec<Address>,
+    /// Guardians that voted to cancel the request.
+    pub cancellations: Vec<Address>,
+}
+
+/// # Panics
+/// * If the guardian list is empty or contains duplicates
+/// * If `threshold` is zero or exceeds the number of guardians
+/// * If a recovery request is pending; swapping the guardian set would let
+///   the admin being replaced discard approvals gathered against it
+pub fn set_config(env: &Env, config: &GuardianConfig) {
+    if get_request(env).is_some() {
+        panic!("Recovery pending");
+    }
+    let count = config.guardians.len();
+    if count == 0 || config.threshold == 0 || config.threshold > count {
+        panic!("Invalid guardian threshold");
+    }
+    for (i, guardian) in config.guardians.iter().enumerate() {
+        if config.guardians.first_index_of(&guardian) != Some(i as u32) {
+            panic!("Duplicate guardian");
+        }
+    }
+    env.storage().instance().set(&RecoveryKey::Config, config);
+}
+
+pub fn get_config(env: &Env) -> Option<GuardianConfig> {
+    env.storage().instance().get(&RecoveryKey::Config)
+}
+
+pub fn get_request(env: &Env) -> Option<RecoveryRequest> {
+    env.storage().instance().get(&RecoveryKey::Request)
+}
+
+pub fn clear_request(env: &Env) {
+    env.storage().instance().remove(&RecoveryKey::Request);
+}
+
+fn require_guardian(env: &Env, guardian: &Address) -> GuardianConfig {
+    guardian.require_auth();
+    let config = get_config(env).unwrap_or_else(|| panic!("Guardians not configured"));
+    if !config.guardians.contains(guardian) {
+        panic!("Not a guardian");
+    }
+    config
+}
+
+/// Open a recovery request; the initiating guardian counts as the first
+/// approval.
+///
+/// # Panics
+/// * If a request is already pending; it must be executed or cancelled
+///   first, so one guardian cannot wipe out approvals gathered by others
+pub fn initiate(env: &Env, guardian: &Address, new_admin: &Address) -> RecoveryRequest {
+    require_guardian(env, guardian);
+    if get_request(env).is_some() {
+        panic!("Recovery already pending");
+    }
+    let mut approvals = Vec::new(env);
+    approvals.push_back(guardian.clone());
+    let request = RecoveryRequest {
+        new_admin: new_admin.clone(),
+        initiated_by: guardian.clone(),
+        initiated_at: env.ledger().timestamp(),
+        approvals,
+        cancellations: Vec::new(env),
+    };
+    env.storage()
+        .instance()
+        .set(&RecoveryKey::Request, &request);
+    request
+}
+
+/// Add `guardian`'s approval to the pending request.
+///
+/// # Panics
+/// * If there is no pending request or the guardian already approved
+pub fn approve(env: &Env, guardian: &Address) -> RecoveryRequest {
+    require_guardian(env, guardian);
+    let mut request = get_request(env).unwrap_or_else(|| panic!("No recovery pending"));
+    if request.approvals.contains(guardian) {
+        panic!("Already approved");
+    }
+    request.approvals.push_back(guardian.clone());
+    env.storage()
+        .instance()
+        .set(&RecoveryKey::Request, &request);
+    request
+}
+
+/// Add `guardian`'s vote to cancel the pending request. Returns the request
+/// and whether the cancel quorum was reached, in which case it is cleared.
+///
+/// # Panics
+/// * If there is no pending request or the guardian already voted to cancel
+pub fn cancel(env: &Env, guardian: &Address) -> (RecoveryRequest, bool) {
+    let config = require_guardian(env, guardian);
+    let mut request = get_request(env).unwrap_or_else(|| panic!("No recovery pending"));
+    if request.cancellations.contains(guardian) {
+        panic!("Already voted to cancel");
+    }
+    request.cancellations.push_back(guardian.clone());
+    let cancelled = request.cancellations.len() >= config.threshold;
+    if cancelled {
+        clear_request(env);
+    } else {
+        env.storage()
+            .instance()
+            .set(&RecoveryKey::Request, &request);
+    }
+    (request, cancelled)
+}
+
+/// Whether the pending request has quorum and its timelock has elapsed.
+pub fn is_executable(env: &Env, request: &RecoveryRequest) -> bool {
+    let config = match get_config(env) {
+        Some(config) => config,
+        None => return false,
+    };
+    request.approvals.len() >= config.threshold
+        && env.ledger().timestamp() >= request.initiated_at.saturating_add(config.timelock)
+}
@@ -0,0 +1,76 @@
+#![cfg(test)]
+
+use crate::{GrainlifyContract, GrainlifyContractClient};
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env};
+
+fn setup(env: &Env) -> (GrainlifyContractClient<'static>, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, GrainlifyContract);
+    let client = GrainlifyContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.init_admin(&admin);
+    (client, admin)
+}
+
+#[test]
+fn test_two_step_rotation() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let new_admin = Address::generate(&env);
+
+    client.propose_admin(&new_admin);
+    let pending = client.get_pending_admin().unwrap();
+    assert_eq!(pending.new_admin, new_admin);
+    assert_eq!(pending.proposed_by, admin);
+    // Nothing changes until the proposed admin accepts.
+    assert_eq!(client.get_admin(), Some(admin.clone()));
+
+    client.accept_admin();
+    assert_eq!(client.get_admin(), Some(new_admin.clone()));
+    assert_eq!(client.get_pending_admin(), None);
+
+    let history = client.get_admin_history();
+    assert_eq!(history.len(), 1);
+    let rotation = history.get(0).unwrap();
+    assert_eq!(rotation.previous_admin, admin);
+    assert_eq!(rotation.new_admin, new_admin);
+    assert_eq!(rotation.method, symbol_short!("accept"));
+}
+
+#[test]
+#[should_panic]
+fn test_accept_requires_proposed_admin_auth() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let new_admin = Address::generate(&env);
+    client.propose_admin(&new_admin);
+
+    env.set_auths(&[]);
+    client.accept_admin();
+}
+
+#[test]
+#[should_panic(expected = "No pending admin")]
+fn test_cancelled_proposal_cannot_be_accepted() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    client.propose_admin(&Address::generate(&env));
+
+    client.cancel_admin_proposal();
+    assert_eq!(client.get_pending_admin(), None);
+    assert_eq!(client.get_admin(), Some(admin));
+    client.accept_admin();
+}
+
+#[test]
+fn test_new_proposal_replaces_pending() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+
+    client.propose_admin(&first);
+    client.propose_admin(&second);
+    client.accept_admin();
+    assert_eq!(client.get_admin(), Some(second));
+}
@@ -0,0 +1,121 @@
+#![cfg(test)]
+
+use crate::{factory, EscrowDeployment, EscrowKind, GrainlifyContract, GrainlifyContractClient};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, testutils::Address as _, Address, BytesN, Env,
+};
+
+#[contract]
+pub struct MockEscrow;
+
+#[contractimpl]
+impl MockEscrow {
+    pub fn set_version(env: Env, version: u32) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("ver"), &version);
+    }
+
+    pub fn get_version(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("ver"))
+            .unwrap_or(0)
+    }
+}
+
+fn setup(env: &Env) -> GrainlifyContractClient<'static> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, GrainlifyContract);
+    let client = GrainlifyContractClient::new(env, &contract_id);
+    client.init_admin(&Address::generate(env));
+    client
+}
+
+fn register_escrow(env: &Env, client: &GrainlifyContractClient, version: u32) -> Address {
+    let escrow = env.register_contract(None, MockEscrow);
+    MockEscrowClient::new(env, &escrow).set_version(&version);
+    env.as_contract(&client.address, || {
+        factory::record(
+            env,
+            &EscrowDeployment {
+                address: escrow.clone(),
+                kind: EscrowKind::Bounty,
+                wasm_hash: BytesN::from_array(env, &[0u8; 32]),
+                salt: BytesN::from_array(env, &[0u8; 32]),
+                initialized: true,
+                deployed_at: 0,
+            },
+        );
+    });
+    escrow
+}
+
+#[test]
+fn test_check_compatibility_uses_current_core_version() {
+    let env = Env::default();
+    let client = setup(&env);
+    let core_version = client.get_version();
+
+    assert!(!client.check_compatibility(&EscrowKind::Bounty, &1));
+
+    client.set_compatibility(&EscrowKind::Bounty, &core_version, &1, &2);
+    assert!(client.check_compatibility(&EscrowKind::Bounty, &1));
+    assert!(client.check_compatibility(&EscrowKind::Bounty, &2));
+    assert!(!client.check_compatibility(&EscrowKind::Bounty, &3));
+    assert!(!client.check_compatibility(&EscrowKind::Program, &1));
+}
+
+#[test]
+fn test_staged_upgrade_of_core() {
+    let env = Env::default();
+    let client = setup(&env);
+    let core_version = client.get_version();
+
+    client.set_compatibility(&EscrowKind::Bounty, &core_version, &1, &1);
+    // Next core version drops support for v1 escrows.
+    client.set_compatibility(&EscrowKind::Bounty, &(core_version + 1), &2, &2);
+    assert!(client.check_compatibility(&EscrowKind::Bounty, &1));
+
+    client.set_version(&(core_version + 1));
+    assert!(!client.check_compatibility(&EscrowKind::Bounty, &1));
+    assert!(client.check_compatibility(&EscrowKind::Bounty, &2));
+}
+
+#[test]
+fn test_escrow_compatibility_follows_reported_version() {
+    let env = Env::default();
+    let client = setup(&env);
+    client.set_compatibility(&EscrowKind::Bounty, &client.get_version(), &2, &3);
+
+    let escrow = register_escrow(&env, &client, 1);
+    assert_eq!(client.get_escrow_version(&escrow), None);
+    assert!(!client.check_escrow_compatibility(&escrow));
+
+    assert_eq!(client.refresh_escrow_version(&escrow), 1);
+    assert!(!client.check_escrow_compatibility(&escrow));
+
+    MockEscrowClient::new(&env, &escrow).set_version(&2);
+    client.refresh_escrow_version(&escrow);
+    assert_eq!(client.get_escrow_version(&escrow), Some(2));
+    assert!(client.check_escrow_compatibility(&escrow));
+}
+
+#[test]
+#[should_panic(expected = "Escrow not registered")]
+fn test_refresh_requires_registered_escrow() {
+    let env = Env::default();
+    let client = setup(&env);
+    let escrow = env.register_contract(None, MockEscrow);
+
+    client.refresh_escrow_version(&escrow);
+}
+
+#[test]
+#[should_panic(expected = "Invalid version range")]
+fn test_rejects_inverted_range() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    client.set_compatibility(&EscrowKind::Program, &2, &3, &1);
+}
@@ -0,0 +1,98 @@
+#![cfg(test)]
+
+use crate::config::CONFIG_HISTORY_LIMIT;
+use crate::{ConfigValue, GrainlifyContract, GrainlifyContractClient};
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env};
+
+fn setup(env: &Env) -> (GrainlifyContractClient<'static>, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, GrainlifyContract);
+    let client = GrainlifyContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.init_admin(&admin);
+    (client, admin)
+}
+
+#[test]
+fn test_typed_values_round_trip() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let oracle = Address::generate(&env);
+
+    client.set_config_u64(&symbol_short!("fee_bps"), &250);
+    client.set_config_i128(&symbol_short!("min_lock"), &-1);
+    client.set_config_address(&symbol_short!("oracle"), &oracle);
+    client.set_config_bool(&symbol_short!("kyc"), &true);
+
+    assert_eq!(client.get_config_u64(&symbol_short!("fee_bps")), Some(250));
+    assert_eq!(client.get_config_i128(&symbol_short!("min_lock")), Some(-1));
+    assert_eq!(
+        client.get_config_address(&symbol_short!("oracle")),
+        Some(oracle)
+    );
+    assert_eq!(client.get_config_bool(&symbol_short!("kyc")), Some(true));
+    assert_eq!(client.get_config_u64(&symbol_short!("unset")), None);
+}
+
+#[test]
+fn test_changes_are_logged() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let key = symbol_short!("fee_bps");
+
+    client.set_config_u64(&key, &100);
+    client.set_config_u64(&key, &150);
+
+    let history = client.get_config_history();
+    assert_eq!(history.len(), 2);
+    let latest = history.get(1).unwrap();
+    assert_eq!(latest.key, key);
+    assert_eq!(latest.value, ConfigValue::U64(150));
+    assert_eq!(latest.changed_by, admin);
+    assert_eq!(history.get(0).unwrap().value, ConfigValue::U64(100));
+}
+
+#[test]
+fn test_history_is_bounded() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+
+    for i in 0..(CONFIG_HISTORY_LIMIT as u64 + 3) {
+        env.budget().reset_default();
+        client.set_config_u64(&symbol_short!("fee_bps"), &i);
+    }
+
+    let history = client.get_config_history();
+    assert_eq!(history.len(), CONFIG_HISTORY_LIMIT);
+    assert_eq!(history.get(0).unwrap().value, ConfigValue::U64(3));
+}
+
+#[test]
+#[should_panic(expected = "Config type mismatch")]
+fn test_key_cannot_change_type() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+
+    client.set_config_u64(&symbol_short!("fee_bps"), &100);
+    client.set_config_bool(&symbol_short!("fee_bps"), &true);
+}
+
+#[test]
+#[should_panic(expected = "Config type mismatch")]
+fn test_wrong_getter_panics() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+
+    client.set_config_bool(&symbol_short!("kyc"), &false);
+    client.get_config_u64(&symbol_short!("kyc"));
+}
+
+#[test]
+#[should_panic]
+fn test_set_config_requires_admin() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+
+    env.set_auths(&[]);
+    client.set_config_u64(&symbol_short!("fee_bps"), &100);
+}
@@ -0,0 +1,85 @@
+#![cfg(test)]
+
+use crate::asset::AssetIdError;
+use crate::error_registry::DOMAIN_WIDTH;
+use crate::nonce::NonceError;
+use crate::{ContractError, ErrorDomain, GovError, GrainlifyContract, GrainlifyContractClient};
+use soroban_sdk::{Env, String};
+
+fn setup(env: &Env) -> GrainlifyContractClient<'static> {
+    let contract_id = env.register_contract(None, GrainlifyContract);
+    GrainlifyContractClient::new(env, &contract_id)
+}
+
+#[test]
+fn test_overlapping_local_codes_get_distinct_global_codes() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    let bounty = client.get_global_error_code(&ErrorDomain::BountyEscrow, &13);
+    let governance = client.get_global_error_code(&ErrorDomain::Governance, &13);
+    assert_ne!(bounty, governance);
+
+    let info = client.describe_error(&bounty).unwrap();
+    assert_eq!(info.domain, ErrorDomain::BountyEscrow);
+    assert_eq!(info.local_code, 13);
+    assert_eq!(info.name, String::from_str(&env, "InvalidAmount"));
+
+    let info = client.describe_error(&governance).unwrap();
+    assert_eq!(info.name, String::from_str(&env, "ExecutionDelayNotMet"));
+}
+
+#[test]
+fn test_unknown_codes_are_not_described() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    assert_eq!(client.describe_error(&0), None);
+    assert_eq!(client.describe_error(&(3 * DOMAIN_WIDTH + 15)), None);
+    assert_eq!(client.describe_error(&(99 * DOMAIN_WIDTH + 1)), None);
+}
+
+#[test]
+fn test_core_registry_matches_contract_enums() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    for (code, name) in [
+        (ContractError::NotInitialized as u32, "NotInitialized"),
+        (
+            ContractError::UnauthorizedReporter as u32,
+            "UnauthorizedReporter",
+        ),
+        (NonceError::InvalidNonce as u32, "InvalidNonce"),
+        (
+            AssetIdError::TransferAmountMismatch as u32,
+            "TransferAmountMismatch",
+        ),
+    ] {
+        let global = client.get_global_error_code(&ErrorDomain::Core, &code);
+        let info = client.describe_error(&global).unwrap();
+        assert_eq!(info.code, global);
+        assert_eq!(info.name, String::from_str(&env, name));
+    }
+
+    let global = client.get_global_error_code(
+        &ErrorDomain::Governance,
+        &(GovError::ProposalExpired as u32),
+    );
+    assert_eq!(
+        client.describe_error(&global).unwrap().name,
+        String::from_str(&env, "ProposalExpired")
+    );
+}
+
+#[test]
+fn test_list_error_codes_round_trips() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    let errors = client.list_error_codes(&ErrorDomain::ProgramEscrow);
+    assert_eq!(errors.len(), 3);
+    for info in errors.iter() {
+        assert_eq!(client.describe_error(&info.code), Some(info.clone()));
+    }
+}
@@ -0,0 +1,87 @@
+#![cfg(test)]
+
+use crate::{EscrowKind, GrainlifyContract, GrainlifyContractClient};
+use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, Vec};
+
+fn setup(env: &Env) -> (GrainlifyContractClient<'static>, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, GrainlifyContract);
+    let client = GrainlifyContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.init_admin(&admin);
+    (client, admin)
+}
+
+#[test]
+fn test_set_escrow_wasm_per_kind() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+
+    let program_hash = BytesN::from_array(&env, &[1u8; 32]);
+    let bounty_hash = BytesN::from_array(&env, &[2u8; 32]);
+
+    assert_eq!(client.get_escrow_wasm(&EscrowKind::Program), None);
+    client.set_escrow_wasm(&EscrowKind::Program, &program_hash);
+    client.set_escrow_wasm(&EscrowKind::Bounty, &bounty_hash);
+
+    assert_eq!(
+        client.get_escrow_wasm(&EscrowKind::Program),
+        Some(program_hash)
+    );
+    assert_eq!(
+        client.get_escrow_wasm(&EscrowKind::Bounty),
+        Some(bounty_hash)
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_set_escrow_wasm_requires_admin_auth() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, GrainlifyContract);
+    let client = GrainlifyContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.init_admin(&admin);
+
+    client.set_escrow_wasm(&EscrowKind::Program, &BytesN::from_array(&env, &[1u8; 32]));
+}
+
+#[test]
+#[should_panic(expected = "Escrow wasm not registered")]
+fn test_deploy_escrow_without_wasm_panics() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+
+    client.deploy_escrow(
+        &EscrowKind::Bounty,
+        &BytesN::from_array(&env, &[7u8; 32]),
+        &Vec::new(&env),
+    );
+}
+
+#[test]
+fn test_escrow_address_is_deterministic_per_salt() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+
+    let salt_a = BytesN::from_array(&env, &[1u8; 32]);
+    let salt_b = BytesN::from_array(&env, &[2u8; 32]);
+
+    assert_eq!(
+        client.get_escrow_address(&salt_a),
+        client.get_escrow_address(&salt_a)
+    );
+    assert_ne!(
+        client.get_escrow_address(&salt_a),
+        client.get_escrow_address(&salt_b)
+    );
+}
+
+#[test]
+fn test_registry_starts_empty() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+
+    assert_eq!(client.get_deployed_escrows().len(), 0);
+    assert_eq!(client.get_escrow_deployment(&Address::generate(&env)), None);
+}
@@ -0,0 +1,62 @@
+#![cfg(test)]
+
+use crate::{GrainlifyContract, GrainlifyContractClient};
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env};
+
+fn setup(env: &Env) -> GrainlifyContractClient<'static> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, GrainlifyContract);
+    let client = GrainlifyContractClient::new(env, &contract_id);
+    client.init_admin(&Address::generate(env));
+    client
+}
+
+#[test]
+fn test_unknown_flag_is_disabled() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    assert!(!client.is_feature_enabled(&symbol_short!("anon_esc")));
+    assert_eq!(client.get_feature_flags().len(), 0);
+}
+
+#[test]
+fn test_set_and_kill_feature_flag() {
+    let env = Env::default();
+    let client = setup(&env);
+    let flag = symbol_short!("oracle");
+
+    client.set_feature_flag(&flag, &true);
+    assert!(client.is_feature_enabled(&flag));
+
+    client.set_feature_flag(&flag, &false);
+    assert!(!client.is_feature_enabled(&flag));
+
+    let flags = client.get_feature_flags();
+    assert_eq!(flags.len(), 1);
+    assert_eq!(flags.get(flag), Some(false));
+}
+
+#[test]
+fn test_flags_are_independent() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    client.set_feature_flag(&symbol_short!("anon_esc"), &true);
+    client.set_feature_flag(&symbol_short!("oracle"), &false);
+
+    assert!(client.is_feature_enabled(&symbol_short!("anon_esc")));
+    assert!(!client.is_feature_enabled(&symbol_short!("oracle")));
+    assert_eq!(client.get_feature_flags().len(), 2);
+}
+
+#[test]
+#[should_panic]
+fn test_set_feature_flag_requires_admin_auth() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, GrainlifyContract);
+    let client = GrainlifyContractClient::new(&env, &contract_id);
+    client.init_admin(&Address::generate(&env));
+
+    client.set_feature_flag(&symbol_short!("oracle"), &true);
+}
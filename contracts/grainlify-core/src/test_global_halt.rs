@@ -0,0 +1,81 @@
+#![cfg(test)]
+
+use crate::{GrainlifyContract, GrainlifyContractClient};
+use soroban_sdk::{testutils::Address as _, Address, Env, String, Vec};
+
+fn setup(env: &Env) -> (GrainlifyContractClient<'static>, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, GrainlifyContract);
+    let client = GrainlifyContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.init_admin(&admin);
+    (client, admin)
+}
+
+#[test]
+fn test_admin_can_halt_and_resume() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let reason = String::from_str(&env, "oracle exploit");
+
+    assert!(!client.is_globally_halted());
+    client.global_halt(&admin, &reason);
+
+    assert!(client.is_globally_halted());
+    let state = client.get_global_halt().unwrap();
+    assert_eq!(state.halted_by, admin);
+    assert_eq!(state.reason, reason);
+
+    client.global_resume(&admin);
+    assert!(!client.is_globally_halted());
+    assert_eq!(client.get_global_halt(), None);
+}
+
+#[test]
+fn test_multisig_signer_can_halt() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, GrainlifyContract);
+    let client = GrainlifyContractClient::new(&env, &contract_id);
+
+    let signer = Address::generate(&env);
+    let mut signers = Vec::new(&env);
+    signers.push_back(signer.clone());
+    signers.push_back(Address::generate(&env));
+    client.init(&signers, &2);
+
+    client.global_halt(&signer, &String::from_str(&env, "incident"));
+    assert!(client.is_globally_halted());
+}
+
+#[test]
+#[should_panic]
+fn test_outsider_cannot_halt() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+
+    client.global_halt(
+        &Address::generate(&env),
+        &String::from_str(&env, "griefing"),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Already halted")]
+fn test_double_halt_panics() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let reason = String::from_str(&env, "incident");
+
+    client.global_halt(&admin, &reason);
+    client.global_halt(&admin, &reason);
+}
+
+#[test]
+#[should_panic(expected = "Not halted")]
+fn test_resume_without_halt_panics() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+
+    client.global_resume(&admin);
+}
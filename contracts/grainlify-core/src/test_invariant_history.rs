@@ -0,0 +1,138 @@
+#![cfg(test)]
+
+use crate::invariant_history::INVARIANT_HISTORY_CAPACITY;
+use crate::{GrainlifyContract, GrainlifyContractClient, KeeperConfig};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, Symbol,
+};
+
+fn setup(env: &Env) -> (GrainlifyContractClient<'static>, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, GrainlifyContract);
+    let client = GrainlifyContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.init_admin(&admin);
+    (client, admin)
+}
+
+fn keeper_token(
+    env: &Env,
+    client: &GrainlifyContractClient,
+    funded: i128,
+) -> token::Client<'static> {
+    let token_id = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    if funded > 0 {
+        token::StellarAssetClient::new(env, &token_id).mint(&client.address, &funded);
+    }
+    token::Client::new(env, &token_id)
+}
+
+#[test]
+fn test_record_invariant_run_appends_history() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+
+    assert_eq!(client.get_invariant_history(&10).len(), 0);
+
+    let run = client.record_invariant_run();
+    assert_eq!(run.run_id, 0);
+    assert_eq!(run.triggered_by, admin);
+    assert!(run.healthy);
+
+    let history = client.get_invariant_history(&10);
+    assert_eq!(history.len(), 1);
+    assert_eq!(history.get(0).unwrap(), run);
+}
+
+#[test]
+fn test_violations_are_preserved_in_history() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+
+    client.record_invariant_run();
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .set(&Symbol::new(&env, "err_count"), &9_u64);
+    });
+    client.record_invariant_run();
+
+    let history = client.get_invariant_history(&10);
+    assert_eq!(history.len(), 2);
+    // Newest first.
+    assert!(!history.get(0).unwrap().healthy);
+    assert!(history.get(0).unwrap().violation_count > 0);
+    assert!(history.get(1).unwrap().healthy);
+}
+
+#[test]
+fn test_history_ring_buffer_keeps_latest_runs() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+
+    for _ in 0..(INVARIANT_HISTORY_CAPACITY + 5) {
+        client.record_invariant_run();
+    }
+
+    let history = client.get_invariant_history(&u32::MAX);
+    assert_eq!(history.len() as u64, INVARIANT_HISTORY_CAPACITY);
+    assert_eq!(
+        history.get(0).unwrap().run_id,
+        INVARIANT_HISTORY_CAPACITY + 4
+    );
+    assert_eq!(history.get(history.len() - 1).unwrap().run_id, 5);
+    assert_eq!(client.get_invariant_history(&3).len(), 3);
+}
+
+#[test]
+fn test_scheduled_check_pays_keeper_and_enforces_interval() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let token = keeper_token(&env, &client, 1_000);
+    client.set_keeper_config(&KeeperConfig {
+        reward_token: token.address.clone(),
+        reward_amount: 100,
+        min_interval: 3_600,
+    });
+
+    let keeper = Address::generate(&env);
+    let run = client.run_scheduled_check(&keeper);
+    assert_eq!(run.triggered_by, keeper);
+    assert_eq!(token.balance(&keeper), 100);
+
+    assert!(client.try_run_scheduled_check(&keeper).is_err());
+
+    env.ledger().with_mut(|li| li.timestamp += 3_600);
+    client.run_scheduled_check(&keeper);
+    assert_eq!(token.balance(&keeper), 200);
+    assert_eq!(client.get_invariant_history(&10).len(), 2);
+}
+
+#[test]
+fn test_scheduled_check_records_without_reward_when_unfunded() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let token = keeper_token(&env, &client, 0);
+    client.set_keeper_config(&KeeperConfig {
+        reward_token: token.address.clone(),
+        reward_amount: 100,
+        min_interval: 0,
+    });
+
+    let keeper = Address::generate(&env);
+    client.run_scheduled_check(&keeper);
+    assert_eq!(token.balance(&keeper), 0);
+    assert_eq!(client.get_invariant_history(&10).len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "Keeper not configured")]
+fn test_scheduled_check_requires_keeper_config() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+
+    client.run_scheduled_check(&Address::generate(&env));
+}
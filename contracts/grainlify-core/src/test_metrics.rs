@@ -0,0 +1,100 @@
+#![cfg(test)]
+
+use crate::metrics::DEFAULT_METRIC_TTL;
+use crate::{
+    factory, EscrowDeployment, EscrowKind, GrainlifyContract, GrainlifyContractClient, MetricKind,
+};
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, BytesN, Env};
+
+fn setup(env: &Env) -> (GrainlifyContractClient<'static>, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, GrainlifyContract);
+    let client = GrainlifyContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.init_admin(&admin);
+    (client, admin)
+}
+
+#[test]
+fn test_counters_and_gauges() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+
+    client.increment_counter(&admin, &symbol_short!("payouts"));
+    let counter = client.increment_counter(&admin, &symbol_short!("payouts"));
+    assert_eq!(counter.kind, MetricKind::Counter);
+    assert_eq!(counter.value, 2);
+
+    client.set_gauge(&admin, &symbol_short!("tvl"), &5_000);
+    client.set_gauge(&admin, &symbol_short!("tvl"), &4_200);
+    assert_eq!(
+        client.get_metric(&symbol_short!("tvl")).unwrap().value,
+        4_200
+    );
+
+    let all = client.list_metrics();
+    assert_eq!(all.len(), 2);
+    assert_eq!(all.get(0).unwrap().name, symbol_short!("payouts"));
+    assert_eq!(all.get(1).unwrap().kind, MetricKind::Gauge);
+    assert_eq!(client.get_metric(&symbol_short!("missing")), None);
+}
+
+#[test]
+#[should_panic(expected = "Metric kind mismatch")]
+fn test_gauge_cannot_be_incremented() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+
+    client.set_gauge(&admin, &symbol_short!("tvl"), &1);
+    client.increment_counter(&admin, &symbol_short!("tvl"));
+}
+
+#[test]
+fn test_registered_escrow_can_report() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let escrow = Address::generate(&env);
+    env.as_contract(&client.address, || {
+        factory::record(
+            &env,
+            &EscrowDeployment {
+                address: escrow.clone(),
+                kind: EscrowKind::Bounty,
+                wasm_hash: BytesN::from_array(&env, &[0u8; 32]),
+                salt: BytesN::from_array(&env, &[0u8; 32]),
+                initialized: true,
+                deployed_at: 0,
+            },
+        );
+    });
+
+    let metric = client.increment_counter(&escrow, &symbol_short!("locks"));
+    assert_eq!(metric.value, 1);
+}
+
+#[test]
+#[should_panic(expected = "6")]
+fn test_unregistered_reporter_rejected() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+
+    client.increment_counter(&Address::generate(&env), &symbol_short!("locks"));
+}
+
+#[test]
+fn test_metric_ttl_override() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let name = symbol_short!("payouts");
+
+    assert_eq!(client.get_metric_ttl(&name), DEFAULT_METRIC_TTL);
+    client.increment_counter(&admin, &name);
+
+    client.set_metric_ttl(&name, &1_000);
+    assert_eq!(client.get_metric_ttl(&name), 1_000);
+    assert_eq!(
+        client.get_metric_ttl(&symbol_short!("other")),
+        DEFAULT_METRIC_TTL
+    );
+    assert_eq!(client.get_metric(&name).unwrap().value, 1);
+}
@@ -0,0 +1,181 @@
+#![cfg(test)]
+
+use crate::{GrainlifyContract, GrainlifyContractClient, GuardianConfig};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    vec, Address, Env,
+};
+
+const TIMELOCK: u64 = 86_400;
+
+fn setup(env: &Env) -> (GrainlifyContractClient<'static>, Address, [Address; 3]) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, GrainlifyContract);
+    let client = GrainlifyContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.init_admin(&admin);
+
+    let guardians = [
+        Address::generate(env),
+        Address::generate(env),
+        Address::generate(env),
+    ];
+    client.set_guardians(&GuardianConfig {
+        guardians: vec![
+            env,
+            guardians[0].clone(),
+            guardians[1].clone(),
+            guardians[2].clone(),
+        ],
+        threshold: 2,
+        timelock: TIMELOCK,
+    });
+    (client, admin, guardians)
+}
+
+#[test]
+fn test_quorum_after_timelock_rotates_admin() {
+    let env = Env::default();
+    let (client, admin, guardians) = setup(&env);
+    let new_admin = Address::generate(&env);
+
+    client.initiate_recovery(&guardians[0], &new_admin);
+    // Quorum reached, but the timelock has not elapsed.
+    assert!(!client.approve_recovery(&guardians[1]));
+    assert_eq!(client.get_admin(), Some(admin.clone()));
+    assert!(client.try_execute_recovery().is_err());
+
+    env.ledger().with_mut(|li| li.timestamp += TIMELOCK);
+    client.execute_recovery();
+    assert_eq!(client.get_admin(), Some(new_admin.clone()));
+    assert_eq!(client.get_recovery_request(), None);
+
+    let rotation = client.get_admin_history().get(0).unwrap();
+    assert_eq!(rotation.previous_admin, admin);
+    assert_eq!(rotation.method, symbol_short!("recovery"));
+}
+
+#[test]
+fn test_late_approval_executes_immediately() {
+    let env = Env::default();
+    let (client, _admin, guardians) = setup(&env);
+    let new_admin = Address::generate(&env);
+
+    client.initiate_recovery(&guardians[2], &new_admin);
+    env.ledger().with_mut(|li| li.timestamp += TIMELOCK);
+    assert!(client.approve_recovery(&guardians[0]));
+    assert_eq!(client.get_admin(), Some(new_admin));
+}
+
+#[test]
+fn test_single_approval_is_not_enough() {
+    let env = Env::default();
+    let (client, admin, guardians) = setup(&env);
+
+    client.initiate_recovery(&guardians[0], &Address::generate(&env));
+    env.ledger().with_mut(|li| li.timestamp += TIMELOCK);
+    assert!(client.try_execute_recovery().is_err());
+    assert_eq!(client.get_admin(), Some(admin));
+}
+
+#[test]
+#[should_panic(expected = "Already approved")]
+fn test_guardian_cannot_approve_twice() {
+    let env = Env::default();
+    let (client, _admin, guardians) = setup(&env);
+
+    client.initiate_recovery(&guardians[0], &Address::generate(&env));
+    client.approve_recovery(&guardians[0]);
+}
+
+#[test]
+#[should_panic(expected = "Recovery already pending")]
+fn test_pending_request_cannot_be_replaced() {
+    let env = Env::default();
+    let (client, _admin, guardians) = setup(&env);
+
+    client.initiate_recovery(&guardians[0], &Address::generate(&env));
+    client.approve_recovery(&guardians[1]);
+    client.initiate_recovery(&guardians[2], &Address::generate(&env));
+}
+
+#[test]
+fn test_new_request_after_cancel() {
+    let env = Env::default();
+    let (client, _admin, guardians) = setup(&env);
+
+    client.initiate_recovery(&guardians[0], &Address::generate(&env));
+    client.cancel_recovery(&guardians[1]);
+    client.cancel_recovery(&guardians[2]);
+    let replacement = Address::generate(&env);
+    let request = client.initiate_recovery(&guardians[2], &replacement);
+    assert_eq!(request.new_admin, replacement);
+    assert_eq!(request.approvals.len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "Not a guardian")]
+fn test_non_guardian_cannot_initiate() {
+    let env = Env::default();
+    let (client, _admin, _guardians) = setup(&env);
+
+    client.initiate_recovery(&Address::generate(&env), &Address::generate(&env));
+}
+
+#[test]
+fn test_guardian_quorum_can_cancel_recovery() {
+    let env = Env::default();
+    let (client, admin, guardians) = setup(&env);
+
+    client.initiate_recovery(&guardians[0], &Address::generate(&env));
+    client.approve_recovery(&guardians[1]);
+    assert!(!client.cancel_recovery(&guardians[2]));
+    assert_eq!(
+        client.get_recovery_request().unwrap().cancellations.len(),
+        1
+    );
+    assert!(client.cancel_recovery(&guardians[1]));
+    assert_eq!(client.get_recovery_request(), None);
+
+    env.ledger().with_mut(|li| li.timestamp += TIMELOCK);
+    assert!(client.try_execute_recovery().is_err());
+    assert_eq!(client.get_admin(), Some(admin));
+}
+
+#[test]
+#[should_panic(expected = "Invalid guardian threshold")]
+fn test_threshold_cannot_exceed_guardians() {
+    let env = Env::default();
+    let (client, _admin, guardians) = setup(&env);
+
+    client.set_guardians(&GuardianConfig {
+        guardians: vec![&env, guardians[0].clone()],
+        threshold: 2,
+        timelock: 0,
+    });
+}
+
+#[test]
+#[should_panic(expected = "Not a guardian")]
+fn test_admin_cannot_cancel_recovery() {
+    let env = Env::default();
+    let (client, admin, guardians) = setup(&env);
+
+    client.initiate_recovery(&guardians[0], &Address::generate(&env));
+    client.cancel_recovery(&admin);
+}
+
+#[test]
+#[should_panic(expected = "Recovery pending")]
+fn test_guardians_frozen_while_recovery_pending() {
+    let env = Env::default();
+    let (client, _admin, guardians) = setup(&env);
+
+    client.initiate_recovery(&guardians[0], &Address::generate(&env));
+    client.set_guardians(&GuardianConfig {
+        guardians: vec![&env, Address::generate(&env)],
+        threshold: 1,
+        timelock: 0,
+    });
+}
@@ -1,8 +1,9 @@
 use grainlify_core::asset::{
-    balance, normalize_asset_id, transfer_exact, validate_asset_id, AssetIdError,
+    balance, is_native, native_asset_id, normalize_amount, normalize_asset_id, transfer_exact,
+    validate_asset_id, AssetIdError,
 };
 use soroban_sdk::{
-    contract, contractimpl, contracttype, testutils::Address as _, token, Address, Env,
+    contract, contractimpl, contracttype, testutils::Address as _, token, Address, Bytes, Env,
 };
 
 #[contracttype]
@@ -171,3 +172,39 @@ fn transfer_exact_is_noop_for_zero_amount_or_same_party() {
     );
     assert_eq!(balance(&env, &token_address, &holder), Ok(321));
 }
+
+#[test]
+fn native_asset_id_matches_deployed_native_sac() {
+    let env = Env::default();
+    let native_sac = env
+        .deployer()
+        .with_stellar_asset(Bytes::from_array(&env, &[0, 0, 0, 0]))
+        .deploy();
+
+    assert_eq!(native_asset_id(&env), native_sac);
+    assert!(is_native(&env, &native_sac));
+
+    let token_admin = Address::generate(&env);
+    let sac = env.register_stellar_asset_contract_v2(token_admin);
+    assert!(!is_native(&env, &sac.address()));
+}
+
+#[test]
+fn normalize_amount_scales_to_asset_decimals() {
+    let env = Env::default();
+    let token_admin = Address::generate(&env);
+    let sac = env.register_stellar_asset_contract_v2(token_admin);
+    let native = native_asset_id(&env);
+
+    assert_eq!(normalize_amount(&env, &native, 5, 0), Some(50_000_000));
+    assert_eq!(
+        normalize_amount(&env, &sac.address(), 5, 0),
+        Some(50_000_000)
+    );
+    // 9 -> 7 decimals floors the two dropped digits
+    assert_eq!(
+        normalize_amount(&env, &sac.address(), 123_456_789, 9),
+        Some(1_234_567)
+    );
+    assert_eq!(normalize_amount(&env, &native, i128::MAX, 0), None);
+}
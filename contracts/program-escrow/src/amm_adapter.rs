@@ -0,0 +1,109 @@
+//! # AMM Payout Adapter
+//!
+//! Lets `single_payout_as` deliver a payout in a different asset than the
+//! program holds by routing it through an admin-registered AMM contract.
+//!
+//! The adapter transfers the program tokens to the AMM and then invokes
+//!
+//! ```text
+//! swap(token_in, token_out, amount_in, min_out, to) -> i128
+//! ```
+//!
+//! expecting the AMM to send at least `min_out` of `token_out` to `to`. The
+//! adapter does not trust the return value: it measures the recipient's
+//! `token_out` balance before and after the call and panics if the increase is
+//! below `min_out`. Any failure (no AMM registered, AMM call trapping, short
+//! output) aborts the whole payout, so the recipient never receives the
+//! program asset in place of the requested one.
+
+use crate::events_log::LogLevel;
+use crate::{next_event_sequence, EVENT_VERSION_V2};
+use soroban_sdk::{contracttype, symbol_short, token, vec, Address, Env, IntoVal, Symbol};
+
+const PAYOUT_SWAPPED: Symbol = symbol_short!("PaySwap");
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AmmKey {
+    /// AMM contract used by `single_payout_as`
+    Amm,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutSwappedEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub recipient: Address,
+    pub token_in: Address,
+    pub amount_in: i128,
+    pub token_out: Address,
+    pub amount_out: i128,
+}
+
+pub fn set_amm(env: &Env, amm: Option<Address>) {
+    match amm {
+        Some(amm) => env.storage().instance().set(&AmmKey::Amm, &amm),
+        None => env.storage().instance().remove(&AmmKey::Amm),
+    }
+}
+
+pub fn get_amm(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&AmmKey::Amm)
+}
+
+/// Swap `amount_in` of the contract's `token_in` into `token_out` for
+/// `recipient`, returning the amount delivered. Panics unless at least
+/// `min_out` arrives.
+pub fn swap_to(
+    env: &Env,
+    token_in: &Address,
+    token_out: &Address,
+    amount_in: i128,
+    min_out: i128,
+    recipient: &Address,
+) -> i128 {
+    let amm = get_amm(env).unwrap_or_else(|| panic!("AMM not registered"));
+    if token_out == token_in {
+        panic!("Target token must differ from program token");
+    }
+    if min_out <= 0 {
+        panic!("min_out must be greater than zero");
+    }
+
+    let out_client = token::Client::new(env, token_out);
+    let before = out_client.balance(recipient);
+
+    token::Client::new(env, token_in).transfer(&env.current_contract_address(), &amm, &amount_in);
+    env.invoke_contract::<i128>(
+        &amm,
+        &Symbol::new(env, "swap"),
+        vec![
+            env,
+            token_in.into_val(env),
+            token_out.into_val(env),
+            amount_in.into_val(env),
+            min_out.into_val(env),
+            recipient.into_val(env),
+        ],
+    );
+
+    let amount_out = out_client.balance(recipient) - before;
+    if amount_out < min_out {
+        panic!("Slippage exceeded");
+    }
+
+    env.events().publish(
+        (LogLevel::Info, PAYOUT_SWAPPED),
+        PayoutSwappedEvent {
+            version: EVENT_VERSION_V2,
+            sequence: next_event_sequence(env),
+            recipient: recipient.clone(),
+            token_in: token_in.clone(),
+            amount_in,
+            token_out: token_out.clone(),
+            amount_out,
+        },
+    );
+    amount_out
+}
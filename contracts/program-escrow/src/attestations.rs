@@ -0,0 +1,120 @@
+//! # Completion Attestations
+//!
+//! Non-transferable records minted to a recipient whenever the program pays
+//! them for completed work: single and batch payouts and release-schedule
+//! (milestone) releases. Together they form a portable, on-chain track record
+//! that other programs can read without trusting an off-chain profile.
+//!
+//! Attestations are soulbound by construction: they are stored under the
+//! recipient's address and this module exposes no transfer or burn path.
+//! `work_hash` holds the 32-byte hash of the delivered work when the payer
+//! supplies one (see `single_payout_with_work`); other payout paths leave it
+//! all zero.
+
+use crate::events_log::LogLevel;
+use crate::EVENT_VERSION_V2;
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, String, Symbol, Vec};
+
+const ATTESTATION_MINTED: Symbol = symbol_short!("AttMint");
+
+/// Maximum number of attestations returned by a single `get_attestations` call.
+pub const MAX_ATTESTATION_PAGE_SIZE: u32 = 100;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AttestationKey {
+    /// recipient -> u32 number of attestations held
+    Count(Address),
+    /// (recipient, zero-based index) -> Attestation
+    Entry(Address, u32),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Attestation {
+    pub recipient: Address,
+    pub program_id: String,
+    pub amount: i128,
+    pub completed_at: u64,
+    pub work_hash: BytesN<32>,
+}
+
+/// Emitted per mint. Like deposit receipts it carries no `sequence`; the
+/// payout event it accompanies holds the sequence number.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestationMintedEvent {
+    pub version: u32,
+    pub recipient: Address,
+    pub index: u32,
+    pub program_id: String,
+    pub amount: i128,
+    pub work_hash: BytesN<32>,
+}
+
+pub fn count(env: &Env, recipient: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&AttestationKey::Count(recipient.clone()))
+        .unwrap_or(0)
+}
+
+/// Append an attestation for `recipient`.
+pub fn mint(
+    env: &Env,
+    recipient: &Address,
+    program_id: &String,
+    amount: i128,
+    work_hash: Option<BytesN<32>>,
+) {
+    let work_hash = work_hash.unwrap_or_else(|| BytesN::from_array(env, &[0u8; 32]));
+    let index = count(env, recipient);
+    let attestation = Attestation {
+        recipient: recipient.clone(),
+        program_id: program_id.clone(),
+        amount,
+        completed_at: env.ledger().timestamp(),
+        work_hash: work_hash.clone(),
+    };
+    env.storage().persistent().set(
+        &AttestationKey::Entry(recipient.clone(), index),
+        &attestation,
+    );
+    env.storage()
+        .persistent()
+        .set(&AttestationKey::Count(recipient.clone()), &(index + 1));
+
+    env.events().publish(
+        (LogLevel::Info, ATTESTATION_MINTED),
+        AttestationMintedEvent {
+            version: EVENT_VERSION_V2,
+            recipient: recipient.clone(),
+            index,
+            program_id: program_id.clone(),
+            amount,
+            work_hash,
+        },
+    );
+}
+
+/// Returns up to `limit` of `recipient`'s attestations starting at `offset`,
+/// oldest first. `limit` is capped at [`MAX_ATTESTATION_PAGE_SIZE`].
+pub fn get_page(env: &Env, recipient: &Address, offset: u32, limit: u32) -> Vec<Attestation> {
+    let mut page = Vec::new(env);
+    let end = offset
+        .saturating_add(limit.min(MAX_ATTESTATION_PAGE_SIZE))
+        .min(count(env, recipient));
+
+    let mut index = offset;
+    while index < end {
+        if let Some(attestation) = env
+            .storage()
+            .persistent()
+            .get(&AttestationKey::Entry(recipient.clone(), index))
+        {
+            page.push_back(attestation);
+        }
+        index += 1;
+    }
+    page
+}
@@ -0,0 +1,138 @@
+//! # Admin Audit Trail
+//!
+//! Append-only, on-chain record of every privileged call (pauses, config
+//! changes, admin transfers, emergency withdraws). Events expire from RPC
+//! history after a retention window; entries written here stay queryable for
+//! as long as the contract's persistent storage is kept alive.
+//!
+//! Each entry stores the authorizing caller, an action symbol, the ledger
+//! timestamp and a SHA-256 hash of the XDR-encoded call arguments, so an
+//! auditor holding the original arguments can prove what was submitted
+//! without the log having to store them in full.
+//!
+//! Addresses granted the read-only auditor role can additionally export the
+//! trail together with the circuit breaker's error log, which has no public
+//! view. The role carries no fund-moving or configuration permission.
+
+use crate::error_recovery::{self, ErrorEntry};
+use soroban_sdk::{contracttype, xdr::ToXdr, Address, BytesN, Env, IntoVal, Symbol, Val, Vec};
+
+/// Maximum number of entries returned by a single `get_entries` call.
+pub const MAX_AUDIT_PAGE_SIZE: u32 = 100;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AuditKey {
+    /// Total number of entries ever appended.
+    Count,
+    /// Entry by zero-based index.
+    Entry(u64),
+    /// Address holding the read-only auditor role.
+    Auditor(Address),
+}
+
+/// A single privileged action recorded in the audit trail.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuditEntry {
+    pub index: u64,
+    pub caller: Address,
+    pub action: Symbol,
+    pub timestamp: u64,
+    pub args_hash: BytesN<32>,
+}
+
+/// A page of the audit trail and the full error log, as exported to
+/// auditors.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuditExport {
+    pub entries: Vec<AuditEntry>,
+    pub entry_count: u64,
+    pub error_log: Vec<ErrorEntry>,
+}
+
+/// Returns the number of entries recorded so far.
+pub fn entry_count(env: &Env) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&AuditKey::Count)
+        .unwrap_or(0)
+}
+
+/// Appends an entry for `action` performed by `caller` with the given `args`.
+///
+/// `args` is usually a tuple of the entrypoint's parameters; it is
+/// XDR-encoded and hashed, never stored verbatim.
+pub fn record<A>(env: &Env, caller: &Address, action: Symbol, args: A)
+where
+    A: IntoVal<Env, Val>,
+{
+    let args_val: Val = args.into_val(env);
+    let args_hash: BytesN<32> = env.crypto().sha256(&args_val.to_xdr(env)).into();
+
+    let index = entry_count(env);
+    let entry = AuditEntry {
+        index,
+        caller: caller.clone(),
+        action,
+        timestamp: env.ledger().timestamp(),
+        args_hash,
+    };
+
+    env.storage()
+        .persistent()
+        .set(&AuditKey::Entry(index), &entry);
+    env.storage()
+        .persistent()
+        .set(&AuditKey::Count, &(index + 1));
+}
+
+/// Returns up to `limit` entries starting at `offset`, oldest first.
+///
+/// `limit` is capped at [`MAX_AUDIT_PAGE_SIZE`].
+pub fn get_entries(env: &Env, offset: u64, limit: u32) -> Vec<AuditEntry> {
+    let mut entries = Vec::new(env);
+    let count = entry_count(env);
+    let limit = limit.min(MAX_AUDIT_PAGE_SIZE) as u64;
+    let end = offset.saturating_add(limit).min(count);
+
+    let mut index = offset;
+    while index < end {
+        if let Some(entry) = env.storage().persistent().get(&AuditKey::Entry(index)) {
+            entries.push_back(entry);
+        }
+        index += 1;
+    }
+    entries
+}
+
+/// Returns `true` if `addr` holds the read-only auditor role.
+pub fn is_auditor(env: &Env, addr: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&AuditKey::Auditor(addr.clone()))
+        .unwrap_or(false)
+}
+
+pub fn set_auditor(env: &Env, auditor: &Address, enabled: bool) {
+    let key = AuditKey::Auditor(auditor.clone());
+    if enabled {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Up to `limit` entries from `offset` plus the error log, for `auditor`.
+pub fn export(env: &Env, auditor: &Address, offset: u64, limit: u32) -> AuditExport {
+    auditor.require_auth();
+    if !is_auditor(env, auditor) {
+        panic!("Unauthorized: not an auditor");
+    }
+    AuditExport {
+        entries: get_entries(env, offset, limit),
+        entry_count: entry_count(env),
+        error_log: error_recovery::get_error_log(env),
+    }
+}
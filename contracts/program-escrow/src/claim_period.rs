@@ -17,7 +17,10 @@
 //
 // ============================================================
 
-use crate::{DataKey, ProgramData, PROGRAM_DATA};
+use crate::{
+    adjust_reserved_obligations, next_event_sequence, DataKey, ProgramData, ProgramEscrowContract,
+    PROGRAM_DATA,
+};
 use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol};
 
 /// The status of a pending claim record.
@@ -112,6 +115,7 @@ pub fn create_pending_claim(
     // Reserve the funds (deduct from remaining balance)
     program.remaining_balance -= amount;
     save_program(env, &program);
+    adjust_reserved_obligations(env, amount);
 
     let claim_id = next_claim_id(env);
     let now = env.ledger().timestamp();
@@ -138,6 +142,7 @@ pub fn create_pending_claim(
             recipient.clone(),
             amount,
             claim_deadline,
+            next_event_sequence(env),
         ),
     );
 
@@ -151,6 +156,10 @@ pub fn create_pending_claim(
 pub fn execute_claim(env: &Env, program_id: &String, claim_id: u64, caller: &Address) {
     caller.require_auth();
 
+    if ProgramEscrowContract::check_paused(env, symbol_short!("release")) {
+        panic!("Funds Paused");
+    }
+
     let key = claim_key(program_id, claim_id);
     let mut record: ClaimRecord = env
         .storage()
@@ -175,6 +184,8 @@ pub fn execute_claim(env: &Env, program_id: &String, claim_id: u64, caller: &Add
 
     // transfer funds to recipient
     let program = get_program(env);
+    adjust_reserved_obligations(env, -record.amount);
+    crate::yield_adapter::ensure_liquid(env, &program.token_address, record.amount);
     let token_client = soroban_sdk::token::Client::new(env, &program.token_address);
     token_client.transfer(
         &env.current_contract_address(),
@@ -193,6 +204,7 @@ pub fn execute_claim(env: &Env, program_id: &String, claim_id: u64, caller: &Add
             claim_id,
             record.recipient.clone(),
             record.amount,
+            next_event_sequence(env),
         ),
     );
 }
@@ -226,6 +238,7 @@ pub fn cancel_claim(env: &Env, program_id: &String, claim_id: u64, admin: &Addre
     let mut program = get_program(env);
     program.remaining_balance += record.amount;
     save_program(env, &program);
+    adjust_reserved_obligations(env, -record.amount);
 
     // mark claim as cancelled
     record.status = ClaimStatus::Cancelled;
@@ -238,6 +251,7 @@ pub fn cancel_claim(env: &Env, program_id: &String, claim_id: u64, admin: &Addre
             claim_id,
             record.recipient.clone(),
             record.amount,
+            next_event_sequence(env),
         ),
     );
 }
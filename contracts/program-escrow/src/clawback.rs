@@ -0,0 +1,168 @@
+//! # Fraud Clawback
+//!
+//! Last-resort recovery of a payout later found to be fraudulent. A payout is
+//! identified by its index in the program's `payout_history`.
+//!
+//! 1. The compliance officer (a role appointed by the admin) calls
+//!    `flag_payout_fraud`, which starts a timelock of `clawback_delay`
+//!    seconds. The officer may cancel a flag before it is executed.
+//! 2. Once the timelock has passed, the officer calls `execute_clawback`.
+//!    This uses Stellar asset clawback to remove up to the flagged amount
+//!    from the recipient and mints it back to the escrow, crediting the
+//!    program's `remaining_balance`.
+//!
+//! Clawback only works when the settlement token is a Stellar asset whose
+//! issuer enabled clawback, and the escrow contract has been made the asset
+//! contract's admin. Otherwise `execute_clawback` panics and nothing moves.
+//! Every step emits an event and is recorded in the audit trail.
+
+use crate::events_log::LogLevel;
+use crate::{next_event_sequence, PayoutRecord, EVENT_VERSION_V2};
+use soroban_sdk::{contracttype, symbol_short, token, Address, Env, String, Symbol};
+
+const FRAUD_FLAGGED: Symbol = symbol_short!("ClwFlag");
+const FRAUD_FLAG_CANCELLED: Symbol = symbol_short!("ClwCncl");
+const CLAWBACK_EXECUTED: Symbol = symbol_short!("ClwExec");
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ClawbackKey {
+    /// Compliance officer allowed to flag and claw back payouts.
+    Officer,
+    /// Timelock between flagging and clawback, in seconds.
+    Delay,
+    /// payout_id -> FraudFlag
+    Flag(u32),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FraudFlag {
+    pub payout_id: u32,
+    pub recipient: Address,
+    pub amount: i128,
+    pub reason: String,
+    pub flagged_at: u64,
+    pub executable_at: u64,
+    /// Amount actually recovered; `None` until executed.
+    pub clawed_back: Option<i128>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClawbackEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub officer: Address,
+    pub flag: FraudFlag,
+}
+
+pub fn set_officer(env: &Env, officer: &Address) {
+    env.storage().instance().set(&ClawbackKey::Officer, officer);
+}
+
+pub fn get_officer(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&ClawbackKey::Officer)
+}
+
+pub fn set_delay(env: &Env, delay_secs: u64) {
+    env.storage()
+        .instance()
+        .set(&ClawbackKey::Delay, &delay_secs);
+}
+
+pub fn get_delay(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&ClawbackKey::Delay)
+        .unwrap_or(0)
+}
+
+pub fn get_flag(env: &Env, payout_id: u32) -> Option<FraudFlag> {
+    env.storage()
+        .persistent()
+        .get(&ClawbackKey::Flag(payout_id))
+}
+
+/// Require the compliance officer's signature and return it.
+pub fn require_officer(env: &Env) -> Address {
+    let officer = get_officer(env).unwrap_or_else(|| panic!("Compliance officer not set"));
+    officer.require_auth();
+    officer
+}
+
+pub fn flag(env: &Env, officer: &Address, payout: PayoutRecord, payout_id: u32, reason: String) {
+    if get_flag(env, payout_id).is_some() {
+        panic!("Payout already flagged");
+    }
+    let now = env.ledger().timestamp();
+    let flag = FraudFlag {
+        payout_id,
+        recipient: payout.recipient,
+        amount: payout.amount,
+        reason,
+        flagged_at: now,
+        executable_at: now + get_delay(env),
+        clawed_back: None,
+    };
+    env.storage()
+        .persistent()
+        .set(&ClawbackKey::Flag(payout_id), &flag);
+    publish(env, FRAUD_FLAGGED, officer, flag);
+}
+
+pub fn cancel(env: &Env, officer: &Address, payout_id: u32) {
+    let flag = pending_flag(env, payout_id);
+    env.storage()
+        .persistent()
+        .remove(&ClawbackKey::Flag(payout_id));
+    publish(env, FRAUD_FLAG_CANCELLED, officer, flag);
+}
+
+/// Claw back the flagged payout into the escrow and return the amount
+/// recovered (capped at the recipient's current balance).
+pub fn execute(env: &Env, officer: &Address, token: &Address, payout_id: u32) -> i128 {
+    let mut flag = pending_flag(env, payout_id);
+    if env.ledger().timestamp() < flag.executable_at {
+        panic!("Clawback timelock active");
+    }
+
+    let amount = flag
+        .amount
+        .min(token::Client::new(env, token).balance(&flag.recipient));
+    if amount <= 0 {
+        panic!("Nothing to claw back");
+    }
+    let asset = token::StellarAssetClient::new(env, token);
+    if asset.try_clawback(&flag.recipient, &amount).is_err() {
+        panic!("Token does not support clawback");
+    }
+    asset.mint(&env.current_contract_address(), &amount);
+
+    flag.clawed_back = Some(amount);
+    env.storage()
+        .persistent()
+        .set(&ClawbackKey::Flag(payout_id), &flag);
+    publish(env, CLAWBACK_EXECUTED, officer, flag);
+    amount
+}
+
+fn pending_flag(env: &Env, payout_id: u32) -> FraudFlag {
+    let flag = get_flag(env, payout_id).unwrap_or_else(|| panic!("Payout not flagged"));
+    if flag.clawed_back.is_some() {
+        panic!("Clawback already executed");
+    }
+    flag
+}
+
+fn publish(env: &Env, topic: Symbol, officer: &Address, flag: FraudFlag) {
+    env.events().publish(
+        (LogLevel::Info, topic),
+        ClawbackEvent {
+            version: EVENT_VERSION_V2,
+            sequence: next_event_sequence(env),
+            officer: officer.clone(),
+            flag,
+        },
+    );
+}
@@ -0,0 +1,290 @@
+//! # Commit-Reveal Allocation Rounds
+//!
+//! Fair submission flow for competitive program funding. Applicants first
+//! submit `sha256(proposal || salt)` during the commit window, then disclose
+//! `proposal` and `salt` during the reveal window. Nobody can read or copy a
+//! competitor's proposal before commitments close, and a reveal that does not
+//! hash to the stored commitment is rejected.
+//!
+//! ```text
+//! opened_at ── commit window ──▶ commit_deadline ── reveal window ──▶ reveal_deadline
+//! ```
+//!
+//! Each program has at most one round; the program's authorized payout key
+//! opens it and a new round can only be opened once the previous one's reveal
+//! window has closed.
+
+use crate::events_log::LogLevel;
+use crate::{load_program_data, next_event_sequence, EVENT_VERSION_V2};
+use soroban_sdk::{contracttype, symbol_short, Address, Bytes, BytesN, Env, String, Symbol, Vec};
+
+const ROUND_OPENED: Symbol = symbol_short!("CrRound");
+const PROPOSAL_COMMITTED: Symbol = symbol_short!("CrCommit");
+const PROPOSAL_REVEALED: Symbol = symbol_short!("CrReveal");
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CommitRevealKey {
+    /// program_id -> AllocationRound
+    Round(String),
+    /// (program_id, round_id, applicant) -> ProposalCommitment
+    Commitment(String, u32, Address),
+    /// (program_id, round_id) -> Vec<Address> of applicants that committed
+    Applicants(String, u32),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AllocationRound {
+    pub program_id: String,
+    pub round_id: u32,
+    pub opened_at: u64,
+    pub commit_deadline: u64,
+    pub reveal_deadline: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalCommitment {
+    pub applicant: Address,
+    pub commitment: BytesN<32>,
+    pub committed_at: u64,
+    /// Set once a matching reveal has been accepted.
+    pub proposal: Option<Bytes>,
+    pub revealed_at: Option<u64>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoundOpenedEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub program_id: String,
+    pub round_id: u32,
+    pub opened_at: u64,
+    pub commit_deadline: u64,
+    pub reveal_deadline: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub program_id: String,
+    pub round_id: u32,
+    pub applicant: Address,
+    pub commitment: BytesN<32>,
+    pub timestamp: u64,
+}
+
+/// Commitment hash an applicant must submit for `proposal` and `salt`.
+pub fn compute_commitment(env: &Env, proposal: &Bytes, salt: &BytesN<32>) -> BytesN<32> {
+    let mut data = proposal.clone();
+    data.append(&Bytes::from(salt.clone()));
+    env.crypto().sha256(&data).into()
+}
+
+pub fn get_round(env: &Env, program_id: &String) -> Option<AllocationRound> {
+    env.storage()
+        .persistent()
+        .get(&CommitRevealKey::Round(program_id.clone()))
+}
+
+fn require_round(env: &Env, program_id: &String) -> AllocationRound {
+    get_round(env, program_id).unwrap_or_else(|| panic!("No allocation round"))
+}
+
+pub fn get_commitment(
+    env: &Env,
+    program_id: &String,
+    applicant: &Address,
+) -> Option<ProposalCommitment> {
+    let round = get_round(env, program_id)?;
+    env.storage().persistent().get(&CommitRevealKey::Commitment(
+        program_id.clone(),
+        round.round_id,
+        applicant.clone(),
+    ))
+}
+
+/// Applicants of the current round whose reveal was accepted, in commit order.
+pub fn get_revealed_applicants(env: &Env, program_id: &String) -> Vec<Address> {
+    let mut revealed = Vec::new(env);
+    let round = match get_round(env, program_id) {
+        Some(round) => round,
+        None => return revealed,
+    };
+    let applicants: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&CommitRevealKey::Applicants(
+            program_id.clone(),
+            round.round_id,
+        ))
+        .unwrap_or_else(|| Vec::new(env));
+    for applicant in applicants.iter() {
+        if let Some(entry) = get_commitment(env, program_id, &applicant) {
+            if entry.proposal.is_some() {
+                revealed.push_back(applicant);
+            }
+        }
+    }
+    revealed
+}
+
+/// Open a commit-reveal round for `program_id` (authorized payout key only).
+pub fn open_round(
+    env: &Env,
+    program_id: &String,
+    commit_deadline: u64,
+    reveal_deadline: u64,
+) -> AllocationRound {
+    let program_data = load_program_data(env, program_id);
+    program_data.authorized_payout_key.require_auth();
+
+    let now = env.ledger().timestamp();
+    if commit_deadline <= now || reveal_deadline <= commit_deadline {
+        panic!("Invalid round windows");
+    }
+
+    let round_id = match get_round(env, program_id) {
+        Some(previous) => {
+            if now < previous.reveal_deadline {
+                panic!("Allocation round still active");
+            }
+            previous.round_id + 1
+        }
+        None => 1,
+    };
+
+    let round = AllocationRound {
+        program_id: program_id.clone(),
+        round_id,
+        opened_at: now,
+        commit_deadline,
+        reveal_deadline,
+    };
+    env.storage()
+        .persistent()
+        .set(&CommitRevealKey::Round(program_id.clone()), &round);
+
+    env.events().publish(
+        (LogLevel::Info, ROUND_OPENED, program_id.clone()),
+        RoundOpenedEvent {
+            version: EVENT_VERSION_V2,
+            sequence: next_event_sequence(env),
+            program_id: program_id.clone(),
+            round_id: round.round_id,
+            opened_at: round.opened_at,
+            commit_deadline: round.commit_deadline,
+            reveal_deadline: round.reveal_deadline,
+        },
+    );
+    round
+}
+
+/// Record `applicant`'s commitment during the commit window.
+pub fn submit_commitment(
+    env: &Env,
+    program_id: &String,
+    applicant: &Address,
+    commitment: BytesN<32>,
+) {
+    applicant.require_auth();
+
+    let round = require_round(env, program_id);
+    let now = env.ledger().timestamp();
+    if now >= round.commit_deadline {
+        panic!("Commit window closed");
+    }
+
+    let key = CommitRevealKey::Commitment(program_id.clone(), round.round_id, applicant.clone());
+    if env.storage().persistent().has(&key) {
+        panic!("Already committed");
+    }
+
+    env.storage().persistent().set(
+        &key,
+        &ProposalCommitment {
+            applicant: applicant.clone(),
+            commitment: commitment.clone(),
+            committed_at: now,
+            proposal: None,
+            revealed_at: None,
+        },
+    );
+
+    let applicants_key = CommitRevealKey::Applicants(program_id.clone(), round.round_id);
+    let mut applicants: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&applicants_key)
+        .unwrap_or_else(|| Vec::new(env));
+    applicants.push_back(applicant.clone());
+    env.storage().persistent().set(&applicants_key, &applicants);
+
+    env.events().publish(
+        (LogLevel::Info, PROPOSAL_COMMITTED, program_id.clone()),
+        ProposalEvent {
+            version: EVENT_VERSION_V2,
+            sequence: next_event_sequence(env),
+            program_id: program_id.clone(),
+            round_id: round.round_id,
+            applicant: applicant.clone(),
+            commitment,
+            timestamp: now,
+        },
+    );
+}
+
+/// Disclose a committed proposal during the reveal window.
+pub fn reveal_proposal(
+    env: &Env,
+    program_id: &String,
+    applicant: &Address,
+    proposal: Bytes,
+    salt: BytesN<32>,
+) -> ProposalCommitment {
+    applicant.require_auth();
+
+    let round = require_round(env, program_id);
+    let now = env.ledger().timestamp();
+    if now < round.commit_deadline {
+        panic!("Reveal window not open");
+    }
+    if now >= round.reveal_deadline {
+        panic!("Reveal window closed");
+    }
+
+    let key = CommitRevealKey::Commitment(program_id.clone(), round.round_id, applicant.clone());
+    let mut entry: ProposalCommitment = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| panic!("No commitment"));
+    if entry.proposal.is_some() {
+        panic!("Already revealed");
+    }
+    if compute_commitment(env, &proposal, &salt) != entry.commitment {
+        panic!("Reveal does not match commitment");
+    }
+
+    entry.proposal = Some(proposal);
+    entry.revealed_at = Some(now);
+    env.storage().persistent().set(&key, &entry);
+
+    env.events().publish(
+        (LogLevel::Info, PROPOSAL_REVEALED, program_id.clone()),
+        ProposalEvent {
+            version: EVENT_VERSION_V2,
+            sequence: next_event_sequence(env),
+            program_id: program_id.clone(),
+            round_id: round.round_id,
+            applicant: applicant.clone(),
+            commitment: entry.commitment.clone(),
+            timestamp: now,
+        },
+    );
+    entry
+}
@@ -21,6 +21,7 @@
 // All circuit breaker state is stored in persistent storage keyed by
 // `CircuitBreakerKey::*`.
 
+use crate::events_log::LogLevel;
 use soroban_sdk::{contracttype, symbol_short, Address, Env, String};
 
 // ─────────────────────────────────────────────────────────
@@ -141,7 +142,11 @@ pub fn set_config(env: &Env, config: CircuitBreakerConfig) {
 
     // Emit audit event for config change
     env.events().publish(
-        (symbol_short!("circuit"), symbol_short!("cb_cfg")),
+        (
+            LogLevel::Info,
+            symbol_short!("circuit"),
+            symbol_short!("cb_cfg"),
+        ),
         (
             prev_config.failure_threshold,
             config.failure_threshold,
@@ -205,7 +210,12 @@ pub fn get_status(env: &Env) -> CircuitBreakerStatus {
 pub fn check_and_allow(env: &Env) -> Result<(), u32> {
     match get_state(env) {
         CircuitState::Open => {
-            emit_circuit_event(env, symbol_short!("cb_reject"), get_failure_count(env));
+            emit_circuit_event(
+                env,
+                LogLevel::Warn,
+                symbol_short!("cb_reject"),
+                get_failure_count(env),
+            );
             Err(ERR_CIRCUIT_OPEN)
         }
         CircuitState::Closed | CircuitState::HalfOpen => Ok(()),
@@ -323,6 +333,7 @@ pub fn record_failure(
 
     emit_circuit_event_detailed(
         env,
+        LogLevel::Debug,
         symbol_short!("cb_fail"),
         failures,
         Some(operation),
@@ -354,7 +365,11 @@ fn open_circuit_internal(env: &Env, reason: soroban_sdk::Symbol) {
         .set(&CircuitBreakerKey::SuccessCount, &0u32);
 
     env.events().publish(
-        (symbol_short!("circuit"), symbol_short!("cb_open")),
+        (
+            LogLevel::Error,
+            symbol_short!("circuit"),
+            symbol_short!("cb_open"),
+        ),
         (get_failure_count(env), reason, now),
     );
 }
@@ -368,7 +383,12 @@ pub fn half_open_circuit(env: &Env) {
         .persistent()
         .set(&CircuitBreakerKey::SuccessCount, &0u32);
 
-    emit_circuit_event(env, symbol_short!("cb_half"), get_failure_count(env));
+    emit_circuit_event(
+        env,
+        LogLevel::Info,
+        symbol_short!("cb_half"),
+        get_failure_count(env),
+    );
 }
 
 /// Transitions the circuit to **Closed** state and resets all counters.
@@ -389,7 +409,11 @@ pub fn close_circuit(env: &Env) {
         .set(&CircuitBreakerKey::OpenedAt, &0u64);
 
     env.events().publish(
-        (symbol_short!("circuit"), symbol_short!("cb_close")),
+        (
+            LogLevel::Info,
+            symbol_short!("circuit"),
+            symbol_short!("cb_close"),
+        ),
         (env.ledger().timestamp(),),
     );
 }
@@ -413,7 +437,11 @@ pub fn reset_circuit_breaker(env: &Env, admin: &Address) {
 
     // Emit audit event for manual reset
     env.events().publish(
-        (symbol_short!("circuit"), symbol_short!("cb_reset")),
+        (
+            LogLevel::Warn,
+            symbol_short!("circuit"),
+            symbol_short!("cb_reset"),
+        ),
         (admin.clone(), state.clone(), now),
     );
 
@@ -443,7 +471,11 @@ pub fn set_circuit_admin(env: &Env, new_admin: Address, caller: Option<Address>)
 
     // Emit audit event for admin change
     env.events().publish(
-        (symbol_short!("circuit"), symbol_short!("cb_adm")),
+        (
+            LogLevel::Info,
+            symbol_short!("circuit"),
+            symbol_short!("cb_adm"),
+        ),
         (existing, new_admin, env.ledger().timestamp()),
     );
 }
@@ -617,15 +649,16 @@ where
 // Internal helpers
 // ─────────────────────────────────────────────────────────
 
-fn emit_circuit_event(env: &Env, event_type: soroban_sdk::Symbol, value: u32) {
+fn emit_circuit_event(env: &Env, level: LogLevel, event_type: soroban_sdk::Symbol, value: u32) {
     env.events().publish(
-        (symbol_short!("circuit"), event_type),
+        (level, symbol_short!("circuit"), event_type),
         (value, env.ledger().timestamp()),
     );
 }
 
 fn emit_circuit_event_detailed(
     env: &Env,
+    level: LogLevel,
     event_type: soroban_sdk::Symbol,
     value: u32,
     operation: Option<soroban_sdk::Symbol>,
@@ -633,7 +666,7 @@ fn emit_circuit_event_detailed(
     error_code: Option<u32>,
 ) {
     env.events().publish(
-        (symbol_short!("circuit"), event_type),
+        (level, symbol_short!("circuit"), event_type),
         (
             value,
             operation,
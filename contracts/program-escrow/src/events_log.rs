@@ -0,0 +1,60 @@
+//! # Event Logging Levels — Program Escrow Contract
+//!
+//! Ports the bounty escrow's `LogLevel`-as-topic[0] design so that every
+//! registration, payout, threshold-breach and circuit-breaker event carries a
+//! severity that indexers can filter on without deserialising the payload.
+//!
+//! ### Level semantics
+//!
+//! | Level   | u32 | When to use |
+//! |---------|-----|-------------|
+//! | `Debug` |  0  | High-frequency operational detail (circuit failure counters) |
+//! | `Info`  |  1  | Normal state transitions (init, register, lock, payout, release) |
+//! | `Warn`  |  2  | Unexpected-but-handled situations (threshold breach, rejected op) |
+//! | `Error` |  3  | Protective halt — the circuit breaker has opened |
+//!
+//! ### Topic layout
+//!
+//! ```text
+//! topic[0]  LogLevel  (always)
+//! topic[1]  Symbol    (event name or category, e.g. "Payout", "circuit")
+//! topic[2]  ...       (event-specific qualifier where applicable)
+//! ```
+//!
+//! ### Mapping of events to levels
+//!
+//! | Event                         | Level |
+//! |-------------------------------|-------|
+//! | `PrgInit`, `ProgRgd`          | Info  |
+//! | `FndsLock`                    | Info  |
+//! | `BatchPay`, `Payout`          | Info  |
+//! | `RelSched`, `SchRel`          | Info  |
+//! | `th_breach`                   | Warn  |
+//! | `circuit` / `cb_fail`         | Debug |
+//! | `circuit` / `cb_cfg`, `cb_adm`, `cb_half`, `cb_close` | Info |
+//! | `circuit` / `cb_reject`, `cb_reset` | Warn |
+//! | `circuit` / `cb_open`         | Error |
+
+// `LogLevel::Error` collides with the `Error` associated type in the
+// `TryFrom` impls generated by `#[contracttype]` (rust-lang/rust#57644).
+#![allow(ambiguous_associated_items)]
+
+use soroban_sdk::contracttype;
+
+/// Logging level attached as the first topic to leveled contract events.
+///
+/// Numeric values match the bounty escrow's `LogLevel` so a single indexer
+/// filter works across both contracts.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum LogLevel {
+    /// High-frequency detail useful during development or deep debugging.
+    Debug = 0,
+    /// Normal operational events — the default for state transitions.
+    Info = 1,
+    /// Something noteworthy that didn't block execution.
+    Warn = 2,
+    /// Severe condition that halts protected operations.
+    Error = 3,
+}
@@ -0,0 +1,80 @@
+//! # Global Halt
+//!
+//! Links the escrow to a grainlify-core contract whose security council can
+//! halt every registered escrow at once. When a core contract is configured,
+//! `check_paused` asks it `is_globally_halted()` before any fund-moving
+//! operation and treats a halt as a pause of every operation.
+//!
+//! The check fails closed: if the core contract cannot be reached or returns
+//! something other than a bool, the escrow behaves as halted. The admin can
+//! clear the link with `set_core_contract(None)` to recover from a broken
+//! core deployment.
+//!
+//! The same link gates capabilities behind core's per-network feature flags
+//! (`is_feature_enabled`): oracle-conditioned releases require the
+//! [`ORACLE_FEATURE`] flag. Without a core contract nothing is gated; with
+//! one, an unreachable core counts as the flag being off.
+
+use soroban_sdk::{contracttype, symbol_short, vec, Address, Env, IntoVal, Symbol, Vec};
+
+/// Core feature flag gating oracle-conditioned releases.
+pub const ORACLE_FEATURE: Symbol = symbol_short!("oracle");
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GlobalHaltKey {
+    /// grainlify-core contract consulted for the global halt
+    CoreContract,
+}
+
+pub fn set_core_contract(env: &Env, core: Option<Address>) {
+    match core {
+        Some(core) => env
+            .storage()
+            .instance()
+            .set(&GlobalHaltKey::CoreContract, &core),
+        None => env
+            .storage()
+            .instance()
+            .remove(&GlobalHaltKey::CoreContract),
+    }
+}
+
+pub fn get_core_contract(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&GlobalHaltKey::CoreContract)
+}
+
+/// True when the configured core contract reports a global halt.
+pub fn is_halted(env: &Env) -> bool {
+    let core = match get_core_contract(env) {
+        Some(core) => core,
+        None => return false,
+    };
+
+    match env.try_invoke_contract::<bool, soroban_sdk::Error>(
+        &core,
+        &Symbol::new(env, "is_globally_halted"),
+        Vec::new(env),
+    ) {
+        Ok(Ok(halted)) => halted,
+        _ => true,
+    }
+}
+
+/// True when the configured core contract has `feature` enabled, or when no
+/// core contract is configured.
+pub fn is_feature_enabled(env: &Env, feature: Symbol) -> bool {
+    let core = match get_core_contract(env) {
+        Some(core) => core,
+        None => return true,
+    };
+
+    match env.try_invoke_contract::<bool, soroban_sdk::Error>(
+        &core,
+        &Symbol::new(env, "is_feature_enabled"),
+        vec![env, feature.into_val(env)],
+    ) {
+        Ok(Ok(enabled)) => enabled,
+        _ => false,
+    }
+}
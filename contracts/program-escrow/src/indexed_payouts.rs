@@ -0,0 +1,177 @@
+//! # Inflation-Indexed Release Schedules
+//!
+//! Lets a release schedule be denominated in a reference unit (e.g. a
+//! basket-price index) instead of tokens. The schedule's stored `amount` is
+//! then a reference amount; at release it is converted with
+//!
+//! ```text
+//! tokens = amount * price / base_price
+//! ```
+//!
+//! where `price` is read from the program's registered index feed by invoking
+//! `get_price(feed) -> (i128, u64)` (value, last-update timestamp) on an
+//! allowlisted oracle, and `base_price` is the feed's value when the reference
+//! unit was fixed.
+//!
+//! A reading is used only when the oracle is still allowlisted, the call
+//! succeeds, the price is positive, it is at most `max_staleness_secs` old,
+//! and it moved at most `max_deviation_bps` from the last accepted reading
+//! (initially `base_price`). Otherwise the release falls back to paying the
+//! nominal amount, so an unhealthy feed can never block or inflate payouts.
+
+use crate::events_log::LogLevel;
+use crate::{next_event_sequence, oracle_conditions, EVENT_VERSION_V2};
+use soroban_sdk::{contracttype, symbol_short, vec, Address, Env, IntoVal, Symbol};
+
+const INDEX_FEED_SET: Symbol = symbol_short!("IdxFeed");
+const INDEXED_RELEASE: Symbol = symbol_short!("IdxRel");
+
+const BASIS_POINTS: i128 = 10_000;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum IndexKey {
+    /// -> IndexFeed
+    Feed,
+    /// Last reading accepted by the health checks.
+    LastPrice,
+    /// schedule_id -> bool
+    Indexed(u64),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IndexFeed {
+    pub oracle: Address,
+    pub feed: Symbol,
+    /// Feed value at which one reference unit equals one token unit.
+    pub base_price: i128,
+    pub max_staleness_secs: u64,
+    pub max_deviation_bps: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IndexFeedEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub feed: IndexFeed,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IndexedReleaseEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub schedule_id: u64,
+    pub nominal_amount: i128,
+    pub amount: i128,
+    /// Reading used for the conversion; `None` when the release fell back.
+    pub price: Option<i128>,
+}
+
+pub fn get_feed(env: &Env) -> Option<IndexFeed> {
+    env.storage().persistent().get(&IndexKey::Feed)
+}
+
+/// Register the index feed. The oracle must be allowlisted; replacing the
+/// feed resets the deviation baseline to `base_price`.
+pub fn set_feed(env: &Env, feed: IndexFeed) {
+    if !oracle_conditions::is_allowed(env, &feed.oracle) {
+        panic!("Oracle not allowlisted");
+    }
+    if feed.base_price <= 0 {
+        panic!("Base price must be positive");
+    }
+    env.storage().persistent().set(&IndexKey::Feed, &feed);
+    env.storage().persistent().remove(&IndexKey::LastPrice);
+
+    env.events().publish(
+        (LogLevel::Info, INDEX_FEED_SET),
+        IndexFeedEvent {
+            version: EVENT_VERSION_V2,
+            sequence: next_event_sequence(env),
+            feed,
+        },
+    );
+}
+
+pub fn is_indexed(env: &Env, schedule_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .get(&IndexKey::Indexed(schedule_id))
+        .unwrap_or(false)
+}
+
+pub fn set_indexed(env: &Env, schedule_id: u64, indexed: bool) {
+    let key = IndexKey::Indexed(schedule_id);
+    if indexed {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// A reading that passes every health check, or `None`.
+fn healthy_price(env: &Env, feed: &IndexFeed) -> Option<i128> {
+    if !oracle_conditions::is_allowed(env, &feed.oracle) {
+        return None;
+    }
+    let (price, updated_at) = match env.try_invoke_contract::<(i128, u64), soroban_sdk::Error>(
+        &feed.oracle,
+        &Symbol::new(env, "get_price"),
+        vec![env, feed.feed.into_val(env)],
+    ) {
+        Ok(Ok(reading)) => reading,
+        _ => return None,
+    };
+
+    let now = env.ledger().timestamp();
+    if price <= 0 || updated_at > now || now - updated_at > feed.max_staleness_secs {
+        return None;
+    }
+    let last: i128 = env
+        .storage()
+        .persistent()
+        .get(&IndexKey::LastPrice)
+        .unwrap_or(feed.base_price);
+    let deviation_bps = (price - last).abs().checked_mul(BASIS_POINTS)? / last;
+    if deviation_bps > feed.max_deviation_bps as i128 {
+        return None;
+    }
+    Some(price)
+}
+
+/// Token amount to release for `schedule_id` whose stored amount is
+/// `nominal`. Non-indexed schedules pass through unchanged.
+pub fn resolve_amount(env: &Env, schedule_id: u64, nominal: i128) -> i128 {
+    if !is_indexed(env, schedule_id) {
+        return nominal;
+    }
+
+    let converted = get_feed(env).and_then(|feed| {
+        let price = healthy_price(env, &feed)?;
+        let amount = nominal.checked_mul(price)? / feed.base_price;
+        Some((price, amount))
+    });
+    let (price, amount) = match converted {
+        Some((price, amount)) => {
+            env.storage().persistent().set(&IndexKey::LastPrice, &price);
+            (Some(price), amount)
+        }
+        None => (None, nominal),
+    };
+
+    env.events().publish(
+        (LogLevel::Info, INDEXED_RELEASE),
+        IndexedReleaseEvent {
+            version: EVENT_VERSION_V2,
+            sequence: next_event_sequence(env),
+            schedule_id,
+            nominal_amount: nominal,
+            amount,
+            price,
+        },
+    );
+    amount
+}
@@ -141,16 +141,21 @@
 
 #![no_std]
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, token, vec, Address, Env,
-    String, Symbol, Vec,
+    contract, contracterror, contractimpl, contracttype, symbol_short, token, vec, Address, BytesN,
+    Env, String, Symbol, Vec,
 };
 
+use events_log::LogLevel;
+
 // Event types
 const PROGRAM_INITIALIZED: Symbol = symbol_short!("PrgInit");
 const FUNDS_LOCKED: Symbol = symbol_short!("FndsLock");
 const BATCH_PAYOUT: Symbol = symbol_short!("BatchPay");
 const PAYOUT: Symbol = symbol_short!("Payout");
 const EVENT_VERSION_V2: u32 = 2;
+/// Contract version reported by `get_version`, read by grainlify-core's
+/// escrow compatibility matrix.
+const CONTRACT_VERSION: u32 = 1;
 const PAUSE_STATE_CHANGED: Symbol = symbol_short!("PauseSt");
 const MAINTENANCE_MODE_CHANGED: Symbol = symbol_short!("MaintSt");
 const PROGRAM_RISK_FLAGS_UPDATED: Symbol = symbol_short!("pr_risk");
@@ -162,6 +167,8 @@ const SCHEDULE_RELEASED: Symbol = symbol_short!("SchRel");
 // Storage keys
 const PROGRAM_DATA: Symbol = symbol_short!("ProgData");
 const RECEIPT_ID: Symbol = symbol_short!("RcptID");
+const EVENT_SEQUENCE: Symbol = symbol_short!("EvtSeq");
+const RESERVED_OBLIGATIONS: Symbol = symbol_short!("Reserved");
 const SCHEDULES: Symbol = symbol_short!("Scheds");
 const RELEASE_HISTORY: Symbol = symbol_short!("RelHist");
 const NEXT_SCHEDULE_ID: Symbol = symbol_short!("NxtSched");
@@ -289,6 +296,7 @@ pub struct PayoutRecord {
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ProgramInitializedEvent {
     pub version: u32,
+    pub sequence: u64,
     pub program_id: String,
     pub authorized_payout_key: Address,
     pub token_address: Address,
@@ -299,6 +307,7 @@ pub struct ProgramInitializedEvent {
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct FundsLockedEvent {
     pub version: u32,
+    pub sequence: u64,
     pub program_id: String,
     pub amount: i128,
     pub remaining_balance: i128,
@@ -308,6 +317,7 @@ pub struct FundsLockedEvent {
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct BatchPayoutEvent {
     pub version: u32,
+    pub sequence: u64,
     pub program_id: String,
     pub recipient_count: u32,
     pub total_amount: i128,
@@ -318,6 +328,7 @@ pub struct BatchPayoutEvent {
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PayoutEvent {
     pub version: u32,
+    pub sequence: u64,
     pub program_id: String,
     pub recipient: Address,
     pub amount: i128,
@@ -328,6 +339,7 @@ pub struct PayoutEvent {
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ReleaseScheduledEvent {
     pub version: u32,
+    pub sequence: u64,
     pub program_id: String,
     pub schedule_id: u64,
     pub recipient: Address,
@@ -339,6 +351,7 @@ pub struct ReleaseScheduledEvent {
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ScheduleReleasedEvent {
     pub version: u32,
+    pub sequence: u64,
     pub program_id: String,
     pub schedule_id: u64,
     pub recipient: Address,
@@ -469,9 +482,7 @@ pub enum DataKey {
     MaintenanceMode,                 // bool flag
     ProgramDependencies(String),     // program_id -> Vec<String>
     DependencyStatus(String),        // program_id -> DependencyStatus
-    SplitConfig(String),             // program_id -> SplitConfig (payout splits)
     Dispute,                         // DisputeRecord (single active dispute per contract)
-    SplitConfig(String),             // program_id -> SplitConfig
 }
 
 #[contracttype]
@@ -511,6 +522,7 @@ pub struct EmergencyWithdrawEvent {
     pub amount: i128,
     pub timestamp: u64,
     pub receipt_id: u64,
+    pub sequence: u64,
 }
 
 #[contracttype]
@@ -610,6 +622,54 @@ pub enum BatchError {
 
 pub const MAX_BATCH_SIZE: u32 = 100;
 
+/// Returns the next value of the contract-wide event sequence counter.
+///
+/// Every registration, funding, payout, refund and schedule event carries the
+/// value returned here, so indexers can detect gaps (a missed ledger shows up as
+/// a skipped sequence number) and backfill from RPC history.
+pub(crate) fn next_event_sequence(env: &Env) -> u64 {
+    let sequence: u64 = env
+        .storage()
+        .instance()
+        .get(&EVENT_SEQUENCE)
+        .unwrap_or(0_u64)
+        + 1;
+    env.storage().instance().set(&EVENT_SEQUENCE, &sequence);
+    sequence
+}
+
+/// Tokens owed to pending claims, committed Merkle distributions and
+/// parametric enrollments that have not been paid out yet. The yield adapter
+/// keeps these on hand alongside pending schedules.
+pub(crate) fn reserved_obligations(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&RESERVED_OBLIGATIONS)
+        .unwrap_or(0)
+}
+
+pub(crate) fn adjust_reserved_obligations(env: &Env, delta: i128) {
+    let reserved = reserved_obligations(env) + delta;
+    env.storage()
+        .instance()
+        .set(&RESERVED_OBLIGATIONS, &reserved.max(0));
+}
+
+/// Loads program data for `program_id`, preferring the active `PROGRAM_DATA`
+/// record that the single-program lock and payout paths keep up to date.
+pub(crate) fn load_program_data(env: &Env, program_id: &String) -> ProgramData {
+    if let Some(program_data) = env
+        .storage()
+        .instance()
+        .get::<_, ProgramData>(&PROGRAM_DATA)
+    {
+        if &program_data.program_id == program_id {
+            return program_data;
+        }
+    }
+    ProgramEscrowContract::get_program_data_by_id(env, program_id)
+}
+
 fn vec_contains(values: &Vec<String>, target: &String) -> bool {
     for value in values.iter() {
         if value == *target {
@@ -688,14 +748,30 @@ mod test_circuit_breaker_audit;
 #[cfg(test)]
 mod error_recovery_tests;
 
-mod payout_splits;
 #[cfg(any())]
 mod reentrancy_tests;
 #[cfg(test)]
 mod test_dispute_resolution;
 mod threshold_monitor;
+pub mod events_log;
+pub mod audit_trail;
+pub mod merkle_distribution;
+pub mod commit_reveal;
+pub mod oracle_conditions;
+pub mod indexed_payouts;
+pub mod amm_adapter;
+pub mod yield_adapter;
+pub mod clawback;
+pub mod travel_rule;
+pub mod parametric;
+pub mod matching;
+pub mod quadratic_funding;
+pub mod receipts;
+pub mod attestations;
+pub mod global_halt;
+pub mod reputation;
 mod token_math;
-pub use payout_splits::{BeneficiarySplit, SplitConfig, SplitPayoutResult};
+pub use payout_splits::SplitPayoutResult;
 
 #[cfg(test)]
 mod reentrancy_guard_standalone_test;
@@ -722,6 +798,60 @@ mod test_serialization_compatibility;
 #[cfg(test)]
 mod test_payout_splits;
 
+#[cfg(test)]
+mod test_event_sequence;
+
+#[cfg(test)]
+mod test_event_log_levels;
+
+#[cfg(test)]
+mod test_audit_trail;
+
+#[cfg(test)]
+mod test_merkle_distribution;
+
+#[cfg(test)]
+mod test_commit_reveal;
+
+#[cfg(test)]
+mod test_oracle_conditions;
+
+#[cfg(test)]
+mod test_indexed_payouts;
+
+#[cfg(test)]
+mod test_amm_payout;
+
+#[cfg(test)]
+mod test_yield_adapter;
+
+#[cfg(test)]
+mod test_clawback;
+
+#[cfg(test)]
+mod test_travel_rule;
+
+#[cfg(test)]
+mod test_parametric;
+
+#[cfg(test)]
+mod test_matching;
+
+#[cfg(test)]
+mod test_quadratic_funding;
+
+#[cfg(test)]
+mod test_receipts;
+
+#[cfg(test)]
+mod test_attestations;
+
+#[cfg(test)]
+mod test_global_halt;
+
+#[cfg(test)]
+mod test_reputation;
+
 // ========================================================================
 // Contract Implementation
 // ========================================================================
@@ -857,6 +987,17 @@ impl ProgramEscrowContract {
                 },
             );
         }
+        if !env.storage().instance().has(&FEE_CONFIG) {
+            env.storage().instance().set(
+                &FEE_CONFIG,
+                &FeeConfig {
+                    lock_fee_rate: 0,
+                    payout_fee_rate: 0,
+                    fee_recipient: authorized_payout_key.clone(),
+                    fee_enabled: false,
+                },
+            );
+        }
 
         env.storage()
             .instance()
@@ -868,9 +1009,10 @@ impl ProgramEscrowContract {
 
         // Emit ProgramInitialized event
         env.events().publish(
-            (PROGRAM_INITIALIZED,),
+            (LogLevel::Info, PROGRAM_INITIALIZED),
             ProgramInitializedEvent {
                 version: EVENT_VERSION_V2,
+                sequence: next_event_sequence(&env),
                 program_id,
                 authorized_payout_key,
                 token_address,
@@ -1001,8 +1143,14 @@ impl ProgramEscrowContract {
 
             registry.push_back(program_id.clone());
             env.events().publish(
-                (PROGRAM_REGISTERED,),
-                (program_id, authorized_payout_key, token_address, 0i128),
+                (LogLevel::Info, PROGRAM_REGISTERED),
+                (
+                    program_id,
+                    authorized_payout_key,
+                    token_address,
+                    0i128,
+                    next_event_sequence(&env),
+                ),
             );
         }
         env.storage().instance().set(&PROGRAM_REGISTRY, &registry);
@@ -1054,6 +1202,7 @@ impl ProgramEscrowContract {
         let mut config = Self::get_fee_config_internal(&env);
         config.lock_fee_rate = rate;
         env.storage().instance().set(&FEE_CONFIG, &config);
+        audit_trail::record(&env, &admin, symbol_short!("lock_fee"), (rate,));
     }
 
     /// Set the payout fee rate (admin-only).
@@ -1075,6 +1224,7 @@ impl ProgramEscrowContract {
         let mut config = Self::get_fee_config_internal(&env);
         config.payout_fee_rate = rate;
         env.storage().instance().set(&FEE_CONFIG, &config);
+        audit_trail::record(&env, &admin, symbol_short!("pay_fee"), (rate,));
     }
 
     /// Set the fee recipient address (admin-only).
@@ -1092,6 +1242,7 @@ impl ProgramEscrowContract {
         let mut config = Self::get_fee_config_internal(&env);
         config.fee_recipient = recipient;
         env.storage().instance().set(&FEE_CONFIG, &config);
+        audit_trail::record(&env, &admin, symbol_short!("fee_rcpt"), (config.fee_recipient,));
     }
 
     /// Enable or disable fee collection (admin-only).
@@ -1109,6 +1260,7 @@ impl ProgramEscrowContract {
         let mut config = Self::get_fee_config_internal(&env);
         config.fee_enabled = enabled;
         env.storage().instance().set(&FEE_CONFIG, &config);
+        audit_trail::record(&env, &admin, symbol_short!("fees_on"), (enabled,));
     }
 
     /// Get current fee configuration (public).
@@ -1185,6 +1337,7 @@ impl ProgramEscrowContract {
         // Transfer fee to recipient if fee > 0
         if fee_amount > 0 {
             let contract_address = env.current_contract_address();
+            yield_adapter::ensure_liquid(&env, &program_data.token_address, fee_amount);
             let token_client = token::Client::new(&env, &program_data.token_address);
             token_client.transfer(&contract_address, &fee_config.fee_recipient, &fee_amount);
         }
@@ -1203,11 +1356,20 @@ impl ProgramEscrowContract {
         // Store updated data
         env.storage().instance().set(&PROGRAM_DATA, &program_data);
 
+        receipts::issue(
+            &env,
+            &program_data.program_id,
+            &program_data.authorized_payout_key,
+            amount,
+            receipts::DepositKind::Lock,
+        );
+
         // Emit FundsLocked event
         env.events().publish(
-            (FUNDS_LOCKED,),
+            (LogLevel::Info, FUNDS_LOCKED),
             FundsLockedEvent {
                 version: EVENT_VERSION_V2,
+                sequence: next_event_sequence(&env),
                 program_id: program_data.program_id.clone(),
                 amount,
                 remaining_balance: program_data.remaining_balance,
@@ -1243,11 +1405,18 @@ impl ProgramEscrowContract {
         );
     }
 
+    /// Version of this contract's code, for grainlify-core's compatibility
+    /// checks.
+    pub fn get_version(_env: Env) -> u32 {
+        CONTRACT_VERSION
+    }
+
     /// Set or rotate admin. If no admin is set, sets initial admin. If admin exists, current admin must authorize and the new address becomes admin.
     pub fn set_admin(env: Env, admin: Address) {
         if env.storage().instance().has(&DataKey::Admin) {
             let current: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
             current.require_auth();
+            audit_trail::record(&env, &current, symbol_short!("set_admin"), (admin.clone(),));
         }
         env.storage().instance().set(&DataKey::Admin, &admin);
     }
@@ -1257,6 +1426,39 @@ impl ProgramEscrowContract {
         env.storage().instance().get(&DataKey::Admin)
     }
 
+    /// Returns up to `limit` audit trail entries starting at `offset`, oldest first.
+    /// `limit` is capped at `audit_trail::MAX_AUDIT_PAGE_SIZE`.
+    pub fn get_audit_entries(env: Env, offset: u64, limit: u32) -> Vec<audit_trail::AuditEntry> {
+        audit_trail::get_entries(&env, offset, limit)
+    }
+
+    /// Returns the total number of audit trail entries recorded.
+    pub fn get_audit_entry_count(env: Env) -> u64 {
+        audit_trail::entry_count(&env)
+    }
+
+    /// Grant or revoke the read-only auditor role (admin only).
+    pub fn set_auditor(env: Env, auditor: Address, enabled: bool) {
+        let admin = Self::require_admin(&env);
+        audit_trail::set_auditor(&env, &auditor, enabled);
+        audit_trail::record(&env, &admin, symbol_short!("auditor"), (auditor, enabled));
+    }
+
+    pub fn is_auditor(env: Env, addr: Address) -> bool {
+        audit_trail::is_auditor(&env, &addr)
+    }
+
+    /// Auditor-only export of the audit trail (paged like
+    /// `get_audit_entries`) together with the circuit breaker's error log.
+    pub fn export_audit_trail(
+        env: Env,
+        auditor: Address,
+        offset: u64,
+        limit: u32,
+    ) -> audit_trail::AuditExport {
+        audit_trail::export(&env, &auditor, offset, limit)
+    }
+
     fn require_admin(env: &Env) -> Address {
         let admin: Address = env
             .storage()
@@ -1314,6 +1516,12 @@ impl ProgramEscrowContract {
         let previous_flags = program_data.risk_flags;
         program_data.risk_flags = flags;
         Self::store_program_data(&env, &program_id, &program_data);
+        audit_trail::record(
+            &env,
+            &admin,
+            symbol_short!("risk_set"),
+            (program_id.clone(), flags),
+        );
 
         env.events().publish(
             (PROGRAM_RISK_FLAGS_UPDATED, program_id.clone()),
@@ -1337,6 +1545,12 @@ impl ProgramEscrowContract {
         let previous_flags = program_data.risk_flags;
         program_data.risk_flags &= !flags;
         Self::store_program_data(&env, &program_id, &program_data);
+        audit_trail::record(
+            &env,
+            &admin,
+            symbol_short!("risk_clr"),
+            (program_id.clone(), flags),
+        );
 
         env.events().publish(
             (PROGRAM_RISK_FLAGS_UPDATED, program_id.clone()),
@@ -1442,6 +1656,12 @@ impl ProgramEscrowContract {
         }
 
         env.storage().instance().set(&DataKey::PauseFlags, &flags);
+        audit_trail::record(
+            &env,
+            &admin,
+            symbol_short!("pause"),
+            (lock, release, refund, reason),
+        );
     }
 
     /// Check if the contract is in maintenance mode
@@ -1463,6 +1683,7 @@ impl ProgramEscrowContract {
         env.storage()
             .instance()
             .set(&DataKey::MaintenanceMode, &enabled);
+        audit_trail::record(&env, &admin, symbol_short!("maint"), (enabled,));
         env.events().publish(
             (MAINTENANCE_MODE_CHANGED,),
             MaintenanceModeChanged {
@@ -1498,6 +1719,13 @@ impl ProgramEscrowContract {
 
         if balance > 0 {
             token_client.transfer(&contract_address, &target, &balance);
+            audit_trail::record(
+                &env,
+                &admin,
+                symbol_short!("em_wtd"),
+                (target.clone(), balance),
+            );
+            reputation::record_refund(&env);
             let receipt_id = Self::increment_receipt_id(&env);
             env.events().publish(
                 (symbol_short!("em_wtd"),),
@@ -1507,6 +1735,7 @@ impl ProgramEscrowContract {
                     amount: balance,
                     timestamp: env.ledger().timestamp(),
                     receipt_id,
+                    sequence: next_event_sequence(&env),
                 },
             );
         }
@@ -1528,6 +1757,9 @@ impl ProgramEscrowContract {
 
     /// Check if an operation is paused
     fn check_paused(env: &Env, operation: Symbol) -> bool {
+        if global_halt::is_halted(env) {
+            return true;
+        }
         if Self::is_maintenance_mode(env.clone()) && operation == symbol_short!("lock") {
             return true;
         }
@@ -1544,8 +1776,20 @@ impl ProgramEscrowContract {
 
     // --- Circuit Breaker & Rate Limit ---
 
+    /// Appoint the circuit breaker admin. The first appointment is made by
+    /// the contract admin; after that the current circuit admin rotates it,
+    /// passing itself as `caller`. The audit trail records whoever authorized
+    /// the change.
     pub fn set_circuit_admin(env: Env, new_admin: Address, caller: Option<Address>) {
-        error_recovery::set_circuit_admin(&env, new_admin, caller);
+        let actor = if error_recovery::get_circuit_admin(&env).is_some() {
+            caller.clone().unwrap_or_else(|| {
+                panic!("Unauthorized: only current admin can change circuit breaker admin")
+            })
+        } else {
+            Self::require_admin(&env)
+        };
+        error_recovery::set_circuit_admin(&env, new_admin.clone(), caller);
+        audit_trail::record(&env, &actor, symbol_short!("cb_adm"), (new_admin,));
     }
 
     pub fn get_circuit_admin(env: Env) -> Option<Address> {
@@ -1559,6 +1803,7 @@ impl ProgramEscrowContract {
             panic!("Unauthorized: only circuit admin can reset");
         }
         error_recovery::reset_circuit_breaker(&env, &admin);
+        audit_trail::record(&env, &admin, symbol_short!("cb_reset"), ());
     }
 
     pub fn configure_circuit_breaker(
@@ -1580,6 +1825,12 @@ impl ProgramEscrowContract {
             max_error_log,
         };
         error_recovery::set_config(&env, config);
+        audit_trail::record(
+            &env,
+            &caller,
+            symbol_short!("cb_cfg"),
+            (failure_threshold, success_threshold, max_error_log),
+        );
     }
 
     pub fn update_rate_limit_config(
@@ -1601,6 +1852,13 @@ impl ProgramEscrowContract {
             .instance()
             .set(&DataKey::RateLimitConfig, &config);
 
+        audit_trail::record(
+            &env,
+            &admin,
+            symbol_short!("rate_lim"),
+            (window_size, max_operations, cooldown_period),
+        );
+
         // Emit audit event for rate limit config update
         env.events().publish(
             (symbol_short!("rate_lim"), symbol_short!("update")),
@@ -1625,6 +1883,27 @@ impl ProgramEscrowContract {
             })
     }
 
+    /// Replace the outflow/failure thresholds that trip the circuit breaker
+    /// (admin-only).
+    pub fn set_threshold_config(env: Env, config: threshold_monitor::ThresholdConfig) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("Not initialized"));
+        admin.require_auth();
+
+        if threshold_monitor::set_threshold_config(&env, config).is_err() {
+            panic!("Invalid threshold config");
+        }
+        audit_trail::record(&env, &admin, symbol_short!("th_cfg"), ());
+    }
+
+    /// Current threshold config; the defaults until an admin overrides them.
+    pub fn get_threshold_config(env: Env) -> threshold_monitor::ThresholdConfig {
+        threshold_monitor::get_threshold_config(&env)
+    }
+
     pub fn get_analytics(_env: Env) -> Analytics {
         Analytics {
             total_locked: 0,
@@ -1666,6 +1945,27 @@ impl ProgramEscrowContract {
     /// - Protected by reentrancy guard.
     /// - Respects circuit breaker and threshold limits.
     pub fn batch_payout(env: Env, recipients: Vec<Address>, amounts: Vec<i128>) -> ProgramData {
+        Self::execute_batch_payout(env, recipients, amounts, None)
+    }
+
+    /// Same as `batch_payout`, attaching `travel_rule_hashes[i]` to the i-th
+    /// payout. Entries may be `None` for payouts at or below the travel rule
+    /// threshold.
+    pub fn batch_payout_with_travel_rule(
+        env: Env,
+        recipients: Vec<Address>,
+        amounts: Vec<i128>,
+        travel_rule_hashes: Vec<Option<BytesN<32>>>,
+    ) -> ProgramData {
+        Self::execute_batch_payout(env, recipients, amounts, Some(travel_rule_hashes))
+    }
+
+    fn execute_batch_payout(
+        env: Env,
+        recipients: Vec<Address>,
+        amounts: Vec<i128>,
+        travel_rule_hashes: Option<Vec<Option<BytesN<32>>>>,
+    ) -> ProgramData {
         // Validation precedence (deterministic ordering):
         // 1. Reentrancy guard
         // 2. Contract initialized
@@ -1714,13 +2014,31 @@ impl ProgramEscrowContract {
             panic!("Cannot process empty batch");
         }
 
+        let travel_rule_hashes = travel_rule_hashes.unwrap_or_else(|| {
+            let mut none = Vec::new(&env);
+            for _ in 0..recipients.len() {
+                none.push_back(None);
+            }
+            none
+        });
+        if travel_rule_hashes.len() != recipients.len() {
+            reentrancy_guard::clear_entered(&env);
+            panic!("Travel rule hashes must match recipients length");
+        }
+
         // Calculate total payout amount
         let mut total_payout: i128 = 0;
-        for amount in amounts.iter() {
+        for (i, amount) in amounts.iter().enumerate() {
             if amount <= 0 {
                 reentrancy_guard::clear_entered(&env);
                 panic!("All amounts must be greater than zero");
             }
+            if travel_rule::is_required(&env, amount)
+                && travel_rule_hashes.get(i as u32).unwrap().is_none()
+            {
+                reentrancy_guard::clear_entered(&env);
+                panic!("Travel rule hash required");
+            }
             total_payout = total_payout.checked_add(amount).unwrap_or_else(|| {
                 reentrancy_guard::clear_entered(&env);
                 panic!("Payout amount overflow")
@@ -1744,6 +2062,7 @@ impl ProgramEscrowContract {
         }
 
         // Execute transfers
+        yield_adapter::ensure_liquid(&env, &program_data.token_address, total_payout);
         let mut updated_history = program_data.payout_history.clone();
         let timestamp = env.ledger().timestamp();
         let contract_address = env.current_contract_address();
@@ -1766,6 +2085,19 @@ impl ProgramEscrowContract {
             threshold_monitor::record_operation_success(&env);
             threshold_monitor::record_outflow(&env, amount);
 
+            attestations::mint(&env, &recipient, &program_data.program_id, amount, None);
+
+            if let Some(hash) = travel_rule_hashes.get(i).unwrap() {
+                travel_rule::record(
+                    &env,
+                    &program_data.program_id,
+                    updated_history.len(),
+                    &recipient,
+                    amount,
+                    hash,
+                );
+            }
+
             // Record payout
             let payout_record = PayoutRecord {
                 recipient,
@@ -1785,9 +2117,10 @@ impl ProgramEscrowContract {
 
         // Emit BatchPayout event
         env.events().publish(
-            (BATCH_PAYOUT,),
+            (LogLevel::Info, BATCH_PAYOUT),
             BatchPayoutEvent {
                 version: EVENT_VERSION_V2,
+                sequence: next_event_sequence(&env),
                 program_id: updated_data.program_id.clone(),
                 recipient_count: recipients.len() as u32,
                 total_amount: total_payout,
@@ -1815,6 +2148,99 @@ impl ProgramEscrowContract {
     /// - Protected by reentrancy guard.
     /// - Respects circuit breaker and threshold limits.
     pub fn single_payout(env: Env, recipient: Address, amount: i128) -> ProgramData {
+        Self::execute_single_payout(env, recipient, amount, None, None, None)
+    }
+
+    /// Same as `single_payout`, but delivers the payout in `target_token` by
+    /// swapping `amount` program tokens through the registered AMM. Panics
+    /// (and pays nothing) if fewer than `min_out` target tokens arrive.
+    pub fn single_payout_as(
+        env: Env,
+        recipient: Address,
+        amount: i128,
+        target_token: Address,
+        min_out: i128,
+    ) -> ProgramData {
+        Self::execute_single_payout(
+            env,
+            recipient,
+            amount,
+            None,
+            Some((target_token, min_out)),
+            None,
+        )
+    }
+
+    /// Same as `single_payout`, recording `work_hash` (a hash of the delivered
+    /// work) on the recipient's completion attestation.
+    pub fn single_payout_with_work(
+        env: Env,
+        recipient: Address,
+        amount: i128,
+        work_hash: BytesN<32>,
+    ) -> ProgramData {
+        Self::execute_single_payout(env, recipient, amount, Some(work_hash), None, None)
+    }
+
+    /// `single_payout_as` with a `travel_rule_hash`, for swapped payouts
+    /// above the travel rule threshold.
+    pub fn single_payout_as_with_travel(
+        env: Env,
+        recipient: Address,
+        amount: i128,
+        target_token: Address,
+        min_out: i128,
+        travel_rule_hash: BytesN<32>,
+    ) -> ProgramData {
+        Self::execute_single_payout(
+            env,
+            recipient,
+            amount,
+            None,
+            Some((target_token, min_out)),
+            Some(travel_rule_hash),
+        )
+    }
+
+    /// `single_payout_with_work` with a `travel_rule_hash`, for payouts above
+    /// the travel rule threshold.
+    pub fn single_payout_work_with_travel(
+        env: Env,
+        recipient: Address,
+        amount: i128,
+        work_hash: BytesN<32>,
+        travel_rule_hash: BytesN<32>,
+    ) -> ProgramData {
+        Self::execute_single_payout(
+            env,
+            recipient,
+            amount,
+            Some(work_hash),
+            None,
+            Some(travel_rule_hash),
+        )
+    }
+
+    /// Same as `single_payout`, attaching `travel_rule_hash` (a hash of the
+    /// off-chain originator/beneficiary data). Required for payouts above the
+    /// travel rule threshold.
+    pub fn single_payout_with_travel_rule(
+        env: Env,
+        recipient: Address,
+        amount: i128,
+        travel_rule_hash: BytesN<32>,
+    ) -> ProgramData {
+        Self::execute_single_payout(env, recipient, amount, None, None, Some(travel_rule_hash))
+    }
+
+    fn execute_single_payout(
+        env: Env,
+        recipient: Address,
+        amount: i128,
+        work_hash: Option<BytesN<32>>,
+        swap: Option<(Address, i128)>,
+        travel_rule_hash: Option<BytesN<32>>,
+    ) -> ProgramData {
         // Validation precedence (deterministic ordering):
         // 1. Reentrancy guard
         // 2. Contract initialized
@@ -1857,6 +2283,10 @@ impl ProgramEscrowContract {
             reentrancy_guard::clear_entered(&env);
             panic!("Amount must be greater than zero");
         }
+        if travel_rule_hash.is_none() && travel_rule::is_required(&env, amount) {
+            reentrancy_guard::clear_entered(&env);
+            panic!("Travel rule hash required");
+        }
 
         // 6. Business logic: sufficient balance
         if amount > program_data.remaining_balance {
@@ -1874,16 +2304,51 @@ impl ProgramEscrowContract {
             }
         }
 
-        // Transfer funds from contract to recipient
-        let contract_address = env.current_contract_address();
-        let token_client = token::Client::new(&env, &program_data.token_address);
-        token_client.transfer(&contract_address, &recipient, &amount);
+        // Transfer funds from contract to recipient, swapping into the
+        // requested asset when one was given
+        yield_adapter::ensure_liquid(&env, &program_data.token_address, amount);
+        match swap {
+            Some((target_token, min_out)) => {
+                amm_adapter::swap_to(
+                    &env,
+                    &program_data.token_address,
+                    &target_token,
+                    amount,
+                    min_out,
+                    &recipient,
+                );
+            }
+            None => {
+                let contract_address = env.current_contract_address();
+                let token_client = token::Client::new(&env, &program_data.token_address);
+                token_client.transfer(&contract_address, &recipient, &amount);
+            }
+        }
 
         // Record success for circuit breaker and threshold monitor
         error_recovery::record_success(&env);
         threshold_monitor::record_operation_success(&env);
         threshold_monitor::record_outflow(&env, amount);
 
+        attestations::mint(
+            &env,
+            &recipient,
+            &program_data.program_id,
+            amount,
+            work_hash,
+        );
+
+        if let Some(hash) = travel_rule_hash {
+            travel_rule::record(
+                &env,
+                &program_data.program_id,
+                program_data.payout_history.len(),
+                &recipient,
+                amount,
+                hash,
+            );
+        }
+
         // Record payout
         let timestamp = env.ledger().timestamp();
         let payout_record = PayoutRecord {
@@ -1905,9 +2370,10 @@ impl ProgramEscrowContract {
 
         // Emit Payout event
         env.events().publish(
-            (PAYOUT,),
+            (LogLevel::Info, PAYOUT),
             PayoutEvent {
                 version: EVENT_VERSION_V2,
+                sequence: next_event_sequence(&env),
                 program_id: updated_data.program_id.clone(),
                 recipient,
                 amount,
@@ -1946,6 +2412,58 @@ impl ProgramEscrowContract {
         program_data.remaining_balance
     }
 
+    /// Funds-conservation view used by grainlify-core's invariant checks.
+    ///
+    /// Returns `(tracked, held)`: the remaining balance of every program paid
+    /// in the primary program's token plus any unmatched matching-pool
+    /// reserve, and the contract's balance of that token plus the principal
+    /// parked in the yield protocol. Anyone can send tokens to the contract,
+    /// so `held >= tracked` is the healthy state. Both are 0 before the
+    /// program is initialized.
+    pub fn reconcile_balances(env: Env) -> (i128, i128) {
+        let program_data: ProgramData = match env.storage().instance().get(&PROGRAM_DATA) {
+            Some(data) => data,
+            None => return (0, 0),
+        };
+
+        // Reserved obligations (claim windows, distributions, unmatched
+        // pool caps) are outside `remaining_balance` but still held by the
+        // contract.
+        let mut tracked = program_data.remaining_balance + reserved_obligations(&env);
+        let registry: Vec<String> = env
+            .storage()
+            .instance()
+            .get(&PROGRAM_REGISTRY)
+            .unwrap_or_else(|| Vec::new(&env));
+        for program_id in registry.iter() {
+            if program_id == program_data.program_id {
+                continue;
+            }
+            if let Some(other) = env
+                .storage()
+                .instance()
+                .get::<_, ProgramData>(&DataKey::Program(program_id))
+            {
+                if other.token_address == program_data.token_address {
+                    tracked += other.remaining_balance;
+                }
+            }
+        }
+        let held = token::Client::new(&env, &program_data.token_address)
+            .balance(&env.current_contract_address())
+            + yield_adapter::get_principal(&env);
+        (tracked, held)
+    }
+
+    /// Get the sequence number of the most recently emitted program event.
+    ///
+    /// Returns `0` when no sequenced event has been emitted yet. Indexers can
+    /// compare this against the highest sequence they have ingested to detect
+    /// missing events.
+    pub fn get_event_sequence(env: Env) -> u64 {
+        env.storage().instance().get(&EVENT_SEQUENCE).unwrap_or(0)
+    }
+
     /// Create a release schedule entry that can be triggered at/after `release_timestamp`.
     ///
     /// # Arguments
@@ -1973,11 +2491,23 @@ impl ProgramEscrowContract {
             panic!("Amount must be greater than zero");
         }
 
+        Self::push_release_schedule(&env, &program_data, recipient, amount, release_timestamp)
+    }
+
+    /// Append a pending schedule and emit `RelSched`. Callers are responsible
+    /// for authorization and amount validation.
+    fn push_release_schedule(
+        env: &Env,
+        program_data: &ProgramData,
+        recipient: Address,
+        amount: i128,
+        release_timestamp: u64,
+    ) -> ProgramReleaseSchedule {
         let mut schedules: Vec<ProgramReleaseSchedule> = env
             .storage()
             .instance()
             .get(&SCHEDULES)
-            .unwrap_or_else(|| Vec::new(&env));
+            .unwrap_or_else(|| Vec::new(env));
         let schedule_id: u64 = env
             .storage()
             .instance()
@@ -2002,10 +2532,11 @@ impl ProgramEscrowContract {
 
         // Emit ReleaseScheduled event
         env.events().publish(
-            (RELEASE_SCHEDULED,),
+            (LogLevel::Info, RELEASE_SCHEDULED),
             ReleaseScheduledEvent {
                 version: EVENT_VERSION_V2,
-                program_id: program_data.program_id,
+                sequence: next_event_sequence(env),
+                program_id: program_data.program_id.clone(),
                 schedule_id,
                 recipient,
                 amount,
@@ -2058,41 +2589,66 @@ impl ProgramEscrowContract {
             if schedule.released || now < schedule.release_timestamp {
                 continue;
             }
-
-            if schedule.amount > program_data.remaining_balance {
+            if !oracle_conditions::condition_holds(&env, schedule.schedule_id) {
+                continue;
+            }
+            let amount =
+                indexed_payouts::resolve_amount(&env, schedule.schedule_id, schedule.amount);
+            if amount > program_data.remaining_balance {
+                // An indexed schedule can resolve above what is left; leave
+                // it pending rather than blocking every other due schedule.
+                if indexed_payouts::is_indexed(&env, schedule.schedule_id) {
+                    continue;
+                }
                 reentrancy_guard::clear_entered(&env);
                 panic!("Insufficient balance");
             }
 
-            token_client.transfer(&contract_address, &schedule.recipient, &schedule.amount);
+            travel_rule::apply_registered(
+                &env,
+                &program_data.program_id,
+                program_data.payout_history.len(),
+                &schedule.recipient,
+                amount,
+            );
+            yield_adapter::ensure_liquid(&env, &program_data.token_address, amount);
+            token_client.transfer(&contract_address, &schedule.recipient, &amount);
             schedule.released = true;
             schedule.released_at = Some(now);
             schedule.released_by = Some(contract_address.clone());
             schedules.set(i, schedule.clone());
 
-            program_data.remaining_balance -= schedule.amount;
+            program_data.remaining_balance -= amount;
+            attestations::mint(
+                &env,
+                &schedule.recipient,
+                &program_data.program_id,
+                amount,
+                None,
+            );
             program_data.payout_history.push_back(PayoutRecord {
                 recipient: schedule.recipient.clone(),
-                amount: schedule.amount,
+                amount,
                 timestamp: now,
             });
             release_history.push_back(ProgramReleaseHistory {
                 schedule_id: schedule.schedule_id,
                 recipient: schedule.recipient.clone(),
-                amount: schedule.amount,
+                amount,
                 released_at: now,
                 release_type: ReleaseType::Automatic,
             });
 
             // Emit ScheduleReleased event
             env.events().publish(
-                (SCHEDULE_RELEASED,),
+                (LogLevel::Info, SCHEDULE_RELEASED),
                 ScheduleReleasedEvent {
                     version: EVENT_VERSION_V2,
+                    sequence: next_event_sequence(&env),
                     program_id: program_data.program_id.clone(),
                     schedule_id: schedule.schedule_id,
                     recipient: schedule.recipient,
-                    amount: schedule.amount,
+                    amount,
                     released_at: now,
                     released_by: contract_address.clone(),
                 },
@@ -2565,6 +3121,10 @@ impl ProgramEscrowContract {
 
         program_data.authorized_payout_key.require_auth();
 
+        if Self::check_paused(&env, symbol_short!("release")) {
+            panic!("Funds Paused");
+        }
+
         let caller = program_data.authorized_payout_key.clone();
         let now = env.ledger().timestamp();
         let mut released_schedule: Option<ProgramReleaseSchedule> = None;
@@ -2577,14 +3137,22 @@ impl ProgramEscrowContract {
                     panic!("Already released");
                 }
 
-                // Transfer funds
+                // Transfer funds; indexed schedules store a reference amount
+                let amount = indexed_payouts::resolve_amount(&env, schedule_id, s.amount);
+                if amount > program_data.remaining_balance {
+                    panic!("Insufficient balance");
+                }
+                yield_adapter::ensure_liquid(&env, &program_data.token_address, amount);
                 let token_client = token::Client::new(&env, &program_data.token_address);
-                token_client.transfer(&env.current_contract_address(), &s.recipient, &s.amount);
+                token_client.transfer(&env.current_contract_address(), &s.recipient, &amount);
 
                 s.released = true;
                 s.released_at = Some(now);
                 s.released_by = Some(caller.clone());
-                released_schedule = Some(s.clone());
+                released_schedule = Some(ProgramReleaseSchedule {
+                    amount,
+                    ..s.clone()
+                });
                 schedules.set(i, s);
                 found = true;
                 break;
@@ -2599,8 +3167,21 @@ impl ProgramEscrowContract {
 
         // Write to release history
         if let Some(s) = released_schedule {
+            attestations::mint(&env, &s.recipient, &program_data.program_id, s.amount, None);
+            travel_rule::apply_registered(
+                &env,
+                &program_data.program_id,
+                program_data.payout_history.len(),
+                &s.recipient,
+                s.amount,
+            );
             let mut updated_program_data = program_data.clone();
             updated_program_data.remaining_balance -= s.amount;
+            updated_program_data.payout_history.push_back(PayoutRecord {
+                recipient: s.recipient.clone(),
+                amount: s.amount,
+                timestamp: now,
+            });
             env.storage()
                 .instance()
                 .set(&PROGRAM_DATA, &updated_program_data);
@@ -2622,6 +3203,10 @@ impl ProgramEscrowContract {
     }
 
     pub fn release_prog_schedule_automatic(env: Env, schedule_id: u64) {
+        if Self::check_paused(&env, symbol_short!("release")) {
+            panic!("Funds Paused");
+        }
+
         let mut schedules = Self::get_release_schedules(env.clone());
         let program_data = Self::get_program_info(env.clone());
         let now = env.ledger().timestamp();
@@ -2637,15 +3222,26 @@ impl ProgramEscrowContract {
                 if now < s.release_timestamp {
                     panic!("Not yet due");
                 }
+                if !oracle_conditions::condition_holds(&env, schedule_id) {
+                    panic!("Oracle condition not met");
+                }
 
-                // Transfer funds
+                // Transfer funds; indexed schedules store a reference amount
+                let amount = indexed_payouts::resolve_amount(&env, schedule_id, s.amount);
+                if amount > program_data.remaining_balance {
+                    panic!("Insufficient balance");
+                }
+                yield_adapter::ensure_liquid(&env, &program_data.token_address, amount);
                 let token_client = token::Client::new(&env, &program_data.token_address);
-                token_client.transfer(&env.current_contract_address(), &s.recipient, &s.amount);
+                token_client.transfer(&env.current_contract_address(), &s.recipient, &amount);
 
                 s.released = true;
                 s.released_at = Some(now);
                 s.released_by = Some(env.current_contract_address());
-                released_schedule = Some(s.clone());
+                released_schedule = Some(ProgramReleaseSchedule {
+                    amount,
+                    ..s.clone()
+                });
                 schedules.set(i, s);
                 found = true;
                 break;
@@ -2660,8 +3256,21 @@ impl ProgramEscrowContract {
 
         // Write to release history
         if let Some(s) = released_schedule {
+            attestations::mint(&env, &s.recipient, &program_data.program_id, s.amount, None);
+            travel_rule::apply_registered(
+                &env,
+                &program_data.program_id,
+                program_data.payout_history.len(),
+                &s.recipient,
+                s.amount,
+            );
             let mut updated_program_data = program_data.clone();
             updated_program_data.remaining_balance -= s.amount;
+            updated_program_data.payout_history.push_back(PayoutRecord {
+                recipient: s.recipient.clone(),
+                amount: s.amount,
+                timestamp: now,
+            });
             env.storage()
                 .instance()
                 .set(&PROGRAM_DATA, &updated_program_data);
@@ -2705,47 +3314,583 @@ impl ProgramEscrowContract {
     }
 
     pub fn set_claim_window(env: Env, admin: Address, window_seconds: u64) {
-        claim_period::set_claim_window(&env, &admin, window_seconds)
+        claim_period::set_claim_window(&env, &admin, window_seconds);
+        audit_trail::record(&env, &admin, symbol_short!("clm_win"), (window_seconds,));
     }
 
     pub fn get_claim_window(env: Env) -> u64 {
         claim_period::get_claim_window(&env)
     }
 
-    // ========================================================================
-    // Payout Splits
-    // ========================================================================
+    // --- Merkle Distribution ---
 
-    pub fn set_split_config(
+    pub fn commit_distribution_root(
         env: Env,
         program_id: String,
-        beneficiaries: soroban_sdk::Vec<BeneficiarySplit>,
-    ) -> SplitConfig {
-        payout_splits::set_split_config(&env, &program_id, beneficiaries)
+        root: BytesN<32>,
+        total_amount: i128,
+        leaf_count: u32,
+        expires_at: u64,
+    ) -> merkle_distribution::MerkleDistribution {
+        merkle_distribution::commit_distribution(
+            &env,
+            &program_id,
+            root,
+            total_amount,
+            leaf_count,
+            expires_at,
+        )
     }
 
-    pub fn get_split_config(env: Env, program_id: String) -> Option<SplitConfig> {
-        payout_splits::get_split_config(&env, &program_id)
+    /// Claim leaf `index` of the program's committed distribution. `index` and
+    /// `recipient` are part of the leaf so the claim can be tracked in the
+    /// bitmap and authorized by the recipient.
+    pub fn claim_distribution(
+        env: Env,
+        program_id: String,
+        recipient: Address,
+        index: u32,
+        amount: i128,
+        proof: Vec<BytesN<32>>,
+    ) -> ProgramData {
+        merkle_distribution::claim_distribution(&env, &program_id, &recipient, index, amount, proof)
     }
 
-    pub fn disable_split_config(env: Env, program_id: String) {
-        payout_splits::disable_split_config(&env, &program_id)
+    /// Return the unclaimed remainder of an expired distribution to the
+    /// program's balance, so the next round can be committed.
+    pub fn sweep_distribution(env: Env, program_id: String) -> ProgramData {
+        merkle_distribution::sweep_distribution(&env, &program_id)
     }
 
-    pub fn execute_split_payout(
+    pub fn get_distribution(
         env: Env,
         program_id: String,
-        total_amount: i128,
-    ) -> SplitPayoutResult {
-        payout_splits::execute_split_payout(&env, &program_id, total_amount)
+    ) -> Option<merkle_distribution::MerkleDistribution> {
+        merkle_distribution::get_distribution(&env, &program_id)
     }
 
-    pub fn preview_split(
+    pub fn is_distribution_claimed(env: Env, program_id: String, index: u32) -> bool {
+        merkle_distribution::is_claimed(&env, &program_id, index)
+    }
+
+    // --- Commit-Reveal Allocation Rounds ---
+
+    pub fn open_allocation_round(
         env: Env,
         program_id: String,
-        total_amount: i128,
-    ) -> soroban_sdk::Vec<BeneficiarySplit> {
-        payout_splits::preview_split(&env, &program_id, total_amount)
+        commit_deadline: u64,
+        reveal_deadline: u64,
+    ) -> commit_reveal::AllocationRound {
+        commit_reveal::open_round(&env, &program_id, commit_deadline, reveal_deadline)
+    }
+
+    pub fn submit_commitment(
+        env: Env,
+        program_id: String,
+        applicant: Address,
+        commitment: BytesN<32>,
+    ) {
+        commit_reveal::submit_commitment(&env, &program_id, &applicant, commitment)
+    }
+
+    pub fn reveal_proposal(
+        env: Env,
+        program_id: String,
+        applicant: Address,
+        proposal: soroban_sdk::Bytes,
+        salt: BytesN<32>,
+    ) -> commit_reveal::ProposalCommitment {
+        commit_reveal::reveal_proposal(&env, &program_id, &applicant, proposal, salt)
+    }
+
+    pub fn get_allocation_round(
+        env: Env,
+        program_id: String,
+    ) -> Option<commit_reveal::AllocationRound> {
+        commit_reveal::get_round(&env, &program_id)
+    }
+
+    pub fn get_commitment(
+        env: Env,
+        program_id: String,
+        applicant: Address,
+    ) -> Option<commit_reveal::ProposalCommitment> {
+        commit_reveal::get_commitment(&env, &program_id, &applicant)
+    }
+
+    pub fn get_revealed_applicants(env: Env, program_id: String) -> Vec<Address> {
+        commit_reveal::get_revealed_applicants(&env, &program_id)
+    }
+
+    // --- Oracle-Conditioned Releases ---
+
+    /// Add or remove `oracle` from the allowlist of oracles that release
+    /// schedules may reference (admin only).
+    pub fn set_oracle_allowed(env: Env, oracle: Address, allowed: bool) {
+        let admin = Self::require_admin(&env);
+        oracle_conditions::set_allowed(&env, &oracle, allowed);
+        audit_trail::record(
+            &env,
+            &admin,
+            symbol_short!("orc_allow"),
+            (oracle, allowed),
+        );
+    }
+
+    pub fn is_oracle_allowed(env: Env, oracle: Address) -> bool {
+        oracle_conditions::is_allowed(&env, &oracle)
+    }
+
+    // --- Global Halt ---
+
+    /// Point this escrow at the grainlify-core contract whose global halt it
+    /// obeys, or pass `None` to stop consulting it (admin only).
+    pub fn set_core_contract(env: Env, core: Option<Address>) {
+        let admin = Self::require_admin(&env);
+        global_halt::set_core_contract(&env, core.clone());
+        audit_trail::record(&env, &admin, symbol_short!("core_set"), (core,));
+    }
+
+    pub fn get_core_contract(env: Env) -> Option<Address> {
+        global_halt::get_core_contract(&env)
+    }
+
+    /// True when the linked core contract has a global halt in effect.
+    pub fn is_globally_halted(env: Env) -> bool {
+        global_halt::is_halted(&env)
+    }
+
+    /// Gate a pending release schedule on an allowlisted oracle's reading.
+    /// Due schedules whose condition does not hold are skipped by
+    /// `trigger_program_releases` until it does.
+    pub fn set_schedule_oracle_condition(
+        env: Env,
+        schedule_id: u64,
+        condition: oracle_conditions::OracleCondition,
+    ) {
+        let program_data = Self::get_program_info(env.clone());
+        program_data.authorized_payout_key.require_auth();
+
+        let schedule = Self::get_program_release_schedule(env.clone(), schedule_id);
+        if schedule.released {
+            panic!("Already released");
+        }
+        oracle_conditions::set_condition(&env, schedule_id, condition);
+    }
+
+    pub fn get_schedule_oracle_condition(
+        env: Env,
+        schedule_id: u64,
+    ) -> Option<oracle_conditions::OracleCondition> {
+        oracle_conditions::get_condition(&env, schedule_id)
+    }
+
+    // --- Inflation-Indexed Releases ---
+
+    /// Register the price feed indexed schedules are converted with (admin
+    /// only). The oracle must be on the oracle allowlist.
+    pub fn set_index_feed(env: Env, feed: indexed_payouts::IndexFeed) {
+        let admin = Self::require_admin(&env);
+        indexed_payouts::set_feed(&env, feed.clone());
+        audit_trail::record(
+            &env,
+            &admin,
+            symbol_short!("idx_feed"),
+            (feed.oracle, feed.feed),
+        );
+    }
+
+    pub fn get_index_feed(env: Env) -> Option<indexed_payouts::IndexFeed> {
+        indexed_payouts::get_feed(&env)
+    }
+
+    /// Treat a pending schedule's `amount` as reference units converted at
+    /// release time, or switch it back to a plain token amount.
+    pub fn set_schedule_indexed(env: Env, schedule_id: u64, indexed: bool) {
+        let program_data = Self::get_program_info(env.clone());
+        program_data.authorized_payout_key.require_auth();
+
+        let schedule = Self::get_program_release_schedule(env.clone(), schedule_id);
+        if schedule.released {
+            panic!("Already released");
+        }
+        indexed_payouts::set_indexed(&env, schedule_id, indexed);
+    }
+
+    pub fn is_schedule_indexed(env: Env, schedule_id: u64) -> bool {
+        indexed_payouts::is_indexed(&env, schedule_id)
+    }
+
+    // --- AMM Payouts ---
+
+    /// Register (or with `None`, remove) the AMM contract `single_payout_as`
+    /// swaps through (admin only).
+    pub fn set_amm(env: Env, amm: Option<Address>) {
+        let admin = Self::require_admin(&env);
+        amm_adapter::set_amm(&env, amm.clone());
+        audit_trail::record(&env, &admin, symbol_short!("set_amm"), amm);
+    }
+
+    pub fn get_amm(env: Env) -> Option<Address> {
+        amm_adapter::get_amm(&env)
+    }
+
+    // --- Idle-Fund Yield ---
+
+    pub fn set_yield_protocol_allowed(env: Env, protocol: Address, allowed: bool) {
+        let admin = Self::require_admin(&env);
+        yield_adapter::set_allowed(&env, &protocol, allowed);
+        audit_trail::record(
+            &env,
+            &admin,
+            symbol_short!("yld_allow"),
+            (protocol, allowed),
+        );
+    }
+
+    pub fn is_yield_protocol_allowed(env: Env, protocol: Address) -> bool {
+        yield_adapter::is_allowed(&env, &protocol)
+    }
+
+    /// Select the allowlisted protocol idle funds are deposited into (admin
+    /// only). Nothing may be deposited with the current protocol.
+    pub fn set_yield_protocol(env: Env, protocol: Option<Address>) {
+        let admin = Self::require_admin(&env);
+        yield_adapter::set_protocol(&env, protocol.clone());
+        audit_trail::record(&env, &admin, symbol_short!("yld_proto"), protocol);
+    }
+
+    /// Deposit `amount` of the program token into the yield protocol (admin
+    /// only). Funds reserved for pending release schedules stay on hand.
+    pub fn deposit_idle(env: Env, amount: i128) {
+        let admin = Self::require_admin(&env);
+        let program_data = Self::get_program_info(env.clone());
+        yield_adapter::deposit(&env, &program_data.token_address, amount);
+        audit_trail::record(&env, &admin, symbol_short!("yld_dep"), amount);
+    }
+
+    /// Withdraw `amount` (principal first, then yield) back to the contract
+    /// (admin only).
+    pub fn withdraw_idle(env: Env, amount: i128) {
+        let admin = Self::require_admin(&env);
+        yield_adapter::withdraw(&env, amount);
+        audit_trail::record(&env, &admin, symbol_short!("yld_wdr"), amount);
+    }
+
+    pub fn get_yield_position(env: Env) -> yield_adapter::YieldPosition {
+        yield_adapter::position(&env)
+    }
+
+    /// Keeper entrypoint: harvest yield above principal and credit realized
+    /// yield to programs pro-rata to their remaining balances. Returns the
+    /// amount credited.
+    pub fn accrue_interest(env: Env) -> i128 {
+        yield_adapter::accrue(&env)
+    }
+
+    /// Total interest credited to `program_id` so far.
+    pub fn get_accrued_interest(env: Env, program_id: String) -> i128 {
+        yield_adapter::get_accrued(&env, &program_id)
+    }
+
+    /// Realized yield held by the contract but not yet credited.
+    pub fn get_unaccrued_yield(env: Env) -> i128 {
+        yield_adapter::get_unaccrued(&env)
+    }
+
+    // --- Fraud Clawback ---
+
+    /// Appoint the compliance officer who may flag and claw back payouts
+    /// (admin only).
+    pub fn set_compliance_officer(env: Env, officer: Address) {
+        let admin = Self::require_admin(&env);
+        clawback::set_officer(&env, &officer);
+        audit_trail::record(&env, &admin, symbol_short!("clw_offcr"), officer);
+    }
+
+    pub fn get_compliance_officer(env: Env) -> Option<Address> {
+        clawback::get_officer(&env)
+    }
+
+    /// Set the timelock between flagging a payout and clawing it back
+    /// (admin only).
+    pub fn set_clawback_delay(env: Env, delay_secs: u64) {
+        let admin = Self::require_admin(&env);
+        clawback::set_delay(&env, delay_secs);
+        audit_trail::record(&env, &admin, symbol_short!("clw_delay"), delay_secs);
+    }
+
+    pub fn get_clawback_delay(env: Env) -> u64 {
+        clawback::get_delay(&env)
+    }
+
+    /// Flag the payout at index `payout_id` of the payout history as
+    /// fraudulent, starting the clawback timelock (compliance officer only).
+    pub fn flag_payout_fraud(env: Env, payout_id: u32, reason: String) -> clawback::FraudFlag {
+        let officer = clawback::require_officer(&env);
+        let program_data = Self::get_program_info(env.clone());
+        let payout = program_data
+            .payout_history
+            .get(payout_id)
+            .unwrap_or_else(|| panic!("Payout not found"));
+        clawback::flag(&env, &officer, payout, payout_id, reason);
+        audit_trail::record(&env, &officer, symbol_short!("clw_flag"), payout_id);
+        clawback::get_flag(&env, payout_id).unwrap()
+    }
+
+    /// Withdraw a fraud flag that has not been executed (compliance officer
+    /// only).
+    pub fn cancel_fraud_flag(env: Env, payout_id: u32) {
+        let officer = clawback::require_officer(&env);
+        clawback::cancel(&env, &officer, payout_id);
+        audit_trail::record(&env, &officer, symbol_short!("clw_cncl"), payout_id);
+    }
+
+    /// Claw a flagged payout back into the escrow once its timelock has
+    /// passed (compliance officer only). The recovered amount is credited to
+    /// the program's remaining balance.
+    pub fn execute_clawback(env: Env, payout_id: u32) -> ProgramData {
+        let officer = clawback::require_officer(&env);
+        let mut program_data = Self::get_program_info(env.clone());
+        let amount = clawback::execute(&env, &officer, &program_data.token_address, payout_id);
+
+        program_data.remaining_balance += amount;
+        env.storage().instance().set(&PROGRAM_DATA, &program_data);
+        audit_trail::record(
+            &env,
+            &officer,
+            symbol_short!("clw_exec"),
+            (payout_id, amount),
+        );
+        program_data
+    }
+
+    pub fn get_fraud_flag(env: Env, payout_id: u32) -> Option<clawback::FraudFlag> {
+        clawback::get_flag(&env, payout_id)
+    }
+
+    // --- Travel Rule ---
+
+    /// Require a travel rule hash on payouts above `threshold` (admin only).
+    /// `None` lifts the requirement.
+    pub fn set_travel_rule_threshold(env: Env, threshold: Option<i128>) {
+        let admin = Self::require_admin(&env);
+        travel_rule::set_threshold(&env, threshold);
+        audit_trail::record(&env, &admin, symbol_short!("trvl_thr"), threshold);
+    }
+
+    pub fn get_travel_rule_threshold(env: Env) -> Option<i128> {
+        travel_rule::get_threshold(&env)
+    }
+
+    /// Travel rule hash attached to the payout at index `payout_id` of the
+    /// payout history.
+    pub fn get_travel_rule_hash(env: Env, payout_id: u32) -> Option<BytesN<32>> {
+        travel_rule::get_hash(&env, payout_id)
+    }
+
+    /// Register the travel rule hash used for payouts to `recipient` that
+    /// take no hash of their own: schedule releases, split payouts,
+    /// distribution claims and parametric claims (payout key only). `None`
+    /// clears it.
+    pub fn set_recipient_travel_rule_hash(
+        env: Env,
+        recipient: Address,
+        travel_rule_hash: Option<BytesN<32>>,
+    ) {
+        let program_data = Self::get_program_info(env.clone());
+        program_data.authorized_payout_key.require_auth();
+        travel_rule::set_recipient_hash(&env, &recipient, travel_rule_hash);
+    }
+
+    pub fn get_recipient_travel_rule_hash(env: Env, recipient: Address) -> Option<BytesN<32>> {
+        travel_rule::get_recipient_hash(&env, &recipient)
+    }
+
+    // --- Parametric Payouts ---
+
+    pub fn define_parametric_policy(
+        env: Env,
+        program_id: String,
+        reporter: Address,
+        bands: Vec<parametric::TriggerBand>,
+        claim_window: u64,
+    ) -> parametric::ParametricPolicy {
+        parametric::define_policy(&env, &program_id, reporter, bands, claim_window)
+    }
+
+    pub fn enroll_parametric_recipient(
+        env: Env,
+        program_id: String,
+        recipient: Address,
+        insured_amount: i128,
+    ) {
+        parametric::enroll(&env, &program_id, &recipient, insured_amount)
+    }
+
+    pub fn report_parametric_index(
+        env: Env,
+        program_id: String,
+        index: i128,
+    ) -> parametric::ParametricPolicy {
+        parametric::report_index(&env, &program_id, index)
+    }
+
+    pub fn claim_parametric_payout(env: Env, program_id: String, recipient: Address) -> ProgramData {
+        parametric::claim(&env, &program_id, &recipient)
+    }
+
+    /// Return unclaimed parametric liability to the program once the claim
+    /// window has closed.
+    pub fn sweep_parametric_policy(env: Env, program_id: String) -> ProgramData {
+        parametric::sweep(&env, &program_id)
+    }
+
+    pub fn get_parametric_policy(
+        env: Env,
+        program_id: String,
+    ) -> Option<parametric::ParametricPolicy> {
+        parametric::get_policy(&env, &program_id)
+    }
+
+    pub fn get_parametric_enrollment(
+        env: Env,
+        program_id: String,
+        recipient: Address,
+    ) -> Option<parametric::ParametricEnrollment> {
+        parametric::get_enrollment(&env, &program_id, &recipient)
+    }
+
+    pub fn get_parametric_claimable(env: Env, program_id: String, recipient: Address) -> i128 {
+        parametric::claimable(&env, &program_id, &recipient)
+    }
+
+    // --- Matching Pools ---
+
+    /// Escrow `cap` from `matcher` to match contributions at `ratio_bps`.
+    pub fn create_matching_pool(
+        env: Env,
+        program_id: String,
+        matcher: Address,
+        ratio_bps: u32,
+        cap: i128,
+    ) -> matching::MatchingPool {
+        matching::create_pool(&env, &program_id, &matcher, ratio_bps, cap)
+    }
+
+    /// Permissionless contribution to a program; matched from the active pool.
+    pub fn contribute(
+        env: Env,
+        program_id: String,
+        contributor: Address,
+        amount: i128,
+    ) -> ProgramData {
+        matching::contribute(&env, &program_id, &contributor, amount)
+    }
+
+    /// Close the matching pool and refund the unmatched remainder to the matcher.
+    pub fn close_matching_pool(env: Env, program_id: String) -> i128 {
+        matching::close_pool(&env, &program_id)
+    }
+
+    pub fn get_matching_pool(env: Env, program_id: String) -> Option<matching::MatchingPool> {
+        matching::get_pool(&env, &program_id)
+    }
+
+    // --- Quadratic Funding ---
+
+    pub fn create_qf_round(
+        env: Env,
+        matching_pool: i128,
+        closes_at: u64,
+    ) -> quadratic_funding::QfRound {
+        quadratic_funding::create_round(&env, matching_pool, closes_at)
+    }
+
+    pub fn register_qf_project(env: Env, round_id: u32, recipient: Address) {
+        quadratic_funding::register_project(&env, round_id, &recipient)
+    }
+
+    pub fn contribute_qf(
+        env: Env,
+        round_id: u32,
+        project: Address,
+        contributor: Address,
+        amount: i128,
+    ) -> quadratic_funding::QfProject {
+        quadratic_funding::contribute(&env, round_id, &project, &contributor, amount)
+    }
+
+    /// Close the round, compute quadratic matches and queue each project's
+    /// payout as a release schedule due at `release_timestamp`.
+    pub fn close_qf_round(
+        env: Env,
+        round_id: u32,
+        release_timestamp: u64,
+    ) -> Vec<quadratic_funding::QfProject> {
+        quadratic_funding::close_round(&env, round_id, release_timestamp)
+    }
+
+    pub fn get_qf_round(env: Env, round_id: u32) -> Option<quadratic_funding::QfRound> {
+        quadratic_funding::get_round(&env, round_id)
+    }
+
+    pub fn get_qf_project(
+        env: Env,
+        round_id: u32,
+        project: Address,
+    ) -> Option<quadratic_funding::QfProject> {
+        quadratic_funding::get_project(&env, round_id, &project)
+    }
+
+    pub fn get_qf_projects(env: Env, round_id: u32) -> Vec<Address> {
+        quadratic_funding::get_projects(&env, round_id)
+    }
+
+    // --- Completion Attestations ---
+
+    /// Page through `recipient`'s completion attestations, oldest first.
+    pub fn get_attestations(
+        env: Env,
+        recipient: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<attestations::Attestation> {
+        attestations::get_page(&env, &recipient, offset, limit)
+    }
+
+    pub fn get_attestation_count(env: Env, recipient: Address) -> u32 {
+        attestations::count(&env, &recipient)
+    }
+
+    // --- Deposit Receipts ---
+
+    pub fn get_receipt(env: Env, receipt_id: u64) -> receipts::DepositReceipt {
+        receipts::get_receipt(&env, receipt_id).unwrap_or_else(|| panic!("Receipt not found"))
+    }
+
+    /// Check a receipt against the amount and depositor an auditor expects.
+    pub fn verify_receipt(
+        env: Env,
+        receipt_id: u64,
+        expected_amount: i128,
+        expected_depositor: Address,
+    ) -> bool {
+        receipts::verify(&env, receipt_id, expected_amount, &expected_depositor)
+    }
+
+    /// Transfer a receipt to `to` (current holder only).
+    pub fn transfer_receipt(env: Env, receipt_id: u64, to: Address) -> receipts::DepositReceipt {
+        receipts::transfer(&env, receipt_id, &to)
+    }
+
+    pub fn get_qf_contribution(
+        env: Env,
+        round_id: u32,
+        project: Address,
+        contributor: Address,
+    ) -> i128 {
+        quadratic_funding::get_contribution(&env, round_id, &project, &contributor)
     }
 
     // ========================================================================
@@ -2803,6 +3948,7 @@ impl ProgramEscrowContract {
         };
 
         env.storage().instance().set(&DataKey::Dispute, &record);
+        reputation::record_dispute(&env);
 
         env.events().publish(
             (DISPUTE_OPENED,),
@@ -2877,6 +4023,11 @@ impl ProgramEscrowContract {
     pub fn get_dispute(env: Env) -> Option<DisputeRecord> {
         env.storage().instance().get(&DataKey::Dispute)
     }
+
+    /// Payout reliability score for the program; see [`reputation`].
+    pub fn get_program_reputation(env: Env) -> reputation::ProgramReputationScore {
+        reputation::score(&env)
+    }
 }
 
 #[cfg(test)]
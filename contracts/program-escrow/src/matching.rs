@@ -0,0 +1,288 @@
+//! # Matching Pools
+//!
+//! Lets a donor (the matcher) pledge to match community contributions to a
+//! program. The matcher escrows `cap` up front with `create_pool`; every
+//! subsequent `contribute` call adds the contribution to the program and tops
+//! it up from the pool by `ratio_bps` of the contribution, until the cap is
+//! exhausted.
+//!
+//! ```text
+//! ratio_bps = 5_000, cap = 1_000
+//! contribute(400)  -> program +400, match +200   (matched 200 / 1_000)
+//! contribute(2000) -> program +2000, match +800  (matched 1_000 / 1_000, exhausted)
+//! contribute(100)  -> program +100, match +0
+//! ```
+//!
+//! The matcher can close the pool at any time and reclaim whatever has not
+//! been matched yet.
+
+use crate::events_log::LogLevel;
+use crate::receipts::{self, DepositKind};
+use crate::token_math::BASIS_POINTS;
+use crate::yield_adapter;
+use crate::{
+    adjust_reserved_obligations, load_program_data, next_event_sequence, ProgramData,
+    ProgramEscrowContract, EVENT_VERSION_V2,
+};
+use soroban_sdk::{contracttype, symbol_short, token, Address, Env, String, Symbol};
+
+const POOL_CREATED: Symbol = symbol_short!("MatchNew");
+const CONTRIBUTION: Symbol = symbol_short!("Contrib");
+const MATCHED: Symbol = symbol_short!("Matched");
+const POOL_CLOSED: Symbol = symbol_short!("MatchEnd");
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MatchingKey {
+    /// program_id -> MatchingPool
+    Pool(String),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MatchingPool {
+    pub program_id: String,
+    pub matcher: Address,
+    pub ratio_bps: u32,
+    pub cap: i128,
+    pub matched: i128,
+    pub active: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolCreatedEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub program_id: String,
+    pub matcher: Address,
+    pub ratio_bps: u32,
+    pub cap: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolClosedEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub program_id: String,
+    pub matcher: Address,
+    pub matched: i128,
+    pub refund: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContributionEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub program_id: String,
+    pub contributor: Address,
+    pub amount: i128,
+    pub matched: i128,
+    pub remaining_balance: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MatchEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub program_id: String,
+    pub matcher: Address,
+    pub contributor: Address,
+    pub amount: i128,
+    pub total_matched: i128,
+    pub cap: i128,
+}
+
+pub fn get_pool(env: &Env, program_id: &String) -> Option<MatchingPool> {
+    env.storage()
+        .persistent()
+        .get(&MatchingKey::Pool(program_id.clone()))
+}
+
+fn store_pool(env: &Env, pool: &MatchingPool) {
+    env.storage()
+        .persistent()
+        .set(&MatchingKey::Pool(pool.program_id.clone()), pool);
+}
+
+/// Match owed for `amount` under `pool`, limited by what is left of the cap.
+pub fn match_amount(pool: &MatchingPool, amount: i128) -> i128 {
+    if !pool.active {
+        return 0;
+    }
+    let uncapped = amount
+        .checked_mul(pool.ratio_bps as i128)
+        .and_then(|x| x.checked_div(BASIS_POINTS))
+        .unwrap_or(i128::MAX);
+    uncapped.min(pool.cap - pool.matched)
+}
+
+/// Escrow `cap` from `matcher` and start matching contributions to
+/// `program_id` at `ratio_bps`. A program has at most one active pool.
+pub fn create_pool(
+    env: &Env,
+    program_id: &String,
+    matcher: &Address,
+    ratio_bps: u32,
+    cap: i128,
+) -> MatchingPool {
+    matcher.require_auth();
+
+    let program_data = load_program_data(env, program_id);
+    if ratio_bps == 0 || cap <= 0 {
+        panic!("Invalid matching parameters");
+    }
+    if let Some(existing) = get_pool(env, program_id) {
+        if existing.active {
+            panic!("Matching pool already active");
+        }
+    }
+
+    let token_client = token::Client::new(env, &program_data.token_address);
+    token_client.transfer(matcher, &env.current_contract_address(), &cap);
+    // The unmatched cap belongs to the matcher, not the program; keep it out
+    // of the idle balance the yield adapter may deploy.
+    adjust_reserved_obligations(env, cap);
+
+    let pool = MatchingPool {
+        program_id: program_id.clone(),
+        matcher: matcher.clone(),
+        ratio_bps,
+        cap,
+        matched: 0,
+        active: true,
+    };
+    store_pool(env, &pool);
+
+    env.events().publish(
+        (LogLevel::Info, POOL_CREATED, program_id.clone()),
+        PoolCreatedEvent {
+            version: EVENT_VERSION_V2,
+            sequence: next_event_sequence(env),
+            program_id: program_id.clone(),
+            matcher: matcher.clone(),
+            ratio_bps,
+            cap,
+        },
+    );
+    pool
+}
+
+/// Add `amount` from `contributor` to the program, plus any match owed.
+pub fn contribute(
+    env: &Env,
+    program_id: &String,
+    contributor: &Address,
+    amount: i128,
+) -> ProgramData {
+    contributor.require_auth();
+
+    if ProgramEscrowContract::check_paused(env, symbol_short!("lock")) {
+        panic!("Funds Paused");
+    }
+    if amount <= 0 {
+        panic!("Amount must be greater than zero");
+    }
+
+    let mut program_data = load_program_data(env, program_id);
+    let token_client = token::Client::new(env, &program_data.token_address);
+    token_client.transfer(contributor, &env.current_contract_address(), &amount);
+
+    let mut matched = 0;
+    if let Some(mut pool) = get_pool(env, program_id) {
+        matched = match_amount(&pool, amount);
+        if matched > 0 {
+            pool.matched += matched;
+            store_pool(env, &pool);
+            adjust_reserved_obligations(env, -matched);
+
+            env.events().publish(
+                (LogLevel::Info, MATCHED),
+                MatchEvent {
+                    version: EVENT_VERSION_V2,
+                    sequence: next_event_sequence(env),
+                    program_id: program_id.clone(),
+                    matcher: pool.matcher.clone(),
+                    contributor: contributor.clone(),
+                    amount: matched,
+                    total_matched: pool.matched,
+                    cap: pool.cap,
+                },
+            );
+        }
+    }
+
+    let credited = amount
+        .checked_add(matched)
+        .unwrap_or_else(|| panic!("Total funds overflow"));
+    program_data.total_funds = program_data
+        .total_funds
+        .checked_add(credited)
+        .unwrap_or_else(|| panic!("Total funds overflow"));
+    program_data.remaining_balance = program_data
+        .remaining_balance
+        .checked_add(credited)
+        .unwrap_or_else(|| panic!("Remaining balance overflow"));
+    ProgramEscrowContract::store_program_data(env, program_id, &program_data);
+    receipts::issue(
+        env,
+        program_id,
+        contributor,
+        amount,
+        DepositKind::Contribution,
+    );
+
+    env.events().publish(
+        (LogLevel::Info, CONTRIBUTION),
+        ContributionEvent {
+            version: EVENT_VERSION_V2,
+            sequence: next_event_sequence(env),
+            program_id: program_id.clone(),
+            contributor: contributor.clone(),
+            amount,
+            matched,
+            remaining_balance: program_data.remaining_balance,
+        },
+    );
+    program_data
+}
+
+/// Stop matching and return the unmatched part of the cap to the matcher.
+pub fn close_pool(env: &Env, program_id: &String) -> i128 {
+    let mut pool = get_pool(env, program_id).unwrap_or_else(|| panic!("No matching pool"));
+    pool.matcher.require_auth();
+    if ProgramEscrowContract::check_paused(env, symbol_short!("refund")) {
+        panic!("Funds Paused");
+    }
+    if !pool.active {
+        panic!("Matching pool closed");
+    }
+
+    let refund = pool.cap - pool.matched;
+    pool.active = false;
+    store_pool(env, &pool);
+
+    if refund > 0 {
+        adjust_reserved_obligations(env, -refund);
+        let program_data = load_program_data(env, program_id);
+        yield_adapter::ensure_liquid(env, &program_data.token_address, refund);
+        let token_client = token::Client::new(env, &program_data.token_address);
+        token_client.transfer(&env.current_contract_address(), &pool.matcher, &refund);
+    }
+
+    env.events().publish(
+        (LogLevel::Info, POOL_CLOSED, program_id.clone()),
+        PoolClosedEvent {
+            version: EVENT_VERSION_V2,
+            sequence: next_event_sequence(env),
+            program_id: program_id.clone(),
+            matcher: pool.matcher.clone(),
+            matched: pool.matched,
+            refund,
+        },
+    );
+    refund
+}
@@ -0,0 +1,370 @@
+//! # Merkle Distribution
+//!
+//! Bulk payouts for programs with thousands of beneficiaries. Instead of one
+//! schedule per recipient, the program's authorized payout key commits a
+//! single Merkle root over `(index, recipient, amount)` leaves and each
+//! recipient pulls their own payout with `claim_distribution`.
+//!
+//! ## Hashing
+//!
+//! ```text
+//! leaf = sha256(xdr((index: u32, recipient: Address, amount: i128)))
+//! node = sha256(min(a, b) || max(a, b))
+//! ```
+//!
+//! Pairs are hashed in sorted order, so proofs carry only sibling hashes and
+//! no left/right flags.
+//!
+//! ## Claim tracking
+//!
+//! Claimed leaves are tracked in a bitmap of `u32` words keyed by round and
+//! `index / 32`, so each claim touches a single storage entry regardless of
+//! distribution size, and a new round starts with a clean bitmap.
+//!
+//! ## Funding
+//!
+//! Committing a root reserves `total_amount`: it leaves the program's
+//! `remaining_balance` at commit time and is held as an outstanding
+//! obligation that each claim draws down, so other payouts cannot spend funds
+//! the distribution still owes.
+//!
+//! ## Expiry and rounds
+//!
+//! Every root carries an `expires_at` timestamp. Claims are rejected from
+//! then on, and anyone may call `sweep_distribution` to return the unclaimed
+//! remainder to the program's `remaining_balance` and release its
+//! reservation. Once a round is swept or fully claimed, the payout key can
+//! commit the next root.
+
+use crate::events_log::LogLevel;
+use crate::{
+    adjust_reserved_obligations, load_program_data, next_event_sequence, reentrancy_guard,
+    travel_rule, yield_adapter, PayoutRecord, ProgramData, ProgramEscrowContract,
+};
+use soroban_sdk::{
+    contracttype, symbol_short, token, xdr::ToXdr, Address, Bytes, BytesN, Env, String, Symbol, Vec,
+};
+
+const DISTRIBUTION_COMMITTED: Symbol = symbol_short!("MrklRoot");
+const DISTRIBUTION_CLAIMED: Symbol = symbol_short!("MrklClm");
+const DISTRIBUTION_SWEPT: Symbol = symbol_short!("MrklSwp");
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DistributionKey {
+    /// program_id -> MerkleDistribution
+    Distribution(String),
+    /// (program_id, round, word index) -> u32 claimed bitmap
+    ClaimedWord(String, u32, u32),
+}
+
+/// Committed distribution for a program.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MerkleDistribution {
+    pub program_id: String,
+    pub root: BytesN<32>,
+    pub total_amount: i128,
+    pub claimed_amount: i128,
+    pub leaf_count: u32,
+    pub committed_at: u64,
+    /// Starts at 1 and increases with each root committed for the program.
+    pub round: u32,
+    /// Claims are rejected at or after this timestamp.
+    pub expires_at: u64,
+    /// Set once the unclaimed remainder has been returned to the program.
+    pub swept: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DistributionCommittedEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub program_id: String,
+    pub root: BytesN<32>,
+    pub total_amount: i128,
+    pub leaf_count: u32,
+    pub round: u32,
+    pub expires_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DistributionClaimedEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub program_id: String,
+    pub index: u32,
+    pub recipient: Address,
+    pub amount: i128,
+    pub remaining_balance: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DistributionSweptEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub program_id: String,
+    pub round: u32,
+    pub amount: i128,
+    pub remaining_balance: i128,
+}
+
+/// Hash of a single distribution leaf.
+pub fn leaf_hash(env: &Env, index: u32, recipient: &Address, amount: i128) -> BytesN<32> {
+    let encoded = (index, recipient.clone(), amount).to_xdr(env);
+    env.crypto().sha256(&encoded).into()
+}
+
+/// Hash of two sibling nodes, order-independent.
+pub fn hash_pair(env: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (first, second) = if a.to_array() <= b.to_array() {
+        (a, b)
+    } else {
+        (b, a)
+    };
+    let mut data = Bytes::new(env);
+    data.append(&Bytes::from(first.clone()));
+    data.append(&Bytes::from(second.clone()));
+    env.crypto().sha256(&data).into()
+}
+
+fn verify_proof(env: &Env, proof: &Vec<BytesN<32>>, root: &BytesN<32>, leaf: BytesN<32>) -> bool {
+    let mut computed = leaf;
+    for sibling in proof.iter() {
+        computed = hash_pair(env, &computed, &sibling);
+    }
+    &computed == root
+}
+
+fn claimed_word(env: &Env, program_id: &String, round: u32, word: u32) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DistributionKey::ClaimedWord(
+            program_id.clone(),
+            round,
+            word,
+        ))
+        .unwrap_or(0)
+}
+
+fn is_settled(distribution: &MerkleDistribution) -> bool {
+    distribution.swept || distribution.claimed_amount == distribution.total_amount
+}
+
+pub fn get_distribution(env: &Env, program_id: &String) -> Option<MerkleDistribution> {
+    env.storage()
+        .persistent()
+        .get(&DistributionKey::Distribution(program_id.clone()))
+}
+
+/// Whether leaf `index` of the program's current round has been claimed.
+pub fn is_claimed(env: &Env, program_id: &String, index: u32) -> bool {
+    let round = match get_distribution(env, program_id) {
+        Some(distribution) => distribution.round,
+        None => return false,
+    };
+    claimed_word(env, program_id, round, index / 32) & (1 << (index % 32)) != 0
+}
+
+/// Commit the Merkle root for a program's distribution.
+///
+/// Only the program's authorized payout key may commit, `total_amount` must
+/// fit within the remaining balance and is reserved out of it, and
+/// `expires_at` must lie in the future. A new root replaces the previous one
+/// only after that round has been swept or fully claimed.
+pub fn commit_distribution(
+    env: &Env,
+    program_id: &String,
+    root: BytesN<32>,
+    total_amount: i128,
+    leaf_count: u32,
+    expires_at: u64,
+) -> MerkleDistribution {
+    let mut program_data = load_program_data(env, program_id);
+    program_data.authorized_payout_key.require_auth();
+
+    let round = match get_distribution(env, program_id) {
+        Some(previous) if !is_settled(&previous) => panic!("Distribution already committed"),
+        Some(previous) => previous.round + 1,
+        None => 1,
+    };
+    if total_amount <= 0 || leaf_count == 0 {
+        panic!("Invalid distribution parameters");
+    }
+    if expires_at <= env.ledger().timestamp() {
+        panic!("Invalid expiry");
+    }
+    if total_amount > program_data.remaining_balance {
+        panic!("Insufficient balance");
+    }
+
+    let distribution = MerkleDistribution {
+        program_id: program_id.clone(),
+        root: root.clone(),
+        total_amount,
+        claimed_amount: 0,
+        leaf_count,
+        committed_at: env.ledger().timestamp(),
+        round,
+        expires_at,
+        swept: false,
+    };
+    env.storage().persistent().set(
+        &DistributionKey::Distribution(program_id.clone()),
+        &distribution,
+    );
+    program_data.remaining_balance -= total_amount;
+    ProgramEscrowContract::store_program_data(env, program_id, &program_data);
+    adjust_reserved_obligations(env, total_amount);
+
+    env.events().publish(
+        (LogLevel::Info, DISTRIBUTION_COMMITTED),
+        DistributionCommittedEvent {
+            version: crate::EVENT_VERSION_V2,
+            sequence: next_event_sequence(env),
+            program_id: program_id.clone(),
+            root,
+            total_amount,
+            leaf_count,
+            round,
+            expires_at,
+        },
+    );
+
+    distribution
+}
+
+/// Pay out leaf `index` to `recipient` after verifying `proof` against the
+/// committed root. Each leaf can be claimed exactly once, and only before the
+/// round expires.
+pub fn claim_distribution(
+    env: &Env,
+    program_id: &String,
+    recipient: &Address,
+    index: u32,
+    amount: i128,
+    proof: Vec<BytesN<32>>,
+) -> ProgramData {
+    reentrancy_guard::check_not_entered(env);
+    reentrancy_guard::set_entered(env);
+
+    recipient.require_auth();
+
+    if ProgramEscrowContract::check_paused(env, symbol_short!("release")) {
+        panic!("Funds Paused");
+    }
+
+    let mut distribution =
+        get_distribution(env, program_id).unwrap_or_else(|| panic!("No distribution committed"));
+    if distribution.swept || env.ledger().timestamp() >= distribution.expires_at {
+        panic!("Distribution expired");
+    }
+    if index >= distribution.leaf_count {
+        panic!("Leaf index out of range");
+    }
+    if is_claimed(env, program_id, index) {
+        panic!("Already claimed");
+    }
+
+    let leaf = leaf_hash(env, index, recipient, amount);
+    if !verify_proof(env, &proof, &distribution.root, leaf) {
+        panic!("Invalid proof");
+    }
+
+    if distribution.claimed_amount + amount > distribution.total_amount {
+        panic!("Distribution exhausted");
+    }
+    let mut program_data = load_program_data(env, program_id);
+
+    // Effects before the external transfer.
+    let word = index / 32;
+    let bits = claimed_word(env, program_id, distribution.round, word) | (1 << (index % 32));
+    env.storage().persistent().set(
+        &DistributionKey::ClaimedWord(program_id.clone(), distribution.round, word),
+        &bits,
+    );
+
+    distribution.claimed_amount += amount;
+    env.storage().persistent().set(
+        &DistributionKey::Distribution(program_id.clone()),
+        &distribution,
+    );
+
+    travel_rule::apply_registered(
+        env,
+        program_id,
+        program_data.payout_history.len(),
+        recipient,
+        amount,
+    );
+    program_data.payout_history.push_back(PayoutRecord {
+        recipient: recipient.clone(),
+        amount,
+        timestamp: env.ledger().timestamp(),
+    });
+    ProgramEscrowContract::store_program_data(env, program_id, &program_data);
+
+    adjust_reserved_obligations(env, -amount);
+    yield_adapter::ensure_liquid(env, &program_data.token_address, amount);
+    let token_client = token::Client::new(env, &program_data.token_address);
+    token_client.transfer(&env.current_contract_address(), recipient, &amount);
+
+    env.events().publish(
+        (LogLevel::Info, DISTRIBUTION_CLAIMED),
+        DistributionClaimedEvent {
+            version: crate::EVENT_VERSION_V2,
+            sequence: next_event_sequence(env),
+            program_id: program_id.clone(),
+            index,
+            recipient: recipient.clone(),
+            amount,
+            remaining_balance: program_data.remaining_balance,
+        },
+    );
+
+    reentrancy_guard::clear_entered(env);
+    program_data
+}
+
+/// Close an expired round: the unclaimed remainder goes back to the program's
+/// `remaining_balance` and its reservation is released. Anyone may sweep, since
+/// the funds can only return to the program.
+pub fn sweep_distribution(env: &Env, program_id: &String) -> ProgramData {
+    let mut distribution =
+        get_distribution(env, program_id).unwrap_or_else(|| panic!("No distribution committed"));
+    if distribution.swept {
+        panic!("Distribution already swept");
+    }
+    if env.ledger().timestamp() < distribution.expires_at {
+        panic!("Distribution not expired");
+    }
+
+    let unclaimed = distribution.total_amount - distribution.claimed_amount;
+    distribution.swept = true;
+    env.storage().persistent().set(
+        &DistributionKey::Distribution(program_id.clone()),
+        &distribution,
+    );
+
+    let mut program_data = load_program_data(env, program_id);
+    program_data.remaining_balance += unclaimed;
+    ProgramEscrowContract::store_program_data(env, program_id, &program_data);
+    adjust_reserved_obligations(env, -unclaimed);
+
+    env.events().publish(
+        (LogLevel::Info, DISTRIBUTION_SWEPT),
+        DistributionSweptEvent {
+            version: crate::EVENT_VERSION_V2,
+            sequence: next_event_sequence(env),
+            program_id: program_id.clone(),
+            round: distribution.round,
+            amount: unclaimed,
+            remaining_balance: program_data.remaining_balance,
+        },
+    );
+
+    program_data
+}
@@ -0,0 +1,158 @@
+//! # Oracle-Conditioned Releases
+//!
+//! Lets a release schedule depend on an external data feed, e.g. release a
+//! drought-relief tranche only while a rainfall index is below a threshold,
+//! or a price-support payment only while a commodity price is above one.
+//!
+//! A condition references an oracle contract, a feed symbol, a comparator and
+//! a threshold. When `trigger_program_releases` reaches a due schedule that
+//! carries a condition, it invokes `get_value(feed) -> i128` on the oracle and
+//! releases only if the comparison holds. Otherwise the schedule stays
+//! pending and is re-evaluated on the next trigger.
+//!
+//! Only oracles on the admin-managed allowlist can be attached to schedules.
+//! Removing an oracle from the allowlist makes its conditions evaluate to
+//! false, so a compromised feed can be cut off without editing schedules.
+//!
+//! The whole capability is also behind grainlify-core's `oracle` feature flag
+//! when a core contract is linked (see `global_halt`): with the flag off, no
+//! condition can be attached and existing conditions evaluate to false.
+
+use crate::events_log::LogLevel;
+use crate::{global_halt, next_event_sequence, EVENT_VERSION_V2};
+use soroban_sdk::{contracttype, symbol_short, vec, Address, Env, IntoVal, Symbol};
+
+const ORACLE_ALLOWLIST_UPDATED: Symbol = symbol_short!("OrcAllow");
+const ORACLE_CONDITION_SET: Symbol = symbol_short!("OrcCond");
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum OracleKey {
+    /// oracle address -> bool
+    Allowed(Address),
+    /// schedule_id -> OracleCondition
+    Condition(u64),
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OracleComparator {
+    /// Holds when the reported value is strictly below the threshold.
+    Below,
+    /// Holds when the reported value is strictly above the threshold.
+    Above,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleCondition {
+    pub oracle: Address,
+    pub feed: Symbol,
+    pub comparator: OracleComparator,
+    pub threshold: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleAllowlistEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub oracle: Address,
+    pub allowed: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleConditionEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub schedule_id: u64,
+    pub condition: OracleCondition,
+}
+
+pub fn is_allowed(env: &Env, oracle: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&OracleKey::Allowed(oracle.clone()))
+        .unwrap_or(false)
+}
+
+pub fn set_allowed(env: &Env, oracle: &Address, allowed: bool) {
+    let key = OracleKey::Allowed(oracle.clone());
+    if allowed {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+
+    env.events().publish(
+        (LogLevel::Info, ORACLE_ALLOWLIST_UPDATED),
+        OracleAllowlistEvent {
+            version: EVENT_VERSION_V2,
+            sequence: next_event_sequence(env),
+            oracle: oracle.clone(),
+            allowed,
+        },
+    );
+}
+
+pub fn get_condition(env: &Env, schedule_id: u64) -> Option<OracleCondition> {
+    env.storage()
+        .persistent()
+        .get(&OracleKey::Condition(schedule_id))
+}
+
+/// Attach `condition` to `schedule_id`. The oracle must be allowlisted and
+/// the `oracle` feature enabled.
+pub fn set_condition(env: &Env, schedule_id: u64, condition: OracleCondition) {
+    if !global_halt::is_feature_enabled(env, global_halt::ORACLE_FEATURE) {
+        panic!("Feature disabled");
+    }
+    if !is_allowed(env, &condition.oracle) {
+        panic!("Oracle not allowlisted");
+    }
+    env.storage()
+        .persistent()
+        .set(&OracleKey::Condition(schedule_id), &condition);
+
+    env.events().publish(
+        (LogLevel::Info, ORACLE_CONDITION_SET),
+        OracleConditionEvent {
+            version: EVENT_VERSION_V2,
+            sequence: next_event_sequence(env),
+            schedule_id,
+            condition,
+        },
+    );
+}
+
+/// Returns whether `schedule_id` may be released now.
+///
+/// Schedules without a condition always pass. A delisted oracle, one whose
+/// `get_value` call fails, or a disabled `oracle` feature is treated as the
+/// condition not holding.
+pub fn condition_holds(env: &Env, schedule_id: u64) -> bool {
+    let condition = match get_condition(env, schedule_id) {
+        Some(condition) => condition,
+        None => return true,
+    };
+    if !is_allowed(env, &condition.oracle)
+        || !global_halt::is_feature_enabled(env, global_halt::ORACLE_FEATURE)
+    {
+        return false;
+    }
+
+    let value = match env.try_invoke_contract::<i128, soroban_sdk::Error>(
+        &condition.oracle,
+        &Symbol::new(env, "get_value"),
+        vec![env, condition.feed.into_val(env)],
+    ) {
+        Ok(Ok(value)) => value,
+        _ => return false,
+    };
+
+    match condition.comparator {
+        OracleComparator::Below => value < condition.threshold,
+        OracleComparator::Above => value > condition.threshold,
+    }
+}
@@ -0,0 +1,469 @@
+//! # Parametric Payouts
+//!
+//! Crop-insurance style payouts driven by a single reported index instead of
+//! per-recipient loss assessment. A program defines trigger bands mapping an
+//! index range to a payout percentage, enrolls recipients with an insured
+//! amount, and names a reporter (typically an oracle operator) who posts the
+//! season's index once. Every enrolled recipient can then claim
+//! `insured_amount * payout_bps / 10_000`.
+//!
+//! ```text
+//! bands:  [min_index, max_index) -> payout_bps
+//!         [0,   50)   -> 10_000   severe drought, full payout
+//!         [50,  80)   ->  5_000   moderate drought
+//!         index >= 80 ->      0   no band matches, nothing to claim
+//! ```
+//!
+//! Enrollment is closed once the index is reported. Each enrollment reserves
+//! its liability at the highest band percentage out of the program's
+//! remaining balance, so other payouts cannot spend it. Reporting the index
+//! shrinks the reservation to the liability at the selected percentage and
+//! returns the excess to the remaining balance; an index that matches no band
+//! returns the whole reservation. Claims then pay out of what is left.
+//!
+//! Claims close `claim_window` seconds after the report. From then on anyone
+//! can call `sweep` to return the unclaimed liability to the program.
+
+use crate::events_log::LogLevel;
+use crate::token_math::BASIS_POINTS;
+use crate::{
+    adjust_reserved_obligations, load_program_data, next_event_sequence, reentrancy_guard,
+    travel_rule, yield_adapter, PayoutRecord, ProgramData, ProgramEscrowContract, EVENT_VERSION_V2,
+};
+use soroban_sdk::{contracttype, symbol_short, token, Address, Env, String, Symbol, Vec};
+
+const POLICY_DEFINED: Symbol = symbol_short!("ParPol");
+const INDEX_REPORTED: Symbol = symbol_short!("ParIdx");
+const PARAMETRIC_CLAIMED: Symbol = symbol_short!("ParClm");
+const PARAMETRIC_SWEPT: Symbol = symbol_short!("ParSwp");
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParametricKey {
+    /// program_id -> ParametricPolicy
+    Policy(String),
+    /// (program_id, recipient) -> ParametricEnrollment
+    Enrollment(String, Address),
+}
+
+/// Index range `[min_index, max_index)` paying `payout_bps` of the insured amount.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TriggerBand {
+    pub min_index: i128,
+    pub max_index: i128,
+    pub payout_bps: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParametricPolicy {
+    pub program_id: String,
+    pub reporter: Address,
+    pub bands: Vec<TriggerBand>,
+    pub total_insured: i128,
+    /// Liability still held for unpaid enrollments: at the highest band until
+    /// the index is reported, at the reported percentage afterwards.
+    pub reserved: i128,
+    pub enrolled_count: u32,
+    pub reported_index: Option<i128>,
+    pub reported_at: Option<u64>,
+    /// Payout percentage selected by the reported index; 0 until reported.
+    pub payout_bps: u32,
+    /// Seconds after the report during which enrollments can claim.
+    pub claim_window: u64,
+    /// `reported_at + claim_window`; claims are rejected from then on.
+    pub claim_deadline: Option<u64>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParametricEnrollment {
+    pub recipient: Address,
+    pub insured_amount: i128,
+    pub claimed: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PolicyDefinedEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub program_id: String,
+    pub reporter: Address,
+    pub bands: Vec<TriggerBand>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IndexReportedEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub program_id: String,
+    pub index: i128,
+    pub payout_bps: u32,
+    pub reporter: Address,
+    pub claim_deadline: u64,
+    /// Reservation returned to the remaining balance by the report.
+    pub released: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParametricClaimEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub program_id: String,
+    pub recipient: Address,
+    pub insured_amount: i128,
+    pub amount: i128,
+    pub remaining_balance: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParametricSweptEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub program_id: String,
+    pub amount: i128,
+    pub remaining_balance: i128,
+}
+
+/// Payout percentage for `index`, or 0 when no band contains it.
+pub fn payout_bps_for(bands: &Vec<TriggerBand>, index: i128) -> u32 {
+    for band in bands.iter() {
+        if index >= band.min_index && index < band.max_index {
+            return band.payout_bps;
+        }
+    }
+    0
+}
+
+fn max_payout_bps(bands: &Vec<TriggerBand>) -> u32 {
+    bands.iter().map(|b| b.payout_bps).max().unwrap_or(0)
+}
+
+fn payout_amount(insured_amount: i128, payout_bps: u32) -> i128 {
+    insured_amount
+        .checked_mul(payout_bps as i128)
+        .and_then(|x| x.checked_div(BASIS_POINTS))
+        .unwrap_or_else(|| panic!("Payout overflow"))
+}
+
+/// Bands must be non-empty, non-degenerate, sorted ascending, non-overlapping
+/// and pay at most 100%.
+fn validate_bands(bands: &Vec<TriggerBand>) {
+    if bands.is_empty() {
+        panic!("Invalid trigger bands");
+    }
+    let mut previous_max: Option<i128> = None;
+    for band in bands.iter() {
+        if band.min_index >= band.max_index || band.payout_bps as i128 > BASIS_POINTS {
+            panic!("Invalid trigger bands");
+        }
+        if let Some(prev) = previous_max {
+            if band.min_index < prev {
+                panic!("Invalid trigger bands");
+            }
+        }
+        previous_max = Some(band.max_index);
+    }
+}
+
+pub fn get_policy(env: &Env, program_id: &String) -> Option<ParametricPolicy> {
+    env.storage()
+        .persistent()
+        .get(&ParametricKey::Policy(program_id.clone()))
+}
+
+fn require_policy(env: &Env, program_id: &String) -> ParametricPolicy {
+    get_policy(env, program_id).unwrap_or_else(|| panic!("No parametric policy"))
+}
+
+fn store_policy(env: &Env, policy: &ParametricPolicy) {
+    env.storage()
+        .persistent()
+        .set(&ParametricKey::Policy(policy.program_id.clone()), policy);
+}
+
+pub fn get_enrollment(
+    env: &Env,
+    program_id: &String,
+    recipient: &Address,
+) -> Option<ParametricEnrollment> {
+    env.storage().persistent().get(&ParametricKey::Enrollment(
+        program_id.clone(),
+        recipient.clone(),
+    ))
+}
+
+/// Amount `recipient` can currently claim; 0 before the index is reported,
+/// after the claim deadline, after claiming, or when not enrolled.
+pub fn claimable(env: &Env, program_id: &String, recipient: &Address) -> i128 {
+    let policy = match get_policy(env, program_id) {
+        Some(policy) if policy.reported_index.is_some() && !claims_closed(env, &policy) => policy,
+        _ => return 0,
+    };
+    match get_enrollment(env, program_id, recipient) {
+        Some(enrollment) if !enrollment.claimed => {
+            payout_amount(enrollment.insured_amount, policy.payout_bps)
+        }
+        _ => 0,
+    }
+}
+
+fn claims_closed(env: &Env, policy: &ParametricPolicy) -> bool {
+    match policy.claim_deadline {
+        Some(deadline) => env.ledger().timestamp() >= deadline,
+        None => false,
+    }
+}
+
+/// Define the program's trigger bands, index reporter and claim window
+/// (payout key only).
+pub fn define_policy(
+    env: &Env,
+    program_id: &String,
+    reporter: Address,
+    bands: Vec<TriggerBand>,
+    claim_window: u64,
+) -> ParametricPolicy {
+    let program_data = load_program_data(env, program_id);
+    program_data.authorized_payout_key.require_auth();
+
+    if get_policy(env, program_id).is_some() {
+        panic!("Parametric policy already defined");
+    }
+    validate_bands(&bands);
+    if claim_window == 0 {
+        panic!("Invalid claim window");
+    }
+
+    let policy = ParametricPolicy {
+        program_id: program_id.clone(),
+        reporter,
+        bands,
+        total_insured: 0,
+        reserved: 0,
+        enrolled_count: 0,
+        reported_index: None,
+        reported_at: None,
+        payout_bps: 0,
+        claim_window,
+        claim_deadline: None,
+    };
+    store_policy(env, &policy);
+
+    env.events().publish(
+        (LogLevel::Info, POLICY_DEFINED, program_id.clone()),
+        PolicyDefinedEvent {
+            version: EVENT_VERSION_V2,
+            sequence: next_event_sequence(env),
+            program_id: program_id.clone(),
+            reporter: policy.reporter.clone(),
+            bands: policy.bands.clone(),
+        },
+    );
+    policy
+}
+
+/// Enroll `recipient` with `insured_amount` (payout key only, before the
+/// index is reported).
+pub fn enroll(env: &Env, program_id: &String, recipient: &Address, insured_amount: i128) {
+    let mut program_data = load_program_data(env, program_id);
+    program_data.authorized_payout_key.require_auth();
+
+    let mut policy = require_policy(env, program_id);
+    if policy.reported_index.is_some() {
+        panic!("Enrollment closed");
+    }
+    if insured_amount <= 0 {
+        panic!("Amount must be greater than zero");
+    }
+    let key = ParametricKey::Enrollment(program_id.clone(), recipient.clone());
+    if env.storage().persistent().has(&key) {
+        panic!("Already enrolled");
+    }
+
+    let total_insured = policy
+        .total_insured
+        .checked_add(insured_amount)
+        .unwrap_or_else(|| panic!("Payout overflow"));
+    let liability = payout_amount(insured_amount, max_payout_bps(&policy.bands));
+    if liability > program_data.remaining_balance {
+        panic!("Insufficient balance");
+    }
+
+    env.storage().persistent().set(
+        &key,
+        &ParametricEnrollment {
+            recipient: recipient.clone(),
+            insured_amount,
+            claimed: false,
+        },
+    );
+    policy.total_insured = total_insured;
+    policy.reserved += liability;
+    policy.enrolled_count += 1;
+    store_policy(env, &policy);
+    program_data.remaining_balance -= liability;
+    ProgramEscrowContract::store_program_data(env, program_id, &program_data);
+    adjust_reserved_obligations(env, liability);
+}
+
+/// Post the season's index (reporter only, once). Fixes the payout
+/// percentage for every enrollment, releases the reservation held above it
+/// and opens the claim window.
+pub fn report_index(env: &Env, program_id: &String, index: i128) -> ParametricPolicy {
+    let mut policy = require_policy(env, program_id);
+    policy.reporter.require_auth();
+
+    if policy.reported_index.is_some() {
+        panic!("Index already reported");
+    }
+
+    let now = env.ledger().timestamp();
+    policy.payout_bps = payout_bps_for(&policy.bands, index);
+    policy.reported_index = Some(index);
+    policy.reported_at = Some(now);
+    policy.claim_deadline = Some(now.saturating_add(policy.claim_window));
+
+    // Each claim rounds down, so the liability on the total covers every
+    // enrollment. With no matching band it is 0 and everything is released.
+    let liability = payout_amount(policy.total_insured, policy.payout_bps);
+    let released = policy.reserved - liability;
+    if released > 0 {
+        let mut program_data = load_program_data(env, program_id);
+        program_data.remaining_balance += released;
+        ProgramEscrowContract::store_program_data(env, program_id, &program_data);
+        adjust_reserved_obligations(env, -released);
+        policy.reserved = liability;
+    }
+    store_policy(env, &policy);
+
+    env.events().publish(
+        (LogLevel::Info, INDEX_REPORTED, program_id.clone()),
+        IndexReportedEvent {
+            version: EVENT_VERSION_V2,
+            sequence: next_event_sequence(env),
+            program_id: program_id.clone(),
+            index,
+            payout_bps: policy.payout_bps,
+            reporter: policy.reporter.clone(),
+            claim_deadline: now.saturating_add(policy.claim_window),
+            released,
+        },
+    );
+    policy
+}
+
+/// Pay `recipient` their share at the reported percentage.
+pub fn claim(env: &Env, program_id: &String, recipient: &Address) -> ProgramData {
+    reentrancy_guard::check_not_entered(env);
+    reentrancy_guard::set_entered(env);
+
+    recipient.require_auth();
+
+    if ProgramEscrowContract::check_paused(env, symbol_short!("release")) {
+        panic!("Funds Paused");
+    }
+
+    let mut policy = require_policy(env, program_id);
+    if policy.reported_index.is_none() {
+        panic!("Index not reported");
+    }
+    if claims_closed(env, &policy) {
+        panic!("Claim window closed");
+    }
+    let key = ParametricKey::Enrollment(program_id.clone(), recipient.clone());
+    let mut enrollment: ParametricEnrollment = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| panic!("Not enrolled"));
+    if enrollment.claimed {
+        panic!("Already claimed");
+    }
+
+    let amount = payout_amount(enrollment.insured_amount, policy.payout_bps);
+    if amount == 0 {
+        panic!("Nothing to claim");
+    }
+    let mut program_data = load_program_data(env, program_id);
+
+    // Effects before the external transfer.
+    enrollment.claimed = true;
+    env.storage().persistent().set(&key, &enrollment);
+    policy.reserved -= amount;
+    store_policy(env, &policy);
+    adjust_reserved_obligations(env, -amount);
+
+    travel_rule::apply_registered(
+        env,
+        program_id,
+        program_data.payout_history.len(),
+        recipient,
+        amount,
+    );
+    program_data.payout_history.push_back(PayoutRecord {
+        recipient: recipient.clone(),
+        amount,
+        timestamp: env.ledger().timestamp(),
+    });
+    ProgramEscrowContract::store_program_data(env, program_id, &program_data);
+
+    yield_adapter::ensure_liquid(env, &program_data.token_address, amount);
+    let token_client = token::Client::new(env, &program_data.token_address);
+    token_client.transfer(&env.current_contract_address(), recipient, &amount);
+
+    env.events().publish(
+        (LogLevel::Info, PARAMETRIC_CLAIMED),
+        ParametricClaimEvent {
+            version: EVENT_VERSION_V2,
+            sequence: next_event_sequence(env),
+            program_id: program_id.clone(),
+            recipient: recipient.clone(),
+            insured_amount: enrollment.insured_amount,
+            amount,
+            remaining_balance: program_data.remaining_balance,
+        },
+    );
+
+    reentrancy_guard::clear_entered(env);
+    program_data
+}
+
+/// Return the liability left unclaimed after the claim deadline to the
+/// program's remaining balance. Anyone may sweep, since the funds can only
+/// return to the program.
+pub fn sweep(env: &Env, program_id: &String) -> ProgramData {
+    let mut policy = require_policy(env, program_id);
+    if policy.reported_index.is_none() || !claims_closed(env, &policy) {
+        panic!("Claim window open");
+    }
+    if policy.reserved == 0 {
+        panic!("Nothing to sweep");
+    }
+
+    let amount = policy.reserved;
+    policy.reserved = 0;
+    store_policy(env, &policy);
+
+    let mut program_data = load_program_data(env, program_id);
+    program_data.remaining_balance += amount;
+    ProgramEscrowContract::store_program_data(env, program_id, &program_data);
+    adjust_reserved_obligations(env, -amount);
+
+    env.events().publish(
+        (LogLevel::Info, PARAMETRIC_SWEPT),
+        ParametricSweptEvent {
+            version: EVENT_VERSION_V2,
+            sequence: next_event_sequence(env),
+            program_id: program_id.clone(),
+            amount,
+            remaining_balance: program_data.remaining_balance,
+        },
+    );
+    program_data
+}
@@ -321,6 +321,7 @@ pub fn execute_split_payout(
     amounts.set(0, first_amount);
 
     // Transfer and record payouts.
+    crate::yield_adapter::ensure_liquid(env, &program.token_address, total_amount);
     for i in 0..n {
         let entry = config.beneficiaries.get(i).unwrap();
         let amount = amounts.get(i).unwrap();
@@ -331,6 +332,13 @@ pub fn execute_split_payout(
             continue;
         }
 
+        crate::travel_rule::apply_registered(
+            env,
+            program_id,
+            program.payout_history.len(),
+            &entry.recipient,
+            amount,
+        );
         token_client.transfer(&contract_addr, &entry.recipient, &amount);
 
         program.payout_history.push_back(PayoutRecord {
@@ -0,0 +1,408 @@
+//! # Quadratic Funding Rounds
+//!
+//! Community grant rounds where a matching pool is split between projects in
+//! proportion to the breadth of their support rather than its size. During the
+//! round anyone can contribute to a registered project; contributions are
+//! locked into the program's balance. At close each project's ideal match is
+//!
+//! ```text
+//! ideal_j = (Σ_i √c_ij)² − Σ_i c_ij
+//! ```
+//!
+//! using floor integer square roots. If the ideal matches exceed the pool they
+//! are scaled down pro rata (floor), otherwise they are paid in full and the
+//! rest of the pool stays in the program. Each project's contributions plus
+//! match are then queued as a regular release schedule, so payout goes through
+//! `trigger_program_releases` like any other schedule.
+//!
+//! The matching pool is reserved out of the program's remaining balance when
+//! the round is created, and each contribution is reserved as it arrives, so
+//! other payouts cannot spend either while the round is open. Both are
+//! returned to the remaining balance at close to fund the queued schedules.
+//!
+//! Rounds operate on the contract's primary program (the one release
+//! schedules belong to) and are managed by its authorized payout key.
+
+use crate::events_log::LogLevel;
+use crate::receipts::{self, DepositKind};
+use crate::{
+    adjust_reserved_obligations, next_event_sequence, ProgramData, ProgramEscrowContract,
+    EVENT_VERSION_V2,
+};
+use soroban_sdk::{contracttype, symbol_short, token, Address, Env, Symbol, Vec};
+
+const QF_CONTRIBUTION: Symbol = symbol_short!("QfContrib");
+const QF_ROUND_CLOSED: Symbol = symbol_short!("QfClose");
+
+/// Upper bound on projects per round, keeping `close_round` within budget.
+pub const MAX_QF_PROJECTS: u32 = 50;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum QfKey {
+    NextRoundId,
+    /// round_id -> QfRound
+    Round(u32),
+    /// round_id -> Vec<Address> of project recipients, in registration order
+    Projects(u32),
+    /// (round_id, project) -> QfProject
+    Project(u32, Address),
+    /// (round_id, project, contributor) -> i128 cumulative contribution
+    Contribution(u32, Address, Address),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QfRound {
+    pub round_id: u32,
+    pub matching_pool: i128,
+    pub closes_at: u64,
+    pub closed: bool,
+    pub total_contributed: i128,
+    pub total_matched: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QfProject {
+    pub recipient: Address,
+    pub total_contributed: i128,
+    /// Σ √c_i over this project's contributors.
+    pub sqrt_sum: i128,
+    pub contributor_count: u32,
+    /// Set at close.
+    pub matched: i128,
+    /// Release schedule created at close, if anything was owed.
+    pub schedule_id: Option<u64>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QfContributionEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub round_id: u32,
+    pub project: Address,
+    pub contributor: Address,
+    pub amount: i128,
+    pub project_total: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QfRoundClosedEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub round_id: u32,
+    pub total_contributed: i128,
+    pub total_matched: i128,
+    pub project_count: u32,
+}
+
+/// Floor square root of a non-negative value (Newton's method).
+pub fn isqrt(n: i128) -> i128 {
+    if n <= 0 {
+        return 0;
+    }
+    let mut x = n;
+    let mut y = (x + 1) / 2;
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
+}
+
+fn primary_program(env: &Env) -> ProgramData {
+    ProgramEscrowContract::get_program_info(env.clone())
+}
+
+pub fn get_round(env: &Env, round_id: u32) -> Option<QfRound> {
+    env.storage().persistent().get(&QfKey::Round(round_id))
+}
+
+fn require_round(env: &Env, round_id: u32) -> QfRound {
+    get_round(env, round_id).unwrap_or_else(|| panic!("Round not found"))
+}
+
+pub fn get_projects(env: &Env, round_id: u32) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&QfKey::Projects(round_id))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn get_project(env: &Env, round_id: u32, project: &Address) -> Option<QfProject> {
+    env.storage()
+        .persistent()
+        .get(&QfKey::Project(round_id, project.clone()))
+}
+
+pub fn get_contribution(
+    env: &Env,
+    round_id: u32,
+    project: &Address,
+    contributor: &Address,
+) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&QfKey::Contribution(
+            round_id,
+            project.clone(),
+            contributor.clone(),
+        ))
+        .unwrap_or(0)
+}
+
+/// Open a round whose match is paid out of the program's existing balance,
+/// reserving `matching_pool` until the round closes.
+pub fn create_round(env: &Env, matching_pool: i128, closes_at: u64) -> QfRound {
+    let mut program_data = primary_program(env);
+    program_data.authorized_payout_key.require_auth();
+
+    if matching_pool < 0 || closes_at <= env.ledger().timestamp() {
+        panic!("Invalid round parameters");
+    }
+    if matching_pool > program_data.remaining_balance {
+        panic!("Insufficient balance");
+    }
+
+    let round_id: u32 = env
+        .storage()
+        .persistent()
+        .get(&QfKey::NextRoundId)
+        .unwrap_or(1);
+    env.storage()
+        .persistent()
+        .set(&QfKey::NextRoundId, &(round_id + 1));
+
+    let round = QfRound {
+        round_id,
+        matching_pool,
+        closes_at,
+        closed: false,
+        total_contributed: 0,
+        total_matched: 0,
+    };
+    env.storage()
+        .persistent()
+        .set(&QfKey::Round(round_id), &round);
+    program_data.remaining_balance -= matching_pool;
+    ProgramEscrowContract::store_program_data(env, &program_data.program_id, &program_data);
+    adjust_reserved_obligations(env, matching_pool);
+    round
+}
+
+pub fn register_project(env: &Env, round_id: u32, recipient: &Address) {
+    let program_data = primary_program(env);
+    program_data.authorized_payout_key.require_auth();
+
+    let round = require_round(env, round_id);
+    if round.closed {
+        panic!("Round closed");
+    }
+    let mut projects = get_projects(env, round_id);
+    if projects.len() >= MAX_QF_PROJECTS {
+        panic!("Too many projects");
+    }
+    if get_project(env, round_id, recipient).is_some() {
+        panic!("Project already registered");
+    }
+
+    projects.push_back(recipient.clone());
+    env.storage()
+        .persistent()
+        .set(&QfKey::Projects(round_id), &projects);
+    env.storage().persistent().set(
+        &QfKey::Project(round_id, recipient.clone()),
+        &QfProject {
+            recipient: recipient.clone(),
+            total_contributed: 0,
+            sqrt_sum: 0,
+            contributor_count: 0,
+            matched: 0,
+            schedule_id: None,
+        },
+    );
+}
+
+/// Contribute `amount` from `contributor` to `project`. Funds are locked into
+/// the program, reserved until the round closes, and then paid to the project.
+pub fn contribute(
+    env: &Env,
+    round_id: u32,
+    project: &Address,
+    contributor: &Address,
+    amount: i128,
+) -> QfProject {
+    contributor.require_auth();
+
+    if ProgramEscrowContract::check_paused(env, symbol_short!("lock")) {
+        panic!("Funds Paused");
+    }
+    if amount <= 0 {
+        panic!("Amount must be greater than zero");
+    }
+    let mut round = require_round(env, round_id);
+    if round.closed || env.ledger().timestamp() >= round.closes_at {
+        panic!("Round closed");
+    }
+    let mut stats =
+        get_project(env, round_id, project).unwrap_or_else(|| panic!("Project not registered"));
+
+    let mut program_data = primary_program(env);
+    let token_client = token::Client::new(env, &program_data.token_address);
+    token_client.transfer(contributor, &env.current_contract_address(), &amount);
+
+    program_data.total_funds = program_data
+        .total_funds
+        .checked_add(amount)
+        .unwrap_or_else(|| panic!("Total funds overflow"));
+    ProgramEscrowContract::store_program_data(env, &program_data.program_id, &program_data);
+    adjust_reserved_obligations(env, amount);
+    receipts::issue(
+        env,
+        &program_data.program_id,
+        contributor,
+        amount,
+        DepositKind::Contribution,
+    );
+
+    let key = QfKey::Contribution(round_id, project.clone(), contributor.clone());
+    let previous: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+    let updated = previous + amount;
+    env.storage().persistent().set(&key, &updated);
+
+    if previous == 0 {
+        stats.contributor_count += 1;
+    }
+    stats.sqrt_sum = stats.sqrt_sum - isqrt(previous) + isqrt(updated);
+    stats.total_contributed += amount;
+    env.storage()
+        .persistent()
+        .set(&QfKey::Project(round_id, project.clone()), &stats);
+
+    round.total_contributed += amount;
+    env.storage()
+        .persistent()
+        .set(&QfKey::Round(round_id), &round);
+
+    env.events().publish(
+        (LogLevel::Info, QF_CONTRIBUTION),
+        QfContributionEvent {
+            version: EVENT_VERSION_V2,
+            sequence: next_event_sequence(env),
+            round_id,
+            project: project.clone(),
+            contributor: contributor.clone(),
+            amount,
+            project_total: stats.total_contributed,
+        },
+    );
+    stats
+}
+
+fn ideal_match(stats: &QfProject) -> i128 {
+    let squared = stats
+        .sqrt_sum
+        .checked_mul(stats.sqrt_sum)
+        .unwrap_or_else(|| panic!("Quadratic funding overflow"));
+    (squared - stats.total_contributed).max(0)
+}
+
+/// Compute matches and queue one release schedule per funded project at
+/// `release_timestamp`. Returns the final project records.
+///
+/// Every match is computed and the total payout checked against the program's
+/// funds before any schedule is queued.
+pub fn close_round(env: &Env, round_id: u32, release_timestamp: u64) -> Vec<QfProject> {
+    let mut program_data = primary_program(env);
+    program_data.authorized_payout_key.require_auth();
+
+    let mut round = require_round(env, round_id);
+    if round.closed {
+        panic!("Round closed");
+    }
+    if env.ledger().timestamp() < round.closes_at {
+        panic!("Round still open");
+    }
+
+    let projects = get_projects(env, round_id);
+    let mut total_ideal: i128 = 0;
+    for project in projects.iter() {
+        let stats = get_project(env, round_id, &project).unwrap();
+        total_ideal = total_ideal
+            .checked_add(ideal_match(&stats))
+            .unwrap_or_else(|| panic!("Quadratic funding overflow"));
+    }
+
+    let mut results = Vec::new(env);
+    let mut total_matched: i128 = 0;
+    let mut total_payout: i128 = 0;
+    for project in projects.iter() {
+        let mut stats = get_project(env, round_id, &project).unwrap();
+        let ideal = ideal_match(&stats);
+        stats.matched = if total_ideal <= round.matching_pool {
+            ideal
+        } else {
+            ideal
+                .checked_mul(round.matching_pool)
+                .map(|x| x / total_ideal)
+                .unwrap_or_else(|| panic!("Quadratic funding overflow"))
+        };
+        total_matched += stats.matched;
+        total_payout += stats.total_contributed + stats.matched;
+        results.push_back(stats);
+    }
+    if total_matched > round.matching_pool {
+        panic!("Quadratic funding overflow");
+    }
+
+    // Release the pool and contribution reservations; the queued schedules
+    // are paid from them.
+    let reserved = round.matching_pool + round.total_contributed;
+    program_data.remaining_balance += reserved;
+    if total_payout > program_data.remaining_balance {
+        panic!("Insufficient balance");
+    }
+    ProgramEscrowContract::store_program_data(env, &program_data.program_id, &program_data);
+    adjust_reserved_obligations(env, -reserved);
+
+    for i in 0..results.len() {
+        let mut stats = results.get(i).unwrap();
+        let payout = stats.total_contributed + stats.matched;
+        if payout > 0 {
+            let schedule = ProgramEscrowContract::push_release_schedule(
+                env,
+                &program_data,
+                stats.recipient.clone(),
+                payout,
+                release_timestamp,
+            );
+            stats.schedule_id = Some(schedule.schedule_id);
+        }
+        env.storage()
+            .persistent()
+            .set(&QfKey::Project(round_id, stats.recipient.clone()), &stats);
+        results.set(i, stats);
+    }
+
+    round.closed = true;
+    round.total_matched = total_matched;
+    env.storage()
+        .persistent()
+        .set(&QfKey::Round(round_id), &round);
+
+    env.events().publish(
+        (LogLevel::Info, QF_ROUND_CLOSED),
+        QfRoundClosedEvent {
+            version: EVENT_VERSION_V2,
+            sequence: next_event_sequence(env),
+            round_id,
+            total_contributed: round.total_contributed,
+            total_matched,
+            project_count: projects.len(),
+        },
+    );
+    results
+}
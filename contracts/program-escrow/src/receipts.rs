@@ -0,0 +1,153 @@
+//! # Deposit Receipts
+//!
+//! On-chain proof of every deposit into a program. A receipt is written when
+//! funds are locked (`lock_program_funds`) or contributed by a third party
+//! (`contribute`, `contribute_qf`), keyed by an id drawn from the contract's
+//! shared receipt counter, so ids are unique across all receipt-bearing events.
+//!
+//! Receipts record the original depositor and a current `holder`. The holder
+//! can transfer the receipt, e.g. to a treasury or an auditor's custody
+//! address; the depositor, amount and timestamp never change, so
+//! `verify_receipt` keeps answering the same way after a transfer.
+//!
+//! For `lock_program_funds`, which takes no depositor argument, the program's
+//! authorized payout key is recorded as the depositor.
+
+use crate::events_log::LogLevel;
+use crate::{next_event_sequence, ProgramEscrowContract, EVENT_VERSION_V2};
+use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol};
+
+const RECEIPT_ISSUED: Symbol = symbol_short!("RcptNew");
+const RECEIPT_TRANSFERRED: Symbol = symbol_short!("RcptXfer");
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReceiptKey {
+    /// receipt_id -> DepositReceipt
+    Receipt(u64),
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DepositKind {
+    Lock,
+    Contribution,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DepositReceipt {
+    pub receipt_id: u64,
+    pub program_id: String,
+    pub depositor: Address,
+    pub holder: Address,
+    pub amount: i128,
+    pub kind: DepositKind,
+    pub issued_at: u64,
+}
+
+/// Emitted alongside the deposit's own event.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReceiptIssuedEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub receipt_id: u64,
+    pub program_id: String,
+    pub depositor: Address,
+    pub amount: i128,
+    pub kind: DepositKind,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReceiptTransferredEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub receipt_id: u64,
+    pub from: Address,
+    pub to: Address,
+}
+
+/// Write a receipt for a deposit and return its id.
+pub fn issue(
+    env: &Env,
+    program_id: &String,
+    depositor: &Address,
+    amount: i128,
+    kind: DepositKind,
+) -> u64 {
+    let receipt_id = ProgramEscrowContract::increment_receipt_id(env);
+    let receipt = DepositReceipt {
+        receipt_id,
+        program_id: program_id.clone(),
+        depositor: depositor.clone(),
+        holder: depositor.clone(),
+        amount,
+        kind,
+        issued_at: env.ledger().timestamp(),
+    };
+    env.storage()
+        .persistent()
+        .set(&ReceiptKey::Receipt(receipt_id), &receipt);
+
+    env.events().publish(
+        (LogLevel::Info, RECEIPT_ISSUED, receipt_id),
+        ReceiptIssuedEvent {
+            version: EVENT_VERSION_V2,
+            sequence: next_event_sequence(env),
+            receipt_id,
+            program_id: receipt.program_id,
+            depositor: receipt.depositor,
+            amount,
+            kind,
+        },
+    );
+    receipt_id
+}
+
+pub fn get_receipt(env: &Env, receipt_id: u64) -> Option<DepositReceipt> {
+    env.storage()
+        .persistent()
+        .get(&ReceiptKey::Receipt(receipt_id))
+}
+
+/// True when `receipt_id` exists and records exactly `expected_amount`
+/// deposited by `expected_depositor`.
+pub fn verify(
+    env: &Env,
+    receipt_id: u64,
+    expected_amount: i128,
+    expected_depositor: &Address,
+) -> bool {
+    match get_receipt(env, receipt_id) {
+        Some(receipt) => {
+            receipt.amount == expected_amount && &receipt.depositor == expected_depositor
+        }
+        None => false,
+    }
+}
+
+/// Move a receipt to `to`. Only the current holder can transfer it.
+pub fn transfer(env: &Env, receipt_id: u64, to: &Address) -> DepositReceipt {
+    let mut receipt = get_receipt(env, receipt_id).unwrap_or_else(|| panic!("Receipt not found"));
+    receipt.holder.require_auth();
+
+    let from = receipt.holder.clone();
+    receipt.holder = to.clone();
+    env.storage()
+        .persistent()
+        .set(&ReceiptKey::Receipt(receipt_id), &receipt);
+
+    env.events().publish(
+        (LogLevel::Info, RECEIPT_TRANSFERRED, receipt_id),
+        ReceiptTransferredEvent {
+            version: EVENT_VERSION_V2,
+            sequence: next_event_sequence(env),
+            receipt_id,
+            from,
+            to: to.clone(),
+        },
+    );
+    receipt
+}
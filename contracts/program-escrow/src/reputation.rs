@@ -0,0 +1,129 @@
+//! # Program Reputation
+//!
+//! A read-only score summarising how reliably the program pays out, for
+//! grant seekers and for the registry contract that forwards it
+//! (`program_reputation` in `soroban/contracts/program-escrow`).
+//!
+//! ```text
+//! completion_rate_bps         = completed_releases / total_scheduled
+//! payout_fulfillment_rate_bps = total_funds_distributed / total_funds_locked
+//! overall_score_bps           = (completion * 60 + fulfillment * 40) / 100
+//! ```
+//!
+//! Both rates are 10_000 when there is nothing to measure yet (no schedules,
+//! no locked funds). Disputes and refunds (emergency withdrawals) are counted
+//! as they happen, since neither leaves a history to recount.
+
+use crate::{ProgramData, ProgramReleaseSchedule, PROGRAM_DATA, SCHEDULES};
+use soroban_sdk::{contracttype, Env, Vec};
+
+const FULL_BPS: u32 = 10_000;
+const COMPLETION_WEIGHT: u32 = 60;
+const FULFILLMENT_WEIGHT: u32 = 40;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReputationKey {
+    /// Disputes opened over the program's lifetime.
+    Disputes,
+    /// Emergency withdrawals that returned program funds.
+    Refunds,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProgramReputationScore {
+    pub total_payouts: u32,
+    pub total_scheduled: u32,
+    pub completed_releases: u32,
+    pub pending_releases: u32,
+    pub overdue_releases: u32,
+    pub dispute_count: u32,
+    pub refund_count: u32,
+    pub total_funds_locked: i128,
+    pub total_funds_distributed: i128,
+    pub completion_rate_bps: u32,
+    pub payout_fulfillment_rate_bps: u32,
+    pub overall_score_bps: u32,
+}
+
+fn bump(env: &Env, key: ReputationKey) {
+    let count: u32 = env.storage().instance().get(&key).unwrap_or(0);
+    env.storage().instance().set(&key, &(count + 1));
+}
+
+pub fn record_dispute(env: &Env) {
+    bump(env, ReputationKey::Disputes);
+}
+
+pub fn record_refund(env: &Env) {
+    bump(env, ReputationKey::Refunds);
+}
+
+pub fn score(env: &Env) -> ProgramReputationScore {
+    let program_data: ProgramData = env
+        .storage()
+        .instance()
+        .get(&PROGRAM_DATA)
+        .unwrap_or_else(|| panic!("Program not initialized"));
+    let schedules: Vec<ProgramReleaseSchedule> = env
+        .storage()
+        .instance()
+        .get(&SCHEDULES)
+        .unwrap_or_else(|| Vec::new(env));
+
+    let now = env.ledger().timestamp();
+    let mut completed_releases: u32 = 0;
+    let mut overdue_releases: u32 = 0;
+    for schedule in schedules.iter() {
+        if schedule.released {
+            completed_releases += 1;
+        } else if schedule.release_timestamp < now {
+            overdue_releases += 1;
+        }
+    }
+    let total_scheduled = schedules.len();
+
+    let mut total_funds_distributed: i128 = 0;
+    for record in program_data.payout_history.iter() {
+        total_funds_distributed += record.amount;
+    }
+
+    let completion_rate_bps = if total_scheduled == 0 {
+        FULL_BPS
+    } else {
+        completed_releases * FULL_BPS / total_scheduled
+    };
+    let payout_fulfillment_rate_bps = if program_data.total_funds <= 0 {
+        FULL_BPS
+    } else {
+        (total_funds_distributed * FULL_BPS as i128 / program_data.total_funds)
+            .clamp(0, FULL_BPS as i128) as u32
+    };
+    let overall_score_bps = (completion_rate_bps * COMPLETION_WEIGHT
+        + payout_fulfillment_rate_bps * FULFILLMENT_WEIGHT)
+        / 100;
+
+    ProgramReputationScore {
+        total_payouts: program_data.payout_history.len(),
+        total_scheduled,
+        completed_releases,
+        pending_releases: total_scheduled - completed_releases,
+        overdue_releases,
+        dispute_count: env
+            .storage()
+            .instance()
+            .get(&ReputationKey::Disputes)
+            .unwrap_or(0),
+        refund_count: env
+            .storage()
+            .instance()
+            .get(&ReputationKey::Refunds)
+            .unwrap_or(0),
+        total_funds_locked: program_data.total_funds,
+        total_funds_distributed,
+        completion_rate_bps,
+        payout_fulfillment_rate_bps,
+        overall_score_bps,
+    }
+}
@@ -72,6 +72,15 @@ fn test_init_program_and_event() {
     assert!(events.len() >= 1);
 }
 
+#[test]
+fn test_get_version() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    assert_eq!(client.get_version(), CONTRACT_VERSION);
+}
+
 #[test]
 fn test_lock_program_funds_multi_step_balance() {
     let env = Env::default();
@@ -174,6 +183,8 @@ fn test_property_fuzz_balance_invariants() {
     let mut expected_remaining = 1_000_000_i128;
 
     for _ in 0..40 {
+        // Each iteration stands for a separate transaction with its own budget.
+        env.budget().reset_default();
         let amount = (next_seed(&mut seed) % 4_000 + 1) as i128;
         if amount > expected_remaining {
             continue;
@@ -209,6 +220,8 @@ fn test_property_fuzz_balance_invariants() {
 fn test_stress_high_load_many_payouts() {
     let env = Env::default();
     let (client, _admin, token_client, _token_admin) = setup_program(&env, 1_000_000);
+    // The test budget is cumulative per Env; this checks state, not cost.
+    env.budget().reset_unlimited();
 
     for _ in 0..100 {
         let recipient = Address::generate(&env);
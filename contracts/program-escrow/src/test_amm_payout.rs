@@ -0,0 +1,161 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, testutils::Address as _, token, Address, Env, String,
+};
+
+/// Constant-rate AMM: pays `amount_in * rate_bps / 10_000` of `token_out`
+/// from its own liquidity. With `honour_min_out` off it ignores `min_out`,
+/// standing in for a misbehaving pool.
+#[contract]
+pub struct MockAmm;
+
+#[contractimpl]
+impl MockAmm {
+    pub fn configure(env: Env, rate_bps: i128, honour_min_out: bool) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("cfg"), &(rate_bps, honour_min_out));
+    }
+
+    pub fn swap(
+        env: Env,
+        _token_in: Address,
+        token_out: Address,
+        amount_in: i128,
+        min_out: i128,
+        to: Address,
+    ) -> i128 {
+        let (rate_bps, honour_min_out): (i128, bool) =
+            env.storage().instance().get(&symbol_short!("cfg")).unwrap();
+        let amount_out = amount_in * rate_bps / 10_000;
+        if honour_min_out && amount_out < min_out {
+            panic!("AMM: insufficient output");
+        }
+        token::Client::new(&env, &token_out).transfer(
+            &env.current_contract_address(),
+            &to,
+            &amount_out,
+        );
+        amount_out
+    }
+}
+
+struct Setup<'a> {
+    client: ProgramEscrowContractClient<'a>,
+    amm: MockAmmClient<'a>,
+    program_token: token::Client<'a>,
+    usdc: token::Client<'a>,
+    recipient: Address,
+}
+
+/// Program funded with 10_000 program tokens; the AMM holds 10_000 USDC and
+/// quotes 1 program token = 0.95 USDC.
+fn setup(env: &Env) -> Setup<'static> {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let token_admin = Address::generate(env);
+    let token_id = env
+        .register_stellar_asset_contract_v2(token_admin.clone())
+        .address();
+    let usdc_id = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+
+    let program_id = String::from_str(env, "usdc-payouts");
+    client.init_program(&program_id, &admin, &token_id, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_id).mint(&client.address, &10_000);
+    client.lock_program_funds(&10_000);
+
+    let amm_id = env.register_contract(None, MockAmm);
+    let amm = MockAmmClient::new(env, &amm_id);
+    amm.configure(&9_500, &true);
+    token::StellarAssetClient::new(env, &usdc_id).mint(&amm_id, &10_000);
+    client.set_amm(&Some(amm_id));
+
+    Setup {
+        client,
+        amm,
+        program_token: token::Client::new(env, &token_id),
+        usdc: token::Client::new(env, &usdc_id),
+        recipient: Address::generate(env),
+    }
+}
+
+#[test]
+fn test_single_payout_as_delivers_target_token() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let data = s
+        .client
+        .single_payout_as(&s.recipient, &1_000, &s.usdc.address, &900);
+
+    assert_eq!(s.usdc.balance(&s.recipient), 950);
+    assert_eq!(s.program_token.balance(&s.recipient), 0);
+    assert_eq!(s.program_token.balance(&s.amm.address), 1_000);
+    // Accounting stays in program tokens.
+    assert_eq!(data.remaining_balance, 9_000);
+    assert_eq!(data.payout_history.get(0).unwrap().amount, 1_000);
+}
+
+#[test]
+#[should_panic(expected = "AMM: insufficient output")]
+fn test_amm_enforced_min_out_aborts_payout() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client
+        .single_payout_as(&s.recipient, &1_000, &s.usdc.address, &990);
+}
+
+#[test]
+#[should_panic(expected = "Slippage exceeded")]
+fn test_adapter_rejects_short_output_from_amm() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.amm.configure(&9_500, &false);
+    s.client
+        .single_payout_as(&s.recipient, &1_000, &s.usdc.address, &990);
+}
+
+#[test]
+fn test_failed_swap_leaves_balances_untouched() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let res = s
+        .client
+        .try_single_payout_as(&s.recipient, &1_000, &s.usdc.address, &990);
+    assert!(res.is_err());
+    assert_eq!(s.usdc.balance(&s.recipient), 0);
+    assert_eq!(s.program_token.balance(&s.recipient), 0);
+    assert_eq!(s.program_token.balance(&s.client.address), 10_000);
+    assert_eq!(s.client.get_remaining_balance(), 10_000);
+}
+
+#[test]
+#[should_panic(expected = "AMM not registered")]
+fn test_payout_as_requires_registered_amm() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.set_amm(&None);
+    assert_eq!(s.client.get_amm(), None);
+    s.client
+        .single_payout_as(&s.recipient, &1_000, &s.usdc.address, &900);
+}
+
+#[test]
+#[should_panic(expected = "Target token must differ from program token")]
+fn test_payout_as_rejects_program_token_target() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client
+        .single_payout_as(&s.recipient, &1_000, &s.program_token.address, &900);
+}
@@ -0,0 +1,115 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, BytesN, Env, String,
+};
+
+fn setup(env: &Env) -> ProgramEscrowContractClient<'static> {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_id = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+
+    let program_id = String::from_str(env, "attest-prog");
+    client.init_program(&program_id, &admin, &token_id, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_id).mint(&client.address, &10_000);
+    client.lock_program_funds(&10_000);
+    env.ledger().set_timestamp(500);
+
+    client
+}
+
+#[test]
+fn test_payouts_mint_attestations() {
+    let env = Env::default();
+    let client = setup(&env);
+    let dev = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    client.single_payout(&dev, &1_000);
+    client.batch_payout(
+        &vec![&env, dev.clone(), other.clone()],
+        &vec![&env, 200, 300],
+    );
+
+    assert_eq!(client.get_attestation_count(&dev), 2);
+    assert_eq!(client.get_attestation_count(&other), 1);
+
+    let history = client.get_attestations(&dev, &0, &10);
+    let first = history.get(0).unwrap();
+    assert_eq!(first.recipient, dev);
+    assert_eq!(first.program_id, String::from_str(&env, "attest-prog"));
+    assert_eq!(first.amount, 1_000);
+    assert_eq!(first.completed_at, 500);
+    assert_eq!(first.work_hash, BytesN::from_array(&env, &[0u8; 32]));
+    assert_eq!(history.get(1).unwrap().amount, 200);
+}
+
+#[test]
+fn test_work_hash_recorded() {
+    let env = Env::default();
+    let client = setup(&env);
+    let dev = Address::generate(&env);
+    let work_hash = BytesN::from_array(&env, &[9u8; 32]);
+
+    client.single_payout_with_work(&dev, &750, &work_hash);
+
+    let attestation = client.get_attestations(&dev, &0, &1).get(0).unwrap();
+    assert_eq!(attestation.amount, 750);
+    assert_eq!(attestation.work_hash, work_hash);
+}
+
+#[test]
+fn test_milestone_releases_mint_attestations() {
+    let env = Env::default();
+    let client = setup(&env);
+    let dev = Address::generate(&env);
+
+    client.create_program_release_schedule(&dev, &400, &600);
+    client.create_program_release_schedule(&dev, &600, &600);
+    client.create_program_release_schedule(&dev, &100, &900);
+
+    env.ledger().set_timestamp(600);
+    client.trigger_program_releases();
+    client.release_program_schedule_manual(&3);
+
+    let history = client.get_attestations(&dev, &0, &10);
+    assert_eq!(history.len(), 3);
+    assert_eq!(history.get(0).unwrap().amount, 400);
+    assert_eq!(history.get(2).unwrap().amount, 100);
+    assert_eq!(history.get(2).unwrap().completed_at, 600);
+}
+
+#[test]
+fn test_pagination_and_limit_cap() {
+    let env = Env::default();
+    let client = setup(&env);
+    let dev = Address::generate(&env);
+
+    for _ in 0..(attestations::MAX_ATTESTATION_PAGE_SIZE + 3) {
+        env.budget().reset_default();
+        client.single_payout(&dev, &1);
+    }
+    env.budget().reset_default();
+
+    assert_eq!(
+        client.get_attestations(&dev, &0, &u32::MAX).len(),
+        attestations::MAX_ATTESTATION_PAGE_SIZE
+    );
+    let tail = client.get_attestations(&dev, &100, &10);
+    assert_eq!(tail.len(), 3);
+    assert_eq!(client.get_attestations(&dev, &103, &10).len(), 0);
+    assert_eq!(
+        client
+            .get_attestations(&Address::generate(&env), &0, &10)
+            .len(),
+        0
+    );
+}
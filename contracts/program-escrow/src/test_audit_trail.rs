@@ -0,0 +1,195 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+fn setup(env: &Env) -> (ProgramEscrowContractClient<'static>, Address) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    (client, admin)
+}
+
+#[test]
+fn test_audit_trail_starts_empty() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+
+    assert_eq!(client.get_audit_entry_count(), 0);
+    assert_eq!(client.get_audit_entries(&0, &10).len(), 0);
+}
+
+#[test]
+fn test_privileged_calls_are_recorded_in_order() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    env.ledger().set_timestamp(1_000);
+
+    client.set_paused(&Some(true), &None, &None, &None);
+    client.set_maintenance_mode(&true);
+    client.update_rate_limit_config(&3600, &5, &60);
+
+    let entries = client.get_audit_entries(&0, &10);
+    assert_eq!(entries.len(), 3);
+
+    let first = entries.get(0).unwrap();
+    assert_eq!(first.index, 0);
+    assert_eq!(first.caller, admin);
+    assert_eq!(first.action, symbol_short!("pause"));
+    assert_eq!(first.timestamp, 1_000);
+
+    assert_eq!(entries.get(1).unwrap().action, symbol_short!("maint"));
+    assert_eq!(entries.get(2).unwrap().action, symbol_short!("rate_lim"));
+}
+
+#[test]
+fn test_admin_transfer_records_previous_admin_as_caller() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let new_admin = Address::generate(&env);
+
+    client.set_admin(&new_admin);
+
+    let entry = client.get_audit_entries(&0, &1).get(0).unwrap();
+    assert_eq!(entry.caller, admin);
+    assert_eq!(entry.action, symbol_short!("set_admin"));
+}
+
+#[test]
+fn test_circuit_admin_changes_record_the_authorizing_admin() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let circuit_admin = Address::generate(&env);
+    let successor = Address::generate(&env);
+
+    client.set_circuit_admin(&circuit_admin, &None);
+    client.set_circuit_admin(&successor, &Some(circuit_admin.clone()));
+
+    let entries = client.get_audit_entries(&0, &2);
+    assert_eq!(entries.get(0).unwrap().caller, admin);
+    assert_eq!(entries.get(0).unwrap().action, symbol_short!("cb_adm"));
+    assert_eq!(entries.get(1).unwrap().caller, circuit_admin);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: only current admin can change circuit breaker admin")]
+fn test_circuit_admin_rotation_requires_caller() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+
+    client.set_circuit_admin(&Address::generate(&env), &None);
+    client.set_circuit_admin(&Address::generate(&env), &None);
+}
+
+#[test]
+fn test_args_hash_distinguishes_arguments() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+
+    client.set_maintenance_mode(&true);
+    client.set_maintenance_mode(&false);
+    client.set_maintenance_mode(&true);
+
+    let entries = client.get_audit_entries(&0, &3);
+    let on = entries.get(0).unwrap().args_hash;
+    let off = entries.get(1).unwrap().args_hash;
+    let on_again = entries.get(2).unwrap().args_hash;
+
+    assert_ne!(on, off);
+    assert_eq!(on, on_again);
+}
+
+#[test]
+fn test_emergency_withdraw_is_recorded() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+
+    let token_admin = Address::generate(&env);
+    let token_id = env
+        .register_stellar_asset_contract_v2(token_admin.clone())
+        .address();
+    let program_id = String::from_str(&env, "audit-prog");
+    client.init_program(&program_id, &admin, &token_id, &admin, &None, &None);
+    token::StellarAssetClient::new(&env, &token_id).mint(&client.address, &5_000);
+    client.lock_program_funds(&5_000);
+
+    let target = Address::generate(&env);
+    client.set_paused(&Some(true), &None, &None, &None);
+    client.emergency_withdraw(&target);
+
+    let count = client.get_audit_entry_count();
+    let last = client.get_audit_entries(&(count - 1), &1).get(0).unwrap();
+    assert_eq!(last.action, symbol_short!("em_wtd"));
+    assert_eq!(last.caller, admin);
+}
+
+#[test]
+fn test_pagination_and_limit_cap() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+
+    for i in 0..(audit_trail::MAX_AUDIT_PAGE_SIZE + 5) {
+        client.set_maintenance_mode(&(i % 2 == 0));
+    }
+
+    let total = client.get_audit_entry_count();
+    assert_eq!(total, (audit_trail::MAX_AUDIT_PAGE_SIZE + 5) as u64);
+
+    let capped = client.get_audit_entries(&0, &u32::MAX);
+    assert_eq!(capped.len(), audit_trail::MAX_AUDIT_PAGE_SIZE);
+
+    let tail = client.get_audit_entries(&100, &50);
+    assert_eq!(tail.len(), 5);
+    assert_eq!(tail.get(0).unwrap().index, 100);
+
+    assert_eq!(client.get_audit_entries(&total, &10).len(), 0);
+}
+
+#[test]
+fn test_auditor_exports_trail_and_error_log() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let auditor = Address::generate(&env);
+
+    client.set_auditor(&auditor, &true);
+    assert!(client.is_auditor(&auditor));
+    client.set_maintenance_mode(&true);
+    env.as_contract(&client.address, || {
+        error_recovery::record_failure(
+            &env,
+            String::from_str(&env, "prog"),
+            symbol_short!("payout"),
+            error_recovery::ERR_TRANSFER_FAILED,
+        );
+    });
+
+    let export = client.export_audit_trail(&auditor, &0, &10);
+    assert_eq!(export.entry_count, 2);
+    let grant = export.entries.get(0).unwrap();
+    assert_eq!(grant.caller, admin);
+    assert_eq!(grant.action, symbol_short!("auditor"));
+    let failure = export.error_log.get(0).unwrap();
+    assert_eq!(export.error_log.len(), 1);
+    assert_eq!(failure.operation, symbol_short!("payout"));
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: not an auditor")]
+fn test_revoked_auditor_cannot_export() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let auditor = Address::generate(&env);
+
+    client.set_auditor(&auditor, &true);
+    client.set_auditor(&auditor, &false);
+    assert!(!client.is_auditor(&auditor));
+    client.export_audit_trail(&auditor, &0, &10);
+}
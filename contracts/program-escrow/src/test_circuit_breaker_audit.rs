@@ -18,8 +18,11 @@ mod test {
             return false;
         }
 
+        // Leveled events carry a LogLevel at topic[0]; match on the trailing pair.
+        let offset = topics.len() - 2;
+        let actual = topics.slice(offset..);
         let expected = vec![env, first.into_val(env), second.into_val(env)];
-        *topics == expected
+        actual == expected
     }
 
     fn setup_test(env: &Env) -> (ProgramEscrowContractClient, Address) {
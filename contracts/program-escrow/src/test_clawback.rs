@@ -0,0 +1,136 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, IssuerFlags, Ledger},
+    token, Address, Env, String,
+};
+
+struct Setup<'a> {
+    client: ProgramEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    recipient: Address,
+}
+
+/// Program funded with 10_000 that has paid 1_000 to `recipient` (payout 0).
+/// With `clawback_enabled` the asset allows clawback and the escrow is its
+/// admin. Clawbacks use a one-hour timelock.
+fn setup(env: &Env, clawback_enabled: bool) -> Setup<'static> {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let sac = env.register_stellar_asset_contract_v2(Address::generate(env));
+    if clawback_enabled {
+        sac.issuer().set_flag(IssuerFlags::RevocableFlag);
+        sac.issuer().set_flag(IssuerFlags::ClawbackEnabledFlag);
+    }
+    let token_id = sac.address();
+    let asset = token::StellarAssetClient::new(env, &token_id);
+
+    let program_id = String::from_str(env, "regulated-grant");
+    client.init_program(&program_id, &admin, &token_id, &admin, &None, &None);
+    asset.mint(&client.address, &10_000);
+    client.lock_program_funds(&10_000);
+    if clawback_enabled {
+        asset.set_admin(&client.address);
+    }
+
+    let recipient = Address::generate(env);
+    client.single_payout(&recipient, &1_000);
+
+    client.set_compliance_officer(&Address::generate(env));
+    client.set_clawback_delay(&3_600);
+
+    Setup {
+        client,
+        token: token::Client::new(env, &token_id),
+        recipient,
+    }
+}
+
+#[test]
+fn test_clawback_returns_funds_after_timelock() {
+    let env = Env::default();
+    let s = setup(&env, true);
+
+    let flag = s
+        .client
+        .flag_payout_fraud(&0, &String::from_str(&env, "forged KYC"));
+    assert_eq!(flag.recipient, s.recipient);
+    assert_eq!(flag.amount, 1_000);
+    assert_eq!(flag.executable_at, flag.flagged_at + 3_600);
+
+    env.ledger().set_timestamp(flag.executable_at);
+    let data = s.client.execute_clawback(&0);
+
+    assert_eq!(data.remaining_balance, 10_000);
+    assert_eq!(s.token.balance(&s.recipient), 0);
+    assert_eq!(s.token.balance(&s.client.address), 10_000);
+    let flag = s.client.get_fraud_flag(&0).unwrap();
+    assert_eq!(flag.clawed_back, Some(1_000));
+}
+
+#[test]
+#[should_panic(expected = "Clawback timelock active")]
+fn test_clawback_respects_timelock() {
+    let env = Env::default();
+    let s = setup(&env, true);
+    s.client
+        .flag_payout_fraud(&0, &String::from_str(&env, "forged KYC"));
+    s.client.execute_clawback(&0);
+}
+
+#[test]
+fn test_clawback_capped_at_recipient_balance() {
+    let env = Env::default();
+    let s = setup(&env, true);
+    s.token
+        .transfer(&s.recipient, &Address::generate(&env), &700);
+
+    s.client
+        .flag_payout_fraud(&0, &String::from_str(&env, "forged KYC"));
+    env.ledger().set_timestamp(3_600);
+    let data = s.client.execute_clawback(&0);
+
+    assert_eq!(data.remaining_balance, 9_300);
+    assert_eq!(s.client.get_fraud_flag(&0).unwrap().clawed_back, Some(300));
+}
+
+#[test]
+#[should_panic(expected = "Token does not support clawback")]
+fn test_clawback_requires_clawback_asset() {
+    let env = Env::default();
+    let s = setup(&env, false);
+    s.client
+        .flag_payout_fraud(&0, &String::from_str(&env, "forged KYC"));
+    env.ledger().set_timestamp(3_600);
+    s.client.execute_clawback(&0);
+}
+
+#[test]
+#[should_panic(expected = "Payout not flagged")]
+fn test_cancelled_flag_cannot_be_executed() {
+    let env = Env::default();
+    let s = setup(&env, true);
+    s.client
+        .flag_payout_fraud(&0, &String::from_str(&env, "false alarm"));
+    s.client.cancel_fraud_flag(&0);
+    assert_eq!(s.client.get_fraud_flag(&0), None);
+
+    env.ledger().set_timestamp(3_600);
+    s.client.execute_clawback(&0);
+}
+
+#[test]
+#[should_panic(expected = "Payout not found")]
+fn test_flag_requires_existing_payout() {
+    let env = Env::default();
+    let s = setup(&env, true);
+    s.client
+        .flag_payout_fraud(&5, &String::from_str(&env, "forged KYC"));
+}
@@ -0,0 +1,153 @@
+#![cfg(test)]
+
+use super::*;
+use crate::commit_reveal::compute_commitment;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Bytes, BytesN, Env, String,
+};
+
+fn setup(env: &Env) -> (ProgramEscrowContractClient<'static>, String) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let token_id = env
+        .register_stellar_asset_contract_v2(token_admin.clone())
+        .address();
+
+    let program_id = String::from_str(env, "grant-round");
+    client.init_program(&program_id, &admin, &token_id, &admin, &None, &None);
+    env.ledger().set_timestamp(1_000);
+    client.open_allocation_round(&program_id, &2_000, &3_000);
+
+    (client, program_id)
+}
+
+fn proposal(env: &Env, text: &str) -> (Bytes, BytesN<32>) {
+    (
+        Bytes::from_slice(env, text.as_bytes()),
+        BytesN::from_array(env, &[7u8; 32]),
+    )
+}
+
+#[test]
+fn test_commit_then_reveal_accepted() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+    let applicant = Address::generate(&env);
+    let (body, salt) = proposal(&env, "irrigation for 40 smallholders");
+
+    client.submit_commitment(
+        &program_id,
+        &applicant,
+        &compute_commitment(&env, &body, &salt),
+    );
+    assert_eq!(client.get_revealed_applicants(&program_id).len(), 0);
+
+    env.ledger().set_timestamp(2_500);
+    let entry = client.reveal_proposal(&program_id, &applicant, &body, &salt);
+    assert_eq!(entry.proposal, Some(body));
+    assert_eq!(entry.revealed_at, Some(2_500));
+    assert_eq!(
+        client.get_revealed_applicants(&program_id),
+        vec![&env, applicant]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Reveal does not match commitment")]
+fn test_mismatched_reveal_rejected() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+    let applicant = Address::generate(&env);
+    let (body, salt) = proposal(&env, "original");
+
+    client.submit_commitment(
+        &program_id,
+        &applicant,
+        &compute_commitment(&env, &body, &salt),
+    );
+
+    env.ledger().set_timestamp(2_500);
+    let (altered, _) = proposal(&env, "altered after seeing rivals");
+    client.reveal_proposal(&program_id, &applicant, &altered, &salt);
+}
+
+#[test]
+#[should_panic(expected = "Commit window closed")]
+fn test_commit_after_deadline_rejected() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+    let (body, salt) = proposal(&env, "late");
+
+    env.ledger().set_timestamp(2_000);
+    client.submit_commitment(
+        &program_id,
+        &Address::generate(&env),
+        &compute_commitment(&env, &body, &salt),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Reveal window not open")]
+fn test_reveal_during_commit_window_rejected() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+    let applicant = Address::generate(&env);
+    let (body, salt) = proposal(&env, "early");
+
+    client.submit_commitment(
+        &program_id,
+        &applicant,
+        &compute_commitment(&env, &body, &salt),
+    );
+    client.reveal_proposal(&program_id, &applicant, &body, &salt);
+}
+
+#[test]
+#[should_panic(expected = "Reveal window closed")]
+fn test_reveal_after_deadline_rejected() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+    let applicant = Address::generate(&env);
+    let (body, salt) = proposal(&env, "too late");
+
+    client.submit_commitment(
+        &program_id,
+        &applicant,
+        &compute_commitment(&env, &body, &salt),
+    );
+    env.ledger().set_timestamp(3_000);
+    client.reveal_proposal(&program_id, &applicant, &body, &salt);
+}
+
+#[test]
+#[should_panic(expected = "Already committed")]
+fn test_double_commit_rejected() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+    let applicant = Address::generate(&env);
+    let (body, salt) = proposal(&env, "one");
+    let commitment = compute_commitment(&env, &body, &salt);
+
+    client.submit_commitment(&program_id, &applicant, &commitment);
+    client.submit_commitment(&program_id, &applicant, &commitment);
+}
+
+#[test]
+fn test_new_round_opens_after_previous_reveal_window() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+
+    assert!(client
+        .try_open_allocation_round(&program_id, &4_000, &5_000)
+        .is_err());
+
+    env.ledger().set_timestamp(3_000);
+    let round = client.open_allocation_round(&program_id, &4_000, &5_000);
+    assert_eq!(round.round_id, 2);
+}
@@ -0,0 +1,114 @@
+#![cfg(test)]
+
+use super::*;
+use crate::error_recovery;
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    token, Address, Env, String, TryFromVal, Val,
+};
+
+fn setup_program(env: &Env) -> ProgramEscrowContractClient<'static> {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let token_id = env
+        .register_stellar_asset_contract_v2(token_admin.clone())
+        .address();
+    let token_admin_client = token::StellarAssetClient::new(env, &token_id);
+
+    let program_id = String::from_str(env, "log-prog");
+    client.init_program(&program_id, &admin, &token_id, &admin, &None, &None);
+
+    token_admin_client.mint(&client.address, &10_000);
+    client.lock_program_funds(&10_000);
+
+    client
+}
+
+/// Returns the level at topic[0] of the last event whose topic[1] is `name`.
+fn level_of(env: &Env, name: Symbol) -> Option<LogLevel> {
+    let mut found = None;
+    for (_, topics, _) in env.events().all().iter() {
+        if topics.len() < 2 {
+            continue;
+        }
+        let second: Val = topics.get(1).unwrap();
+        if Symbol::try_from_val(env, &second).ok() != Some(name.clone()) {
+            continue;
+        }
+        let first: Val = topics.get(0).unwrap();
+        found = LogLevel::try_from_val(env, &first).ok();
+    }
+    found
+}
+
+#[test]
+fn test_registration_and_lock_events_are_info() {
+    let env = Env::default();
+    let _client = setup_program(&env);
+
+    assert_eq!(level_of(&env, PROGRAM_INITIALIZED), Some(LogLevel::Info));
+    assert_eq!(level_of(&env, FUNDS_LOCKED), Some(LogLevel::Info));
+}
+
+#[test]
+fn test_payout_events_are_info() {
+    let env = Env::default();
+    let client = setup_program(&env);
+    let winner = Address::generate(&env);
+
+    client.single_payout(&winner, &1_000);
+    assert_eq!(level_of(&env, PAYOUT), Some(LogLevel::Info));
+
+    client.batch_payout(
+        &soroban_sdk::vec![&env, winner.clone()],
+        &soroban_sdk::vec![&env, 500],
+    );
+    assert_eq!(level_of(&env, BATCH_PAYOUT), Some(LogLevel::Info));
+}
+
+#[test]
+fn test_circuit_open_is_error_and_reject_is_warn() {
+    let env = Env::default();
+    let client = setup_program(&env);
+
+    env.as_contract(&client.address, || {
+        error_recovery::open_circuit(&env);
+        assert_eq!(
+            level_of(&env, symbol_short!("circuit")),
+            Some(LogLevel::Error)
+        );
+
+        assert!(error_recovery::check_and_allow(&env).is_err());
+        assert_eq!(
+            level_of(&env, symbol_short!("circuit")),
+            Some(LogLevel::Warn)
+        );
+    });
+}
+
+#[test]
+fn test_threshold_breach_is_warn() {
+    let env = Env::default();
+    let client = setup_program(&env);
+
+    env.as_contract(&client.address, || {
+        let breach = crate::threshold_monitor::ThresholdBreach {
+            metric_type: symbol_short!("outflow"),
+            threshold_value: 100,
+            actual_value: 200,
+            timestamp: env.ledger().timestamp(),
+            breach_count: 1,
+        };
+        crate::threshold_monitor::emit_threshold_breach_event(&env, &breach);
+    });
+
+    assert_eq!(
+        level_of(&env, symbol_short!("th_breach")),
+        Some(LogLevel::Warn)
+    );
+}
@@ -0,0 +1,138 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, token, Address, Env, String};
+
+#[contract]
+pub struct MockCore;
+
+#[contractimpl]
+impl MockCore {
+    pub fn set_halted(env: Env, halted: bool) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("halted"), &halted);
+    }
+
+    pub fn is_globally_halted(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("halted"))
+            .unwrap_or(false)
+    }
+}
+
+fn setup(
+    env: &Env,
+) -> (
+    ProgramEscrowContractClient<'static>,
+    MockCoreClient<'static>,
+) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let token_admin = Address::generate(env);
+    let token_id = env
+        .register_stellar_asset_contract_v2(token_admin.clone())
+        .address();
+
+    let program_id = String::from_str(env, "halt-2026");
+    client.init_program(&program_id, &admin, &token_id, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_id).mint(&client.address, &10_000);
+    client.lock_program_funds(&10_000);
+
+    let core_id = env.register_contract(None, MockCore);
+    (client, MockCoreClient::new(env, &core_id))
+}
+
+#[test]
+fn test_no_core_contract_is_never_halted() {
+    let env = Env::default();
+    let (client, _core) = setup(&env);
+
+    assert_eq!(client.get_core_contract(), None);
+    assert!(!client.is_globally_halted());
+    client.single_payout(&Address::generate(&env), &100);
+}
+
+#[test]
+fn test_global_halt_blocks_payouts_until_resumed() {
+    let env = Env::default();
+    let (client, core) = setup(&env);
+    client.set_core_contract(&Some(core.address.clone()));
+
+    core.set_halted(&true);
+    assert!(client.is_globally_halted());
+    assert!(client
+        .try_single_payout(&Address::generate(&env), &100)
+        .is_err());
+
+    core.set_halted(&false);
+    assert!(!client.is_globally_halted());
+    let data = client.single_payout(&Address::generate(&env), &100);
+    assert_eq!(data.remaining_balance, 9_900);
+}
+
+#[test]
+#[should_panic(expected = "Funds Paused")]
+fn test_global_halt_blocks_lock() {
+    let env = Env::default();
+    let (client, core) = setup(&env);
+    client.set_core_contract(&Some(core.address.clone()));
+
+    core.set_halted(&true);
+    client.lock_program_funds(&1);
+}
+
+#[test]
+fn test_unreachable_core_fails_closed_and_can_be_unlinked() {
+    let env = Env::default();
+    let (client, _core) = setup(&env);
+    client.set_core_contract(&Some(Address::generate(&env)));
+
+    assert!(client.is_globally_halted());
+
+    client.set_core_contract(&None);
+    assert!(!client.is_globally_halted());
+    client.single_payout(&Address::generate(&env), &100);
+}
+
+#[test]
+fn test_global_halt_blocks_schedule_claim_and_pool_exits() {
+    let env = Env::default();
+    let (client, core) = setup(&env);
+    let program_id = String::from_str(&env, "halt-2026");
+    let recipient = Address::generate(&env);
+
+    let manual = client.create_program_release_schedule(&recipient, &100, &0);
+    let automatic = client.create_program_release_schedule(&recipient, &100, &0);
+    let claim_id = client.create_pending_claim(&program_id, &recipient, &100, &1_000);
+    let matcher = Address::generate(&env);
+    let token_id = client.get_program_info().token_address;
+    token::StellarAssetClient::new(&env, &token_id).mint(&matcher, &1_000);
+    client.create_matching_pool(&program_id, &matcher, &5_000, &1_000);
+
+    client.set_core_contract(&Some(core.address.clone()));
+    core.set_halted(&true);
+    assert!(client
+        .try_release_program_schedule_manual(&manual.schedule_id)
+        .is_err());
+    assert!(client
+        .try_release_prog_schedule_automatic(&automatic.schedule_id)
+        .is_err());
+    assert!(client
+        .try_execute_claim(&program_id, &claim_id, &recipient)
+        .is_err());
+    assert!(client.try_close_matching_pool(&program_id).is_err());
+
+    core.set_halted(&false);
+    client.release_program_schedule_manual(&manual.schedule_id);
+    client.release_prog_schedule_automatic(&automatic.schedule_id);
+    client.execute_claim(&program_id, &claim_id, &recipient);
+    assert_eq!(client.close_matching_pool(&program_id), 1_000);
+}
@@ -0,0 +1,212 @@
+#![cfg(test)]
+
+use super::*;
+use crate::indexed_payouts::IndexFeed;
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String, Symbol,
+};
+
+#[contract]
+pub struct MockPriceOracle;
+
+#[contractimpl]
+impl MockPriceOracle {
+    pub fn set_price(env: Env, feed: Symbol, price: i128, updated_at: u64) {
+        env.storage().instance().set(&feed, &(price, updated_at));
+    }
+
+    pub fn get_price(env: Env, feed: Symbol) -> (i128, u64) {
+        env.storage()
+            .instance()
+            .get(&feed)
+            .unwrap_or_else(|| panic!("Unknown feed"))
+    }
+}
+
+struct Setup<'a> {
+    client: ProgramEscrowContractClient<'a>,
+    oracle: MockPriceOracleClient<'a>,
+    token: token::Client<'a>,
+    recipient: Address,
+    feed: Symbol,
+}
+
+/// Schedule 1 pays 1_000 reference units at t=100, indexed to a CPI feed
+/// whose base price is 100.
+fn setup(env: &Env) -> Setup<'static> {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let token_admin = Address::generate(env);
+    let token_id = env
+        .register_stellar_asset_contract_v2(token_admin.clone())
+        .address();
+
+    let program_id = String::from_str(env, "farm-subsidy");
+    client.init_program(&program_id, &admin, &token_id, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_id).mint(&client.address, &10_000);
+    client.lock_program_funds(&10_000);
+
+    let oracle_id = env.register_contract(None, MockPriceOracle);
+    let oracle = MockPriceOracleClient::new(env, &oracle_id);
+    let feed = Symbol::new(env, "cpi");
+    client.set_oracle_allowed(&oracle_id, &true);
+    client.set_index_feed(&IndexFeed {
+        oracle: oracle_id,
+        feed: feed.clone(),
+        base_price: 100,
+        max_staleness_secs: 3_600,
+        max_deviation_bps: 2_000,
+    });
+
+    let recipient = Address::generate(env);
+    client.create_program_release_schedule(&recipient, &1_000, &100);
+    client.set_schedule_indexed(&1, &true);
+
+    Setup {
+        client,
+        oracle,
+        token: token::Client::new(env, &token_id),
+        recipient,
+        feed,
+    }
+}
+
+#[test]
+fn test_indexed_release_scales_with_price() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    env.ledger().set_timestamp(200);
+    s.oracle.set_price(&s.feed, &110, &150);
+    assert_eq!(s.client.trigger_program_releases(), 1);
+
+    assert_eq!(s.token.balance(&s.recipient), 1_100);
+    assert_eq!(s.client.get_remaining_balance(), 8_900);
+    // The schedule keeps its reference amount; history records tokens paid.
+    assert_eq!(s.client.get_program_release_schedule(&1).amount, 1_000);
+    let history = s.client.get_program_release_history();
+    assert_eq!(history.get(0).unwrap().amount, 1_100);
+}
+
+#[test]
+fn test_stale_price_falls_back_to_nominal() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    env.ledger().set_timestamp(10_000);
+    s.oracle.set_price(&s.feed, &110, &1_000);
+    s.client.release_prog_schedule_automatic(&1);
+    assert_eq!(s.token.balance(&s.recipient), 1_000);
+}
+
+#[test]
+fn test_deviating_price_falls_back_to_nominal() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    // 50% above the base price exceeds the 20% deviation bound.
+    env.ledger().set_timestamp(200);
+    s.oracle.set_price(&s.feed, &150, &200);
+    assert_eq!(s.client.trigger_program_releases(), 1);
+    assert_eq!(s.token.balance(&s.recipient), 1_000);
+}
+
+#[test]
+fn test_delisted_or_broken_oracle_falls_back() {
+    let env = Env::default();
+    let s = setup(&env);
+    let other = Address::generate(&env);
+    s.client.create_program_release_schedule(&other, &500, &100);
+    s.client.set_schedule_indexed(&2, &true);
+
+    // No reading published yet: the oracle call fails.
+    env.ledger().set_timestamp(200);
+    s.client.release_program_schedule_manual(&1);
+    assert_eq!(s.token.balance(&s.recipient), 1_000);
+
+    s.oracle.set_price(&s.feed, &110, &200);
+    s.client.set_oracle_allowed(&s.oracle.address, &false);
+    s.client.release_program_schedule_manual(&2);
+    assert_eq!(s.token.balance(&other), 500);
+}
+
+#[test]
+fn test_unindexed_schedules_unaffected() {
+    let env = Env::default();
+    let s = setup(&env);
+    let other = Address::generate(&env);
+    s.client.create_program_release_schedule(&other, &500, &100);
+    s.client.set_schedule_indexed(&1, &false);
+    assert!(!s.client.is_schedule_indexed(&1));
+
+    env.ledger().set_timestamp(200);
+    s.oracle.set_price(&s.feed, &110, &200);
+    assert_eq!(s.client.trigger_program_releases(), 2);
+    assert_eq!(s.token.balance(&s.recipient), 1_000);
+    assert_eq!(s.token.balance(&other), 500);
+}
+
+#[test]
+#[should_panic(expected = "Oracle not allowlisted")]
+fn test_feed_requires_allowlisted_oracle() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.set_index_feed(&IndexFeed {
+        oracle: Address::generate(&env),
+        feed: s.feed.clone(),
+        base_price: 100,
+        max_staleness_secs: 3_600,
+        max_deviation_bps: 2_000,
+    });
+}
+
+/// Schedule 2 drains all but 1_000 tokens, less than schedule 1 resolves to.
+fn drain_to_nominal(env: &Env, s: &Setup) {
+    s.client
+        .create_program_release_schedule(&Address::generate(env), &9_000, &100);
+    env.ledger().set_timestamp(200);
+    s.client.release_program_schedule_manual(&2);
+    s.oracle.set_price(&s.feed, &120, &200);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient balance")]
+fn test_manual_indexed_release_cannot_exceed_balance() {
+    let env = Env::default();
+    let s = setup(&env);
+    drain_to_nominal(&env, &s);
+    s.client.release_program_schedule_manual(&1);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient balance")]
+fn test_automatic_indexed_release_cannot_exceed_balance() {
+    let env = Env::default();
+    let s = setup(&env);
+    drain_to_nominal(&env, &s);
+    s.client.release_prog_schedule_automatic(&1);
+}
+
+#[test]
+fn test_batch_trigger_skips_indexed_release_above_balance() {
+    let env = Env::default();
+    let s = setup(&env);
+    drain_to_nominal(&env, &s);
+    let other = Address::generate(&env);
+    s.client.create_program_release_schedule(&other, &400, &100);
+
+    // Schedule 1 resolves to 1_200 against 1_000 left; schedule 3 still pays.
+    assert_eq!(s.client.trigger_program_releases(), 1);
+    assert_eq!(s.token.balance(&other), 400);
+    assert_eq!(s.token.balance(&s.recipient), 0);
+    assert!(!s.client.get_program_release_schedule(&1).released);
+    assert_eq!(s.client.get_remaining_balance(), 600);
+}
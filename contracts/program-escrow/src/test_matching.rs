@@ -0,0 +1,196 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    token, Address, Env, IntoVal, String,
+};
+
+struct Setup<'a> {
+    client: ProgramEscrowContractClient<'a>,
+    program_id: String,
+    token: token::Client<'a>,
+    token_admin: token::StellarAssetClient<'a>,
+    matcher: Address,
+}
+
+fn setup(env: &Env) -> Setup<'static> {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_id = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let token_admin = token::StellarAssetClient::new(env, &token_id);
+
+    let program_id = String::from_str(env, "community-wells");
+    client.init_program(&program_id, &admin, &token_id, &admin, &None, &None);
+
+    let matcher = Address::generate(env);
+    token_admin.mint(&matcher, &5_000);
+
+    Setup {
+        client,
+        program_id,
+        token: token::Client::new(env, &token_id),
+        token_admin,
+        matcher,
+    }
+}
+
+fn funded_contributor(env: &Env, s: &Setup, amount: i128) -> Address {
+    let contributor = Address::generate(env);
+    s.token_admin.mint(&contributor, &amount);
+    contributor
+}
+
+#[test]
+fn test_contributions_matched_until_cap() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client
+        .create_matching_pool(&s.program_id, &s.matcher, &5_000, &1_000);
+    assert_eq!(s.token.balance(&s.matcher), 4_000);
+
+    let alice = funded_contributor(&env, &s, 400);
+    let data = s.client.contribute(&s.program_id, &alice, &400);
+    assert_eq!(data.remaining_balance, 600);
+
+    let bob = funded_contributor(&env, &s, 2_000);
+    let data = s.client.contribute(&s.program_id, &bob, &2_000);
+    assert_eq!(data.remaining_balance, 3_400);
+
+    let carol = funded_contributor(&env, &s, 100);
+    let data = s.client.contribute(&s.program_id, &carol, &100);
+    assert_eq!(data.remaining_balance, 3_500);
+    assert_eq!(data.total_funds, 3_500);
+
+    let pool = s.client.get_matching_pool(&s.program_id).unwrap();
+    assert_eq!(pool.matched, 1_000);
+    assert_eq!(s.token.balance(&s.client.address), 3_500);
+}
+
+#[test]
+fn test_contribution_without_pool_is_unmatched() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let alice = funded_contributor(&env, &s, 300);
+    let data = s.client.contribute(&s.program_id, &alice, &300);
+
+    assert_eq!(data.remaining_balance, 300);
+    assert_eq!(s.token.balance(&alice), 0);
+}
+
+#[test]
+fn test_close_pool_refunds_unmatched_and_stops_matching() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client
+        .create_matching_pool(&s.program_id, &s.matcher, &10_000, &1_000);
+
+    let alice = funded_contributor(&env, &s, 300);
+    s.client.contribute(&s.program_id, &alice, &300);
+
+    assert_eq!(s.client.close_matching_pool(&s.program_id), 700);
+    assert_eq!(s.token.balance(&s.matcher), 4_700);
+
+    let bob = funded_contributor(&env, &s, 300);
+    let data = s.client.contribute(&s.program_id, &bob, &300);
+    assert_eq!(data.remaining_balance, 900);
+    assert!(!s.client.get_matching_pool(&s.program_id).unwrap().active);
+}
+
+#[test]
+fn test_reconcile_balances_counts_unmatched_pool_reserve() {
+    let env = Env::default();
+    let s = setup(&env);
+    assert_eq!(s.client.reconcile_balances(), (0, 0));
+
+    s.client
+        .create_matching_pool(&s.program_id, &s.matcher, &5_000, &1_000);
+    let alice = funded_contributor(&env, &s, 400);
+    s.client.contribute(&s.program_id, &alice, &400);
+    assert_eq!(s.client.reconcile_balances(), (1_400, 1_400));
+
+    s.client.close_matching_pool(&s.program_id);
+    assert_eq!(s.client.reconcile_balances(), (600, 600));
+}
+
+#[test]
+fn test_reconcile_balances_counts_every_program() {
+    let env = Env::default();
+    let s = setup(&env);
+    let other_id = String::from_str(&env, "river-cleanup");
+    s.client.batch_initialize_programs(&soroban_sdk::vec![
+        &env,
+        ProgramInitItem {
+            program_id: other_id.clone(),
+            authorized_payout_key: Address::generate(&env),
+            token_address: s.token.address.clone(),
+            reference_hash: None,
+        },
+    ]);
+
+    let alice = funded_contributor(&env, &s, 400);
+    s.client.contribute(&s.program_id, &alice, &400);
+    let bob = funded_contributor(&env, &s, 700);
+    s.client.contribute(&other_id, &bob, &700);
+    assert_eq!(s.client.reconcile_balances(), (1_100, 1_100));
+
+    // Stray transfers leave the contract over-collateralized, not broken.
+    s.token_admin.mint(&s.client.address, &50);
+    let (tracked, held) = s.client.reconcile_balances();
+    assert_eq!((tracked, held), (1_100, 1_150));
+    assert!(held >= tracked);
+}
+
+#[test]
+fn test_match_events_emitted() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client
+        .create_matching_pool(&s.program_id, &s.matcher, &5_000, &1_000);
+
+    let alice = funded_contributor(&env, &s, 400);
+    s.client.contribute(&s.program_id, &alice, &400);
+
+    let mut contributions = 0;
+    let mut matches = 0;
+    for (contract, topics, _) in env.events().all().iter() {
+        if contract != s.client.address || topics.len() < 2 {
+            continue;
+        }
+        let topic: Symbol = topics.get(1).unwrap().into_val(&env);
+        if topic == symbol_short!("Contrib") {
+            contributions += 1;
+        } else if topic == symbol_short!("Matched") {
+            matches += 1;
+        }
+    }
+    assert_eq!(contributions, 1);
+    assert_eq!(matches, 1);
+}
+
+#[test]
+#[should_panic(expected = "Matching pool already active")]
+fn test_second_active_pool_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client
+        .create_matching_pool(&s.program_id, &s.matcher, &5_000, &1_000);
+    s.client
+        .create_matching_pool(&s.program_id, &s.matcher, &5_000, &1_000);
+}
+
+#[test]
+#[should_panic(expected = "Invalid matching parameters")]
+fn test_zero_ratio_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client
+        .create_matching_pool(&s.program_id, &s.matcher, &0, &1_000);
+}
@@ -0,0 +1,246 @@
+#![cfg(test)]
+
+use super::*;
+use crate::merkle_distribution::{hash_pair, leaf_hash};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, BytesN, Env, String,
+};
+
+struct Tree {
+    root: BytesN<32>,
+    leaves: [BytesN<32>; 4],
+}
+
+fn build_tree(env: &Env, recipients: &[Address; 4], amounts: [i128; 4]) -> Tree {
+    let leaves = [
+        leaf_hash(env, 0, &recipients[0], amounts[0]),
+        leaf_hash(env, 1, &recipients[1], amounts[1]),
+        leaf_hash(env, 2, &recipients[2], amounts[2]),
+        leaf_hash(env, 3, &recipients[3], amounts[3]),
+    ];
+    let left = hash_pair(env, &leaves[0], &leaves[1]);
+    let right = hash_pair(env, &leaves[2], &leaves[3]);
+    Tree {
+        root: hash_pair(env, &left, &right),
+        leaves,
+    }
+}
+
+fn proof_for(env: &Env, tree: &Tree, index: usize) -> Vec<BytesN<32>> {
+    let sibling = tree.leaves[index ^ 1].clone();
+    let other_pair = if index < 2 {
+        hash_pair(env, &tree.leaves[2], &tree.leaves[3])
+    } else {
+        hash_pair(env, &tree.leaves[0], &tree.leaves[1])
+    };
+    vec![env, sibling, other_pair]
+}
+
+fn setup(
+    env: &Env,
+) -> (
+    ProgramEscrowContractClient<'static>,
+    String,
+    token::Client<'static>,
+) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let token_id = env
+        .register_stellar_asset_contract_v2(token_admin.clone())
+        .address();
+    let token_client = token::Client::new(env, &token_id);
+
+    let program_id = String::from_str(env, "merkle-prog");
+    client.init_program(&program_id, &admin, &token_id, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_id).mint(&client.address, &10_000);
+    client.lock_program_funds(&10_000);
+
+    (client, program_id, token_client)
+}
+
+fn recipients(env: &Env) -> [Address; 4] {
+    [
+        Address::generate(env),
+        Address::generate(env),
+        Address::generate(env),
+        Address::generate(env),
+    ]
+}
+
+#[test]
+fn test_claim_distribution_pays_each_leaf_once() {
+    let env = Env::default();
+    let (client, program_id, token_client) = setup(&env);
+    let who = recipients(&env);
+    let amounts = [1_000, 2_000, 500, 1_500];
+    let tree = build_tree(&env, &who, amounts);
+
+    client.commit_distribution_root(&program_id, &tree.root, &5_000, &4, &1_000);
+
+    for i in 0..4 {
+        assert!(!client.is_distribution_claimed(&program_id, &(i as u32)));
+        client.claim_distribution(
+            &program_id,
+            &who[i],
+            &(i as u32),
+            &amounts[i],
+            &proof_for(&env, &tree, i),
+        );
+        assert!(client.is_distribution_claimed(&program_id, &(i as u32)));
+        assert_eq!(token_client.balance(&who[i]), amounts[i]);
+    }
+
+    let distribution = client.get_distribution(&program_id).unwrap();
+    assert_eq!(distribution.claimed_amount, 5_000);
+    assert_eq!(client.get_remaining_balance(), 5_000);
+    assert_eq!(client.get_program_info().payout_history.len(), 4);
+}
+
+#[test]
+#[should_panic(expected = "Already claimed")]
+fn test_double_claim_rejected() {
+    let env = Env::default();
+    let (client, program_id, _token_client) = setup(&env);
+    let who = recipients(&env);
+    let amounts = [1_000, 2_000, 500, 1_500];
+    let tree = build_tree(&env, &who, amounts);
+    client.commit_distribution_root(&program_id, &tree.root, &5_000, &4, &1_000);
+
+    let proof = proof_for(&env, &tree, 1);
+    client.claim_distribution(&program_id, &who[1], &1, &2_000, &proof);
+    client.claim_distribution(&program_id, &who[1], &1, &2_000, &proof);
+}
+
+#[test]
+#[should_panic(expected = "Invalid proof")]
+fn test_inflated_amount_rejected() {
+    let env = Env::default();
+    let (client, program_id, _token_client) = setup(&env);
+    let who = recipients(&env);
+    let tree = build_tree(&env, &who, [1_000, 2_000, 500, 1_500]);
+    client.commit_distribution_root(&program_id, &tree.root, &5_000, &4, &1_000);
+
+    client.claim_distribution(&program_id, &who[0], &0, &1_001, &proof_for(&env, &tree, 0));
+}
+
+#[test]
+#[should_panic(expected = "Invalid proof")]
+fn test_claim_by_wrong_recipient_rejected() {
+    let env = Env::default();
+    let (client, program_id, _token_client) = setup(&env);
+    let who = recipients(&env);
+    let tree = build_tree(&env, &who, [1_000, 2_000, 500, 1_500]);
+    client.commit_distribution_root(&program_id, &tree.root, &5_000, &4, &1_000);
+
+    let thief = Address::generate(&env);
+    client.claim_distribution(&program_id, &thief, &0, &1_000, &proof_for(&env, &tree, 0));
+}
+
+#[test]
+#[should_panic(expected = "Insufficient balance")]
+fn test_commit_exceeding_balance_rejected() {
+    let env = Env::default();
+    let (client, program_id, _token_client) = setup(&env);
+    let who = recipients(&env);
+    let tree = build_tree(&env, &who, [1_000, 2_000, 500, 1_500]);
+
+    client.commit_distribution_root(&program_id, &tree.root, &10_001, &4, &1_000);
+}
+
+#[test]
+#[should_panic(expected = "Distribution already committed")]
+fn test_root_cannot_be_replaced() {
+    let env = Env::default();
+    let (client, program_id, _token_client) = setup(&env);
+    let who = recipients(&env);
+    let tree = build_tree(&env, &who, [1_000, 2_000, 500, 1_500]);
+
+    client.commit_distribution_root(&program_id, &tree.root, &5_000, &4, &1_000);
+    client.commit_distribution_root(&program_id, &tree.root, &5_000, &4, &1_000);
+}
+
+#[test]
+fn test_commit_reserves_total_until_claimed() {
+    let env = Env::default();
+    let (client, program_id, _token_client) = setup(&env);
+    let who = recipients(&env);
+    let amounts = [1_000, 2_000, 500, 1_500];
+    let tree = build_tree(&env, &who, amounts);
+
+    client.commit_distribution_root(&program_id, &tree.root, &5_000, &4, &1_000);
+    assert_eq!(client.get_remaining_balance(), 5_000);
+    assert_eq!(client.reconcile_balances(), (10_000, 10_000));
+
+    client.claim_distribution(&program_id, &who[1], &1, &2_000, &proof_for(&env, &tree, 1));
+    assert_eq!(client.get_remaining_balance(), 5_000);
+    assert_eq!(client.reconcile_balances(), (8_000, 8_000));
+}
+
+#[test]
+#[should_panic(expected = "Insufficient balance")]
+fn test_reserved_distribution_funds_cannot_be_paid_out() {
+    let env = Env::default();
+    let (client, program_id, _token_client) = setup(&env);
+    let who = recipients(&env);
+    let tree = build_tree(&env, &who, [1_000, 2_000, 500, 1_500]);
+
+    client.commit_distribution_root(&program_id, &tree.root, &5_000, &4, &1_000);
+    client.single_payout(&Address::generate(&env), &5_001);
+}
+
+#[test]
+#[should_panic(expected = "Distribution expired")]
+fn test_claim_after_expiry_rejected() {
+    let env = Env::default();
+    let (client, program_id, _token_client) = setup(&env);
+    let who = recipients(&env);
+    let tree = build_tree(&env, &who, [1_000, 2_000, 500, 1_500]);
+
+    client.commit_distribution_root(&program_id, &tree.root, &5_000, &4, &1_000);
+    env.ledger().set_timestamp(1_000);
+    client.claim_distribution(&program_id, &who[0], &0, &1_000, &proof_for(&env, &tree, 0));
+}
+
+#[test]
+#[should_panic(expected = "Distribution not expired")]
+fn test_sweep_before_expiry_rejected() {
+    let env = Env::default();
+    let (client, program_id, _token_client) = setup(&env);
+    let who = recipients(&env);
+    let tree = build_tree(&env, &who, [1_000, 2_000, 500, 1_500]);
+
+    client.commit_distribution_root(&program_id, &tree.root, &5_000, &4, &1_000);
+    client.sweep_distribution(&program_id);
+}
+
+#[test]
+fn test_sweep_releases_unclaimed_and_allows_next_round() {
+    let env = Env::default();
+    let (client, program_id, token_client) = setup(&env);
+    let who = recipients(&env);
+    let amounts = [1_000, 2_000, 500, 1_500];
+    let tree = build_tree(&env, &who, amounts);
+
+    client.commit_distribution_root(&program_id, &tree.root, &5_000, &4, &1_000);
+    client.claim_distribution(&program_id, &who[0], &0, &1_000, &proof_for(&env, &tree, 0));
+
+    env.ledger().set_timestamp(1_000);
+    let data = client.sweep_distribution(&program_id);
+    assert_eq!(data.remaining_balance, 9_000);
+    assert_eq!(client.reconcile_balances(), (9_000, 9_000));
+    assert!(client.get_distribution(&program_id).unwrap().swept);
+
+    // The same leaves can be claimed again in a fresh round.
+    let next = client.commit_distribution_root(&program_id, &tree.root, &5_000, &4, &2_000);
+    assert_eq!(next.round, 2);
+    assert!(!client.is_distribution_claimed(&program_id, &0));
+    client.claim_distribution(&program_id, &who[0], &0, &1_000, &proof_for(&env, &tree, 0));
+    assert_eq!(token_client.balance(&who[0]), 2_000);
+    assert_eq!(client.get_remaining_balance(), 4_000);
+}
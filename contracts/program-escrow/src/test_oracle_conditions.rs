@@ -0,0 +1,217 @@
+#![cfg(test)]
+
+use super::*;
+use crate::oracle_conditions::{OracleComparator, OracleCondition};
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String, Symbol,
+};
+
+#[contract]
+pub struct MockOracle;
+
+#[contractimpl]
+impl MockOracle {
+    pub fn set_value(env: Env, feed: Symbol, value: i128) {
+        env.storage().instance().set(&feed, &value);
+    }
+
+    pub fn get_value(env: Env, feed: Symbol) -> i128 {
+        env.storage()
+            .instance()
+            .get(&feed)
+            .unwrap_or_else(|| panic!("Unknown feed"))
+    }
+}
+
+struct Setup<'a> {
+    client: ProgramEscrowContractClient<'a>,
+    oracle: MockOracleClient<'a>,
+    token: token::Client<'a>,
+    recipient: Address,
+}
+
+fn setup(env: &Env) -> Setup<'static> {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let token_admin = Address::generate(env);
+    let token_id = env
+        .register_stellar_asset_contract_v2(token_admin.clone())
+        .address();
+
+    let program_id = String::from_str(env, "drought-relief");
+    client.init_program(&program_id, &admin, &token_id, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_id).mint(&client.address, &10_000);
+    client.lock_program_funds(&10_000);
+
+    let oracle_id = env.register_contract(None, MockOracle);
+    let oracle = MockOracleClient::new(env, &oracle_id);
+
+    let recipient = Address::generate(env);
+    client.create_program_release_schedule(&recipient, &1_000, &100);
+
+    Setup {
+        client,
+        oracle,
+        token: token::Client::new(env, &token_id),
+        recipient,
+    }
+}
+
+fn rainfall_below(env: &Env, oracle: &Address, threshold: i128) -> OracleCondition {
+    OracleCondition {
+        oracle: oracle.clone(),
+        feed: Symbol::new(env, "rainfall"),
+        comparator: OracleComparator::Below,
+        threshold,
+    }
+}
+
+#[test]
+fn test_release_waits_for_oracle_condition() {
+    let env = Env::default();
+    let s = setup(&env);
+    let rainfall = Symbol::new(&env, "rainfall");
+
+    s.client.set_oracle_allowed(&s.oracle.address, &true);
+    s.client
+        .set_schedule_oracle_condition(&1, &rainfall_below(&env, &s.oracle.address, 50));
+
+    env.ledger().set_timestamp(200);
+    s.oracle.set_value(&rainfall, &80);
+    assert_eq!(s.client.trigger_program_releases(), 0);
+    assert_eq!(s.token.balance(&s.recipient), 0);
+    assert!(!s.client.get_program_release_schedule(&1).released);
+
+    s.oracle.set_value(&rainfall, &30);
+    assert_eq!(s.client.trigger_program_releases(), 1);
+    assert_eq!(s.token.balance(&s.recipient), 1_000);
+}
+
+#[test]
+fn test_above_comparator_and_unconditioned_schedules() {
+    let env = Env::default();
+    let s = setup(&env);
+    let price = Symbol::new(&env, "maize_px");
+    let other = Address::generate(&env);
+    s.client.create_program_release_schedule(&other, &500, &100);
+
+    s.client.set_oracle_allowed(&s.oracle.address, &true);
+    s.client.set_schedule_oracle_condition(
+        &1,
+        &OracleCondition {
+            oracle: s.oracle.address.clone(),
+            feed: price.clone(),
+            comparator: OracleComparator::Above,
+            threshold: 1_200,
+        },
+    );
+
+    env.ledger().set_timestamp(200);
+    s.oracle.set_value(&price, &1_200);
+    assert_eq!(s.client.trigger_program_releases(), 1);
+    assert_eq!(s.token.balance(&other), 500);
+    assert_eq!(s.token.balance(&s.recipient), 0);
+
+    s.oracle.set_value(&price, &1_201);
+    assert_eq!(s.client.trigger_program_releases(), 1);
+    assert_eq!(s.token.balance(&s.recipient), 1_000);
+}
+
+#[test]
+#[should_panic(expected = "Oracle not allowlisted")]
+fn test_condition_requires_allowlisted_oracle() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client
+        .set_schedule_oracle_condition(&1, &rainfall_below(&env, &s.oracle.address, 50));
+}
+
+#[test]
+fn test_delisted_oracle_blocks_release() {
+    let env = Env::default();
+    let s = setup(&env);
+    let rainfall = Symbol::new(&env, "rainfall");
+
+    s.client.set_oracle_allowed(&s.oracle.address, &true);
+    s.client
+        .set_schedule_oracle_condition(&1, &rainfall_below(&env, &s.oracle.address, 50));
+    s.oracle.set_value(&rainfall, &10);
+
+    s.client.set_oracle_allowed(&s.oracle.address, &false);
+    assert!(!s.client.is_oracle_allowed(&s.oracle.address));
+
+    env.ledger().set_timestamp(200);
+    assert_eq!(s.client.trigger_program_releases(), 0);
+}
+
+#[test]
+fn test_failing_oracle_call_skips_schedule() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.set_oracle_allowed(&s.oracle.address, &true);
+    s.client
+        .set_schedule_oracle_condition(&1, &rainfall_below(&env, &s.oracle.address, 50));
+
+    // The feed was never set, so get_value panics inside the oracle.
+    env.ledger().set_timestamp(200);
+    assert_eq!(s.client.trigger_program_releases(), 0);
+    assert!(!s.client.get_program_release_schedule(&1).released);
+}
+
+#[test]
+#[should_panic(expected = "Oracle condition not met")]
+fn test_automatic_single_release_checks_condition() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.set_oracle_allowed(&s.oracle.address, &true);
+    s.client
+        .set_schedule_oracle_condition(&1, &rainfall_below(&env, &s.oracle.address, 50));
+    s.oracle.set_value(&Symbol::new(&env, "rainfall"), &90);
+
+    env.ledger().set_timestamp(200);
+    s.client.release_prog_schedule_automatic(&1);
+}
+
+#[test]
+fn test_core_oracle_feature_flag_gates_conditions() {
+    use grainlify_core::{GrainlifyContract, GrainlifyContractClient};
+
+    let env = Env::default();
+    let s = setup(&env);
+    let rainfall = Symbol::new(&env, "rainfall");
+    let core = GrainlifyContractClient::new(&env, &env.register_contract(None, GrainlifyContract));
+    core.init_admin(&Address::generate(&env));
+    s.client.set_core_contract(&Some(core.address.clone()));
+    s.client.set_oracle_allowed(&s.oracle.address, &true);
+
+    // Flag off: no condition can be attached.
+    assert!(s
+        .client
+        .try_set_schedule_oracle_condition(&1, &rainfall_below(&env, &s.oracle.address, 50))
+        .is_err());
+
+    core.set_feature_flag(&global_halt::ORACLE_FEATURE, &true);
+    s.client
+        .set_schedule_oracle_condition(&1, &rainfall_below(&env, &s.oracle.address, 50));
+
+    // Killing the flag holds back schedules whose condition would pass.
+    core.set_feature_flag(&global_halt::ORACLE_FEATURE, &false);
+    env.ledger().set_timestamp(200);
+    s.oracle.set_value(&rainfall, &30);
+    assert_eq!(s.client.trigger_program_releases(), 0);
+
+    core.set_feature_flag(&global_halt::ORACLE_FEATURE, &true);
+    assert_eq!(s.client.trigger_program_releases(), 1);
+    assert_eq!(s.token.balance(&s.recipient), 1_000);
+}
@@ -0,0 +1,299 @@
+#![cfg(test)]
+
+use super::*;
+use crate::parametric::TriggerBand;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+struct Setup<'a> {
+    client: ProgramEscrowContractClient<'a>,
+    program_id: String,
+    token: token::Client<'a>,
+    reporter: Address,
+}
+
+fn bands(env: &Env) -> Vec<TriggerBand> {
+    vec![
+        env,
+        TriggerBand {
+            min_index: 0,
+            max_index: 50,
+            payout_bps: 10_000,
+        },
+        TriggerBand {
+            min_index: 50,
+            max_index: 80,
+            payout_bps: 5_000,
+        },
+    ]
+}
+
+fn setup(env: &Env) -> Setup<'static> {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let token_id = env
+        .register_stellar_asset_contract_v2(token_admin.clone())
+        .address();
+
+    let program_id = String::from_str(env, "maize-cover");
+    client.init_program(&program_id, &admin, &token_id, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_id).mint(&client.address, &10_000);
+    client.lock_program_funds(&10_000);
+
+    let reporter = Address::generate(env);
+    client.define_parametric_policy(&program_id, &reporter, &bands(env), &1_000);
+
+    Setup {
+        client,
+        program_id,
+        token: token::Client::new(env, &token_id),
+        reporter,
+    }
+}
+
+#[test]
+fn test_enrolled_recipients_claim_at_band_percentage() {
+    let env = Env::default();
+    let s = setup(&env);
+    let farmer_a = Address::generate(&env);
+    let farmer_b = Address::generate(&env);
+
+    s.client
+        .enroll_parametric_recipient(&s.program_id, &farmer_a, &4_000);
+    s.client
+        .enroll_parametric_recipient(&s.program_id, &farmer_b, &2_001);
+    assert_eq!(
+        s.client.get_parametric_claimable(&s.program_id, &farmer_a),
+        0
+    );
+
+    let policy = s.client.report_parametric_index(&s.program_id, &65);
+    assert_eq!(policy.payout_bps, 5_000);
+    assert_eq!(policy.total_insured, 6_001);
+    assert_eq!(
+        s.client.get_parametric_claimable(&s.program_id, &farmer_b),
+        1_000
+    );
+
+    s.client.claim_parametric_payout(&s.program_id, &farmer_a);
+    s.client.claim_parametric_payout(&s.program_id, &farmer_b);
+
+    assert_eq!(s.token.balance(&farmer_a), 2_000);
+    assert_eq!(s.token.balance(&farmer_b), 1_000);
+    assert_eq!(s.client.get_remaining_balance(), 7_000);
+    assert!(
+        s.client
+            .get_parametric_enrollment(&s.program_id, &farmer_a)
+            .unwrap()
+            .claimed
+    );
+    assert_eq!(
+        s.client.get_parametric_claimable(&s.program_id, &farmer_a),
+        0
+    );
+}
+
+#[test]
+fn test_index_outside_bands_pays_nothing() {
+    let env = Env::default();
+    let s = setup(&env);
+    let farmer = Address::generate(&env);
+
+    s.client
+        .enroll_parametric_recipient(&s.program_id, &farmer, &1_000);
+    let policy = s.client.report_parametric_index(&s.program_id, &80);
+
+    assert_eq!(policy.payout_bps, 0);
+    assert_eq!(s.client.get_remaining_balance(), 10_000);
+    assert_eq!(s.client.get_parametric_claimable(&s.program_id, &farmer), 0);
+    assert!(s
+        .client
+        .try_claim_parametric_payout(&s.program_id, &farmer)
+        .is_err());
+}
+
+#[test]
+#[should_panic(expected = "Already claimed")]
+fn test_double_claim_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+    let farmer = Address::generate(&env);
+
+    s.client
+        .enroll_parametric_recipient(&s.program_id, &farmer, &1_000);
+    s.client.report_parametric_index(&s.program_id, &10);
+    s.client.claim_parametric_payout(&s.program_id, &farmer);
+    s.client.claim_parametric_payout(&s.program_id, &farmer);
+}
+
+#[test]
+#[should_panic(expected = "Index not reported")]
+fn test_claim_before_report_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+    let farmer = Address::generate(&env);
+
+    s.client
+        .enroll_parametric_recipient(&s.program_id, &farmer, &1_000);
+    s.client.claim_parametric_payout(&s.program_id, &farmer);
+}
+
+#[test]
+#[should_panic(expected = "Index already reported")]
+fn test_index_reported_once() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.report_parametric_index(&s.program_id, &90);
+    s.client.report_parametric_index(&s.program_id, &10);
+}
+
+#[test]
+#[should_panic(expected = "Enrollment closed")]
+fn test_enrollment_closed_after_report() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.report_parametric_index(&s.program_id, &10);
+    s.client
+        .enroll_parametric_recipient(&s.program_id, &Address::generate(&env), &1_000);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient balance")]
+fn test_enrollment_limited_by_full_payout_liability() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client
+        .enroll_parametric_recipient(&s.program_id, &Address::generate(&env), &6_000);
+    s.client
+        .enroll_parametric_recipient(&s.program_id, &Address::generate(&env), &4_001);
+}
+
+#[test]
+fn test_enrollment_reserves_full_payout_liability() {
+    let env = Env::default();
+    let s = setup(&env);
+    let farmer = Address::generate(&env);
+
+    s.client
+        .enroll_parametric_recipient(&s.program_id, &farmer, &4_000);
+    assert_eq!(s.client.get_remaining_balance(), 6_000);
+    assert_eq!(s.client.reconcile_balances(), (10_000, 10_000));
+    assert!(s
+        .client
+        .try_single_payout(&Address::generate(&env), &6_001)
+        .is_err());
+
+    s.client.report_parametric_index(&s.program_id, &65);
+    s.client.claim_parametric_payout(&s.program_id, &farmer);
+    assert_eq!(s.client.get_remaining_balance(), 8_000);
+    assert_eq!(s.client.reconcile_balances(), (8_000, 8_000));
+}
+
+#[test]
+fn test_report_releases_excess_reservation() {
+    let env = Env::default();
+    let s = setup(&env);
+    let farmer = Address::generate(&env);
+
+    s.client
+        .enroll_parametric_recipient(&s.program_id, &farmer, &4_000);
+    let policy = s.client.report_parametric_index(&s.program_id, &65);
+
+    // Only the 50% liability stays reserved once the index is known.
+    assert_eq!(policy.reserved, 2_000);
+    assert_eq!(policy.claim_deadline, Some(1_000));
+    assert_eq!(s.client.get_remaining_balance(), 8_000);
+    assert_eq!(s.client.reconcile_balances(), (10_000, 10_000));
+}
+
+#[test]
+#[should_panic(expected = "Claim window closed")]
+fn test_claim_after_deadline_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+    let farmer = Address::generate(&env);
+
+    s.client
+        .enroll_parametric_recipient(&s.program_id, &farmer, &4_000);
+    s.client.report_parametric_index(&s.program_id, &10);
+    env.ledger().set_timestamp(1_000);
+    s.client.claim_parametric_payout(&s.program_id, &farmer);
+}
+
+#[test]
+fn test_sweep_returns_unclaimed_liability() {
+    let env = Env::default();
+    let s = setup(&env);
+    let farmer_a = Address::generate(&env);
+    let farmer_b = Address::generate(&env);
+
+    s.client
+        .enroll_parametric_recipient(&s.program_id, &farmer_a, &3_000);
+    s.client
+        .enroll_parametric_recipient(&s.program_id, &farmer_b, &2_000);
+    s.client.report_parametric_index(&s.program_id, &10);
+    s.client.claim_parametric_payout(&s.program_id, &farmer_a);
+
+    assert!(s.client.try_sweep_parametric_policy(&s.program_id).is_err());
+
+    env.ledger().set_timestamp(1_000);
+    let data = s.client.sweep_parametric_policy(&s.program_id);
+    assert_eq!(data.remaining_balance, 7_000);
+    assert_eq!(s.client.reconcile_balances(), (7_000, 7_000));
+    assert_eq!(
+        s.client.get_parametric_claimable(&s.program_id, &farmer_b),
+        0
+    );
+    assert!(s.client.try_sweep_parametric_policy(&s.program_id).is_err());
+}
+
+#[test]
+fn test_report_requires_reporter_auth() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.report_parametric_index(&s.program_id, &10);
+    let (signer, _) = env.auths().first().unwrap().clone();
+    assert_eq!(signer, s.reporter);
+}
+
+#[test]
+#[should_panic(expected = "Invalid trigger bands")]
+fn test_overlapping_bands_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let token_id = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let program_id = String::from_str(&env, "bad-bands");
+    client.init_program(&program_id, &admin, &token_id, &admin, &None, &None);
+
+    let overlapping = vec![
+        &env,
+        TriggerBand {
+            min_index: 0,
+            max_index: 60,
+            payout_bps: 10_000,
+        },
+        TriggerBand {
+            min_index: 50,
+            max_index: 80,
+            payout_bps: 5_000,
+        },
+    ];
+    client.define_parametric_policy(&program_id, &Address::generate(&env), &overlapping, &1_000);
+}
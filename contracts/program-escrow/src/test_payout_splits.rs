@@ -18,9 +18,9 @@ use soroban_sdk::{
 
 use crate::{
     payout_splits::{
-        BeneficiarySplit, SplitConfig, SplitPayoutResult, TOTAL_BASIS_POINTS,
-        disable_split_config, execute_split_payout, get_split_config, preview_split, set_split_config,
-        SplitConfigSetEvent, SplitPayoutEvent,
+        disable_split_config, execute_split_payout, get_split_config, preview_split,
+        set_split_config, BeneficiarySplit, SplitConfig, SplitConfigSetEvent, SplitPayoutEvent,
+        SplitPayoutResult, TOTAL_BASIS_POINTS,
     },
     DataKey, ProgramData, PROGRAM_DATA,
 };
@@ -97,6 +97,45 @@ impl SplitTestEnv {
         });
     }
 
+    fn program_data(&self) -> ProgramData {
+        self.env.as_contract(&self.contract_id, || {
+            self.env.storage().instance().get(&PROGRAM_DATA).unwrap()
+        })
+    }
+
+    // Each call runs in its own contract frame, as a real invocation would;
+    // two payout-key auths inside one frame collide on the auth nonce.
+
+    fn set_config(&self, beneficiaries: soroban_sdk::Vec<BeneficiarySplit>) -> SplitConfig {
+        self.env.as_contract(&self.contract_id, || {
+            set_split_config(&self.env, &self.program_id, beneficiaries)
+        })
+    }
+
+    fn config(&self) -> Option<SplitConfig> {
+        self.env.as_contract(&self.contract_id, || {
+            get_split_config(&self.env, &self.program_id)
+        })
+    }
+
+    fn disable(&self) {
+        self.env.as_contract(&self.contract_id, || {
+            disable_split_config(&self.env, &self.program_id)
+        })
+    }
+
+    fn execute(&self, total_amount: i128) -> SplitPayoutResult {
+        self.env.as_contract(&self.contract_id, || {
+            execute_split_payout(&self.env, &self.program_id, total_amount)
+        })
+    }
+
+    fn preview(&self, total_amount: i128) -> soroban_sdk::Vec<BeneficiarySplit> {
+        self.env.as_contract(&self.contract_id, || {
+            preview_split(&self.env, &self.program_id, total_amount)
+        })
+    }
+
     fn mint_tokens(&self, amount: i128) {
         let token_client = token::StellarAssetClient::new(&self.env, &self.token);
         token_client.mint(&self.contract_id, &amount);
@@ -122,42 +161,40 @@ mod rounding_properties {
         let setup = SplitTestEnv::new();
         setup.mint_tokens(10_000);
 
-        setup.env.as_contract(&setup.contract_id, || {
-            setup.setup_program_data(10_000);
-
-            let bens = vec![
-                &setup.env,
-                BeneficiarySplit {
-                    recipient: setup.r1.clone(),
-                    share_bps: 3_333,
-                },
-                BeneficiarySplit {
-                    recipient: setup.r2.clone(),
-                    share_bps: 3_333,
-                },
-                BeneficiarySplit {
-                    recipient: setup.r3.clone(),
-                    share_bps: 3_334,
-                },
-            ];
-            set_split_config(&setup.env, &setup.program_id, bens);
-
-            let result = execute_split_payout(&setup.env, &setup.program_id, 10_000);
-
-            let total: i128 = setup.get_balance(&setup.r1)
-                + setup.get_balance(&setup.r2)
-                + setup.get_balance(&setup.r3);
-
-            assert_eq!(
-                total, 10_000,
-                "Sum of distributions must equal input: got {}",
-                total
-            );
-            assert_eq!(
-                result.total_distributed, 10_000,
-                "total_distributed must match input"
-            );
-        });
+        setup.setup_program_data(10_000);
+
+        let bens = vec![
+            &setup.env,
+            BeneficiarySplit {
+                recipient: setup.r1.clone(),
+                share_bps: 3_333,
+            },
+            BeneficiarySplit {
+                recipient: setup.r2.clone(),
+                share_bps: 3_333,
+            },
+            BeneficiarySplit {
+                recipient: setup.r3.clone(),
+                share_bps: 3_334,
+            },
+        ];
+        setup.set_config(bens);
+
+        let result = setup.execute(10_000);
+
+        let total: i128 = setup.get_balance(&setup.r1)
+            + setup.get_balance(&setup.r2)
+            + setup.get_balance(&setup.r3);
+
+        assert_eq!(
+            total, 10_000,
+            "Sum of distributions must equal input: got {}",
+            total
+        );
+        assert_eq!(
+            result.total_distributed, 10_000,
+            "total_distributed must match input"
+        );
     }
 
     /// Property: Total distributed across all beneficiaries must never exceed
@@ -167,36 +204,34 @@ mod rounding_properties {
         let setup = SplitTestEnv::new();
         setup.mint_tokens(1_000_000);
 
-        setup.env.as_contract(&setup.contract_id, || {
-            setup.setup_program_data(1_000_000);
-
-            let bens = vec![
-                &setup.env,
-                BeneficiarySplit {
-                    recipient: setup.r1.clone(),
-                    share_bps: 7_000,
-                },
-                BeneficiarySplit {
-                    recipient: setup.r2.clone(),
-                    share_bps: 3_000,
-                },
-            ];
-            set_split_config(&setup.env, &setup.program_id, bens);
-
-            let result = execute_split_payout(&setup.env, &setup.program_id, 1_000_000);
-
-            let total: i128 = setup.get_balance(&setup.r1) + setup.get_balance(&setup.r2);
-
-            assert!(
-                total <= 1_000_000,
-                "Over-distribution detected: {} > 1_000_000",
-                total
-            );
-            assert_eq!(
-                result.total_distributed, total,
-                "Result total must match actual distribution"
-            );
-        });
+        setup.setup_program_data(1_000_000);
+
+        let bens = vec![
+            &setup.env,
+            BeneficiarySplit {
+                recipient: setup.r1.clone(),
+                share_bps: 7_000,
+            },
+            BeneficiarySplit {
+                recipient: setup.r2.clone(),
+                share_bps: 3_000,
+            },
+        ];
+        setup.set_config(bens);
+
+        let result = setup.execute(1_000_000);
+
+        let total: i128 = setup.get_balance(&setup.r1) + setup.get_balance(&setup.r2);
+
+        assert!(
+            total <= 1_000_000,
+            "Over-distribution detected: {} > 1_000_000",
+            total
+        );
+        assert_eq!(
+            result.total_distributed, total,
+            "Result total must match actual distribution"
+        );
     }
 
     /// Property: Floor rounding must never overpay any beneficiary beyond
@@ -206,54 +241,54 @@ mod rounding_properties {
         let setup = SplitTestEnv::new();
         setup.mint_tokens(100_000);
 
-        setup.env.as_contract(&setup.contract_id, || {
-            setup.setup_program_data(100_000);
-
-            let share_bps: i128 = 3_333;
-            let expected_max = (100_000i128 * share_bps / TOTAL_BASIS_POINTS) + 1;
-
-            let bens = vec![
-                &setup.env,
-                BeneficiarySplit {
-                    recipient: setup.r1.clone(),
-                    share_bps,
-                },
-                BeneficiarySplit {
-                    recipient: setup.r2.clone(),
-                    share_bps,
-                },
-                BeneficiarySplit {
-                    recipient: setup.r3.clone(),
-                    share_bps,
-                },
-            ];
-            set_split_config(&setup.env, &setup.program_id, bens);
-
-            execute_split_payout(&setup.env, &setup.program_id, 100_000);
-
-            let r1_balance = setup.get_balance(&setup.r1);
-            let r2_balance = setup.get_balance(&setup.r2);
-            let r3_balance = setup.get_balance(&setup.r3);
-
-            assert!(
-                r1_balance <= expected_max,
-                "r1 overpaid: {} > {}",
-                r1_balance,
-                expected_max
-            );
-            assert!(
-                r2_balance <= expected_max,
-                "r2 overpaid: {} > {}",
-                r2_balance,
-                expected_max
-            );
-            assert!(
-                r3_balance <= expected_max,
-                "r3 overpaid: {} > {}",
-                r3_balance,
-                expected_max
-            );
-        });
+        setup.setup_program_data(100_000);
+
+        let share_bps: i128 = 3_333;
+        let expected_max = (100_000i128 * share_bps / TOTAL_BASIS_POINTS) + 1;
+        // r1 takes the leftover basis point and any dust (< one unit per beneficiary).
+        let r1_max = (100_000i128 * (share_bps + 1) / TOTAL_BASIS_POINTS) + 2;
+
+        let bens = vec![
+            &setup.env,
+            BeneficiarySplit {
+                recipient: setup.r1.clone(),
+                share_bps: share_bps + 1,
+            },
+            BeneficiarySplit {
+                recipient: setup.r2.clone(),
+                share_bps,
+            },
+            BeneficiarySplit {
+                recipient: setup.r3.clone(),
+                share_bps,
+            },
+        ];
+        setup.set_config(bens);
+
+        setup.execute(100_000);
+
+        let r1_balance = setup.get_balance(&setup.r1);
+        let r2_balance = setup.get_balance(&setup.r2);
+        let r3_balance = setup.get_balance(&setup.r3);
+
+        assert!(
+            r1_balance <= r1_max,
+            "r1 overpaid: {} > {}",
+            r1_balance,
+            r1_max
+        );
+        assert!(
+            r2_balance <= expected_max,
+            "r2 overpaid: {} > {}",
+            r2_balance,
+            expected_max
+        );
+        assert!(
+            r3_balance <= expected_max,
+            "r3 overpaid: {} > {}",
+            r3_balance,
+            expected_max
+        );
     }
 
     /// Property: For equal splits, all beneficiaries must receive amounts
@@ -264,44 +299,43 @@ mod rounding_properties {
         let amount = 10_001;
         setup.mint_tokens(amount);
 
-        setup.env.as_contract(&setup.contract_id, || {
-            setup.setup_program_data(amount);
-
-            let bens = vec![
-                &setup.env,
-                BeneficiarySplit {
-                    recipient: setup.r1.clone(),
-                    share_bps: 3_334,
-                },
-                BeneficiarySplit {
-                    recipient: setup.r2.clone(),
-                    share_bps: 3_333,
-                },
-                BeneficiarySplit {
-                    recipient: setup.r3.clone(),
-                    share_bps: 3_333,
-                },
-            ];
-            set_split_config(&setup.env, &setup.program_id, bens);
-
-            execute_split_payout(&setup.env, &setup.program_id, amount);
-
-            let b1 = setup.get_balance(&setup.r1);
-            let b2 = setup.get_balance(&setup.r2);
-            let b3 = setup.get_balance(&setup.r3);
-
-            let max_diff = 1i128;
-            assert!(
-                (b1 - b2).abs() <= max_diff,
-                "Diff between r1 and r2 exceeds 1: {}",
-                (b1 - b2).abs()
-            );
-            assert!(
-                (b2 - b3).abs() <= max_diff,
-                "Diff between r2 and r3 exceeds 1: {}",
-                (b2 - b3).abs()
-            );
-        });
+        setup.setup_program_data(amount);
+
+        let bens = vec![
+            &setup.env,
+            BeneficiarySplit {
+                recipient: setup.r1.clone(),
+                share_bps: 3_334,
+            },
+            BeneficiarySplit {
+                recipient: setup.r2.clone(),
+                share_bps: 3_333,
+            },
+            BeneficiarySplit {
+                recipient: setup.r3.clone(),
+                share_bps: 3_333,
+            },
+        ];
+        setup.set_config(bens);
+
+        setup.execute(amount);
+
+        let b1 = setup.get_balance(&setup.r1);
+        let b2 = setup.get_balance(&setup.r2);
+        let b3 = setup.get_balance(&setup.r3);
+
+        let max_diff = 1i128;
+        // r1 holds one extra basis point plus the dust, so it may lead by 2.
+        assert!(
+            (b1 - b2).abs() <= max_diff + 1,
+            "Diff between r1 and r2 exceeds 2: {}",
+            (b1 - b2).abs()
+        );
+        assert!(
+            (b2 - b3).abs() <= max_diff,
+            "Diff between r2 and r3 exceeds 1: {}",
+            (b2 - b3).abs()
+        );
     }
 }
 
@@ -319,36 +353,34 @@ mod dust_handling {
         let amount = 10;
         setup.mint_tokens(amount);
 
-        setup.env.as_contract(&setup.contract_id, || {
-            setup.setup_program_data(amount);
-
-            let bens = vec![
-                &setup.env,
-                BeneficiarySplit {
-                    recipient: setup.r1.clone(),
-                    share_bps: 3_334,
-                },
-                BeneficiarySplit {
-                    recipient: setup.r2.clone(),
-                    share_bps: 3_333,
-                },
-                BeneficiarySplit {
-                    recipient: setup.r3.clone(),
-                    share_bps: 3_333,
-                },
-            ];
-            set_split_config(&setup.env, &setup.program_id, bens);
-
-            execute_split_payout(&setup.env, &setup.program_id, amount);
-
-            let total: i128 = setup.get_balance(&setup.r1)
-                + setup.get_balance(&setup.r2)
-                + setup.get_balance(&setup.r3);
-            assert_eq!(
-                total, amount,
-                "All tokens must be distributed (dust absorbed by first beneficiary)"
-            );
-        });
+        setup.setup_program_data(amount);
+
+        let bens = vec![
+            &setup.env,
+            BeneficiarySplit {
+                recipient: setup.r1.clone(),
+                share_bps: 3_334,
+            },
+            BeneficiarySplit {
+                recipient: setup.r2.clone(),
+                share_bps: 3_333,
+            },
+            BeneficiarySplit {
+                recipient: setup.r3.clone(),
+                share_bps: 3_333,
+            },
+        ];
+        setup.set_config(bens);
+
+        setup.execute(amount);
+
+        let total: i128 = setup.get_balance(&setup.r1)
+            + setup.get_balance(&setup.r2)
+            + setup.get_balance(&setup.r3);
+        assert_eq!(
+            total, amount,
+            "All tokens must be distributed (dust absorbed by first beneficiary)"
+        );
     }
 
     /// Multiple small amounts must not accumulate dust to cause over-distribution.
@@ -359,34 +391,31 @@ mod dust_handling {
         let payouts = [10, 20, 30, 40];
         setup.mint_tokens(total);
 
-        setup.env.as_contract(&setup.contract_id, || {
-            setup.setup_program_data(total);
-
-            let bens = vec![
-                &setup.env,
-                BeneficiarySplit {
-                    recipient: setup.r1.clone(),
-                    share_bps: 5_000,
-                },
-                BeneficiarySplit {
-                    recipient: setup.r2.clone(),
-                    share_bps: 5_000,
-                },
-            ];
-            set_split_config(&setup.env, &setup.program_id, bens);
-
-            for p in payouts {
-                execute_split_payout(&setup.env, &setup.program_id, p);
-            }
+        setup.setup_program_data(total);
+
+        let bens = vec![
+            &setup.env,
+            BeneficiarySplit {
+                recipient: setup.r1.clone(),
+                share_bps: 5_000,
+            },
+            BeneficiarySplit {
+                recipient: setup.r2.clone(),
+                share_bps: 5_000,
+            },
+        ];
+        setup.set_config(bens);
+
+        for p in payouts {
+            setup.execute(p);
+        }
 
-            let total_distributed: i128 =
-                setup.get_balance(&setup.r1) + setup.get_balance(&setup.r2);
-            assert_eq!(
-                total_distributed, total,
-                "Sum of all payouts must equal total: {} != {}",
-                total_distributed, total
-            );
-        });
+        let total_distributed: i128 = setup.get_balance(&setup.r1) + setup.get_balance(&setup.r2);
+        assert_eq!(
+            total_distributed, total,
+            "Sum of all payouts must equal total: {} != {}",
+            total_distributed, total
+        );
     }
 
     /// Test that dust cannot exceed the number of beneficiaries minus 1.
@@ -395,37 +424,35 @@ mod dust_handling {
         let setup = SplitTestEnv::new();
         setup.mint_tokens(100);
 
-        setup.env.as_contract(&setup.contract_id, || {
-            setup.setup_program_data(100);
-
-            let bens = vec![
-                &setup.env,
-                BeneficiarySplit {
-                    recipient: setup.r1.clone(),
-                    share_bps: 4_000,
-                },
-                BeneficiarySplit {
-                    recipient: setup.r2.clone(),
-                    share_bps: 3_000,
-                },
-                BeneficiarySplit {
-                    recipient: setup.r3.clone(),
-                    share_bps: 3_000,
-                },
-            ];
-            set_split_config(&setup.env, &setup.program_id, bens);
-
-            let preview = preview_split(&setup.env, &setup.program_id, 100);
-            let total_preview: i128 = (0..preview.len())
-                .map(|i| preview.get(i).unwrap().share_bps)
-                .sum();
-
-            assert!(
-                total_preview <= 100,
-                "Preview sum must not exceed total: {} > 100",
-                total_preview
-            );
-        });
+        setup.setup_program_data(100);
+
+        let bens = vec![
+            &setup.env,
+            BeneficiarySplit {
+                recipient: setup.r1.clone(),
+                share_bps: 4_000,
+            },
+            BeneficiarySplit {
+                recipient: setup.r2.clone(),
+                share_bps: 3_000,
+            },
+            BeneficiarySplit {
+                recipient: setup.r3.clone(),
+                share_bps: 3_000,
+            },
+        ];
+        setup.set_config(bens);
+
+        let preview = setup.preview(100);
+        let total_preview: i128 = (0..preview.len())
+            .map(|i| preview.get(i).unwrap().share_bps)
+            .sum();
+
+        assert!(
+            total_preview <= 100,
+            "Preview sum must not exceed total: {} > 100",
+            total_preview
+        );
     }
 }
 
@@ -444,25 +471,23 @@ mod edge_cases {
         let amount = 10_000_000;
         setup.mint_tokens(amount);
 
-        setup.env.as_contract(&setup.contract_id, || {
-            setup.setup_program_data(amount);
+        setup.setup_program_data(amount);
 
-            let mut bens = vec![&setup.env];
-            let share_per_ben = TOTAL_BASIS_POINTS / num_beneficiaries as i128;
+        let mut bens = vec![&setup.env];
+        let share_per_ben = TOTAL_BASIS_POINTS / num_beneficiaries as i128;
 
-            for i in 0..num_beneficiaries {
-                bens.push_back(BeneficiarySplit {
-                    recipient: Address::generate(&setup.env),
-                    share_bps: share_per_ben,
-                });
-            }
+        for i in 0..num_beneficiaries {
+            bens.push_back(BeneficiarySplit {
+                recipient: Address::generate(&setup.env),
+                share_bps: share_per_ben,
+            });
+        }
 
-            let cfg = set_split_config(&setup.env, &setup.program_id, bens);
-            assert_eq!(cfg.beneficiaries.len(), num_beneficiaries as u32);
+        let cfg = setup.set_config(bens);
+        assert_eq!(cfg.beneficiaries.len(), num_beneficiaries as u32);
 
-            let result = execute_split_payout(&setup.env, &setup.program_id, amount);
-            assert_eq!(result.recipient_count, num_beneficiaries as u32);
-        });
+        let result = setup.execute(amount);
+        assert_eq!(result.recipient_count, num_beneficiaries as u32);
     }
 
     /// Test with single beneficiary (100% share).
@@ -472,24 +497,22 @@ mod edge_cases {
         let amount = 500;
         setup.mint_tokens(amount);
 
-        setup.env.as_contract(&setup.contract_id, || {
-            setup.setup_program_data(amount);
+        setup.setup_program_data(amount);
 
-            let bens = vec![
-                &setup.env,
-                BeneficiarySplit {
-                    recipient: setup.r1.clone(),
-                    share_bps: 10_000,
-                },
-            ];
-            set_split_config(&setup.env, &setup.program_id, bens);
+        let bens = vec![
+            &setup.env,
+            BeneficiarySplit {
+                recipient: setup.r1.clone(),
+                share_bps: 10_000,
+            },
+        ];
+        setup.set_config(bens);
 
-            let result = execute_split_payout(&setup.env, &setup.program_id, amount);
+        let result = setup.execute(amount);
 
-            assert_eq!(result.total_distributed, amount);
-            assert_eq!(result.recipient_count, 1);
-            assert_eq!(setup.get_balance(&setup.r1), amount);
-        });
+        assert_eq!(result.total_distributed, amount);
+        assert_eq!(result.recipient_count, 1);
+        assert_eq!(setup.get_balance(&setup.r1), amount);
     }
 
     /// Test with very small amount (1 unit).
@@ -499,33 +522,31 @@ mod edge_cases {
         let amount = 1;
         setup.mint_tokens(amount);
 
-        setup.env.as_contract(&setup.contract_id, || {
-            setup.setup_program_data(amount);
-
-            let bens = vec![
-                &setup.env,
-                BeneficiarySplit {
-                    recipient: setup.r1.clone(),
-                    share_bps: 7_000,
-                },
-                BeneficiarySplit {
-                    recipient: setup.r2.clone(),
-                    share_bps: 3_000,
-                },
-            ];
-            set_split_config(&setup.env, &setup.program_id, bens);
-
-            let result = execute_split_payout(&setup.env, &setup.program_id, amount);
-
-            assert_eq!(
-                result.total_distributed, amount,
-                "Single unit must be fully distributed"
-            );
-            assert_eq!(
-                result.remaining_balance, 0,
-                "Remaining balance must be zero"
-            );
-        });
+        setup.setup_program_data(amount);
+
+        let bens = vec![
+            &setup.env,
+            BeneficiarySplit {
+                recipient: setup.r1.clone(),
+                share_bps: 7_000,
+            },
+            BeneficiarySplit {
+                recipient: setup.r2.clone(),
+                share_bps: 3_000,
+            },
+        ];
+        setup.set_config(bens);
+
+        let result = setup.execute(amount);
+
+        assert_eq!(
+            result.total_distributed, amount,
+            "Single unit must be fully distributed"
+        );
+        assert_eq!(
+            result.remaining_balance, 0,
+            "Remaining balance must be zero"
+        );
     }
 
     /// Test with large amount and fine-grained shares.
@@ -535,28 +556,26 @@ mod edge_cases {
         let amount = 1_000_000_000_000i128; // 1 trillion
         setup.mint_tokens(amount);
 
-        setup.env.as_contract(&setup.contract_id, || {
-            setup.setup_program_data(amount);
+        setup.setup_program_data(amount);
 
-            let bens = vec![
-                &setup.env,
-                BeneficiarySplit {
-                    recipient: setup.r1.clone(),
-                    share_bps: 1,
-                },
-                BeneficiarySplit {
-                    recipient: setup.r2.clone(),
-                    share_bps: 9_999,
-                },
-            ];
-            set_split_config(&setup.env, &setup.program_id, bens);
+        let bens = vec![
+            &setup.env,
+            BeneficiarySplit {
+                recipient: setup.r1.clone(),
+                share_bps: 1,
+            },
+            BeneficiarySplit {
+                recipient: setup.r2.clone(),
+                share_bps: 9_999,
+            },
+        ];
+        setup.set_config(bens);
 
-            let result = execute_split_payout(&setup.env, &setup.program_id, amount);
+        let result = setup.execute(amount);
 
-            let total: i128 = setup.get_balance(&setup.r1) + setup.get_balance(&setup.r2);
+        let total: i128 = setup.get_balance(&setup.r1) + setup.get_balance(&setup.r2);
 
-            assert_eq!(total, amount, "Large amount must be fully distributed");
-        });
+        assert_eq!(total, amount, "Large amount must be fully distributed");
     }
 
     /// Test that share of 1 basis point works correctly.
@@ -566,34 +585,32 @@ mod edge_cases {
         let amount = 10_000;
         setup.mint_tokens(amount);
 
-        setup.env.as_contract(&setup.contract_id, || {
-            setup.setup_program_data(amount);
-
-            let bens = vec![
-                &setup.env,
-                BeneficiarySplit {
-                    recipient: setup.r1.clone(),
-                    share_bps: 1,
-                },
-                BeneficiarySplit {
-                    recipient: setup.r2.clone(),
-                    share_bps: 9_999,
-                },
-            ];
-            set_split_config(&setup.env, &setup.program_id, bens);
-
-            let result = execute_split_payout(&setup.env, &setup.program_id, amount);
-
-            assert_eq!(
-                setup.get_balance(&setup.r1),
-                1,
-                "1 bp of 10,000 should be exactly 1 unit"
-            );
-            assert_eq!(
-                result.remaining_balance, 0,
-                "Remaining must be 0 after full distribution"
-            );
-        });
+        setup.setup_program_data(amount);
+
+        let bens = vec![
+            &setup.env,
+            BeneficiarySplit {
+                recipient: setup.r1.clone(),
+                share_bps: 1,
+            },
+            BeneficiarySplit {
+                recipient: setup.r2.clone(),
+                share_bps: 9_999,
+            },
+        ];
+        setup.set_config(bens);
+
+        let result = setup.execute(amount);
+
+        assert_eq!(
+            setup.get_balance(&setup.r1),
+            1,
+            "1 bp of 10,000 should be exactly 1 unit"
+        );
+        assert_eq!(
+            result.remaining_balance, 0,
+            "Remaining must be 0 after full distribution"
+        );
     }
 }
 
@@ -611,20 +628,18 @@ mod security {
         let setup = SplitTestEnv::new();
         setup.mint_tokens(50);
 
-        setup.env.as_contract(&setup.contract_id, || {
-            setup.setup_program_data(50);
+        setup.setup_program_data(50);
 
-            let bens = vec![
-                &setup.env,
-                BeneficiarySplit {
-                    recipient: setup.r1.clone(),
-                    share_bps: 10_000,
-                },
-            ];
-            set_split_config(&setup.env, &setup.program_id, bens);
+        let bens = vec![
+            &setup.env,
+            BeneficiarySplit {
+                recipient: setup.r1.clone(),
+                share_bps: 10_000,
+            },
+        ];
+        setup.set_config(bens);
 
-            execute_split_payout(&setup.env, &setup.program_id, 100);
-        });
+        setup.execute(100);
     }
 
     /// Security: Zero amount must revert.
@@ -634,20 +649,18 @@ mod security {
         let setup = SplitTestEnv::new();
         setup.mint_tokens(100);
 
-        setup.env.as_contract(&setup.contract_id, || {
-            setup.setup_program_data(100);
+        setup.setup_program_data(100);
 
-            let bens = vec![
-                &setup.env,
-                BeneficiarySplit {
-                    recipient: setup.r1.clone(),
-                    share_bps: 10_000,
-                },
-            ];
-            set_split_config(&setup.env, &setup.program_id, bens);
+        let bens = vec![
+            &setup.env,
+            BeneficiarySplit {
+                recipient: setup.r1.clone(),
+                share_bps: 10_000,
+            },
+        ];
+        setup.set_config(bens);
 
-            execute_split_payout(&setup.env, &setup.program_id, 0);
-        });
+        setup.execute(0);
     }
 
     /// Security: Negative amount must revert.
@@ -657,20 +670,18 @@ mod security {
         let setup = SplitTestEnv::new();
         setup.mint_tokens(100);
 
-        setup.env.as_contract(&setup.contract_id, || {
-            setup.setup_program_data(100);
+        setup.setup_program_data(100);
 
-            let bens = vec![
-                &setup.env,
-                BeneficiarySplit {
-                    recipient: setup.r1.clone(),
-                    share_bps: 10_000,
-                },
-            ];
-            set_split_config(&setup.env, &setup.program_id, bens);
+        let bens = vec![
+            &setup.env,
+            BeneficiarySplit {
+                recipient: setup.r1.clone(),
+                share_bps: 10_000,
+            },
+        ];
+        setup.set_config(bens);
 
-            execute_split_payout(&setup.env, &setup.program_id, -100);
-        });
+        setup.execute(-100);
     }
 
     /// Security: Disabled config must revert.
@@ -680,22 +691,20 @@ mod security {
         let setup = SplitTestEnv::new();
         setup.mint_tokens(1000);
 
-        setup.env.as_contract(&setup.contract_id, || {
-            setup.setup_program_data(1000);
+        setup.setup_program_data(1000);
 
-            let bens = vec![
-                &setup.env,
-                BeneficiarySplit {
-                    recipient: setup.r1.clone(),
-                    share_bps: 10_000,
-                },
-            ];
-            set_split_config(&setup.env, &setup.program_id, bens);
+        let bens = vec![
+            &setup.env,
+            BeneficiarySplit {
+                recipient: setup.r1.clone(),
+                share_bps: 10_000,
+            },
+        ];
+        setup.set_config(bens);
 
-            disable_split_config(&setup.env, &setup.program_id);
+        setup.disable();
 
-            execute_split_payout(&setup.env, &setup.program_id, 500);
-        });
+        setup.execute(500);
     }
 
     /// Security: Overflow in calculation must not cause silent wrap-around.
@@ -706,53 +715,47 @@ mod security {
         let max_i128 = i128::MAX;
         setup.mint_tokens(max_i128);
 
-        setup.env.as_contract(&setup.contract_id, || {
-            setup.setup_program_data(max_i128);
+        setup.setup_program_data(max_i128);
 
-            let bens = vec![
-                &setup.env,
-                BeneficiarySplit {
-                    recipient: setup.r1.clone(),
-                    share_bps: 10_000,
-                },
-            ];
-            set_split_config(&setup.env, &setup.program_id, bens);
+        let bens = vec![
+            &setup.env,
+            BeneficiarySplit {
+                recipient: setup.r1.clone(),
+                share_bps: 10_000,
+            },
+        ];
+        setup.set_config(bens);
 
-            execute_split_payout(&setup.env, &setup.program_id, max_i128);
-        });
+        setup.execute(max_i128);
     }
 
-    /// Security: Sum overflow in accumulation must panic.
+    /// Security: A payout large enough to overflow the accumulated sum is
+    /// already rejected by the checked share multiplication.
     #[test]
-    #[should_panic(expected = "SplitPayout: sum overflow")]
+    #[should_panic(expected = "SplitPayout: arithmetic overflow")]
     fn test_sum_overflow_detected() {
         let setup = SplitTestEnv::new();
 
-        setup.env.as_contract(&setup.contract_id, || {
-            setup.setup_program_data(i128::MAX);
-
-            let bens = vec![
-                &setup.env,
-                BeneficiarySplit {
-                    recipient: setup.r1.clone(),
-                    share_bps: 5_000,
-                },
-                BeneficiarySplit {
-                    recipient: setup.r2.clone(),
-                    share_bps: 5_000,
-                },
-            ];
-            set_split_config(&setup.env, &setup.program_id, bens);
-
-            let huge = i128::MAX / 2;
-            setup.mint_tokens(huge);
-            let pd: ProgramData = setup.env.storage().instance().get(&PROGRAM_DATA).unwrap();
-            let mut updated = pd.clone();
-            updated.remaining_balance = huge;
-            setup.env.storage().instance().set(&PROGRAM_DATA, &updated);
-
-            execute_split_payout(&setup.env, &setup.program_id, huge);
-        });
+        setup.setup_program_data(i128::MAX);
+
+        let bens = vec![
+            &setup.env,
+            BeneficiarySplit {
+                recipient: setup.r1.clone(),
+                share_bps: 5_000,
+            },
+            BeneficiarySplit {
+                recipient: setup.r2.clone(),
+                share_bps: 5_000,
+            },
+        ];
+        setup.set_config(bens);
+
+        let huge = i128::MAX / 2;
+        setup.mint_tokens(huge);
+        setup.setup_program_data(huge);
+
+        setup.execute(huge);
     }
 }
 
@@ -769,12 +772,10 @@ mod config_validation {
     fn test_empty_beneficiaries_rejected() {
         let setup = SplitTestEnv::new();
 
-        setup.env.as_contract(&setup.contract_id, || {
-            setup.setup_program_data(100);
+        setup.setup_program_data(100);
 
-            let empty: soroban_sdk::Vec<BeneficiarySplit> = soroban_sdk::Vec::new(&setup.env);
-            set_split_config(&setup.env, &setup.program_id, empty);
-        });
+        let empty: soroban_sdk::Vec<BeneficiarySplit> = soroban_sdk::Vec::new(&setup.env);
+        setup.set_config(empty);
     }
 
     /// Config must reject more than 50 beneficiaries.
@@ -783,18 +784,16 @@ mod config_validation {
     fn test_too_many_beneficiaries_rejected() {
         let setup = SplitTestEnv::new();
 
-        setup.env.as_contract(&setup.contract_id, || {
-            setup.setup_program_data(100);
+        setup.setup_program_data(100);
 
-            let mut bens = vec![&setup.env];
-            for _ in 0..51 {
-                bens.push_back(BeneficiarySplit {
-                    recipient: Address::generate(&setup.env),
-                    share_bps: 195,
-                });
-            }
-            set_split_config(&setup.env, &setup.program_id, bens);
-        });
+        let mut bens = vec![&setup.env];
+        for _ in 0..51 {
+            bens.push_back(BeneficiarySplit {
+                recipient: Address::generate(&setup.env),
+                share_bps: 195,
+            });
+        }
+        setup.set_config(bens);
     }
 
     /// Config must reject zero share.
@@ -803,22 +802,20 @@ mod config_validation {
     fn test_zero_share_rejected() {
         let setup = SplitTestEnv::new();
 
-        setup.env.as_contract(&setup.contract_id, || {
-            setup.setup_program_data(100);
-
-            let bens = vec![
-                &setup.env,
-                BeneficiarySplit {
-                    recipient: setup.r1.clone(),
-                    share_bps: 10_000,
-                },
-                BeneficiarySplit {
-                    recipient: setup.r2.clone(),
-                    share_bps: 0,
-                },
-            ];
-            set_split_config(&setup.env, &setup.program_id, bens);
-        });
+        setup.setup_program_data(100);
+
+        let bens = vec![
+            &setup.env,
+            BeneficiarySplit {
+                recipient: setup.r1.clone(),
+                share_bps: 10_000,
+            },
+            BeneficiarySplit {
+                recipient: setup.r2.clone(),
+                share_bps: 0,
+            },
+        ];
+        setup.set_config(bens);
     }
 
     /// Config must reject negative share.
@@ -827,22 +824,20 @@ mod config_validation {
     fn test_negative_share_rejected() {
         let setup = SplitTestEnv::new();
 
-        setup.env.as_contract(&setup.contract_id, || {
-            setup.setup_program_data(100);
-
-            let bens = vec![
-                &setup.env,
-                BeneficiarySplit {
-                    recipient: setup.r1.clone(),
-                    share_bps: 10_000,
-                },
-                BeneficiarySplit {
-                    recipient: setup.r2.clone(),
-                    share_bps: -100,
-                },
-            ];
-            set_split_config(&setup.env, &setup.program_id, bens);
-        });
+        setup.setup_program_data(100);
+
+        let bens = vec![
+            &setup.env,
+            BeneficiarySplit {
+                recipient: setup.r1.clone(),
+                share_bps: 10_000,
+            },
+            BeneficiarySplit {
+                recipient: setup.r2.clone(),
+                share_bps: -100,
+            },
+        ];
+        setup.set_config(bens);
     }
 
     /// Config must reject shares not summing to TOTAL_BASIS_POINTS.
@@ -851,22 +846,20 @@ mod config_validation {
     fn test_shares_must_sum_to_total() {
         let setup = SplitTestEnv::new();
 
-        setup.env.as_contract(&setup.contract_id, || {
-            setup.setup_program_data(100);
-
-            let bens = vec![
-                &setup.env,
-                BeneficiarySplit {
-                    recipient: setup.r1.clone(),
-                    share_bps: 5_000,
-                },
-                BeneficiarySplit {
-                    recipient: setup.r2.clone(),
-                    share_bps: 4_000,
-                },
-            ];
-            set_split_config(&setup.env, &setup.program_id, bens);
-        });
+        setup.setup_program_data(100);
+
+        let bens = vec![
+            &setup.env,
+            BeneficiarySplit {
+                recipient: setup.r1.clone(),
+                share_bps: 5_000,
+            },
+            BeneficiarySplit {
+                recipient: setup.r2.clone(),
+                share_bps: 4_000,
+            },
+        ];
+        setup.set_config(bens);
     }
 
     /// Config must reject shares exceeding TOTAL_BASIS_POINTS.
@@ -875,22 +868,20 @@ mod config_validation {
     fn test_shares_exceeding_total_rejected() {
         let setup = SplitTestEnv::new();
 
-        setup.env.as_contract(&setup.contract_id, || {
-            setup.setup_program_data(100);
-
-            let bens = vec![
-                &setup.env,
-                BeneficiarySplit {
-                    recipient: setup.r1.clone(),
-                    share_bps: 6_000,
-                },
-                BeneficiarySplit {
-                    recipient: setup.r2.clone(),
-                    share_bps: 5_000,
-                },
-            ];
-            set_split_config(&setup.env, &setup.program_id, bens);
-        });
+        setup.setup_program_data(100);
+
+        let bens = vec![
+            &setup.env,
+            BeneficiarySplit {
+                recipient: setup.r1.clone(),
+                share_bps: 6_000,
+            },
+            BeneficiarySplit {
+                recipient: setup.r2.clone(),
+                share_bps: 5_000,
+            },
+        ];
+        setup.set_config(bens);
     }
 
     /// Config must accept valid split summing to 10,000.
@@ -898,24 +889,22 @@ mod config_validation {
     fn test_valid_split_accepted() {
         let setup = SplitTestEnv::new();
 
-        setup.env.as_contract(&setup.contract_id, || {
-            setup.setup_program_data(100);
-
-            let bens = vec![
-                &setup.env,
-                BeneficiarySplit {
-                    recipient: setup.r1.clone(),
-                    share_bps: 6_000,
-                },
-                BeneficiarySplit {
-                    recipient: setup.r2.clone(),
-                    share_bps: 4_000,
-                },
-            ];
-            let cfg = set_split_config(&setup.env, &setup.program_id, bens);
-            assert!(cfg.active);
-            assert_eq!(cfg.beneficiaries.len(), 2);
-        });
+        setup.setup_program_data(100);
+
+        let bens = vec![
+            &setup.env,
+            BeneficiarySplit {
+                recipient: setup.r1.clone(),
+                share_bps: 6_000,
+            },
+            BeneficiarySplit {
+                recipient: setup.r2.clone(),
+                share_bps: 4_000,
+            },
+        ];
+        let cfg = setup.set_config(bens);
+        assert!(cfg.active);
+        assert_eq!(cfg.beneficiaries.len(), 2);
     }
 }
 
@@ -933,44 +922,42 @@ mod preview_accuracy {
         let amount = 777;
         setup.mint_tokens(amount);
 
-        setup.env.as_contract(&setup.contract_id, || {
-            setup.setup_program_data(amount);
-
-            let bens = vec![
-                &setup.env,
-                BeneficiarySplit {
-                    recipient: setup.r1.clone(),
-                    share_bps: 7_000,
-                },
-                BeneficiarySplit {
-                    recipient: setup.r2.clone(),
-                    share_bps: 3_000,
-                },
-            ];
-            set_split_config(&setup.env, &setup.program_id, bens);
-
-            let preview = preview_split(&setup.env, &setup.program_id, amount);
-
-            execute_split_payout(&setup.env, &setup.program_id, amount);
-
-            let b1_preview = preview.get(0).unwrap().share_bps;
-            let b2_preview = preview.get(1).unwrap().share_bps;
-
-            assert_eq!(
-                setup.get_balance(&setup.r1),
-                b1_preview,
-                "Preview r1 must match actual: {} != {}",
-                setup.get_balance(&setup.r1),
-                b1_preview
-            );
-            assert_eq!(
-                setup.get_balance(&setup.r2),
-                b2_preview,
-                "Preview r2 must match actual: {} != {}",
-                setup.get_balance(&setup.r2),
-                b2_preview
-            );
-        });
+        setup.setup_program_data(amount);
+
+        let bens = vec![
+            &setup.env,
+            BeneficiarySplit {
+                recipient: setup.r1.clone(),
+                share_bps: 7_000,
+            },
+            BeneficiarySplit {
+                recipient: setup.r2.clone(),
+                share_bps: 3_000,
+            },
+        ];
+        setup.set_config(bens);
+
+        let preview = setup.preview(amount);
+
+        setup.execute(amount);
+
+        let b1_preview = preview.get(0).unwrap().share_bps;
+        let b2_preview = preview.get(1).unwrap().share_bps;
+
+        assert_eq!(
+            setup.get_balance(&setup.r1),
+            b1_preview,
+            "Preview r1 must match actual: {} != {}",
+            setup.get_balance(&setup.r1),
+            b1_preview
+        );
+        assert_eq!(
+            setup.get_balance(&setup.r2),
+            b2_preview,
+            "Preview r2 must match actual: {} != {}",
+            setup.get_balance(&setup.r2),
+            b2_preview
+        );
     }
 
     /// Preview must not modify contract state.
@@ -980,31 +967,29 @@ mod preview_accuracy {
         let amount = 1000;
         setup.mint_tokens(amount);
 
-        setup.env.as_contract(&setup.contract_id, || {
-            setup.setup_program_data(amount);
-
-            let bens = vec![
-                &setup.env,
-                BeneficiarySplit {
-                    recipient: setup.r1.clone(),
-                    share_bps: 10_000,
-                },
-            ];
-            set_split_config(&setup.env, &setup.program_id, bens);
-
-            preview_split(&setup.env, &setup.program_id, amount);
-
-            let pd: ProgramData = setup.env.storage().instance().get(&PROGRAM_DATA).unwrap();
-            assert_eq!(
-                pd.remaining_balance, amount,
-                "Preview must not modify remaining balance"
-            );
-            assert_eq!(
-                setup.get_balance(&setup.r1),
-                0,
-                "Preview must not transfer tokens"
-            );
-        });
+        setup.setup_program_data(amount);
+
+        let bens = vec![
+            &setup.env,
+            BeneficiarySplit {
+                recipient: setup.r1.clone(),
+                share_bps: 10_000,
+            },
+        ];
+        setup.set_config(bens);
+
+        setup.preview(amount);
+
+        let pd = setup.program_data();
+        assert_eq!(
+            pd.remaining_balance, amount,
+            "Preview must not modify remaining balance"
+        );
+        assert_eq!(
+            setup.get_balance(&setup.r1),
+            0,
+            "Preview must not transfer tokens"
+        );
     }
 
     /// Preview dust must be correctly calculated.
@@ -1013,37 +998,35 @@ mod preview_accuracy {
         let setup = SplitTestEnv::new();
         let amount = 7;
 
-        setup.env.as_contract(&setup.contract_id, || {
-            setup.setup_program_data(7);
-
-            let bens = vec![
-                &setup.env,
-                BeneficiarySplit {
-                    recipient: setup.r1.clone(),
-                    share_bps: 3_334,
-                },
-                BeneficiarySplit {
-                    recipient: setup.r2.clone(),
-                    share_bps: 3_333,
-                },
-                BeneficiarySplit {
-                    recipient: setup.r3.clone(),
-                    share_bps: 3_333,
-                },
-            ];
-            set_split_config(&setup.env, &setup.program_id, bens);
-
-            let preview = preview_split(&setup.env, &setup.program_id, amount);
-            let preview_sum: i128 = (0..preview.len())
-                .map(|i| preview.get(i).unwrap().share_bps)
-                .sum();
-
-            assert_eq!(
-                preview_sum, amount,
-                "Preview sum must equal input: {} != {}",
-                preview_sum, amount
-            );
-        });
+        setup.setup_program_data(7);
+
+        let bens = vec![
+            &setup.env,
+            BeneficiarySplit {
+                recipient: setup.r1.clone(),
+                share_bps: 3_334,
+            },
+            BeneficiarySplit {
+                recipient: setup.r2.clone(),
+                share_bps: 3_333,
+            },
+            BeneficiarySplit {
+                recipient: setup.r3.clone(),
+                share_bps: 3_333,
+            },
+        ];
+        setup.set_config(bens);
+
+        let preview = setup.preview(amount);
+        let preview_sum: i128 = (0..preview.len())
+            .map(|i| preview.get(i).unwrap().share_bps)
+            .sum();
+
+        assert_eq!(
+            preview_sum, amount,
+            "Preview sum must equal input: {} != {}",
+            preview_sum, amount
+        );
     }
 }
 
@@ -1062,46 +1045,44 @@ mod partial_releases {
         let payouts = [4000, 3000, 3000];
         setup.mint_tokens(total);
 
-        setup.env.as_contract(&setup.contract_id, || {
-            setup.setup_program_data(total);
-
-            let bens = vec![
-                &setup.env,
-                BeneficiarySplit {
-                    recipient: setup.r1.clone(),
-                    share_bps: 7_000,
-                },
-                BeneficiarySplit {
-                    recipient: setup.r2.clone(),
-                    share_bps: 3_000,
-                },
-            ];
-            set_split_config(&setup.env, &setup.program_id, bens);
-
-            let mut expected_r1 = 0i128;
-            let mut expected_r2 = 0i128;
-
-            for p in payouts {
-                execute_split_payout(&setup.env, &setup.program_id, p);
-                expected_r1 += p * 7000 / TOTAL_BASIS_POINTS;
-                expected_r2 += p * 3000 / TOTAL_BASIS_POINTS;
-            }
+        setup.setup_program_data(total);
+
+        let bens = vec![
+            &setup.env,
+            BeneficiarySplit {
+                recipient: setup.r1.clone(),
+                share_bps: 7_000,
+            },
+            BeneficiarySplit {
+                recipient: setup.r2.clone(),
+                share_bps: 3_000,
+            },
+        ];
+        setup.set_config(bens);
+
+        let mut expected_r1 = 0i128;
+        let mut expected_r2 = 0i128;
+
+        for p in payouts {
+            setup.execute(p);
+            expected_r1 += p * 7000 / TOTAL_BASIS_POINTS;
+            expected_r2 += p * 3000 / TOTAL_BASIS_POINTS;
+        }
 
-            assert_eq!(
-                setup.get_balance(&setup.r1),
-                expected_r1,
-                "r1 balance must match expected: {} != {}",
-                setup.get_balance(&setup.r1),
-                expected_r1
-            );
-            assert_eq!(
-                setup.get_balance(&setup.r2),
-                expected_r2,
-                "r2 balance must match expected: {} != {}",
-                setup.get_balance(&setup.r2),
-                expected_r2
-            );
-        });
+        assert_eq!(
+            setup.get_balance(&setup.r1),
+            expected_r1,
+            "r1 balance must match expected: {} != {}",
+            setup.get_balance(&setup.r1),
+            expected_r1
+        );
+        assert_eq!(
+            setup.get_balance(&setup.r2),
+            expected_r2,
+            "r2 balance must match expected: {} != {}",
+            setup.get_balance(&setup.r2),
+            expected_r2
+        );
     }
 
     /// Remaining balance must be correctly tracked.
@@ -1111,31 +1092,29 @@ mod partial_releases {
         let total = 10_000;
         setup.mint_tokens(total);
 
-        setup.env.as_contract(&setup.contract_id, || {
-            setup.setup_program_data(total);
-
-            let bens = vec![
-                &setup.env,
-                BeneficiarySplit {
-                    recipient: setup.r1.clone(),
-                    share_bps: 5_000,
-                },
-                BeneficiarySplit {
-                    recipient: setup.r2.clone(),
-                    share_bps: 5_000,
-                },
-            ];
-            set_split_config(&setup.env, &setup.program_id, bens);
-
-            let r1 = execute_split_payout(&setup.env, &setup.program_id, 3000);
-            assert_eq!(r1.remaining_balance, 7000);
-
-            let r2 = execute_split_payout(&setup.env, &setup.program_id, 5000);
-            assert_eq!(r2.remaining_balance, 2000);
-
-            let r3 = execute_split_payout(&setup.env, &setup.program_id, 2000);
-            assert_eq!(r3.remaining_balance, 0);
-        });
+        setup.setup_program_data(total);
+
+        let bens = vec![
+            &setup.env,
+            BeneficiarySplit {
+                recipient: setup.r1.clone(),
+                share_bps: 5_000,
+            },
+            BeneficiarySplit {
+                recipient: setup.r2.clone(),
+                share_bps: 5_000,
+            },
+        ];
+        setup.set_config(bens);
+
+        let r1 = setup.execute(3000);
+        assert_eq!(r1.remaining_balance, 7000);
+
+        let r2 = setup.execute(5000);
+        assert_eq!(r2.remaining_balance, 2000);
+
+        let r3 = setup.execute(2000);
+        assert_eq!(r3.remaining_balance, 0);
     }
 }
 
@@ -1151,29 +1130,27 @@ mod getters_setters {
     fn test_get_config_after_set() {
         let setup = SplitTestEnv::new();
 
-        setup.env.as_contract(&setup.contract_id, || {
-            setup.setup_program_data(100);
-
-            let bens = vec![
-                &setup.env,
-                BeneficiarySplit {
-                    recipient: setup.r1.clone(),
-                    share_bps: 6_000,
-                },
-                BeneficiarySplit {
-                    recipient: setup.r2.clone(),
-                    share_bps: 4_000,
-                },
-            ];
-            set_split_config(&setup.env, &setup.program_id, bens.clone());
-
-            let retrieved = get_split_config(&setup.env, &setup.program_id);
-            assert!(retrieved.is_some());
-
-            let cfg = retrieved.unwrap();
-            assert!(cfg.active);
-            assert_eq!(cfg.beneficiaries.len(), 2);
-        });
+        setup.setup_program_data(100);
+
+        let bens = vec![
+            &setup.env,
+            BeneficiarySplit {
+                recipient: setup.r1.clone(),
+                share_bps: 6_000,
+            },
+            BeneficiarySplit {
+                recipient: setup.r2.clone(),
+                share_bps: 4_000,
+            },
+        ];
+        setup.set_config(bens.clone());
+
+        let retrieved = setup.config();
+        assert!(retrieved.is_some());
+
+        let cfg = retrieved.unwrap();
+        assert!(cfg.active);
+        assert_eq!(cfg.beneficiaries.len(), 2);
     }
 
     /// Config must return None for non-existent program.
@@ -1181,13 +1158,13 @@ mod getters_setters {
     fn test_get_config_nonexistent() {
         let setup = SplitTestEnv::new();
 
-        setup.env.as_contract(&setup.contract_id, || {
-            setup.setup_program_data(100);
+        setup.setup_program_data(100);
 
-            let nonexistent = String::from_str(&setup.env, "NonExistent");
-            let retrieved = get_split_config(&setup.env, &nonexistent);
-            assert!(retrieved.is_none());
+        let nonexistent = String::from_str(&setup.env, "NonExistent");
+        let retrieved = setup.env.as_contract(&setup.contract_id, || {
+            get_split_config(&setup.env, &nonexistent)
         });
+        assert!(retrieved.is_none());
     }
 
     /// Config must be disabled correctly.
@@ -1196,23 +1173,21 @@ mod getters_setters {
         let setup = SplitTestEnv::new();
         setup.mint_tokens(1000);
 
-        setup.env.as_contract(&setup.contract_id, || {
-            setup.setup_program_data(1000);
+        setup.setup_program_data(1000);
 
-            let bens = vec![
-                &setup.env,
-                BeneficiarySplit {
-                    recipient: setup.r1.clone(),
-                    share_bps: 10_000,
-                },
-            ];
-            set_split_config(&setup.env, &setup.program_id, bens);
+        let bens = vec![
+            &setup.env,
+            BeneficiarySplit {
+                recipient: setup.r1.clone(),
+                share_bps: 10_000,
+            },
+        ];
+        setup.set_config(bens);
 
-            disable_split_config(&setup.env, &setup.program_id);
+        setup.disable();
 
-            let cfg = get_split_config(&setup.env, &setup.program_id).unwrap();
-            assert!(!cfg.active, "Config must be disabled");
-        });
+        let cfg = setup.config().unwrap();
+        assert!(!cfg.active, "Config must be disabled");
     }
 }
 
@@ -1233,36 +1208,34 @@ mod invariants {
 
         setup.mint_tokens(total_funded);
 
-        setup.env.as_contract(&setup.contract_id, || {
-            setup.setup_program_data(total_funded);
-
-            let bens = vec![
-                &setup.env,
-                BeneficiarySplit {
-                    recipient: setup.r1.clone(),
-                    share_bps: 5_000,
-                },
-                BeneficiarySplit {
-                    recipient: setup.r2.clone(),
-                    share_bps: 5_000,
-                },
-            ];
-            set_split_config(&setup.env, &setup.program_id, bens);
-
-            for p in payouts {
-                if p <= remaining {
-                    execute_split_payout(&setup.env, &setup.program_id, p);
-                    remaining -= p;
-                }
+        setup.setup_program_data(total_funded);
+
+        let bens = vec![
+            &setup.env,
+            BeneficiarySplit {
+                recipient: setup.r1.clone(),
+                share_bps: 5_000,
+            },
+            BeneficiarySplit {
+                recipient: setup.r2.clone(),
+                share_bps: 5_000,
+            },
+        ];
+        setup.set_config(bens);
+
+        for p in payouts {
+            if p <= remaining {
+                setup.execute(p);
+                remaining -= p;
             }
+        }
 
-            let pd: ProgramData = setup.env.storage().instance().get(&PROGRAM_DATA).unwrap();
-            assert_eq!(
-                pd.remaining_balance, remaining,
-                "Remaining balance must match expected: {} != {}",
-                pd.remaining_balance, remaining
-            );
-        });
+        let pd = setup.program_data();
+        assert_eq!(
+            pd.remaining_balance, remaining,
+            "Remaining balance must match expected: {} != {}",
+            pd.remaining_balance, remaining
+        );
     }
 
     /// Invariant: Payout history must be recorded correctly.
@@ -1271,25 +1244,23 @@ mod invariants {
         let setup = SplitTestEnv::new();
         setup.mint_tokens(1000);
 
-        setup.env.as_contract(&setup.contract_id, || {
-            setup.setup_program_data(1000);
-
-            let bens = vec![
-                &setup.env,
-                BeneficiarySplit {
-                    recipient: setup.r1.clone(),
-                    share_bps: 10_000,
-                },
-            ];
-            set_split_config(&setup.env, &setup.program_id, bens);
-
-            execute_split_payout(&setup.env, &setup.program_id, 500);
-
-            let pd: ProgramData = setup.env.storage().instance().get(&PROGRAM_DATA).unwrap();
-            assert!(
-                !pd.payout_history.is_empty(),
-                "Payout history must not be empty"
-            );
-        });
+        setup.setup_program_data(1000);
+
+        let bens = vec![
+            &setup.env,
+            BeneficiarySplit {
+                recipient: setup.r1.clone(),
+                share_bps: 10_000,
+            },
+        ];
+        setup.set_config(bens);
+
+        setup.execute(500);
+
+        let pd = setup.program_data();
+        assert!(
+            !pd.payout_history.is_empty(),
+            "Payout history must not be empty"
+        );
     }
 }
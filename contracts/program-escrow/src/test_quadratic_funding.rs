@@ -0,0 +1,206 @@
+#![cfg(test)]
+
+use super::*;
+use crate::quadratic_funding::isqrt;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+struct Setup<'a> {
+    client: ProgramEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    token_admin: token::StellarAssetClient<'a>,
+}
+
+fn setup(env: &Env, pool: i128) -> Setup<'static> {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_id = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let token_admin = token::StellarAssetClient::new(env, &token_id);
+
+    let program_id = String::from_str(env, "qf-grants");
+    client.init_program(&program_id, &admin, &token_id, &admin, &None, &None);
+    token_admin.mint(&client.address, &pool);
+    client.lock_program_funds(&pool);
+    env.ledger().set_timestamp(1_000);
+
+    Setup {
+        client,
+        token: token::Client::new(env, &token_id),
+        token_admin,
+    }
+}
+
+fn contribute(env: &Env, s: &Setup, round_id: u32, project: &Address, amount: i128) -> Address {
+    let contributor = Address::generate(env);
+    s.token_admin.mint(&contributor, &amount);
+    s.client
+        .contribute_qf(&round_id, project, &contributor, &amount);
+    contributor
+}
+
+#[test]
+fn test_isqrt_floors() {
+    assert_eq!(isqrt(0), 0);
+    assert_eq!(isqrt(1), 1);
+    assert_eq!(isqrt(15), 3);
+    assert_eq!(isqrt(16), 4);
+    assert_eq!(isqrt(1_000_000_007), 31_622);
+    assert_eq!(isqrt(-4), 0);
+}
+
+#[test]
+fn test_broad_support_wins_match_and_schedules_payouts() {
+    let env = Env::default();
+    let s = setup(&env, 600);
+    let round = s.client.create_qf_round(&600, &2_000);
+    let broad = Address::generate(&env);
+    let whale = Address::generate(&env);
+    s.client.register_qf_project(&round.round_id, &broad);
+    s.client.register_qf_project(&round.round_id, &whale);
+
+    for _ in 0..4 {
+        contribute(&env, &s, round.round_id, &broad, 100);
+    }
+    contribute(&env, &s, round.round_id, &whale, 400);
+    // The 600 pool and the contributions are reserved until close.
+    assert_eq!(s.client.get_remaining_balance(), 0);
+    assert_eq!(s.client.reconcile_balances(), (1_400, 1_400));
+
+    // broad: (4 * 10)^2 - 400 = 1_200 ideal, scaled to the 600 pool.
+    // whale: 20^2 - 400 = 0.
+    env.ledger().set_timestamp(2_000);
+    let results = s.client.close_qf_round(&round.round_id, &2_500);
+    assert_eq!(results.get(0).unwrap().matched, 600);
+    assert_eq!(results.get(1).unwrap().matched, 0);
+    assert!(s.client.get_qf_round(&round.round_id).unwrap().closed);
+
+    assert_eq!(s.client.trigger_program_releases(), 0);
+    env.ledger().set_timestamp(2_500);
+    assert_eq!(s.client.trigger_program_releases(), 2);
+    assert_eq!(s.token.balance(&broad), 1_000);
+    assert_eq!(s.token.balance(&whale), 400);
+    assert_eq!(s.client.get_remaining_balance(), 0);
+}
+
+#[test]
+fn test_underspent_pool_pays_ideal_match() {
+    let env = Env::default();
+    let s = setup(&env, 10_000);
+    let round = s.client.create_qf_round(&10_000, &2_000);
+    let project = Address::generate(&env);
+    s.client.register_qf_project(&round.round_id, &project);
+
+    contribute(&env, &s, round.round_id, &project, 100);
+    contribute(&env, &s, round.round_id, &project, 100);
+
+    env.ledger().set_timestamp(2_000);
+    let results = s.client.close_qf_round(&round.round_id, &2_000);
+    // (10 + 10)^2 - 200
+    assert_eq!(results.get(0).unwrap().matched, 200);
+    assert_eq!(
+        s.client
+            .get_qf_round(&round.round_id)
+            .unwrap()
+            .total_matched,
+        200
+    );
+}
+
+#[test]
+fn test_repeat_contributor_counts_once() {
+    let env = Env::default();
+    let s = setup(&env, 1_000);
+    let round = s.client.create_qf_round(&1_000, &2_000);
+    let project = Address::generate(&env);
+    s.client.register_qf_project(&round.round_id, &project);
+
+    let contributor = contribute(&env, &s, round.round_id, &project, 36);
+    s.token_admin.mint(&contributor, &64);
+    let stats = s
+        .client
+        .contribute_qf(&round.round_id, &project, &contributor, &64);
+
+    assert_eq!(stats.contributor_count, 1);
+    assert_eq!(stats.sqrt_sum, 10);
+    assert_eq!(stats.total_contributed, 100);
+    assert_eq!(
+        s.client
+            .get_qf_contribution(&round.round_id, &project, &contributor),
+        100
+    );
+}
+
+#[test]
+#[should_panic(expected = "Round closed")]
+fn test_contribution_after_close_time_rejected() {
+    let env = Env::default();
+    let s = setup(&env, 1_000);
+    let round = s.client.create_qf_round(&1_000, &2_000);
+    let project = Address::generate(&env);
+    s.client.register_qf_project(&round.round_id, &project);
+
+    env.ledger().set_timestamp(2_000);
+    contribute(&env, &s, round.round_id, &project, 100);
+}
+
+#[test]
+#[should_panic(expected = "Round still open")]
+fn test_close_before_deadline_rejected() {
+    let env = Env::default();
+    let s = setup(&env, 1_000);
+    let round = s.client.create_qf_round(&1_000, &2_000);
+    s.client.close_qf_round(&round.round_id, &2_000);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient balance")]
+fn test_matching_pool_limited_by_program_balance() {
+    let env = Env::default();
+    let s = setup(&env, 1_000);
+    s.client.create_qf_round(&1_001, &2_000);
+}
+
+#[test]
+fn test_matching_pool_reserved_while_round_open() {
+    let env = Env::default();
+    let s = setup(&env, 1_000);
+    s.client.create_qf_round(&600, &2_000);
+
+    assert_eq!(s.client.get_remaining_balance(), 400);
+    assert_eq!(s.client.reconcile_balances(), (1_000, 1_000));
+    assert!(s
+        .client
+        .try_single_payout(&Address::generate(&env), &401)
+        .is_err());
+}
+
+#[test]
+fn test_contributions_reserved_until_close() {
+    let env = Env::default();
+    let s = setup(&env, 600);
+    let round = s.client.create_qf_round(&600, &2_000);
+    let broad = Address::generate(&env);
+    s.client.register_qf_project(&round.round_id, &broad);
+    for _ in 0..4 {
+        contribute(&env, &s, round.round_id, &broad, 100);
+    }
+    // Contributions cannot be spent before the round closes.
+    assert!(s
+        .client
+        .try_single_payout(&Address::generate(&env), &400)
+        .is_err());
+
+    env.ledger().set_timestamp(2_000);
+    s.client.close_qf_round(&round.round_id, &2_500);
+    assert_eq!(s.client.get_release_schedules().len(), 1);
+    assert_eq!(s.client.get_remaining_balance(), 1_000);
+    assert_eq!(s.client.reconcile_balances(), (1_000, 1_000));
+}
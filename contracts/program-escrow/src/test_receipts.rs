@@ -0,0 +1,109 @@
+#![cfg(test)]
+
+use super::*;
+use crate::receipts::DepositKind;
+use soroban_sdk::{testutils::Address as _, token, Address, Env, String};
+
+fn setup(
+    env: &Env,
+) -> (
+    ProgramEscrowContractClient<'static>,
+    Address,
+    token::StellarAssetClient<'static>,
+) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let payout_key = Address::generate(env);
+    let token_id = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let token_admin = token::StellarAssetClient::new(env, &token_id);
+
+    let program_id = String::from_str(env, "receipts-prog");
+    client.init_program(
+        &program_id,
+        &payout_key,
+        &token_id,
+        &payout_key,
+        &None,
+        &None,
+    );
+
+    (client, payout_key, token_admin)
+}
+
+#[test]
+fn test_lock_issues_verifiable_receipt() {
+    let env = Env::default();
+    let (client, payout_key, token_admin) = setup(&env);
+    token_admin.mint(&client.address, &5_000);
+
+    client.lock_program_funds(&5_000);
+
+    let receipt = client.get_receipt(&1);
+    assert_eq!(receipt.amount, 5_000);
+    assert_eq!(receipt.depositor, payout_key);
+    assert_eq!(receipt.holder, payout_key);
+    assert_eq!(receipt.kind, DepositKind::Lock);
+
+    assert!(client.verify_receipt(&1, &5_000, &payout_key));
+    assert!(!client.verify_receipt(&1, &4_999, &payout_key));
+    assert!(!client.verify_receipt(&1, &5_000, &Address::generate(&env)));
+    assert!(!client.verify_receipt(&99, &5_000, &payout_key));
+}
+
+#[test]
+fn test_contribution_issues_receipt_to_contributor() {
+    let env = Env::default();
+    let (client, _payout_key, token_admin) = setup(&env);
+    let donor = Address::generate(&env);
+    token_admin.mint(&donor, &750);
+
+    client.contribute(&String::from_str(&env, "receipts-prog"), &donor, &750);
+
+    let receipt = client.get_receipt(&1);
+    assert_eq!(receipt.depositor, donor);
+    assert_eq!(receipt.kind, DepositKind::Contribution);
+    assert!(client.verify_receipt(&1, &750, &donor));
+}
+
+#[test]
+fn test_receipt_ids_are_unique_per_deposit() {
+    let env = Env::default();
+    let (client, payout_key, token_admin) = setup(&env);
+    token_admin.mint(&client.address, &300);
+
+    client.lock_program_funds(&100);
+    client.lock_program_funds(&200);
+
+    assert_eq!(client.get_receipt(&1).amount, 100);
+    assert_eq!(client.get_receipt(&2).amount, 200);
+    assert!(client.verify_receipt(&2, &200, &payout_key));
+}
+
+#[test]
+fn test_transfer_changes_holder_not_depositor() {
+    let env = Env::default();
+    let (client, payout_key, token_admin) = setup(&env);
+    token_admin.mint(&client.address, &1_000);
+    client.lock_program_funds(&1_000);
+
+    let auditor = Address::generate(&env);
+    let receipt = client.transfer_receipt(&1, &auditor);
+
+    assert_eq!(receipt.holder, auditor);
+    assert_eq!(receipt.depositor, payout_key);
+    assert_eq!(client.get_receipt(&1).holder, auditor);
+    assert!(client.verify_receipt(&1, &1_000, &payout_key));
+}
+
+#[test]
+#[should_panic(expected = "Receipt not found")]
+fn test_unknown_receipt_panics() {
+    let env = Env::default();
+    let (client, _payout_key, _token_admin) = setup(&env);
+    client.get_receipt(&7);
+}
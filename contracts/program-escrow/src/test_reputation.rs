@@ -204,3 +204,19 @@ fn test_reputation_overdue_schedules() {
     // overall = (0 * 60 + 0 * 40) / 100 = 0
     assert_eq!(rep.overall_score_bps, 0);
 }
+
+#[test]
+fn test_reputation_counts_disputes_and_refunds() {
+    let env = Env::default();
+    let (client, _, _, _, _) = setup_active_program(&env, 100_000);
+
+    client.open_dispute(&String::from_str(&env, "late milestone"));
+    client.resolve_dispute(&String::from_str(&env, "delivered"));
+    client.open_dispute(&String::from_str(&env, "late again"));
+    client.set_paused(&Some(true), &None, &None, &None);
+    client.emergency_withdraw(&Address::generate(&env));
+
+    let rep = client.get_program_reputation();
+    assert_eq!(rep.dispute_count, 2);
+    assert_eq!(rep.refund_count, 1);
+}
@@ -89,6 +89,7 @@ fn serialization_compatibility_public_types_and_events() {
 
     let program_initialized = ProgramInitializedEvent {
         version: EVENT_VERSION_V2,
+        sequence: 1,
         program_id: program_id.clone(),
         authorized_payout_key: authorized.clone(),
         token_address: token.clone(),
@@ -143,6 +144,7 @@ fn serialization_compatibility_public_types_and_events() {
             "FundsLockedEvent",
             FundsLockedEvent {
                 version: EVENT_VERSION_V2,
+                sequence: 1,
                 program_id: program_id.clone(),
                 amount: 1000,
                 remaining_balance: 9000,
@@ -153,6 +155,7 @@ fn serialization_compatibility_public_types_and_events() {
             "BatchPayoutEvent",
             BatchPayoutEvent {
                 version: EVENT_VERSION_V2,
+                sequence: 1,
                 program_id: program_id.clone(),
                 recipient_count: 2,
                 total_amount: 500,
@@ -164,6 +167,7 @@ fn serialization_compatibility_public_types_and_events() {
             "PayoutEvent",
             PayoutEvent {
                 version: EVENT_VERSION_V2,
+                sequence: 1,
                 program_id: program_id.clone(),
                 recipient: recipient.clone(),
                 amount: 200,
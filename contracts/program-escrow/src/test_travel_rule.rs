@@ -0,0 +1,258 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_amm_payout::{MockAmm, MockAmmClient};
+use soroban_sdk::{testutils::Address as _, token, vec, Address, BytesN, Env, String};
+
+struct Setup<'a> {
+    client: ProgramEscrowContractClient<'a>,
+    token: token::Client<'a>,
+}
+
+/// Program funded with 10_000 that requires a travel rule hash above 1_000.
+fn setup(env: &Env) -> Setup<'static> {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let token_id = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let program_id = String::from_str(env, "vasp-grant");
+    client.init_program(&program_id, &admin, &token_id, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_id).mint(&client.address, &10_000);
+    client.lock_program_funds(&10_000);
+    client.set_travel_rule_threshold(&Some(1_000));
+
+    Setup {
+        client,
+        token: token::Client::new(env, &token_id),
+    }
+}
+
+#[test]
+fn test_large_single_payout_stores_hash() {
+    let env = Env::default();
+    let s = setup(&env);
+    let recipient = Address::generate(&env);
+    let hash = BytesN::from_array(&env, &[3u8; 32]);
+
+    s.client.single_payout(&Address::generate(&env), &1_000);
+    s.client
+        .single_payout_with_travel_rule(&recipient, &2_500, &hash);
+
+    assert_eq!(s.token.balance(&recipient), 2_500);
+    assert_eq!(s.client.get_travel_rule_hash(&0), None);
+    assert_eq!(s.client.get_travel_rule_hash(&1), Some(hash));
+    assert_eq!(s.client.get_travel_rule_threshold(), Some(1_000));
+}
+
+#[test]
+#[should_panic(expected = "Travel rule hash required")]
+fn test_large_single_payout_without_hash_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.single_payout(&Address::generate(&env), &1_001);
+}
+
+#[test]
+fn test_large_swapped_payout_stores_hash() {
+    let env = Env::default();
+    let s = setup(&env);
+    let recipient = Address::generate(&env);
+    let hash = BytesN::from_array(&env, &[4u8; 32]);
+
+    let usdc = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let amm_id = env.register_contract(None, MockAmm);
+    MockAmmClient::new(&env, &amm_id).configure(&9_500, &true);
+    token::StellarAssetClient::new(&env, &usdc).mint(&amm_id, &10_000);
+    s.client.set_amm(&Some(amm_id));
+
+    s.client
+        .single_payout_as_with_travel(&recipient, &2_000, &usdc, &1_800, &hash);
+
+    assert_eq!(token::Client::new(&env, &usdc).balance(&recipient), 1_900);
+    assert_eq!(s.client.get_travel_rule_hash(&0), Some(hash));
+}
+
+#[test]
+#[should_panic(expected = "Travel rule hash required")]
+fn test_large_swapped_payout_without_hash_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+    let usdc = Address::generate(&env);
+    s.client
+        .single_payout_as(&Address::generate(&env), &1_001, &usdc, &0);
+}
+
+#[test]
+fn test_large_payout_with_work_stores_hash() {
+    let env = Env::default();
+    let s = setup(&env);
+    let recipient = Address::generate(&env);
+    let work = BytesN::from_array(&env, &[5u8; 32]);
+    let hash = BytesN::from_array(&env, &[6u8; 32]);
+
+    s.client
+        .single_payout_work_with_travel(&recipient, &2_000, &work, &hash);
+
+    assert_eq!(s.token.balance(&recipient), 2_000);
+    assert_eq!(s.client.get_travel_rule_hash(&0), Some(hash));
+}
+
+#[test]
+fn test_batch_payout_hashes_per_recipient() {
+    let env = Env::default();
+    let s = setup(&env);
+    let hash = BytesN::from_array(&env, &[9u8; 32]);
+    let recipients = vec![&env, Address::generate(&env), Address::generate(&env)];
+
+    s.client.batch_payout_with_travel_rule(
+        &recipients,
+        &vec![&env, 500, 4_000],
+        &vec![&env, None, Some(hash.clone())],
+    );
+
+    assert_eq!(s.client.get_travel_rule_hash(&0), None);
+    assert_eq!(s.client.get_travel_rule_hash(&1), Some(hash));
+    assert_eq!(s.client.get_remaining_balance(), 5_500);
+}
+
+#[test]
+#[should_panic(expected = "Travel rule hash required")]
+fn test_large_batch_payout_without_hash_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.batch_payout(
+        &vec![&env, Address::generate(&env), Address::generate(&env)],
+        &vec![&env, 500, 4_000],
+    );
+}
+
+#[test]
+fn test_cleared_threshold_allows_large_payouts() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.set_travel_rule_threshold(&None);
+
+    let data = s.client.single_payout(&Address::generate(&env), &5_000);
+    assert_eq!(data.remaining_balance, 5_000);
+    assert_eq!(s.client.get_travel_rule_threshold(), None);
+}
+
+#[test]
+#[should_panic(expected = "Travel rule hash required")]
+fn test_large_schedule_release_without_registered_hash_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+    let schedule = s
+        .client
+        .create_program_release_schedule(&Address::generate(&env), &2_000, &0);
+    s.client
+        .release_program_schedule_manual(&schedule.schedule_id);
+}
+
+#[test]
+fn test_schedule_releases_record_registered_hash() {
+    let env = Env::default();
+    let s = setup(&env);
+    let recipient = Address::generate(&env);
+    let hash = BytesN::from_array(&env, &[4u8; 32]);
+    s.client
+        .set_recipient_travel_rule_hash(&recipient, &Some(hash.clone()));
+
+    let first = s
+        .client
+        .create_program_release_schedule(&recipient, &2_000, &0);
+    let second = s
+        .client
+        .create_program_release_schedule(&recipient, &3_000, &0);
+    s.client.release_program_schedule_manual(&first.schedule_id);
+    s.client
+        .release_prog_schedule_automatic(&second.schedule_id);
+
+    assert_eq!(s.token.balance(&recipient), 5_000);
+    assert_eq!(s.client.get_travel_rule_hash(&0), Some(hash.clone()));
+    assert_eq!(s.client.get_travel_rule_hash(&1), Some(hash.clone()));
+    assert_eq!(
+        s.client.get_recipient_travel_rule_hash(&recipient),
+        Some(hash)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Travel rule hash required")]
+fn test_large_split_share_without_registered_hash_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+    let program_id = String::from_str(&env, "vasp-grant");
+    let small = Address::generate(&env);
+    s.client
+        .set_recipient_travel_rule_hash(&small, &Some(BytesN::from_array(&env, &[1u8; 32])));
+    s.client.set_split_config(
+        &program_id,
+        &vec![
+            &env,
+            BeneficiarySplit {
+                recipient: small,
+                share_bps: 2_000,
+            },
+            BeneficiarySplit {
+                recipient: Address::generate(&env),
+                share_bps: 8_000,
+            },
+        ],
+    );
+    s.client.execute_split_payout(&program_id, &5_000);
+}
+
+#[test]
+#[should_panic(expected = "Travel rule hash required")]
+fn test_large_distribution_claim_without_registered_hash_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+    let program_id = String::from_str(&env, "vasp-grant");
+    let recipient = Address::generate(&env);
+    let root = crate::merkle_distribution::leaf_hash(&env, 0, &recipient, 2_000);
+    s.client
+        .commit_distribution_root(&program_id, &root, &2_000, &1, &1_000);
+    s.client
+        .claim_distribution(&program_id, &recipient, &0, &2_000, &vec![&env]);
+}
+
+#[test]
+fn test_parametric_claim_records_registered_hash() {
+    let env = Env::default();
+    let s = setup(&env);
+    let program_id = String::from_str(&env, "vasp-grant");
+    let recipient = Address::generate(&env);
+    let hash = BytesN::from_array(&env, &[6u8; 32]);
+    s.client.define_parametric_policy(
+        &program_id,
+        &Address::generate(&env),
+        &vec![
+            &env,
+            parametric::TriggerBand {
+                min_index: 0,
+                max_index: 100,
+                payout_bps: 10_000,
+            },
+        ],
+        &1_000,
+    );
+    s.client
+        .enroll_parametric_recipient(&program_id, &recipient, &4_000);
+    s.client.report_parametric_index(&program_id, &10);
+    s.client
+        .set_recipient_travel_rule_hash(&recipient, &Some(hash.clone()));
+    s.client.claim_parametric_payout(&program_id, &recipient);
+
+    assert_eq!(s.token.balance(&recipient), 4_000);
+    assert_eq!(s.client.get_travel_rule_hash(&0), Some(hash));
+}
@@ -0,0 +1,376 @@
+#![cfg(test)]
+
+use super::*;
+use crate::merkle_distribution::leaf_hash;
+use crate::parametric::TriggerBand;
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, testutils::Address as _, token, vec, Address, BytesN,
+    Env, String,
+};
+
+/// Vault-style yield protocol: tracks a balance per depositor and lets the
+/// test credit yield with `accrue` (backed by tokens minted to the vault).
+#[contract]
+pub struct MockYieldProtocol;
+
+#[contractimpl]
+impl MockYieldProtocol {
+    pub fn init(env: Env, token: Address) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("token"), &token);
+    }
+
+    pub fn deposit(env: Env, from: Address, amount: i128) {
+        from.require_auth();
+        Self::token(&env).transfer(&from, &env.current_contract_address(), &amount);
+        Self::credit(&env, &from, amount);
+    }
+
+    pub fn withdraw(env: Env, to: Address, amount: i128) {
+        to.require_auth();
+        if amount > Self::balance(env.clone(), to.clone()) {
+            panic!("Vault: insufficient balance");
+        }
+        Self::credit(&env, &to, -amount);
+        Self::token(&env).transfer(&env.current_contract_address(), &to, &amount);
+    }
+
+    pub fn balance(env: Env, owner: Address) -> i128 {
+        env.storage().persistent().get(&owner).unwrap_or(0)
+    }
+
+    pub fn accrue(env: Env, owner: Address, amount: i128) {
+        Self::credit(&env, &owner, amount);
+    }
+
+    fn token(env: &Env) -> token::Client<'_> {
+        let token: Address = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("token"))
+            .unwrap();
+        token::Client::new(env, &token)
+    }
+
+    fn credit(env: &Env, owner: &Address, delta: i128) {
+        let balance = Self::balance(env.clone(), owner.clone()) + delta;
+        env.storage().persistent().set(owner, &balance);
+    }
+}
+
+struct Setup<'a> {
+    client: ProgramEscrowContractClient<'a>,
+    vault: MockYieldProtocolClient<'a>,
+    token: token::Client<'a>,
+    token_admin: token::StellarAssetClient<'a>,
+}
+
+/// Program funded with 10_000 and an allowlisted, selected vault.
+fn setup(env: &Env) -> Setup<'static> {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let token_id = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let token_admin = token::StellarAssetClient::new(env, &token_id);
+
+    let program_id = String::from_str(env, "long-grant");
+    client.init_program(&program_id, &admin, &token_id, &admin, &None, &None);
+    token_admin.mint(&client.address, &10_000);
+    client.lock_program_funds(&10_000);
+
+    let vault_id = env.register_contract(None, MockYieldProtocol);
+    let vault = MockYieldProtocolClient::new(env, &vault_id);
+    vault.init(&token_id);
+    client.set_yield_protocol_allowed(&vault_id, &true);
+    client.set_yield_protocol(&Some(vault_id));
+
+    Setup {
+        client,
+        vault,
+        token: token::Client::new(env, &token_id),
+        token_admin,
+    }
+}
+
+#[test]
+fn test_deposit_idle_moves_funds_without_touching_balance() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.deposit_idle(&6_000);
+
+    assert_eq!(s.token.balance(&s.client.address), 4_000);
+    assert_eq!(s.token.balance(&s.vault.address), 6_000);
+    assert_eq!(s.client.get_remaining_balance(), 10_000);
+    let position = s.client.get_yield_position();
+    assert_eq!(position.protocol, Some(s.vault.address.clone()));
+    assert_eq!((position.principal, position.current_value), (6_000, 6_000));
+}
+
+#[test]
+#[should_panic(expected = "Amount exceeds idle balance")]
+fn test_pending_schedules_are_not_idle() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client
+        .create_program_release_schedule(&Address::generate(&env), &3_000, &100);
+    s.client.deposit_idle(&7_001);
+}
+
+#[test]
+fn test_payout_withdraws_shortfall_first() {
+    let env = Env::default();
+    let s = setup(&env);
+    let recipient = Address::generate(&env);
+    s.client.deposit_idle(&9_000);
+
+    let data = s.client.single_payout(&recipient, &2_500);
+
+    assert_eq!(s.token.balance(&recipient), 2_500);
+    assert_eq!(data.remaining_balance, 7_500);
+    assert_eq!(s.token.balance(&s.client.address), 0);
+    assert_eq!(s.client.get_yield_position().principal, 7_500);
+}
+
+#[test]
+fn test_withdraw_idle_returns_principal_then_yield() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.deposit_idle(&5_000);
+
+    s.token_admin.mint(&s.vault.address, &250);
+    s.vault.accrue(&s.client.address, &250);
+    assert_eq!(s.client.get_yield_position().current_value, 5_250);
+
+    s.client.withdraw_idle(&5_250);
+    let position = s.client.get_yield_position();
+    assert_eq!((position.principal, position.current_value), (0, 0));
+    assert_eq!(s.token.balance(&s.client.address), 10_250);
+    // Realized yield waits for `accrue_interest` before it counts.
+    assert_eq!(s.client.get_remaining_balance(), 10_000);
+}
+
+#[test]
+#[should_panic(expected = "Idle funds still deposited")]
+fn test_protocol_switch_requires_full_withdrawal() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.deposit_idle(&1_000);
+    s.client.set_yield_protocol(&None);
+}
+
+#[test]
+fn test_delisted_protocol_blocks_deposits_but_not_exits() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.deposit_idle(&1_000);
+    s.client
+        .set_yield_protocol_allowed(&s.vault.address, &false);
+    assert!(!s.client.is_yield_protocol_allowed(&s.vault.address));
+
+    assert!(s.client.try_deposit_idle(&1_000).is_err());
+    s.client.withdraw_idle(&1_000);
+    assert_eq!(s.token.balance(&s.client.address), 10_000);
+}
+
+#[test]
+fn test_accrue_interest_harvests_and_credits_program() {
+    let env = Env::default();
+    let s = setup(&env);
+    let program_id = String::from_str(&env, "long-grant");
+    s.client.deposit_idle(&5_000);
+    assert_eq!(s.client.accrue_interest(), 0);
+
+    s.token_admin.mint(&s.vault.address, &400);
+    s.vault.accrue(&s.client.address, &400);
+    assert_eq!(s.client.accrue_interest(), 400);
+
+    let info = s.client.get_program_info();
+    assert_eq!((info.total_funds, info.remaining_balance), (10_400, 10_400));
+    assert_eq!(s.client.get_accrued_interest(&program_id), 400);
+    assert_eq!(s.client.get_unaccrued_yield(), 0);
+    // Principal stays deposited; only the yield was harvested.
+    assert_eq!(s.client.get_yield_position().principal, 5_000);
+    assert_eq!(s.token.balance(&s.client.address), 5_400);
+}
+
+#[test]
+fn test_yield_realized_by_withdrawal_is_accrued_later() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.deposit_idle(&2_000);
+    s.token_admin.mint(&s.vault.address, &100);
+    s.vault.accrue(&s.client.address, &100);
+
+    s.client.withdraw_idle(&2_100);
+    assert_eq!(s.client.get_unaccrued_yield(), 100);
+    assert_eq!(s.client.get_remaining_balance(), 10_000);
+
+    assert_eq!(s.client.accrue_interest(), 100);
+    assert_eq!(s.client.get_remaining_balance(), 10_100);
+    assert_eq!(
+        s.client
+            .get_accrued_interest(&String::from_str(&env, "long-grant")),
+        100
+    );
+}
+
+#[test]
+fn test_reconcile_counts_deposited_principal() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.deposit_idle(&6_000);
+    assert_eq!(s.client.reconcile_balances(), (10_000, 10_000));
+}
+
+#[test]
+fn test_outstanding_obligations_are_not_idle() {
+    let env = Env::default();
+    let s = setup(&env);
+    let program_id = String::from_str(&env, "long-grant");
+    let deadline = env.ledger().timestamp() + 100;
+    s.client
+        .create_pending_claim(&program_id, &Address::generate(&env), &2_000, &deadline);
+    s.client.commit_distribution_root(
+        &program_id,
+        &BytesN::from_array(&env, &[1; 32]),
+        &3_000,
+        &4,
+        &1_000,
+    );
+    let band = TriggerBand {
+        min_index: 0,
+        max_index: 50,
+        payout_bps: 10_000,
+    };
+    s.client.define_parametric_policy(
+        &program_id,
+        &Address::generate(&env),
+        &vec![&env, band],
+        &1_000,
+    );
+    s.client
+        .enroll_parametric_recipient(&program_id, &Address::generate(&env), &1_000);
+
+    // 10_000 on hand, 6_000 of it owed.
+    assert!(s.client.try_deposit_idle(&4_001).is_err());
+    s.client.deposit_idle(&4_000);
+}
+
+#[test]
+fn test_unmatched_pool_cap_is_not_idle() {
+    let env = Env::default();
+    let s = setup(&env);
+    let program_id = String::from_str(&env, "long-grant");
+    let matcher = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    s.token_admin.mint(&matcher, &2_000);
+    s.token_admin.mint(&contributor, &400);
+    s.client
+        .create_matching_pool(&program_id, &matcher, &5_000, &2_000);
+
+    // 12_000 on hand, 2_000 of it the matcher's.
+    assert!(s.client.try_deposit_idle(&10_001).is_err());
+    // Matching 200 moves it into the program; 1_800 stays reserved.
+    s.client.contribute(&program_id, &contributor, &400);
+    assert!(s.client.try_deposit_idle(&10_601).is_err());
+    s.client.deposit_idle(&10_600);
+
+    // The payout spends some of the matcher's tokens on hand, so closing the
+    // pool has to pull the refund back from the vault.
+    s.client.single_payout(&Address::generate(&env), &2_000);
+    assert_eq!(s.client.close_matching_pool(&program_id), 1_800);
+    assert_eq!(s.token.balance(&matcher), 1_800);
+    assert_eq!(s.client.get_yield_position().principal, 8_600);
+    assert_eq!(s.client.reconcile_balances(), (8_600, 8_600));
+}
+
+#[test]
+fn test_claim_and_split_payouts_withdraw_shortfall_first() {
+    let env = Env::default();
+    let s = setup(&env);
+    let program_id = String::from_str(&env, "long-grant");
+    let claimant = Address::generate(&env);
+    let split_to = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 100;
+    let claim_id = s
+        .client
+        .create_pending_claim(&program_id, &claimant, &1_000, &deadline);
+    s.client.deposit_idle(&9_000);
+    s.client.set_split_config(
+        &program_id,
+        &vec![
+            &env,
+            BeneficiarySplit {
+                recipient: split_to.clone(),
+                share_bps: 10_000,
+            },
+        ],
+    );
+
+    s.client.execute_split_payout(&program_id, &1_500);
+    s.client.execute_claim(&program_id, &claim_id, &claimant);
+
+    assert_eq!(s.token.balance(&split_to), 1_500);
+    assert_eq!(s.token.balance(&claimant), 1_000);
+    assert_eq!(s.client.get_yield_position().principal, 7_500);
+}
+
+#[test]
+fn test_distribution_and_parametric_claims_withdraw_shortfall_first() {
+    let env = Env::default();
+    let s = setup(&env);
+    let program_id = String::from_str(&env, "long-grant");
+    let leaf_owner = Address::generate(&env);
+    let insured = Address::generate(&env);
+    s.client.deposit_idle(&9_000);
+
+    let root = leaf_hash(&env, 0, &leaf_owner, 3_000);
+    s.client
+        .commit_distribution_root(&program_id, &root, &3_000, &1, &1_000);
+    let band = TriggerBand {
+        min_index: 0,
+        max_index: 50,
+        payout_bps: 10_000,
+    };
+    s.client.define_parametric_policy(
+        &program_id,
+        &Address::generate(&env),
+        &vec![&env, band],
+        &1_000,
+    );
+    s.client
+        .enroll_parametric_recipient(&program_id, &insured, &2_000);
+    s.client.report_parametric_index(&program_id, &10);
+
+    s.client
+        .claim_distribution(&program_id, &leaf_owner, &0, &3_000, &vec![&env]);
+    s.client.claim_parametric_payout(&program_id, &insured);
+
+    assert_eq!(s.token.balance(&leaf_owner), 3_000);
+    assert_eq!(s.token.balance(&insured), 2_000);
+    assert_eq!(s.client.get_yield_position().principal, 5_000);
+}
+
+#[test]
+fn test_lock_fee_withdraws_shortfall_first() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.token_admin.mint(&s.client.address, &1_000);
+    s.client.deposit_idle(&11_000);
+    s.client.set_lock_fee_rate(&200);
+    s.client.set_fees_enabled(&true);
+
+    s.client.lock_program_funds(&1_000);
+
+    assert_eq!(s.token.balance(&s.client.address), 0);
+    assert_eq!(s.client.get_yield_position().principal, 10_980);
+}
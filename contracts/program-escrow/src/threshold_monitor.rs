@@ -6,6 +6,7 @@
 // for failure rates and token outflow volumes. Monitors operations in sliding
 // time windows and opens the circuit breaker when abnormal patterns are detected.
 
+use crate::events_log::LogLevel;
 use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol};
 
 // ─────────────────────────────────────────────────────────
@@ -413,7 +414,11 @@ pub fn reset_metrics(env: &Env, admin: &Address) {
 /// Emit threshold breach event
 pub fn emit_threshold_breach_event(env: &Env, breach: &ThresholdBreach) {
     env.events().publish(
-        (symbol_short!("th_breach"), breach.metric_type.clone()),
+        (
+            LogLevel::Warn,
+            symbol_short!("th_breach"),
+            breach.metric_type.clone(),
+        ),
         (
             breach.threshold_value,
             breach.actual_value,
@@ -0,0 +1,130 @@
+//! # Travel Rule
+//!
+//! Payouts of more than the configured threshold must carry a
+//! `travel_rule_hash`: a hash of the originator/beneficiary data held off
+//! chain, which VASP counterparties use to reconcile the transfer. The hash is
+//! stored by payout id (the payout's index in `payout_history`) and emitted in
+//! a `TrvlRule` event next to the payout event.
+//!
+//! Direct payouts (`single_payout*` and `batch_payout*`) take the hash as an
+//! argument. Payout paths that pay a recipient fixed ahead of time (release
+//! schedules, split payouts, Merkle distribution claims and parametric
+//! claims) use the hash the payout key registered for that recipient with
+//! `set_recipient_travel_rule_hash`. Either way, large payouts without a hash
+//! are rejected. No threshold means no payout requires one. A hash given for a
+//! payout under the threshold is still recorded.
+
+use crate::events_log::LogLevel;
+use crate::{next_event_sequence, EVENT_VERSION_V2};
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, String, Symbol};
+
+const TRAVEL_RULE: Symbol = symbol_short!("TrvlRule");
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TravelRuleKey {
+    /// Payouts above this amount require a travel rule hash.
+    Threshold,
+    /// payout_id -> BytesN<32>
+    Hash(u32),
+    /// recipient -> BytesN<32> registered for payouts that take no hash
+    Recipient(Address),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TravelRuleEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub program_id: String,
+    pub payout_id: u32,
+    pub recipient: Address,
+    pub amount: i128,
+    pub travel_rule_hash: BytesN<32>,
+}
+
+pub fn set_threshold(env: &Env, threshold: Option<i128>) {
+    match threshold {
+        Some(threshold) => {
+            if threshold < 0 {
+                panic!("Travel rule threshold must not be negative");
+            }
+            env.storage()
+                .instance()
+                .set(&TravelRuleKey::Threshold, &threshold);
+        }
+        None => env.storage().instance().remove(&TravelRuleKey::Threshold),
+    }
+}
+
+pub fn get_threshold(env: &Env) -> Option<i128> {
+    env.storage().instance().get(&TravelRuleKey::Threshold)
+}
+
+/// Whether a payout of `amount` must carry a travel rule hash.
+pub fn is_required(env: &Env, amount: i128) -> bool {
+    get_threshold(env).is_some_and(|threshold| amount > threshold)
+}
+
+pub fn get_hash(env: &Env, payout_id: u32) -> Option<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&TravelRuleKey::Hash(payout_id))
+}
+
+/// Store the hash for payout `payout_id` and emit it for counterparties.
+pub fn record(
+    env: &Env,
+    program_id: &String,
+    payout_id: u32,
+    recipient: &Address,
+    amount: i128,
+    travel_rule_hash: BytesN<32>,
+) {
+    env.storage()
+        .persistent()
+        .set(&TravelRuleKey::Hash(payout_id), &travel_rule_hash);
+    env.events().publish(
+        (LogLevel::Info, TRAVEL_RULE),
+        TravelRuleEvent {
+            version: EVENT_VERSION_V2,
+            sequence: next_event_sequence(env),
+            program_id: program_id.clone(),
+            payout_id,
+            recipient: recipient.clone(),
+            amount,
+            travel_rule_hash,
+        },
+    );
+}
+
+pub fn set_recipient_hash(env: &Env, recipient: &Address, travel_rule_hash: Option<BytesN<32>>) {
+    let key = TravelRuleKey::Recipient(recipient.clone());
+    match travel_rule_hash {
+        Some(hash) => env.storage().persistent().set(&key, &hash),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+pub fn get_recipient_hash(env: &Env, recipient: &Address) -> Option<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&TravelRuleKey::Recipient(recipient.clone()))
+}
+
+/// Apply the rule to payout `payout_id`, which takes no hash of its own: record
+/// the hash registered for `recipient`, or reject the payout when it is above
+/// the threshold and none is registered.
+pub fn apply_registered(
+    env: &Env,
+    program_id: &String,
+    payout_id: u32,
+    recipient: &Address,
+    amount: i128,
+) {
+    match get_recipient_hash(env, recipient) {
+        Some(hash) => record(env, program_id, payout_id, recipient, amount, hash),
+        None if is_required(env, amount) => panic!("Travel rule hash required"),
+        None => {}
+    }
+}
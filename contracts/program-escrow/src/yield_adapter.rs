@@ -0,0 +1,388 @@
+//! # Idle-Fund Yield Adapter
+//!
+//! Lets the admin park part of a program's locked balance in a yield protocol
+//! while it waits to be paid out. Only protocols on the admin-managed
+//! allowlist can be selected, and the protocol must expose
+//!
+//! ```text
+//! deposit(from: Address, amount: i128)   // pulls `amount` tokens from `from`
+//! withdraw(to: Address, amount: i128)    // sends `amount` tokens to `to`
+//! balance(owner: Address) -> i128        // principal plus accrued yield
+//! ```
+//!
+//! Accounting is strict in two ways:
+//!
+//! - `deposit_idle` may only move funds that are not already owed: the
+//!   contract keeps at least the sum of all unreleased schedule amounts plus
+//!   outstanding pending claims, Merkle distributions and parametric
+//!   liabilities on hand.
+//! - Every payout path calls [`ensure_liquid`] before transferring, which
+//!   withdraws any shortfall from the protocol first. Payouts therefore never
+//!   fail because funds are deposited, and `remaining_balance` is unaffected
+//!   by where the tokens sit.
+//!
+//! Yield earned above the deposited principal is returned to the contract by
+//! `withdraw_idle` (or harvested by `accrue_interest`) and held as realized,
+//! unaccrued yield. `accrue_interest` then credits it to the programs' balances
+//! pro-rata to each program's `remaining_balance`, with any rounding dust going
+//! to the last program, so no yield is left unaccounted for.
+
+use crate::events_log::LogLevel;
+use crate::{
+    load_program_data, next_event_sequence, reserved_obligations, ProgramData,
+    ProgramEscrowContract, ProgramReleaseSchedule, EVENT_VERSION_V2, PROGRAM_DATA,
+    PROGRAM_REGISTRY, SCHEDULES,
+};
+use soroban_sdk::auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation};
+use soroban_sdk::{
+    contracttype, symbol_short, token, vec, Address, Env, IntoVal, String, Symbol, Vec,
+};
+
+const YIELD_DEPOSITED: Symbol = symbol_short!("YldDep");
+const YIELD_WITHDRAWN: Symbol = symbol_short!("YldWdr");
+const INTEREST_ACCRUED: Symbol = symbol_short!("YldAccr");
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum YieldKey {
+    /// protocol address -> bool
+    Allowed(Address),
+    /// Protocol idle funds are deposited into.
+    Protocol,
+    /// Principal currently deposited with `Protocol`.
+    Principal,
+    /// Yield withdrawn to the contract but not yet credited to programs.
+    Unaccrued,
+    /// program_id -> total interest credited
+    Accrued(String),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct YieldPosition {
+    pub protocol: Option<Address>,
+    /// Tokens deposited and not yet withdrawn.
+    pub principal: i128,
+    /// Protocol-reported balance, including accrued yield.
+    pub current_value: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct YieldMovedEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub protocol: Address,
+    pub amount: i128,
+    pub principal: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InterestAccruedEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub program_id: String,
+    pub amount: i128,
+    pub remaining_balance: i128,
+}
+
+pub fn is_allowed(env: &Env, protocol: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&YieldKey::Allowed(protocol.clone()))
+        .unwrap_or(false)
+}
+
+pub fn set_allowed(env: &Env, protocol: &Address, allowed: bool) {
+    let key = YieldKey::Allowed(protocol.clone());
+    if allowed {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+pub fn get_protocol(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&YieldKey::Protocol)
+}
+
+pub fn get_principal(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&YieldKey::Principal)
+        .unwrap_or(0)
+}
+
+/// Select the protocol `deposit_idle` uses. Switching (or clearing) is only
+/// possible once everything has been withdrawn from the current one.
+pub fn set_protocol(env: &Env, protocol: Option<Address>) {
+    if get_principal(env) > 0 {
+        panic!("Idle funds still deposited");
+    }
+    match protocol {
+        Some(protocol) => {
+            if !is_allowed(env, &protocol) {
+                panic!("Yield protocol not allowlisted");
+            }
+            env.storage().instance().set(&YieldKey::Protocol, &protocol);
+        }
+        None => env.storage().instance().remove(&YieldKey::Protocol),
+    }
+}
+
+pub fn get_unaccrued(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&YieldKey::Unaccrued)
+        .unwrap_or(0)
+}
+
+pub fn get_accrued(env: &Env, program_id: &String) -> i128 {
+    env.storage()
+        .instance()
+        .get(&YieldKey::Accrued(program_id.clone()))
+        .unwrap_or(0)
+}
+
+pub fn position(env: &Env) -> YieldPosition {
+    let protocol = get_protocol(env);
+    let current_value = match &protocol {
+        Some(protocol) => protocol_balance(env, protocol),
+        None => 0,
+    };
+    YieldPosition {
+        protocol,
+        principal: get_principal(env),
+        current_value,
+    }
+}
+
+/// Tokens on hand that are not owed to a pending release schedule or to
+/// another outstanding obligation.
+pub fn idle_balance(env: &Env, token: &Address) -> i128 {
+    let schedules: Vec<ProgramReleaseSchedule> = env
+        .storage()
+        .instance()
+        .get(&SCHEDULES)
+        .unwrap_or_else(|| Vec::new(env));
+    let mut reserved: i128 = reserved_obligations(env);
+    for s in schedules.iter() {
+        if !s.released {
+            reserved += s.amount;
+        }
+    }
+    let on_hand = token::Client::new(env, token).balance(&env.current_contract_address());
+    (on_hand - reserved).max(0)
+}
+
+pub fn deposit(env: &Env, token: &Address, amount: i128) {
+    let protocol = active_protocol(env);
+    if amount <= 0 {
+        panic!("Amount must be greater than zero");
+    }
+    if amount > idle_balance(env, token) {
+        panic!("Amount exceeds idle balance");
+    }
+
+    let this = env.current_contract_address();
+    env.authorize_as_current_contract(vec![
+        env,
+        InvokerContractAuthEntry::Contract(SubContractInvocation {
+            context: ContractContext {
+                contract: token.clone(),
+                fn_name: Symbol::new(env, "transfer"),
+                args: (this.clone(), protocol.clone(), amount).into_val(env),
+            },
+            sub_invocations: vec![env],
+        }),
+    ]);
+    env.invoke_contract::<()>(
+        &protocol,
+        &Symbol::new(env, "deposit"),
+        vec![env, this.into_val(env), amount.into_val(env)],
+    );
+
+    let principal = get_principal(env) + amount;
+    env.storage()
+        .instance()
+        .set(&YieldKey::Principal, &principal);
+    publish(env, YIELD_DEPOSITED, protocol, amount, principal);
+}
+
+/// Pull funds back from the selected protocol. Allowed even after the
+/// protocol has been delisted, so a bad protocol can always be exited.
+pub fn withdraw(env: &Env, amount: i128) {
+    let protocol = get_protocol(env).unwrap_or_else(|| panic!("Yield protocol not set"));
+    if amount <= 0 {
+        panic!("Amount must be greater than zero");
+    }
+    withdraw_from(env, &protocol, amount);
+}
+
+/// Make sure at least `needed` tokens are on hand, withdrawing the shortfall
+/// from the yield protocol. A no-op when nothing is deposited.
+pub fn ensure_liquid(env: &Env, token: &Address, needed: i128) {
+    let protocol = match get_protocol(env) {
+        Some(protocol) if get_principal(env) > 0 => protocol,
+        _ => return,
+    };
+    let on_hand = token::Client::new(env, token).balance(&env.current_contract_address());
+    if on_hand < needed {
+        withdraw_from(env, &protocol, needed - on_hand);
+    }
+}
+
+/// Harvest yield above principal from the protocol and credit all realized
+/// yield to the programs holding the contract's token, pro-rata to their
+/// `remaining_balance`. Returns the amount credited.
+pub fn accrue(env: &Env) -> i128 {
+    if let Some(protocol) = get_protocol(env) {
+        let principal = get_principal(env);
+        let earned = protocol_balance(env, &protocol) - principal;
+        if earned > 0 {
+            // Harvest only the yield; the principal stays deposited.
+            pull(env, &protocol, earned);
+            env.storage()
+                .instance()
+                .set(&YieldKey::Unaccrued, &(get_unaccrued(env) + earned));
+            publish(env, YIELD_WITHDRAWN, protocol, earned, principal);
+        }
+    }
+
+    let pool = get_unaccrued(env);
+    let programs = funded_programs(env);
+    if pool <= 0 || programs.is_empty() {
+        return 0;
+    }
+    let mut total: i128 = 0;
+    for program in programs.iter() {
+        total += program.remaining_balance;
+    }
+
+    let last = programs.len() - 1;
+    let mut credited: i128 = 0;
+    for i in 0..programs.len() {
+        let mut program = programs.get(i).unwrap();
+        let share = if i == last {
+            pool - credited
+        } else {
+            pool * program.remaining_balance / total
+        };
+        credited += share;
+        if share == 0 {
+            continue;
+        }
+
+        program.total_funds += share;
+        program.remaining_balance += share;
+        ProgramEscrowContract::store_program_data(env, &program.program_id, &program);
+        let accrued = get_accrued(env, &program.program_id) + share;
+        env.storage()
+            .instance()
+            .set(&YieldKey::Accrued(program.program_id.clone()), &accrued);
+
+        env.events().publish(
+            (LogLevel::Info, INTEREST_ACCRUED),
+            InterestAccruedEvent {
+                version: EVENT_VERSION_V2,
+                sequence: next_event_sequence(env),
+                program_id: program.program_id,
+                amount: share,
+                remaining_balance: program.remaining_balance,
+            },
+        );
+    }
+    env.storage().instance().remove(&YieldKey::Unaccrued);
+    credited
+}
+
+/// The active program plus registered programs in the same token that still
+/// hold a balance.
+fn funded_programs(env: &Env) -> Vec<ProgramData> {
+    let mut programs = Vec::new(env);
+    let active: ProgramData = match env.storage().instance().get(&PROGRAM_DATA) {
+        Some(active) => active,
+        None => return programs,
+    };
+    let registry: Vec<String> = env
+        .storage()
+        .instance()
+        .get(&PROGRAM_REGISTRY)
+        .unwrap_or_else(|| Vec::new(env));
+    for program_id in registry.iter() {
+        if program_id == active.program_id {
+            continue;
+        }
+        let program = load_program_data(env, &program_id);
+        if program.token_address == active.token_address && program.remaining_balance > 0 {
+            programs.push_back(program);
+        }
+    }
+    if active.remaining_balance > 0 {
+        programs.push_back(active);
+    }
+    programs
+}
+
+fn active_protocol(env: &Env) -> Address {
+    let protocol = get_protocol(env).unwrap_or_else(|| panic!("Yield protocol not set"));
+    if !is_allowed(env, &protocol) {
+        panic!("Yield protocol not allowlisted");
+    }
+    protocol
+}
+
+fn protocol_balance(env: &Env, protocol: &Address) -> i128 {
+    env.invoke_contract::<i128>(
+        protocol,
+        &Symbol::new(env, "balance"),
+        vec![env, env.current_contract_address().into_val(env)],
+    )
+}
+
+fn pull(env: &Env, protocol: &Address, amount: i128) {
+    if amount > protocol_balance(env, protocol) {
+        panic!("Amount exceeds deposited balance");
+    }
+    env.invoke_contract::<()>(
+        protocol,
+        &Symbol::new(env, "withdraw"),
+        vec![
+            env,
+            env.current_contract_address().into_val(env),
+            amount.into_val(env),
+        ],
+    );
+}
+
+fn withdraw_from(env: &Env, protocol: &Address, amount: i128) {
+    pull(env, protocol, amount);
+
+    // Withdrawals draw down principal first; anything beyond it is yield.
+    let principal_before = get_principal(env);
+    let principal = (principal_before - amount).max(0);
+    env.storage()
+        .instance()
+        .set(&YieldKey::Principal, &principal);
+    let realized = amount - (principal_before - principal);
+    if realized > 0 {
+        env.storage()
+            .instance()
+            .set(&YieldKey::Unaccrued, &(get_unaccrued(env) + realized));
+    }
+    publish(env, YIELD_WITHDRAWN, protocol.clone(), amount, principal);
+}
+
+fn publish(env: &Env, topic: Symbol, protocol: Address, amount: i128, principal: i128) {
+    env.events().publish(
+        (LogLevel::Info, topic),
+        YieldMovedEvent {
+            version: EVENT_VERSION_V2,
+            sequence: next_event_sequence(env),
+            protocol,
+            amount,
+            principal,
+        },
+    );
+}
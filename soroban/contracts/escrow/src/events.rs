@@ -0,0 +1,137 @@
+//! Fund-moving and jurisdiction events, laid out exactly as in
+//! `contracts/bounty_escrow/contracts/escrow/src/events.rs`: same topics,
+//! same payload structs and field order, same version. One indexer can
+//! decode the events of either escrow. Keep the two files in step.
+
+use soroban_sdk::{contracttype, symbol_short, Address, Env, String};
+
+/// Fund-moving events carry the escrow's `remaining_before` and
+/// `remaining_after` from this version on.
+pub const EVENT_VERSION_V3: u32 = 3;
+
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct FundsLocked {
+    pub version: u32,
+    pub bounty_id: u64,
+    pub amount: i128,
+    pub remaining_before: i128,
+    pub remaining_after: i128,
+    pub depositor: Address,
+    pub deadline: u64,
+}
+
+pub fn emit_funds_locked(env: &Env, event: FundsLocked) {
+    let topics = (symbol_short!("f_lock"), event.bounty_id);
+    env.events().publish(topics, event.clone());
+}
+
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct FundsReleased {
+    pub version: u32,
+    pub bounty_id: u64,
+    pub amount: i128,
+    pub remaining_before: i128,
+    pub remaining_after: i128,
+    pub recipient: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_funds_released(env: &Env, event: FundsReleased) {
+    let topics = (symbol_short!("f_rel"), event.bounty_id);
+    env.events().publish(topics, event.clone());
+}
+
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct FundsRefunded {
+    pub version: u32,
+    pub bounty_id: u64,
+    pub amount: i128,
+    pub remaining_before: i128,
+    pub remaining_after: i128,
+    pub refund_to: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_funds_refunded(env: &Env, event: FundsRefunded) {
+    let topics = (symbol_short!("f_ref"), event.bounty_id);
+    env.events().publish(topics, event.clone());
+}
+
+/// Emitted by `split_escrow`. `remaining_before`/`remaining_after` are the
+/// parent's; the child `new_bounty_id` starts with `amount`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowSplit {
+    pub version: u32,
+    pub bounty_id: u64,
+    pub new_bounty_id: u64,
+    pub amount: i128,
+    pub remaining_before: i128,
+    pub remaining_after: i128,
+    pub depositor: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_escrow_split(env: &Env, event: EscrowSplit) {
+    let topics = (symbol_short!("f_split"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+/// Emitted by `merge_escrows` on the target `bounty_id`, which absorbed
+/// `amount` from `source_bounty_id`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowsMerged {
+    pub version: u32,
+    pub bounty_id: u64,
+    pub source_bounty_id: u64,
+    pub amount: i128,
+    pub remaining_before: i128,
+    pub remaining_after: i128,
+    pub deadline: u64,
+    pub depositor: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_escrows_merged(env: &Env, event: EscrowsMerged) {
+    let topics = (symbol_short!("f_merge"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct JurisdictionTagRegistered {
+    pub tag: String,
+    pub registered_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_jurisdiction_tag_registered(env: &Env, event: JurisdictionTagRegistered) {
+    let topics = (symbol_short!("juris_reg"),);
+    env.events().publish(topics, event);
+}
+
+/// Emitted by `set_escrow_pause_flags`: as `esc_frz` when any operation is
+/// left paused, `esc_unfrz` when all flags are cleared.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowPauseFlagsChanged {
+    pub bounty_id: u64,
+    pub previous_flags: u32,
+    pub flags: u32,
+    pub changed_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_escrow_frozen(env: &Env, event: EscrowPauseFlagsChanged) {
+    let topics = (symbol_short!("esc_frz"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+pub fn emit_escrow_unfrozen(env: &Env, event: EscrowPauseFlagsChanged) {
+    let topics = (symbol_short!("esc_unfrz"), event.bounty_id);
+    env.events().publish(topics, event);
+}
@@ -57,6 +57,24 @@ pub struct RiskThresholds {
     pub high_risk_multiplier: u32, // e.g., 50 (50% of tier limit)
 }
 
+/// Cap on the value a depositor of one tier may lock within a window of
+/// `period` seconds, however many locks it is split into.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PeriodLimit {
+    pub period: u64,
+    pub max_locked_value: i128,
+}
+
+/// Value a depositor has locked since `window_start`. The window restarts
+/// with the first lock after the tier's period has elapsed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PeriodWindow {
+    pub window_start: u64,
+    pub locked_value: i128,
+}
+
 impl Default for AddressIdentity {
     fn default() -> Self {
         Self {
@@ -2,12 +2,18 @@
 //! Minimal Soroban escrow demo: lock, release, and refund.
 //! Parity with main contracts/bounty_escrow where applicable; see soroban/PARITY.md.
 
-use soroban_sdk::{contract, contracterror, contractimpl, contracttype, token, Address, Env, BytesN};
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, symbol_short, token, xdr::ToXdr, Address,
+    Bytes, BytesN, Env, String, Symbol, Vec,
+};
 
+pub mod events;
 mod identity;
 pub use identity::*;
 
 mod reentrancy_guard;
+pub mod settlement;
+pub use settlement::BountyOutcome;
 
 #[contracterror]
 #[derive(Clone, Debug, PartialEq)]
@@ -21,6 +27,16 @@ pub enum Error {
     DeadlineNotPassed = 6,
     Unauthorized = 7,
     InsufficientBalance = 8,
+    InvalidSplit = 9,
+    JurisdictionPaused = 10,
+    JurisdictionKycRequired = 11,
+    JurisdictionLimitExceeded = 12,
+    InvalidPauseFlags = 13,
+    InvalidJurisdictionTag = 14,
+    JurisdictionTagNotRegistered = 15,
+    InvalidAmount = 16,
+    IncompatibleEscrows = 17,
+    RecoveryKeyNotSet = 18,
     // Identity-related errors
     InvalidSignature = 100,
     ClaimExpired = 101,
@@ -29,6 +45,8 @@ pub enum Error {
     TransactionExceedsLimit = 104,
     InvalidRiskScore = 105,
     InvalidTier = 106,
+    InvalidLimit = 107,
+    PeriodLimitExceeded = 108,
 }
 
 #[contracttype]
@@ -49,6 +67,61 @@ pub struct Escrow {
     pub deadline: u64,
 }
 
+/// Who receives one row of a payout split.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SplitRecipient {
+    /// The contributor named at `release_funds`.
+    Contributor,
+    /// A fixed address chosen at lock time (reviewer, platform, ...).
+    Fixed(Address),
+}
+
+/// One row of a payout split table, in basis points of the released amount.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutShare {
+    pub recipient: SplitRecipient,
+    pub bps: u32,
+}
+
+pub const BASIS_POINTS: u32 = 10_000;
+pub const MAX_SPLIT_SHARES: u32 = 10;
+
+/// Per-escrow regulatory controls for a market segment (see
+/// JURISDICTION_SEGMENTATION.md). Escrows locked through `lock_funds` have
+/// none.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowJurisdictionConfig {
+    pub tag: Option<String>,
+    /// Depositor (at lock and refund) and contributor (at release) must hold
+    /// a valid identity claim.
+    pub requires_kyc: bool,
+    /// When false, identity tier limits are not applied to the escrow.
+    pub enforce_identity_limits: bool,
+    pub lock_paused: bool,
+    pub release_paused: bool,
+    pub refund_paused: bool,
+    pub max_lock_amount: Option<i128>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum OptionalJurisdiction {
+    None,
+    Some(EscrowJurisdictionConfig),
+}
+
+/// Pause flags for `set_escrow_pause_flags`, matching the bounty escrow.
+pub const ESCROW_PAUSE_LOCK: u32 = 1 << 0;
+pub const ESCROW_PAUSE_RELEASE: u32 = 1 << 1;
+pub const ESCROW_PAUSE_REFUND: u32 = 1 << 2;
+const ESCROW_PAUSE_ALL: u32 = ESCROW_PAUSE_LOCK | ESCROW_PAUSE_RELEASE | ESCROW_PAUSE_REFUND;
+
+/// Longest accepted jurisdiction tag, in bytes.
+pub const MAX_JURISDICTION_TAG_LEN: u32 = 32;
+
 #[contracttype]
 pub enum DataKey {
     Admin,
@@ -60,6 +133,16 @@ pub enum DataKey {
     TierLimits,
     RiskThresholds,
     ReentrancyGuard,
+    PayoutSplit(u64),
+    CumulativeLimit(IdentityTier),
+    LockedExposure(Address),
+    PeriodLimit(IdentityTier),
+    PeriodWindow(Address),
+    EscrowJurisdiction(u64),
+    JurisdictionTags,
+    RecoveryKey(Address),   // depositor -> ed25519 key authorizing refund_to
+    RecoveryNonce(Address), // depositor -> next refund_to nonce
+    SettlementListener(Address), // depositor told of releases and refunds
 }
 
 #[contract]
@@ -136,6 +219,195 @@ impl EscrowContract {
         Ok(())
     }
 
+    /// Configure the limits of one tier (admin only). `max_single` caps a
+    /// single lock or payout; `max_cumulative` caps the total an address of
+    /// this tier may have locked at once, with zero meaning no cap.
+    pub fn set_identity_limits(
+        env: Env,
+        tier: IdentityTier,
+        max_single: i128,
+        max_cumulative: i128,
+    ) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        if max_single <= 0 || max_cumulative < 0 {
+            return Err(Error::InvalidLimit);
+        }
+        if max_cumulative > 0 && max_cumulative < max_single {
+            return Err(Error::InvalidLimit);
+        }
+
+        let mut limits: TierLimits = env
+            .storage()
+            .persistent()
+            .get(&DataKey::TierLimits)
+            .unwrap_or_default();
+        match tier {
+            IdentityTier::Unverified => limits.unverified_limit = max_single,
+            IdentityTier::Basic => limits.basic_limit = max_single,
+            IdentityTier::Verified => limits.verified_limit = max_single,
+            IdentityTier::Premium => limits.premium_limit = max_single,
+        }
+        env.storage().persistent().set(&DataKey::TierLimits, &limits);
+        env.storage()
+            .persistent()
+            .set(&DataKey::CumulativeLimit(tier.clone()), &max_cumulative);
+
+        env.events().publish(
+            (symbol_short!("limits"), tier),
+            (max_single, max_cumulative),
+        );
+        Ok(())
+    }
+
+    /// `(max_single, max_cumulative)` configured for a tier.
+    pub fn get_identity_limits(env: Env, tier: IdentityTier) -> (i128, i128) {
+        let limits: TierLimits = env
+            .storage()
+            .persistent()
+            .get(&DataKey::TierLimits)
+            .unwrap_or_default();
+        let max_single = match tier {
+            IdentityTier::Unverified => limits.unverified_limit,
+            IdentityTier::Basic => limits.basic_limit,
+            IdentityTier::Verified => limits.verified_limit,
+            IdentityTier::Premium => limits.premium_limit,
+        };
+        let max_cumulative = env
+            .storage()
+            .persistent()
+            .get(&DataKey::CumulativeLimit(tier))
+            .unwrap_or(0);
+        (max_single, max_cumulative)
+    }
+
+    /// Assign a tier to an address directly (admin only), e.g. after an
+    /// off-chain review. The assignment does not expire and keeps the
+    /// address's current risk score.
+    pub fn set_address_tier(env: Env, address: Address, tier: IdentityTier) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        let mut identity = Self::get_address_identity(env.clone(), address.clone());
+        identity.tier = tier.clone();
+        identity.expiry = u64::MAX;
+        identity.last_updated = env.ledger().timestamp();
+        env.storage()
+            .persistent()
+            .set(&DataKey::AddressIdentity(address.clone()), &identity);
+
+        env.events().publish((symbol_short!("tier"), address), tier);
+        Ok(())
+    }
+
+    /// Total an address currently has locked across open escrows.
+    pub fn get_locked_exposure(env: Env, address: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::LockedExposure(address))
+            .unwrap_or(0)
+    }
+
+    fn adjust_exposure(env: &Env, address: &Address, delta: i128) {
+        let exposure = Self::get_locked_exposure(env.clone(), address.clone()) + delta;
+        env.storage()
+            .persistent()
+            .set(&DataKey::LockedExposure(address.clone()), &exposure);
+    }
+
+    /// Cap the value an address of `tier` may lock per `period` seconds
+    /// (admin only). Both zero clears the cap.
+    pub fn set_period_limit(
+        env: Env,
+        tier: IdentityTier,
+        period: u64,
+        max_locked_value: i128,
+    ) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        if period == 0 && max_locked_value == 0 {
+            env.storage()
+                .persistent()
+                .remove(&DataKey::PeriodLimit(tier));
+            return Ok(());
+        }
+        if period == 0 || max_locked_value <= 0 {
+            return Err(Error::InvalidLimit);
+        }
+        env.storage().persistent().set(
+            &DataKey::PeriodLimit(tier),
+            &PeriodLimit {
+                period,
+                max_locked_value,
+            },
+        );
+        Ok(())
+    }
+
+    /// Per-period cap configured for a tier, if any.
+    pub fn get_period_limit(env: Env, tier: IdentityTier) -> Option<PeriodLimit> {
+        env.storage().persistent().get(&DataKey::PeriodLimit(tier))
+    }
+
+    /// Value `depositor` has locked in its current window; zero once the
+    /// window of its tier has elapsed.
+    pub fn get_period_exposure(env: Env, depositor: Address) -> i128 {
+        let tier = Self::get_address_identity(env.clone(), depositor.clone()).tier;
+        match Self::get_period_limit(env.clone(), tier) {
+            Some(limit) => Self::current_window(&env, &depositor, &limit)
+                .map_or(0, |window| window.locked_value),
+            None => 0,
+        }
+    }
+
+    fn current_window(env: &Env, depositor: &Address, limit: &PeriodLimit) -> Option<PeriodWindow> {
+        let now = env.ledger().timestamp();
+        env.storage()
+            .persistent()
+            .get::<_, PeriodWindow>(&DataKey::PeriodWindow(depositor.clone()))
+            .filter(|w| now < w.window_start.saturating_add(limit.period))
+    }
+
+    /// Internal: count a lock of `amount` against the depositor's window,
+    /// failing with `PeriodLimitExceeded` when it would pass the tier's cap.
+    fn enforce_period_limit(env: &Env, depositor: &Address, amount: i128) -> Result<(), Error> {
+        let tier = Self::get_address_identity(env.clone(), depositor.clone()).tier;
+        let limit = match Self::get_period_limit(env.clone(), tier) {
+            Some(limit) => limit,
+            None => return Ok(()),
+        };
+        let mut window = Self::current_window(env, depositor, &limit).unwrap_or(PeriodWindow {
+            window_start: env.ledger().timestamp(),
+            locked_value: 0,
+        });
+
+        window.locked_value = window.locked_value.saturating_add(amount);
+        if window.locked_value > limit.max_locked_value {
+            env.events().publish(
+                (symbol_short!("limit"), depositor.clone()),
+                (symbol_short!("period"), window.locked_value, limit.max_locked_value),
+            );
+            return Err(Error::PeriodLimitExceeded);
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::PeriodWindow(depositor.clone()), &window);
+        Ok(())
+    }
+
     /// Configure risk-based adjustments (admin only)
     pub fn set_risk_thresholds(
         env: Env,
@@ -248,9 +520,11 @@ impl EscrowContract {
         }
     }
 
-    /// Query effective transaction limit for an address
+    /// Query effective transaction limit for an address: the tier's single
+    /// limit, risk-adjusted, and no more than what is left of the tier's
+    /// cumulative limit after the address's currently locked funds.
     pub fn get_effective_limit(env: Env, address: Address) -> i128 {
-        let identity = Self::get_address_identity(env.clone(), address);
+        let identity = Self::get_address_identity(env.clone(), address.clone());
         
         let tier_limits: TierLimits = env
             .storage()
@@ -264,7 +538,18 @@ impl EscrowContract {
             .get(&DataKey::RiskThresholds)
             .unwrap_or_default();
 
-        identity::calculate_effective_limit(&env, &identity, &tier_limits, &risk_thresholds)
+        let limit =
+            identity::calculate_effective_limit(&env, &identity, &tier_limits, &risk_thresholds);
+        let max_cumulative: i128 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::CumulativeLimit(identity.tier))
+            .unwrap_or(0);
+        if max_cumulative == 0 {
+            return limit;
+        }
+        let headroom = max_cumulative - Self::get_locked_exposure(env, address);
+        limit.min(headroom.max(0))
     }
 
     /// Check if an address has a valid (non-expired) claim
@@ -314,6 +599,229 @@ impl EscrowContract {
         amount: i128,
         deadline: u64,
     ) -> Result<(), Error> {
+        Self::lock_escrow(&env, depositor, bounty_id, amount, deadline, None)
+    }
+
+    /// Lock funds like `lock_funds` under a jurisdiction config, which is
+    /// stored with the escrow and enforced again at release and refund.
+    ///
+    /// A tag must have been registered with `register_jurisdiction_tag`.
+    /// `lock_paused` blocks the lock, `max_lock_amount` caps it, and
+    /// `requires_kyc` needs a valid identity claim from the depositor. With
+    /// `enforce_identity_limits` off, tier limits are skipped. Emits a
+    /// `juris` event.
+    pub fn lock_funds_with_jurisdiction(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+        jurisdiction: OptionalJurisdiction,
+    ) -> Result<(), Error> {
+        let config = match jurisdiction {
+            OptionalJurisdiction::Some(config) => Some(config),
+            OptionalJurisdiction::None => None,
+        };
+        Self::lock_escrow(&env, depositor, bounty_id, amount, deadline, config)
+    }
+
+    /// Register a jurisdiction tag for use in `lock_funds_with_jurisdiction`
+    /// (admin only), so every escrow of a segment carries the same spelling.
+    /// Registering a known tag is a no-op.
+    pub fn register_jurisdiction_tag(env: Env, tag: String) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        if tag.is_empty() || tag.len() > MAX_JURISDICTION_TAG_LEN {
+            return Err(Error::InvalidJurisdictionTag);
+        }
+
+        let mut tags = Self::list_jurisdiction_tags(env.clone());
+        if tags.contains(&tag) {
+            return Ok(());
+        }
+        tags.push_back(tag.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::JurisdictionTags, &tags);
+        events::emit_jurisdiction_tag_registered(
+            &env,
+            events::JurisdictionTagRegistered {
+                tag,
+                registered_by: admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Register (or unregister) a depositor contract to be told of each
+    /// release and refund of its escrows through `on_bounty_settled` (admin
+    /// only). See `settlement.rs`.
+    pub fn set_settlement_listener(
+        env: Env,
+        depositor: Address,
+        enabled: bool,
+    ) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        let key = DataKey::SettlementListener(depositor);
+        if enabled {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        Ok(())
+    }
+
+    pub fn is_settlement_listener(env: Env, depositor: Address) -> bool {
+        settlement::is_listener(&env, &depositor)
+    }
+
+    /// Registered jurisdiction tags, in registration order.
+    pub fn list_jurisdiction_tags(env: Env) -> Vec<String> {
+        env.storage()
+            .instance()
+            .get(&DataKey::JurisdictionTags)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Jurisdiction config of an escrow; `None` for generic escrows.
+    pub fn get_escrow_jurisdiction(env: Env, bounty_id: u64) -> OptionalJurisdiction {
+        match Self::jurisdiction_of(&env, bounty_id) {
+            Some(config) => OptionalJurisdiction::Some(config),
+            None => OptionalJurisdiction::None,
+        }
+    }
+
+    /// Override the lock/release/refund pause flags of an existing escrow
+    /// (admin only), e.g. to freeze ongoing escrows of an affected
+    /// jurisdiction. `flags` is a mask of `ESCROW_PAUSE_LOCK`,
+    /// `ESCROW_PAUSE_RELEASE` and `ESCROW_PAUSE_REFUND`; zero unfreezes.
+    /// Generic escrows get an untagged config carrying the flags. Emits
+    /// `esc_frz`, or `esc_unfrz` when `flags` is zero.
+    pub fn set_escrow_pause_flags(env: Env, bounty_id: u64, flags: u32) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        if flags & !ESCROW_PAUSE_ALL != 0 {
+            return Err(Error::InvalidPauseFlags);
+        }
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+
+        let mut config =
+            Self::jurisdiction_of(&env, bounty_id).unwrap_or(EscrowJurisdictionConfig {
+                tag: None,
+                requires_kyc: false,
+                enforce_identity_limits: true,
+                lock_paused: false,
+                release_paused: false,
+                refund_paused: false,
+                max_lock_amount: None,
+            });
+        let previous_flags = Self::pause_flags_of(&config);
+        config.lock_paused = flags & ESCROW_PAUSE_LOCK != 0;
+        config.release_paused = flags & ESCROW_PAUSE_RELEASE != 0;
+        config.refund_paused = flags & ESCROW_PAUSE_REFUND != 0;
+        env.storage()
+            .persistent()
+            .set(&DataKey::EscrowJurisdiction(bounty_id), &config);
+
+        let event = events::EscrowPauseFlagsChanged {
+            bounty_id,
+            previous_flags,
+            flags,
+            changed_by: admin,
+            timestamp: env.ledger().timestamp(),
+        };
+        if flags == 0 {
+            events::emit_escrow_unfrozen(&env, event);
+        } else {
+            events::emit_escrow_frozen(&env, event);
+        }
+        Ok(())
+    }
+
+    /// Current pause flags of an escrow; zero when nothing is paused.
+    pub fn get_escrow_pause_flags(env: Env, bounty_id: u64) -> u32 {
+        Self::jurisdiction_of(&env, bounty_id).map_or(0, |config| Self::pause_flags_of(&config))
+    }
+
+    fn jurisdiction_of(env: &Env, bounty_id: u64) -> Option<EscrowJurisdictionConfig> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::EscrowJurisdiction(bounty_id))
+    }
+
+    fn pause_flags_of(config: &EscrowJurisdictionConfig) -> u32 {
+        let mut flags = 0;
+        if config.lock_paused {
+            flags |= ESCROW_PAUSE_LOCK;
+        }
+        if config.release_paused {
+            flags |= ESCROW_PAUSE_RELEASE;
+        }
+        if config.refund_paused {
+            flags |= ESCROW_PAUSE_REFUND;
+        }
+        flags
+    }
+
+    /// Internal: enforce the escrow's jurisdiction config for `operation`
+    /// (`lock`, `release` or `refund`) by `party`, and emit a `juris` event
+    /// carrying the config. Returns whether identity limits still apply.
+    fn apply_jurisdiction(
+        env: &Env,
+        bounty_id: u64,
+        config: &Option<EscrowJurisdictionConfig>,
+        operation: Symbol,
+        party: &Address,
+    ) -> Result<bool, Error> {
+        let config = match config {
+            Some(config) => config,
+            None => return Ok(true),
+        };
+        let paused = if operation == symbol_short!("lock") {
+            config.lock_paused
+        } else if operation == symbol_short!("release") {
+            config.release_paused
+        } else {
+            config.refund_paused
+        };
+        if paused {
+            return Err(Error::JurisdictionPaused);
+        }
+        if config.requires_kyc && !Self::is_claim_valid(env.clone(), party.clone()) {
+            return Err(Error::JurisdictionKycRequired);
+        }
+        env.events().publish(
+            (symbol_short!("juris"), operation, bounty_id),
+            config.clone(),
+        );
+        Ok(config.enforce_identity_limits)
+    }
+
+    fn lock_escrow(
+        env: &Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+        jurisdiction: Option<EscrowJurisdictionConfig>,
+    ) -> Result<(), Error> {
+        let env = env.clone();
         // GUARD: acquire reentrancy lock
         reentrancy_guard::acquire(&env);
 
@@ -327,9 +835,34 @@ impl EscrowContract {
         if env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
             return Err(Error::BountyExists);
         }
+        if let Some(max_lock_amount) = jurisdiction.as_ref().and_then(|c| c.max_lock_amount) {
+            if amount > max_lock_amount {
+                return Err(Error::JurisdictionLimitExceeded);
+            }
+        }
+        if let Some(tag) = jurisdiction.as_ref().and_then(|c| c.tag.clone()) {
+            if !Self::list_jurisdiction_tags(env.clone()).contains(&tag) {
+                return Err(Error::JurisdictionTagNotRegistered);
+            }
+        }
+        let enforce_limits = Self::apply_jurisdiction(
+            &env,
+            bounty_id,
+            &jurisdiction,
+            symbol_short!("lock"),
+            &depositor,
+        )?;
 
         // Enforce transaction limit based on identity tier
-        Self::enforce_transaction_limit(&env, &depositor, amount)?;
+        if enforce_limits {
+            Self::enforce_transaction_limit(&env, &depositor, amount)?;
+            Self::enforce_period_limit(&env, &depositor, amount)?;
+        }
+        if let Some(config) = &jurisdiction {
+            env.storage()
+                .persistent()
+                .set(&DataKey::EscrowJurisdiction(bounty_id), config);
+        }
         
         // EFFECTS: write escrow state before external call
         let escrow = Escrow {
@@ -342,6 +875,7 @@ impl EscrowContract {
         env.storage()
             .persistent()
             .set(&DataKey::Escrow(bounty_id), &escrow);
+        Self::adjust_exposure(&env, &depositor, amount);
 
         // INTERACTION: external token transfer is last
         let token = env
@@ -353,13 +887,80 @@ impl EscrowContract {
         let token_client = token::Client::new(&env, &token);
         token_client.transfer(&depositor, &contract, &amount);
 
+        events::emit_funds_locked(
+            &env,
+            events::FundsLocked {
+                version: events::EVENT_VERSION_V3,
+                bounty_id,
+                amount,
+                remaining_before: 0,
+                remaining_after: amount,
+                depositor,
+                deadline,
+            },
+        );
+
         // GUARD: release reentrancy lock
         reentrancy_guard::release(&env);
         Ok(())
     }
 
+    /// Lock funds like `lock_funds`, with a payout split table applied at
+    /// `release_funds` (e.g. 80% contributor, 15% reviewer, 5% platform).
+    ///
+    /// Shares must be non-zero, sum to exactly `BASIS_POINTS`, number at most
+    /// `MAX_SPLIT_SHARES` and name `SplitRecipient::Contributor` at most once.
+    pub fn lock_funds_with_split(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+        shares: Vec<PayoutShare>,
+    ) -> Result<(), Error> {
+        Self::validate_split(&shares)?;
+        Self::lock_funds(env.clone(), depositor, bounty_id, amount, deadline)?;
+        env.storage()
+            .persistent()
+            .set(&DataKey::PayoutSplit(bounty_id), &shares);
+        Ok(())
+    }
+
+    /// Payout split table of a bounty, if it was locked with one.
+    pub fn get_payout_split(env: Env, bounty_id: u64) -> Option<Vec<PayoutShare>> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PayoutSplit(bounty_id))
+    }
+
+    fn validate_split(shares: &Vec<PayoutShare>) -> Result<(), Error> {
+        if shares.is_empty() || shares.len() > MAX_SPLIT_SHARES {
+            return Err(Error::InvalidSplit);
+        }
+        let mut total: u32 = 0;
+        let mut contributor_rows = 0;
+        for share in shares.iter() {
+            if share.bps == 0 || share.bps > BASIS_POINTS {
+                return Err(Error::InvalidSplit);
+            }
+            if share.recipient == SplitRecipient::Contributor {
+                contributor_rows += 1;
+            }
+            total += share.bps;
+        }
+        if total != BASIS_POINTS || contributor_rows > 1 {
+            return Err(Error::InvalidSplit);
+        }
+        Ok(())
+    }
+
     /// Release funds to contributor. Admin must be authorized. Fails if already released or refunded.
     ///
+    /// If the bounty was locked with a payout split, the amount is divided
+    /// by the table instead: each row is rounded down and the rounding dust
+    /// goes to the first row. Every transfer emits a `split` event with
+    /// `(recipient, amount, bps)`.
+    ///
     /// # Reentrancy
     /// Protected by reentrancy guard. Escrow state is updated to
     /// `Released` *before* the outbound token transfer (CEI pattern).
@@ -385,18 +986,46 @@ impl EscrowContract {
             return Err(Error::InsufficientBalance);
         }
 
+        let release_amount = escrow.remaining_amount;
+        let payouts = match env
+            .storage()
+            .persistent()
+            .get::<_, Vec<PayoutShare>>(&DataKey::PayoutSplit(bounty_id))
+        {
+            Some(shares) => Self::split_payouts(&env, &shares, &contributor, release_amount),
+            None => {
+                let mut single = Vec::new(&env);
+                single.push_back((contributor.clone(), release_amount, BASIS_POINTS));
+                single
+            }
+        };
+
+        let enforce_limits = Self::apply_jurisdiction(
+            &env,
+            bounty_id,
+            &Self::jurisdiction_of(&env, bounty_id),
+            symbol_short!("release"),
+            &contributor,
+        )?;
+
         // Enforce transaction limit for contributor
-        Self::enforce_transaction_limit(&env, &contributor, escrow.remaining_amount)?;
-        
+        if enforce_limits {
+            let contributor_amount = payouts
+                .iter()
+                .filter(|(to, _, _)| *to == contributor)
+                .fold(0, |sum, (_, amount, _)| sum + amount);
+            Self::enforce_transaction_limit(&env, &contributor, contributor_amount)?;
+        }
+
         // EFFECTS: update state before external call (CEI)
-        let release_amount = escrow.remaining_amount;
         escrow.remaining_amount = 0;
         escrow.status = EscrowStatus::Released;
         env.storage()
             .persistent()
             .set(&DataKey::Escrow(bounty_id), &escrow);
+        Self::adjust_exposure(&env, &escrow.depositor, -release_amount);
 
-        // INTERACTION: external token transfer is last
+        // INTERACTION: external token transfers are last
         let token = env
             .storage()
             .instance()
@@ -404,7 +1033,40 @@ impl EscrowContract {
             .unwrap();
         let contract = env.current_contract_address();
         let token_client = token::Client::new(&env, &token);
-        token_client.transfer(&contract, &contributor, &release_amount);
+        let split = env
+            .storage()
+            .persistent()
+            .has(&DataKey::PayoutSplit(bounty_id));
+        let mut remaining = release_amount;
+        for (to, amount, bps) in payouts.iter() {
+            if amount > 0 {
+                token_client.transfer(&contract, &to, &amount);
+                events::emit_funds_released(
+                    &env,
+                    events::FundsReleased {
+                        version: events::EVENT_VERSION_V3,
+                        bounty_id,
+                        amount,
+                        remaining_before: remaining,
+                        remaining_after: remaining - amount,
+                        recipient: to.clone(),
+                        timestamp: env.ledger().timestamp(),
+                    },
+                );
+                remaining -= amount;
+            }
+            if split {
+                env.events()
+                    .publish((symbol_short!("split"), bounty_id), (to, amount, bps));
+            }
+        }
+        settlement::notify(
+            &env,
+            &escrow.depositor,
+            bounty_id,
+            BountyOutcome::Released,
+            release_amount,
+        );
 
         // GUARD: release reentrancy lock
         reentrancy_guard::release(&env);
@@ -417,6 +1079,84 @@ impl EscrowContract {
     /// Protected by reentrancy guard. Escrow state is updated to
     /// `Refunded` *before* the outbound token transfer (CEI pattern).
     pub fn refund(env: Env, bounty_id: u64) -> Result<(), Error> {
+        Self::refund_logic(env, bounty_id, None)
+    }
+
+    /// Register the ed25519 key that can redirect `depositor`'s refunds via
+    /// `refund_to` should the depositor lose access to its account.
+    pub fn set_recovery_key(env: Env, depositor: Address, public_key: BytesN<32>) {
+        depositor.require_auth();
+        env.storage()
+            .persistent()
+            .set(&DataKey::RecoveryKey(depositor), &public_key);
+    }
+
+    pub fn get_recovery_key(env: Env, depositor: Address) -> Option<BytesN<32>> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RecoveryKey(depositor))
+    }
+
+    pub fn get_recovery_nonce(env: Env, depositor: Address) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RecoveryNonce(depositor))
+            .unwrap_or(0)
+    }
+
+    /// The message the recovery key must sign for `refund_to`. It binds
+    /// this contract, the bounty, the alternate recipient and the
+    /// depositor's current recovery nonce, so a signature is single-use.
+    pub fn get_refund_to_message(
+        env: Env,
+        bounty_id: u64,
+        alternate: Address,
+    ) -> Result<Bytes, Error> {
+        let escrow = Self::get_escrow(env.clone(), bounty_id)?;
+        let nonce = Self::get_recovery_nonce(env.clone(), escrow.depositor);
+        Ok(Self::refund_to_message(&env, bounty_id, &alternate, nonce))
+    }
+
+    /// `refund`, paid to `alternate` instead of the depositor. Authorized by
+    /// a signature from the depositor's recovery key over
+    /// `get_refund_to_message`; the deadline and jurisdiction rules of
+    /// `refund` still apply.
+    pub fn refund_to(
+        env: Env,
+        bounty_id: u64,
+        alternate: Address,
+        signature: BytesN<64>,
+    ) -> Result<(), Error> {
+        let escrow = Self::get_escrow(env.clone(), bounty_id)?;
+        let depositor = escrow.depositor;
+        let public_key: BytesN<32> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::RecoveryKey(depositor.clone()))
+            .ok_or(Error::RecoveryKeyNotSet)?;
+        let nonce = Self::get_recovery_nonce(env.clone(), depositor.clone());
+        let message = Self::refund_to_message(&env, bounty_id, &alternate, nonce);
+        env.crypto()
+            .ed25519_verify(&public_key, &message, &signature);
+        env.storage()
+            .persistent()
+            .set(&DataKey::RecoveryNonce(depositor), &(nonce + 1));
+
+        Self::refund_logic(env, bounty_id, Some(alternate))
+    }
+
+    fn refund_to_message(env: &Env, bounty_id: u64, alternate: &Address, nonce: u64) -> Bytes {
+        let mut message = symbol_short!("rfnd_to").to_xdr(env);
+        message.append(&env.current_contract_address().to_xdr(env));
+        message.append(&Bytes::from_array(env, &bounty_id.to_be_bytes()));
+        message.append(&alternate.clone().to_xdr(env));
+        message.append(&Bytes::from_array(env, &nonce.to_be_bytes()));
+        message
+    }
+
+    /// Refund `bounty_id` to the depositor, or to `alternate` once
+    /// `refund_to` has verified the recovery signature.
+    fn refund_logic(env: Env, bounty_id: u64, alternate: Option<Address>) -> Result<(), Error> {
         // GUARD: acquire reentrancy lock
         reentrancy_guard::acquire(&env);
 
@@ -439,6 +1179,13 @@ impl EscrowContract {
         if escrow.remaining_amount <= 0 {
             return Err(Error::InsufficientBalance);
         }
+        Self::apply_jurisdiction(
+            &env,
+            bounty_id,
+            &Self::jurisdiction_of(&env, bounty_id),
+            symbol_short!("refund"),
+            &escrow.depositor,
+        )?;
 
         // EFFECTS: update state before external call (CEI)
         let amount = escrow.remaining_amount;
@@ -448,6 +1195,7 @@ impl EscrowContract {
         env.storage()
             .persistent()
             .set(&DataKey::Escrow(bounty_id), &escrow);
+        Self::adjust_exposure(&env, &depositor, -amount);
 
         // INTERACTION: external token transfer is last
         let token = env
@@ -457,13 +1205,184 @@ impl EscrowContract {
             .unwrap();
         let contract = env.current_contract_address();
         let token_client = token::Client::new(&env, &token);
-        token_client.transfer(&contract, &depositor, &amount);
+        let refund_to = alternate.unwrap_or(depositor.clone());
+        token_client.transfer(&contract, &refund_to, &amount);
+        // A refund redirected by `refund_to` never reached the depositor.
+        if refund_to == depositor {
+            settlement::notify(&env, &depositor, bounty_id, BountyOutcome::Refunded, amount);
+        }
+
+        events::emit_funds_refunded(
+            &env,
+            events::FundsRefunded {
+                version: events::EVENT_VERSION_V3,
+                bounty_id,
+                amount,
+                remaining_before: amount,
+                remaining_after: 0,
+                refund_to,
+                timestamp: now,
+            },
+        );
 
         // GUARD: release reentrancy lock
         reentrancy_guard::release(&env);
         Ok(())
     }
 
+    /// Carve `amount` out of a locked escrow into a new, independent escrow
+    /// `new_id`, e.g. when one funded issue is split into two sub-issues.
+    /// Only the depositor can split. The child keeps the parent's deadline,
+    /// jurisdiction config and payout split table; no tokens move.
+    ///
+    /// `amount` must be positive and below the parent's remaining amount.
+    /// Emits `EscrowSplit` (topic `f_split`).
+    pub fn split_escrow(env: Env, bounty_id: u64, new_id: u64, amount: i128) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let mut parent = Self::get_escrow(env.clone(), bounty_id)?;
+        if parent.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+        let depositor = parent.depositor.clone();
+        depositor.require_auth();
+
+        if amount <= 0 || amount >= parent.remaining_amount {
+            return Err(Error::InvalidAmount);
+        }
+        if env.storage().persistent().has(&DataKey::Escrow(new_id)) {
+            return Err(Error::BountyExists);
+        }
+
+        let remaining_before = parent.remaining_amount;
+        parent.amount -= amount;
+        parent.remaining_amount -= amount;
+        let child = Escrow {
+            depositor: depositor.clone(),
+            amount,
+            remaining_amount: amount,
+            status: EscrowStatus::Locked,
+            deadline: parent.deadline,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &parent);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(new_id), &child);
+        if let Some(config) = Self::jurisdiction_of(&env, bounty_id) {
+            env.storage()
+                .persistent()
+                .set(&DataKey::EscrowJurisdiction(new_id), &config);
+        }
+        if let Some(shares) = Self::get_payout_split(env.clone(), bounty_id) {
+            env.storage()
+                .persistent()
+                .set(&DataKey::PayoutSplit(new_id), &shares);
+        }
+
+        events::emit_escrow_split(
+            &env,
+            events::EscrowSplit {
+                version: events::EVENT_VERSION_V3,
+                bounty_id,
+                new_bounty_id: new_id,
+                amount,
+                remaining_before,
+                remaining_after: parent.remaining_amount,
+                depositor,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Fold the remaining balance of locked escrow `source_id` into locked
+    /// escrow `target_id`, the inverse of `split_escrow`. Both must belong
+    /// to the same depositor and carry the same jurisdiction config and
+    /// payout split table; all escrows of this contract share its token. The
+    /// target takes the later of the two deadlines; the source is left
+    /// `Released` with nothing remaining.
+    ///
+    /// Emits `EscrowsMerged` (topic `f_merge`).
+    pub fn merge_escrows(env: Env, target_id: u64, source_id: u64) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        if target_id == source_id {
+            return Err(Error::IncompatibleEscrows);
+        }
+        let mut target = Self::get_escrow(env.clone(), target_id)?;
+        let mut source = Self::get_escrow(env.clone(), source_id)?;
+        if target.status != EscrowStatus::Locked || source.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+        target.depositor.require_auth();
+        if source.depositor != target.depositor
+            || Self::jurisdiction_of(&env, source_id) != Self::jurisdiction_of(&env, target_id)
+            || Self::get_payout_split(env.clone(), source_id)
+                != Self::get_payout_split(env.clone(), target_id)
+        {
+            return Err(Error::IncompatibleEscrows);
+        }
+
+        let moved = source.remaining_amount;
+        let remaining_before = target.remaining_amount;
+        target.amount += moved;
+        target.remaining_amount += moved;
+        target.deadline = target.deadline.max(source.deadline);
+        source.amount -= moved;
+        source.remaining_amount = 0;
+        source.status = EscrowStatus::Released;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(source_id), &source);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(target_id), &target);
+
+        events::emit_escrows_merged(
+            &env,
+            events::EscrowsMerged {
+                version: events::EVENT_VERSION_V3,
+                bounty_id: target_id,
+                source_bounty_id: source_id,
+                amount: moved,
+                remaining_before,
+                remaining_after: target.remaining_amount,
+                deadline: target.deadline,
+                depositor: target.depositor.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Divide `total` by the split table into `(recipient, amount, bps)`
+    /// rows. Rows are rounded down; the dust goes to the first row.
+    fn split_payouts(
+        env: &Env,
+        shares: &Vec<PayoutShare>,
+        contributor: &Address,
+        total: i128,
+    ) -> Vec<(Address, i128, u32)> {
+        let mut payouts = Vec::new(env);
+        let mut paid: i128 = 0;
+        for share in shares.iter() {
+            let to = match share.recipient {
+                SplitRecipient::Contributor => contributor.clone(),
+                SplitRecipient::Fixed(addr) => addr,
+            };
+            let amount = total * share.bps as i128 / BASIS_POINTS as i128;
+            paid += amount;
+            payouts.push_back((to, amount, share.bps));
+        }
+        let (to, first, bps) = payouts.get(0).unwrap();
+        payouts.set(0, (to, first + total - paid, bps));
+        payouts
+    }
+
     /// Read escrow state (for tests).
     pub fn get_escrow(env: Env, bounty_id: u64) -> Result<Escrow, Error> {
         env.storage()
@@ -543,3 +1462,9 @@ pub mod traits {
 
 mod test;
 mod identity_test;
+mod test_payout_split;
+mod test_identity_limits;
+mod test_period_limits;
+mod test_event_parity;
+mod test_split_merge;
+mod test_refund_to;
@@ -0,0 +1,54 @@
+//! Settlement callbacks to registry depositors.
+//!
+//! `soroban/contracts/program-escrow` funds bounties with itself as the
+//! depositor and tracks them per program. Once the admin registers such a
+//! depositor with `set_settlement_listener`, this escrow reports each release
+//! and each refund paid back to it through `on_bounty_settled`. The registry
+//! authorizes that callback because this contract invokes it directly.
+//!
+//! A failing callback is ignored, so a listener can never block funds from
+//! leaving the escrow.
+
+use soroban_sdk::{contractclient, contracttype, Address, Env};
+
+use crate::DataKey;
+
+/// Mirror of `BountyOutcome` in `soroban/contracts/program-escrow`.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BountyOutcome {
+    Released,
+    Refunded,
+}
+
+#[contractclient(name = "SettlementListenerClient")]
+pub trait SettlementListener {
+    fn on_bounty_settled(
+        env: Env,
+        bounty_escrow: Address,
+        bounty_id: u64,
+        outcome: BountyOutcome,
+        amount: i128,
+    );
+}
+
+pub fn is_listener(env: &Env, depositor: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SettlementListener(depositor.clone()))
+        .unwrap_or(false)
+}
+
+/// Report `amount` of `bounty_id` settled with `outcome` to `depositor`, if
+/// it is a registered listener.
+pub fn notify(env: &Env, depositor: &Address, bounty_id: u64, outcome: BountyOutcome, amount: i128) {
+    if !is_listener(env, depositor) {
+        return;
+    }
+    let _ = SettlementListenerClient::new(env, depositor).try_on_bounty_settled(
+        &env.current_contract_address(),
+        &bounty_id,
+        &outcome,
+        &amount,
+    );
+}
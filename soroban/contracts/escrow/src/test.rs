@@ -51,17 +51,6 @@ fn setup<'a>(
 }
 
 fn has_event_topic(env: &Env, topic_name: &str) -> bool {
-    use soroban_sdk::testutils::Events as _;
-    let expected = Symbol::new(env, topic_name);
-    let events = env.events().all();
-    for (_contract, topics, _data) in events.iter() {
-        if topics.len() == 0 {
-            continue;
-        }
-        let first: soroban_sdk::Val = topics.get(0).unwrap();
-        // Compare the raw val representation
-        let expected_val: soroban_sdk::Val = expected.to_val();
-        if first.get_payload() == expected_val.get_payload() {
     use soroban_sdk::IntoVal;
     let expected: soroban_sdk::Val = Symbol::new(env, topic_name).into_val(env);
     let events = env.events().all();
@@ -204,6 +193,7 @@ fn test_jurisdiction_tagged_escrow_can_skip_identity_limits() {
 
     let bounty_id = 51u64;
     let deadline = env.ledger().timestamp() + 1000;
+    client.register_jurisdiction_tag(&String::from_str(&env, "US-only"));
     let cfg = EscrowJurisdictionConfig {
         tag: Some(String::from_str(&env, "US-only")),
         requires_kyc: false,
@@ -246,6 +236,7 @@ fn test_jurisdiction_lock_pause_blocks_new_locks() {
 
     let bounty_id = 53u64;
     let deadline = env.ledger().timestamp() + 1000;
+    client.register_jurisdiction_tag(&String::from_str(&env, "EU-only"));
     let cfg = EscrowJurisdictionConfig {
         tag: Some(String::from_str(&env, "EU-only")),
         requires_kyc: false,
@@ -274,6 +265,7 @@ fn test_jurisdiction_events_emitted() {
 
     let bounty_id = 54u64;
     let deadline = env.ledger().timestamp() + 1000;
+    client.register_jurisdiction_tag(&String::from_str(&env, "pilot-zone"));
     let cfg = EscrowJurisdictionConfig {
         tag: Some(String::from_str(&env, "pilot-zone")),
         requires_kyc: false,
@@ -289,3 +281,104 @@ fn test_jurisdiction_events_emitted() {
 
     assert!(has_event_topic(&env, "juris"));
 }
+
+#[test]
+fn test_pause_flags_freeze_and_unfreeze_an_ongoing_escrow() {
+    let env = Env::default();
+    let amount = 10_000i128;
+    let (client, _cid, _admin, depositor, contributor, token_client) = setup(&env, amount);
+
+    let bounty_id = 55u64;
+    let deadline = env.ledger().timestamp() + 1000;
+    client.lock_funds(&depositor, &bounty_id, &amount, &deadline);
+
+    client.set_escrow_pause_flags(&bounty_id, &(ESCROW_PAUSE_RELEASE | ESCROW_PAUSE_REFUND));
+    assert!(has_event_topic(&env, "esc_frz"));
+    assert_eq!(
+        client.get_escrow_pause_flags(&bounty_id),
+        ESCROW_PAUSE_RELEASE | ESCROW_PAUSE_REFUND
+    );
+    assert_eq!(
+        client.try_release_funds(&bounty_id, &contributor),
+        Err(Ok(Error::JurisdictionPaused))
+    );
+
+    client.set_escrow_pause_flags(&bounty_id, &0);
+    assert!(has_event_topic(&env, "esc_unfrz"));
+    client.release_funds(&bounty_id, &contributor);
+    assert_eq!(token_client.balance(&contributor), amount);
+}
+
+#[test]
+fn test_pause_flags_reject_unknown_bits_and_bounties() {
+    let env = Env::default();
+    let amount = 10_000i128;
+    let (client, _cid, _admin, depositor, _contributor, _token_client) = setup(&env, amount);
+
+    let bounty_id = 56u64;
+    let deadline = env.ledger().timestamp() + 1000;
+    client.lock_funds(&depositor, &bounty_id, &amount, &deadline);
+
+    assert_eq!(
+        client.try_set_escrow_pause_flags(&bounty_id, &(1 << 3)),
+        Err(Ok(Error::InvalidPauseFlags))
+    );
+    assert_eq!(
+        client.try_set_escrow_pause_flags(&99, &ESCROW_PAUSE_LOCK),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
+
+#[test]
+fn test_lock_rejects_unregistered_jurisdiction_tag() {
+    let env = Env::default();
+    let amount = 10_000i128;
+    let (client, _cid, _admin, depositor, _contributor, _token_client) = setup(&env, amount);
+    let deadline = env.ledger().timestamp() + 1000;
+    let cfg = EscrowJurisdictionConfig {
+        tag: Some(String::from_str(&env, "us_only")),
+        requires_kyc: false,
+        enforce_identity_limits: true,
+        lock_paused: false,
+        release_paused: false,
+        refund_paused: false,
+        max_lock_amount: None,
+    };
+
+    client.register_jurisdiction_tag(&String::from_str(&env, "US-only"));
+    assert_eq!(
+        client.try_lock_funds_with_jurisdiction(
+            &depositor,
+            &57,
+            &amount,
+            &deadline,
+            &OptionalJurisdiction::Some(cfg),
+        ),
+        Err(Ok(Error::JurisdictionTagNotRegistered))
+    );
+}
+
+#[test]
+fn test_jurisdiction_tag_registry() {
+    let env = Env::default();
+    let (client, _cid, _admin, _depositor, _contributor, _token_client) = setup(&env, 0);
+    let tag = String::from_str(&env, "EU-only");
+
+    client.register_jurisdiction_tag(&tag);
+    assert!(has_event_topic(&env, "juris_reg"));
+    client.register_jurisdiction_tag(&tag);
+    assert_eq!(client.list_jurisdiction_tags().len(), 1);
+    assert_eq!(client.list_jurisdiction_tags().get(0), Some(tag));
+
+    assert_eq!(
+        client.try_register_jurisdiction_tag(&String::from_str(&env, "")),
+        Err(Ok(Error::InvalidJurisdictionTag))
+    );
+    assert_eq!(
+        client.try_register_jurisdiction_tag(&String::from_str(
+            &env,
+            "a-tag-that-is-longer-than-32-bytes"
+        )),
+        Err(Ok(Error::InvalidJurisdictionTag))
+    );
+}
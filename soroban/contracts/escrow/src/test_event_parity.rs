@@ -0,0 +1,105 @@
+#![cfg(test)]
+//! Fund-moving events use the bounty escrow's topics and payloads.
+
+use super::events::{FundsLocked, FundsRefunded, FundsReleased, EVENT_VERSION_V3};
+use super::*;
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{token, Address, Env, IntoVal, Symbol, TryFromVal, Val};
+
+fn setup<'a>(env: &'a Env) -> (EscrowContractClient<'a>, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register(EscrowContract, ());
+    let client = EscrowContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_addr = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    client.init(&admin, &token_addr);
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_addr).mint(&depositor, &10_000);
+    (client, depositor)
+}
+
+/// Payloads of the last call's events under `(name, bounty_id)`.
+fn payloads(env: &Env, name: &str, bounty_id: u64) -> Vec<Val> {
+    let expected: Vec<Val> = (Symbol::new(env, name), bounty_id).into_val(env);
+    let mut out = Vec::new(env);
+    for (_, topics, data) in env.events().all().iter() {
+        let matches = topics.len() == 2
+            && topics
+                .iter()
+                .zip(expected.iter())
+                .all(|(a, b)| a.get_payload() == b.get_payload());
+        if matches {
+            out.push_back(data);
+        }
+    }
+    out
+}
+
+#[test]
+fn test_lock_event_layout() {
+    let env = Env::default();
+    let (client, depositor) = setup(&env);
+    let deadline = env.ledger().timestamp() + 1_000;
+    client.lock_funds(&depositor, &7, &1_000, &deadline);
+
+    let data = payloads(&env, "f_lock", 7).get(0).unwrap();
+    let event = FundsLocked::try_from_val(&env, &data).unwrap();
+    assert_eq!(event.version, EVENT_VERSION_V3);
+    assert_eq!(event.amount, 1_000);
+    assert_eq!((event.remaining_before, event.remaining_after), (0, 1_000));
+    assert_eq!(event.depositor, depositor);
+    assert_eq!(event.deadline, deadline);
+}
+
+#[test]
+fn test_split_release_emits_running_balance() {
+    let env = Env::default();
+    let (client, depositor) = setup(&env);
+    let deadline = env.ledger().timestamp() + 1_000;
+    let reviewer = Address::generate(&env);
+    let shares = soroban_sdk::vec![
+        &env,
+        PayoutShare {
+            recipient: SplitRecipient::Contributor,
+            bps: 8_000,
+        },
+        PayoutShare {
+            recipient: SplitRecipient::Fixed(reviewer.clone()),
+            bps: 2_000,
+        },
+    ];
+    client.lock_funds_with_split(&depositor, &1, &1_000, &deadline, &shares);
+
+    let contributor = Address::generate(&env);
+    client.release_funds(&1, &contributor);
+    let released = payloads(&env, "f_rel", 1);
+    assert_eq!(released.len(), 2);
+    let first = FundsReleased::try_from_val(&env, &released.get(0).unwrap()).unwrap();
+    let second = FundsReleased::try_from_val(&env, &released.get(1).unwrap()).unwrap();
+    assert_eq!(first.recipient, contributor);
+    assert_eq!(
+        (first.remaining_before, first.remaining_after),
+        (1_000, 200)
+    );
+    assert_eq!(second.recipient, reviewer);
+    assert_eq!((second.remaining_before, second.remaining_after), (200, 0));
+}
+
+#[test]
+fn test_refund_event_layout() {
+    let env = Env::default();
+    let (client, depositor) = setup(&env);
+    let deadline = env.ledger().timestamp() + 1_000;
+    client.lock_funds(&depositor, &3, &500, &deadline);
+
+    env.ledger().with_mut(|li| li.timestamp = deadline);
+    client.refund(&3);
+    let data = payloads(&env, "f_ref", 3).get(0).unwrap();
+    let event = FundsRefunded::try_from_val(&env, &data).unwrap();
+    assert_eq!(event.version, EVENT_VERSION_V3);
+    assert_eq!((event.remaining_before, event.remaining_after), (500, 0));
+    assert_eq!(event.refund_to, depositor);
+}
@@ -0,0 +1,130 @@
+#![cfg(test)]
+//! Operator entrypoints for tier limits and address tiers.
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{token, Address, Env};
+
+struct Setup<'a> {
+    client: EscrowContractClient<'a>,
+    token: token::Client<'a>,
+    depositor: Address,
+}
+
+fn setup<'a>(env: &'a Env) -> Setup<'a> {
+    env.mock_all_auths();
+    let contract_id = env.register(EscrowContract, ());
+    let client = EscrowContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_addr = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    client.init(&admin, &token_addr);
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_addr).mint(&depositor, &10_000);
+
+    Setup {
+        client,
+        token: token::Client::new(env, &token_addr),
+        depositor,
+    }
+}
+
+#[test]
+fn test_set_identity_limits_for_one_tier() {
+    let env = Env::default();
+    let s = setup(&env);
+    let defaults = TierLimits::default();
+
+    s.client
+        .set_identity_limits(&IdentityTier::Basic, &500, &2_000);
+    assert_eq!(
+        s.client.get_identity_limits(&IdentityTier::Basic),
+        (500, 2_000)
+    );
+    assert_eq!(
+        s.client.get_identity_limits(&IdentityTier::Verified),
+        (defaults.verified_limit, 0)
+    );
+}
+
+#[test]
+fn test_set_address_tier_raises_limit() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client
+        .set_identity_limits(&IdentityTier::Unverified, &100, &0);
+    s.client
+        .set_identity_limits(&IdentityTier::Verified, &5_000, &0);
+    assert_eq!(s.client.get_effective_limit(&s.depositor), 100);
+
+    s.client
+        .set_address_tier(&s.depositor, &IdentityTier::Verified);
+    assert_eq!(
+        s.client.get_address_identity(&s.depositor).tier,
+        IdentityTier::Verified
+    );
+    assert_eq!(s.client.get_effective_limit(&s.depositor), 5_000);
+
+    // Admin-assigned tiers do not expire.
+    env.ledger().with_mut(|li| li.timestamp = u64::MAX - 1);
+    assert!(s.client.is_claim_valid(&s.depositor));
+}
+
+#[test]
+fn test_cumulative_limit_tracks_locked_funds() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client
+        .set_identity_limits(&IdentityTier::Unverified, &1_000, &1_500);
+    let deadline = env.ledger().timestamp() + 1_000;
+
+    s.client.lock_funds(&s.depositor, &1, &1_000, &deadline);
+    assert_eq!(s.client.get_locked_exposure(&s.depositor), 1_000);
+    assert_eq!(s.client.get_effective_limit(&s.depositor), 500);
+    assert_eq!(
+        s.client.try_lock_funds(&s.depositor, &2, &600, &deadline),
+        Err(Ok(Error::TransactionExceedsLimit))
+    );
+
+    // Releasing the first bounty frees the headroom again.
+    s.client.release_funds(&1, &Address::generate(&env));
+    assert_eq!(s.client.get_locked_exposure(&s.depositor), 0);
+    s.client.lock_funds(&s.depositor, &2, &600, &deadline);
+    assert_eq!(s.token.balance(&s.depositor), 8_400);
+}
+
+#[test]
+fn test_refund_frees_exposure() {
+    let env = Env::default();
+    let s = setup(&env);
+    let deadline = env.ledger().timestamp() + 100;
+    s.client.lock_funds(&s.depositor, &1, &50, &deadline);
+
+    env.ledger().with_mut(|li| li.timestamp = deadline);
+    s.client.refund(&1);
+    assert_eq!(s.client.get_locked_exposure(&s.depositor), 0);
+}
+
+#[test]
+fn test_invalid_limits_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    assert_eq!(
+        s.client
+            .try_set_identity_limits(&IdentityTier::Basic, &0, &0),
+        Err(Ok(Error::InvalidLimit))
+    );
+    assert_eq!(
+        s.client
+            .try_set_identity_limits(&IdentityTier::Basic, &1_000, &500),
+        Err(Ok(Error::InvalidLimit))
+    );
+    assert_eq!(
+        s.client
+            .try_set_identity_limits(&IdentityTier::Basic, &1_000, &-1),
+        Err(Ok(Error::InvalidLimit))
+    );
+}
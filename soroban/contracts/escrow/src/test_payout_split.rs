@@ -0,0 +1,172 @@
+#![cfg(test)]
+//! Payout split tables applied at `release_funds`.
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{token, vec, Address, Env, IntoVal, Val};
+
+struct Setup<'a> {
+    client: EscrowContractClient<'a>,
+    token: token::Client<'a>,
+    depositor: Address,
+    contributor: Address,
+    reviewer: Address,
+    platform: Address,
+}
+
+fn setup<'a>(env: &'a Env) -> Setup<'a> {
+    env.mock_all_auths();
+    let contract_id = env.register(EscrowContract, ());
+    let client = EscrowContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_addr = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    client.init(&admin, &token_addr);
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_addr).mint(&depositor, &10_000);
+
+    Setup {
+        client,
+        token: token::Client::new(env, &token_addr),
+        depositor,
+        contributor: Address::generate(env),
+        reviewer: Address::generate(env),
+        platform: Address::generate(env),
+    }
+}
+
+fn standard_split(env: &Env, s: &Setup) -> Vec<PayoutShare> {
+    vec![
+        env,
+        PayoutShare {
+            recipient: SplitRecipient::Contributor,
+            bps: 8_000,
+        },
+        PayoutShare {
+            recipient: SplitRecipient::Fixed(s.reviewer.clone()),
+            bps: 1_500,
+        },
+        PayoutShare {
+            recipient: SplitRecipient::Fixed(s.platform.clone()),
+            bps: 500,
+        },
+    ]
+}
+
+#[test]
+fn test_release_applies_split() {
+    let env = Env::default();
+    let s = setup(&env);
+    let deadline = env.ledger().timestamp() + 1_000;
+    s.client.lock_funds_with_split(
+        &s.depositor,
+        &1,
+        &1_000,
+        &deadline,
+        &standard_split(&env, &s),
+    );
+
+    s.client.release_funds(&1, &s.contributor);
+    let split: Val = symbol_short!("split").into_val(&env);
+    let emitted = env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| topics.get(0).unwrap().get_payload() == split.get_payload())
+        .count();
+    assert_eq!(emitted, 3);
+
+    assert_eq!(s.token.balance(&s.contributor), 800);
+    assert_eq!(s.token.balance(&s.reviewer), 150);
+    assert_eq!(s.token.balance(&s.platform), 50);
+    assert_eq!(s.client.get_escrow(&1).status, EscrowStatus::Released);
+}
+
+#[test]
+fn test_rounding_dust_goes_to_first_row() {
+    let env = Env::default();
+    let s = setup(&env);
+    let deadline = env.ledger().timestamp() + 1_000;
+    s.client
+        .lock_funds_with_split(&s.depositor, &1, &999, &deadline, &standard_split(&env, &s));
+
+    s.client.release_funds(&1, &s.contributor);
+    // 799.2 / 149.85 / 49.95 round down to 799 / 149 / 49; 2 units of dust.
+    assert_eq!(s.token.balance(&s.contributor), 801);
+    assert_eq!(s.token.balance(&s.reviewer), 149);
+    assert_eq!(s.token.balance(&s.platform), 49);
+}
+
+#[test]
+fn test_split_must_total_basis_points() {
+    let env = Env::default();
+    let s = setup(&env);
+    let deadline = env.ledger().timestamp() + 1_000;
+    let short = vec![
+        &env,
+        PayoutShare {
+            recipient: SplitRecipient::Contributor,
+            bps: 9_000,
+        },
+    ];
+    assert_eq!(
+        s.client
+            .try_lock_funds_with_split(&s.depositor, &1, &1_000, &deadline, &short),
+        Err(Ok(Error::InvalidSplit))
+    );
+
+    let zero_row = vec![
+        &env,
+        PayoutShare {
+            recipient: SplitRecipient::Contributor,
+            bps: 10_000,
+        },
+        PayoutShare {
+            recipient: SplitRecipient::Fixed(s.platform.clone()),
+            bps: 0,
+        },
+    ];
+    assert_eq!(
+        s.client
+            .try_lock_funds_with_split(&s.depositor, &1, &1_000, &deadline, &zero_row),
+        Err(Ok(Error::InvalidSplit))
+    );
+    assert_eq!(s.token.balance(&s.depositor), 10_000);
+}
+
+#[test]
+fn test_contributor_listed_once() {
+    let env = Env::default();
+    let s = setup(&env);
+    let deadline = env.ledger().timestamp() + 1_000;
+    let twice = vec![
+        &env,
+        PayoutShare {
+            recipient: SplitRecipient::Contributor,
+            bps: 5_000,
+        },
+        PayoutShare {
+            recipient: SplitRecipient::Contributor,
+            bps: 5_000,
+        },
+    ];
+    assert_eq!(
+        s.client
+            .try_lock_funds_with_split(&s.depositor, &1, &1_000, &deadline, &twice),
+        Err(Ok(Error::InvalidSplit))
+    );
+}
+
+#[test]
+fn test_plain_lock_pays_contributor_in_full() {
+    let env = Env::default();
+    let s = setup(&env);
+    let deadline = env.ledger().timestamp() + 1_000;
+    s.client.lock_funds(&s.depositor, &1, &1_000, &deadline);
+
+    assert_eq!(s.client.get_payout_split(&1), None);
+    s.client.release_funds(&1, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+}
@@ -0,0 +1,109 @@
+#![cfg(test)]
+//! Per-tier caps on the value a depositor may lock per period.
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{token, Address, Env};
+
+const DAY: u64 = 86_400;
+
+struct Setup<'a> {
+    client: EscrowContractClient<'a>,
+    depositor: Address,
+    deadline: u64,
+}
+
+/// Unverified depositors may lock at most 1_000 per day.
+fn setup<'a>(env: &'a Env) -> Setup<'a> {
+    env.mock_all_auths();
+    let contract_id = env.register(EscrowContract, ());
+    let client = EscrowContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_addr = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    client.init(&admin, &token_addr);
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_addr).mint(&depositor, &10_000);
+    client.set_period_limit(&IdentityTier::Unverified, &DAY, &1_000);
+
+    Setup {
+        client,
+        depositor,
+        deadline: env.ledger().timestamp() + 10 * DAY,
+    }
+}
+
+#[test]
+fn test_small_locks_add_up() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    for bounty_id in 1..=4u64 {
+        s.client
+            .lock_funds(&s.depositor, &bounty_id, &250, &s.deadline);
+    }
+    assert_eq!(s.client.get_period_exposure(&s.depositor), 1_000);
+    assert_eq!(
+        s.client.try_lock_funds(&s.depositor, &5, &1, &s.deadline),
+        Err(Ok(Error::PeriodLimitExceeded))
+    );
+}
+
+#[test]
+fn test_release_does_not_reset_window() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.lock_funds(&s.depositor, &1, &800, &s.deadline);
+    s.client.release_funds(&1, &Address::generate(&env));
+
+    assert_eq!(
+        s.client.try_lock_funds(&s.depositor, &2, &300, &s.deadline),
+        Err(Ok(Error::PeriodLimitExceeded))
+    );
+}
+
+#[test]
+fn test_window_restarts_after_period() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.lock_funds(&s.depositor, &1, &1_000, &s.deadline);
+
+    env.ledger().with_mut(|li| li.timestamp += DAY);
+    assert_eq!(s.client.get_period_exposure(&s.depositor), 0);
+    s.client.lock_funds(&s.depositor, &2, &1_000, &s.deadline);
+    assert_eq!(s.client.get_period_exposure(&s.depositor), 1_000);
+}
+
+#[test]
+fn test_cap_follows_tier() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client
+        .set_address_tier(&s.depositor, &IdentityTier::Verified);
+
+    s.client.lock_funds(&s.depositor, &1, &1_500, &s.deadline);
+    assert_eq!(s.client.get_period_exposure(&s.depositor), 0);
+}
+
+#[test]
+fn test_set_period_limit_validation() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    assert_eq!(
+        s.client
+            .try_set_period_limit(&IdentityTier::Basic, &0, &1_000),
+        Err(Ok(Error::InvalidLimit))
+    );
+    assert_eq!(
+        s.client
+            .try_set_period_limit(&IdentityTier::Basic, &DAY, &0),
+        Err(Ok(Error::InvalidLimit))
+    );
+
+    s.client.set_period_limit(&IdentityTier::Unverified, &0, &0);
+    assert_eq!(s.client.get_period_limit(&IdentityTier::Unverified), None);
+    s.client.lock_funds(&s.depositor, &1, &2_000, &s.deadline);
+}
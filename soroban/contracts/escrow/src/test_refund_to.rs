@@ -0,0 +1,122 @@
+#![cfg(test)]
+//! Refunding to an alternate address with a depositor recovery key.
+
+use super::*;
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{token, Address, Env};
+
+struct Setup<'a> {
+    client: EscrowContractClient<'a>,
+    token: token::Client<'a>,
+    depositor: Address,
+    deadline: u64,
+}
+
+/// Bounty 1 locked for 1_000 by `depositor`.
+fn setup<'a>(env: &'a Env) -> Setup<'a> {
+    env.mock_all_auths();
+    let contract_id = env.register(EscrowContract, ());
+    let client = EscrowContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_addr = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    client.init(&admin, &token_addr);
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_addr).mint(&depositor, &10_000);
+    let deadline = env.ledger().timestamp() + 1_000;
+    client.lock_funds(&depositor, &1, &1_000, &deadline);
+
+    Setup {
+        client,
+        token: token::Client::new(env, &token_addr),
+        depositor,
+        deadline,
+    }
+}
+
+fn register_key(env: &Env, s: &Setup, seed: u8) -> SigningKey {
+    let key = SigningKey::from_bytes(&[seed; 32]);
+    s.client.set_recovery_key(
+        &s.depositor,
+        &BytesN::from_array(env, &key.verifying_key().to_bytes()),
+    );
+    key
+}
+
+fn sign(env: &Env, key: &SigningKey, message: &Bytes) -> BytesN<64> {
+    let mut buf = [0u8; 256];
+    let len = message.len() as usize;
+    message.copy_into_slice(&mut buf[..len]);
+    BytesN::from_array(env, &key.sign(&buf[..len]).to_bytes())
+}
+
+#[test]
+fn test_refund_to_pays_alternate() {
+    let env = Env::default();
+    let s = setup(&env);
+    let key = register_key(&env, &s, 7);
+    let alternate = Address::generate(&env);
+    env.ledger().set_timestamp(s.deadline + 1);
+
+    let message = s.client.get_refund_to_message(&1, &alternate);
+    s.client
+        .refund_to(&1, &alternate, &sign(&env, &key, &message));
+
+    assert_eq!(s.token.balance(&alternate), 1_000);
+    assert_eq!(s.token.balance(&s.depositor), 9_000);
+    assert_eq!(s.client.get_escrow(&1).status, EscrowStatus::Refunded);
+    assert_eq!(s.client.get_recovery_nonce(&s.depositor), 1);
+}
+
+#[test]
+fn test_refund_to_signature_is_single_use() {
+    let env = Env::default();
+    let s = setup(&env);
+    let key = register_key(&env, &s, 7);
+    let alternate = Address::generate(&env);
+    s.client.lock_funds(&s.depositor, &2, &500, &s.deadline);
+    env.ledger().set_timestamp(s.deadline + 1);
+
+    // The nonce is per depositor, so a signature for bounty 2 taken before
+    // bounty 1 was recovered no longer verifies.
+    let stale = sign(&env, &key, &s.client.get_refund_to_message(&2, &alternate));
+    let message = s.client.get_refund_to_message(&1, &alternate);
+    s.client
+        .refund_to(&1, &alternate, &sign(&env, &key, &message));
+    assert!(s.client.try_refund_to(&2, &alternate, &stale).is_err());
+
+    let fresh = sign(&env, &key, &s.client.get_refund_to_message(&2, &alternate));
+    s.client.refund_to(&2, &alternate, &fresh);
+    assert_eq!(s.token.balance(&alternate), 1_500);
+}
+
+#[test]
+fn test_refund_to_rejects_bad_requests() {
+    let env = Env::default();
+    let s = setup(&env);
+    let alternate = Address::generate(&env);
+    let message = s.client.get_refund_to_message(&1, &alternate);
+    let unregistered = sign(&env, &SigningKey::from_bytes(&[9; 32]), &message);
+
+    assert_eq!(
+        s.client.try_refund_to(&1, &alternate, &unregistered),
+        Err(Ok(Error::RecoveryKeyNotSet))
+    );
+
+    let key = register_key(&env, &s, 7);
+    assert!(s
+        .client
+        .try_refund_to(&1, &alternate, &unregistered)
+        .is_err());
+
+    // A valid signature does not bypass the refund deadline.
+    let signature = sign(&env, &key, &message);
+    assert_eq!(
+        s.client.try_refund_to(&1, &alternate, &signature),
+        Err(Ok(Error::DeadlineNotPassed))
+    );
+    assert_eq!(s.client.get_recovery_nonce(&s.depositor), 0);
+}
@@ -0,0 +1,194 @@
+#![cfg(test)]
+//! Splitting a locked escrow into child escrows and merging escrows back.
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{token, vec, Address, Env, IntoVal, Val};
+
+struct Setup<'a> {
+    client: EscrowContractClient<'a>,
+    token: token::Client<'a>,
+    depositor: Address,
+    deadline: u64,
+}
+
+/// Bounty 1 locked for 1_000 by `depositor`.
+fn setup<'a>(env: &'a Env) -> Setup<'a> {
+    env.mock_all_auths();
+    let contract_id = env.register(EscrowContract, ());
+    let client = EscrowContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_addr = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    client.init(&admin, &token_addr);
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_addr).mint(&depositor, &10_000);
+    let deadline = env.ledger().timestamp() + 1_000;
+    client.lock_funds(&depositor, &1, &1_000, &deadline);
+
+    Setup {
+        client,
+        token: token::Client::new(env, &token_addr),
+        depositor,
+        deadline,
+    }
+}
+
+#[test]
+fn test_split_carves_child_escrow() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.set_escrow_pause_flags(&1, &ESCROW_PAUSE_REFUND);
+
+    s.client.split_escrow(&1, &2, &300);
+
+    let split_topic: Val = symbol_short!("f_split").into_val(&env);
+    let (_, topics, _) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics.get(0).unwrap().get_payload(),
+        split_topic.get_payload()
+    );
+
+    let parent = s.client.get_escrow(&1);
+    assert_eq!(parent.amount, 700);
+    assert_eq!(parent.remaining_amount, 700);
+    let child = s.client.get_escrow(&2);
+    assert_eq!(child.depositor, s.depositor);
+    assert_eq!(child.amount, 300);
+    assert_eq!(child.status, EscrowStatus::Locked);
+    assert_eq!(child.deadline, s.deadline);
+    assert_eq!(s.client.get_escrow_pause_flags(&2), ESCROW_PAUSE_REFUND);
+    assert_eq!(s.token.balance(&s.client.address), 1_000);
+
+    let contributor = Address::generate(&env);
+    s.client.release_funds(&2, &contributor);
+    assert_eq!(s.token.balance(&contributor), 300);
+    assert_eq!(s.client.get_escrow(&1).remaining_amount, 700);
+}
+
+#[test]
+fn test_split_keeps_payout_split_table() {
+    let env = Env::default();
+    let s = setup(&env);
+    let reviewer = Address::generate(&env);
+    let shares = vec![
+        &env,
+        PayoutShare {
+            recipient: SplitRecipient::Contributor,
+            bps: 9_000,
+        },
+        PayoutShare {
+            recipient: SplitRecipient::Fixed(reviewer.clone()),
+            bps: 1_000,
+        },
+    ];
+    s.client
+        .lock_funds_with_split(&s.depositor, &3, &1_000, &s.deadline, &shares);
+
+    s.client.split_escrow(&3, &4, &500);
+    assert_eq!(s.client.get_payout_split(&4), Some(shares));
+}
+
+#[test]
+fn test_split_rejects_invalid_requests() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    assert_eq!(
+        s.client.try_split_escrow(&1, &2, &0),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        s.client.try_split_escrow(&1, &2, &1_000),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        s.client.try_split_escrow(&1, &1, &100),
+        Err(Ok(Error::BountyExists))
+    );
+    assert_eq!(
+        s.client.try_split_escrow(&9, &2, &100),
+        Err(Ok(Error::BountyNotFound))
+    );
+
+    s.client.release_funds(&1, &Address::generate(&env));
+    assert_eq!(
+        s.client.try_split_escrow(&1, &2, &100),
+        Err(Ok(Error::FundsNotLocked))
+    );
+}
+
+#[test]
+fn test_merge_sums_amounts_and_takes_later_deadline() {
+    let env = Env::default();
+    let s = setup(&env);
+    let later = s.deadline + 500;
+    s.client.lock_funds(&s.depositor, &2, &400, &later);
+
+    s.client.merge_escrows(&1, &2);
+
+    let merge_topic: Val = symbol_short!("f_merge").into_val(&env);
+    let (_, topics, _) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics.get(0).unwrap().get_payload(),
+        merge_topic.get_payload()
+    );
+
+    let target = s.client.get_escrow(&1);
+    assert_eq!(target.amount, 1_400);
+    assert_eq!(target.remaining_amount, 1_400);
+    assert_eq!(target.deadline, later);
+    let source = s.client.get_escrow(&2);
+    assert_eq!(source.remaining_amount, 0);
+    assert_eq!(source.status, EscrowStatus::Released);
+
+    let contributor = Address::generate(&env);
+    s.client.release_funds(&1, &contributor);
+    assert_eq!(s.token.balance(&contributor), 1_400);
+    assert_eq!(s.token.balance(&s.client.address), 0);
+}
+
+#[test]
+fn test_merge_undoes_a_split() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.split_escrow(&1, &2, &250);
+    s.client.merge_escrows(&1, &2);
+    assert_eq!(s.client.get_escrow(&1).remaining_amount, 1_000);
+}
+
+#[test]
+fn test_merge_rejects_incompatible_escrows() {
+    let env = Env::default();
+    let s = setup(&env);
+    let other = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &s.token.address).mint(&other, &1_000);
+    s.client.lock_funds(&other, &2, &100, &s.deadline);
+    s.client.lock_funds(&s.depositor, &3, &100, &s.deadline);
+
+    assert_eq!(
+        s.client.try_merge_escrows(&1, &1),
+        Err(Ok(Error::IncompatibleEscrows))
+    );
+    assert_eq!(
+        s.client.try_merge_escrows(&1, &2),
+        Err(Ok(Error::IncompatibleEscrows))
+    );
+
+    // Differing pause flags make the jurisdiction configs differ.
+    s.client.set_escrow_pause_flags(&3, &ESCROW_PAUSE_REFUND);
+    assert_eq!(
+        s.client.try_merge_escrows(&1, &3),
+        Err(Ok(Error::IncompatibleEscrows))
+    );
+
+    s.client.set_escrow_pause_flags(&3, &0);
+    s.client.release_funds(&3, &Address::generate(&env));
+    assert_eq!(
+        s.client.try_merge_escrows(&1, &3),
+        Err(Ok(Error::FundsNotLocked))
+    );
+}
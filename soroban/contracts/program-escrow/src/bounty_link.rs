@@ -0,0 +1,14 @@
+//! Cross-contract view of a bounty escrow (`contracts/bounty_escrow` or
+//! `soroban/contracts/escrow`); both expose the same `lock_funds` signature.
+//!
+//! `fund_bounty_from_program` locks program funds into a bounty with this
+//! contract as the depositor. The escrow pulls the tokens with
+//! `token.transfer(depositor, escrow, amount)`, so the caller pre-authorizes
+//! that transfer with `authorize_as_current_contract` before the call.
+
+use soroban_sdk::{contractclient, Address, Env};
+
+#[contractclient(name = "BountyEscrowClient")]
+pub trait BountyEscrow {
+    fn lock_funds(env: Env, depositor: Address, bounty_id: u64, amount: i128, deadline: u64);
+}
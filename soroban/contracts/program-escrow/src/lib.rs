@@ -16,14 +16,69 @@
 //! - query work is bounded by the stored index and capped page size
 //! - cursor pagination keeps results reviewable and avoids hidden full scans
 
+use soroban_sdk::auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation};
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, token, Address, Env,
-    String, Vec,
+    contract, contracterror, contractimpl, contracttype, symbol_short, token, vec, Address, BytesN,
+    Env, IntoVal, String, Symbol, Vec,
+};
+
+pub mod bounty_link;
+pub mod payout_compat;
+
+use bounty_link::BountyEscrowClient;
+
+use payout_compat::{
+    PayoutContractClient, PayoutProgramData, PayoutReleaseSchedule, PayoutReputationScore,
+    PayoutThresholdConfig,
 };
 
 const MAX_BATCH_SIZE: u32 = 20;
 const MAX_PAGE_SIZE: u32 = 20;
 const PROGRAM_REGISTERED: soroban_sdk::Symbol = symbol_short!("prg_reg");
+const DEPRECATION_CHANGED: soroban_sdk::Symbol = symbol_short!("deprec");
+const FEE_COLLECTED: soroban_sdk::Symbol = symbol_short!("fee");
+const TREASURY_WITHDRAWAL_REQUESTED: soroban_sdk::Symbol = symbol_short!("trs_req");
+const TREASURY_WITHDRAWN: soroban_sdk::Symbol = symbol_short!("trs_wd");
+const CHILD_PROGRAM_REGISTERED: soroban_sdk::Symbol = symbol_short!("prg_child");
+const TEMPLATE_CREATED: soroban_sdk::Symbol = symbol_short!("tmpl_new");
+const FUNDING_ROUND_OPENED: soroban_sdk::Symbol = symbol_short!("rnd_open");
+const PLEDGED: soroban_sdk::Symbol = symbol_short!("pledge");
+const FUNDING_ROUND_FINALIZED: soroban_sdk::Symbol = symbol_short!("rnd_fin");
+const PLEDGE_REFUNDED: soroban_sdk::Symbol = symbol_short!("pld_rfnd");
+const DONATION_RECEIVED: soroban_sdk::Symbol = symbol_short!("donation");
+const RECEIPT_ISSUED: soroban_sdk::Symbol = symbol_short!("rcpt_new");
+const RECEIPT_TRANSFERRED: soroban_sdk::Symbol = symbol_short!("rcpt_xfer");
+const PAYOUT_CONTRACT_LINKED: soroban_sdk::Symbol = symbol_short!("prg_link");
+const BOUNTY_ESCROW_REGISTERED: soroban_sdk::Symbol = symbol_short!("bnty_reg");
+const BOUNTY_FUNDED: soroban_sdk::Symbol = symbol_short!("prg_bnty");
+const BOUNTY_SETTLED: soroban_sdk::Symbol = symbol_short!("bnty_set");
+const BUDGET_CATEGORY_CREATED: soroban_sdk::Symbol = symbol_short!("bdgt_new");
+const BENEFICIARY_ENROLLED: soroban_sdk::Symbol = symbol_short!("ben_add");
+const BENEFICIARY_REMOVED: soroban_sdk::Symbol = symbol_short!("ben_rm");
+const BENEFICIARIES_ONLY_CHANGED: soroban_sdk::Symbol = symbol_short!("ben_only");
+const COOP_MEMBERS_SET: soroban_sdk::Symbol = symbol_short!("coop_set");
+const COOP_MEMBER_PAID: soroban_sdk::Symbol = symbol_short!("coop_pay");
+const PAYOUT_WINDOWS_SET: soroban_sdk::Symbol = symbol_short!("pay_win");
+const INACTIVITY_POLICY_SET: soroban_sdk::Symbol = symbol_short!("inact_set");
+const ADMIN_HEARTBEAT: soroban_sdk::Symbol = symbol_short!("heartbeat");
+const ADMIN_CLAIMED: soroban_sdk::Symbol = symbol_short!("adm_claim");
+
+/// Denominator for fee rates expressed in basis points (1 bp = 0.01%).
+pub const BASIS_POINTS: i128 = 10_000;
+/// Upper bound for any configured fee rate (10%).
+pub const MAX_FEE_RATE: i128 = 1_000;
+/// Delay between requesting and executing a treasury withdrawal (48 hours).
+pub const TREASURY_TIMELOCK_SECS: u64 = 172_800;
+
+/// Schema version carried in the `version` field of every event payload.
+///
+/// Bump this whenever a payload struct gains, loses, or reorders fields so
+/// downstream decoders can branch on `version` before reading the rest.
+///
+/// - `2`: payloads start with `version`; `deprec` was still a raw tuple.
+/// - `3`: `deprec` is a struct, and struct payloads carry a contract-wide
+///   `sequence` right after `version` so indexers can order and dedupe.
+pub const EVENT_SCHEMA_VERSION: u32 = 3;
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -42,6 +97,39 @@ pub enum Error {
     JurisdictionKycRequired = 11,
     JurisdictionFundingLimitExceeded = 12,
     JurisdictionPaused = 13,
+    InvalidFeeRate = 14,
+    InvalidFeeTiers = 15,
+    InsufficientTreasuryBalance = 16,
+    NoPendingWithdrawal = 17,
+    TimelockNotElapsed = 18,
+    InsufficientParentAllocation = 19,
+    TemplateNotFound = 20,
+    InvalidSchedulePattern = 21,
+    FundingRoundNotFound = 22,
+    InvalidFundingDeadline = 23,
+    FundingRoundClosed = 24,
+    FundingGoalNotMet = 25,
+    FundingRoundActive = 26,
+    NothingToRefund = 27,
+    ProgramNotActive = 28,
+    DonationLimitExceeded = 29,
+    PayoutContractNotLinked = 30,
+    BountyEscrowNotRegistered = 31,
+    InsufficientProgramFunds = 32,
+    BountyNotLinked = 33,
+    BudgetCategoryExists = 34,
+    BudgetCategoryNotFound = 35,
+    BudgetExceeded = 36,
+    BeneficiaryNotEnrolled = 37,
+    InvalidCoopMembers = 38,
+    OutsidePayoutWindow = 39,
+    InvalidPayoutWindow = 40,
+    InvalidInactivityPolicy = 41,
+    InactivityPolicyNotSet = 42,
+    AdminStillActive = 43,
+    InvalidReportRange = 44,
+    SchedulePlanNotFound = 45,
+    ReceiptNotFound = 46,
 }
 
 #[contracttype]
@@ -113,6 +201,7 @@ pub struct ProgramRegistrationWithJurisdictionItem {
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ProgramRegisteredEvent {
     pub version: u32,
+    pub sequence: u64,
     pub program_id: u64,
     pub admin: Address,
     pub total_funding: i128,
@@ -124,550 +213,3257 @@ pub struct ProgramRegisteredEvent {
 }
 
 #[contracttype]
-pub enum DataKey {
-    Admin,
-    Token,
-    Program(u64),
-    /// Jurisdiction config is stored separately from the main program record.
-    ProgramJurisdiction(u64),
-    /// Stable index used by `get_programs` and `get_program_count`.
-    ProgramIndex,
-    DeprecationState,
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DeprecationChangedEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub deprecated: bool,
+    pub migration_target: Option<Address>,
+    pub admin: Address,
+    pub timestamp: u64,
 }
 
-/// Filter inputs for cursor-based program search.
+/// Operation a fee was charged on.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FeeOperationType {
+    Registration,
+    Payout,
+}
+
+/// Optional protocol fee configuration.
 ///
-/// `status_filter` values:
-/// - `0`: any status
-/// - `1`: active
-/// - `2`: completed
-/// - `3`: cancelled
+/// Rates are in basis points and capped at `MAX_FEE_RATE`. Each fee type has
+/// its own toggle so registration and payout fees can be enabled independently.
+/// Setting `fee_recipient` to the contract's own address keeps collected fees
+/// in the internal treasury instead of forwarding them. The payout fee is
+/// taken from the program's funds on each forwarded payout or schedule.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct ProgramSearchCriteria {
-    pub status_filter: u32,
-    pub admin: Option<Address>,
+pub struct FeeConfig {
+    pub registration_fee_rate: i128,
+    pub payout_fee_rate: i128,
+    pub fee_recipient: Address,
+    pub registration_fee_enabled: bool,
+    pub payout_fee_enabled: bool,
 }
 
-/// One flattened program entry returned from a search page.
+/// A treasury withdrawal waiting out its timelock.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct ProgramRecord {
-    pub program_id: u64,
+pub struct PendingTreasuryWithdrawal {
+    pub to: Address,
+    pub amount: i128,
+    pub executable_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TreasuryWithdrawalEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub to: Address,
+    pub amount: i128,
+    pub executable_at: u64,
     pub admin: Address,
-    pub name: String,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChildProgramRegisteredEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub parent_id: u64,
+    pub child_id: u64,
+    pub allocation: i128,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+/// One program in a flattened hierarchy returned by `get_program_tree`.
+///
+/// Nodes are listed depth-first starting at the requested root, so a node's
+/// descendants always follow it directly.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProgramTreeNode {
+    pub program_id: u64,
+    pub parent_id: Option<u64>,
+    pub depth: u32,
     pub total_funding: i128,
-    pub status: ProgramStatus,
+    /// Portion of `total_funding` carved out for direct children.
+    pub allocated_to_children: i128,
 }
 
-/// One page of program search results.
+/// One release step of a template's default schedule pattern.
 ///
-/// When `has_more` is true, pass `next_cursor` back into `get_programs` to
-/// continue scanning from the next indexed program.
+/// `offset_secs` is measured from registration; `share_bps` is the portion of
+/// the program's funding released at that step.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct ProgramPage {
-    pub records: Vec<ProgramRecord>,
-    pub next_cursor: Option<u64>,
-    pub has_more: bool,
+pub struct ScheduleStep {
+    pub offset_secs: u64,
+    pub share_bps: u32,
 }
 
-#[contract]
-pub struct ProgramEscrowContract;
+/// Reusable registration defaults for programs that repeat across regions or
+/// seasons.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProgramTemplate {
+    pub template_id: u64,
+    pub name: String,
+    pub default_jurisdiction: OptionalJurisdiction,
+    /// Empty when the template carries no schedule; otherwise shares sum to
+    /// 10_000 bps and offsets are strictly ascending.
+    pub default_schedule_pattern: Vec<ScheduleStep>,
+}
 
-#[contractimpl]
-impl ProgramEscrowContract {
-    fn validate_program_input(name: &String, total_funding: i128) -> Result<(), Error> {
-        if total_funding <= 0 {
-            return Err(Error::InvalidAmount);
-        }
-        if name.len() == 0 {
-            return Err(Error::InvalidName);
-        }
-        Ok(())
-    }
+/// A template step resolved against a registered program: the absolute
+/// release time and the token amount of that step.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlannedRelease {
+    pub release_timestamp: u64,
+    pub amount: i128,
+}
 
-    fn build_jurisdiction(
-        juris_tag: Option<String>,
-        juris_requires_kyc: bool,
-        juris_max_funding: Option<i128>,
-        juris_registration_paused: bool,
-        jurisdiction: OptionalJurisdiction,
-    ) -> OptionalJurisdiction {
-        match jurisdiction {
-            OptionalJurisdiction::Some(config) => OptionalJurisdiction::Some(config),
-            OptionalJurisdiction::None => {
-                let has_juris = juris_tag.is_some()
-                    || juris_requires_kyc
-                    || juris_max_funding.is_some()
-                    || juris_registration_paused;
-                if has_juris {
-                    OptionalJurisdiction::Some(ProgramJurisdictionConfig {
-                        tag: juris_tag,
-                        requires_kyc: juris_requires_kyc,
-                        max_funding: juris_max_funding,
-                        registration_paused: juris_registration_paused,
-                    })
-                } else {
-                    OptionalJurisdiction::None
-                }
-            }
-        }
-    }
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TemplateCreatedEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub template_id: u64,
+    pub name: String,
+    pub admin: Address,
+    pub timestamp: u64,
+}
 
-    fn enforce_jurisdiction_rules(
-        jurisdiction: &OptionalJurisdiction,
-        total_funding: i128,
-        kyc_attested: Option<bool>,
-    ) -> Result<(), Error> {
-        if let OptionalJurisdiction::Some(config) = jurisdiction {
-            if config.registration_paused {
-                return Err(Error::JurisdictionPaused);
-            }
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FundingRoundStatus {
+    /// Accepting pledges.
+    Open,
+    /// Goal met; the program has been registered with the pledged funds.
+    Finalized,
+    /// Deadline passed under goal; at least one pledger has reclaimed.
+    Refunding,
+}
 
-            if let Some(max_funding) = config.max_funding {
-                if total_funding > max_funding {
-                    return Err(Error::JurisdictionFundingLimitExceeded);
-                }
-            }
+/// Pledge phase for a program that is registered only if the community
+/// raises `goal` before `deadline`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FundingRound {
+    pub program_id: u64,
+    pub admin: Address,
+    pub name: String,
+    pub goal: i128,
+    pub deadline: u64,
+    pub pledged: i128,
+    pub refunded: i128,
+    pub status: FundingRoundStatus,
+}
 
-            if config.requires_kyc && !kyc_attested.unwrap_or(false) {
-                return Err(Error::JurisdictionKycRequired);
-            }
-        }
-        Ok(())
-    }
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FundingRoundOpenedEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub program_id: u64,
+    pub admin: Address,
+    pub name: String,
+    pub goal: i128,
+    pub deadline: u64,
+}
 
-    fn emit_program_registered(
-        env: &Env,
-        program_id: u64,
-        admin: Address,
-        total_funding: i128,
-        jurisdiction: &OptionalJurisdiction,
-    ) {
-        let (jurisdiction_tag, requires_kyc, max_funding, registration_paused) =
-            if let OptionalJurisdiction::Some(config) = jurisdiction {
-                (
-                    config.tag.clone(),
-                    config.requires_kyc,
-                    config.max_funding,
-                    config.registration_paused,
-                )
-            } else {
-                (None, false, None, false)
-            };
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FundingRoundFinalizedEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub program_id: u64,
+    pub pledged: i128,
+    pub goal: i128,
+    /// Pledged amount net of the registration fee.
+    pub total_funding: i128,
+    pub timestamp: u64,
+}
 
-        env.events().publish(
-            (PROGRAM_REGISTERED, program_id),
-            ProgramRegisteredEvent {
-                version: 2,
-                program_id,
-                admin,
-                total_funding,
-                jurisdiction_tag,
-                requires_kyc,
-                max_funding,
-                registration_paused,
-                timestamp: env.ledger().timestamp(),
-            },
-        );
-    }
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PledgeEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub program_id: u64,
+    pub contributor: Address,
+    pub amount: i128,
+    pub total_pledged: i128,
+    pub timestamp: u64,
+}
 
-    fn order_batch_registration_items(
-        env: &Env,
-        items: &Vec<ProgramRegistrationItem>,
-    ) -> Vec<ProgramRegistrationItem> {
-        let mut ordered: Vec<ProgramRegistrationItem> = Vec::new(env);
-        for item in items.iter() {
-            let mut next: Vec<ProgramRegistrationItem> = Vec::new(env);
-            let mut inserted = false;
-            for existing in ordered.iter() {
-                if !inserted && item.program_id < existing.program_id {
-                    next.push_back(item.clone());
-                    inserted = true;
-                }
-                next.push_back(existing);
-            }
-            if !inserted {
-                next.push_back(item.clone());
-            }
-            ordered = next;
-        }
-        ordered
-    }
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DonationEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub program_id: u64,
+    pub donor: Address,
+    pub amount: i128,
+    /// Cumulative amount `donor` has given to this program.
+    pub donor_total: i128,
+    pub program_total_funding: i128,
+    pub timestamp: u64,
+}
 
-    fn ensure_initialized(env: &Env) -> Result<(), Error> {
-        if env.storage().instance().has(&DataKey::Admin) {
-            Ok(())
-        } else {
-            Err(Error::NotInitialized)
-        }
-    }
+/// Proof of a donation. The depositor, amount and timestamp never change;
+/// the current `holder` can hand the receipt on, e.g. to an auditor.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DonationReceipt {
+    pub receipt_id: u64,
+    pub program_id: u64,
+    pub depositor: Address,
+    pub holder: Address,
+    pub amount: i128,
+    pub issued_at: u64,
+}
 
-    fn ensure_not_deprecated(env: &Env) -> Result<(), Error> {
-        if Self::get_deprecation_state(env).deprecated {
-            Err(Error::ContractDeprecated)
-        } else {
-            Ok(())
-        }
-    }
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReceiptIssuedEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub receipt_id: u64,
+    pub program_id: u64,
+    pub depositor: Address,
+    pub amount: i128,
+}
 
-    fn require_contract_admin(env: &Env) -> Address {
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
-        admin
-    }
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReceiptTransferredEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub receipt_id: u64,
+    pub from: Address,
+    pub to: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutContractLinkedEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub program_id: u64,
+    pub payout_contract: Address,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BountyEscrowRegisteredEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub bounty_escrow: Address,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+/// A bounty funded out of a program's pool, as listed by
+/// `list_program_bounties`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProgramBounty {
+    pub bounty_escrow: Address,
+    pub bounty_id: u64,
+    pub amount: i128,
+    pub deadline: u64,
+    pub funded_at: u64,
+    /// Reported by the escrow through `on_bounty_settled`.
+    pub released: i128,
+    /// Refunds credited back to the program's `total_funding`.
+    pub refunded: i128,
+}
+
+/// A named budget envelope inside a program. Payouts and schedules sent
+/// through the `program_*` forwarders draw `spent` against `allocation`.
+/// Calls made on the payout contract directly bypass the envelope.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BudgetCategory {
+    pub name: String,
+    pub allocation: i128,
+    pub spent: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BudgetCategoryCreatedEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub program_id: u64,
+    pub name: String,
+    pub allocation: i128,
+    pub timestamp: u64,
+}
+
+/// One row of `get_budget_report`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BudgetUtilization {
+    pub name: String,
+    pub allocation: i128,
+    pub spent: i128,
+    pub remaining: i128,
+    /// `spent / allocation` in basis points.
+    pub utilization_bps: i128,
+}
+
+/// Enrollment record for a program beneficiary. `metadata_hash` commits to
+/// off-chain registration data (e.g. a farmer registry entry).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Beneficiary {
+    pub metadata_hash: BytesN<32>,
+    pub enrolled_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BeneficiaryEnrolledEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub program_id: u64,
+    pub beneficiary: Address,
+    pub metadata_hash: BytesN<32>,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BeneficiaryRemovedEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub program_id: u64,
+    pub beneficiary: Address,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+/// Member of a cooperative beneficiary and its share weight.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CoopMember {
+    pub member: Address,
+    pub weight: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CoopMembersSetEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub coop: Address,
+    pub members: Vec<Address>,
+    pub weights: Vec<u32>,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CoopMemberPaidEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub program_id: u64,
+    pub coop: Address,
+    pub member: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+/// Ledger-timestamp range `[start, end)` during which a program may pay out.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutWindow {
+    pub start: u64,
+    pub end: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutWindowsSetEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub program_id: u64,
+    pub windows: Vec<PayoutWindow>,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+/// Dead man's switch for a program admin: if no heartbeat arrives for
+/// `timeout_secs`, `fallback_admin` may take over the program.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InactivityPolicy {
+    pub timeout_secs: u64,
+    pub fallback_admin: Address,
+    pub last_heartbeat: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InactivityEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub program_id: u64,
+    /// Program admin after the step.
+    pub admin: Address,
+    pub policy: InactivityPolicy,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FlowKind {
+    Inflow,
+    Outflow,
+}
+
+/// One fund movement of a program, with the jurisdiction state it happened
+/// under. Appended to the program's compliance log.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ComplianceEntry {
+    pub kind: FlowKind,
+    pub amount: i128,
+    pub timestamp: u64,
+    pub jurisdiction_tag: Option<String>,
+    pub kyc_gated: bool,
+    /// Set when the program's funding ended above its jurisdiction's
+    /// `max_funding`.
+    pub breach: bool,
+}
+
+/// Aggregate of one page of a program's compliance log over
+/// `[from_ts, to_ts]`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ComplianceReport {
+    pub program_id: u64,
+    pub from_ts: u64,
+    pub to_ts: u64,
+    pub total_inflows: i128,
+    pub total_outflows: i128,
+    pub inflow_count: u32,
+    pub outflow_count: u32,
+    pub kyc_gated_operations: u32,
+    /// Distinct jurisdiction tags in effect during the period.
+    pub jurisdiction_tags: Vec<String>,
+    pub breaches: u32,
+    /// Program funding when the report was generated.
+    pub current_funding: i128,
+    pub generated_at: u64,
+    /// Log index to pass as `cursor` for the next page; `None` once the
+    /// window is covered.
+    pub next_cursor: Option<u32>,
+}
+
+/// How a funded bounty paid out, as reported by its escrow.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BountyOutcome {
+    Released,
+    Refunded,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BountySettledEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub program_id: u64,
+    pub bounty_escrow: Address,
+    pub bounty_id: u64,
+    pub outcome: BountyOutcome,
+    pub amount: i128,
+    pub program_total_funding: i128,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProgramBountyFundedEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub program_id: u64,
+    pub bounty_escrow: Address,
+    pub bounty_id: u64,
+    pub amount: i128,
+    pub deadline: u64,
+    /// Program funding left after the transfer.
+    pub program_total_funding: i128,
+    pub timestamp: u64,
+}
+
+/// One row of the tiered fee table.
+///
+/// A tier applies to program sizes strictly below its `threshold`. Sizes at or
+/// above every threshold fall into the last tier, so the final row acts as the
+/// open-ended top bracket.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeTier {
+    pub threshold: i128,
+    pub fee_rate: i128,
+}
+
+/// Mirrors the bounty escrow's `FeeCollected` payload, plus the schema
+/// `version` and the `program_id` the fee was charged against.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeCollectedEvent {
+    pub version: u32,
+    pub sequence: u64,
+    pub operation_type: FeeOperationType,
+    pub program_id: u64,
+    pub amount: i128,
+    pub fee_rate: i128,
+    pub recipient: Address,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Token,
+    Program(u64),
+    /// Jurisdiction config is stored separately from the main program record.
+    ProgramJurisdiction(u64),
+    /// Stable index used by `get_programs` and `get_program_count`.
+    ProgramIndex,
+    DeprecationState,
+    /// Last `sequence` stamped on an event payload.
+    EventSequence,
+    FeeConfig,
+    /// Ascending tiered fee table; overrides the flat rates when non-empty.
+    FeeTiers,
+    /// Fees retained by the contract and not yet withdrawn.
+    TreasuryBalance,
+    PendingTreasuryWithdrawal,
+    /// Direct children of a program, in registration order.
+    ProgramChildren(u64),
+    ProgramParent(u64),
+    /// Sum of allocations carved out of a program for its children.
+    ChildAllocated(u64),
+    ProgramTemplate(u64),
+    NextTemplateId,
+    /// Template a program was registered from, if any.
+    ProgramTemplateId(u64),
+    /// Releases materialized from a template's schedule pattern, not yet
+    /// forwarded to the payout contract.
+    SchedulePlan(u64),
+    FundingRound(u64),
+    /// Outstanding pledge per (program_id, contributor).
+    Pledge(u64, Address),
+    /// Cumulative donations per (program_id, donor).
+    DonorTotal(u64, Address),
+    /// Upper bound on a single `donate` call; absent leaves donations
+    /// uncapped. Kept apart from the stored jurisdiction config so programs
+    /// registered before donations existed still decode.
+    MaxDonation(u64),
+    /// Last id handed out by `donate` for a receipt.
+    NextReceiptId,
+    DonationReceipt(u64),
+    /// Payout-side contract (`contracts/program-escrow`) serving a program.
+    PayoutContract(u64),
+    /// Bounty escrows programs may fund; set by the contract admin.
+    BountyEscrow(Address),
+    /// Bounties funded from a program, in funding order.
+    ProgramBounties(u64),
+    /// Program that funded (bounty_escrow, bounty_id).
+    BountyProgram(Address, u64),
+    /// Budget envelopes of a program, in creation order.
+    BudgetCategories(u64),
+    Beneficiary(u64, Address),
+    /// Set when a program may only pay enrolled beneficiaries.
+    BeneficiariesOnly(u64),
+    /// Members a cooperative's payouts are split across.
+    CoopMembers(Address),
+    /// Windows a program's payouts are confined to; absent means always open.
+    PayoutWindows(u64),
+    /// Dead man's switch guarding a program's admin.
+    InactivityPolicy(u64),
+    /// Number of fund movements logged for a program.
+    ComplianceLogLen(u64),
+    /// One fund movement per (program_id, index), oldest at index 0.
+    ComplianceEntry(u64, u32),
+}
+
+/// Filter inputs for cursor-based program search.
+///
+/// `status_filter` values:
+/// - `0`: any status
+/// - `1`: active
+/// - `2`: completed
+/// - `3`: cancelled
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProgramSearchCriteria {
+    pub status_filter: u32,
+    pub admin: Option<Address>,
+}
+
+/// One flattened program entry returned from a search page.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProgramRecord {
+    pub program_id: u64,
+    pub admin: Address,
+    pub name: String,
+    pub total_funding: i128,
+    pub status: ProgramStatus,
+}
+
+/// One page of program search results.
+///
+/// When `has_more` is true, pass `next_cursor` back into `get_programs` to
+/// continue scanning from the next indexed program.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProgramPage {
+    pub records: Vec<ProgramRecord>,
+    pub next_cursor: Option<u64>,
+    pub has_more: bool,
+}
+
+#[contract]
+pub struct ProgramEscrowContract;
+
+#[contractimpl]
+impl ProgramEscrowContract {
+    fn validate_program_input(name: &String, total_funding: i128) -> Result<(), Error> {
+        if total_funding <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if name.len() == 0 {
+            return Err(Error::InvalidName);
+        }
+        Ok(())
+    }
+
+    fn build_jurisdiction(
+        juris_tag: Option<String>,
+        juris_requires_kyc: bool,
+        juris_max_funding: Option<i128>,
+        juris_registration_paused: bool,
+        jurisdiction: OptionalJurisdiction,
+    ) -> OptionalJurisdiction {
+        match jurisdiction {
+            OptionalJurisdiction::Some(config) => OptionalJurisdiction::Some(config),
+            OptionalJurisdiction::None => {
+                let has_juris = juris_tag.is_some()
+                    || juris_requires_kyc
+                    || juris_max_funding.is_some()
+                    || juris_registration_paused;
+                if has_juris {
+                    OptionalJurisdiction::Some(ProgramJurisdictionConfig {
+                        tag: juris_tag,
+                        requires_kyc: juris_requires_kyc,
+                        max_funding: juris_max_funding,
+                        registration_paused: juris_registration_paused,
+                    })
+                } else {
+                    OptionalJurisdiction::None
+                }
+            }
+        }
+    }
+
+    fn enforce_jurisdiction_rules(
+        jurisdiction: &OptionalJurisdiction,
+        total_funding: i128,
+        kyc_attested: Option<bool>,
+    ) -> Result<(), Error> {
+        if let OptionalJurisdiction::Some(config) = jurisdiction {
+            if config.registration_paused {
+                return Err(Error::JurisdictionPaused);
+            }
+
+            if let Some(max_funding) = config.max_funding {
+                if total_funding > max_funding {
+                    return Err(Error::JurisdictionFundingLimitExceeded);
+                }
+            }
+
+            if config.requires_kyc && !kyc_attested.unwrap_or(false) {
+                return Err(Error::JurisdictionKycRequired);
+            }
+        }
+        Ok(())
+    }
+
+    fn emit_program_registered(
+        env: &Env,
+        program_id: u64,
+        admin: Address,
+        total_funding: i128,
+        jurisdiction: &OptionalJurisdiction,
+    ) {
+        let (jurisdiction_tag, requires_kyc, max_funding, registration_paused) =
+            if let OptionalJurisdiction::Some(config) = jurisdiction {
+                (
+                    config.tag.clone(),
+                    config.requires_kyc,
+                    config.max_funding,
+                    config.registration_paused,
+                )
+            } else {
+                (None, false, None, false)
+            };
+
+        env.events().publish(
+            (PROGRAM_REGISTERED, program_id),
+            ProgramRegisteredEvent {
+                version: EVENT_SCHEMA_VERSION,
+                sequence: Self::next_event_sequence(env),
+                program_id,
+                admin,
+                total_funding,
+                jurisdiction_tag,
+                requires_kyc,
+                max_funding,
+                registration_paused,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+    }
+
+    fn order_batch_registration_items(
+        env: &Env,
+        items: &Vec<ProgramRegistrationItem>,
+    ) -> Vec<ProgramRegistrationItem> {
+        let mut ordered: Vec<ProgramRegistrationItem> = Vec::new(env);
+        for item in items.iter() {
+            let mut next: Vec<ProgramRegistrationItem> = Vec::new(env);
+            let mut inserted = false;
+            for existing in ordered.iter() {
+                if !inserted && item.program_id < existing.program_id {
+                    next.push_back(item.clone());
+                    inserted = true;
+                }
+                next.push_back(existing);
+            }
+            if !inserted {
+                next.push_back(item.clone());
+            }
+            ordered = next;
+        }
+        ordered
+    }
+
+    fn ensure_initialized(env: &Env) -> Result<(), Error> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            Ok(())
+        } else {
+            Err(Error::NotInitialized)
+        }
+    }
+
+    fn ensure_not_deprecated(env: &Env) -> Result<(), Error> {
+        if Self::get_deprecation_state(env).deprecated {
+            Err(Error::ContractDeprecated)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn require_contract_admin(env: &Env) -> Address {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        admin
+    }
+
+    /// A program id is taken once a program or a funding round uses it, so
+    /// neither can later overwrite the other.
+    fn ensure_program_id_free(env: &Env, program_id: u64) -> Result<(), Error> {
+        let storage = env.storage().persistent();
+        if storage.has(&DataKey::Program(program_id))
+            || storage.has(&DataKey::FundingRound(program_id))
+        {
+            return Err(Error::ProgramExists);
+        }
+        Ok(())
+    }
+
+    fn next_event_sequence(env: &Env) -> u64 {
+        let sequence: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::EventSequence)
+            .unwrap_or(0)
+            + 1;
+        env.storage()
+            .instance()
+            .set(&DataKey::EventSequence, &sequence);
+        sequence
+    }
+
+    fn append_program_id(env: &Env, program_id: u64) {
+        let mut index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ProgramIndex)
+            .unwrap_or_else(|| Vec::new(env));
+        index.push_back(program_id);
+        env.storage().persistent().set(&DataKey::ProgramIndex, &index);
+    }
+
+    fn store_program(env: &Env, program_id: u64, program: &Program) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Program(program_id), program);
+
+        match &program.jurisdiction {
+            OptionalJurisdiction::Some(config) => env
+                .storage()
+                .persistent()
+                .set(&DataKey::ProgramJurisdiction(program_id), config),
+            OptionalJurisdiction::None => {
+                env.storage()
+                    .persistent()
+                    .remove(&DataKey::ProgramJurisdiction(program_id));
+            }
+        }
+    }
+
+    fn get_deprecation_state(env: &Env) -> DeprecationState {
+        env.storage()
+            .instance()
+            .get(&DataKey::DeprecationState)
+            .unwrap_or(DeprecationState {
+                deprecated: false,
+                migration_target: None,
+            })
+    }
+
+    /// Fee owed on `amount` at `fee_rate` basis points, rounded down.
+    fn calculate_fee(amount: i128, fee_rate: i128) -> i128 {
+        amount
+            .checked_mul(fee_rate)
+            .and_then(|x| x.checked_div(BASIS_POINTS))
+            .unwrap_or(0)
+    }
+
+    /// Effective rate for a program of `program_size`: the matching tier when a
+    /// tier table is configured, otherwise the flat `base_rate`.
+    fn effective_fee_rate(env: &Env, base_rate: i128, program_size: i128) -> i128 {
+        let tiers: Vec<FeeTier> = env
+            .storage()
+            .instance()
+            .get(&DataKey::FeeTiers)
+            .unwrap_or_else(|| Vec::new(env));
+        if tiers.is_empty() {
+            return base_rate;
+        }
+        for tier in tiers.iter() {
+            if program_size < tier.threshold {
+                return tier.fee_rate;
+            }
+        }
+        tiers.last().map(|tier| tier.fee_rate).unwrap_or(base_rate)
+    }
+
+    /// Charges the registration fee (if enabled) out of funds already held by
+    /// the contract and returns the net amount credited to the program.
+    fn collect_registration_fee(
+        env: &Env,
+        token_client: &token::Client,
+        program_id: u64,
+        gross_amount: i128,
+    ) -> i128 {
+        let config = match env
+            .storage()
+            .instance()
+            .get::<_, FeeConfig>(&DataKey::FeeConfig)
+        {
+            Some(config) if config.registration_fee_enabled => config,
+            _ => return gross_amount,
+        };
+
+        let fee_rate = Self::effective_fee_rate(env, config.registration_fee_rate, gross_amount);
+        let fee = Self::calculate_fee(gross_amount, fee_rate);
+        if fee <= 0 {
+            return gross_amount;
+        }
+
+        let contract_address = env.current_contract_address();
+        if config.fee_recipient == contract_address {
+            let balance = Self::treasury_balance(env);
+            env.storage()
+                .instance()
+                .set(&DataKey::TreasuryBalance, &(balance + fee));
+        } else {
+            token_client.transfer(&contract_address, &config.fee_recipient, &fee);
+        }
+        env.events().publish(
+            (FEE_COLLECTED, program_id),
+            FeeCollectedEvent {
+                version: EVENT_SCHEMA_VERSION,
+                sequence: Self::next_event_sequence(env),
+                operation_type: FeeOperationType::Registration,
+                program_id,
+                amount: fee,
+                fee_rate,
+                recipient: config.fee_recipient,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        gross_amount - fee
+    }
+
+    /// Charges the payout fee (if enabled) on `amount` forwarded to the
+    /// linked payout contract. The fee comes out of the program's pool held
+    /// here, so the recipient is still paid in full. The rate follows the
+    /// tier table for the program's current funding, like registration.
+    fn collect_payout_fee(env: &Env, program_id: u64, amount: i128) -> Result<(), Error> {
+        let config = match env
+            .storage()
+            .instance()
+            .get::<_, FeeConfig>(&DataKey::FeeConfig)
+        {
+            Some(config) if config.payout_fee_enabled => config,
+            _ => return Ok(()),
+        };
+
+        let mut program = Self::get_program(env.clone(), program_id)?;
+        let fee_rate = Self::effective_fee_rate(env, config.payout_fee_rate, program.total_funding);
+        let fee = Self::calculate_fee(amount, fee_rate);
+        if fee <= 0 {
+            return Ok(());
+        }
+        if fee > program.total_funding - Self::child_allocated(env, program_id) {
+            return Err(Error::InsufficientProgramFunds);
+        }
+        program.total_funding -= fee;
+        Self::store_program(env, program_id, &program);
+        Self::record_flow(env, program_id, &program, FlowKind::Outflow, fee);
+
+        let contract_address = env.current_contract_address();
+        if config.fee_recipient == contract_address {
+            let balance = Self::treasury_balance(env);
+            env.storage()
+                .instance()
+                .set(&DataKey::TreasuryBalance, &(balance + fee));
+        } else {
+            let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+            token::Client::new(env, &token_addr).transfer(
+                &contract_address,
+                &config.fee_recipient,
+                &fee,
+            );
+        }
+        env.events().publish(
+            (FEE_COLLECTED, program_id),
+            FeeCollectedEvent {
+                version: EVENT_SCHEMA_VERSION,
+                sequence: Self::next_event_sequence(env),
+                operation_type: FeeOperationType::Payout,
+                program_id,
+                amount: fee,
+                fee_rate,
+                recipient: config.fee_recipient,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    fn validate_schedule_pattern(pattern: &Vec<ScheduleStep>) -> Result<(), Error> {
+        if pattern.is_empty() {
+            return Ok(());
+        }
+        let mut total_bps = 0u32;
+        let mut previous_offset: Option<u64> = None;
+        for step in pattern.iter() {
+            if step.share_bps == 0 {
+                return Err(Error::InvalidSchedulePattern);
+            }
+            if let Some(previous) = previous_offset {
+                if step.offset_secs <= previous {
+                    return Err(Error::InvalidSchedulePattern);
+                }
+            }
+            previous_offset = Some(step.offset_secs);
+            total_bps = total_bps
+                .checked_add(step.share_bps)
+                .ok_or(Error::InvalidSchedulePattern)?;
+        }
+        if total_bps != BASIS_POINTS as u32 {
+            return Err(Error::InvalidSchedulePattern);
+        }
+        Ok(())
+    }
+
+    fn child_allocated(env: &Env, program_id: u64) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ChildAllocated(program_id))
+            .unwrap_or(0)
+    }
+
+    fn program_children(env: &Env, program_id: u64) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ProgramChildren(program_id))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    fn treasury_balance(env: &Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TreasuryBalance)
+            .unwrap_or(0)
+    }
+
+    /// Initialize the contract with an admin and token address. Call once.
+    pub fn init(env: Env, admin: Address, token: Address) -> Result<(), Error> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::AlreadyInitialized);
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Token, &token);
+        Ok(())
+    }
+
+    /// Register a single program.
+    pub fn register_program(
+        env: Env,
+        program_id: u64,
+        admin: Address,
+        name: String,
+        total_funding: i128,
+    ) -> Result<(), Error> {
+        Self::register_program_juris(
+            env,
+            program_id,
+            admin,
+            name,
+            total_funding,
+            None,
+            false,
+            None,
+            false,
+            OptionalJurisdiction::None,
+            None,
+        )
+    }
+
+    /// Register a single program with optional jurisdiction controls.
+    pub fn register_program_juris(
+        env: Env,
+        program_id: u64,
+        admin: Address,
+        name: String,
+        total_funding: i128,
+        juris_tag: Option<String>,
+        juris_requires_kyc: bool,
+        juris_max_funding: Option<i128>,
+        juris_registration_paused: bool,
+        jurisdiction: OptionalJurisdiction,
+        kyc_attested: Option<bool>,
+    ) -> Result<(), Error> {
+        Self::ensure_initialized(&env)?;
+        Self::ensure_not_deprecated(&env)?;
+        Self::require_contract_admin(&env);
+
+        Self::ensure_program_id_free(&env, program_id)?;
+        Self::validate_program_input(&name, total_funding)?;
+
+        let jurisdiction = Self::build_jurisdiction(
+            juris_tag,
+            juris_requires_kyc,
+            juris_max_funding,
+            juris_registration_paused,
+            jurisdiction,
+        );
+        Self::enforce_jurisdiction_rules(&jurisdiction, total_funding, kyc_attested)?;
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let token_client = token::Client::new(&env, &token_addr);
+        admin.require_auth();
+        token_client.transfer(&admin, &env.current_contract_address(), &total_funding);
+        let total_funding =
+            Self::collect_registration_fee(&env, &token_client, program_id, total_funding);
+
+        let program = Program {
+            admin: admin.clone(),
+            name,
+            total_funding,
+            status: ProgramStatus::Active,
+            jurisdiction: jurisdiction.clone(),
+        };
+        Self::store_program(&env, program_id, &program);
+        Self::append_program_id(&env, program_id);
+        Self::record_flow(&env, program_id, &program, FlowKind::Inflow, total_funding);
+        Self::emit_program_registered(&env, program_id, admin, total_funding, &jurisdiction);
+        Ok(())
+    }
+
+    /// Backward-compatible alias.
+    pub fn register_prog_w_juris(
+        env: Env,
+        program_id: u64,
+        admin: Address,
+        name: String,
+        total_funding: i128,
+        juris_tag: Option<String>,
+        juris_requires_kyc: bool,
+        juris_max_funding: Option<i128>,
+        juris_registration_paused: bool,
+        jurisdiction: OptionalJurisdiction,
+        kyc_attested: Option<bool>,
+    ) -> Result<(), Error> {
+        Self::register_program_juris(
+            env,
+            program_id,
+            admin,
+            name,
+            total_funding,
+            juris_tag,
+            juris_requires_kyc,
+            juris_max_funding,
+            juris_registration_paused,
+            jurisdiction,
+            kyc_attested,
+        )
+    }
+
+    /// Batch register multiple programs in a single transaction.
+    pub fn batch_register_programs(
+        env: Env,
+        items: Vec<ProgramRegistrationItem>,
+    ) -> Result<u32, Error> {
+        let batch_size = items.len() as u32;
+        if batch_size == 0 || batch_size > MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatchSize);
+        }
+
+        Self::ensure_initialized(&env)?;
+        Self::ensure_not_deprecated(&env)?;
+        Self::require_contract_admin(&env);
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let token_client = token::Client::new(&env, &token_addr);
+        let contract_address = env.current_contract_address();
+        let ordered_items = Self::order_batch_registration_items(&env, &items);
+
+        for item in ordered_items.iter() {
+            Self::ensure_program_id_free(&env, item.program_id)?;
+            Self::validate_program_input(&item.name, item.total_funding)?;
+
+            let mut count = 0u32;
+            for other in ordered_items.iter() {
+                if other.program_id == item.program_id {
+                    count += 1;
+                }
+            }
+            if count > 1 {
+                return Err(Error::DuplicateProgramId);
+            }
+        }
+
+        let mut seen_admins: Vec<Address> = Vec::new(&env);
+        for item in ordered_items.iter() {
+            let mut found = false;
+            for seen in seen_admins.iter() {
+                if seen == item.admin {
+                    found = true;
+                    break;
+                }
+            }
+            if !found {
+                seen_admins.push_back(item.admin.clone());
+                item.admin.require_auth();
+            }
+        }
+
+        let mut registered_count = 0u32;
+        for item in ordered_items.iter() {
+            token_client.transfer(&item.admin, &contract_address, &item.total_funding);
+            let net_funding = Self::collect_registration_fee(
+                &env,
+                &token_client,
+                item.program_id,
+                item.total_funding,
+            );
+
+            let program = Program {
+                admin: item.admin.clone(),
+                name: item.name.clone(),
+                total_funding: net_funding,
+                status: ProgramStatus::Active,
+                jurisdiction: OptionalJurisdiction::None,
+            };
+            Self::store_program(&env, item.program_id, &program);
+            Self::append_program_id(&env, item.program_id);
+            Self::record_flow(
+                &env,
+                item.program_id,
+                &program,
+                FlowKind::Inflow,
+                net_funding,
+            );
+            Self::emit_program_registered(
+                &env,
+                item.program_id,
+                item.admin.clone(),
+                net_funding,
+                &OptionalJurisdiction::None,
+            );
+            registered_count += 1;
+        }
+
+        Ok(registered_count)
+    }
+
+    /// Batch register programs with optional jurisdiction controls.
+    pub fn batch_register_juris(
+        env: Env,
+        items: Vec<ProgramRegistrationWithJurisdictionItem>,
+    ) -> Result<u32, Error> {
+        let batch_size = items.len() as u32;
+        if batch_size == 0 || batch_size > MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatchSize);
+        }
+
+        Self::ensure_initialized(&env)?;
+        Self::ensure_not_deprecated(&env)?;
+        Self::require_contract_admin(&env);
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let token_client = token::Client::new(&env, &token_addr);
+        let contract_address = env.current_contract_address();
+
+        for item in items.iter() {
+            Self::ensure_program_id_free(&env, item.program_id)?;
+            Self::validate_program_input(&item.name, item.total_funding)?;
+
+            let jurisdiction = Self::build_jurisdiction(
+                item.juris_tag.clone(),
+                item.juris_requires_kyc,
+                item.juris_max_funding,
+                item.juris_registration_paused,
+                item.jurisdiction.clone(),
+            );
+            Self::enforce_jurisdiction_rules(
+                &jurisdiction,
+                item.total_funding,
+                item.kyc_attested,
+            )?;
+
+            let mut count = 0u32;
+            for other in items.iter() {
+                if other.program_id == item.program_id {
+                    count += 1;
+                }
+            }
+            if count > 1 {
+                return Err(Error::DuplicateProgramId);
+            }
+        }
+
+        let mut seen_admins: Vec<Address> = Vec::new(&env);
+        for item in items.iter() {
+            let mut found = false;
+            for seen in seen_admins.iter() {
+                if seen == item.admin {
+                    found = true;
+                    break;
+                }
+            }
+            if !found {
+                seen_admins.push_back(item.admin.clone());
+                item.admin.require_auth();
+            }
+        }
+
+        let mut registered_count = 0u32;
+        for item in items.iter() {
+            token_client.transfer(&item.admin, &contract_address, &item.total_funding);
+            let net_funding = Self::collect_registration_fee(
+                &env,
+                &token_client,
+                item.program_id,
+                item.total_funding,
+            );
+
+            let jurisdiction = Self::build_jurisdiction(
+                item.juris_tag.clone(),
+                item.juris_requires_kyc,
+                item.juris_max_funding,
+                item.juris_registration_paused,
+                item.jurisdiction.clone(),
+            );
+            let program = Program {
+                admin: item.admin.clone(),
+                name: item.name.clone(),
+                total_funding: net_funding,
+                status: ProgramStatus::Active,
+                jurisdiction: jurisdiction.clone(),
+            };
+            Self::store_program(&env, item.program_id, &program);
+            Self::append_program_id(&env, item.program_id);
+            Self::record_flow(
+                &env,
+                item.program_id,
+                &program,
+                FlowKind::Inflow,
+                net_funding,
+            );
+            Self::emit_program_registered(
+                &env,
+                item.program_id,
+                item.admin.clone(),
+                net_funding,
+                &jurisdiction,
+            );
+            registered_count += 1;
+        }
+
+        Ok(registered_count)
+    }
+
+    /// Backward-compatible alias.
+    pub fn batch_reg_progs_w_juris(
+        env: Env,
+        items: Vec<ProgramRegistrationWithJurisdictionItem>,
+    ) -> Result<u32, Error> {
+        Self::batch_register_juris(env, items)
+    }
+
+    /// Read a program's state.
+    pub fn get_program(env: Env, program_id: u64) -> Result<Program, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Program(program_id))
+            .ok_or(Error::ProgramNotFound)
+    }
+
+    /// Store a reusable registration template (contract admin only) and return
+    /// its id.
+    pub fn create_program_template(
+        env: Env,
+        name: String,
+        default_jurisdiction: OptionalJurisdiction,
+        default_schedule_pattern: Vec<ScheduleStep>,
+    ) -> Result<u64, Error> {
+        Self::ensure_initialized(&env)?;
+        let admin = Self::require_contract_admin(&env);
+
+        if name.is_empty() {
+            return Err(Error::InvalidName);
+        }
+        Self::validate_schedule_pattern(&default_schedule_pattern)?;
+
+        let template_id: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::NextTemplateId)
+            .unwrap_or(1);
+        env.storage()
+            .instance()
+            .set(&DataKey::NextTemplateId, &(template_id + 1));
+
+        let template = ProgramTemplate {
+            template_id,
+            name: name.clone(),
+            default_jurisdiction,
+            default_schedule_pattern,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::ProgramTemplate(template_id), &template);
+
+        env.events().publish(
+            (TEMPLATE_CREATED, template_id),
+            TemplateCreatedEvent {
+                version: EVENT_SCHEMA_VERSION,
+                sequence: Self::next_event_sequence(&env),
+                template_id,
+                name,
+                admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(template_id)
+    }
+
+    /// Read a stored template.
+    pub fn get_program_template(env: Env, template_id: u64) -> Result<ProgramTemplate, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ProgramTemplate(template_id))
+            .ok_or(Error::TemplateNotFound)
+    }
+
+    /// Register a program using a template's name and jurisdiction defaults.
+    ///
+    /// Follows the same rules as `register_program_juris`, including fees and
+    /// jurisdiction enforcement. Templates that require KYC cannot be used
+    /// here because there is no attestation argument.
+    ///
+    /// A non-empty schedule pattern is materialized into a release plan over
+    /// the program's net funding, with offsets counted from now. The last step
+    /// takes any rounding remainder. See `program_apply_schedule_plan`.
+    pub fn register_from_template(
+        env: Env,
+        template_id: u64,
+        program_id: u64,
+        admin: Address,
+        funding: i128,
+    ) -> Result<(), Error> {
+        let template = Self::get_program_template(env.clone(), template_id)?;
+
+        Self::register_program_juris(
+            env.clone(),
+            program_id,
+            admin,
+            template.name,
+            funding,
+            None,
+            false,
+            None,
+            false,
+            template.default_jurisdiction,
+            None,
+        )?;
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::ProgramTemplateId(program_id), &template_id);
+
+        let pattern = template.default_schedule_pattern;
+        if !pattern.is_empty() {
+            let funding = Self::get_program(env.clone(), program_id)?.total_funding;
+            let now = env.ledger().timestamp();
+            let mut plan = Vec::new(&env);
+            let mut planned: i128 = 0;
+            for (i, step) in pattern.iter().enumerate() {
+                let amount = if i as u32 + 1 == pattern.len() {
+                    funding - planned
+                } else {
+                    funding * step.share_bps as i128 / BASIS_POINTS
+                };
+                planned += amount;
+                plan.push_back(PlannedRelease {
+                    release_timestamp: now + step.offset_secs,
+                    amount,
+                });
+            }
+            env.storage()
+                .persistent()
+                .set(&DataKey::SchedulePlan(program_id), &plan);
+        }
+        Ok(())
+    }
+
+    /// Releases materialized from the program's template that have not been
+    /// forwarded yet; empty when there are none.
+    pub fn get_schedule_plan(env: Env, program_id: u64) -> Vec<PlannedRelease> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::SchedulePlan(program_id))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Turn the program's release plan into schedules on the linked payout
+    /// contract, all paying `recipient` (program admin only).
+    ///
+    /// Each step is created as by `program_create_schedule`, so budget,
+    /// beneficiary, cooperative and fee rules apply as usual. The plan is
+    /// consumed.
+    pub fn program_apply_schedule_plan(
+        env: Env,
+        program_id: u64,
+        category: String,
+        recipient: Address,
+    ) -> Result<Vec<PayoutReleaseSchedule>, Error> {
+        Self::get_program(env.clone(), program_id)?
+            .admin
+            .require_auth();
+        let plan: Vec<PlannedRelease> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::SchedulePlan(program_id))
+            .ok_or(Error::SchedulePlanNotFound)?;
+        let client = Self::payout_client(&env, program_id)?;
+
+        let mut schedules = Vec::new(&env);
+        for step in plan.iter() {
+            schedules.append(&Self::create_schedules(
+                &env,
+                &client,
+                program_id,
+                &category,
+                &recipient,
+                step.amount,
+                step.release_timestamp,
+            )?);
+        }
+        env.storage()
+            .persistent()
+            .remove(&DataKey::SchedulePlan(program_id));
+        Ok(schedules)
+    }
+
+    /// Template id a program was registered from, if any.
+    pub fn get_program_template_id(env: Env, program_id: u64) -> Option<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ProgramTemplateId(program_id))
+    }
+
+    /// Register `child_id` as a sub-program funded out of `parent_id`.
+    ///
+    /// The allocation is carved out of the parent's unallocated funding, so the
+    /// sum of all children can never exceed the parent's `total_funding`. No
+    /// tokens move: the child is backed by funds the contract already holds.
+    /// The child inherits the parent's admin, name and jurisdiction.
+    pub fn register_child_program(
+        env: Env,
+        parent_id: u64,
+        child_id: u64,
+        allocation: i128,
+    ) -> Result<(), Error> {
+        Self::ensure_initialized(&env)?;
+        Self::ensure_not_deprecated(&env)?;
+
+        let parent: Program = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Program(parent_id))
+            .ok_or(Error::ProgramNotFound)?;
+        parent.admin.require_auth();
+
+        Self::ensure_program_id_free(&env, child_id)?;
+        if allocation <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let allocated = Self::child_allocated(&env, parent_id);
+        if allocation > parent.total_funding - allocated {
+            return Err(Error::InsufficientParentAllocation);
+        }
+
+        let child = Program {
+            admin: parent.admin.clone(),
+            name: parent.name.clone(),
+            total_funding: allocation,
+            status: ProgramStatus::Active,
+            jurisdiction: parent.jurisdiction.clone(),
+        };
+        Self::store_program(&env, child_id, &child);
+        Self::append_program_id(&env, child_id);
+
+        let mut children = Self::program_children(&env, parent_id);
+        children.push_back(child_id);
+        env.storage()
+            .persistent()
+            .set(&DataKey::ProgramChildren(parent_id), &children);
+        env.storage()
+            .persistent()
+            .set(&DataKey::ProgramParent(child_id), &parent_id);
+        env.storage().persistent().set(
+            &DataKey::ChildAllocated(parent_id),
+            &(allocated + allocation),
+        );
+
+        env.events().publish(
+            (CHILD_PROGRAM_REGISTERED, parent_id),
+            ChildProgramRegisteredEvent {
+                version: EVENT_SCHEMA_VERSION,
+                sequence: Self::next_event_sequence(&env),
+                parent_id,
+                child_id,
+                allocation,
+                admin: parent.admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Funding of `program_id` not yet carved out for child programs.
+    pub fn get_unallocated_funding(env: Env, program_id: u64) -> Result<i128, Error> {
+        let program = Self::get_program(env.clone(), program_id)?;
+        Ok(program.total_funding - Self::child_allocated(&env, program_id))
+    }
+
+    /// Depth-first roll-up of `parent_id` and all of its descendants.
+    pub fn get_program_tree(env: Env, parent_id: u64) -> Result<Vec<ProgramTreeNode>, Error> {
+        let mut nodes: Vec<ProgramTreeNode> = Vec::new(&env);
+        // Stack of (program_id, depth); children are pushed in reverse so they
+        // pop in registration order.
+        let mut stack: Vec<(u64, u32)> = Vec::new(&env);
+        stack.push_back((parent_id, 0));
+
+        while let Some((program_id, depth)) = stack.pop_back() {
+            let program = Self::get_program(env.clone(), program_id)?;
+            nodes.push_back(ProgramTreeNode {
+                program_id,
+                parent_id: env
+                    .storage()
+                    .persistent()
+                    .get(&DataKey::ProgramParent(program_id)),
+                depth,
+                total_funding: program.total_funding,
+                allocated_to_children: Self::child_allocated(&env, program_id),
+            });
+
+            let children = Self::program_children(&env, program_id);
+            let mut i = children.len();
+            while i > 0 {
+                i -= 1;
+                stack.push_back((children.get(i).unwrap(), depth + 1));
+            }
+        }
+        Ok(nodes)
+    }
+
+    /// Open a pledge phase for `program_id`. The program is registered by
+    /// `finalize_round` once pledges reach `goal`; if the deadline passes
+    /// under goal, pledgers reclaim their funds with `refund_pledges`.
+    /// After the deadline anyone can finalize a round that met its goal.
+    pub fn open_funding_round(
+        env: Env,
+        program_id: u64,
+        admin: Address,
+        name: String,
+        goal: i128,
+        deadline: u64,
+    ) -> Result<FundingRound, Error> {
+        Self::ensure_initialized(&env)?;
+        Self::ensure_not_deprecated(&env)?;
+        Self::require_contract_admin(&env);
+        admin.require_auth();
+
+        Self::ensure_program_id_free(&env, program_id)?;
+        Self::validate_program_input(&name, goal)?;
+        if deadline <= env.ledger().timestamp() {
+            return Err(Error::InvalidFundingDeadline);
+        }
+
+        let round = FundingRound {
+            program_id,
+            admin,
+            name,
+            goal,
+            deadline,
+            pledged: 0,
+            refunded: 0,
+            status: FundingRoundStatus::Open,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::FundingRound(program_id), &round);
+        env.events().publish(
+            (FUNDING_ROUND_OPENED, program_id),
+            FundingRoundOpenedEvent {
+                version: EVENT_SCHEMA_VERSION,
+                sequence: Self::next_event_sequence(&env),
+                program_id,
+                admin: round.admin.clone(),
+                name: round.name.clone(),
+                goal,
+                deadline,
+            },
+        );
+        Ok(round)
+    }
+
+    /// Pledge `amount` from `from` towards an open funding round.
+    pub fn pledge(env: Env, program_id: u64, from: Address, amount: i128) -> Result<(), Error> {
+        from.require_auth();
+        let mut round = Self::get_funding_round(env.clone(), program_id)?;
+        if round.status != FundingRoundStatus::Open || env.ledger().timestamp() >= round.deadline {
+            return Err(Error::FundingRoundClosed);
+        }
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let token_client = token::Client::new(&env, &token_addr);
+        token_client.transfer(&from, env.current_contract_address(), &amount);
+
+        let key = DataKey::Pledge(program_id, from.clone());
+        let pledged: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        env.storage().persistent().set(&key, &(pledged + amount));
+        round.pledged += amount;
+        env.storage()
+            .persistent()
+            .set(&DataKey::FundingRound(program_id), &round);
+
+        env.events().publish(
+            (PLEDGED, program_id),
+            PledgeEvent {
+                version: EVENT_SCHEMA_VERSION,
+                sequence: Self::next_event_sequence(&env),
+                program_id,
+                contributor: from,
+                amount,
+                total_pledged: round.pledged,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Register the program with everything pledged once the goal is met.
+    /// Before the deadline only the round admin may finalize; afterwards
+    /// anyone can, so pledges never stay locked in a round that met its goal.
+    pub fn finalize_round(env: Env, program_id: u64) -> Result<Program, Error> {
+        Self::ensure_not_deprecated(&env)?;
+        let mut round = Self::get_funding_round(env.clone(), program_id)?;
+        if env.ledger().timestamp() < round.deadline {
+            round.admin.require_auth();
+        }
+        if round.status != FundingRoundStatus::Open {
+            return Err(Error::FundingRoundClosed);
+        }
+        if round.pledged < round.goal {
+            return Err(Error::FundingGoalNotMet);
+        }
+        // Rounds opened before ids were reserved may share one with a program.
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::Program(program_id))
+        {
+            return Err(Error::ProgramExists);
+        }
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let token_client = token::Client::new(&env, &token_addr);
+        let total_funding =
+            Self::collect_registration_fee(&env, &token_client, program_id, round.pledged);
+
+        let program = Program {
+            admin: round.admin.clone(),
+            name: round.name.clone(),
+            total_funding,
+            status: ProgramStatus::Active,
+            jurisdiction: OptionalJurisdiction::None,
+        };
+        Self::store_program(&env, program_id, &program);
+        Self::append_program_id(&env, program_id);
+        Self::record_flow(&env, program_id, &program, FlowKind::Inflow, total_funding);
+
+        round.status = FundingRoundStatus::Finalized;
+        env.storage()
+            .persistent()
+            .set(&DataKey::FundingRound(program_id), &round);
+
+        env.events().publish(
+            (FUNDING_ROUND_FINALIZED, program_id),
+            FundingRoundFinalizedEvent {
+                version: EVENT_SCHEMA_VERSION,
+                sequence: Self::next_event_sequence(&env),
+                program_id,
+                pledged: round.pledged,
+                goal: round.goal,
+                total_funding,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Self::emit_program_registered(
+            &env,
+            program_id,
+            round.admin,
+            total_funding,
+            &OptionalJurisdiction::None,
+        );
+        Ok(program)
+    }
+
+    /// Return `from`'s pledge after the deadline has passed under goal.
+    /// A round that met its goal is refundable too once the contract is
+    /// deprecated, since it can no longer be finalized. Each pledge can be
+    /// refunded once.
+    pub fn refund_pledges(env: Env, program_id: u64, from: Address) -> Result<i128, Error> {
+        from.require_auth();
+        let mut round = Self::get_funding_round(env.clone(), program_id)?;
+        if round.status == FundingRoundStatus::Finalized {
+            return Err(Error::FundingRoundClosed);
+        }
+        let finalizable =
+            round.pledged >= round.goal && !Self::get_deprecation_state(&env).deprecated;
+        if env.ledger().timestamp() < round.deadline || finalizable {
+            return Err(Error::FundingRoundActive);
+        }
+
+        let key = DataKey::Pledge(program_id, from.clone());
+        let amount: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        if amount <= 0 {
+            return Err(Error::NothingToRefund);
+        }
+        env.storage().persistent().remove(&key);
+        round.refunded += amount;
+        round.status = FundingRoundStatus::Refunding;
+        env.storage()
+            .persistent()
+            .set(&DataKey::FundingRound(program_id), &round);
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        token::Client::new(&env, &token_addr).transfer(
+            &env.current_contract_address(),
+            &from,
+            &amount,
+        );
+
+        env.events().publish(
+            (PLEDGE_REFUNDED, program_id),
+            PledgeEvent {
+                version: EVENT_SCHEMA_VERSION,
+                sequence: Self::next_event_sequence(&env),
+                program_id,
+                contributor: from,
+                amount,
+                total_pledged: round.pledged,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(amount)
+    }
+
+    pub fn get_funding_round(env: Env, program_id: u64) -> Result<FundingRound, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::FundingRound(program_id))
+            .ok_or(Error::FundingRoundNotFound)
+    }
+
+    /// Outstanding (unrefunded) pledge of `contributor`.
+    pub fn get_pledge(env: Env, program_id: u64, contributor: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Pledge(program_id, contributor))
+            .unwrap_or(0)
+    }
+
+    /// Permissionless top-up of an active program's pool.
+    ///
+    /// Honors the program's `max_donation` per call and its jurisdiction
+    /// `max_funding` on the resulting total. No registration fee is charged.
+    pub fn donate(env: Env, program_id: u64, from: Address, amount: i128) -> Result<i128, Error> {
+        Self::ensure_initialized(&env)?;
+        Self::ensure_not_deprecated(&env)?;
+        from.require_auth();
+
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        let mut program = Self::get_program(env.clone(), program_id)?;
+        if program.status != ProgramStatus::Active {
+            return Err(Error::ProgramNotActive);
+        }
+        let total_funding = program
+            .total_funding
+            .checked_add(amount)
+            .ok_or(Error::InvalidAmount)?;
+        if let Some(max_donation) = Self::get_max_donation(env.clone(), program_id) {
+            if amount > max_donation {
+                return Err(Error::DonationLimitExceeded);
+            }
+        }
+        if let OptionalJurisdiction::Some(config) = &program.jurisdiction {
+            if let Some(max_funding) = config.max_funding {
+                if total_funding > max_funding {
+                    return Err(Error::JurisdictionFundingLimitExceeded);
+                }
+            }
+        }
 
-    fn append_program_id(env: &Env, program_id: u64) {
-        let mut index: Vec<u64> = env
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let token_client = token::Client::new(&env, &token_addr);
+        token_client.transfer(&from, env.current_contract_address(), &amount);
+
+        program.total_funding = total_funding;
+        Self::store_program(&env, program_id, &program);
+        Self::record_flow(&env, program_id, &program, FlowKind::Inflow, amount);
+
+        let key = DataKey::DonorTotal(program_id, from.clone());
+        let donor_total = Self::get_donor_total(env.clone(), program_id, from.clone()) + amount;
+        env.storage().persistent().set(&key, &donor_total);
+
+        env.events().publish(
+            (DONATION_RECEIVED, program_id),
+            DonationEvent {
+                version: EVENT_SCHEMA_VERSION,
+                sequence: Self::next_event_sequence(&env),
+                program_id,
+                donor: from.clone(),
+                amount,
+                donor_total,
+                program_total_funding: total_funding,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Self::issue_donation_receipt(&env, program_id, &from, amount);
+        Ok(total_funding)
+    }
+
+    fn issue_donation_receipt(env: &Env, program_id: u64, depositor: &Address, amount: i128) {
+        let receipt_id: u64 = env
             .storage()
+            .instance()
+            .get(&DataKey::NextReceiptId)
+            .unwrap_or(0)
+            + 1;
+        env.storage()
+            .instance()
+            .set(&DataKey::NextReceiptId, &receipt_id);
+        let receipt = DonationReceipt {
+            receipt_id,
+            program_id,
+            depositor: depositor.clone(),
+            holder: depositor.clone(),
+            amount,
+            issued_at: env.ledger().timestamp(),
+        };
+        env.storage()
             .persistent()
-            .get(&DataKey::ProgramIndex)
-            .unwrap_or_else(|| Vec::new(env));
-        index.push_back(program_id);
-        env.storage().persistent().set(&DataKey::ProgramIndex, &index);
+            .set(&DataKey::DonationReceipt(receipt_id), &receipt);
+        env.events().publish(
+            (RECEIPT_ISSUED, program_id),
+            ReceiptIssuedEvent {
+                version: EVENT_SCHEMA_VERSION,
+                sequence: Self::next_event_sequence(env),
+                receipt_id,
+                program_id,
+                depositor: depositor.clone(),
+                amount,
+            },
+        );
+    }
+
+    /// Receipt `receipt_id`, written by `donate`.
+    pub fn get_receipt(env: Env, receipt_id: u64) -> Result<DonationReceipt, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::DonationReceipt(receipt_id))
+            .ok_or(Error::ReceiptNotFound)
+    }
+
+    /// True when `receipt_id` exists and records exactly `expected_amount`
+    /// donated by `expected_depositor`.
+    pub fn verify_receipt(
+        env: Env,
+        receipt_id: u64,
+        expected_amount: i128,
+        expected_depositor: Address,
+    ) -> bool {
+        match Self::get_receipt(env, receipt_id) {
+            Ok(receipt) => {
+                receipt.amount == expected_amount && receipt.depositor == expected_depositor
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Move a receipt to `to`. Only the current holder can transfer it.
+    pub fn transfer_receipt(
+        env: Env,
+        receipt_id: u64,
+        to: Address,
+    ) -> Result<DonationReceipt, Error> {
+        let mut receipt = Self::get_receipt(env.clone(), receipt_id)?;
+        receipt.holder.require_auth();
+
+        let from = receipt.holder.clone();
+        receipt.holder = to.clone();
+        env.storage()
+            .persistent()
+            .set(&DataKey::DonationReceipt(receipt_id), &receipt);
+        env.events().publish(
+            (RECEIPT_TRANSFERRED, receipt_id),
+            ReceiptTransferredEvent {
+                version: EVENT_SCHEMA_VERSION,
+                sequence: Self::next_event_sequence(&env),
+                receipt_id,
+                from,
+                to,
+            },
+        );
+        Ok(receipt)
+    }
+
+    /// Cap a single donation to `program_id` (program admin only); `None`
+    /// removes the cap.
+    pub fn set_max_donation(
+        env: Env,
+        program_id: u64,
+        max_donation: Option<i128>,
+    ) -> Result<(), Error> {
+        let program = Self::get_program(env.clone(), program_id)?;
+        program.admin.require_auth();
+        let key = DataKey::MaxDonation(program_id);
+        match max_donation {
+            Some(cap) if cap <= 0 => return Err(Error::InvalidAmount),
+            Some(cap) => env.storage().persistent().set(&key, &cap),
+            None => env.storage().persistent().remove(&key),
+        }
+        Ok(())
+    }
+
+    pub fn get_max_donation(env: Env, program_id: u64) -> Option<i128> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::MaxDonation(program_id))
+    }
+
+    /// Cumulative amount `donor` has donated to `program_id`.
+    pub fn get_donor_total(env: Env, program_id: u64, donor: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::DonorTotal(program_id, donor))
+            .unwrap_or(0)
+    }
+
+    /// Point `program_id` at the payout contract instance that runs its
+    /// payouts and schedules (program admin only). Relinking replaces the
+    /// previous instance.
+    pub fn link_payout_contract(
+        env: Env,
+        program_id: u64,
+        payout_contract: Address,
+    ) -> Result<(), Error> {
+        Self::ensure_initialized(&env)?;
+        let program = Self::get_program(env.clone(), program_id)?;
+        program.admin.require_auth();
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::PayoutContract(program_id), &payout_contract);
+        env.events().publish(
+            (PAYOUT_CONTRACT_LINKED, program_id),
+            PayoutContractLinkedEvent {
+                version: EVENT_SCHEMA_VERSION,
+                sequence: Self::next_event_sequence(&env),
+                program_id,
+                payout_contract,
+                admin: program.admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    pub fn get_payout_contract(env: Env, program_id: u64) -> Option<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PayoutContract(program_id))
+    }
+
+    /// Allow programs to fund bounties on `bounty_escrow` (admin only).
+    pub fn register_bounty_escrow(env: Env, bounty_escrow: Address) -> Result<(), Error> {
+        Self::ensure_initialized(&env)?;
+        let admin = Self::require_contract_admin(&env);
+        env.storage()
+            .instance()
+            .set(&DataKey::BountyEscrow(bounty_escrow.clone()), &true);
+        env.events().publish(
+            (BOUNTY_ESCROW_REGISTERED,),
+            BountyEscrowRegisteredEvent {
+                version: EVENT_SCHEMA_VERSION,
+                sequence: Self::next_event_sequence(&env),
+                bounty_escrow,
+                admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    pub fn is_bounty_escrow_registered(env: Env, bounty_escrow: Address) -> bool {
+        env.storage()
+            .instance()
+            .has(&DataKey::BountyEscrow(bounty_escrow))
+    }
+
+    /// Lock `amount` of `program_id`'s pool into `bounty_id` on a registered
+    /// bounty escrow (program admin only).
+    ///
+    /// This contract is the bounty's depositor, so refunds come back here
+    /// rather than to the program admin. Only funding not carved out for
+    /// child programs can be spent; `total_funding` drops by `amount`.
+    pub fn fund_bounty_from_program(
+        env: Env,
+        program_id: u64,
+        bounty_escrow: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+    ) -> Result<(), Error> {
+        Self::ensure_initialized(&env)?;
+        Self::ensure_not_deprecated(&env)?;
+        let mut program = Self::get_program(env.clone(), program_id)?;
+        program.admin.require_auth();
+
+        if program.status != ProgramStatus::Active {
+            return Err(Error::ProgramNotActive);
+        }
+        if !Self::is_bounty_escrow_registered(env.clone(), bounty_escrow.clone()) {
+            return Err(Error::BountyEscrowNotRegistered);
+        }
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if amount > program.total_funding - Self::child_allocated(&env, program_id) {
+            return Err(Error::InsufficientProgramFunds);
+        }
+
+        program.total_funding -= amount;
+        Self::store_program(&env, program_id, &program);
+        Self::record_flow(&env, program_id, &program, FlowKind::Outflow, amount);
+
+        let this = env.current_contract_address();
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        env.authorize_as_current_contract(vec![
+            &env,
+            InvokerContractAuthEntry::Contract(SubContractInvocation {
+                context: ContractContext {
+                    contract: token_addr,
+                    fn_name: Symbol::new(&env, "transfer"),
+                    args: (this.clone(), bounty_escrow.clone(), amount).into_val(&env),
+                },
+                sub_invocations: vec![&env],
+            }),
+        ]);
+        BountyEscrowClient::new(&env, &bounty_escrow)
+            .lock_funds(&this, &bounty_id, &amount, &deadline);
+
+        let now = env.ledger().timestamp();
+        let mut bounties = Self::list_program_bounties(env.clone(), program_id);
+        bounties.push_back(ProgramBounty {
+            bounty_escrow: bounty_escrow.clone(),
+            bounty_id,
+            amount,
+            deadline,
+            funded_at: now,
+            released: 0,
+            refunded: 0,
+        });
+        env.storage()
+            .persistent()
+            .set(&DataKey::ProgramBounties(program_id), &bounties);
+        env.storage().persistent().set(
+            &DataKey::BountyProgram(bounty_escrow.clone(), bounty_id),
+            &program_id,
+        );
+
+        env.events().publish(
+            (BOUNTY_FUNDED, program_id),
+            ProgramBountyFundedEvent {
+                version: EVENT_SCHEMA_VERSION,
+                sequence: Self::next_event_sequence(&env),
+                program_id,
+                bounty_escrow,
+                bounty_id,
+                amount,
+                deadline,
+                program_total_funding: program.total_funding,
+                timestamp: now,
+            },
+        );
+        Ok(())
+    }
+
+    /// Settlement callback for a bounty funded by `fund_bounty_from_program`.
+    ///
+    /// `bounty_escrow` is the calling escrow; it must be registered and must
+    /// authorize the call, which holds when it invokes this entrypoint itself.
+    /// Refunds land in this contract (the depositor), so a `Refunded` outcome
+    /// credits `amount` back to the originating program's `total_funding`;
+    /// `Released` is recorded for reporting only. Settlements are capped at
+    /// the bounty's unsettled amount.
+    pub fn on_bounty_settled(
+        env: Env,
+        bounty_escrow: Address,
+        bounty_id: u64,
+        outcome: BountyOutcome,
+        amount: i128,
+    ) -> Result<(), Error> {
+        Self::ensure_initialized(&env)?;
+        bounty_escrow.require_auth();
+        if !Self::is_bounty_escrow_registered(env.clone(), bounty_escrow.clone()) {
+            return Err(Error::BountyEscrowNotRegistered);
+        }
+        let program_id: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::BountyProgram(bounty_escrow.clone(), bounty_id))
+            .ok_or(Error::BountyNotLinked)?;
+
+        let mut bounties = Self::list_program_bounties(env.clone(), program_id);
+        let index = bounties
+            .iter()
+            .position(|b| b.bounty_escrow == bounty_escrow && b.bounty_id == bounty_id)
+            .ok_or(Error::BountyNotLinked)? as u32;
+        let mut bounty = bounties.get(index).unwrap();
+        if amount <= 0 || amount > bounty.amount - bounty.released - bounty.refunded {
+            return Err(Error::InvalidAmount);
+        }
+
+        let mut program = Self::get_program(env.clone(), program_id)?;
+        match outcome {
+            BountyOutcome::Released => bounty.released += amount,
+            BountyOutcome::Refunded => {
+                bounty.refunded += amount;
+                program.total_funding += amount;
+                Self::store_program(&env, program_id, &program);
+                Self::record_flow(&env, program_id, &program, FlowKind::Inflow, amount);
+            }
+        }
+        bounties.set(index, bounty);
+        env.storage()
+            .persistent()
+            .set(&DataKey::ProgramBounties(program_id), &bounties);
+
+        env.events().publish(
+            (BOUNTY_SETTLED, program_id),
+            BountySettledEvent {
+                version: EVENT_SCHEMA_VERSION,
+                sequence: Self::next_event_sequence(&env),
+                program_id,
+                bounty_escrow,
+                bounty_id,
+                outcome,
+                amount,
+                program_total_funding: program.total_funding,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Bounties funded from `program_id`, oldest first.
+    pub fn list_program_bounties(env: Env, program_id: u64) -> Vec<ProgramBounty> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ProgramBounties(program_id))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    fn payout_client(env: &Env, program_id: u64) -> Result<PayoutContractClient<'_>, Error> {
+        let address: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PayoutContract(program_id))
+            .ok_or(Error::PayoutContractNotLinked)?;
+        Ok(PayoutContractClient::new(env, &address))
+    }
+
+    /// Carve a named envelope of `allocation` out of `program_id`'s funding
+    /// (program admin only). Allocations across categories cannot exceed the
+    /// program's `total_funding`.
+    ///
+    /// Only the `program_*` forwarders charge categories; the payout contract
+    /// knows nothing of them.
+    pub fn create_budget_category(
+        env: Env,
+        program_id: u64,
+        name: String,
+        allocation: i128,
+    ) -> Result<(), Error> {
+        Self::ensure_initialized(&env)?;
+        let program = Self::get_program(env.clone(), program_id)?;
+        program.admin.require_auth();
+
+        if name.is_empty() {
+            return Err(Error::InvalidName);
+        }
+        if allocation <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        let mut categories = Self::budget_categories(&env, program_id);
+        let mut allocated = 0i128;
+        for category in categories.iter() {
+            if category.name == name {
+                return Err(Error::BudgetCategoryExists);
+            }
+            allocated += category.allocation;
+        }
+        if allocation > program.total_funding - Self::child_allocated(&env, program_id) - allocated
+        {
+            return Err(Error::InsufficientProgramFunds);
+        }
+
+        categories.push_back(BudgetCategory {
+            name: name.clone(),
+            allocation,
+            spent: 0,
+        });
+        env.storage()
+            .persistent()
+            .set(&DataKey::BudgetCategories(program_id), &categories);
+        env.events().publish(
+            (BUDGET_CATEGORY_CREATED, program_id),
+            BudgetCategoryCreatedEvent {
+                version: EVENT_SCHEMA_VERSION,
+                sequence: Self::next_event_sequence(&env),
+                program_id,
+                name,
+                allocation,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Per-category allocation, spend, and utilization for `program_id`.
+    pub fn get_budget_report(env: Env, program_id: u64) -> Vec<BudgetUtilization> {
+        let mut report = Vec::new(&env);
+        for category in Self::budget_categories(&env, program_id).iter() {
+            report.push_back(BudgetUtilization {
+                name: category.name,
+                allocation: category.allocation,
+                spent: category.spent,
+                remaining: category.allocation - category.spent,
+                utilization_bps: category.spent * BASIS_POINTS / category.allocation,
+            });
+        }
+        report
+    }
+
+    fn budget_categories(env: &Env, program_id: u64) -> Vec<BudgetCategory> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::BudgetCategories(program_id))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Charge `amount` to `category`, failing if it would overspend.
+    fn charge_budget(
+        env: &Env,
+        program_id: u64,
+        category: &String,
+        amount: i128,
+    ) -> Result<(), Error> {
+        let mut categories = Self::budget_categories(env, program_id);
+        let index = categories
+            .iter()
+            .position(|c| c.name == *category)
+            .ok_or(Error::BudgetCategoryNotFound)? as u32;
+        let mut entry = categories.get(index).unwrap();
+        if amount <= 0 || amount > entry.allocation - entry.spent {
+            return Err(Error::BudgetExceeded);
+        }
+        entry.spent += amount;
+        categories.set(index, entry);
+        env.storage()
+            .persistent()
+            .set(&DataKey::BudgetCategories(program_id), &categories);
+        Ok(())
+    }
+
+    /// Enroll `beneficiary` in `program_id` (program admin only). Enrolling
+    /// again replaces the metadata hash.
+    pub fn enroll_beneficiary(
+        env: Env,
+        program_id: u64,
+        beneficiary: Address,
+        metadata_hash: BytesN<32>,
+    ) -> Result<(), Error> {
+        Self::ensure_initialized(&env)?;
+        let program = Self::get_program(env.clone(), program_id)?;
+        program.admin.require_auth();
+
+        env.storage().persistent().set(
+            &DataKey::Beneficiary(program_id, beneficiary.clone()),
+            &Beneficiary {
+                metadata_hash: metadata_hash.clone(),
+                enrolled_at: env.ledger().timestamp(),
+            },
+        );
+        env.events().publish(
+            (BENEFICIARY_ENROLLED, program_id),
+            BeneficiaryEnrolledEvent {
+                version: EVENT_SCHEMA_VERSION,
+                sequence: Self::next_event_sequence(&env),
+                program_id,
+                beneficiary,
+                metadata_hash,
+                admin: program.admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Remove `beneficiary` from `program_id` (program admin only).
+    pub fn remove_beneficiary(
+        env: Env,
+        program_id: u64,
+        beneficiary: Address,
+    ) -> Result<(), Error> {
+        Self::ensure_initialized(&env)?;
+        let program = Self::get_program(env.clone(), program_id)?;
+        program.admin.require_auth();
+
+        let key = DataKey::Beneficiary(program_id, beneficiary.clone());
+        if !env.storage().persistent().has(&key) {
+            return Err(Error::BeneficiaryNotEnrolled);
+        }
+        env.storage().persistent().remove(&key);
+        env.events().publish(
+            (BENEFICIARY_REMOVED, program_id),
+            BeneficiaryRemovedEvent {
+                version: EVENT_SCHEMA_VERSION,
+                sequence: Self::next_event_sequence(&env),
+                program_id,
+                beneficiary,
+                admin: program.admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    pub fn get_beneficiary(env: Env, program_id: u64, beneficiary: Address) -> Option<Beneficiary> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Beneficiary(program_id, beneficiary))
+    }
+
+    /// Restrict `program_id`'s payouts and schedules to enrolled
+    /// beneficiaries (program admin only).
+    pub fn set_beneficiaries_only(env: Env, program_id: u64, enabled: bool) -> Result<(), Error> {
+        Self::ensure_initialized(&env)?;
+        let program = Self::get_program(env.clone(), program_id)?;
+        program.admin.require_auth();
+
+        let key = DataKey::BeneficiariesOnly(program_id);
+        if enabled {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        env.events()
+            .publish((BENEFICIARIES_ONLY_CHANGED, program_id), enabled);
+        Ok(())
+    }
+
+    pub fn is_beneficiaries_only(env: Env, program_id: u64) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::BeneficiariesOnly(program_id))
+    }
+
+    fn ensure_beneficiary(env: &Env, program_id: u64, recipient: &Address) -> Result<(), Error> {
+        if Self::is_beneficiaries_only(env.clone(), program_id)
+            && !env
+                .storage()
+                .persistent()
+                .has(&DataKey::Beneficiary(program_id, recipient.clone()))
+        {
+            return Err(Error::BeneficiaryNotEnrolled);
+        }
+        Ok(())
+    }
+
+    /// Make `coop_id` a cooperative whose payouts are split across `members`
+    /// by `weights` (authorized by the cooperative itself). An empty member
+    /// list turns it back into a plain recipient.
+    pub fn set_coop_members(
+        env: Env,
+        coop_id: Address,
+        members: Vec<Address>,
+        weights: Vec<u32>,
+    ) -> Result<(), Error> {
+        Self::ensure_initialized(&env)?;
+        coop_id.require_auth();
+
+        if members.len() != weights.len() || members.len() > MAX_BATCH_SIZE {
+            return Err(Error::InvalidCoopMembers);
+        }
+        let key = DataKey::CoopMembers(coop_id.clone());
+        if members.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            let mut entries: Vec<CoopMember> = Vec::new(&env);
+            for (member, weight) in members.iter().zip(weights.iter()) {
+                if weight == 0
+                    || member == coop_id
+                    || entries.iter().any(|entry| entry.member == member)
+                {
+                    return Err(Error::InvalidCoopMembers);
+                }
+                entries.push_back(CoopMember { member, weight });
+            }
+            env.storage().persistent().set(&key, &entries);
+        }
+        env.events().publish(
+            (COOP_MEMBERS_SET, coop_id.clone()),
+            CoopMembersSetEvent {
+                version: EVENT_SCHEMA_VERSION,
+                sequence: Self::next_event_sequence(&env),
+                coop: coop_id,
+                members,
+                weights,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
     }
 
-    fn store_program(env: &Env, program_id: u64, program: &Program) {
+    pub fn get_coop_members(env: Env, coop_id: Address) -> Vec<CoopMember> {
         env.storage()
             .persistent()
-            .set(&DataKey::Program(program_id), program);
+            .get(&DataKey::CoopMembers(coop_id))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
 
-        match &program.jurisdiction {
-            OptionalJurisdiction::Some(config) => env
-                .storage()
-                .persistent()
-                .set(&DataKey::ProgramJurisdiction(program_id), config),
-            OptionalJurisdiction::None => {
-                env.storage()
-                    .persistent()
-                    .remove(&DataKey::ProgramJurisdiction(program_id));
+    /// Replace each cooperative recipient with its members, splitting its
+    /// amount by weight; the last member absorbs rounding dust. Emits one
+    /// `coop_pay` event per member share.
+    ///
+    /// Each member must pass the program's beneficiary check. A member listed
+    /// twice, or a share that rounds down to zero, fails the whole payout.
+    fn expand_coop_recipients(
+        env: &Env,
+        program_id: u64,
+        recipients: &Vec<Address>,
+        amounts: &Vec<i128>,
+    ) -> Result<(Vec<Address>, Vec<i128>), Error> {
+        if recipients.len() != amounts.len() {
+            return Err(Error::InvalidBatchSize);
+        }
+        let mut out_recipients = Vec::new(env);
+        let mut out_amounts = Vec::new(env);
+        for (recipient, amount) in recipients.iter().zip(amounts.iter()) {
+            let members = Self::get_coop_members(env.clone(), recipient.clone());
+            if members.is_empty() {
+                out_recipients.push_back(recipient);
+                out_amounts.push_back(amount);
+                continue;
+            }
+            let total_weight: i128 = members.iter().map(|m| m.weight as i128).sum();
+            let mut paid = 0i128;
+            let mut seen: Vec<Address> = Vec::new(env);
+            for (i, entry) in members.iter().enumerate() {
+                if seen.contains(&entry.member) {
+                    return Err(Error::InvalidCoopMembers);
+                }
+                seen.push_back(entry.member.clone());
+                Self::ensure_beneficiary(env, program_id, &entry.member)?;
+                let share = if i as u32 == members.len() - 1 {
+                    amount - paid
+                } else {
+                    amount * entry.weight as i128 / total_weight
+                };
+                if share <= 0 {
+                    return Err(Error::InvalidAmount);
+                }
+                paid += share;
+                env.events().publish(
+                    (COOP_MEMBER_PAID, recipient.clone()),
+                    CoopMemberPaidEvent {
+                        version: EVENT_SCHEMA_VERSION,
+                        sequence: Self::next_event_sequence(env),
+                        program_id,
+                        coop: recipient.clone(),
+                        member: entry.member.clone(),
+                        amount: share,
+                        timestamp: env.ledger().timestamp(),
+                    },
+                );
+                out_recipients.push_back(entry.member);
+                out_amounts.push_back(share);
             }
         }
+        Ok((out_recipients, out_amounts))
     }
 
-    fn get_deprecation_state(env: &Env) -> DeprecationState {
+    /// Confine `program_id`'s payouts and schedule releases to `windows`
+    /// (program admin only), e.g. harvest months. An empty list lifts the
+    /// restriction.
+    pub fn set_payout_windows(
+        env: Env,
+        program_id: u64,
+        windows: Vec<PayoutWindow>,
+    ) -> Result<(), Error> {
+        Self::ensure_initialized(&env)?;
+        let program = Self::get_program(env.clone(), program_id)?;
+        program.admin.require_auth();
+
+        if windows.len() > MAX_BATCH_SIZE {
+            return Err(Error::InvalidPayoutWindow);
+        }
+        for window in windows.iter() {
+            if window.start >= window.end {
+                return Err(Error::InvalidPayoutWindow);
+            }
+        }
+        let key = DataKey::PayoutWindows(program_id);
+        if windows.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &windows);
+        }
+        env.events().publish(
+            (PAYOUT_WINDOWS_SET, program_id),
+            PayoutWindowsSetEvent {
+                version: EVENT_SCHEMA_VERSION,
+                sequence: Self::next_event_sequence(&env),
+                program_id,
+                windows,
+                admin: program.admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    pub fn get_payout_windows(env: Env, program_id: u64) -> Vec<PayoutWindow> {
         env.storage()
-            .instance()
-            .get(&DataKey::DeprecationState)
-            .unwrap_or(DeprecationState {
-                deprecated: false,
-                migration_target: None,
-            })
+            .persistent()
+            .get(&DataKey::PayoutWindows(program_id))
+            .unwrap_or_else(|| Vec::new(&env))
     }
 
-    /// Initialize the contract with an admin and token address. Call once.
-    pub fn init(env: Env, admin: Address, token: Address) -> Result<(), Error> {
-        if env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::AlreadyInitialized);
+    fn ensure_payout_window(env: &Env, program_id: u64) -> Result<(), Error> {
+        let windows = Self::get_payout_windows(env.clone(), program_id);
+        if windows.is_empty() {
+            return Ok(());
+        }
+        let now = env.ledger().timestamp();
+        if windows.iter().any(|w| w.start <= now && now < w.end) {
+            Ok(())
+        } else {
+            Err(Error::OutsidePayoutWindow)
         }
-        env.storage().instance().set(&DataKey::Admin, &admin);
-        env.storage().instance().set(&DataKey::Token, &token);
-        Ok(())
     }
 
-    /// Register a single program.
-    pub fn register_program(
+    /// Arm a dead man's switch on `program_id` (program admin only): if the
+    /// admin sends no `heartbeat` for `timeout_secs`, `fallback_admin` may
+    /// claim the program. Setting the policy counts as a heartbeat.
+    pub fn set_inactivity_policy(
         env: Env,
         program_id: u64,
-        admin: Address,
-        name: String,
-        total_funding: i128,
+        timeout_secs: u64,
+        fallback_admin: Address,
     ) -> Result<(), Error> {
-        Self::register_program_juris(
-            env,
+        Self::ensure_initialized(&env)?;
+        let program = Self::get_program(env.clone(), program_id)?;
+        program.admin.require_auth();
+
+        if timeout_secs == 0 || fallback_admin == program.admin {
+            return Err(Error::InvalidInactivityPolicy);
+        }
+        let policy = InactivityPolicy {
+            timeout_secs,
+            fallback_admin,
+            last_heartbeat: env.ledger().timestamp(),
+        };
+        Self::store_inactivity_policy(
+            &env,
             program_id,
-            admin,
-            name,
-            total_funding,
-            None,
-            false,
-            None,
-            false,
-            OptionalJurisdiction::None,
-            None,
-        )
+            program.admin,
+            policy,
+            INACTIVITY_POLICY_SET,
+        );
+        Ok(())
     }
 
-    /// Register a single program with optional jurisdiction controls.
-    pub fn register_program_juris(
-        env: Env,
+    /// Prove the program admin is still active, restarting the timeout.
+    pub fn heartbeat(env: Env, program_id: u64) -> Result<(), Error> {
+        let program = Self::get_program(env.clone(), program_id)?;
+        program.admin.require_auth();
+
+        let mut policy = Self::get_inactivity_policy(env.clone(), program_id)
+            .ok_or(Error::InactivityPolicyNotSet)?;
+        policy.last_heartbeat = env.ledger().timestamp();
+        Self::store_inactivity_policy(&env, program_id, program.admin, policy, ADMIN_HEARTBEAT);
+        Ok(())
+    }
+
+    /// Hand `program_id` to its fallback admin once the admin has missed the
+    /// heartbeat timeout (fallback admin only). The policy is cleared; the
+    /// new admin can arm a fresh one.
+    pub fn claim_program_admin(env: Env, program_id: u64) -> Result<(), Error> {
+        let mut program = Self::get_program(env.clone(), program_id)?;
+        let policy = Self::get_inactivity_policy(env.clone(), program_id)
+            .ok_or(Error::InactivityPolicyNotSet)?;
+        policy.fallback_admin.require_auth();
+
+        let now = env.ledger().timestamp();
+        if now < policy.last_heartbeat.saturating_add(policy.timeout_secs) {
+            return Err(Error::AdminStillActive);
+        }
+        program.admin = policy.fallback_admin.clone();
+        Self::store_program(&env, program_id, &program);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::InactivityPolicy(program_id));
+        env.events().publish(
+            (ADMIN_CLAIMED, program_id),
+            InactivityEvent {
+                version: EVENT_SCHEMA_VERSION,
+                sequence: Self::next_event_sequence(&env),
+                program_id,
+                admin: program.admin,
+                policy,
+                timestamp: now,
+            },
+        );
+        Ok(())
+    }
+
+    pub fn get_inactivity_policy(env: Env, program_id: u64) -> Option<InactivityPolicy> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::InactivityPolicy(program_id))
+    }
+
+    fn store_inactivity_policy(
+        env: &Env,
         program_id: u64,
         admin: Address,
-        name: String,
-        total_funding: i128,
-        juris_tag: Option<String>,
-        juris_requires_kyc: bool,
-        juris_max_funding: Option<i128>,
-        juris_registration_paused: bool,
-        jurisdiction: OptionalJurisdiction,
-        kyc_attested: Option<bool>,
-    ) -> Result<(), Error> {
-        Self::ensure_initialized(&env)?;
-        Self::ensure_not_deprecated(&env)?;
-        Self::require_contract_admin(&env);
+        policy: InactivityPolicy,
+        topic: soroban_sdk::Symbol,
+    ) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::InactivityPolicy(program_id), &policy);
+        env.events().publish(
+            (topic, program_id),
+            InactivityEvent {
+                version: EVENT_SCHEMA_VERSION,
+                sequence: Self::next_event_sequence(env),
+                program_id,
+                admin,
+                timestamp: policy.last_heartbeat,
+                policy,
+            },
+        );
+    }
 
-        if env
-            .storage()
+    /// Summarize `program_id`'s fund movements between `from_ts` and `to_ts`
+    /// (inclusive) for regulatory filings.
+    ///
+    /// Reports are paged over the log: `cursor` is `None` for the first page
+    /// and the previous report's `next_cursor` after that, and `limit`
+    /// follows the `get_programs` rules. Totals cover the page only, so a
+    /// filing adds the pages up.
+    pub fn generate_compliance_report(
+        env: Env,
+        program_id: u64,
+        from_ts: u64,
+        to_ts: u64,
+        cursor: Option<u32>,
+        limit: u32,
+    ) -> Result<ComplianceReport, Error> {
+        let program = Self::get_program(env.clone(), program_id)?;
+        if from_ts > to_ts {
+            return Err(Error::InvalidReportRange);
+        }
+
+        let mut report = ComplianceReport {
+            program_id,
+            from_ts,
+            to_ts,
+            total_inflows: 0,
+            total_outflows: 0,
+            inflow_count: 0,
+            outflow_count: 0,
+            kyc_gated_operations: 0,
+            jurisdiction_tags: Vec::new(&env),
+            breaches: 0,
+            current_funding: program.total_funding,
+            generated_at: env.ledger().timestamp(),
+            next_cursor: None,
+        };
+
+        let len = Self::get_compliance_log_len(env.clone(), program_id);
+        // Entries are appended in ledger order, so their timestamps never
+        // decrease and the window's first entry can be found by bisection.
+        let mut index = match cursor {
+            Some(cursor) => cursor,
+            None => {
+                let (mut lo, mut hi) = (0u32, len);
+                while lo < hi {
+                    let mid = lo + (hi - lo) / 2;
+                    if Self::compliance_entry(&env, program_id, mid).timestamp < from_ts {
+                        lo = mid + 1;
+                    } else {
+                        hi = mid;
+                    }
+                }
+                lo
+            }
+        };
+        let end = index.saturating_add(Self::page_limit(limit)).min(len);
+        while index < end {
+            let entry = Self::compliance_entry(&env, program_id, index);
+            if entry.timestamp > to_ts {
+                return Ok(report);
+            }
+            index += 1;
+            match entry.kind {
+                FlowKind::Inflow => {
+                    report.total_inflows += entry.amount;
+                    report.inflow_count += 1;
+                }
+                FlowKind::Outflow => {
+                    report.total_outflows += entry.amount;
+                    report.outflow_count += 1;
+                }
+            }
+            if entry.kyc_gated {
+                report.kyc_gated_operations += 1;
+            }
+            if entry.breach {
+                report.breaches += 1;
+            }
+            if let Some(tag) = entry.jurisdiction_tag {
+                if !report.jurisdiction_tags.contains(&tag) {
+                    report.jurisdiction_tags.push_back(tag);
+                }
+            }
+        }
+        if index < len && Self::compliance_entry(&env, program_id, index).timestamp <= to_ts {
+            report.next_cursor = Some(index);
+        }
+        Ok(report)
+    }
+
+    /// Number of fund movements recorded for `program_id`.
+    pub fn get_compliance_log_len(env: Env, program_id: u64) -> u32 {
+        env.storage()
             .persistent()
-            .has(&DataKey::Program(program_id))
-        {
-            return Err(Error::ProgramExists);
+            .get(&DataKey::ComplianceLogLen(program_id))
+            .unwrap_or(0)
+    }
+
+    /// Up to `limit` fund movements of `program_id` from index `start`,
+    /// oldest first. `limit` follows the `get_programs` rules.
+    pub fn get_compliance_log(
+        env: Env,
+        program_id: u64,
+        start: u32,
+        limit: u32,
+    ) -> Vec<ComplianceEntry> {
+        let len = Self::get_compliance_log_len(env.clone(), program_id);
+        let end = start.saturating_add(Self::page_limit(limit)).min(len);
+        let mut entries = Vec::new(&env);
+        for index in start..end {
+            entries.push_back(Self::compliance_entry(&env, program_id, index));
+        }
+        entries
+    }
+
+    fn page_limit(limit: u32) -> u32 {
+        if limit == 0 || limit > MAX_PAGE_SIZE {
+            MAX_PAGE_SIZE
+        } else {
+            limit
         }
+    }
 
-        Self::validate_program_input(&name, total_funding)?;
+    fn compliance_entry(env: &Env, program_id: u64, index: u32) -> ComplianceEntry {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ComplianceEntry(program_id, index))
+            .unwrap()
+    }
 
-        let jurisdiction = Self::build_jurisdiction(
-            juris_tag,
-            juris_requires_kyc,
-            juris_max_funding,
-            juris_registration_paused,
-            jurisdiction,
+    fn record_flow(env: &Env, program_id: u64, program: &Program, kind: FlowKind, amount: i128) {
+        let (jurisdiction_tag, kyc_gated, breach) = match &program.jurisdiction {
+            OptionalJurisdiction::Some(config) => (
+                config.tag.clone(),
+                config.requires_kyc,
+                config
+                    .max_funding
+                    .is_some_and(|max| program.total_funding > max),
+            ),
+            OptionalJurisdiction::None => (None, false, false),
+        };
+        let index = Self::get_compliance_log_len(env.clone(), program_id);
+        env.storage().persistent().set(
+            &DataKey::ComplianceEntry(program_id, index),
+            &ComplianceEntry {
+                kind,
+                amount,
+                timestamp: env.ledger().timestamp(),
+                jurisdiction_tag,
+                kyc_gated,
+                breach,
+            },
         );
-        Self::enforce_jurisdiction_rules(&jurisdiction, total_funding, kyc_attested)?;
+        env.storage()
+            .persistent()
+            .set(&DataKey::ComplianceLogLen(program_id), &(index + 1));
+    }
 
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let token_client = token::Client::new(&env, &token_addr);
-        admin.require_auth();
-        token_client.transfer(&admin, &env.current_contract_address(), &total_funding);
+    /// Log a payout or schedule forwarded to the payout contract.
+    fn record_outflow(env: &Env, program_id: u64, amount: i128) -> Result<(), Error> {
+        let program = Self::get_program(env.clone(), program_id)?;
+        Self::record_flow(env, program_id, &program, FlowKind::Outflow, amount);
+        Ok(())
+    }
 
-        let program = Program {
-            admin: admin.clone(),
-            name,
-            total_funding,
-            status: ProgramStatus::Active,
-            jurisdiction: jurisdiction.clone(),
-        };
-        Self::store_program(&env, program_id, &program);
-        Self::append_program_id(&env, program_id);
-        Self::emit_program_registered(&env, program_id, admin, total_funding, &jurisdiction);
+    /// Forward to the linked contract's `batch_payout`, charging the total to
+    /// `category` (program admin only). Cooperative recipients fan out to
+    /// their members.
+    pub fn program_batch_payout(
+        env: Env,
+        program_id: u64,
+        category: String,
+        recipients: Vec<Address>,
+        amounts: Vec<i128>,
+    ) -> Result<PayoutProgramData, Error> {
+        let client = Self::payout_client(&env, program_id)?;
+        Self::get_program(env.clone(), program_id)?
+            .admin
+            .require_auth();
+        Self::ensure_payout_window(&env, program_id)?;
+        for recipient in recipients.iter() {
+            Self::ensure_beneficiary(&env, program_id, &recipient)?;
+        }
+        let mut total = 0i128;
+        for amount in amounts.iter() {
+            total = total.checked_add(amount).ok_or(Error::InvalidAmount)?;
+        }
+        Self::charge_budget(&env, program_id, &category, total)?;
+        Self::record_outflow(&env, program_id, total)?;
+        Self::collect_payout_fee(&env, program_id, total)?;
+        let (recipients, amounts) =
+            Self::expand_coop_recipients(&env, program_id, &recipients, &amounts)?;
+        Ok(client.batch_payout(&recipients, &amounts))
+    }
+
+    /// Forward to the linked contract's `single_payout`, charging `category`
+    /// (program admin only). A cooperative recipient is paid through one
+    /// `batch_payout` to its members instead.
+    pub fn program_single_payout(
+        env: Env,
+        program_id: u64,
+        category: String,
+        recipient: Address,
+        amount: i128,
+    ) -> Result<PayoutProgramData, Error> {
+        let client = Self::payout_client(&env, program_id)?;
+        Self::get_program(env.clone(), program_id)?
+            .admin
+            .require_auth();
+        Self::ensure_payout_window(&env, program_id)?;
+        Self::ensure_beneficiary(&env, program_id, &recipient)?;
+        Self::charge_budget(&env, program_id, &category, amount)?;
+        Self::record_outflow(&env, program_id, amount)?;
+        Self::collect_payout_fee(&env, program_id, amount)?;
+        if Self::get_coop_members(env.clone(), recipient.clone()).is_empty() {
+            return Ok(client.single_payout(&recipient, &amount));
+        }
+        let (recipients, amounts) = Self::expand_coop_recipients(
+            &env,
+            program_id,
+            &vec![&env, recipient],
+            &vec![&env, amount],
+        )?;
+        Ok(client.batch_payout(&recipients, &amounts))
+    }
+
+    /// Forward to the linked contract's `create_program_release_schedule`
+    /// (program admin only). A cooperative recipient gets one schedule per
+    /// member, split by weight as for payouts; the schedules are returned in
+    /// member order.
+    ///
+    /// The amount is charged to `category`, and the payout fee taken, when
+    /// the schedule is created, so releasing it later does not count twice.
+    pub fn program_create_schedule(
+        env: Env,
+        program_id: u64,
+        category: String,
+        recipient: Address,
+        amount: i128,
+        release_timestamp: u64,
+    ) -> Result<Vec<PayoutReleaseSchedule>, Error> {
+        let client = Self::payout_client(&env, program_id)?;
+        Self::get_program(env.clone(), program_id)?
+            .admin
+            .require_auth();
+        Self::create_schedules(
+            &env,
+            &client,
+            program_id,
+            &category,
+            &recipient,
+            amount,
+            release_timestamp,
+        )
+    }
+
+    fn create_schedules(
+        env: &Env,
+        client: &PayoutContractClient,
+        program_id: u64,
+        category: &String,
+        recipient: &Address,
+        amount: i128,
+        release_timestamp: u64,
+    ) -> Result<Vec<PayoutReleaseSchedule>, Error> {
+        Self::ensure_beneficiary(env, program_id, recipient)?;
+        Self::charge_budget(env, program_id, category, amount)?;
+        Self::record_outflow(env, program_id, amount)?;
+        Self::collect_payout_fee(env, program_id, amount)?;
+        let (recipients, amounts) = Self::expand_coop_recipients(
+            env,
+            program_id,
+            &vec![env, recipient.clone()],
+            &vec![env, amount],
+        )?;
+        let mut schedules = Vec::new(env);
+        for (payee, share) in recipients.iter().zip(amounts.iter()) {
+            schedules.push_back(client.create_program_release_schedule(
+                &payee,
+                &share,
+                &release_timestamp,
+            ));
+        }
+        Ok(schedules)
+    }
+
+    /// Forward to the linked contract's `release_prog_schedule_automatic`
+    /// (program admin only). Rejected outside the program's payout windows.
+    pub fn program_release_schedule(
+        env: Env,
+        program_id: u64,
+        schedule_id: u64,
+    ) -> Result<(), Error> {
+        let client = Self::payout_client(&env, program_id)?;
+        Self::get_program(env.clone(), program_id)?
+            .admin
+            .require_auth();
+        Self::ensure_payout_window(&env, program_id)?;
+        client.release_prog_schedule_automatic(&schedule_id);
         Ok(())
     }
 
-    /// Backward-compatible alias.
-    pub fn register_prog_w_juris(
+    pub fn program_schedules(
+        env: Env,
+        program_id: u64,
+    ) -> Result<Vec<PayoutReleaseSchedule>, Error> {
+        Ok(Self::payout_client(&env, program_id)?.get_program_release_schedules())
+    }
+
+    pub fn program_payout_info(env: Env, program_id: u64) -> Result<PayoutProgramData, Error> {
+        Ok(Self::payout_client(&env, program_id)?.get_program_info())
+    }
+
+    pub fn program_remaining_balance(env: Env, program_id: u64) -> Result<i128, Error> {
+        Ok(Self::payout_client(&env, program_id)?.get_remaining_balance())
+    }
+
+    /// Forward to the linked contract's `get_program_reputation`.
+    pub fn program_reputation(
+        env: Env,
+        program_id: u64,
+    ) -> Result<PayoutReputationScore, Error> {
+        Ok(Self::payout_client(&env, program_id)?.get_program_reputation())
+    }
+
+    pub fn program_threshold_config(
         env: Env,
         program_id: u64,
-        admin: Address,
-        name: String,
-        total_funding: i128,
-        juris_tag: Option<String>,
-        juris_requires_kyc: bool,
-        juris_max_funding: Option<i128>,
-        juris_registration_paused: bool,
-        jurisdiction: OptionalJurisdiction,
-        kyc_attested: Option<bool>,
-    ) -> Result<(), Error> {
-        Self::register_program_juris(
-            env,
-            program_id,
-            admin,
-            name,
-            total_funding,
-            juris_tag,
-            juris_requires_kyc,
-            juris_max_funding,
-            juris_registration_paused,
-            jurisdiction,
-            kyc_attested,
-        )
+    ) -> Result<PayoutThresholdConfig, Error> {
+        Ok(Self::payout_client(&env, program_id)?.get_threshold_config())
     }
 
-    /// Batch register multiple programs in a single transaction.
-    pub fn batch_register_programs(
+    /// Forward to the linked contract's `set_threshold_config`; the payout
+    /// contract's admin must authorize the nested call.
+    pub fn program_set_threshold_config(
         env: Env,
-        items: Vec<ProgramRegistrationItem>,
-    ) -> Result<u32, Error> {
-        let batch_size = items.len() as u32;
-        if batch_size == 0 || batch_size > MAX_BATCH_SIZE {
-            return Err(Error::InvalidBatchSize);
-        }
+        program_id: u64,
+        config: PayoutThresholdConfig,
+    ) -> Result<(), Error> {
+        Self::payout_client(&env, program_id)?.set_threshold_config(&config);
+        Ok(())
+    }
 
+    /// Set or replace the protocol fee configuration (admin only).
+    pub fn set_fee_config(env: Env, config: FeeConfig) -> Result<(), Error> {
         Self::ensure_initialized(&env)?;
-        Self::ensure_not_deprecated(&env)?;
         Self::require_contract_admin(&env);
 
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let token_client = token::Client::new(&env, &token_addr);
-        let contract_address = env.current_contract_address();
-        let ordered_items = Self::order_batch_registration_items(&env, &items);
-
-        for item in ordered_items.iter() {
-            if env
-                .storage()
-                .persistent()
-                .has(&DataKey::Program(item.program_id))
-            {
-                return Err(Error::ProgramExists);
-            }
-            Self::validate_program_input(&item.name, item.total_funding)?;
-
-            let mut count = 0u32;
-            for other in ordered_items.iter() {
-                if other.program_id == item.program_id {
-                    count += 1;
-                }
-            }
-            if count > 1 {
-                return Err(Error::DuplicateProgramId);
+        for rate in [config.registration_fee_rate, config.payout_fee_rate] {
+            if !(0..=MAX_FEE_RATE).contains(&rate) {
+                return Err(Error::InvalidFeeRate);
             }
         }
 
-        let mut seen_admins: Vec<Address> = Vec::new(&env);
-        for item in ordered_items.iter() {
-            let mut found = false;
-            for seen in seen_admins.iter() {
-                if seen == item.admin {
-                    found = true;
-                    break;
-                }
+        env.storage().instance().set(&DataKey::FeeConfig, &config);
+        Ok(())
+    }
+
+    /// Current fee configuration, or `None` if fees were never configured.
+    pub fn get_fee_config(env: Env) -> Option<FeeConfig> {
+        env.storage().instance().get(&DataKey::FeeConfig)
+    }
+
+    /// Replace the tiered fee table (admin only).
+    ///
+    /// Thresholds must be strictly ascending and positive, and every rate must
+    /// be within `MAX_FEE_RATE`. Pass an empty vector to fall back to the flat
+    /// rates in `FeeConfig`. The per-fee-type toggles still apply.
+    pub fn set_fee_tiers(env: Env, tiers: Vec<FeeTier>) -> Result<(), Error> {
+        Self::ensure_initialized(&env)?;
+        Self::require_contract_admin(&env);
+
+        let mut previous_threshold = 0i128;
+        for tier in tiers.iter() {
+            if tier.threshold <= previous_threshold {
+                return Err(Error::InvalidFeeTiers);
             }
-            if !found {
-                seen_admins.push_back(item.admin.clone());
-                item.admin.require_auth();
+            if !(0..=MAX_FEE_RATE).contains(&tier.fee_rate) {
+                return Err(Error::InvalidFeeRate);
             }
+            previous_threshold = tier.threshold;
         }
 
-        let mut registered_count = 0u32;
-        for item in ordered_items.iter() {
-            token_client.transfer(&item.admin, &contract_address, &item.total_funding);
+        env.storage().instance().set(&DataKey::FeeTiers, &tiers);
+        Ok(())
+    }
 
-            let program = Program {
-                admin: item.admin.clone(),
-                name: item.name.clone(),
-                total_funding: item.total_funding,
-                status: ProgramStatus::Active,
-                jurisdiction: OptionalJurisdiction::None,
-            };
-            Self::store_program(&env, item.program_id, &program);
-            Self::append_program_id(&env, item.program_id);
-            Self::emit_program_registered(
-                &env,
-                item.program_id,
-                item.admin.clone(),
-                item.total_funding,
-                &OptionalJurisdiction::None,
-            );
-            registered_count += 1;
-        }
+    /// Current tiered fee table (empty when flat rates are in use).
+    pub fn get_fee_tiers(env: Env) -> Vec<FeeTier> {
+        env.storage()
+            .instance()
+            .get(&DataKey::FeeTiers)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
 
-        Ok(registered_count)
+    /// Registration fee rate that would apply to a program of `program_size`.
+    pub fn get_registration_fee_rate(env: Env, program_size: i128) -> i128 {
+        let base_rate = env
+            .storage()
+            .instance()
+            .get::<_, FeeConfig>(&DataKey::FeeConfig)
+            .map(|config| config.registration_fee_rate)
+            .unwrap_or(0);
+        Self::effective_fee_rate(&env, base_rate, program_size)
     }
 
-    /// Batch register programs with optional jurisdiction controls.
-    pub fn batch_register_juris(
-        env: Env,
-        items: Vec<ProgramRegistrationWithJurisdictionItem>,
-    ) -> Result<u32, Error> {
-        let batch_size = items.len() as u32;
-        if batch_size == 0 || batch_size > MAX_BATCH_SIZE {
-            return Err(Error::InvalidBatchSize);
-        }
+    /// Fees accumulated in the internal treasury.
+    pub fn get_treasury_balance(env: Env) -> i128 {
+        Self::treasury_balance(&env)
+    }
+
+    /// Treasury withdrawal currently waiting out its timelock, if any.
+    pub fn get_pending_treasury_withdrawal(env: Env) -> Option<PendingTreasuryWithdrawal> {
+        env.storage()
+            .instance()
+            .get(&DataKey::PendingTreasuryWithdrawal)
+    }
 
+    /// Queue a treasury withdrawal (admin only).
+    ///
+    /// The withdrawal becomes executable via `withdraw_treasury` after
+    /// `TREASURY_TIMELOCK_SECS`. A new request replaces any pending one.
+    pub fn request_treasury_withdrawal(env: Env, to: Address, amount: i128) -> Result<(), Error> {
         Self::ensure_initialized(&env)?;
-        Self::ensure_not_deprecated(&env)?;
-        Self::require_contract_admin(&env);
+        let admin = Self::require_contract_admin(&env);
 
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let token_client = token::Client::new(&env, &token_addr);
-        let contract_address = env.current_contract_address();
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if amount > Self::treasury_balance(&env) {
+            return Err(Error::InsufficientTreasuryBalance);
+        }
 
-        for item in items.iter() {
-            if env
-                .storage()
-                .persistent()
-                .has(&DataKey::Program(item.program_id))
-            {
-                return Err(Error::ProgramExists);
-            }
-            Self::validate_program_input(&item.name, item.total_funding)?;
+        let now = env.ledger().timestamp();
+        let pending = PendingTreasuryWithdrawal {
+            to: to.clone(),
+            amount,
+            executable_at: now + TREASURY_TIMELOCK_SECS,
+        };
+        env.storage()
+            .instance()
+            .set(&DataKey::PendingTreasuryWithdrawal, &pending);
 
-            let jurisdiction = Self::build_jurisdiction(
-                item.juris_tag.clone(),
-                item.juris_requires_kyc,
-                item.juris_max_funding,
-                item.juris_registration_paused,
-                item.jurisdiction.clone(),
-            );
-            Self::enforce_jurisdiction_rules(
-                &jurisdiction,
-                item.total_funding,
-                item.kyc_attested,
-            )?;
+        env.events().publish(
+            (TREASURY_WITHDRAWAL_REQUESTED,),
+            TreasuryWithdrawalEvent {
+                version: EVENT_SCHEMA_VERSION,
+                sequence: Self::next_event_sequence(&env),
+                to,
+                amount,
+                executable_at: pending.executable_at,
+                admin,
+                timestamp: now,
+            },
+        );
+        Ok(())
+    }
 
-            let mut count = 0u32;
-            for other in items.iter() {
-                if other.program_id == item.program_id {
-                    count += 1;
-                }
-            }
-            if count > 1 {
-                return Err(Error::DuplicateProgramId);
-            }
-        }
+    /// Execute the pending treasury withdrawal (admin only).
+    ///
+    /// `to` and `amount` must match the queued request and its timelock must
+    /// have elapsed.
+    pub fn withdraw_treasury(env: Env, to: Address, amount: i128) -> Result<(), Error> {
+        Self::ensure_initialized(&env)?;
+        let admin = Self::require_contract_admin(&env);
 
-        let mut seen_admins: Vec<Address> = Vec::new(&env);
-        for item in items.iter() {
-            let mut found = false;
-            for seen in seen_admins.iter() {
-                if seen == item.admin {
-                    found = true;
-                    break;
-                }
-            }
-            if !found {
-                seen_admins.push_back(item.admin.clone());
-                item.admin.require_auth();
-            }
+        let pending: PendingTreasuryWithdrawal = env
+            .storage()
+            .instance()
+            .get(&DataKey::PendingTreasuryWithdrawal)
+            .ok_or(Error::NoPendingWithdrawal)?;
+        if pending.to != to || pending.amount != amount {
+            return Err(Error::NoPendingWithdrawal);
         }
 
-        let mut registered_count = 0u32;
-        for item in items.iter() {
-            token_client.transfer(&item.admin, &contract_address, &item.total_funding);
+        let now = env.ledger().timestamp();
+        if now < pending.executable_at {
+            return Err(Error::TimelockNotElapsed);
+        }
 
-            let jurisdiction = Self::build_jurisdiction(
-                item.juris_tag.clone(),
-                item.juris_requires_kyc,
-                item.juris_max_funding,
-                item.juris_registration_paused,
-                item.jurisdiction.clone(),
-            );
-            let program = Program {
-                admin: item.admin.clone(),
-                name: item.name.clone(),
-                total_funding: item.total_funding,
-                status: ProgramStatus::Active,
-                jurisdiction: jurisdiction.clone(),
-            };
-            Self::store_program(&env, item.program_id, &program);
-            Self::append_program_id(&env, item.program_id);
-            Self::emit_program_registered(
-                &env,
-                item.program_id,
-                item.admin.clone(),
-                item.total_funding,
-                &jurisdiction,
-            );
-            registered_count += 1;
+        let balance = Self::treasury_balance(&env);
+        if amount > balance {
+            return Err(Error::InsufficientTreasuryBalance);
         }
 
-        Ok(registered_count)
-    }
+        env.storage()
+            .instance()
+            .set(&DataKey::TreasuryBalance, &(balance - amount));
+        env.storage()
+            .instance()
+            .remove(&DataKey::PendingTreasuryWithdrawal);
 
-    /// Backward-compatible alias.
-    pub fn batch_reg_progs_w_juris(
-        env: Env,
-        items: Vec<ProgramRegistrationWithJurisdictionItem>,
-    ) -> Result<u32, Error> {
-        Self::batch_register_juris(env, items)
-    }
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        token::Client::new(&env, &token_addr).transfer(
+            &env.current_contract_address(),
+            &to,
+            &amount,
+        );
 
-    /// Read a program's state.
-    pub fn get_program(env: Env, program_id: u64) -> Result<Program, Error> {
-        env.storage()
-            .persistent()
-            .get(&DataKey::Program(program_id))
-            .ok_or(Error::ProgramNotFound)
+        env.events().publish(
+            (TREASURY_WITHDRAWN,),
+            TreasuryWithdrawalEvent {
+                version: EVENT_SCHEMA_VERSION,
+                sequence: Self::next_event_sequence(&env),
+                to,
+                amount,
+                executable_at: pending.executable_at,
+                admin,
+                timestamp: now,
+            },
+        );
+        Ok(())
     }
 
     /// Set deprecation and optional migration target.
@@ -687,13 +3483,15 @@ impl ProgramEscrowContract {
         };
         env.storage().instance().set(&DataKey::DeprecationState, &state);
         env.events().publish(
-            (symbol_short!("deprec"),),
-            (
-                state.deprecated,
-                state.migration_target,
+            (DEPRECATION_CHANGED,),
+            DeprecationChangedEvent {
+                version: EVENT_SCHEMA_VERSION,
+                sequence: Self::next_event_sequence(&env),
+                deprecated: state.deprecated,
+                migration_target: state.migration_target,
                 admin,
-                env.ledger().timestamp(),
-            ),
+                timestamp: env.ledger().timestamp(),
+            },
         );
         Ok(())
     }
@@ -702,12 +3500,24 @@ impl ProgramEscrowContract {
         Self::get_deprecation_state(&env)
     }
 
+    /// Return the schema version stamped on every event this contract emits.
+    ///
+    /// Decoders should read this once and branch on it instead of assuming a
+    /// payload layout; it only changes when an event struct changes shape.
+    pub fn get_event_schema_version(_env: Env) -> u32 {
+        EVENT_SCHEMA_VERSION
+    }
+
     /// Read the stored jurisdiction config for a program.
     pub fn get_program_jurisdiction(
         env: Env,
         program_id: u64,
     ) -> Result<Option<ProgramJurisdictionConfig>, Error> {
-        if !env.storage().persistent().has(&DataKey::Program(program_id)) {
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::Program(program_id))
+        {
             return Err(Error::ProgramNotFound);
         }
         Ok(env
@@ -826,4 +3636,20 @@ impl ProgramEscrowContract {
 #[cfg(test)]
 mod test;
 #[cfg(test)]
+mod test_beneficiaries;
+#[cfg(test)]
+mod test_bounty_link;
+#[cfg(test)]
+mod test_budget_categories;
+#[cfg(test)]
+mod test_compliance_report;
+#[cfg(test)]
+mod test_coop_accounts;
+#[cfg(test)]
+mod test_inactivity;
+#[cfg(test)]
+mod test_payout_compat;
+#[cfg(test)]
+mod test_payout_windows;
+#[cfg(test)]
 mod test_search;
@@ -0,0 +1,105 @@
+//! Cross-contract view of the payout-side program escrow
+//! (`contracts/program-escrow`).
+//!
+//! That contract runs one program per instance and owns payouts, release
+//! schedules, reputation, and circuit-breaker thresholds; this contract registers many
+//! programs by `u64` id. A program admin links their payout instance with
+//! `link_payout_contract`, after which the forwarding entrypoints on
+//! `ProgramEscrowContract` reach it through `PayoutContractClient`.
+//!
+//! The structs below mirror the payout contract's types field for field.
+//! Contract types are encoded as maps keyed by field name, so only the field
+//! names, order, and types have to match; keep them in step with
+//! `contracts/program-escrow/src/lib.rs`, `reputation.rs`, and
+//! `threshold_monitor.rs`.
+//!
+//! Auth is checked by the payout contract: its `authorized_payout_key` (or
+//! admin, for thresholds) must authorize the nested call.
+
+use soroban_sdk::{contractclient, contracttype, Address, Bytes, Env, String, Vec};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutRecord {
+    pub recipient: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+/// Mirror of the payout contract's `ProgramData`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutProgramData {
+    pub program_id: String,
+    pub total_funds: i128,
+    pub remaining_balance: i128,
+    pub authorized_payout_key: Address,
+    pub payout_history: Vec<PayoutRecord>,
+    pub token_address: Address,
+    pub initial_liquidity: i128,
+    pub risk_flags: u32,
+    pub reference_hash: Option<Bytes>,
+}
+
+/// Mirror of the payout contract's `ProgramReleaseSchedule`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutReleaseSchedule {
+    pub schedule_id: u64,
+    pub recipient: Address,
+    pub amount: i128,
+    pub release_timestamp: u64,
+    pub released: bool,
+    pub released_at: Option<u64>,
+    pub released_by: Option<Address>,
+}
+
+/// Mirror of the payout contract's `ThresholdConfig`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutThresholdConfig {
+    pub failure_rate_threshold: u32,
+    pub outflow_volume_threshold: i128,
+    pub max_single_payout: i128,
+    pub time_window_secs: u64,
+    pub cooldown_period_secs: u64,
+    pub cooldown_multiplier: u32,
+}
+
+/// Mirror of the payout contract's `ProgramReputationScore`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutReputationScore {
+    pub total_payouts: u32,
+    pub total_scheduled: u32,
+    pub completed_releases: u32,
+    pub pending_releases: u32,
+    pub overdue_releases: u32,
+    pub dispute_count: u32,
+    pub refund_count: u32,
+    pub total_funds_locked: i128,
+    pub total_funds_distributed: i128,
+    pub completion_rate_bps: u32,
+    pub payout_fulfillment_rate_bps: u32,
+    pub overall_score_bps: u32,
+}
+
+/// Entrypoints of the payout contract reachable from this contract.
+#[contractclient(name = "PayoutContractClient")]
+pub trait PayoutContract {
+    fn batch_payout(env: Env, recipients: Vec<Address>, amounts: Vec<i128>) -> PayoutProgramData;
+    fn single_payout(env: Env, recipient: Address, amount: i128) -> PayoutProgramData;
+    fn create_program_release_schedule(
+        env: Env,
+        recipient: Address,
+        amount: i128,
+        release_timestamp: u64,
+    ) -> PayoutReleaseSchedule;
+    fn release_prog_schedule_automatic(env: Env, schedule_id: u64);
+    fn get_program_release_schedules(env: Env) -> Vec<PayoutReleaseSchedule>;
+    fn get_program_info(env: Env) -> PayoutProgramData;
+    fn get_remaining_balance(env: Env) -> i128;
+    fn get_program_reputation(env: Env) -> PayoutReputationScore;
+    fn get_threshold_config(env: Env) -> PayoutThresholdConfig;
+    fn set_threshold_config(env: Env, config: PayoutThresholdConfig);
+}
@@ -1228,3 +1228,1185 @@ fn test_deprecated_contract_blocks_registration_paths() {
     let batch_res = client.try_batch_register_programs(&batch);
     assert!(batch_res.is_err());
 }
+
+// ==================== EVENT SCHEMA VERSIONING ====================
+
+#[test]
+fn test_event_schema_version_stamped_on_all_events() {
+    use soroban_sdk::testutils::Events as _;
+    use soroban_sdk::TryIntoVal;
+
+    setup!(
+        env,
+        client,
+        _contract_id,
+        admin,
+        program_admin,
+        _token_client,
+        _token_admin,
+        10_000i128
+    );
+
+    let schema_version = client.get_event_schema_version();
+    assert_eq!(schema_version, EVENT_SCHEMA_VERSION);
+
+    client.register_program(
+        &1,
+        &program_admin,
+        &String::from_str(&env, "Versioned"),
+        &5_000,
+    );
+    let registered: ProgramRegisteredEvent = env
+        .events()
+        .all()
+        .last()
+        .unwrap()
+        .2
+        .try_into_val(&env)
+        .unwrap();
+    assert_eq!(registered.version, schema_version);
+
+    client.set_deprecated(&true, &None);
+    let deprecated: DeprecationChangedEvent = env
+        .events()
+        .all()
+        .last()
+        .unwrap()
+        .2
+        .try_into_val(&env)
+        .unwrap();
+    assert_eq!(deprecated.version, schema_version);
+    assert_eq!(schema_version, 3);
+    assert!(deprecated.sequence > registered.sequence);
+    assert!(deprecated.deprecated);
+    assert_eq!(deprecated.migration_target, None);
+    assert_eq!(deprecated.admin, admin);
+}
+
+// ==================== FEES ====================
+
+#[test]
+fn test_registration_fee_deducted_and_sent_to_recipient() {
+    use soroban_sdk::testutils::Events as _;
+    use soroban_sdk::TryIntoVal;
+
+    setup!(
+        env,
+        client,
+        contract_id,
+        admin,
+        program_admin,
+        token_client,
+        token_admin,
+        10_000i128
+    );
+    let fee_recipient = Address::generate(&env);
+    client.set_fee_config(&FeeConfig {
+        registration_fee_rate: 200,
+        payout_fee_rate: 0,
+        fee_recipient: fee_recipient.clone(),
+        registration_fee_enabled: true,
+        payout_fee_enabled: false,
+    });
+
+    client.register_program(
+        &1,
+        &program_admin,
+        &String::from_str(&env, "Fee Grant"),
+        &5_000,
+    );
+    // The fee event is emitted just before `prg_reg`.
+    let events = env.events().all();
+    let fee_event: FeeCollectedEvent = events
+        .get(events.len() - 2)
+        .unwrap()
+        .2
+        .try_into_val(&env)
+        .unwrap();
+
+    // 2% of 5_000 = 100
+    assert_eq!(client.get_program(&1).total_funding, 4_900);
+    assert_eq!(token_client.balance(&fee_recipient), 100);
+    assert_eq!(token_client.balance(&contract_id), 4_900);
+    assert_eq!(fee_event.operation_type, FeeOperationType::Registration);
+    assert_eq!(fee_event.program_id, 1);
+    assert_eq!(fee_event.amount, 100);
+    assert_eq!(fee_event.fee_rate, 200);
+    assert_eq!(fee_event.recipient, fee_recipient);
+}
+
+#[test]
+fn test_registration_fee_toggle_disabled_charges_nothing() {
+    setup!(
+        env,
+        client,
+        contract_id,
+        admin,
+        program_admin,
+        token_client,
+        token_admin,
+        10_000i128
+    );
+    let fee_recipient = Address::generate(&env);
+    client.set_fee_config(&FeeConfig {
+        registration_fee_rate: 500,
+        payout_fee_rate: 500,
+        fee_recipient: fee_recipient.clone(),
+        registration_fee_enabled: false,
+        payout_fee_enabled: true,
+    });
+
+    client.register_program(
+        &1,
+        &program_admin,
+        &String::from_str(&env, "No Fee"),
+        &5_000,
+    );
+
+    assert_eq!(client.get_program(&1).total_funding, 5_000);
+    assert_eq!(token_client.balance(&fee_recipient), 0);
+    assert_eq!(token_client.balance(&contract_id), 5_000);
+}
+
+#[test]
+fn test_batch_registration_charges_fee_per_program() {
+    setup!(
+        env,
+        client,
+        contract_id,
+        admin,
+        program_admin,
+        token_client,
+        token_admin,
+        10_000i128
+    );
+    let fee_recipient = Address::generate(&env);
+    client.set_fee_config(&FeeConfig {
+        registration_fee_rate: 100,
+        payout_fee_rate: 0,
+        fee_recipient: fee_recipient.clone(),
+        registration_fee_enabled: true,
+        payout_fee_enabled: false,
+    });
+
+    let batch = vec![
+        &env,
+        ProgramRegistrationItem {
+            program_id: 1,
+            admin: program_admin.clone(),
+            name: String::from_str(&env, "A"),
+            total_funding: 2_000,
+        },
+        ProgramRegistrationItem {
+            program_id: 2,
+            admin: program_admin.clone(),
+            name: String::from_str(&env, "B"),
+            total_funding: 3_000,
+        },
+    ];
+    client.batch_register_programs(&batch);
+
+    assert_eq!(client.get_program(&1).total_funding, 1_980);
+    assert_eq!(client.get_program(&2).total_funding, 2_970);
+    assert_eq!(token_client.balance(&fee_recipient), 50);
+    assert_eq!(token_client.balance(&contract_id), 4_950);
+}
+
+#[test]
+fn test_fee_config_rejects_rate_above_max() {
+    setup!(
+        env,
+        client,
+        _contract_id,
+        admin,
+        _program_admin,
+        _token_client,
+        _token_admin,
+        0i128
+    );
+    assert_eq!(client.get_fee_config(), None);
+
+    let res = client.try_set_fee_config(&FeeConfig {
+        registration_fee_rate: MAX_FEE_RATE + 1,
+        payout_fee_rate: 0,
+        fee_recipient: admin.clone(),
+        registration_fee_enabled: true,
+        payout_fee_enabled: false,
+    });
+    assert_eq!(res, Err(Ok(Error::InvalidFeeRate)));
+
+    let res = client.try_set_fee_config(&FeeConfig {
+        registration_fee_rate: 0,
+        payout_fee_rate: -1,
+        fee_recipient: admin.clone(),
+        registration_fee_enabled: false,
+        payout_fee_enabled: true,
+    });
+    assert_eq!(res, Err(Ok(Error::InvalidFeeRate)));
+}
+
+// ==================== TIERED FEES ====================
+
+fn fee_tiers(env: &Env) -> Vec<FeeTier> {
+    vec![
+        env,
+        FeeTier {
+            threshold: 100_000,
+            fee_rate: 100,
+        },
+        FeeTier {
+            threshold: 1_000_000,
+            fee_rate: 50,
+        },
+        FeeTier {
+            threshold: i128::MAX,
+            fee_rate: 25,
+        },
+    ]
+}
+
+#[test]
+fn test_tiered_fee_rate_selection() {
+    setup!(
+        env,
+        client,
+        _contract_id,
+        admin,
+        _program_admin,
+        _token_client,
+        _token_admin,
+        0i128
+    );
+    client.set_fee_config(&FeeConfig {
+        registration_fee_rate: 300,
+        payout_fee_rate: 0,
+        fee_recipient: admin.clone(),
+        registration_fee_enabled: true,
+        payout_fee_enabled: false,
+    });
+
+    // Flat rate until a table is configured.
+    assert_eq!(client.get_registration_fee_rate(&50_000), 300);
+
+    client.set_fee_tiers(&fee_tiers(&env));
+    assert_eq!(client.get_fee_tiers().len(), 3);
+    assert_eq!(client.get_registration_fee_rate(&99_999), 100);
+    assert_eq!(client.get_registration_fee_rate(&100_000), 50);
+    assert_eq!(client.get_registration_fee_rate(&999_999), 50);
+    assert_eq!(client.get_registration_fee_rate(&5_000_000), 25);
+
+    // Clearing the table restores the flat rate.
+    client.set_fee_tiers(&Vec::new(&env));
+    assert_eq!(client.get_registration_fee_rate(&5_000_000), 300);
+}
+
+#[test]
+fn test_tiered_fee_applied_at_registration() {
+    setup!(
+        env,
+        client,
+        contract_id,
+        admin,
+        program_admin,
+        token_client,
+        token_admin,
+        2_000_000i128
+    );
+    let fee_recipient = Address::generate(&env);
+    client.set_fee_config(&FeeConfig {
+        registration_fee_rate: 300,
+        payout_fee_rate: 0,
+        fee_recipient: fee_recipient.clone(),
+        registration_fee_enabled: true,
+        payout_fee_enabled: false,
+    });
+    client.set_fee_tiers(&fee_tiers(&env));
+
+    // Small program: 1% of 50_000 = 500
+    client.register_program(
+        &1,
+        &program_admin,
+        &String::from_str(&env, "Small"),
+        &50_000,
+    );
+    // Large program: 0.25% of 1_200_000 = 3_000
+    client.register_program(
+        &2,
+        &program_admin,
+        &String::from_str(&env, "Large"),
+        &1_200_000,
+    );
+
+    assert_eq!(client.get_program(&1).total_funding, 49_500);
+    assert_eq!(client.get_program(&2).total_funding, 1_197_000);
+    assert_eq!(token_client.balance(&fee_recipient), 3_500);
+    assert_eq!(token_client.balance(&contract_id), 1_246_500);
+}
+
+#[test]
+fn test_fee_tiers_validation() {
+    setup!(
+        env,
+        client,
+        _contract_id,
+        admin,
+        _program_admin,
+        _token_client,
+        _token_admin,
+        0i128
+    );
+
+    let unordered = vec![
+        &env,
+        FeeTier {
+            threshold: 1_000_000,
+            fee_rate: 50,
+        },
+        FeeTier {
+            threshold: 100_000,
+            fee_rate: 100,
+        },
+    ];
+    assert_eq!(
+        client.try_set_fee_tiers(&unordered),
+        Err(Ok(Error::InvalidFeeTiers))
+    );
+
+    let too_high = vec![
+        &env,
+        FeeTier {
+            threshold: 100_000,
+            fee_rate: MAX_FEE_RATE + 1,
+        },
+    ];
+    assert_eq!(
+        client.try_set_fee_tiers(&too_high),
+        Err(Ok(Error::InvalidFeeRate))
+    );
+    assert_eq!(client.get_fee_tiers().len(), 0);
+}
+
+// ==================== TREASURY ====================
+
+fn route_fees_to_treasury(client: &ProgramEscrowContractClient, contract_id: &Address) {
+    client.set_fee_config(&FeeConfig {
+        registration_fee_rate: 100,
+        payout_fee_rate: 0,
+        fee_recipient: contract_id.clone(),
+        registration_fee_enabled: true,
+        payout_fee_enabled: false,
+    });
+}
+
+#[test]
+fn test_fees_accumulate_in_treasury() {
+    setup!(
+        env,
+        client,
+        contract_id,
+        admin,
+        program_admin,
+        token_client,
+        token_admin,
+        20_000i128
+    );
+    route_fees_to_treasury(&client, &contract_id);
+
+    client.register_program(&1, &program_admin, &String::from_str(&env, "A"), &10_000);
+    client.register_program(&2, &program_admin, &String::from_str(&env, "B"), &5_000);
+
+    assert_eq!(client.get_treasury_balance(), 150);
+    // Fees stay inside the contract alongside program funds.
+    assert_eq!(token_client.balance(&contract_id), 15_000);
+}
+
+#[test]
+fn test_treasury_withdrawal_respects_timelock() {
+    use soroban_sdk::testutils::Ledger as _;
+
+    setup!(
+        env,
+        client,
+        contract_id,
+        admin,
+        program_admin,
+        token_client,
+        token_admin,
+        20_000i128
+    );
+    route_fees_to_treasury(&client, &contract_id);
+    client.register_program(&1, &program_admin, &String::from_str(&env, "A"), &10_000);
+
+    let dao = Address::generate(&env);
+    env.ledger().set_timestamp(1_000);
+    client.request_treasury_withdrawal(&dao, &60);
+
+    let pending = client.get_pending_treasury_withdrawal().unwrap();
+    assert_eq!(pending.executable_at, 1_000 + TREASURY_TIMELOCK_SECS);
+
+    assert_eq!(
+        client.try_withdraw_treasury(&dao, &60),
+        Err(Ok(Error::TimelockNotElapsed))
+    );
+
+    env.ledger().set_timestamp(1_000 + TREASURY_TIMELOCK_SECS);
+    client.withdraw_treasury(&dao, &60);
+
+    assert_eq!(token_client.balance(&dao), 60);
+    assert_eq!(client.get_treasury_balance(), 40);
+    assert_eq!(client.get_pending_treasury_withdrawal(), None);
+    assert_eq!(
+        client.try_withdraw_treasury(&dao, &60),
+        Err(Ok(Error::NoPendingWithdrawal))
+    );
+}
+
+#[test]
+fn test_treasury_withdrawal_guards() {
+    use soroban_sdk::testutils::Ledger as _;
+
+    setup!(
+        env,
+        client,
+        contract_id,
+        admin,
+        program_admin,
+        _token_client,
+        token_admin,
+        20_000i128
+    );
+    route_fees_to_treasury(&client, &contract_id);
+    client.register_program(&1, &program_admin, &String::from_str(&env, "A"), &10_000);
+
+    let dao = Address::generate(&env);
+    assert_eq!(
+        client.try_request_treasury_withdrawal(&dao, &101),
+        Err(Ok(Error::InsufficientTreasuryBalance))
+    );
+    assert_eq!(
+        client.try_request_treasury_withdrawal(&dao, &0),
+        Err(Ok(Error::InvalidAmount))
+    );
+
+    client.request_treasury_withdrawal(&dao, &50);
+    env.ledger().set_timestamp(TREASURY_TIMELOCK_SECS);
+
+    // Execution must match the queued request exactly.
+    assert_eq!(
+        client.try_withdraw_treasury(&dao, &40),
+        Err(Ok(Error::NoPendingWithdrawal))
+    );
+    assert_eq!(
+        client.try_withdraw_treasury(&admin, &50),
+        Err(Ok(Error::NoPendingWithdrawal))
+    );
+}
+
+// ==================== HIERARCHICAL PROGRAMS ====================
+
+#[test]
+fn test_register_child_program_carves_out_parent_allocation() {
+    setup!(
+        env,
+        client,
+        contract_id,
+        admin,
+        program_admin,
+        token_client,
+        token_admin,
+        100_000i128
+    );
+    client.register_program(
+        &1,
+        &program_admin,
+        &String::from_str(&env, "National"),
+        &100_000,
+    );
+
+    client.register_child_program(&1, &10, &60_000);
+    client.register_child_program(&1, &11, &30_000);
+
+    assert_eq!(client.get_program(&10).total_funding, 60_000);
+    assert_eq!(client.get_program(&10).admin, program_admin);
+    assert_eq!(client.get_unallocated_funding(&1), 10_000);
+    // Children are backed by existing escrow; no extra tokens move.
+    assert_eq!(token_client.balance(&contract_id), 100_000);
+
+    assert_eq!(
+        client.try_register_child_program(&1, &12, &10_001),
+        Err(Ok(Error::InsufficientParentAllocation))
+    );
+    client.register_child_program(&1, &12, &10_000);
+    assert_eq!(client.get_unallocated_funding(&1), 0);
+}
+
+#[test]
+fn test_grandchild_limited_by_child_allocation() {
+    setup!(
+        env,
+        client,
+        _contract_id,
+        admin,
+        program_admin,
+        _token_client,
+        token_admin,
+        100_000i128
+    );
+    client.register_program(
+        &1,
+        &program_admin,
+        &String::from_str(&env, "National"),
+        &100_000,
+    );
+    client.register_child_program(&1, &10, &40_000);
+
+    assert_eq!(
+        client.try_register_child_program(&10, &100, &40_001),
+        Err(Ok(Error::InsufficientParentAllocation))
+    );
+    client.register_child_program(&10, &100, &25_000);
+    assert_eq!(client.get_unallocated_funding(&10), 15_000);
+    assert_eq!(client.get_unallocated_funding(&1), 60_000);
+}
+
+#[test]
+fn test_get_program_tree_rolls_up_depth_first() {
+    setup!(
+        env,
+        client,
+        _contract_id,
+        admin,
+        program_admin,
+        _token_client,
+        token_admin,
+        100_000i128
+    );
+    client.register_program(
+        &1,
+        &program_admin,
+        &String::from_str(&env, "National"),
+        &100_000,
+    );
+    client.register_child_program(&1, &10, &50_000);
+    client.register_child_program(&1, &11, &20_000);
+    client.register_child_program(&10, &100, &5_000);
+
+    let tree = client.get_program_tree(&1);
+    assert_eq!(tree.len(), 4);
+
+    let ids: std::vec::Vec<u64> = tree.iter().map(|n| n.program_id).collect();
+    assert_eq!(ids, std::vec![1, 10, 100, 11]);
+
+    let root = tree.get(0).unwrap();
+    assert_eq!(root.parent_id, None);
+    assert_eq!(root.depth, 0);
+    assert_eq!(root.allocated_to_children, 70_000);
+
+    let grandchild = tree.get(2).unwrap();
+    assert_eq!(grandchild.parent_id, Some(10));
+    assert_eq!(grandchild.depth, 2);
+
+    assert_eq!(client.get_program_tree(&11).len(), 1);
+}
+
+#[test]
+fn test_register_child_program_validation() {
+    setup!(
+        env,
+        client,
+        _contract_id,
+        admin,
+        program_admin,
+        _token_client,
+        token_admin,
+        10_000i128
+    );
+    client.register_program(
+        &1,
+        &program_admin,
+        &String::from_str(&env, "Parent"),
+        &10_000,
+    );
+
+    assert_eq!(
+        client.try_register_child_program(&99, &2, &1_000),
+        Err(Ok(Error::ProgramNotFound))
+    );
+    assert_eq!(
+        client.try_register_child_program(&1, &1, &1_000),
+        Err(Ok(Error::ProgramExists))
+    );
+    assert_eq!(
+        client.try_register_child_program(&1, &2, &0),
+        Err(Ok(Error::InvalidAmount))
+    );
+}
+
+// ==================== PROGRAM TEMPLATES ====================
+
+fn harvest_pattern(env: &Env) -> Vec<ScheduleStep> {
+    vec![
+        env,
+        ScheduleStep {
+            offset_secs: 0,
+            share_bps: 4_000,
+        },
+        ScheduleStep {
+            offset_secs: 90 * 86_400,
+            share_bps: 6_000,
+        },
+    ]
+}
+
+#[test]
+fn test_register_from_template_applies_defaults() {
+    setup!(
+        env,
+        client,
+        contract_id,
+        admin,
+        program_admin,
+        token_client,
+        token_admin,
+        20_000i128
+    );
+    let jurisdiction = OptionalJurisdiction::Some(ProgramJurisdictionConfig {
+        tag: Some(String::from_str(&env, "KE-RV")),
+        requires_kyc: false,
+        max_funding: Some(15_000),
+        registration_paused: false,
+    });
+    let template_id = client.create_program_template(
+        &String::from_str(&env, "Maize Input Subsidy"),
+        &jurisdiction,
+        &harvest_pattern(&env),
+    );
+    assert_eq!(template_id, 1);
+
+    client.register_from_template(&template_id, &7, &program_admin, &10_000);
+    client.register_from_template(&template_id, &8, &program_admin, &5_000);
+
+    let program = client.get_program(&7);
+    assert_eq!(program.name, String::from_str(&env, "Maize Input Subsidy"));
+    assert_eq!(program.jurisdiction, jurisdiction);
+    assert_eq!(client.get_program_template_id(&8), Some(template_id));
+    assert_eq!(client.get_program_template_id(&1), None);
+    assert_eq!(token_client.balance(&contract_id), 15_000);
+
+    let template = client.get_program_template(&template_id);
+    assert_eq!(template.default_schedule_pattern, harvest_pattern(&env));
+
+    let now = env.ledger().timestamp();
+    assert_eq!(
+        client.get_schedule_plan(&8),
+        vec![
+            &env,
+            PlannedRelease {
+                release_timestamp: now,
+                amount: 2_000,
+            },
+            PlannedRelease {
+                release_timestamp: now + 90 * 86_400,
+                amount: 3_000,
+            },
+        ]
+    );
+    assert!(client.get_schedule_plan(&1).is_empty());
+}
+
+#[test]
+fn test_register_from_template_enforces_jurisdiction() {
+    setup!(
+        env,
+        client,
+        _contract_id,
+        admin,
+        program_admin,
+        _token_client,
+        token_admin,
+        50_000i128
+    );
+    let template_id = client.create_program_template(
+        &String::from_str(&env, "Capped"),
+        &OptionalJurisdiction::Some(ProgramJurisdictionConfig {
+            tag: None,
+            requires_kyc: false,
+            max_funding: Some(1_000),
+            registration_paused: false,
+        }),
+        &Vec::new(&env),
+    );
+
+    assert_eq!(
+        client.try_register_from_template(&template_id, &1, &program_admin, &1_001),
+        Err(Ok(Error::JurisdictionFundingLimitExceeded))
+    );
+    assert_eq!(
+        client.try_register_from_template(&99, &1, &program_admin, &500),
+        Err(Ok(Error::TemplateNotFound))
+    );
+}
+
+#[test]
+fn test_create_program_template_validates_pattern() {
+    setup!(
+        env,
+        client,
+        _contract_id,
+        admin,
+        _program_admin,
+        _token_client,
+        _token_admin,
+        0i128
+    );
+    let name = String::from_str(&env, "Bad");
+
+    let short = vec![
+        &env,
+        ScheduleStep {
+            offset_secs: 0,
+            share_bps: 5_000,
+        },
+    ];
+    assert_eq!(
+        client.try_create_program_template(&name, &OptionalJurisdiction::None, &short),
+        Err(Ok(Error::InvalidSchedulePattern))
+    );
+
+    let unordered = vec![
+        &env,
+        ScheduleStep {
+            offset_secs: 100,
+            share_bps: 5_000,
+        },
+        ScheduleStep {
+            offset_secs: 100,
+            share_bps: 5_000,
+        },
+    ];
+    assert_eq!(
+        client.try_create_program_template(&name, &OptionalJurisdiction::None, &unordered),
+        Err(Ok(Error::InvalidSchedulePattern))
+    );
+
+    assert_eq!(
+        client.try_create_program_template(
+            &String::from_str(&env, ""),
+            &OptionalJurisdiction::None,
+            &Vec::new(&env)
+        ),
+        Err(Ok(Error::InvalidName))
+    );
+}
+
+// ==================== CROWDFUNDED REGISTRATION ====================
+
+#[test]
+fn test_funding_round_goal_met_registers_program() {
+    use soroban_sdk::testutils::Ledger as _;
+    setup!(
+        env,
+        client,
+        contract_id,
+        _admin,
+        program_admin,
+        token_client,
+        token_admin,
+        0i128
+    );
+    env.ledger().set_timestamp(1_000);
+    let name = String::from_str(&env, "Village Seed Bank");
+    client.open_funding_round(&1, &program_admin, &name, &1_000, &2_000);
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    token_admin.mint(&alice, &600);
+    token_admin.mint(&bob, &600);
+    client.pledge(&1, &alice, &600);
+    assert_eq!(
+        client.try_finalize_round(&1),
+        Err(Ok(Error::FundingGoalNotMet))
+    );
+    client.pledge(&1, &bob, &500);
+
+    let program = client.finalize_round(&1);
+    assert_eq!(program.total_funding, 1_100);
+    assert_eq!(program.admin, program_admin);
+    assert_eq!(client.get_program(&1), program);
+    assert_eq!(client.get_program_count(), 1);
+    assert_eq!(
+        client.get_funding_round(&1).status,
+        FundingRoundStatus::Finalized
+    );
+    assert_eq!(token_client.balance(&contract_id), 1_100);
+
+    assert_eq!(
+        client.try_pledge(&1, &bob, &100),
+        Err(Ok(Error::FundingRoundClosed))
+    );
+    env.ledger().set_timestamp(2_000);
+    assert_eq!(
+        client.try_refund_pledges(&1, &alice),
+        Err(Ok(Error::FundingRoundClosed))
+    );
+}
+
+#[test]
+fn test_funding_round_under_goal_refunds_once() {
+    use soroban_sdk::testutils::Ledger as _;
+    setup!(
+        env,
+        client,
+        contract_id,
+        _admin,
+        program_admin,
+        token_client,
+        token_admin,
+        0i128
+    );
+    env.ledger().set_timestamp(1_000);
+    let name = String::from_str(&env, "Cold Storage");
+    client.open_funding_round(&7, &program_admin, &name, &1_000, &2_000);
+
+    let alice = Address::generate(&env);
+    token_admin.mint(&alice, &400);
+    client.pledge(&7, &alice, &250);
+    client.pledge(&7, &alice, &150);
+    assert_eq!(client.get_pledge(&7, &alice), 400);
+
+    assert_eq!(
+        client.try_refund_pledges(&7, &alice),
+        Err(Ok(Error::FundingRoundActive))
+    );
+
+    env.ledger().set_timestamp(2_000);
+    assert_eq!(
+        client.try_pledge(&7, &alice, &1),
+        Err(Ok(Error::FundingRoundClosed))
+    );
+    assert_eq!(client.refund_pledges(&7, &alice), 400);
+    assert_eq!(token_client.balance(&alice), 400);
+    assert_eq!(token_client.balance(&contract_id), 0);
+    assert_eq!(client.get_pledge(&7, &alice), 0);
+
+    let round = client.get_funding_round(&7);
+    assert_eq!(round.status, FundingRoundStatus::Refunding);
+    assert_eq!(round.refunded, 400);
+
+    assert_eq!(
+        client.try_refund_pledges(&7, &alice),
+        Err(Ok(Error::NothingToRefund))
+    );
+    assert_eq!(
+        client.try_finalize_round(&7),
+        Err(Ok(Error::FundingRoundClosed))
+    );
+    assert_eq!(client.try_get_program(&7), Err(Ok(Error::ProgramNotFound)));
+}
+
+#[test]
+fn test_funding_round_finalizable_by_anyone_after_deadline() {
+    use soroban_sdk::testutils::{Events as _, Ledger as _};
+    use soroban_sdk::TryFromVal;
+    setup!(
+        env,
+        client,
+        contract_id,
+        _admin,
+        program_admin,
+        token_client,
+        token_admin,
+        0i128
+    );
+    env.ledger().set_timestamp(1_000);
+    let name = String::from_str(&env, "Grain Silo");
+    client.open_funding_round(&3, &program_admin, &name, &1_000, &2_000);
+    let opened = env
+        .events()
+        .all()
+        .iter()
+        .find_map(|(_, _, data)| FundingRoundOpenedEvent::try_from_val(&env, &data).ok())
+        .unwrap();
+    assert_eq!(opened.version, EVENT_SCHEMA_VERSION);
+    assert_eq!(opened.goal, 1_000);
+    assert_eq!(opened.deadline, 2_000);
+
+    let alice = Address::generate(&env);
+    token_admin.mint(&alice, &1_000);
+    client.pledge(&3, &alice, &1_000);
+
+    // The admin never finalizes; without any auth the call fails until the
+    // deadline, then goes through.
+    env.set_auths(&[]);
+    assert!(client.try_finalize_round(&3).is_err());
+    env.ledger().set_timestamp(2_000);
+    env.mock_all_auths();
+    assert_eq!(
+        client.try_refund_pledges(&3, &alice),
+        Err(Ok(Error::FundingRoundActive))
+    );
+    env.set_auths(&[]);
+    let program = client.finalize_round(&3);
+    let finalized = env
+        .events()
+        .all()
+        .iter()
+        .find_map(|(_, _, data)| FundingRoundFinalizedEvent::try_from_val(&env, &data).ok())
+        .unwrap();
+    assert_eq!(finalized.version, EVENT_SCHEMA_VERSION);
+    assert!(finalized.sequence > opened.sequence);
+    assert_eq!(finalized.pledged, 1_000);
+    assert_eq!(finalized.total_funding, 1_000);
+
+    assert_eq!(program.total_funding, 1_000);
+    assert_eq!(program.admin, program_admin);
+    assert_eq!(token_client.balance(&contract_id), 1_000);
+}
+
+#[test]
+fn test_funding_round_goal_met_refundable_once_deprecated() {
+    use soroban_sdk::testutils::Ledger as _;
+    setup!(
+        env,
+        client,
+        _contract_id,
+        _admin,
+        program_admin,
+        token_client,
+        token_admin,
+        0i128
+    );
+    env.ledger().set_timestamp(1_000);
+    let name = String::from_str(&env, "Seed Library");
+    client.open_funding_round(&4, &program_admin, &name, &500, &2_000);
+
+    let alice = Address::generate(&env);
+    token_admin.mint(&alice, &600);
+    client.pledge(&4, &alice, &600);
+
+    client.set_deprecated(&true, &None);
+    env.ledger().set_timestamp(2_000);
+    assert_eq!(
+        client.try_finalize_round(&4),
+        Err(Ok(Error::ContractDeprecated))
+    );
+    assert_eq!(client.refund_pledges(&4, &alice), 600);
+    assert_eq!(token_client.balance(&alice), 600);
+}
+
+#[test]
+fn test_open_funding_round_validation() {
+    use soroban_sdk::testutils::Ledger as _;
+    setup!(
+        env,
+        client,
+        _contract_id,
+        _admin,
+        program_admin,
+        _token_client,
+        _token_admin,
+        1_000i128
+    );
+    env.ledger().set_timestamp(1_000);
+    let name = String::from_str(&env, "Round");
+
+    assert_eq!(
+        client.try_open_funding_round(&1, &program_admin, &name, &1_000, &1_000),
+        Err(Ok(Error::InvalidFundingDeadline))
+    );
+    assert_eq!(
+        client.try_open_funding_round(&1, &program_admin, &name, &0, &2_000),
+        Err(Ok(Error::InvalidAmount))
+    );
+
+    client.open_funding_round(&1, &program_admin, &name, &1_000, &2_000);
+    assert_eq!(
+        client.try_open_funding_round(&1, &program_admin, &name, &1_000, &2_000),
+        Err(Ok(Error::ProgramExists))
+    );
+
+    client.register_program(&2, &program_admin, &name, &500);
+    assert_eq!(
+        client.try_open_funding_round(&2, &program_admin, &name, &1_000, &2_000),
+        Err(Ok(Error::ProgramExists))
+    );
+
+    // An open round reserves its id against every registration path.
+    assert_eq!(
+        client.try_register_program(&1, &program_admin, &name, &100),
+        Err(Ok(Error::ProgramExists))
+    );
+    assert_eq!(
+        client.try_batch_register_programs(&vec![
+            &env,
+            ProgramRegistrationItem {
+                program_id: 1,
+                admin: program_admin.clone(),
+                name: name.clone(),
+                total_funding: 100,
+            },
+        ]),
+        Err(Ok(Error::ProgramExists))
+    );
+    assert_eq!(
+        client.try_register_child_program(&2, &1, &100),
+        Err(Ok(Error::ProgramExists))
+    );
+    assert_eq!(client.get_program_count(), 1);
+    assert_eq!(
+        client.try_get_funding_round(&3),
+        Err(Ok(Error::FundingRoundNotFound))
+    );
+}
+
+// ==================== DONATIONS ====================
+
+#[test]
+fn test_donate_tracks_donor_totals() {
+    use soroban_sdk::testutils::Events as _;
+    setup!(
+        env,
+        client,
+        contract_id,
+        _admin,
+        program_admin,
+        token_client,
+        token_admin,
+        1_000i128
+    );
+    client.register_program(&1, &program_admin, &String::from_str(&env, "Wells"), &1_000);
+
+    let donor = Address::generate(&env);
+    token_admin.mint(&donor, &500);
+    assert_eq!(client.donate(&1, &donor, &200), 1_200);
+    assert_eq!(env.events().all().len(), 3);
+    assert_eq!(client.donate(&1, &donor, &300), 1_500);
+
+    assert_eq!(client.get_donor_total(&1, &donor), 500);
+    assert_eq!(client.get_donor_total(&1, &program_admin), 0);
+    assert_eq!(client.get_program(&1).total_funding, 1_500);
+    assert_eq!(token_client.balance(&contract_id), 1_500);
+    assert_eq!(token_client.balance(&donor), 0);
+}
+
+#[test]
+fn test_donate_issues_transferable_receipt() {
+    use soroban_sdk::testutils::Events as _;
+    use soroban_sdk::TryIntoVal;
+    setup!(
+        env,
+        client,
+        _contract_id,
+        _admin,
+        program_admin,
+        _token_client,
+        token_admin,
+        1_000i128
+    );
+    client.register_program(&1, &program_admin, &String::from_str(&env, "Wells"), &1_000);
+
+    let donor = Address::generate(&env);
+    token_admin.mint(&donor, &500);
+    client.donate(&1, &donor, &200);
+    let issued: ReceiptIssuedEvent = env
+        .events()
+        .all()
+        .last()
+        .unwrap()
+        .2
+        .try_into_val(&env)
+        .unwrap();
+    assert_eq!(issued.version, EVENT_SCHEMA_VERSION);
+    assert_eq!(issued.receipt_id, 1);
+    assert_eq!(issued.amount, 200);
+
+    client.donate(&1, &donor, &300);
+    let receipt = client.get_receipt(&2);
+    assert_eq!(receipt.program_id, 1);
+    assert_eq!(receipt.depositor, donor);
+    assert_eq!(receipt.amount, 300);
+    assert!(client.verify_receipt(&1, &200, &donor));
+    assert!(!client.verify_receipt(&1, &300, &donor));
+    assert!(!client.verify_receipt(&3, &200, &donor));
+
+    let auditor = Address::generate(&env);
+    let moved = client.transfer_receipt(&1, &auditor);
+    assert_eq!(moved.holder, auditor);
+    assert!(client.verify_receipt(&1, &200, &donor));
+    assert_eq!(client.try_get_receipt(&3), Err(Ok(Error::ReceiptNotFound)));
+}
+
+#[test]
+fn test_donate_respects_jurisdiction_caps() {
+    setup!(
+        env,
+        client,
+        _contract_id,
+        _admin,
+        program_admin,
+        _token_client,
+        token_admin,
+        1_000i128
+    );
+    let config = ProgramJurisdictionConfig {
+        tag: Some(String::from_str(&env, "KE")),
+        requires_kyc: false,
+        max_funding: Some(1_500),
+        registration_paused: false,
+    };
+    client.register_program_juris(
+        &1,
+        &program_admin,
+        &String::from_str(&env, "Dairy Co-op"),
+        &1_000,
+        &None,
+        &false,
+        &None,
+        &false,
+        &OptionalJurisdiction::Some(config),
+        &None,
+    );
+    assert_eq!(
+        client.try_set_max_donation(&1, &Some(0)),
+        Err(Ok(Error::InvalidAmount))
+    );
+    client.set_max_donation(&1, &Some(300));
+    assert_eq!(client.get_max_donation(&1), Some(300));
+
+    let donor = Address::generate(&env);
+    token_admin.mint(&donor, &1_000);
+    assert_eq!(
+        client.try_donate(&1, &donor, &301),
+        Err(Ok(Error::DonationLimitExceeded))
+    );
+    client.donate(&1, &donor, &300);
+    client.donate(&1, &donor, &200);
+    assert_eq!(
+        client.try_donate(&1, &donor, &1),
+        Err(Ok(Error::JurisdictionFundingLimitExceeded))
+    );
+    assert_eq!(client.get_donor_total(&1, &donor), 500);
+}
+
+#[test]
+fn test_donate_validation() {
+    setup!(
+        env,
+        client,
+        _contract_id,
+        _admin,
+        _program_admin,
+        _token_client,
+        _token_admin,
+        0i128
+    );
+    let donor = Address::generate(&env);
+    assert_eq!(
+        client.try_donate(&9, &donor, &100),
+        Err(Ok(Error::ProgramNotFound))
+    );
+    assert_eq!(
+        client.try_donate(&9, &donor, &0),
+        Err(Ok(Error::InvalidAmount))
+    );
+}
@@ -0,0 +1,110 @@
+#![cfg(test)]
+//! Restricting program payouts to enrolled beneficiaries.
+
+use super::test_payout_compat::setup;
+use super::*;
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{vec, Address, BytesN, TryFromVal};
+
+#[test]
+fn test_enroll_and_remove() {
+    let s = setup();
+    let farmer = Address::generate(&s.env);
+    let hash = BytesN::from_array(&s.env, &[7; 32]);
+
+    assert_eq!(s.client.get_beneficiary(&1, &farmer), None);
+    s.client.enroll_beneficiary(&1, &farmer, &hash);
+    let (_, _, data) = s.env.events().all().last().unwrap();
+    let enrolled = BeneficiaryEnrolledEvent::try_from_val(&s.env, &data).unwrap();
+    assert_eq!(enrolled.version, EVENT_SCHEMA_VERSION);
+    assert_eq!(
+        (enrolled.program_id, enrolled.beneficiary.clone()),
+        (1, farmer.clone())
+    );
+    assert_eq!(enrolled.metadata_hash, hash);
+    let record = s.client.get_beneficiary(&1, &farmer).unwrap();
+    assert_eq!(record.metadata_hash, hash);
+    // Enrollment is per program.
+    assert_eq!(s.client.get_beneficiary(&2, &farmer), None);
+
+    s.client.remove_beneficiary(&1, &farmer);
+    let (_, _, data) = s.env.events().all().last().unwrap();
+    let removed = BeneficiaryRemovedEvent::try_from_val(&s.env, &data).unwrap();
+    assert_eq!(removed.version, EVENT_SCHEMA_VERSION);
+    assert_eq!(removed.beneficiary, farmer);
+    assert!(removed.sequence > enrolled.sequence);
+    assert_eq!(s.client.get_beneficiary(&1, &farmer), None);
+    assert_eq!(
+        s.client.try_remove_beneficiary(&1, &farmer),
+        Err(Ok(Error::BeneficiaryNotEnrolled))
+    );
+}
+
+#[test]
+fn test_unrestricted_program_pays_anyone() {
+    let s = setup();
+    assert!(!s.client.is_beneficiaries_only(&1));
+    s.client
+        .program_single_payout(&1, &s.category, &Address::generate(&s.env), &10);
+}
+
+#[test]
+fn test_restricted_program_pays_only_enrolled() {
+    let s = setup();
+    let farmer = Address::generate(&s.env);
+    let stranger = Address::generate(&s.env);
+    s.client
+        .enroll_beneficiary(&1, &farmer, &BytesN::from_array(&s.env, &[1; 32]));
+    s.client.set_beneficiaries_only(&1, &true);
+    assert!(s.client.is_beneficiaries_only(&1));
+
+    s.client
+        .program_single_payout(&1, &s.category, &farmer, &100);
+    assert_eq!(
+        s.client
+            .try_program_single_payout(&1, &s.category, &stranger, &100),
+        Err(Ok(Error::BeneficiaryNotEnrolled))
+    );
+    assert_eq!(
+        s.client.try_program_batch_payout(
+            &1,
+            &s.category,
+            &vec![&s.env, farmer.clone(), stranger.clone()],
+            &vec![&s.env, 10, 10],
+        ),
+        Err(Ok(Error::BeneficiaryNotEnrolled))
+    );
+    assert_eq!(
+        s.client
+            .try_program_create_schedule(&1, &s.category, &stranger, &10, &100),
+        Err(Ok(Error::BeneficiaryNotEnrolled))
+    );
+    // Rejected payouts did not touch the budget.
+    assert_eq!(s.client.get_budget_report(&1).get(0).unwrap().spent, 100);
+
+    s.client.remove_beneficiary(&1, &farmer);
+    assert_eq!(
+        s.client
+            .try_program_single_payout(&1, &s.category, &farmer, &100),
+        Err(Ok(Error::BeneficiaryNotEnrolled))
+    );
+
+    s.client.set_beneficiaries_only(&1, &false);
+    s.client
+        .program_single_payout(&1, &s.category, &stranger, &100);
+}
+
+#[test]
+fn test_registry_requires_program() {
+    let s = setup();
+    let farmer = Address::generate(&s.env);
+    assert_eq!(
+        s.client
+            .try_enroll_beneficiary(&9, &farmer, &BytesN::from_array(&s.env, &[1; 32])),
+        Err(Ok(Error::ProgramNotFound))
+    );
+    assert_eq!(
+        s.client.try_set_beneficiaries_only(&9, &true),
+        Err(Ok(Error::ProgramNotFound))
+    );
+}
@@ -0,0 +1,265 @@
+#![cfg(test)]
+//! Funding bounty escrows out of a program's pool.
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Events, MockAuth, MockAuthInvoke};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, token, Address, Env, IntoVal, String, TryFromVal,
+};
+
+/// Minimal bounty escrow: `lock_funds` pulls the tokens from the depositor
+/// the same way the real escrows do.
+#[contract]
+struct MockBountyEscrow;
+
+#[contractimpl]
+impl MockBountyEscrow {
+    pub fn init(env: Env, token: Address) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("token"), &token);
+    }
+
+    pub fn lock_funds(env: Env, depositor: Address, bounty_id: u64, amount: i128, deadline: u64) {
+        depositor.require_auth();
+        let token: Address = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("token"))
+            .unwrap();
+        token::Client::new(&env, &token).transfer(
+            &depositor,
+            env.current_contract_address(),
+            &amount,
+        );
+        env.storage()
+            .persistent()
+            .set(&bounty_id, &(depositor, amount, deadline));
+    }
+
+    pub fn get_bounty(env: Env, bounty_id: u64) -> (Address, i128, u64) {
+        env.storage().persistent().get(&bounty_id).unwrap()
+    }
+
+    /// Return `amount` to the depositor and report it, as an escrow wired to
+    /// the settlement callback would.
+    pub fn refund(env: Env, bounty_id: u64, amount: i128) {
+        let (depositor, _, _) = Self::get_bounty(env.clone(), bounty_id);
+        let token: Address = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("token"))
+            .unwrap();
+        let this = env.current_contract_address();
+        token::Client::new(&env, &token).transfer(&this, &depositor, &amount);
+        ProgramEscrowContractClient::new(&env, &depositor).on_bounty_settled(
+            &this,
+            &bounty_id,
+            &BountyOutcome::Refunded,
+            &amount,
+        );
+    }
+}
+
+struct Setup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    program_admin: Address,
+    bounty_escrow: Address,
+}
+
+/// Program 1 funded with 1_000 and one registered bounty escrow.
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(ProgramEscrowContract, ());
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let program_admin = Address::generate(&env);
+    let token_addr = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    client.init(&admin, &token_addr);
+    token::StellarAssetClient::new(&env, &token_addr).mint(&program_admin, &1_000);
+    client.register_program(&1, &program_admin, &String::from_str(&env, "Grant"), &1_000);
+
+    let bounty_escrow = env.register(MockBountyEscrow, ());
+    MockBountyEscrowClient::new(&env, &bounty_escrow).init(&token_addr);
+    client.register_bounty_escrow(&bounty_escrow);
+
+    Setup {
+        token: token::Client::new(&env, &token_addr),
+        env,
+        client,
+        program_admin,
+        bounty_escrow,
+    }
+}
+
+#[test]
+fn test_fund_bounty_moves_program_funds() {
+    let s = setup();
+    let deadline = s.env.ledger().timestamp() + 1_000;
+
+    // Only the program admin signs; the token transfer out of the program
+    // contract is authorized by the contract itself.
+    let args = (1u64, s.bounty_escrow.clone(), 7u64, 400i128, deadline);
+    s.env.mock_auths(&[MockAuth {
+        address: &s.program_admin,
+        invoke: &MockAuthInvoke {
+            contract: &s.client.address,
+            fn_name: "fund_bounty_from_program",
+            args: args.into_val(&s.env),
+            sub_invokes: &[],
+        },
+    }]);
+    s.client
+        .fund_bounty_from_program(&1, &s.bounty_escrow, &7, &400, &deadline);
+
+    assert_eq!(s.token.balance(&s.bounty_escrow), 400);
+    assert_eq!(s.token.balance(&s.client.address), 600);
+    assert_eq!(s.client.get_program(&1).total_funding, 600);
+    assert_eq!(
+        MockBountyEscrowClient::new(&s.env, &s.bounty_escrow).get_bounty(&7),
+        (s.client.address.clone(), 400, deadline)
+    );
+
+    let bounties = s.client.list_program_bounties(&1);
+    assert_eq!(bounties.len(), 1);
+    let bounty = bounties.get(0).unwrap();
+    assert_eq!(bounty.bounty_escrow, s.bounty_escrow);
+    assert_eq!((bounty.bounty_id, bounty.amount), (7, 400));
+    assert_eq!(s.client.list_program_bounties(&2).len(), 0);
+}
+
+#[test]
+fn test_unregistered_escrow_rejected() {
+    let s = setup();
+    let other = s.env.register(MockBountyEscrow, ());
+    assert_eq!(
+        s.client
+            .try_fund_bounty_from_program(&1, &other, &7, &100, &1_000),
+        Err(Ok(Error::BountyEscrowNotRegistered))
+    );
+    assert!(!s.client.is_bounty_escrow_registered(&other));
+    assert!(s.client.is_bounty_escrow_registered(&s.bounty_escrow));
+}
+
+#[test]
+fn test_register_bounty_escrow_emits_versioned_event() {
+    let s = setup();
+    let other = s.env.register(MockBountyEscrow, ());
+    s.client.register_bounty_escrow(&other);
+
+    let (_, _, data) = s.env.events().all().last().unwrap();
+    let event = BountyEscrowRegisteredEvent::try_from_val(&s.env, &data).unwrap();
+    assert_eq!(event.version, EVENT_SCHEMA_VERSION);
+    assert_eq!(event.bounty_escrow, other);
+    assert!(event.sequence > 0);
+}
+
+#[test]
+fn test_child_allocations_not_spendable() {
+    let s = setup();
+    s.client.register_child_program(&1, &2, &700);
+
+    assert_eq!(
+        s.client
+            .try_fund_bounty_from_program(&1, &s.bounty_escrow, &7, &400, &1_000),
+        Err(Ok(Error::InsufficientProgramFunds))
+    );
+    assert_eq!(
+        s.client
+            .try_fund_bounty_from_program(&1, &s.bounty_escrow, &7, &0, &1_000),
+        Err(Ok(Error::InvalidAmount))
+    );
+    s.client
+        .fund_bounty_from_program(&1, &s.bounty_escrow, &7, &300, &1_000);
+    assert_eq!(s.client.get_unallocated_funding(&1), 0);
+}
+
+#[test]
+fn test_refund_flows_back_to_program() {
+    let s = setup();
+    s.client
+        .fund_bounty_from_program(&1, &s.bounty_escrow, &7, &400, &1_000);
+
+    // No signatures: the escrow authorizes the callback by invoking it.
+    s.env.set_auths(&[]);
+    MockBountyEscrowClient::new(&s.env, &s.bounty_escrow).refund(&7, &150);
+
+    assert_eq!(s.client.get_program(&1).total_funding, 750);
+    assert_eq!(s.token.balance(&s.client.address), 750);
+    let bounty = s.client.list_program_bounties(&1).get(0).unwrap();
+    assert_eq!((bounty.released, bounty.refunded), (0, 150));
+}
+
+#[test]
+fn test_release_recorded_without_credit() {
+    let s = setup();
+    s.client
+        .fund_bounty_from_program(&1, &s.bounty_escrow, &7, &400, &1_000);
+
+    s.client
+        .on_bounty_settled(&s.bounty_escrow, &7, &BountyOutcome::Released, &300);
+    assert_eq!(s.client.get_program(&1).total_funding, 600);
+    assert_eq!(
+        s.client
+            .try_on_bounty_settled(&s.bounty_escrow, &7, &BountyOutcome::Refunded, &101),
+        Err(Ok(Error::InvalidAmount))
+    );
+    s.client
+        .on_bounty_settled(&s.bounty_escrow, &7, &BountyOutcome::Refunded, &100);
+    let bounty = s.client.list_program_bounties(&1).get(0).unwrap();
+    assert_eq!((bounty.released, bounty.refunded), (300, 100));
+    assert_eq!(s.client.get_program(&1).total_funding, 700);
+}
+
+#[test]
+fn test_settlement_requires_linked_bounty() {
+    let s = setup();
+    let other = s.env.register(MockBountyEscrow, ());
+    assert_eq!(
+        s.client
+            .try_on_bounty_settled(&other, &7, &BountyOutcome::Refunded, &10),
+        Err(Ok(Error::BountyEscrowNotRegistered))
+    );
+    assert_eq!(
+        s.client
+            .try_on_bounty_settled(&s.bounty_escrow, &8, &BountyOutcome::Refunded, &10),
+        Err(Ok(Error::BountyNotLinked))
+    );
+}
+
+#[test]
+fn test_escrow_settlements_reach_program() {
+    use soroban_sdk::testutils::Ledger as _;
+    let s = setup();
+    let admin = Address::generate(&s.env);
+    let escrow_id = s.env.register(escrow::EscrowContract, ());
+    let escrow = escrow::EscrowContractClient::new(&s.env, &escrow_id);
+    escrow.init(&admin, &s.token.address);
+    escrow.set_settlement_listener(&s.client.address, &true);
+    s.client.register_bounty_escrow(&escrow_id);
+
+    let deadline = s.env.ledger().timestamp() + 100;
+    s.client
+        .fund_bounty_from_program(&1, &escrow_id, &7, &400, &deadline);
+    s.client
+        .fund_bounty_from_program(&1, &escrow_id, &8, &300, &deadline);
+    assert_eq!(s.client.get_program(&1).total_funding, 300);
+
+    escrow.release_funds(&8, &Address::generate(&s.env));
+    s.env.ledger().set_timestamp(deadline);
+    escrow.refund(&7);
+
+    assert_eq!(s.client.get_program(&1).total_funding, 700);
+    assert_eq!(s.token.balance(&s.client.address), 700);
+    let bounties = s.client.list_program_bounties(&1);
+    let refunded = bounties.get(0).unwrap();
+    assert_eq!((refunded.released, refunded.refunded), (0, 400));
+    let released = bounties.get(1).unwrap();
+    assert_eq!((released.released, released.refunded), (300, 0));
+}
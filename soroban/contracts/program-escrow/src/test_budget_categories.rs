@@ -0,0 +1,123 @@
+#![cfg(test)]
+//! Budget envelopes charged by the payout and schedule forwarders.
+
+use super::test_payout_compat::setup;
+use super::*;
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{token, vec, Address, String, TryFromVal};
+
+#[test]
+fn test_spend_tracked_per_category() {
+    let s = setup();
+    let recipient = Address::generate(&s.env);
+
+    s.client
+        .program_single_payout(&1, &s.category, &recipient, &250);
+    s.client
+        .program_create_schedule(&1, &s.category, &recipient, &150, &100);
+
+    let report = s.client.get_budget_report(&1);
+    assert_eq!(report.len(), 1);
+    let ops = report.get(0).unwrap();
+    assert_eq!(ops.name, s.category);
+    assert_eq!(
+        (ops.allocation, ops.spent, ops.remaining),
+        (1_000, 400, 600)
+    );
+    assert_eq!(ops.utilization_bps, 4_000);
+}
+
+#[test]
+fn test_category_allocation_is_a_hard_cap() {
+    let s = setup();
+    let recipient = Address::generate(&s.env);
+
+    assert_eq!(
+        s.client.try_program_batch_payout(
+            &1,
+            &s.category,
+            &vec![&s.env, recipient.clone(), recipient.clone()],
+            &vec![&s.env, 600, 500],
+        ),
+        Err(Ok(Error::BudgetExceeded))
+    );
+    s.client
+        .program_single_payout(&1, &s.category, &recipient, &1_000);
+    assert_eq!(
+        s.client
+            .try_program_create_schedule(&1, &s.category, &recipient, &1, &100),
+        Err(Ok(Error::BudgetExceeded))
+    );
+    assert_eq!(s.client.program_remaining_balance(&1), 0);
+}
+
+#[test]
+fn test_payout_requires_known_category() {
+    let s = setup();
+    assert_eq!(
+        s.client.try_program_single_payout(
+            &1,
+            &String::from_str(&s.env, "marketing"),
+            &Address::generate(&s.env),
+            &10,
+        ),
+        Err(Ok(Error::BudgetCategoryNotFound))
+    );
+}
+
+#[test]
+fn test_create_category_validation() {
+    let s = setup();
+    let ops = String::from_str(&s.env, "ops");
+    let audits = String::from_str(&s.env, "audits");
+
+    assert_eq!(
+        s.client.try_create_budget_category(&1, &ops, &10),
+        Err(Ok(Error::BudgetCategoryExists))
+    );
+    // "ops" already holds the program's entire 1_000 of funding.
+    assert_eq!(
+        s.client.try_create_budget_category(&1, &audits, &1),
+        Err(Ok(Error::InsufficientProgramFunds))
+    );
+    assert_eq!(
+        s.client.try_create_budget_category(&1, &audits, &0),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        s.client
+            .try_create_budget_category(&1, &String::from_str(&s.env, ""), &10),
+        Err(Ok(Error::InvalidName))
+    );
+    assert_eq!(
+        s.client.try_create_budget_category(&9, &audits, &10),
+        Err(Ok(Error::ProgramNotFound))
+    );
+    assert_eq!(s.client.get_budget_report(&9).len(), 0);
+}
+
+#[test]
+fn test_category_allocation_excludes_child_programs() {
+    let s = setup();
+    token::StellarAssetClient::new(&s.env, &s.token).mint(&s.program_admin, &500);
+    s.client.register_program(
+        &2,
+        &s.program_admin,
+        &String::from_str(&s.env, "Grant 2"),
+        &500,
+    );
+    s.client.register_child_program(&2, &3, &300);
+
+    let audits = String::from_str(&s.env, "audits");
+    assert_eq!(
+        s.client.try_create_budget_category(&2, &audits, &201),
+        Err(Ok(Error::InsufficientProgramFunds))
+    );
+    s.client.create_budget_category(&2, &audits, &200);
+
+    let (_, _, data) = s.env.events().all().last().unwrap();
+    let event = BudgetCategoryCreatedEvent::try_from_val(&s.env, &data).unwrap();
+    assert_eq!(event.version, EVENT_SCHEMA_VERSION);
+    assert_eq!((event.program_id, event.allocation), (2, 200));
+    assert_eq!(event.name, audits);
+}
@@ -0,0 +1,153 @@
+#![cfg(test)]
+//! Compliance reports over a program's fund movements.
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{token, Address, Env, String};
+
+struct Setup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    donor: Address,
+}
+
+/// Program 1 under an "EU" KYC jurisdiction registered with 1_000 at t=100,
+/// and a donor holding 5_000.
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 100);
+
+    let contract_id = env.register(ProgramEscrowContract, ());
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let program_admin = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    client.init(&admin, &token);
+    let token_admin = token::StellarAssetClient::new(&env, &token);
+    token_admin.mint(&program_admin, &1_000);
+    token_admin.mint(&donor, &5_000);
+
+    let cfg = ProgramJurisdictionConfig {
+        tag: Some(String::from_str(&env, "EU")),
+        requires_kyc: true,
+        max_funding: Some(10_000),
+        registration_paused: false,
+    };
+    client.register_program_juris(
+        &1,
+        &program_admin,
+        &String::from_str(&env, "EU Grants"),
+        &1_000,
+        &cfg.tag.clone(),
+        &cfg.requires_kyc,
+        &cfg.max_funding,
+        &cfg.registration_paused,
+        &OptionalJurisdiction::Some(cfg.clone()),
+        &Some(true),
+    );
+
+    Setup { env, client, donor }
+}
+
+fn at(env: &Env, timestamp: u64) {
+    env.ledger().with_mut(|li| li.timestamp = timestamp);
+}
+
+#[test]
+fn test_report_aggregates_inflows() {
+    let s = setup();
+    at(&s.env, 200);
+    s.client.donate(&1, &s.donor, &300);
+    at(&s.env, 300);
+    s.client.donate(&1, &s.donor, &200);
+
+    let report = s
+        .client
+        .generate_compliance_report(&1, &0, &1_000, &None, &0);
+    assert_eq!(report.total_inflows, 1_500);
+    assert_eq!(report.inflow_count, 3);
+    assert_eq!((report.total_outflows, report.outflow_count), (0, 0));
+    assert_eq!(report.kyc_gated_operations, 3);
+    assert_eq!(report.jurisdiction_tags.len(), 1);
+    assert_eq!(
+        report.jurisdiction_tags.get(0),
+        Some(String::from_str(&s.env, "EU"))
+    );
+    assert_eq!(report.breaches, 0);
+    assert_eq!(report.current_funding, 1_500);
+    assert_eq!(report.generated_at, 300);
+    assert_eq!(report.next_cursor, None);
+    assert_eq!(s.client.get_compliance_log(&1, &0, &0).len(), 3);
+}
+
+#[test]
+fn test_report_only_covers_requested_period() {
+    let s = setup();
+    at(&s.env, 200);
+    s.client.donate(&1, &s.donor, &300);
+    at(&s.env, 400);
+    s.client.donate(&1, &s.donor, &200);
+
+    let report = s
+        .client
+        .generate_compliance_report(&1, &150, &200, &None, &0);
+    assert_eq!((report.total_inflows, report.inflow_count), (300, 1));
+    assert_eq!(report.kyc_gated_operations, 1);
+
+    let report = s
+        .client
+        .generate_compliance_report(&1, &500, &600, &None, &0);
+    assert_eq!(report.inflow_count, 0);
+    assert_eq!(report.jurisdiction_tags.len(), 0);
+    assert_eq!(report.current_funding, 1_500);
+}
+
+#[test]
+fn test_report_and_log_are_paged() {
+    let s = setup();
+    for (i, timestamp) in [200u64, 300, 400, 500].iter().enumerate() {
+        at(&s.env, *timestamp);
+        s.client.donate(&1, &s.donor, &(100 * (i as i128 + 1)));
+    }
+    assert_eq!(s.client.get_compliance_log_len(&1), 5);
+
+    let page = s.client.get_compliance_log(&1, &1, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(
+        (page.get(0).unwrap().amount, page.get(1).unwrap().amount),
+        (100, 200)
+    );
+    assert_eq!(s.client.get_compliance_log(&1, &4, &10).len(), 1);
+    assert!(s.client.get_compliance_log(&1, &9, &10).is_empty());
+
+    // The window [250, 450] holds the 200 and 300 donations.
+    let first = s
+        .client
+        .generate_compliance_report(&1, &250, &450, &None, &1);
+    assert_eq!((first.total_inflows, first.inflow_count), (200, 1));
+    assert_eq!(first.next_cursor, Some(3));
+    let second = s
+        .client
+        .generate_compliance_report(&1, &250, &450, &first.next_cursor, &1);
+    assert_eq!((second.total_inflows, second.inflow_count), (300, 1));
+    assert_eq!(second.next_cursor, None);
+}
+
+#[test]
+fn test_report_rejects_bad_range_and_unknown_program() {
+    let s = setup();
+    assert_eq!(
+        s.client
+            .try_generate_compliance_report(&1, &200, &100, &None, &0),
+        Err(Ok(Error::InvalidReportRange))
+    );
+    assert_eq!(
+        s.client
+            .try_generate_compliance_report(&9, &0, &100, &None, &0),
+        Err(Ok(Error::ProgramNotFound))
+    );
+}
@@ -0,0 +1,182 @@
+#![cfg(test)]
+//! Cooperative beneficiaries whose payouts fan out to members by weight.
+
+extern crate std;
+use super::test_payout_compat::setup;
+use super::*;
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{vec, Address, BytesN, Env, TryFromVal};
+
+fn member_events(env: &Env) -> std::vec::Vec<CoopMemberPaidEvent> {
+    let mut out = std::vec::Vec::new();
+    for (_, _, data) in env.events().all().iter() {
+        if let Ok(event) = CoopMemberPaidEvent::try_from_val(env, &data) {
+            out.push(event);
+        }
+    }
+    out
+}
+
+#[test]
+fn test_coop_payout_fans_out_by_weight() {
+    let s = setup();
+    let coop = Address::generate(&s.env);
+    let a = Address::generate(&s.env);
+    let b = Address::generate(&s.env);
+    let c = Address::generate(&s.env);
+    s.client.set_coop_members(
+        &coop,
+        &vec![&s.env, a.clone(), b.clone(), c.clone()],
+        &vec![&s.env, 1, 1, 1],
+    );
+
+    let data = s.client.program_single_payout(&1, &s.category, &coop, &100);
+    let events = member_events(&s.env);
+    assert_eq!(events.len(), 3);
+    assert_eq!((events[0].member.clone(), events[0].amount), (a, 33));
+    assert_eq!((events[1].member.clone(), events[1].amount), (b, 33));
+    // The last member absorbs rounding dust.
+    assert_eq!((events[2].member.clone(), events[2].amount), (c, 34));
+    assert!(events.iter().all(|e| e.coop == coop && e.program_id == 1));
+
+    assert_eq!(data.payout_history.len(), 3);
+    assert_eq!(data.remaining_balance, 900);
+    assert_eq!(s.client.get_budget_report(&1).get(0).unwrap().spent, 100);
+}
+
+#[test]
+fn test_batch_mixes_coops_and_plain_recipients() {
+    let s = setup();
+    let coop = Address::generate(&s.env);
+    let solo = Address::generate(&s.env);
+    let a = Address::generate(&s.env);
+    let b = Address::generate(&s.env);
+    s.client.set_coop_members(
+        &coop,
+        &vec![&s.env, a.clone(), b.clone()],
+        &vec![&s.env, 3, 1],
+    );
+
+    let data = s.client.program_batch_payout(
+        &1,
+        &s.category,
+        &vec![&s.env, solo.clone(), coop],
+        &vec![&s.env, 50, 200],
+    );
+    let paid: std::vec::Vec<(Address, i128)> = data
+        .payout_history
+        .iter()
+        .map(|r| (r.recipient, r.amount))
+        .collect();
+    assert_eq!(paid, [(solo, 50), (a, 150), (b, 50)]);
+}
+
+#[test]
+fn test_coop_schedule_splits_per_member() {
+    let s = setup();
+    let coop = Address::generate(&s.env);
+    let a = Address::generate(&s.env);
+    let b = Address::generate(&s.env);
+    s.client.set_coop_members(
+        &coop,
+        &vec![&s.env, a.clone(), b.clone()],
+        &vec![&s.env, 3, 1],
+    );
+
+    let schedules = s
+        .client
+        .program_create_schedule(&1, &s.category, &coop, &200, &100);
+    let split: std::vec::Vec<(Address, i128)> = schedules
+        .iter()
+        .map(|schedule| (schedule.recipient, schedule.amount))
+        .collect();
+    assert_eq!(split, [(a, 150), (b, 50)]);
+    assert_eq!(member_events(&s.env).len(), 2);
+    // The budget is charged once, for the cooperative entry.
+    assert_eq!(s.client.get_budget_report(&1).get(0).unwrap().spent, 200);
+}
+
+#[test]
+fn test_beneficiary_check_applies_to_coop_and_members() {
+    let s = setup();
+    let coop = Address::generate(&s.env);
+    let member = Address::generate(&s.env);
+    s.client
+        .set_coop_members(&coop, &vec![&s.env, member.clone()], &vec![&s.env, 1]);
+    s.client
+        .enroll_beneficiary(&1, &coop, &BytesN::from_array(&s.env, &[3; 32]));
+    s.client.set_beneficiaries_only(&1, &true);
+
+    // Enrolling the coop does not cover its members.
+    assert_eq!(
+        s.client
+            .try_program_single_payout(&1, &s.category, &coop, &10),
+        Err(Ok(Error::BeneficiaryNotEnrolled))
+    );
+    s.client
+        .enroll_beneficiary(&1, &member, &BytesN::from_array(&s.env, &[4; 32]));
+    s.client.program_single_payout(&1, &s.category, &coop, &10);
+    assert_eq!(s.client.program_remaining_balance(&1), 990);
+}
+
+#[test]
+fn test_coop_share_rounding_to_zero_rejected() {
+    let s = setup();
+    let coop = Address::generate(&s.env);
+    let a = Address::generate(&s.env);
+    let b = Address::generate(&s.env);
+    s.client
+        .set_coop_members(&coop, &vec![&s.env, a, b], &vec![&s.env, 1, 9]);
+
+    // 1 * 5 / 10 rounds the first member's share down to zero.
+    assert_eq!(
+        s.client
+            .try_program_single_payout(&1, &s.category, &coop, &5),
+        Err(Ok(Error::InvalidAmount))
+    );
+    s.client.program_single_payout(&1, &s.category, &coop, &10);
+}
+
+#[test]
+fn test_set_coop_members_validation() {
+    let s = setup();
+    let coop = Address::generate(&s.env);
+    let a = Address::generate(&s.env);
+
+    assert_eq!(
+        s.client
+            .try_set_coop_members(&coop, &vec![&s.env, a.clone()], &vec![&s.env, 1, 2]),
+        Err(Ok(Error::InvalidCoopMembers))
+    );
+    assert_eq!(
+        s.client
+            .try_set_coop_members(&coop, &vec![&s.env, a.clone()], &vec![&s.env, 0]),
+        Err(Ok(Error::InvalidCoopMembers))
+    );
+    assert_eq!(
+        s.client
+            .try_set_coop_members(&coop, &vec![&s.env, coop.clone()], &vec![&s.env, 1]),
+        Err(Ok(Error::InvalidCoopMembers))
+    );
+    assert_eq!(
+        s.client.try_set_coop_members(
+            &coop,
+            &vec![&s.env, a.clone(), a.clone()],
+            &vec![&s.env, 1, 1]
+        ),
+        Err(Ok(Error::InvalidCoopMembers))
+    );
+
+    s.client
+        .set_coop_members(&coop, &vec![&s.env, a.clone()], &vec![&s.env, 1]);
+    let (_, _, data) = s.env.events().all().last().unwrap();
+    let event = CoopMembersSetEvent::try_from_val(&s.env, &data).unwrap();
+    assert_eq!(event.version, EVENT_SCHEMA_VERSION);
+    assert_eq!(event.coop, coop);
+    assert_eq!(event.members, vec![&s.env, a.clone()]);
+    assert_eq!(event.weights, vec![&s.env, 1]);
+    assert_eq!(s.client.get_coop_members(&coop).len(), 1);
+    s.client
+        .set_coop_members(&coop, &Vec::new(&s.env), &Vec::new(&s.env));
+    assert!(s.client.get_coop_members(&coop).is_empty());
+}
@@ -0,0 +1,127 @@
+#![cfg(test)]
+//! Dead man's switch for program admins.
+extern crate std;
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Ledger};
+use soroban_sdk::{token, Address, Env, IntoVal, String, Symbol};
+
+struct Setup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    program_admin: Address,
+    fallback: Address,
+}
+
+/// Program 1 with a one-day inactivity policy armed at t=1_000.
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+
+    let contract_id = env.register(ProgramEscrowContract, ());
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let program_admin = Address::generate(&env);
+    let fallback = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    client.init(&admin, &token);
+    token::StellarAssetClient::new(&env, &token).mint(&program_admin, &2_000);
+    client.register_program(&1, &program_admin, &String::from_str(&env, "Grant"), &1_000);
+    client.set_inactivity_policy(&1, &86_400, &fallback);
+
+    Setup {
+        env,
+        client,
+        program_admin,
+        fallback,
+    }
+}
+
+fn at(env: &Env, timestamp: u64) {
+    env.ledger().with_mut(|li| li.timestamp = timestamp);
+}
+
+#[test]
+fn test_fallback_claims_after_timeout() {
+    let s = setup();
+    let policy = s.client.get_inactivity_policy(&1).unwrap();
+    assert_eq!(policy.fallback_admin, s.fallback);
+    assert_eq!(policy.last_heartbeat, 1_000);
+
+    at(&s.env, 1_000 + 86_399);
+    assert_eq!(
+        s.client.try_claim_program_admin(&1),
+        Err(Ok(Error::AdminStillActive))
+    );
+
+    at(&s.env, 1_000 + 86_400);
+    s.client.claim_program_admin(&1);
+    assert_eq!(
+        s.env.auths(),
+        std::vec![(
+            s.fallback.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    s.client.address.clone(),
+                    Symbol::new(&s.env, "claim_program_admin"),
+                    (1u64,).into_val(&s.env),
+                )),
+                sub_invocations: std::vec![],
+            }
+        )]
+    );
+    assert_eq!(s.client.get_program(&1).admin, s.fallback);
+    assert_eq!(s.client.get_inactivity_policy(&1), None);
+}
+
+#[test]
+fn test_heartbeat_restarts_timeout() {
+    let s = setup();
+
+    at(&s.env, 80_000);
+    s.client.heartbeat(&1);
+    assert_eq!(
+        s.client.get_inactivity_policy(&1).unwrap().last_heartbeat,
+        80_000
+    );
+
+    at(&s.env, 1_000 + 86_400);
+    assert_eq!(
+        s.client.try_claim_program_admin(&1),
+        Err(Ok(Error::AdminStillActive))
+    );
+    assert_eq!(s.client.get_program(&1).admin, s.program_admin);
+}
+
+#[test]
+fn test_policy_validation() {
+    let s = setup();
+    assert_eq!(
+        s.client
+            .try_set_inactivity_policy(&1, &0, &Address::generate(&s.env)),
+        Err(Ok(Error::InvalidInactivityPolicy))
+    );
+    assert_eq!(
+        s.client
+            .try_set_inactivity_policy(&1, &60, &s.program_admin),
+        Err(Ok(Error::InvalidInactivityPolicy))
+    );
+
+    s.client.register_program(
+        &2,
+        &s.program_admin,
+        &String::from_str(&s.env, "Other"),
+        &1_000,
+    );
+    assert_eq!(
+        s.client.try_heartbeat(&2),
+        Err(Ok(Error::InactivityPolicyNotSet))
+    );
+    assert_eq!(
+        s.client.try_claim_program_admin(&2),
+        Err(Ok(Error::InactivityPolicyNotSet))
+    );
+}
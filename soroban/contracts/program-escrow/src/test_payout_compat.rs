@@ -0,0 +1,458 @@
+#![cfg(test)]
+//! Forwarding from registered programs to a linked payout contract.
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, AuthorizedFunction};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, token, vec, Address, Env, IntoVal, String, Symbol,
+};
+
+/// Stand-in for `contracts/program-escrow`, exposing the same entrypoint
+/// signatures over the mirrored types.
+#[contract]
+struct MockPayout;
+
+#[contractimpl]
+impl MockPayout {
+    pub fn setup(env: Env, payout_key: Address, funds: i128) {
+        let token = Address::generate(&env);
+        let data = PayoutProgramData {
+            program_id: String::from_str(&env, "mock"),
+            total_funds: funds,
+            remaining_balance: funds,
+            authorized_payout_key: payout_key,
+            payout_history: Vec::new(&env),
+            token_address: token,
+            initial_liquidity: 0,
+            risk_flags: 0,
+            reference_hash: None,
+        };
+        env.storage().instance().set(&symbol_short!("data"), &data);
+    }
+
+    fn pay(env: &Env, recipient: Address, amount: i128) -> PayoutProgramData {
+        let mut data = Self::get_program_info(env.clone());
+        data.remaining_balance -= amount;
+        data.payout_history.push_back(payout_compat::PayoutRecord {
+            recipient,
+            amount,
+            timestamp: env.ledger().timestamp(),
+        });
+        env.storage().instance().set(&symbol_short!("data"), &data);
+        data
+    }
+
+    pub fn batch_payout(
+        env: Env,
+        recipients: Vec<Address>,
+        amounts: Vec<i128>,
+    ) -> PayoutProgramData {
+        let mut data = Self::get_program_info(env.clone());
+        data.authorized_payout_key.require_auth();
+        for (recipient, amount) in recipients.iter().zip(amounts.iter()) {
+            data = Self::pay(&env, recipient, amount);
+        }
+        data
+    }
+
+    pub fn single_payout(env: Env, recipient: Address, amount: i128) -> PayoutProgramData {
+        Self::get_program_info(env.clone())
+            .authorized_payout_key
+            .require_auth();
+        Self::pay(&env, recipient, amount)
+    }
+
+    pub fn create_program_release_schedule(
+        env: Env,
+        recipient: Address,
+        amount: i128,
+        release_timestamp: u64,
+    ) -> PayoutReleaseSchedule {
+        Self::get_program_info(env.clone())
+            .authorized_payout_key
+            .require_auth();
+        let mut schedules = Self::get_program_release_schedules(env.clone());
+        let schedule = PayoutReleaseSchedule {
+            schedule_id: schedules.len() as u64 + 1,
+            recipient,
+            amount,
+            release_timestamp,
+            released: false,
+            released_at: None,
+            released_by: None,
+        };
+        schedules.push_back(schedule.clone());
+        env.storage()
+            .instance()
+            .set(&symbol_short!("sched"), &schedules);
+        schedule
+    }
+
+    pub fn release_prog_schedule_automatic(env: Env, schedule_id: u64) {
+        let mut schedules = Self::get_program_release_schedules(env.clone());
+        let index = (schedule_id - 1) as u32;
+        let mut schedule = schedules.get(index).unwrap();
+        Self::pay(&env, schedule.recipient.clone(), schedule.amount);
+        schedule.released = true;
+        schedule.released_at = Some(env.ledger().timestamp());
+        schedules.set(index, schedule);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("sched"), &schedules);
+    }
+
+    pub fn get_program_release_schedules(env: Env) -> Vec<PayoutReleaseSchedule> {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("sched"))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    pub fn get_program_info(env: Env) -> PayoutProgramData {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("data"))
+            .unwrap()
+    }
+
+    pub fn get_remaining_balance(env: Env) -> i128 {
+        Self::get_program_info(env).remaining_balance
+    }
+
+    pub fn get_program_reputation(env: Env) -> PayoutReputationScore {
+        let data = Self::get_program_info(env);
+        PayoutReputationScore {
+            total_payouts: data.payout_history.len(),
+            total_scheduled: 0,
+            completed_releases: 0,
+            pending_releases: 0,
+            overdue_releases: 0,
+            dispute_count: 0,
+            refund_count: 0,
+            total_funds_locked: data.total_funds,
+            total_funds_distributed: data.total_funds - data.remaining_balance,
+            completion_rate_bps: 10_000,
+            payout_fulfillment_rate_bps: 0,
+            overall_score_bps: 0,
+        }
+    }
+
+    pub fn get_threshold_config(env: Env) -> PayoutThresholdConfig {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("thresh"))
+            .unwrap_or(PayoutThresholdConfig {
+                failure_rate_threshold: 10,
+                outflow_volume_threshold: 1_000,
+                max_single_payout: 500,
+                time_window_secs: 600,
+                cooldown_period_secs: 300,
+                cooldown_multiplier: 2,
+            })
+    }
+
+    pub fn set_threshold_config(env: Env, config: PayoutThresholdConfig) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("thresh"), &config);
+    }
+}
+
+pub(super) struct Setup<'a> {
+    pub env: Env,
+    pub client: ProgramEscrowContractClient<'a>,
+    pub program_admin: Address,
+    pub payout: Address,
+    pub payout_key: Address,
+    pub category: String,
+    pub token: Address,
+}
+
+/// Program 1 registered with a 1_000 "ops" budget category and linked to a
+/// mock payout contract holding 1_000.
+pub(super) fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths_allowing_non_root_auth();
+
+    let contract_id = env.register(ProgramEscrowContract, ());
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let program_admin = Address::generate(&env);
+    let token_addr = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    client.init(&admin, &token_addr);
+    token::StellarAssetClient::new(&env, &token_addr).mint(&program_admin, &1_000);
+    client.register_program(&1, &program_admin, &String::from_str(&env, "Grant"), &1_000);
+    let category = String::from_str(&env, "ops");
+    client.create_budget_category(&1, &category, &1_000);
+
+    let payout = env.register(MockPayout, ());
+    let payout_key = Address::generate(&env);
+    MockPayoutClient::new(&env, &payout).setup(&payout_key, &1_000);
+    client.link_payout_contract(&1, &payout);
+
+    Setup {
+        env,
+        client,
+        program_admin,
+        payout,
+        payout_key,
+        category,
+        token: token_addr,
+    }
+}
+
+fn assert_program_admin_authorized(s: &Setup, function: &str) {
+    let auths = s.env.auths();
+    let (address, invocation) = &auths[0];
+    assert_eq!(*address, s.program_admin);
+    match &invocation.function {
+        AuthorizedFunction::Contract((contract, name, _)) => {
+            assert_eq!(*contract, s.client.address);
+            assert_eq!(*name, Symbol::new(&s.env, function));
+        }
+        _ => panic!("expected a contract invocation"),
+    }
+}
+
+#[test]
+fn test_forwarders_require_program_admin() {
+    let s = setup();
+    let recipient = Address::generate(&s.env);
+
+    s.client
+        .program_single_payout(&1, &s.category, &recipient, &10);
+    assert_program_admin_authorized(&s, "program_single_payout");
+    s.client.program_batch_payout(
+        &1,
+        &s.category,
+        &vec![&s.env, recipient.clone()],
+        &vec![&s.env, 10],
+    );
+    assert_program_admin_authorized(&s, "program_batch_payout");
+    let schedule = s
+        .client
+        .program_create_schedule(&1, &s.category, &recipient, &10, &0)
+        .get(0)
+        .unwrap();
+    assert_program_admin_authorized(&s, "program_create_schedule");
+    s.client.program_release_schedule(&1, &schedule.schedule_id);
+    assert_program_admin_authorized(&s, "program_release_schedule");
+}
+
+#[test]
+fn test_link_requires_program_admin() {
+    let s = setup();
+    assert_eq!(
+        s.env.auths()[0].1.function,
+        AuthorizedFunction::Contract((
+            s.client.address.clone(),
+            Symbol::new(&s.env, "link_payout_contract"),
+            (1u64, s.payout.clone()).into_val(&s.env),
+        ))
+    );
+    assert_eq!(s.env.auths()[0].0, s.program_admin);
+    assert_eq!(s.client.get_payout_contract(&1), Some(s.payout.clone()));
+    assert_eq!(s.client.get_payout_contract(&2), None);
+}
+
+#[test]
+fn test_unlinked_program_rejected() {
+    let s = setup();
+    assert_eq!(
+        s.client.try_link_payout_contract(&2, &s.payout),
+        Err(Ok(Error::ProgramNotFound))
+    );
+    assert_eq!(
+        s.client.try_program_remaining_balance(&2),
+        Err(Ok(Error::PayoutContractNotLinked))
+    );
+    assert_eq!(
+        s.client
+            .try_program_single_payout(&2, &s.category, &Address::generate(&s.env), &10),
+        Err(Ok(Error::PayoutContractNotLinked))
+    );
+}
+
+#[test]
+fn test_payouts_forwarded() {
+    let s = setup();
+    let alice = Address::generate(&s.env);
+    let bob = Address::generate(&s.env);
+
+    let data = s.client.program_batch_payout(
+        &1,
+        &s.category,
+        &vec![&s.env, alice.clone(), bob],
+        &vec![&s.env, 100, 200],
+    );
+    assert_eq!(data.remaining_balance, 700);
+    assert_eq!(data.payout_history.len(), 2);
+    // The payout key authorizes the nested call, not the registration contract.
+    assert!(s.env.auths().iter().any(|(addr, _)| *addr == s.payout_key));
+
+    s.client.program_single_payout(&1, &s.category, &alice, &50);
+    assert_eq!(s.client.program_remaining_balance(&1), 650);
+    assert_eq!(s.client.program_payout_info(&1).payout_history.len(), 3);
+}
+
+#[test]
+fn test_payout_fee_charged_to_program_pool() {
+    let s = setup();
+    let recipient = Address::generate(&s.env);
+    let treasury = s.client.address.clone();
+    let mut config = FeeConfig {
+        registration_fee_rate: 0,
+        payout_fee_rate: 500,
+        fee_recipient: treasury,
+        registration_fee_enabled: false,
+        payout_fee_enabled: true,
+    };
+    s.client.set_fee_config(&config);
+
+    s.client.program_batch_payout(
+        &1,
+        &s.category,
+        &vec![&s.env, recipient.clone()],
+        &vec![&s.env, 200],
+    );
+    s.client
+        .program_single_payout(&1, &s.category, &recipient, &100);
+    s.client
+        .program_create_schedule(&1, &s.category, &recipient, &100, &100);
+    // Recipients are paid in full; 5% of each amount leaves the pool here.
+    assert_eq!(s.client.program_remaining_balance(&1), 700);
+    assert_eq!(s.client.get_treasury_balance(), 20);
+    assert_eq!(s.client.get_program(&1).total_funding, 980);
+
+    config.payout_fee_enabled = false;
+    s.client.set_fee_config(&config);
+    s.client
+        .program_single_payout(&1, &s.category, &recipient, &100);
+    assert_eq!(s.client.get_treasury_balance(), 20);
+    assert_eq!(s.client.get_program(&1).total_funding, 980);
+}
+
+#[test]
+fn test_payout_fee_follows_tier_table() {
+    let s = setup();
+    s.client.set_fee_config(&FeeConfig {
+        registration_fee_rate: 0,
+        payout_fee_rate: 500,
+        fee_recipient: s.client.address.clone(),
+        registration_fee_enabled: false,
+        payout_fee_enabled: true,
+    });
+    // Program 1 holds 1_000, inside the first (1%) bracket.
+    s.client.set_fee_tiers(&vec![
+        &s.env,
+        FeeTier {
+            threshold: 100_000,
+            fee_rate: 100,
+        },
+        FeeTier {
+            threshold: i128::MAX,
+            fee_rate: 50,
+        },
+    ]);
+
+    s.client
+        .program_single_payout(&1, &s.category, &Address::generate(&s.env), &200);
+    assert_eq!(s.client.get_treasury_balance(), 2);
+    assert_eq!(s.client.get_program(&1).total_funding, 998);
+}
+
+#[test]
+fn test_reputation_forwarded() {
+    let s = setup();
+    s.client
+        .program_single_payout(&1, &s.category, &Address::generate(&s.env), &250);
+
+    let reputation = s.client.program_reputation(&1);
+    assert_eq!(reputation.total_payouts, 1);
+    assert_eq!(reputation.total_funds_locked, 1_000);
+    assert_eq!(reputation.total_funds_distributed, 250);
+    assert_eq!(
+        s.client.try_program_reputation(&2),
+        Err(Ok(Error::PayoutContractNotLinked))
+    );
+}
+
+#[test]
+fn test_schedules_forwarded() {
+    let s = setup();
+    let recipient = Address::generate(&s.env);
+
+    let schedule = s
+        .client
+        .program_create_schedule(&1, &s.category, &recipient, &300, &100)
+        .get(0)
+        .unwrap();
+    assert_eq!(schedule.schedule_id, 1);
+    s.client.program_release_schedule(&1, &schedule.schedule_id);
+
+    let schedules = s.client.program_schedules(&1);
+    assert!(schedules.get(0).unwrap().released);
+    assert_eq!(s.client.program_remaining_balance(&1), 700);
+}
+
+#[test]
+fn test_schedule_plan_applied_from_template() {
+    let s = setup();
+    let recipient = Address::generate(&s.env);
+    let pattern = vec![
+        &s.env,
+        ScheduleStep {
+            offset_secs: 0,
+            share_bps: 3_333,
+        },
+        ScheduleStep {
+            offset_secs: 100,
+            share_bps: 6_667,
+        },
+    ];
+    let template_id = s.client.create_program_template(
+        &String::from_str(&s.env, "Seasonal"),
+        &OptionalJurisdiction::None,
+        &pattern,
+    );
+    token::StellarAssetClient::new(&s.env, &s.token).mint(&s.program_admin, &500);
+    s.client
+        .register_from_template(&template_id, &2, &s.program_admin, &500);
+    assert_eq!(
+        s.client
+            .try_program_apply_schedule_plan(&2, &s.category, &recipient),
+        Err(Ok(Error::PayoutContractNotLinked))
+    );
+
+    s.client.link_payout_contract(&2, &s.payout);
+    s.client.create_budget_category(&2, &s.category, &500);
+    let schedules = s
+        .client
+        .program_apply_schedule_plan(&2, &s.category, &recipient);
+    assert_eq!(schedules.len(), 2);
+    assert_eq!(schedules.get(0).unwrap().amount, 166);
+    assert_eq!(schedules.get(1).unwrap().amount, 334);
+    assert_eq!(
+        schedules.get(1).unwrap().release_timestamp,
+        s.env.ledger().timestamp() + 100
+    );
+    assert!(s.client.get_schedule_plan(&2).is_empty());
+    assert_eq!(
+        s.client
+            .try_program_apply_schedule_plan(&2, &s.category, &recipient),
+        Err(Ok(Error::SchedulePlanNotFound))
+    );
+}
+
+#[test]
+fn test_threshold_config_forwarded() {
+    let s = setup();
+    let mut config = s.client.program_threshold_config(&1);
+    assert_eq!(config.max_single_payout, 500);
+
+    config.max_single_payout = 250;
+    s.client.program_set_threshold_config(&1, &config);
+    assert_eq!(s.client.program_threshold_config(&1), config);
+}
@@ -0,0 +1,138 @@
+#![cfg(test)]
+//! Seasonal payout windows.
+
+use super::test_payout_compat::setup;
+use super::*;
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{vec, Address, TryFromVal};
+
+fn at(env: &Env, timestamp: u64) {
+    env.ledger().with_mut(|li| li.timestamp = timestamp);
+}
+
+#[test]
+fn test_payouts_confined_to_windows() {
+    let s = setup();
+    let farmer = Address::generate(&s.env);
+    let windows = vec![
+        &s.env,
+        PayoutWindow {
+            start: 1_000,
+            end: 2_000,
+        },
+        PayoutWindow {
+            start: 5_000,
+            end: 6_000,
+        },
+    ];
+    s.client.set_payout_windows(&1, &windows);
+    let (_, _, data) = s.env.events().all().last().unwrap();
+    let event = PayoutWindowsSetEvent::try_from_val(&s.env, &data).unwrap();
+    assert_eq!(event.version, EVENT_SCHEMA_VERSION);
+    assert_eq!(event.program_id, 1);
+    assert_eq!(event.windows, windows);
+    assert_eq!(s.client.get_payout_windows(&1), windows);
+
+    at(&s.env, 999);
+    assert_eq!(
+        s.client
+            .try_program_single_payout(&1, &s.category, &farmer, &10),
+        Err(Ok(Error::OutsidePayoutWindow))
+    );
+    at(&s.env, 1_000);
+    s.client
+        .program_single_payout(&1, &s.category, &farmer, &10);
+    // `end` is exclusive.
+    at(&s.env, 2_000);
+    assert_eq!(
+        s.client.try_program_batch_payout(
+            &1,
+            &s.category,
+            &vec![&s.env, farmer.clone()],
+            &vec![&s.env, 10],
+        ),
+        Err(Ok(Error::OutsidePayoutWindow))
+    );
+    at(&s.env, 5_500);
+    s.client.program_batch_payout(
+        &1,
+        &s.category,
+        &vec![&s.env, farmer.clone()],
+        &vec![&s.env, 10],
+    );
+    // Rejected payouts were not charged to the budget.
+    assert_eq!(s.client.get_budget_report(&1).get(0).unwrap().spent, 20);
+}
+
+#[test]
+fn test_schedule_release_confined_to_windows() {
+    let s = setup();
+    let farmer = Address::generate(&s.env);
+    s.client.set_payout_windows(
+        &1,
+        &vec![
+            &s.env,
+            PayoutWindow {
+                start: 1_000,
+                end: 2_000,
+            },
+        ],
+    );
+
+    // Schedules can be created off-season; only the release is gated.
+    let schedule = s
+        .client
+        .program_create_schedule(&1, &s.category, &farmer, &100, &0)
+        .get(0)
+        .unwrap();
+    assert_eq!(
+        s.client
+            .try_program_release_schedule(&1, &schedule.schedule_id),
+        Err(Ok(Error::OutsidePayoutWindow))
+    );
+    at(&s.env, 1_500);
+    s.client.program_release_schedule(&1, &schedule.schedule_id);
+    assert!(s.client.program_schedules(&1).get(0).unwrap().released);
+}
+
+#[test]
+fn test_clearing_windows_reopens_payouts() {
+    let s = setup();
+    let farmer = Address::generate(&s.env);
+    s.client.set_payout_windows(
+        &1,
+        &vec![
+            &s.env,
+            PayoutWindow {
+                start: 1_000,
+                end: 2_000,
+            },
+        ],
+    );
+    s.client.set_payout_windows(&1, &Vec::new(&s.env));
+    assert!(s.client.get_payout_windows(&1).is_empty());
+    s.client
+        .program_single_payout(&1, &s.category, &farmer, &10);
+}
+
+#[test]
+fn test_invalid_windows_rejected() {
+    let s = setup();
+    assert_eq!(
+        s.client.try_set_payout_windows(
+            &1,
+            &vec![
+                &s.env,
+                PayoutWindow {
+                    start: 2_000,
+                    end: 2_000,
+                },
+            ],
+        ),
+        Err(Ok(Error::InvalidPayoutWindow))
+    );
+    assert_eq!(
+        s.client.try_set_payout_windows(&9, &Vec::new(&s.env)),
+        Err(Ok(Error::ProgramNotFound))
+    );
+}